@@ -278,7 +278,7 @@ pub fn app() -> Html {
         let render_state = Rc::clone(&render_state);
         Callback::from(move |e: MouseEvent| {
             e.prevent_default();
-            render_state.borrow_mut().set_should_save_image(true);
+            render_state.borrow().save_image().request();
         })
     };
 
@@ -1,7 +1,6 @@
 use crate::state::render_state_handle::RenderStateHandle;
-use js_sys::Math;
 use web_sys::{WebGl2RenderingContext, WebGlBuffer};
-use wrend::{BufferCreateContext, QUAD};
+use wrend::{BufferCreateContext, Rng, QUAD};
 
 pub fn create_quad_vertex_buffer(ctx: &BufferCreateContext) -> WebGlBuffer {
     let gl = ctx.gl();
@@ -28,14 +27,12 @@ pub fn make_create_particle_buffer_a(
     move |ctx| {
         let gl = ctx.gl();
         let buffer = gl.create_buffer().unwrap();
-        let num_particle_vertices = app_context.get().borrow().num_particle_vertices();
+        let num_particle_vertices = app_context.borrow().num_particle_vertices();
 
         gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, Some(&buffer));
 
-        let initial_particle_positions: Vec<f32> = vec![0.0; num_particle_vertices as usize]
-            .into_iter()
-            .map(|_| (Math::random() * 2.0 - 1.0) as f32)
-            .collect();
+        let initial_particle_positions =
+            Rng::new(js_sys::Date::now() as u64).vec_f32(num_particle_vertices as usize, -1.0, 1.0);
         let initial_particle_positions =
             unsafe { js_sys::Float32Array::view(&initial_particle_positions) };
         gl.buffer_data_with_array_buffer_view(
@@ -56,7 +53,7 @@ pub fn make_create_particle_buffer_b(
     move |ctx| {
         let gl = ctx.gl();
         let buffer = gl.create_buffer().unwrap();
-        let num_particle_vertices = app_context.get().borrow().num_particle_vertices();
+        let num_particle_vertices = app_context.borrow().num_particle_vertices();
 
         gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, Some(&buffer));
 
@@ -4,7 +4,7 @@ use super::{
     transform_feedback_id::TransformFeedbackId, uniform_id::UniformId, vao_id::VAOId,
     vertex_shader_id::VertexShaderId,
 };
-use crate::{state::render_state_handle::RenderStateHandle, utils};
+use crate::state::render_state_handle::RenderStateHandle;
 use web_sys::{HtmlCanvasElement, WebGl2RenderingContext};
 use wrend::RendererData;
 
@@ -44,7 +44,7 @@ pub fn render(
     let user_ctx = renderer_data
         .user_ctx()
         .expect("RenderState should exist during render callback")
-        .get();
+        .clone();
 
     let num_particles = user_ctx.borrow().num_particles();
 
@@ -157,8 +157,5 @@ pub fn render(
     // );
     gl.draw_arrays(WebGl2RenderingContext::POINTS, 0, num_particles as i32);
 
-    if user_ctx.borrow().should_save_image() {
-        user_ctx.borrow_mut().set_should_save_image(false);
-        utils::save_image(canvas);
-    }
+    user_ctx.borrow().save_image().save_if_requested(renderer_data);
 }
@@ -4,7 +4,6 @@ pub mod assets;
 pub mod components;
 pub mod graphics;
 pub mod state;
-pub mod utils;
 
 /// This main function is only used for testing this portion of the app in isolation
 #[wasm_bindgen]
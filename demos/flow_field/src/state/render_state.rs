@@ -1,14 +1,15 @@
 use crate::graphics::{buffer_id::BufferId, vao_id::VAOId};
 
 use super::read_write_buffers::ReadWriteIds;
+use wrend_extras::SaveImage;
 
 pub type RenderStateCount = u32;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Debug)]
 pub struct RenderState {
     num_particles: u32,
     count: u32,
-    should_save_image: bool,
+    save_image: SaveImage,
     is_first_render: bool,
 }
 
@@ -17,7 +18,7 @@ impl Default for RenderState {
         Self {
             num_particles: 100_000,
             count: 0,
-            should_save_image: false,
+            save_image: SaveImage::new(),
             is_first_render: true,
         }
     }
@@ -50,13 +51,8 @@ impl RenderState {
         read_write_buffers
     }
 
-    pub fn should_save_image(&self) -> bool {
-        self.should_save_image
-    }
-
-    pub fn set_should_save_image(&mut self, should_save_image: bool) -> &mut Self {
-        self.should_save_image = should_save_image;
-        self
+    pub fn save_image(&self) -> &SaveImage {
+        &self.save_image
     }
 
     pub fn is_first_render(&self) -> bool {
@@ -1,3 +0,0 @@
-mod save_image;
-
-pub use save_image::save_image;
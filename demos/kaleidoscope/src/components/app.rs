@@ -11,10 +11,11 @@ use strum::IntoEnumIterator;
 
 use wasm_bindgen::JsCast;
 use web_sys::{Event, HtmlSelectElement, MouseEvent};
+use wrend_extras::RecordingControls;
 
 use yew::{
     classes, function_component, html, use_effect_with_deps, use_mut_ref, use_node_ref,
-    use_state_eq, Callback, UseStateHandle,
+    use_state, use_state_eq, Callback, UseStateHandle,
 };
 use yew_router::prelude::*;
 
@@ -35,7 +36,7 @@ pub fn app() -> Html {
     let applied_filters_ref = use_mut_ref(Vec::default);
 
     let app_state_handle_ref = use_mut_ref(|| None);
-    let renderer_ref = use_mut_ref(|| None);
+    let recording_controls = use_state(RecordingControls::new);
 
     use_effect_with_deps(
         {
@@ -60,7 +61,8 @@ pub fn app() -> Html {
             let video_ref = video_ref.clone();
             let app_state_handle_ref = Rc::clone(&app_state_handle_ref);
             let applied_filters_ref = Rc::clone(&applied_filters_ref);
-            let renderer_ref = Rc::clone(&renderer_ref);
+            let recording_controls = (*recording_controls).clone();
+            let is_recording = is_recording.clone();
             let generation_type = generation_type.clone();
             let applied_filters = applied_filters.clone();
             move |_| {
@@ -79,7 +81,8 @@ pub fn app() -> Html {
                 });
 
                 // save handle to keep animation going
-                *renderer_ref.borrow_mut() = Some(new_renderer);
+                recording_controls.set_renderer(Some(new_renderer));
+                recording_controls.add_change_callback(move |value| is_recording.set(value));
 
                 || {}
             }
@@ -151,35 +154,18 @@ pub fn app() -> Html {
     };
 
     let handle_start_recording = {
-        let renderer_ref = Rc::clone(&renderer_ref);
-        let is_recording = is_recording.clone();
-        Callback::from(move |_: MouseEvent| {
-            if let Some(renderer) = &mut *renderer_ref.borrow_mut() {
-                renderer.start_recording();
-                is_recording.set(true);
-            }
-        })
+        let recording_controls = (*recording_controls).clone();
+        Callback::from(move |_: MouseEvent| recording_controls.start())
     };
 
     let handle_stop_recording = {
-        let renderer_ref = Rc::clone(&renderer_ref);
-        let is_recording = is_recording.clone();
-        Callback::from(move |_: MouseEvent| {
-            if let Some(renderer) = &mut *renderer_ref.borrow_mut() {
-                renderer.stop_recording();
-                is_recording.set(false);
-            }
-        })
+        let recording_controls = (*recording_controls).clone();
+        Callback::from(move |_: MouseEvent| recording_controls.stop())
     };
 
     let handle_clear_recorded_data = {
-        let renderer_ref = Rc::clone(&renderer_ref);
-        let _is_recording = is_recording.clone();
-        Callback::from(move |_: MouseEvent| {
-            if let Some(renderer) = &mut *renderer_ref.borrow_mut() {
-                renderer.clear_recorded_data();
-            }
-        })
+        let recording_controls = (*recording_controls).clone();
+        Callback::from(move |_: MouseEvent| recording_controls.clear())
     };
 
     // hide video element when not using video as input
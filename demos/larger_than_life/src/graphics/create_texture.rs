@@ -1,7 +1,6 @@
-use js_sys::Math;
 use wasm_bindgen::JsCast;
 use web_sys::{HtmlCanvasElement, WebGl2RenderingContext, WebGlTexture};
-use wrend::TextureCreateContext;
+use wrend::{Rng, TextureCreateContext};
 
 pub fn create_texture(ctx: &TextureCreateContext) -> WebGlTexture {
     let gl = ctx.gl();
@@ -43,9 +42,9 @@ pub fn create_texture(ctx: &TextureCreateContext) -> WebGlTexture {
     let bytes_per_pixel = 4;
     let length_of_noise_array = (canvas_width * canvas_height * bytes_per_pixel) as usize;
     let mut noise_image = vec![0u8; length_of_noise_array];
+    let mut rng = Rng::new(js_sys::Date::now() as u64);
     for rgba in noise_image.chunks_mut(bytes_per_pixel as usize) {
-        let random_float = Math::random();
-        let black_or_white = if random_float < 0.5 { u8::MIN } else { u8::MAX };
+        let black_or_white = if rng.chance(0.5) { u8::MIN } else { u8::MAX };
 
         rgba[0] = black_or_white;
         rgba[1] = black_or_white;
@@ -0,0 +1,592 @@
+use crate::{Id, RendererBackend, RendererBackendError, ShaderType};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use thiserror::Error;
+
+/// One program for [build_programs] to link: the shaders it's linked from and what to bind while
+/// linking it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgramDescriptor<'a, ProgramId: Id, VertexShaderId: Id, FragmentShaderId: Id> {
+    pub program_id: &'a ProgramId,
+    pub vertex_shader_id: &'a VertexShaderId,
+    pub fragment_shader_id: &'a FragmentShaderId,
+    /// Locations bound (by attribute name) in this program, after the ones in
+    /// [ProgramSources::attribute_locations]
+    pub attribute_locations: &'a [(String, u32)],
+    /// Varyings captured (interleaved) with transform feedback
+    pub transform_feedback_varyings: &'a [String],
+    /// Identifies the program across builds, for backends that cache linked programs
+    pub cache_key: u64,
+}
+
+/// Everything needed to compile the shaders and link the programs of a build
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgramSources<'a, VertexShaderId: Id, FragmentShaderId: Id, ProgramId: Id> {
+    pub vertex_shader_sources: &'a HashMap<VertexShaderId, String>,
+    pub fragment_shader_sources: &'a HashMap<FragmentShaderId, String>,
+    pub programs: &'a [ProgramDescriptor<'a, ProgramId, VertexShaderId, FragmentShaderId>],
+    /// Locations bound (by attribute name) in every program, before each program's own locations
+    pub attribute_locations: &'a [(String, u32)],
+}
+
+/// Why [build_programs] couldn't link a program
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum LinkStepError {
+    #[error("No vertex shader was found associated with the id provided")]
+    VertexShaderNotFound,
+    #[error("No fragment shader was found associated with the id provided")]
+    FragmentShaderNotFound,
+    #[error(transparent)]
+    Backend(#[from] RendererBackendError),
+}
+
+/// Compiles every shader with `compile_shader`, then links every program from them.
+///
+/// Fragment shaders are compiled before vertex shaders, and nothing is linked until every shader
+/// has compiled. `compile_shader` receives each shader's id (for error messages) and its original
+/// source, so that it can preprocess the source before handing it to the backend.
+///
+/// Shaders and programs that already have a handle (e.g. ones reused from a previous build) are
+/// skipped, and the first failure is returned before anything else is created.
+pub fn build_programs<
+    VertexShaderId: Id,
+    FragmentShaderId: Id,
+    ProgramId: Id,
+    Backend: RendererBackend + ?Sized,
+    E: From<LinkStepError>,
+>(
+    backend: &Backend,
+    sources: &ProgramSources<VertexShaderId, FragmentShaderId, ProgramId>,
+    compile_shader: impl Fn(&Backend, &dyn Debug, ShaderType, &str) -> Result<Backend::Shader, E>,
+    vertex_shaders: &mut HashMap<VertexShaderId, Backend::Shader>,
+    fragment_shaders: &mut HashMap<FragmentShaderId, Backend::Shader>,
+    programs: &mut HashMap<ProgramId, Backend::Program>,
+) -> Result<(), E> {
+    compile_shaders(
+        backend,
+        ShaderType::FragmentShader,
+        sources.fragment_shader_sources,
+        &compile_shader,
+        fragment_shaders,
+    )?;
+    compile_shaders(
+        backend,
+        ShaderType::VertexShader,
+        sources.vertex_shader_sources,
+        &compile_shader,
+        vertex_shaders,
+    )?;
+
+    for program in sources.programs {
+        if programs.contains_key(program.program_id) {
+            continue;
+        }
+        let vertex_shader = vertex_shaders
+            .get(program.vertex_shader_id)
+            .ok_or(LinkStepError::VertexShaderNotFound)?;
+        let fragment_shader = fragment_shaders
+            .get(program.fragment_shader_id)
+            .ok_or(LinkStepError::FragmentShaderNotFound)?;
+
+        let attribute_locations: Vec<(String, u32)> = sources
+            .attribute_locations
+            .iter()
+            .chain(program.attribute_locations)
+            .cloned()
+            .collect();
+
+        let linked_program = backend
+            .link_program(
+                vertex_shader,
+                fragment_shader,
+                &attribute_locations,
+                program.transform_feedback_varyings,
+                program.cache_key,
+            )
+            .map_err(LinkStepError::from)?;
+        programs.insert(program.program_id.clone(), linked_program);
+    }
+
+    Ok(())
+}
+
+/// Compiles every source that doesn't already have a shader in `shaders`
+fn compile_shaders<ShaderId: Id, Backend: RendererBackend + ?Sized, E>(
+    backend: &Backend,
+    shader_type: ShaderType,
+    sources: &HashMap<ShaderId, String>,
+    compile_shader: &impl Fn(&Backend, &dyn Debug, ShaderType, &str) -> Result<Backend::Shader, E>,
+    shaders: &mut HashMap<ShaderId, Backend::Shader>,
+) -> Result<(), E> {
+    for (shader_id, source) in sources {
+        if shaders.contains_key(shader_id) {
+            continue;
+        }
+        let shader = compile_shader(backend, shader_id, shader_type, source)?;
+        shaders.insert(shader_id.clone(), shader);
+    }
+
+    Ok(())
+}
+
+/// Creates a vertex array for every id, plus the empty one used for attribute-less draws (if it
+/// doesn't exist yet)
+pub fn create_vertex_arrays<'a, VertexArrayObjectId: Id, Backend: RendererBackend + ?Sized>(
+    backend: &Backend,
+    vertex_array_object_ids: impl IntoIterator<Item = &'a VertexArrayObjectId>,
+    vertex_array_objects: &mut HashMap<VertexArrayObjectId, Backend::VertexArray>,
+    empty_vao: &mut Option<Backend::VertexArray>,
+) -> Result<(), RendererBackendError> {
+    for vertex_array_object_id in vertex_array_object_ids {
+        let vertex_array_object = backend.create_vertex_array()?;
+        vertex_array_objects.insert(vertex_array_object_id.clone(), vertex_array_object);
+    }
+
+    if empty_vao.is_none() {
+        *empty_vao = Some(backend.create_vertex_array()?);
+    }
+
+    Ok(())
+}
+
+/// Creates a transform feedback object for every id that doesn't already have one
+pub fn create_transform_feedbacks<
+    'a,
+    TransformFeedbackId: Id,
+    Backend: RendererBackend + ?Sized,
+>(
+    backend: &Backend,
+    transform_feedback_ids: impl IntoIterator<Item = &'a TransformFeedbackId>,
+    transform_feedbacks: &mut HashMap<TransformFeedbackId, Backend::TransformFeedback>,
+) -> Result<(), RendererBackendError> {
+    for transform_feedback_id in transform_feedback_ids {
+        if transform_feedbacks.contains_key(transform_feedback_id) {
+            continue;
+        }
+        let transform_feedback = backend.create_transform_feedback()?;
+        transform_feedbacks.insert(transform_feedback_id.clone(), transform_feedback);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        build_programs, create_transform_feedbacks, create_vertex_arrays, LinkStepError,
+        ProgramDescriptor, ProgramSources,
+    };
+    use crate::{RendererBackend, RendererBackendError, ShaderType};
+    use std::{cell::RefCell, collections::HashMap, fmt::Debug};
+
+    /// Records every call it receives. Shaders are named after the `// name` comment in their
+    /// source. Sources that use `fail;` fail to compile, and programs using an `unlinkable` shader
+    /// fail to link.
+    #[derive(Default)]
+    struct MockBackend {
+        calls: RefCell<Vec<String>>,
+        unavailable: bool,
+    }
+
+    impl MockBackend {
+        fn calls(&self) -> Vec<String> {
+            self.calls.borrow().clone()
+        }
+    }
+
+    impl RendererBackend for MockBackend {
+        type Shader = String;
+        type Program = String;
+        type VertexArray = usize;
+        type TransformFeedback = usize;
+
+        fn compile_shader(
+            &self,
+            shader_type: ShaderType,
+            source: &str,
+        ) -> Result<Self::Shader, RendererBackendError> {
+            let name = source
+                .lines()
+                .find_map(|line| line.strip_prefix("// "))
+                .unwrap_or_default()
+                .to_string();
+            self.calls
+                .borrow_mut()
+                .push(format!("compile {shader_type:?} {name}"));
+            if source.contains("fail;") {
+                return Err(RendererBackendError::KnownError(
+                    "'fail' : undeclared identifier".to_string(),
+                ));
+            }
+            Ok(name)
+        }
+
+        fn link_program(
+            &self,
+            vertex_shader: &Self::Shader,
+            fragment_shader: &Self::Shader,
+            attribute_locations: &[(String, u32)],
+            transform_feedback_varyings: &[String],
+            _cache_key: u64,
+        ) -> Result<Self::Program, RendererBackendError> {
+            let program = format!("{vertex_shader}+{fragment_shader}");
+            self.calls.borrow_mut().push(format!(
+                "link {program} {attribute_locations:?} {transform_feedback_varyings:?}"
+            ));
+            if program.contains("unlinkable") {
+                return Err(RendererBackendError::KnownError(
+                    "Varyings over limit".to_string(),
+                ));
+            }
+            Ok(program)
+        }
+
+        fn create_vertex_array(&self) -> Result<Self::VertexArray, RendererBackendError> {
+            let mut calls = self.calls.borrow_mut();
+            calls.push("create vertex array".to_string());
+            if self.unavailable {
+                return Err(RendererBackendError::NoneReturned);
+            }
+            Ok(calls.len())
+        }
+
+        fn create_transform_feedback(
+            &self,
+        ) -> Result<Self::TransformFeedback, RendererBackendError> {
+            let mut calls = self.calls.borrow_mut();
+            calls.push("create transform feedback".to_string());
+            if self.unavailable {
+                return Err(RendererBackendError::NoneReturned);
+            }
+            Ok(calls.len())
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum TestError {
+        Compile(String, RendererBackendError),
+        Link(LinkStepError),
+    }
+
+    impl From<LinkStepError> for TestError {
+        fn from(error: LinkStepError) -> Self {
+            TestError::Link(error)
+        }
+    }
+
+    fn compile_shader(
+        backend: &MockBackend,
+        shader_id: &dyn Debug,
+        shader_type: ShaderType,
+        source: &str,
+    ) -> Result<String, TestError> {
+        backend
+            .compile_shader(shader_type, source)
+            .map_err(|error| TestError::Compile(format!("{shader_id:?}"), error))
+    }
+
+    struct TestProgram {
+        program_id: String,
+        vertex_shader_id: String,
+        fragment_shader_id: String,
+        attribute_locations: Vec<(String, u32)>,
+        transform_feedback_varyings: Vec<String>,
+    }
+
+    impl TestProgram {
+        fn new(program_id: &str, vertex_shader_id: &str, fragment_shader_id: &str) -> Self {
+            Self {
+                program_id: program_id.to_string(),
+                vertex_shader_id: vertex_shader_id.to_string(),
+                fragment_shader_id: fragment_shader_id.to_string(),
+                attribute_locations: Vec::new(),
+                transform_feedback_varyings: Vec::new(),
+            }
+        }
+    }
+
+    struct TestProgramSources {
+        vertex_shader_sources: HashMap<String, String>,
+        fragment_shader_sources: HashMap<String, String>,
+        programs: Vec<TestProgram>,
+        attribute_locations: Vec<(String, u32)>,
+    }
+
+    impl TestProgramSources {
+        /// Two programs that share a vertex shader
+        fn new() -> Self {
+            Self {
+                vertex_shader_sources: HashMap::from([(
+                    "quad".to_string(),
+                    "// quad\nvoid main() {}".to_string(),
+                )]),
+                fragment_shader_sources: HashMap::from([
+                    ("red".to_string(), "// red\nvoid main() {}".to_string()),
+                    ("blue".to_string(), "// blue\nvoid main() {}".to_string()),
+                ]),
+                programs: vec![
+                    TestProgram::new("red", "quad", "red"),
+                    TestProgram::new("blue", "quad", "blue"),
+                ],
+                attribute_locations: Vec::new(),
+            }
+        }
+
+        fn build(
+            &self,
+            backend: &MockBackend,
+            programs: &mut HashMap<String, String>,
+        ) -> Result<(), TestError> {
+            let mut vertex_shaders = HashMap::new();
+            let mut fragment_shaders = HashMap::new();
+            self.build_reusing(
+                backend,
+                &mut vertex_shaders,
+                &mut fragment_shaders,
+                programs,
+            )
+        }
+
+        fn build_reusing(
+            &self,
+            backend: &MockBackend,
+            vertex_shaders: &mut HashMap<String, String>,
+            fragment_shaders: &mut HashMap<String, String>,
+            programs: &mut HashMap<String, String>,
+        ) -> Result<(), TestError> {
+            let descriptors: Vec<_> = self
+                .programs
+                .iter()
+                .map(|program| ProgramDescriptor {
+                    program_id: &program.program_id,
+                    vertex_shader_id: &program.vertex_shader_id,
+                    fragment_shader_id: &program.fragment_shader_id,
+                    attribute_locations: &program.attribute_locations,
+                    transform_feedback_varyings: &program.transform_feedback_varyings,
+                    cache_key: 0,
+                })
+                .collect();
+            let sources = ProgramSources {
+                vertex_shader_sources: &self.vertex_shader_sources,
+                fragment_shader_sources: &self.fragment_shader_sources,
+                programs: &descriptors,
+                attribute_locations: &self.attribute_locations,
+            };
+            build_programs(
+                backend,
+                &sources,
+                compile_shader,
+                vertex_shaders,
+                fragment_shaders,
+                programs,
+            )
+        }
+    }
+
+    fn position(calls: &[String], prefix: &str) -> usize {
+        calls
+            .iter()
+            .position(|call| call.starts_with(prefix))
+            .unwrap_or_else(|| panic!("no call starting with {prefix:?} in {calls:?}"))
+    }
+
+    #[test]
+    fn compiles_every_shader_before_linking_programs() {
+        let backend = MockBackend::default();
+        let mut programs = HashMap::new();
+        TestProgramSources::new()
+            .build(&backend, &mut programs)
+            .unwrap();
+
+        let calls = backend.calls();
+        assert_eq!(calls.len(), 5);
+        let last_compile = calls
+            .iter()
+            .rposition(|call| call.starts_with("compile"))
+            .unwrap();
+        assert!(last_compile < position(&calls, "link"));
+        assert!(
+            position(&calls, "compile FragmentShader") < position(&calls, "compile VertexShader")
+        );
+        assert_eq!(programs["red"], "quad+red");
+        assert_eq!(programs["blue"], "quad+blue");
+    }
+
+    #[test]
+    fn compiles_shared_shaders_once() {
+        let backend = MockBackend::default();
+        TestProgramSources::new()
+            .build(&backend, &mut HashMap::new())
+            .unwrap();
+
+        let calls = backend.calls();
+        let quad_compiles = calls
+            .iter()
+            .filter(|call| call.as_str() == "compile VertexShader quad")
+            .count();
+        assert_eq!(quad_compiles, 1);
+    }
+
+    #[test]
+    fn reuses_handles_from_a_previous_build() {
+        let sources = TestProgramSources::new();
+        let mut vertex_shaders = HashMap::from([("quad".to_string(), "quad".to_string())]);
+        let mut fragment_shaders = HashMap::from([("red".to_string(), "red".to_string())]);
+        let mut programs = HashMap::from([("red".to_string(), "quad+red".to_string())]);
+
+        let backend = MockBackend::default();
+        sources
+            .build_reusing(
+                &backend,
+                &mut vertex_shaders,
+                &mut fragment_shaders,
+                &mut programs,
+            )
+            .unwrap();
+
+        assert_eq!(
+            backend.calls(),
+            vec![
+                "compile FragmentShader blue".to_string(),
+                "link quad+blue [] []".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn binds_shared_attribute_locations_before_program_ones() {
+        let mut sources = TestProgramSources::new();
+        sources.attribute_locations = vec![("a_position".to_string(), 0)];
+        let mut program = TestProgram::new("particles", "quad", "red");
+        program.attribute_locations = vec![("a_velocity".to_string(), 1)];
+        program.transform_feedback_varyings = vec!["v_position".to_string()];
+        sources.programs = vec![program];
+
+        let backend = MockBackend::default();
+        sources.build(&backend, &mut HashMap::new()).unwrap();
+
+        let calls = backend.calls();
+        assert_eq!(
+            calls[position(&calls, "link")],
+            r#"link quad+red [("a_position", 0), ("a_velocity", 1)] ["v_position"]"#
+        );
+    }
+
+    #[test]
+    fn stops_at_the_first_compile_error_without_linking() {
+        let mut sources = TestProgramSources::new();
+        sources.fragment_shader_sources.insert(
+            "red".to_string(),
+            "// red\nvoid main() { fail; }".to_string(),
+        );
+
+        let backend = MockBackend::default();
+        let error = sources.build(&backend, &mut HashMap::new()).unwrap_err();
+
+        assert_eq!(
+            error,
+            TestError::Compile(
+                r#""red""#.to_string(),
+                RendererBackendError::KnownError("'fail' : undeclared identifier".to_string())
+            )
+        );
+        assert!(!backend.calls().iter().any(|call| call.starts_with("link")));
+    }
+
+    #[test]
+    fn reports_link_errors() {
+        let mut sources = TestProgramSources::new();
+        sources.fragment_shader_sources.insert(
+            "blue".to_string(),
+            "// unlinkable\nvoid main() {}".to_string(),
+        );
+
+        let error = sources
+            .build(&MockBackend::default(), &mut HashMap::new())
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            TestError::Link(LinkStepError::Backend(RendererBackendError::KnownError(
+                "Varyings over limit".to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn reports_programs_whose_shaders_were_never_compiled() {
+        let mut sources = TestProgramSources::new();
+        sources
+            .programs
+            .push(TestProgram::new("green", "quad", "green"));
+
+        let error = sources
+            .build(&MockBackend::default(), &mut HashMap::new())
+            .unwrap_err();
+
+        assert_eq!(
+            error,
+            TestError::Link(LinkStepError::FragmentShaderNotFound)
+        );
+    }
+
+    #[test]
+    fn creates_the_empty_vertex_array_once() {
+        let backend = MockBackend::default();
+        let vao_ids = ["points".to_string(), "lines".to_string()];
+        let mut vertex_array_objects = HashMap::new();
+        let mut empty_vao = None;
+
+        create_vertex_arrays(
+            &backend,
+            &vao_ids,
+            &mut vertex_array_objects,
+            &mut empty_vao,
+        )
+        .unwrap();
+        create_vertex_arrays(
+            &backend,
+            &vao_ids,
+            &mut vertex_array_objects,
+            &mut empty_vao,
+        )
+        .unwrap();
+
+        assert_eq!(backend.calls().len(), 5);
+        assert_eq!(vertex_array_objects.len(), 2);
+        assert_eq!(empty_vao, Some(3));
+    }
+
+    #[test]
+    fn creates_missing_transform_feedbacks_only() {
+        let backend = MockBackend::default();
+        let ids = ["particles".to_string(), "trails".to_string()];
+        let mut transform_feedbacks = HashMap::from([("particles".to_string(), 0)]);
+
+        create_transform_feedbacks(&backend, &ids, &mut transform_feedbacks).unwrap();
+
+        assert_eq!(
+            backend.calls(),
+            vec!["create transform feedback".to_string()]
+        );
+        assert_eq!(transform_feedbacks["particles"], 0);
+        assert_eq!(transform_feedbacks["trails"], 1);
+    }
+
+    #[test]
+    fn reports_missing_vertex_arrays_and_transform_feedbacks() {
+        let backend = MockBackend {
+            unavailable: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            create_vertex_arrays::<String, _>(&backend, [], &mut HashMap::new(), &mut None),
+            Err(RendererBackendError::NoneReturned)
+        );
+        assert_eq!(
+            create_transform_feedbacks(&backend, &["particles".to_string()], &mut HashMap::new()),
+            Err(RendererBackendError::NoneReturned)
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+/// A suspicious, but valid, use of [crate::IdName]s found while building (see [crate::LinkGraph::id_name_warnings]).
+///
+/// Ids are stored in their `Debug` representation so that the warning does not need to be generic.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum IdNameWarning {
+    /// Several uniform ids share the same name, each in different programs
+    UniformNameSharedAcrossPrograms {
+        name: String,
+        uniform_ids: Vec<String>,
+    },
+}
+
+impl IdNameWarning {
+    /// The name that is shared
+    pub fn name(&self) -> &str {
+        match self {
+            Self::UniformNameSharedAcrossPrograms { name, .. } => name,
+        }
+    }
+}
+
+impl Display for IdNameWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UniformNameSharedAcrossPrograms { name, uniform_ids } => write!(
+                f,
+                "Uniforms {} are all named `{name}` (in different programs). If they are meant to be the same uniform, use a single id linked to every program",
+                uniform_ids.join(", ")
+            ),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! The GL-free core of [wrend](https://docs.rs/wrend/): ids, link graph validation, and the
+//! [RendererBackend] trait along with the order a build makes its backend calls in.
+//!
+//! Nothing in here depends on `web-sys`, so it can be unit tested natively. Everything is re-exported from `wrend`.
+
+mod build_steps;
+mod id;
+mod id_name;
+mod id_name_warning;
+mod link_graph;
+mod link_graph_error;
+mod renderer_backend;
+mod renderer_backend_error;
+mod shader_type;
+
+pub use build_steps::*;
+pub use id::*;
+pub use id_name::*;
+pub use id_name_warning::*;
+pub use link_graph::*;
+pub use link_graph_error::*;
+pub use renderer_backend::*;
+pub use renderer_backend_error::*;
+pub use shader_type::*;
@@ -0,0 +1,440 @@
+use crate::{Id, IdNameWarning, LinkGraphError};
+use std::collections::{HashMap, HashSet};
+
+/// A GL-free description of how the links added to wrend's `RendererDataBuilder` refer to one another.
+///
+/// Holds only ids, so it can be validated (and unit tested) without a browser or a WebGL context.
+/// The builder validates its graph before creating any WebGL resources, so that a link referring to a
+/// missing resource is reported up front, by id, instead of surfacing later as a generic creation error.
+#[derive(Debug, Clone, Default)]
+pub struct LinkGraph<
+    VertexShaderId: Id,
+    FragmentShaderId: Id,
+    ProgramId: Id,
+    UniformId: Id,
+    BufferId: Id,
+    AttributeId: Id,
+    TextureId: Id,
+    FramebufferId: Id,
+    VertexArrayObjectId: Id,
+> {
+    vertex_shaders: HashSet<VertexShaderId>,
+    fragment_shaders: HashSet<FragmentShaderId>,
+    programs: Vec<(ProgramId, VertexShaderId, FragmentShaderId)>,
+    uniforms: Vec<(UniformId, String, Vec<ProgramId>)>,
+    uniform_namespaces: HashMap<UniformId, String>,
+    buffers: HashSet<BufferId>,
+    attributes: Vec<(AttributeId, String, BufferId, Vec<VertexArrayObjectId>)>,
+    textures: HashSet<TextureId>,
+    framebuffers: Vec<(FramebufferId, Option<TextureId>)>,
+    vaos: HashSet<VertexArrayObjectId>,
+}
+
+impl<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        ProgramId: Id,
+        UniformId: Id,
+        BufferId: Id,
+        AttributeId: Id,
+        TextureId: Id,
+        FramebufferId: Id,
+        VertexArrayObjectId: Id,
+    >
+    LinkGraph<
+        VertexShaderId,
+        FragmentShaderId,
+        ProgramId,
+        UniformId,
+        BufferId,
+        AttributeId,
+        TextureId,
+        FramebufferId,
+        VertexArrayObjectId,
+    >
+{
+    pub fn new() -> Self {
+        Self {
+            vertex_shaders: HashSet::new(),
+            fragment_shaders: HashSet::new(),
+            programs: Vec::new(),
+            uniforms: Vec::new(),
+            uniform_namespaces: HashMap::new(),
+            buffers: HashSet::new(),
+            attributes: Vec::new(),
+            textures: HashSet::new(),
+            framebuffers: Vec::new(),
+            vaos: HashSet::new(),
+        }
+    }
+
+    pub fn add_vertex_shader(&mut self, vertex_shader_id: VertexShaderId) -> &mut Self {
+        self.vertex_shaders.insert(vertex_shader_id);
+        self
+    }
+
+    pub fn add_fragment_shader(&mut self, fragment_shader_id: FragmentShaderId) -> &mut Self {
+        self.fragment_shaders.insert(fragment_shader_id);
+        self
+    }
+
+    pub fn add_program(
+        &mut self,
+        program_id: ProgramId,
+        vertex_shader_id: VertexShaderId,
+        fragment_shader_id: FragmentShaderId,
+    ) -> &mut Self {
+        self.programs
+            .push((program_id, vertex_shader_id, fragment_shader_id));
+        self
+    }
+
+    /// `name` is the uniform's [crate::IdName], which is used to look up its location in each program
+    pub fn add_uniform(
+        &mut self,
+        uniform_id: UniformId,
+        name: String,
+        program_ids: Vec<ProgramId>,
+    ) -> &mut Self {
+        self.uniforms.push((uniform_id, name, program_ids));
+        self
+    }
+
+    /// Marks a uniform as added by a plugin in `namespace` (see `RendererDataBuilder::add_namespace`),
+    /// so that it isn't reported as sharing its name with uniforms outside of that namespace
+    pub fn set_uniform_namespace(&mut self, uniform_id: UniformId, namespace: String) -> &mut Self {
+        self.uniform_namespaces.insert(uniform_id, namespace);
+        self
+    }
+
+    pub fn add_buffer(&mut self, buffer_id: BufferId) -> &mut Self {
+        self.buffers.insert(buffer_id);
+        self
+    }
+
+    /// `name` is the attribute's [crate::IdName], which is used to look up its location
+    pub fn add_attribute(
+        &mut self,
+        attribute_id: AttributeId,
+        name: String,
+        buffer_id: BufferId,
+        vao_ids: Vec<VertexArrayObjectId>,
+    ) -> &mut Self {
+        self.attributes
+            .push((attribute_id, name, buffer_id, vao_ids));
+        self
+    }
+
+    pub fn add_texture(&mut self, texture_id: TextureId) -> &mut Self {
+        self.textures.insert(texture_id);
+        self
+    }
+
+    pub fn add_framebuffer(
+        &mut self,
+        framebuffer_id: FramebufferId,
+        texture_id: Option<TextureId>,
+    ) -> &mut Self {
+        self.framebuffers.push((framebuffer_id, texture_id));
+        self
+    }
+
+    pub fn add_vao(&mut self, vao_id: VertexArrayObjectId) -> &mut Self {
+        self.vaos.insert(vao_id);
+        self
+    }
+
+    /// Checks that every id referred to by a link was added to the graph.
+    ///
+    /// Returns the first dangling reference found.
+    pub fn validate(&self) -> Result<(), LinkGraphError> {
+        let program_ids: HashSet<&ProgramId> = self.programs.iter().map(|(id, ..)| id).collect();
+
+        for (program_id, vertex_shader_id, fragment_shader_id) in &self.programs {
+            if !self.vertex_shaders.contains(vertex_shader_id) {
+                return Err(LinkGraphError::VertexShaderNotFound {
+                    program_id: format!("{program_id:?}"),
+                    vertex_shader_id: format!("{vertex_shader_id:?}"),
+                });
+            }
+            if !self.fragment_shaders.contains(fragment_shader_id) {
+                return Err(LinkGraphError::FragmentShaderNotFound {
+                    program_id: format!("{program_id:?}"),
+                    fragment_shader_id: format!("{fragment_shader_id:?}"),
+                });
+            }
+        }
+
+        let mut uniform_names: HashMap<(&ProgramId, &str), &UniformId> = HashMap::new();
+        for (uniform_id, name, uniform_program_ids) in &self.uniforms {
+            if let Some(program_id) = uniform_program_ids
+                .iter()
+                .find(|program_id| !program_ids.contains(program_id))
+            {
+                return Err(LinkGraphError::UniformProgramNotFound {
+                    uniform_id: format!("{uniform_id:?}"),
+                    program_id: format!("{program_id:?}"),
+                });
+            }
+            for program_id in uniform_program_ids {
+                match uniform_names.insert((program_id, name), uniform_id) {
+                    Some(other_uniform_id) if other_uniform_id != uniform_id => {
+                        return Err(LinkGraphError::UniformNameCollision {
+                            name: name.clone(),
+                            program_id: format!("{program_id:?}"),
+                            uniform_id: format!("{other_uniform_id:?}"),
+                            other_uniform_id: format!("{uniform_id:?}"),
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut attribute_bindings = HashSet::new();
+        let mut attribute_names = HashMap::new();
+        for (attribute_id, name, buffer_id, vao_ids) in &self.attributes {
+            let binding_vao_ids: Vec<Option<&VertexArrayObjectId>> = if vao_ids.is_empty() {
+                vec![None]
+            } else {
+                vao_ids.iter().map(Some).collect()
+            };
+            for vao_id in binding_vao_ids {
+                let vao_name = || {
+                    vao_id.map_or_else(
+                        || String::from("the default vertex array"),
+                        |vao_id| format!("{vao_id:?}"),
+                    )
+                };
+                if !attribute_bindings.insert((attribute_id, vao_id)) {
+                    return Err(LinkGraphError::DuplicateAttributeBinding {
+                        attribute_id: format!("{attribute_id:?}"),
+                        vao_id: vao_name(),
+                    });
+                }
+                if let Some(other_attribute_id) =
+                    attribute_names.insert((name.as_str(), vao_id), attribute_id)
+                {
+                    return Err(LinkGraphError::AttributeNameCollision {
+                        name: name.clone(),
+                        vao_id: vao_name(),
+                        attribute_id: format!("{other_attribute_id:?}"),
+                        other_attribute_id: format!("{attribute_id:?}"),
+                    });
+                }
+            }
+
+            if !self.buffers.contains(buffer_id) {
+                return Err(LinkGraphError::AttributeBufferNotFound {
+                    attribute_id: format!("{attribute_id:?}"),
+                    buffer_id: format!("{buffer_id:?}"),
+                });
+            }
+            if let Some(vao_id) = vao_ids.iter().find(|vao_id| !self.vaos.contains(vao_id)) {
+                return Err(LinkGraphError::AttributeVAONotFound {
+                    attribute_id: format!("{attribute_id:?}"),
+                    vao_id: format!("{vao_id:?}"),
+                });
+            }
+        }
+
+        for (framebuffer_id, texture_id) in &self.framebuffers {
+            if let Some(texture_id) = texture_id {
+                if !self.textures.contains(texture_id) {
+                    return Err(LinkGraphError::FramebufferTextureNotFound {
+                        framebuffer_id: format!("{framebuffer_id:?}"),
+                        texture_id: format!("{texture_id:?}"),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finds uniforms with different ids but the same name in different programs (of the same namespace).
+    ///
+    /// This is allowed (each program has its own uniform locations), but is usually a copy-paste mistake,
+    /// so the builder logs a warning for each one. Collisions within the same program are errors instead
+    /// (see [LinkGraph::validate]).
+    pub fn id_name_warnings(&self) -> Vec<IdNameWarning> {
+        let mut uniform_ids_by_name: HashMap<(Option<&String>, &str), Vec<&UniformId>> =
+            HashMap::new();
+        for (uniform_id, name, _) in &self.uniforms {
+            let namespace = self.uniform_namespaces.get(uniform_id);
+            let uniform_ids = uniform_ids_by_name.entry((namespace, name)).or_default();
+            if !uniform_ids.contains(&uniform_id) {
+                uniform_ids.push(uniform_id);
+            }
+        }
+
+        let mut warnings: Vec<IdNameWarning> = uniform_ids_by_name
+            .into_iter()
+            .filter(|(_, uniform_ids)| uniform_ids.len() > 1)
+            .map(|((_, name), uniform_ids)| {
+                let mut uniform_ids: Vec<String> = uniform_ids
+                    .iter()
+                    .map(|uniform_id| format!("{uniform_id:?}"))
+                    .collect();
+                uniform_ids.sort();
+                IdNameWarning::UniformNameSharedAcrossPrograms {
+                    name: name.to_string(),
+                    uniform_ids,
+                }
+            })
+            .collect();
+        warnings.sort_by(|a, b| a.name().cmp(b.name()));
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinkGraph;
+    use crate::{IdNameWarning, LinkGraphError};
+
+    type TestGraph =
+        LinkGraph<String, String, String, String, String, String, String, String, String>;
+
+    fn valid_graph() -> TestGraph {
+        let mut graph = TestGraph::new();
+        graph
+            .add_vertex_shader("vertex".into())
+            .add_fragment_shader("fragment".into())
+            .add_program("program".into(), "vertex".into(), "fragment".into())
+            .add_uniform("u_time".into(), "u_time".into(), vec!["program".into()])
+            .add_buffer("positions".into())
+            .add_vao("vao".into())
+            .add_attribute(
+                "a_position".into(),
+                "a_position".into(),
+                "positions".into(),
+                vec!["vao".into()],
+            )
+            .add_texture("color".into())
+            .add_framebuffer("target".into(), Some("color".into()));
+        graph
+    }
+
+    #[test]
+    fn accepts_fully_linked_graph() {
+        assert_eq!(valid_graph().validate(), Ok(()));
+    }
+
+    #[test]
+    fn accepts_same_attribute_with_different_buffers_per_vao() {
+        let mut graph = valid_graph();
+        graph
+            .add_buffer("positions_b".into())
+            .add_vao("vao_b".into())
+            .add_attribute(
+                "a_position".into(),
+                "a_position".into(),
+                "positions_b".into(),
+                vec!["vao_b".into()],
+            );
+        assert_eq!(graph.validate(), Ok(()));
+    }
+
+    #[test]
+    fn accepts_vao_without_attributes() {
+        let mut graph = valid_graph();
+        graph.add_vao("attributeless".into());
+        assert_eq!(graph.validate(), Ok(()));
+    }
+
+    #[test]
+    fn reports_dangling_references_by_id() {
+        let mut graph = valid_graph();
+        graph.add_program("other".into(), "missing".into(), "fragment".into());
+        assert_eq!(
+            graph.validate(),
+            Err(LinkGraphError::VertexShaderNotFound {
+                program_id: "\"other\"".into(),
+                vertex_shader_id: "\"missing\"".into(),
+            })
+        );
+
+        let mut graph = valid_graph();
+        graph.add_uniform("u_mouse".into(), "u_mouse".into(), vec!["nowhere".into()]);
+        assert!(matches!(
+            graph.validate(),
+            Err(LinkGraphError::UniformProgramNotFound { .. })
+        ));
+
+        let mut graph = valid_graph();
+        graph.add_attribute(
+            "a_uv".into(),
+            "a_uv".into(),
+            "positions".into(),
+            vec!["other_vao".into()],
+        );
+        assert!(matches!(
+            graph.validate(),
+            Err(LinkGraphError::AttributeVAONotFound { .. })
+        ));
+
+        let mut graph = valid_graph();
+        graph.add_attribute(
+            "a_position".into(),
+            "a_position".into(),
+            "positions".into(),
+            vec!["vao".into()],
+        );
+        assert!(matches!(
+            graph.validate(),
+            Err(LinkGraphError::DuplicateAttributeBinding { .. })
+        ));
+
+        let mut graph = valid_graph();
+        graph.add_framebuffer("blur".into(), Some("missing".into()));
+        assert!(matches!(
+            graph.validate(),
+            Err(LinkGraphError::FramebufferTextureNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn reports_id_name_collisions() {
+        let mut graph = valid_graph();
+        graph.add_uniform("time".into(), "u_time".into(), vec!["program".into()]);
+        assert_eq!(
+            graph.validate(),
+            Err(LinkGraphError::UniformNameCollision {
+                name: "u_time".into(),
+                program_id: "\"program\"".into(),
+                uniform_id: "\"u_time\"".into(),
+                other_uniform_id: "\"time\"".into(),
+            })
+        );
+
+        let mut graph = valid_graph();
+        graph.add_attribute(
+            "position".into(),
+            "a_position".into(),
+            "positions".into(),
+            vec!["vao".into()],
+        );
+        assert!(matches!(
+            graph.validate(),
+            Err(LinkGraphError::AttributeNameCollision { .. })
+        ));
+
+        let mut graph = valid_graph();
+        graph
+            .add_vertex_shader("other_vertex".into())
+            .add_program("other".into(), "other_vertex".into(), "fragment".into())
+            .add_uniform("other_time".into(), "u_time".into(), vec!["other".into()]);
+        assert_eq!(graph.validate(), Ok(()));
+        assert_eq!(
+            graph.id_name_warnings(),
+            vec![IdNameWarning::UniformNameSharedAcrossPrograms {
+                name: "u_time".into(),
+                uniform_ids: vec!["\"other_time\"".into(), "\"u_time\"".into()],
+            }]
+        );
+
+        graph.set_uniform_namespace("other_time".into(), "plugin".into());
+        assert_eq!(graph.id_name_warnings(), vec![]);
+    }
+}
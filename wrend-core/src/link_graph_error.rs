@@ -0,0 +1,64 @@
+use thiserror::Error;
+
+/// Describes a link that refers to a resource that was never added to the builder,
+/// or links whose [crate::IdName]s collide.
+///
+/// Ids are stored in their `Debug` representation so that the error does not need to be generic.
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum LinkGraphError {
+    #[error(
+        "Program {program_id} refers to vertex shader {vertex_shader_id}, which was never added"
+    )]
+    VertexShaderNotFound {
+        program_id: String,
+        vertex_shader_id: String,
+    },
+    #[error(
+        "Program {program_id} refers to fragment shader {fragment_shader_id}, which was never added"
+    )]
+    FragmentShaderNotFound {
+        program_id: String,
+        fragment_shader_id: String,
+    },
+    #[error("Attribute {attribute_id} refers to buffer {buffer_id}, which was never added")]
+    AttributeBufferNotFound {
+        attribute_id: String,
+        buffer_id: String,
+    },
+    #[error(
+        "Attribute {attribute_id} refers to Vertex Array Object {vao_id}, which was never added"
+    )]
+    AttributeVAONotFound {
+        attribute_id: String,
+        vao_id: String,
+    },
+    #[error("Attribute {attribute_id} is linked more than once in {vao_id}")]
+    DuplicateAttributeBinding {
+        attribute_id: String,
+        vao_id: String,
+    },
+    #[error("Attributes {attribute_id} and {other_attribute_id} are both named `{name}` in {vao_id}, so they would share a location")]
+    AttributeNameCollision {
+        name: String,
+        vao_id: String,
+        attribute_id: String,
+        other_attribute_id: String,
+    },
+    #[error("Uniform {uniform_id} refers to program {program_id}, which was never added")]
+    UniformProgramNotFound {
+        uniform_id: String,
+        program_id: String,
+    },
+    #[error("Framebuffer {framebuffer_id} refers to texture {texture_id}, which was never added")]
+    FramebufferTextureNotFound {
+        framebuffer_id: String,
+        texture_id: String,
+    },
+    #[error("Uniforms {uniform_id} and {other_uniform_id} are both named `{name}` in program {program_id}, so they would share a location")]
+    UniformNameCollision {
+        name: String,
+        program_id: String,
+        uniform_id: String,
+        other_uniform_id: String,
+    },
+}
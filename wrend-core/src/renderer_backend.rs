@@ -0,0 +1,50 @@
+use crate::{RendererBackendError, ShaderType};
+
+/// The calls `wrend`'s `RendererDataBuilder` makes to the graphics API while building: compiling
+/// shaders, linking programs, and creating the objects that aren't created by user callbacks.
+///
+/// The builder only reaches the API through a `dyn RendererBackend` (`wrend`'s `WebGl2Backend` in
+/// the browser), and the order things are built in lives in [crate::build_programs],
+/// [crate::create_vertex_arrays] and [crate::create_transform_feedbacks], so a backend only has
+/// to make the calls and hand back its own handle types.
+///
+/// Buffers, attributes, textures, framebuffers and uniforms are still created by callbacks that
+/// receive a `WebGl2RenderingContext`, so the builder can't be handed another backend yet.
+pub trait RendererBackend {
+    /// Handle to a compiled shader
+    type Shader;
+    /// Handle to a linked program (a render pipeline in APIs that don't have programs)
+    type Program;
+    /// Handle to an object that records vertex attribute state
+    type VertexArray;
+    /// Handle to an object that captures vertex shader output
+    type TransformFeedback;
+
+    /// Compiles an already preprocessed shader source.
+    ///
+    /// Failures return the backend's info log as [RendererBackendError::KnownError] when it has one.
+    fn compile_shader(
+        &self,
+        shader_type: ShaderType,
+        source: &str,
+    ) -> Result<Self::Shader, RendererBackendError>;
+
+    /// Links a program from two compiled shaders, binding each named attribute to its location and
+    /// capturing `transform_feedback_varyings` (interleaved).
+    ///
+    /// `cache_key` identifies the program across builds, for backends that cache linked programs.
+    fn link_program(
+        &self,
+        vertex_shader: &Self::Shader,
+        fragment_shader: &Self::Shader,
+        attribute_locations: &[(String, u32)],
+        transform_feedback_varyings: &[String],
+        cache_key: u64,
+    ) -> Result<Self::Program, RendererBackendError>;
+
+    /// Creates a vertex array, which is how `RendererData` keeps the state of each VAO id
+    fn create_vertex_array(&self) -> Result<Self::VertexArray, RendererBackendError>;
+
+    /// Creates a transform feedback object for a `TransformFeedbackLink`
+    fn create_transform_feedback(&self) -> Result<Self::TransformFeedback, RendererBackendError>;
+}
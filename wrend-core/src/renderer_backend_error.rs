@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum RendererBackendError {
+    #[error("The backend did not return an object")]
+    NoneReturned,
+    #[error("{0}")]
+    KnownError(String),
+    #[error("An unknown error occurred")]
+    UnknownError,
+}
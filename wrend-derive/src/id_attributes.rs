@@ -0,0 +1,34 @@
+use crate::RenameRule;
+use syn::{Attribute, LitStr};
+
+/// Options given through `#[id(...)]`, which is shared by `WrendId` and `WrendIdName`
+#[derive(Default)]
+pub struct IdAttributes {
+    /// `#[id(default)]`: the variant returned by `Default::default`
+    pub default: bool,
+    /// `#[id(name = "...")]`: the exact name of a variant (or unit struct)
+    pub name: Option<LitStr>,
+    /// `#[id(rename_all = "...")]`: how variant names are turned into id names
+    pub rename_all: Option<RenameRule>,
+}
+
+impl IdAttributes {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut attributes = IdAttributes::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("id")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("default") {
+                    attributes.default = true;
+                } else if meta.path.is_ident("name") {
+                    attributes.name = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("rename_all") {
+                    attributes.rename_all = Some(RenameRule::parse(&meta.value()?.parse()?)?);
+                } else {
+                    return Err(meta.error("expected `default`, `name`, or `rename_all`"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(attributes)
+    }
+}
@@ -0,0 +1,70 @@
+#![warn(missing_docs)]
+
+//! Derive macros for [wrend](https://docs.rs/wrend/).
+//!
+//! These are re-exported from `wrend` when its `derive` feature is enabled, and the generated code
+//! refers to items through `::wrend`, so `wrend` must be a direct dependency of the crate using them.
+
+mod id_attributes;
+mod rename_rule;
+mod uniform_group;
+mod wrend_id;
+mod wrend_id_name;
+
+use id_attributes::IdAttributes;
+use proc_macro::TokenStream;
+use rename_rule::RenameRule;
+use syn::{parse_macro_input, DeriveInput};
+
+/// Implements `wrend::UniformGroup` for a struct with named fields.
+///
+/// Every field must implement `Into<wrend::UniformValue>` + `Clone`. Supported attributes:
+///
+/// - `#[uniform(prefix = "u_")]` on the struct: prepended to every field name
+/// - `#[uniform(name = "u_name")]` on a field: the full uniform name to use instead (not prefixed)
+/// - `#[uniform(skip)]` on a field: not uploaded
+#[proc_macro_derive(UniformGroup, attributes(uniform))]
+pub fn derive_uniform_group(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    uniform_group::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `wrend::Id` for an enum or struct, which must also implement
+/// `Clone`, `Debug`, `Hash`, `Eq`, and `Default`.
+///
+/// `Default` can be implemented at the same time by marking one unit variant `#[id(default)]`:
+///
+/// ```ignore
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, WrendId)]
+/// pub enum ProgramId {
+///     Update,
+///     #[id(default)]
+///     Render,
+/// }
+/// ```
+#[proc_macro_derive(WrendId, attributes(id))]
+pub fn derive_wrend_id(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    wrend_id::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `wrend::IdName` for an enum of unit variants or a unit struct.
+///
+/// Variants are named with the snake_case of the variant name (`UWhiteNoise` => `u_white_noise`) by default.
+/// Supported attributes:
+///
+/// - `#[id(rename_all = "...")]` on the enum: one of `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case`,
+///   `camelCase`, `PascalCase`, `lowercase`, or `UPPERCASE`
+/// - `#[id(name = "...")]` on a variant: the exact name to use instead
+/// - `#[id(name = "...")]` on a unit struct: its name (required)
+#[proc_macro_derive(WrendIdName, attributes(id))]
+pub fn derive_wrend_id_name(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    wrend_id_name::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
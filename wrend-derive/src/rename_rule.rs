@@ -0,0 +1,126 @@
+use syn::LitStr;
+
+/// How `#[derive(WrendIdName)]` turns a variant name into an id name, set with `#[id(rename_all = "...")]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RenameRule {
+    /// `UWhiteNoise` => `u_white_noise`
+    #[default]
+    Snake,
+    /// `UWhiteNoise` => `U_WHITE_NOISE`
+    ScreamingSnake,
+    /// `UWhiteNoise` => `u-white-noise`
+    Kebab,
+    /// `UWhiteNoise` => `uWhiteNoise`
+    Camel,
+    /// `UWhiteNoise` => `UWhiteNoise`
+    Pascal,
+    /// `UWhiteNoise` => `uwhitenoise`
+    Lower,
+    /// `UWhiteNoise` => `UWHITENOISE`
+    Upper,
+}
+
+impl RenameRule {
+    pub fn parse(lit: &LitStr) -> syn::Result<Self> {
+        Ok(match lit.value().as_str() {
+            "snake_case" => RenameRule::Snake,
+            "SCREAMING_SNAKE_CASE" => RenameRule::ScreamingSnake,
+            "kebab-case" => RenameRule::Kebab,
+            "camelCase" => RenameRule::Camel,
+            "PascalCase" => RenameRule::Pascal,
+            "lowercase" => RenameRule::Lower,
+            "UPPERCASE" => RenameRule::Upper,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "expected one of `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case`, \
+                     `camelCase`, `PascalCase`, `lowercase`, or `UPPERCASE`",
+                ))
+            }
+        })
+    }
+
+    pub fn apply(&self, name: &str) -> String {
+        let words = split_words(name);
+        match self {
+            RenameRule::Snake => words.join("_").to_lowercase(),
+            RenameRule::ScreamingSnake => words.join("_").to_uppercase(),
+            RenameRule::Kebab => words.join("-").to_lowercase(),
+            RenameRule::Camel => {
+                let pascal_case = RenameRule::Pascal.apply(name);
+                let mut chars = pascal_case.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_lowercase().chain(chars).collect())
+                    .unwrap_or_default()
+            }
+            RenameRule::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+            RenameRule::Lower => name.to_lowercase(),
+            RenameRule::Upper => name.to_uppercase(),
+        }
+    }
+}
+
+/// Splits a PascalCase name into words, keeping acronyms together (`VAOId` => `VAO`, `Id`)
+fn split_words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        let previous = i.checked_sub(1).map(|i| chars[i]);
+        let next = chars.get(i + 1);
+        let starts_word = c.is_uppercase()
+            && previous.is_some_and(|previous| {
+                previous.is_lowercase()
+                    || (previous.is_uppercase() && next.is_some_and(|next| next.is_lowercase()))
+            });
+        if starts_word && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|first| {
+            first
+                .to_uppercase()
+                .chain(chars.flat_map(char::to_lowercase))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renames_variants() {
+        assert_eq!(
+            RenameRule::Snake.apply("UWhiteNoiseTexture"),
+            "u_white_noise_texture"
+        );
+        assert_eq!(RenameRule::Snake.apply("VAOId"), "vao_id");
+        assert_eq!(RenameRule::Snake.apply("Texture2D"), "texture2d");
+        assert_eq!(RenameRule::ScreamingSnake.apply("UNow"), "U_NOW");
+        assert_eq!(RenameRule::Kebab.apply("APosition"), "a-position");
+        assert_eq!(RenameRule::Camel.apply("UNow"), "uNow");
+        assert_eq!(RenameRule::Pascal.apply("VAOId"), "VaoId");
+        assert_eq!(RenameRule::Lower.apply("UNow"), "unow");
+        assert_eq!(RenameRule::Upper.apply("UNow"), "UNOW");
+    }
+}
@@ -0,0 +1,125 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Fields, LitStr};
+
+/// Options given through `#[uniform(...)]`
+#[derive(Debug, Default, PartialEq, Eq)]
+struct UniformAttributes {
+    prefix: Option<String>,
+    name: Option<String>,
+    skip: bool,
+}
+
+fn parse_attributes(attrs: &[Attribute]) -> syn::Result<UniformAttributes> {
+    let mut attributes = UniformAttributes::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("uniform")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("prefix") {
+                attributes.prefix = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("name") {
+                attributes.name = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("skip") {
+                attributes.skip = true;
+            } else {
+                return Err(meta.error("expected `prefix`, `name`, or `skip`"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(attributes)
+}
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "UniformGroup can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &data.fields,
+            "UniformGroup can only be derived for structs with named fields",
+        ));
+    };
+
+    let struct_attributes = parse_attributes(&input.attrs)?;
+    if struct_attributes.name.is_some() || struct_attributes.skip {
+        return Err(syn::Error::new_spanned(
+            input,
+            "only `prefix` can be used on the struct itself",
+        ));
+    }
+    let prefix = struct_attributes.prefix.unwrap_or_default();
+
+    let mut uniforms = Vec::new();
+    for field in &fields.named {
+        let attributes = parse_attributes(&field.attrs)?;
+        if attributes.prefix.is_some() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`prefix` can only be used on the struct itself",
+            ));
+        }
+        if attributes.skip {
+            continue;
+        }
+        let ident = field.ident.as_ref().expect("named fields have idents");
+        let name = attributes
+            .name
+            .unwrap_or_else(|| format!("{prefix}{}", unraw(&ident.to_string())));
+        uniforms.push(quote! {
+            (#name, ::wrend::UniformValue::from(::core::clone::Clone::clone(&self.#ident)))
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::wrend::UniformGroup for #ident #ty_generics #where_clause {
+            fn uniforms(&self) -> ::std::vec::Vec<(&'static str, ::wrend::UniformValue)> {
+                ::std::vec![#(#uniforms),*]
+            }
+        }
+    })
+}
+
+/// Raw identifiers (e.g. `r#type`) are uploaded without the `r#`
+fn unraw(ident: &str) -> &str {
+    ident.strip_prefix("r#").unwrap_or(ident)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn names_uniforms_from_fields_and_attributes() {
+        let input: DeriveInput = parse_quote! {
+            #[uniform(prefix = "u_")]
+            struct Params {
+                speed: f32,
+                #[uniform(name = "iterations")]
+                max_iterations: i32,
+                #[uniform(skip)]
+                label: String,
+                r#type: u32,
+            }
+        };
+        let expanded = expand(&input).unwrap().to_string();
+
+        assert!(expanded.contains("\"u_speed\""));
+        assert!(expanded.contains("\"iterations\""));
+        assert!(expanded.contains("\"u_type\""));
+        assert!(!expanded.contains("label"));
+    }
+
+    #[test]
+    fn rejects_enums() {
+        let input: DeriveInput = parse_quote! {
+            enum Params { A, B }
+        };
+        assert!(expand(&input).is_err());
+    }
+}
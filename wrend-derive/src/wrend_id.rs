@@ -0,0 +1,80 @@
+use crate::IdAttributes;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields};
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut default_variants = Vec::new();
+    if let Data::Enum(data) = &input.data {
+        for variant in &data.variants {
+            if IdAttributes::parse(&variant.attrs)?.default {
+                default_variants.push(variant);
+            }
+        }
+    }
+
+    let default_impl = match default_variants.as_slice() {
+        [] => None,
+        [variant] => {
+            if !matches!(variant.fields, Fields::Unit) {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "`#[id(default)]` can only be used on unit variants",
+                ));
+            }
+            let variant_ident = &variant.ident;
+            Some(quote! {
+                impl #impl_generics ::core::default::Default for #ident #ty_generics #where_clause {
+                    fn default() -> Self {
+                        Self::#variant_ident
+                    }
+                }
+            })
+        }
+        [_, extra, ..] => {
+            return Err(syn::Error::new_spanned(
+                extra,
+                "only one variant can be marked `#[id(default)]`",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::wrend::Id for #ident #ty_generics #where_clause {}
+
+        #default_impl
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn implements_default_for_marked_variant() {
+        let input: DeriveInput = parse_quote! {
+            enum ProgramId {
+                Update,
+                #[id(default)]
+                Render,
+            }
+        };
+        let expanded = expand(&input).unwrap().to_string();
+        assert!(expanded.contains("Default for ProgramId"));
+        assert!(expanded.contains("Self :: Render"));
+
+        let input: DeriveInput = parse_quote! {
+            enum ProgramId {
+                #[id(default)]
+                Update,
+                #[id(default)]
+                Render,
+            }
+        };
+        assert!(expand(&input).is_err());
+    }
+}
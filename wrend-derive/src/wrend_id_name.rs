@@ -0,0 +1,91 @@
+use crate::IdAttributes;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields};
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let attributes = IdAttributes::parse(&input.attrs)?;
+
+    let body = match &input.data {
+        Data::Enum(data) => {
+            if let Some(name) = &attributes.name {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "`name` can only be used on variants of an enum; use `rename_all` instead",
+                ));
+            }
+            let rename_rule = attributes.rename_all.unwrap_or_default();
+            let mut arms = Vec::new();
+            for variant in &data.variants {
+                if !matches!(variant.fields, Fields::Unit) {
+                    return Err(syn::Error::new_spanned(
+                        variant,
+                        "WrendIdName can only be derived for enums with unit variants",
+                    ));
+                }
+                let variant_ident = &variant.ident;
+                let name = match IdAttributes::parse(&variant.attrs)?.name {
+                    Some(name) => name.value(),
+                    None => rename_rule.apply(&variant_ident.to_string()),
+                };
+                arms.push(quote! { Self::#variant_ident => #name });
+            }
+            quote! {
+                ::std::string::String::from(match self {
+                    #(#arms,)*
+                })
+            }
+        }
+        Data::Struct(data) if matches!(data.fields, Fields::Unit) => {
+            let Some(name) = &attributes.name else {
+                return Err(syn::Error::new_spanned(
+                    input,
+                    "unit structs need an explicit name: `#[id(name = \"...\")]`",
+                ));
+            };
+            quote! { ::std::string::String::from(#name) }
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "WrendIdName can only be derived for enums and unit structs",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::wrend::IdName for #ident #ty_generics #where_clause {
+            fn name(&self) -> ::std::string::String {
+                #body
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn names_variants_with_rename_rule_and_overrides() {
+        let input: DeriveInput = parse_quote! {
+            #[id(rename_all = "SCREAMING_SNAKE_CASE")]
+            enum UniformId {
+                UNow,
+                #[id(name = "iResolution")]
+                Resolution,
+            }
+        };
+        let expanded = expand(&input).unwrap().to_string();
+        assert!(expanded.contains("\"U_NOW\""));
+        assert!(expanded.contains("\"iResolution\""));
+
+        let input: DeriveInput = parse_quote! {
+            struct AttributeId;
+        };
+        assert!(expand(&input).is_err());
+    }
+}
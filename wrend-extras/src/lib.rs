@@ -0,0 +1,17 @@
+#![warn(missing_docs)]
+
+//! Reusable app-level helpers for [wrend](https://docs.rs/wrend/), promoted out of the demo apps.
+//!
+//! These are framework agnostic: the demos use them from Yew components, but they only depend on `wrend` itself.
+
+mod save_image;
+mod state_handle;
+
+#[cfg(feature = "recording")]
+mod recording_controls;
+
+pub use save_image::*;
+pub use state_handle::*;
+
+#[cfg(feature = "recording")]
+pub use recording_controls::*;
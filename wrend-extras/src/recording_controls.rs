@@ -0,0 +1,266 @@
+use log::warn;
+use std::{
+    cell::{Cell, RefCell},
+    fmt::Debug,
+    rc::Rc,
+};
+use wrend::{Id, IdName, Renderer};
+
+type ChangeCallback = Rc<dyn Fn(bool)>;
+
+/// A renderer that can record its canvas to video
+pub trait Recordable {
+    /// See [Renderer::start_recording]
+    fn start_recording(&mut self);
+
+    /// See [Renderer::stop_recording]
+    fn stop_recording(&self);
+
+    /// See [Renderer::clear_recorded_data]
+    fn clear_recorded_data(&self);
+
+    /// See [Renderer::is_recording]
+    fn is_recording(&self) -> bool;
+}
+
+/// Start / stop / clear controls for recording a renderer that may not exist yet
+/// (e.g. while a component is still mounting), or that gets replaced when rebuilt.
+///
+/// Clones share the same renderer slot and recording state, so they can be handed out to
+/// separate UI callbacks.
+pub struct RecordingControls<R: Recordable> {
+    renderer: Rc<RefCell<Option<R>>>,
+    is_recording: Rc<Cell<bool>>,
+    on_change: Rc<RefCell<Vec<ChangeCallback>>>,
+}
+
+impl<R: Recordable> RecordingControls<R> {
+    /// Creates controls with no renderer attached yet
+    pub fn new() -> Self {
+        Self::from_renderer_ref(Rc::new(RefCell::new(None)))
+    }
+
+    /// Creates controls for a renderer slot that is shared with the rest of the app
+    pub fn from_renderer_ref(renderer: Rc<RefCell<Option<R>>>) -> Self {
+        let is_recording = renderer
+            .borrow()
+            .as_ref()
+            .is_some_and(Recordable::is_recording);
+        Self {
+            renderer,
+            is_recording: Rc::new(Cell::new(is_recording)),
+            on_change: Default::default(),
+        }
+    }
+
+    /// Attaches (or replaces) the renderer being controlled, returning the previous one.
+    ///
+    /// A recording in progress on the previous renderer is stopped.
+    pub fn set_renderer(&self, renderer: Option<R>) -> Option<R> {
+        let previous = self.renderer.replace(renderer);
+        if let Some(previous) = &previous {
+            if previous.is_recording() {
+                previous.stop_recording();
+            }
+        }
+        self.sync();
+        previous
+    }
+
+    /// The shared renderer slot
+    pub fn renderer_ref(&self) -> &Rc<RefCell<Option<R>>> {
+        &self.renderer
+    }
+
+    /// Whether recording is in progress, as of the last change made through these controls
+    pub fn is_recording(&self) -> bool {
+        self.is_recording.get()
+    }
+
+    /// Called with the new recording state whenever it is changed through these controls
+    pub fn add_change_callback(&self, callback: impl Fn(bool) + 'static) {
+        self.on_change.borrow_mut().push(Rc::new(callback));
+    }
+
+    /// Starts recording. Does nothing if already recording.
+    pub fn start(&self) {
+        if let Some(renderer) = &mut *self.renderer.borrow_mut() {
+            if !renderer.is_recording() {
+                renderer.start_recording();
+            }
+        } else {
+            warn!("Can't start recording: no renderer has been attached to the RecordingControls");
+        }
+        self.sync_to(true);
+    }
+
+    /// Stops recording. Does nothing if not recording.
+    pub fn stop(&self) {
+        if let Some(renderer) = &*self.renderer.borrow() {
+            if renderer.is_recording() {
+                renderer.stop_recording();
+            }
+        }
+        self.sync_to(false);
+    }
+
+    /// Starts recording if stopped and stops it if recording
+    pub fn toggle(&self) {
+        if self.is_recording() {
+            self.stop();
+        } else {
+            self.start();
+        }
+    }
+
+    /// Discards everything recorded so far
+    pub fn clear(&self) {
+        if let Some(renderer) = &*self.renderer.borrow() {
+            renderer.clear_recorded_data();
+        }
+    }
+
+    /// Refreshes the recording state from the renderer
+    pub fn sync(&self) {
+        let is_recording = self
+            .renderer
+            .borrow()
+            .as_ref()
+            .is_some_and(Recordable::is_recording);
+        self.sync_to(is_recording);
+    }
+
+    /// `MediaRecorder` changes state asynchronously, so the state is set to what was asked for
+    /// only when there's a renderer to ask
+    fn sync_to(&self, is_recording: bool) {
+        let is_recording = is_recording && self.renderer.borrow().is_some();
+        if self.is_recording.replace(is_recording) != is_recording {
+            let callbacks = self.on_change.borrow().clone();
+            for callback in callbacks {
+                callback(is_recording);
+            }
+        }
+    }
+}
+
+impl<R: Recordable> Default for RecordingControls<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: Recordable> Clone for RecordingControls<R> {
+    fn clone(&self) -> Self {
+        Self {
+            renderer: Rc::clone(&self.renderer),
+            is_recording: Rc::clone(&self.is_recording),
+            on_change: Rc::clone(&self.on_change),
+        }
+    }
+}
+
+impl<R: Recordable> Debug for RecordingControls<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecordingControls")
+            .field("has_renderer", &self.renderer.borrow().is_some())
+            .field("is_recording", &self.is_recording.get())
+            .field("on_change", &self.on_change.borrow().len())
+            .finish()
+    }
+}
+
+impl<
+        VertexShaderId: 'static + Id,
+        FragmentShaderId: 'static + Id,
+        ProgramId: 'static + Id,
+        UniformId: 'static + Id + IdName,
+        BufferId: 'static + Id,
+        AttributeId: 'static + Id + IdName,
+        TextureId: 'static + Id,
+        FramebufferId: 'static + Id,
+        TransformFeedbackId: 'static + Id,
+        VertexArrayObjectId: 'static + Id,
+        UserCtx: Clone + 'static,
+    > Recordable
+    for Renderer<
+        VertexShaderId,
+        FragmentShaderId,
+        ProgramId,
+        UniformId,
+        BufferId,
+        AttributeId,
+        TextureId,
+        FramebufferId,
+        TransformFeedbackId,
+        VertexArrayObjectId,
+        UserCtx,
+    >
+{
+    fn start_recording(&mut self) {
+        Renderer::start_recording(self)
+    }
+
+    fn stop_recording(&self) {
+        Renderer::stop_recording(self)
+    }
+
+    fn clear_recorded_data(&self) {
+        Renderer::clear_recorded_data(self)
+    }
+
+    fn is_recording(&self) -> bool {
+        Renderer::is_recording(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Recordable, RecordingControls};
+    use std::{cell::Cell, rc::Rc};
+
+    #[derive(Default)]
+    struct FakeRenderer {
+        recording: Cell<bool>,
+    }
+
+    impl Recordable for FakeRenderer {
+        fn start_recording(&mut self) {
+            self.recording.set(true);
+        }
+
+        fn stop_recording(&self) {
+            self.recording.set(false);
+        }
+
+        fn clear_recorded_data(&self) {}
+
+        fn is_recording(&self) -> bool {
+            self.recording.get()
+        }
+    }
+
+    #[test]
+    fn toggles_and_notifies() {
+        let controls = RecordingControls::<FakeRenderer>::new();
+        let changes = Rc::new(Cell::new(0));
+        {
+            let changes = Rc::clone(&changes);
+            controls.add_change_callback(move |_| changes.set(changes.get() + 1));
+        }
+
+        controls.start();
+        assert!(!controls.is_recording());
+
+        controls.set_renderer(Some(FakeRenderer::default()));
+        controls.clone().toggle();
+        assert!(controls.is_recording());
+        controls.toggle();
+        assert!(!controls.is_recording());
+        assert_eq!(changes.get(), 2);
+
+        controls.start();
+        let previous = controls.set_renderer(None).unwrap();
+        assert!(!previous.is_recording());
+        assert!(!controls.is_recording());
+    }
+}
@@ -0,0 +1,141 @@
+use std::{cell::Cell, rc::Rc};
+use wrend::{Id, IdName, Renderer, RendererData};
+
+/// Anything that can save its canvas as an image download
+pub trait SaveImageTarget {
+    /// Downloads the current contents of the canvas
+    fn save_image(&self);
+}
+
+/// Lets UI code ask for a screenshot, which is then saved right after the next render.
+///
+/// Unless the context was created with `preserveDrawingBuffer`, the canvas is cleared once the frame
+/// has been composited, so reading it back from an event handler produces a blank image. Call
+/// [SaveImage::save_if_requested] immediately after rendering instead.
+#[derive(Clone, Debug, Default)]
+pub struct SaveImage {
+    requested: Rc<Cell<bool>>,
+}
+
+impl SaveImage {
+    /// Creates a new `SaveImage` with no pending request
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Saves an image after the next render. Shared by all clones.
+    pub fn request(&self) {
+        self.requested.set(true);
+    }
+
+    /// Whether an image has been requested but not yet saved
+    pub fn is_requested(&self) -> bool {
+        self.requested.get()
+    }
+
+    /// Drops a pending request without saving
+    pub fn cancel(&self) {
+        self.requested.set(false);
+    }
+
+    /// Saves an image if one has been requested, returning whether it did
+    pub fn save_if_requested(&self, target: &impl SaveImageTarget) -> bool {
+        let requested = self.requested.replace(false);
+        if requested {
+            target.save_image();
+        }
+        requested
+    }
+}
+
+impl<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        ProgramId: Id,
+        UniformId: Id + IdName,
+        BufferId: Id,
+        AttributeId: Id + IdName,
+        TextureId: Id,
+        FramebufferId: Id,
+        TransformFeedbackId: Id,
+        VertexArrayObjectId: Id,
+        UserCtx: Clone,
+    > SaveImageTarget
+    for RendererData<
+        VertexShaderId,
+        FragmentShaderId,
+        ProgramId,
+        UniformId,
+        BufferId,
+        AttributeId,
+        TextureId,
+        FramebufferId,
+        TransformFeedbackId,
+        VertexArrayObjectId,
+        UserCtx,
+    >
+{
+    fn save_image(&self) {
+        RendererData::save_image(self)
+    }
+}
+
+impl<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        ProgramId: Id,
+        UniformId: Id + IdName,
+        BufferId: Id,
+        AttributeId: Id + IdName,
+        TextureId: Id,
+        FramebufferId: Id,
+        TransformFeedbackId: Id,
+        VertexArrayObjectId: Id,
+        UserCtx: Clone,
+    > SaveImageTarget
+    for Renderer<
+        VertexShaderId,
+        FragmentShaderId,
+        ProgramId,
+        UniformId,
+        BufferId,
+        AttributeId,
+        TextureId,
+        FramebufferId,
+        TransformFeedbackId,
+        VertexArrayObjectId,
+        UserCtx,
+    >
+{
+    fn save_image(&self) {
+        Renderer::save_image(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SaveImage, SaveImageTarget};
+    use std::cell::Cell;
+
+    #[derive(Default)]
+    struct Counter(Cell<u32>);
+
+    impl SaveImageTarget for Counter {
+        fn save_image(&self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn saves_once_per_request() {
+        let save_image = SaveImage::new();
+        let counter = Counter::default();
+
+        assert!(!save_image.save_if_requested(&counter));
+        save_image.clone().request();
+        assert!(save_image.is_requested());
+        assert!(save_image.save_if_requested(&counter));
+        assert!(!save_image.save_if_requested(&counter));
+        assert_eq!(counter.0.get(), 1);
+    }
+}
@@ -0,0 +1,97 @@
+use std::{
+    cell::RefCell,
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+    rc::Rc,
+};
+
+/// Shared, mutable handle to app state, e.g. state that UI code writes to and an animation callback reads from.
+///
+/// Cloning the handle is cheap and clones share the same state.
+pub struct StateHandle<T>(Rc<RefCell<T>>);
+
+impl<T> StateHandle<T> {
+    /// Wraps `state` in a new handle
+    pub fn new(state: T) -> Self {
+        StateHandle(Rc::new(RefCell::new(state)))
+    }
+
+    /// Whether both handles point to the same state
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// Consumes the handle, returning the shared state
+    pub fn into_inner(self) -> Rc<RefCell<T>> {
+        self.0
+    }
+}
+
+impl<T> Clone for StateHandle<T> {
+    fn clone(&self) -> Self {
+        StateHandle(Rc::clone(&self.0))
+    }
+}
+
+impl<T: Debug> Debug for StateHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("StateHandle").field(&self.0).finish()
+    }
+}
+
+impl<T: Default> Default for StateHandle<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+/// Handles are equal when they share the same state
+impl<T> PartialEq for StateHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other)
+    }
+}
+
+impl<T> Eq for StateHandle<T> {}
+
+impl<T> From<Rc<RefCell<T>>> for StateHandle<T> {
+    fn from(state: Rc<RefCell<T>>) -> Self {
+        StateHandle(state)
+    }
+}
+
+impl<T> From<T> for StateHandle<T> {
+    fn from(state: T) -> Self {
+        Self::new(state)
+    }
+}
+
+impl<T> Deref for StateHandle<T> {
+    type Target = Rc<RefCell<T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for StateHandle<T> {
+    fn deref_mut(&mut self) -> &mut Rc<RefCell<T>> {
+        &mut self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StateHandle;
+
+    #[test]
+    fn clones_share_state() {
+        let handle = StateHandle::new(1);
+        let clone = handle.clone();
+        *clone.borrow_mut() += 1;
+
+        assert_eq!(*handle.borrow(), 2);
+        assert_eq!(handle, clone);
+        assert_ne!(handle, StateHandle::new(2));
+    }
+}
@@ -1,8 +1,38 @@
 mod animation_callback;
 mod animation_callback_js;
 mod animation_data;
+mod animation_loop;
+mod deterministic_mode;
+mod loop_callback;
+mod loop_time;
+mod power_saving;
+mod power_saving_callback;
+mod power_saving_listeners;
+mod power_saving_options;
+mod power_saving_policy;
+mod power_saving_reason;
+mod power_saving_state;
+mod reduced_motion;
+mod reduced_motion_listener;
+mod reduced_motion_options;
+mod time_scale;
 
 pub(crate) use animation_data::*;
+pub(crate) use power_saving::*;
+pub(crate) use power_saving_listeners::*;
+pub(crate) use reduced_motion::*;
+pub(crate) use time_scale::*;
 
 pub use animation_callback::*;
 pub use animation_callback_js::*;
+pub use animation_loop::*;
+pub use deterministic_mode::*;
+pub use loop_callback::*;
+pub use loop_time::*;
+pub use power_saving_callback::*;
+pub use power_saving_options::*;
+pub use power_saving_policy::*;
+pub use power_saving_reason::*;
+pub use power_saving_state::*;
+pub use reduced_motion_listener::*;
+pub use reduced_motion_options::*;
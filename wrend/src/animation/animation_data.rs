@@ -1,11 +1,15 @@
-use std::{any::Any, cell::RefCell, rc::Rc};
+use std::{cell::RefCell, rc::Rc};
 
+#[cfg(feature = "js")]
 use wasm_bindgen::JsValue;
 
 use crate::{
-    AnimationCallback, Callback, Id, IdDefault, IdName, RendererData, RendererDataJs,
-    RendererDataJsInner,
+    AnimationCallback, Id, IdDefault, IdName, LoopCallback, LoopTime, PowerSaving,
+    PowerSavingListeners, ReducedMotion, ReducedMotionListener, RendererData,
 };
+#[cfg(feature = "js")]
+use crate::{Callback, RendererDataJs};
+#[cfg(feature = "js")]
 use log::error;
 
 #[derive(Clone, Debug)]
@@ -39,6 +43,27 @@ pub(crate) struct AnimationData<
         >,
     >,
     is_animating: bool,
+    loop_callback: Option<LoopCallback>,
+    last_loop_count: Option<u32>,
+    power_saving: Rc<RefCell<PowerSaving>>,
+    power_saving_listeners: Option<Rc<PowerSavingListeners>>,
+    reduced_motion_animation_callback: Option<
+        AnimationCallback<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+    >,
+    reduced_motion: Rc<RefCell<ReducedMotion>>,
+    reduced_motion_listener: Option<Rc<ReducedMotionListener>>,
 }
 
 impl<
@@ -80,7 +105,8 @@ impl<
         self.request_id
     }
 
-    /// Calls the internal animation callback.
+    /// Calls the internal animation callback, or the reduced motion callback instead if reduced
+    /// motion is active and one has been supplied.
     ///
     /// If no animation has been supplied yet, this is a no-op.
     pub fn call_animation_callback(
@@ -103,16 +129,23 @@ impl<
             >,
         >,
     ) {
-        if let Some(animation_callback) = &self.animation_callback {
+        let animation_callback = if self.reduced_motion.borrow().is_active() {
+            self.reduced_motion_animation_callback
+                .as_ref()
+                .or(self.animation_callback.as_ref())
+        } else {
+            self.animation_callback.as_ref()
+        };
+
+        if let Some(animation_callback) = animation_callback {
             // if the types are compatible with JavaScript, treat as a special case and pass in the `RendererData` to the JavaScript function
-            let rendered = if let Some(renderer_data) =
-                (&renderer_data as &dyn Any).downcast_ref::<Rc<RefCell<RendererDataJsInner>>>()
+            #[cfg(feature = "js")]
+            let rendered = if let Some(renderer_data_js) =
+                RendererDataJs::from_renderer_data(&renderer_data)
             {
-                let renderer_data = Rc::clone(renderer_data);
                 match &**animation_callback {
                     Callback::Rust(_) => false,
                     Callback::Js(js_callback) => {
-                        let renderer_data_js: RendererDataJs = renderer_data.into();
                         let js_value: JsValue = renderer_data_js.into();
                         let result = js_callback.call1(&JsValue::NULL, &js_value);
                         if let Err(err) = result {
@@ -124,6 +157,8 @@ impl<
             } else {
                 false
             };
+            #[cfg(not(feature = "js"))]
+            let rendered = false;
 
             // if not already rendered in JavaScript, call with Rust values
             // this does not pass the `RendererData` to the JavaScript callback if one was supplied,
@@ -155,6 +190,27 @@ impl<
         self.animation_callback = animation_callback;
     }
 
+    pub fn set_reduced_motion_animation_callback(
+        &mut self,
+        reduced_motion_animation_callback: Option<
+            AnimationCallback<
+                VertexShaderId,
+                FragmentShaderId,
+                ProgramId,
+                UniformId,
+                BufferId,
+                AttributeId,
+                TextureId,
+                FramebufferId,
+                TransformFeedbackId,
+                VertexArrayObjectId,
+                UserCtx,
+            >,
+        >,
+    ) {
+        self.reduced_motion_animation_callback = reduced_motion_animation_callback;
+    }
+
     pub fn set_is_animating(&mut self, is_animating: bool) -> &mut Self {
         self.is_animating = is_animating;
         self
@@ -163,6 +219,58 @@ impl<
     pub fn is_animating(&self) -> bool {
         self.is_animating
     }
+
+    pub fn set_loop_callback(&mut self, loop_callback: Option<LoopCallback>) -> &mut Self {
+        self.loop_callback = loop_callback;
+        self
+    }
+
+    /// Shared with the [PowerSavingListeners], which update it as visibility changes
+    pub fn power_saving(&self) -> Rc<RefCell<PowerSaving>> {
+        Rc::clone(&self.power_saving)
+    }
+
+    /// Replacing the listeners disconnects the previous ones
+    pub fn set_power_saving_listeners(
+        &mut self,
+        power_saving_listeners: Option<PowerSavingListeners>,
+    ) -> &mut Self {
+        self.power_saving_listeners = power_saving_listeners.map(Rc::new);
+        self
+    }
+
+    /// Shared with the [ReducedMotionListener], which updates it as the user's preference changes
+    pub fn reduced_motion(&self) -> Rc<RefCell<ReducedMotion>> {
+        Rc::clone(&self.reduced_motion)
+    }
+
+    /// Replacing the listener disconnects the previous one
+    pub fn set_reduced_motion_listener(
+        &mut self,
+        reduced_motion_listener: Option<ReducedMotionListener>,
+    ) -> &mut Self {
+        self.reduced_motion_listener = reduced_motion_listener.map(Rc::new);
+        self
+    }
+
+    /// Returns the loop callback if the loop has wrapped around since the last frame
+    pub fn loop_callback_if_wrapped(
+        &mut self,
+        loop_time: Option<LoopTime>,
+    ) -> Option<LoopCallback> {
+        let loop_count = loop_time.map(|loop_time| loop_time.loop_count());
+        let wrapped = matches!(
+            (self.last_loop_count, loop_count),
+            (Some(last), Some(current)) if current > last
+        );
+        self.last_loop_count = loop_count;
+
+        if wrapped {
+            self.loop_callback.clone()
+        } else {
+            None
+        }
+    }
 }
 
 impl<
@@ -200,6 +308,13 @@ impl<
             // used to cancel a requested animation frame.
             request_id: 0,
             is_animating: false,
+            loop_callback: None,
+            last_loop_count: None,
+            power_saving: Rc::default(),
+            power_saving_listeners: None,
+            reduced_motion_animation_callback: None,
+            reduced_motion: Rc::default(),
+            reduced_motion_listener: None,
         }
     }
 }
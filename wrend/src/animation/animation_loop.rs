@@ -0,0 +1,54 @@
+use crate::LoopTime;
+
+/// A fixed-length animation that repeats forever, starting at `start_ms`.
+///
+/// Set one with [crate::Renderer::set_loop] and read the normalized time from
+/// [crate::RendererData::loop_time] or [crate::UniformContext::loop_time], so that
+/// animations driven by `t` line up perfectly when the loop wraps around.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct AnimationLoop {
+    duration_ms: f64,
+    start_ms: f64,
+}
+
+impl AnimationLoop {
+    pub fn new(duration_ms: f64, start_ms: f64) -> Self {
+        Self {
+            duration_ms: duration_ms.max(f64::EPSILON),
+            start_ms,
+        }
+    }
+
+    pub fn duration_ms(&self) -> f64 {
+        self.duration_ms
+    }
+
+    pub fn start_ms(&self) -> f64 {
+        self.start_ms
+    }
+
+    /// Where the loop is at time `now_ms`. Times before the start are treated as the start.
+    pub fn time_at(&self, now_ms: f64) -> LoopTime {
+        let loops = ((now_ms - self.start_ms) / self.duration_ms).max(0.0);
+        let loop_count = loops.floor();
+        // guards against `t` rounding up to exactly 1.0
+        let t = (loops - loop_count).min(1.0 - f64::EPSILON);
+
+        LoopTime::new(t, loop_count as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AnimationLoop;
+    use crate::LoopTime;
+
+    #[test]
+    fn normalizes_time_within_loop() {
+        let animation_loop = AnimationLoop::new(1000.0, 500.0);
+        assert_eq!(animation_loop.time_at(0.0), LoopTime::new(0.0, 0));
+        assert_eq!(animation_loop.time_at(750.0), LoopTime::new(0.25, 0));
+        assert_eq!(animation_loop.time_at(1500.0), LoopTime::new(0.0, 1));
+        assert_eq!(animation_loop.time_at(3000.0), LoopTime::new(0.5, 2));
+    }
+}
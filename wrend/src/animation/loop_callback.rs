@@ -0,0 +1,42 @@
+use crate::{Callback, LoopTime};
+use js_sys::Function;
+use std::fmt::Debug;
+use std::{ops::Deref, rc::Rc};
+
+/// Called by a [crate::Renderer] each time its [crate::AnimationLoop] wraps around.
+///
+/// JavaScript callbacks receive an object of the form `{ t, loopCount }`.
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd)]
+pub struct LoopCallback(Callback<dyn Fn(LoopTime), Function>);
+
+impl Deref for LoopCallback {
+    type Target = Callback<dyn Fn(LoopTime), Function>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for LoopCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("LoopCallback").field(&self.0).finish()
+    }
+}
+
+impl<F: Fn(LoopTime) + 'static> From<F> for LoopCallback {
+    fn from(callback: F) -> Self {
+        Self(Callback::new_rs(Rc::new(callback) as Rc<dyn Fn(LoopTime)>))
+    }
+}
+
+impl<F: Fn(LoopTime) + 'static> From<Rc<F>> for LoopCallback {
+    fn from(callback: Rc<F>) -> Self {
+        Self(Callback::new_rs(callback as Rc<dyn Fn(LoopTime)>))
+    }
+}
+
+impl From<Function> for LoopCallback {
+    fn from(callback: Function) -> Self {
+        Self(Callback::new_js(callback))
+    }
+}
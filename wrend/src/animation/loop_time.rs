@@ -0,0 +1,34 @@
+use wasm_bindgen::JsValue;
+
+/// Where an [crate::AnimationLoop] is at a given instant
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct LoopTime {
+    t: f64,
+    loop_count: u32,
+}
+
+impl LoopTime {
+    pub fn new(t: f64, loop_count: u32) -> Self {
+        Self { t, loop_count }
+    }
+
+    /// Normalized time within the current loop, in `[0, 1)`
+    pub fn t(&self) -> f64 {
+        self.t
+    }
+
+    /// Number of loops completed since the loop started
+    pub fn loop_count(&self) -> u32 {
+        self.loop_count
+    }
+}
+
+impl From<LoopTime> for JsValue {
+    fn from(loop_time: LoopTime) -> Self {
+        js_sys::JSON::parse(&format!(
+            r#"{{"t":{},"loopCount":{}}}"#,
+            loop_time.t, loop_time.loop_count
+        ))
+        .unwrap_or(JsValue::NULL)
+    }
+}
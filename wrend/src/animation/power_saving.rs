@@ -0,0 +1,139 @@
+use crate::{PowerSavingCallback, PowerSavingOptions, PowerSavingState};
+use std::cell::RefCell;
+
+/// Tolerance for `requestAnimationFrame` jitter when throttling, so that e.g. throttling to 30fps
+/// on a 60Hz display renders every other frame rather than every third
+const FRAME_SLACK_MS: f64 = 1.0;
+
+/// Tracks whether the animation can be seen and decides which frames should be rendered
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PowerSaving {
+    options: Option<PowerSavingOptions>,
+    hidden: bool,
+    offscreen: bool,
+    state: PowerSavingState,
+    last_frame_ms: Option<f64>,
+    callback: Option<PowerSavingCallback>,
+}
+
+impl PowerSaving {
+    pub(crate) fn options(&self) -> Option<PowerSavingOptions> {
+        self.options
+    }
+
+    /// Returns the new state if it changed
+    pub(crate) fn set_options(
+        &mut self,
+        options: Option<PowerSavingOptions>,
+    ) -> Option<PowerSavingState> {
+        self.options = options;
+        self.update()
+    }
+
+    pub(crate) fn state(&self) -> PowerSavingState {
+        self.state
+    }
+
+    pub(crate) fn callback(&self) -> Option<PowerSavingCallback> {
+        self.callback.clone()
+    }
+
+    pub(crate) fn set_callback(&mut self, callback: Option<PowerSavingCallback>) {
+        self.callback = callback;
+    }
+
+    /// Returns the new state if it changed
+    pub(crate) fn set_hidden(&mut self, hidden: bool) -> Option<PowerSavingState> {
+        self.hidden = hidden;
+        self.update()
+    }
+
+    /// Returns the new state if it changed
+    pub(crate) fn set_offscreen(&mut self, offscreen: bool) -> Option<PowerSavingState> {
+        self.offscreen = offscreen;
+        self.update()
+    }
+
+    /// Whether the animation callback should run for a frame at `now_ms`
+    pub(crate) fn should_render(&mut self, now_ms: f64) -> bool {
+        let should_render = match self.state {
+            PowerSavingState::Running => true,
+            PowerSavingState::Paused(_) => false,
+            PowerSavingState::Throttled { fps, .. } => {
+                self.last_frame_ms.is_none_or(|last_frame_ms| {
+                    now_ms - last_frame_ms >= 1000.0 / fps - FRAME_SLACK_MS
+                })
+            }
+        };
+        if should_render {
+            self.last_frame_ms = Some(now_ms);
+        }
+        should_render
+    }
+
+    fn update(&mut self) -> Option<PowerSavingState> {
+        let state = self.options.map_or(PowerSavingState::Running, |options| {
+            options.state(self.hidden, self.offscreen)
+        });
+        if state == self.state {
+            return None;
+        }
+        self.state = state;
+        Some(state)
+    }
+}
+
+/// Applies a change, then calls the power saving callback if the state changed
+pub(crate) fn update_power_saving(
+    power_saving: &RefCell<PowerSaving>,
+    change: impl FnOnce(&mut PowerSaving) -> Option<PowerSavingState>,
+) {
+    let changed = change(&mut power_saving.borrow_mut());
+    let callback = power_saving.borrow().callback();
+    if let (Some(state), Some(callback)) = (changed, callback) {
+        callback.call_with_js_arg(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PowerSaving;
+    use crate::{PowerSavingOptions, PowerSavingPolicy, PowerSavingReason, PowerSavingState};
+
+    #[test]
+    fn pauses_and_throttles_while_not_visible() {
+        let mut power_saving = PowerSaving::default();
+        assert_eq!(power_saving.set_offscreen(true), None);
+
+        let mut options = PowerSavingOptions::new();
+        options.set_offscreen(PowerSavingPolicy::Throttle(30.0));
+        assert_eq!(
+            power_saving.set_options(Some(options)),
+            Some(PowerSavingState::Throttled {
+                reason: PowerSavingReason::Offscreen,
+                fps: 30.0
+            })
+        );
+        let rendered: Vec<_> = (0..6)
+            .map(|frame| power_saving.should_render(frame as f64 * 1000.0 / 60.0))
+            .collect();
+        assert_eq!(rendered, [true, false, true, false, true, false]);
+
+        // hidden takes precedence over offscreen
+        assert_eq!(
+            power_saving.set_hidden(true),
+            Some(PowerSavingState::Paused(PowerSavingReason::Hidden))
+        );
+        assert!(!power_saving.should_render(1000.0));
+
+        assert_eq!(
+            power_saving.set_hidden(false).map(|state| state.reason()),
+            Some(Some(PowerSavingReason::Offscreen))
+        );
+        assert_eq!(
+            power_saving.set_offscreen(false),
+            Some(PowerSavingState::Running)
+        );
+        assert!(power_saving.should_render(1001.0));
+    }
+}
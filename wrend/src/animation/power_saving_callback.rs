@@ -0,0 +1,45 @@
+use crate::{Callback, PowerSavingState};
+use js_sys::Function;
+use std::fmt::Debug;
+use std::{ops::Deref, rc::Rc};
+
+/// Called by a [crate::Renderer] whenever its animation is paused, throttled or resumed to save power.
+///
+/// JavaScript callbacks receive an object of the form `{ state, reason, fps }`, where `state` is
+/// `"running"`, `"paused"` or `"throttled"` and `reason` is `"hidden"` or `"offscreen"`.
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd)]
+pub struct PowerSavingCallback(Callback<dyn Fn(PowerSavingState), Function>);
+
+impl Deref for PowerSavingCallback {
+    type Target = Callback<dyn Fn(PowerSavingState), Function>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for PowerSavingCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("PowerSavingCallback").field(&self.0).finish()
+    }
+}
+
+impl<F: Fn(PowerSavingState) + 'static> From<F> for PowerSavingCallback {
+    fn from(callback: F) -> Self {
+        Self(Callback::new_rs(
+            Rc::new(callback) as Rc<dyn Fn(PowerSavingState)>
+        ))
+    }
+}
+
+impl<F: Fn(PowerSavingState) + 'static> From<Rc<F>> for PowerSavingCallback {
+    fn from(callback: Rc<F>) -> Self {
+        Self(Callback::new_rs(callback as Rc<dyn Fn(PowerSavingState)>))
+    }
+}
+
+impl From<Function> for PowerSavingCallback {
+    fn from(callback: Function) -> Self {
+        Self(Callback::new_js(callback))
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{update_power_saving, Listener, PowerSaving};
+use js_sys::Array;
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+use web_sys::{window, HtmlCanvasElement, IntersectionObserver, IntersectionObserverEntry, Node};
+
+/// Keeps [PowerSaving] up to date with the page's visibility (via the Page Visibility API) and the
+/// canvas's position (via an `IntersectionObserver`). Both are disconnected when dropped.
+pub(crate) struct PowerSavingListeners {
+    _visibility_listener: Listener<Node>,
+    intersection_observer: IntersectionObserver,
+    _on_intersection: Closure<dyn Fn(Array)>,
+}
+
+impl PowerSavingListeners {
+    pub(crate) fn new(
+        canvas: &HtmlCanvasElement,
+        power_saving: Rc<RefCell<PowerSaving>>,
+    ) -> Result<Self, JsValue> {
+        let document = window()
+            .and_then(|window| window.document())
+            .ok_or_else(|| JsValue::from_str("No document available"))?;
+
+        update_power_saving(&power_saving, |power_saving| {
+            power_saving.set_hidden(document.hidden())
+        });
+        let visibility_listener = {
+            let power_saving = Rc::clone(&power_saving);
+            let visibility_document = document.clone();
+            Listener::new(
+                Node::from(document),
+                "visibilitychange",
+                move |_: JsValue| {
+                    update_power_saving(&power_saving, |power_saving| {
+                        power_saving.set_hidden(visibility_document.hidden())
+                    });
+                },
+            )
+        };
+
+        // the observer reports the canvas's initial intersection as soon as it starts observing
+        let on_intersection = Closure::wrap(Box::new(move |entries: Array| {
+            let entry = entries
+                .iter()
+                .last()
+                .map(JsCast::unchecked_into::<IntersectionObserverEntry>);
+            if let Some(entry) = entry {
+                update_power_saving(&power_saving, |power_saving| {
+                    power_saving.set_offscreen(!entry.is_intersecting())
+                });
+            }
+        }) as Box<dyn Fn(Array)>);
+        let intersection_observer =
+            IntersectionObserver::new(on_intersection.as_ref().unchecked_ref())?;
+        intersection_observer.observe(canvas);
+
+        Ok(Self {
+            _visibility_listener: visibility_listener,
+            intersection_observer,
+            _on_intersection: on_intersection,
+        })
+    }
+}
+
+impl Drop for PowerSavingListeners {
+    fn drop(&mut self) {
+        self.intersection_observer.disconnect();
+    }
+}
+
+impl Debug for PowerSavingListeners {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PowerSavingListeners")
+            .field("intersection_observer", &self.intersection_observer)
+            .finish()
+    }
+}
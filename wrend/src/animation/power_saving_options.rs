@@ -0,0 +1,67 @@
+use crate::{PowerSavingPolicy, PowerSavingReason, PowerSavingState};
+
+/// How a [crate::Renderer]'s animation saves power while the page is hidden or the canvas is
+/// offscreen. Enable it with [crate::Renderer::set_power_saving].
+///
+/// Both default to [PowerSavingPolicy::Pause]. When the page is hidden and the canvas is
+/// offscreen at the same time, the hidden policy applies.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct PowerSavingOptions {
+    hidden: PowerSavingPolicy,
+    offscreen: PowerSavingPolicy,
+}
+
+impl PowerSavingOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Policy while the page is in a background tab or minimized window
+    pub fn hidden(&self) -> PowerSavingPolicy {
+        self.hidden
+    }
+
+    pub fn set_hidden(&mut self, policy: PowerSavingPolicy) -> &mut Self {
+        self.hidden = policy;
+        self
+    }
+
+    /// Policy while the canvas is outside of the viewport
+    pub fn offscreen(&self) -> PowerSavingPolicy {
+        self.offscreen
+    }
+
+    pub fn set_offscreen(&mut self, policy: PowerSavingPolicy) -> &mut Self {
+        self.offscreen = policy;
+        self
+    }
+
+    /// The state the animation should be in, given the page's visibility and the canvas's position
+    pub fn state(&self, hidden: bool, offscreen: bool) -> PowerSavingState {
+        let (reason, policy) = if hidden {
+            (PowerSavingReason::Hidden, self.hidden)
+        } else if offscreen {
+            (PowerSavingReason::Offscreen, self.offscreen)
+        } else {
+            return PowerSavingState::Running;
+        };
+
+        match policy {
+            PowerSavingPolicy::KeepRunning => PowerSavingState::Running,
+            PowerSavingPolicy::Pause => PowerSavingState::Paused(reason),
+            PowerSavingPolicy::Throttle(fps) => PowerSavingState::Throttled {
+                reason,
+                fps: fps.max(f64::EPSILON),
+            },
+        }
+    }
+}
+
+impl Default for PowerSavingOptions {
+    fn default() -> Self {
+        Self {
+            hidden: PowerSavingPolicy::Pause,
+            offscreen: PowerSavingPolicy::Pause,
+        }
+    }
+}
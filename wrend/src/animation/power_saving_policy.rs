@@ -0,0 +1,10 @@
+/// What a [crate::Renderer]'s animation does while it can't be seen (see [crate::PowerSavingOptions])
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum PowerSavingPolicy {
+    /// Keep calling the animation callback every frame
+    KeepRunning,
+    /// Skip the animation callback entirely
+    Pause,
+    /// Call the animation callback at most this many times per second
+    Throttle(f64),
+}
@@ -0,0 +1,19 @@
+use std::fmt::Display;
+
+/// Why a [crate::Renderer]'s animation was paused or throttled
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PowerSavingReason {
+    /// The page is in a background tab or minimized window
+    Hidden,
+    /// The canvas has been scrolled (or otherwise moved) out of the viewport
+    Offscreen,
+}
+
+impl Display for PowerSavingReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PowerSavingReason::Hidden => write!(f, "hidden"),
+            PowerSavingReason::Offscreen => write!(f, "offscreen"),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::PowerSavingReason;
+use wasm_bindgen::JsValue;
+
+/// Whether a [crate::Renderer]'s animation is currently rendering at full rate
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub enum PowerSavingState {
+    #[default]
+    Running,
+    Paused(PowerSavingReason),
+    Throttled {
+        reason: PowerSavingReason,
+        fps: f64,
+    },
+}
+
+impl PowerSavingState {
+    /// Why rendering isn't running at full rate, if it isn't
+    pub fn reason(&self) -> Option<PowerSavingReason> {
+        match self {
+            PowerSavingState::Running => None,
+            PowerSavingState::Paused(reason) => Some(*reason),
+            PowerSavingState::Throttled { reason, .. } => Some(*reason),
+        }
+    }
+}
+
+impl From<PowerSavingState> for JsValue {
+    fn from(state: PowerSavingState) -> Self {
+        let json = match state {
+            PowerSavingState::Running => r#"{"state":"running"}"#.to_string(),
+            PowerSavingState::Paused(reason) => {
+                format!(r#"{{"state":"paused","reason":"{reason}"}}"#)
+            }
+            PowerSavingState::Throttled { reason, fps } => {
+                format!(r#"{{"state":"throttled","reason":"{reason}","fps":{fps}}}"#)
+            }
+        };
+        js_sys::JSON::parse(&json).unwrap_or(JsValue::NULL)
+    }
+}
@@ -0,0 +1,35 @@
+use crate::ReducedMotionOptions;
+
+/// Tracks the user's reduced motion preference and what the animation should do about it
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ReducedMotion {
+    options: Option<ReducedMotionOptions>,
+    prefers_reduced_motion: bool,
+}
+
+impl ReducedMotion {
+    pub(crate) fn options(&self) -> Option<ReducedMotionOptions> {
+        self.options
+    }
+
+    pub(crate) fn set_options(&mut self, options: Option<ReducedMotionOptions>) {
+        self.options = options;
+    }
+
+    pub(crate) fn set_prefers_reduced_motion(&mut self, prefers_reduced_motion: bool) {
+        self.prefers_reduced_motion = prefers_reduced_motion;
+    }
+
+    /// Whether reduced motion is both enabled and currently preferred
+    pub(crate) fn is_active(&self) -> bool {
+        self.options.is_some() && self.prefers_reduced_motion
+    }
+
+    /// Speed that animation time should currently run at
+    pub(crate) fn time_scale(&self) -> f64 {
+        match self.options {
+            Some(options) if self.prefers_reduced_motion => options.time_scale(),
+            _ => 1.0,
+        }
+    }
+}
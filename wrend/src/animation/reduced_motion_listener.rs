@@ -0,0 +1,42 @@
+use crate::Listener;
+use std::fmt::Debug;
+use wasm_bindgen::JsValue;
+use web_sys::{window, MediaQueryList};
+
+const REDUCED_MOTION_QUERY: &str = "(prefers-reduced-motion: reduce)";
+
+/// Whether the user has asked the operating system or browser to minimize non-essential motion
+pub fn prefers_reduced_motion() -> bool {
+    window()
+        .and_then(|window| window.match_media(REDUCED_MOTION_QUERY).ok().flatten())
+        .is_some_and(|media_query_list| media_query_list.matches())
+}
+
+/// Calls `on_change` with the reduced motion preference whenever it changes.
+/// Stops listening when dropped.
+pub(crate) struct ReducedMotionListener {
+    _listener: Listener<MediaQueryList>,
+}
+
+impl ReducedMotionListener {
+    pub(crate) fn new(on_change: impl Fn(bool) + 'static) -> Result<Self, JsValue> {
+        let media_query_list = window()
+            .ok_or_else(|| JsValue::from_str("No window available"))?
+            .match_media(REDUCED_MOTION_QUERY)?
+            .ok_or_else(|| JsValue::from_str("Media queries are not supported"))?;
+        let listened_list = media_query_list.clone();
+        let listener = Listener::new(media_query_list, "change", move |_: JsValue| {
+            on_change(listened_list.matches())
+        });
+
+        Ok(Self {
+            _listener: listener,
+        })
+    }
+}
+
+impl Debug for ReducedMotionListener {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReducedMotionListener").finish()
+    }
+}
@@ -0,0 +1,34 @@
+/// How a [crate::Renderer]'s animation responds when the user has asked for reduced motion
+/// (`prefers-reduced-motion: reduce`). Enable it with [crate::Renderer::set_reduced_motion].
+///
+/// While reduced motion is preferred, the clock that uniforms are updated with runs at
+/// [ReducedMotionOptions::time_scale], and the callback set with
+/// [crate::Renderer::set_reduced_motion_animation_callback] (if any) runs instead of the usual
+/// animation callback.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ReducedMotionOptions {
+    time_scale: f64,
+}
+
+impl ReducedMotionOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Speed of animation time while reduced motion is preferred: `0.0` freezes time, and `1.0`
+    /// leaves it unchanged. Defaults to `0.25`.
+    pub fn time_scale(&self) -> f64 {
+        self.time_scale
+    }
+
+    pub fn set_time_scale(&mut self, time_scale: f64) -> &mut Self {
+        self.time_scale = time_scale.clamp(0.0, 1.0);
+        self
+    }
+}
+
+impl Default for ReducedMotionOptions {
+    fn default() -> Self {
+        Self { time_scale: 0.25 }
+    }
+}
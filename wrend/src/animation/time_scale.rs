@@ -0,0 +1,60 @@
+/// Maps real time to animation time at a given speed, without jumping when the speed changes:
+/// time continues from where it was at the moment the scale was set.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub(crate) struct TimeScale {
+    scale: f64,
+    origin_real_ms: f64,
+    origin_scaled_ms: f64,
+}
+
+impl TimeScale {
+    pub(crate) fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Animation time at real time `real_ms`
+    pub(crate) fn at(&self, real_ms: f64) -> f64 {
+        if self.scale == 1.0 && self.origin_real_ms == self.origin_scaled_ms {
+            return real_ms;
+        }
+        self.origin_scaled_ms + (real_ms - self.origin_real_ms) * self.scale
+    }
+
+    /// The same timeline, continuing at `scale` from real time `real_ms` onwards
+    pub(crate) fn rescaled(&self, scale: f64, real_ms: f64) -> Self {
+        Self {
+            scale: scale.max(0.0),
+            origin_real_ms: real_ms,
+            origin_scaled_ms: self.at(real_ms),
+        }
+    }
+}
+
+impl Default for TimeScale {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            origin_real_ms: 0.0,
+            origin_scaled_ms: 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TimeScale;
+
+    #[test]
+    fn continues_from_current_time_when_rescaled() {
+        let time_scale = TimeScale::default();
+        assert_eq!(time_scale.at(1000.0), 1000.0);
+
+        let slow = time_scale.rescaled(0.25, 1000.0);
+        assert_eq!(slow.at(1000.0), 1000.0);
+        assert_eq!(slow.at(1400.0), 1100.0);
+
+        let normal = slow.rescaled(1.0, 1400.0);
+        assert_eq!(normal.at(1500.0), 1200.0);
+        assert_eq!(normal.rescaled(-1.0, 1500.0).at(2000.0), 1200.0);
+    }
+}
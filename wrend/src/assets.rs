@@ -0,0 +1,13 @@
+mod asset;
+mod asset_kind;
+mod asset_loader;
+mod asset_progress;
+mod asset_request;
+mod image_bitmap_decoder;
+
+pub use asset::*;
+pub use asset_kind::*;
+pub use asset_loader::*;
+pub use asset_progress::*;
+pub use asset_request::*;
+pub use image_bitmap_decoder::*;
@@ -0,0 +1,41 @@
+use crate::AssetKind;
+use web_sys::HtmlImageElement;
+
+/// A loaded asset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Asset {
+    Text(String),
+    Bytes(Vec<u8>),
+    Image(HtmlImageElement),
+}
+
+impl Asset {
+    pub fn kind(&self) -> AssetKind {
+        match self {
+            Asset::Text(_) => AssetKind::Text,
+            Asset::Bytes(_) => AssetKind::Bytes,
+            Asset::Image(_) => AssetKind::Image,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Asset::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Asset::Bytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    pub fn as_image(&self) -> Option<&HtmlImageElement> {
+        match self {
+            Asset::Image(image) => Some(image),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+/// How a fetched asset should be decoded
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AssetKind {
+    /// UTF-8 text, such as shader source
+    Text,
+    /// Raw binary data
+    Bytes,
+    /// An image, decoded and ready to upload to a texture
+    Image,
+}
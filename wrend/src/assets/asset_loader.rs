@@ -0,0 +1,246 @@
+use crate::{Asset, AssetError, AssetKind, AssetProgress, AssetRequest, ImageBitmapDecoder};
+use js_sys::{ArrayBuffer, Uint8Array};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{HtmlImageElement, ImageBitmap, Response};
+
+/// Fetches and decodes text, binary, and image assets, caching the results in memory.
+///
+/// Clones share the same cache, so a single loader can be handed to every part of an app.
+#[derive(Debug, Clone, Default)]
+pub struct AssetLoader {
+    cache: Rc<RefCell<HashMap<(AssetKind, String), Asset>>>,
+    image_bitmap_decoder: Rc<RefCell<Option<ImageBitmapDecoder>>>,
+}
+
+/// An asset whose request has been started, but not yet awaited
+enum PendingAsset {
+    Cached(Asset),
+    Fetch {
+        url: String,
+        kind: AssetKind,
+        response: JsFuture,
+    },
+    Image {
+        url: String,
+        image: HtmlImageElement,
+        decode: JsFuture,
+    },
+}
+
+impl AssetLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fetches a url as text (e.g. shader source)
+    pub async fn load_text(&self, url: impl Into<String>) -> Result<String, AssetError> {
+        let asset = self.load(AssetRequest::text(url)).await?;
+        Ok(asset.as_text().unwrap_or_default().to_string())
+    }
+
+    /// Fetches a url as raw bytes
+    pub async fn load_bytes(&self, url: impl Into<String>) -> Result<Vec<u8>, AssetError> {
+        let asset = self.load(AssetRequest::bytes(url)).await?;
+        Ok(asset.as_bytes().unwrap_or_default().to_vec())
+    }
+
+    /// Loads and decodes an image, which can then be uploaded with
+    /// `tex_image_2d_with_u32_and_u32_and_html_image_element`
+    pub async fn load_image(&self, url: impl Into<String>) -> Result<HtmlImageElement, AssetError> {
+        let request = AssetRequest::image(url);
+        let asset = self.load(request.clone()).await?;
+        asset
+            .as_image()
+            .cloned()
+            .ok_or_else(|| AssetError::DecodeFailed {
+                url: request.url().to_string(),
+                message: String::from("Cached asset was not an image"),
+            })
+    }
+
+    /// Fetches and decodes an image in a worker (see [ImageBitmapDecoder]), keeping the decode off
+    /// the main thread. The worker is started on first use and shared by clones of this loader.
+    ///
+    /// Bitmaps are not cached, since they are usually closed once they have been uploaded.
+    pub async fn load_image_bitmap(
+        &self,
+        url: impl Into<String>,
+        flip_y: bool,
+    ) -> Result<ImageBitmap, AssetError> {
+        let decoder = {
+            let mut decoder = self.image_bitmap_decoder.borrow_mut();
+            match decoder.as_ref() {
+                Some(decoder) => decoder.clone(),
+                None => decoder.insert(ImageBitmapDecoder::new()?).clone(),
+            }
+        };
+        decoder.decode(url, flip_y).await
+    }
+
+    /// Loads a single asset, returning the cached copy if it has already been loaded
+    pub async fn load(&self, request: AssetRequest) -> Result<Asset, AssetError> {
+        let pending = self.start(&request)?;
+        self.finish(pending).await
+    }
+
+    /// Loads every asset in `requests`, calling `on_progress` after each one finishes.
+    ///
+    /// All requests are started before any of them are awaited, so they download in parallel.
+    /// Assets are returned in the same order as `requests`.
+    pub async fn load_batch(
+        &self,
+        requests: &[AssetRequest],
+        on_progress: impl Fn(AssetProgress),
+    ) -> Result<Vec<Asset>, AssetError> {
+        let pending = requests
+            .iter()
+            .map(|request| self.start(request))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let total = pending.len();
+        let mut assets = Vec::with_capacity(total);
+        for (i, (pending, request)) in pending.into_iter().zip(requests).enumerate() {
+            assets.push(self.finish(pending).await?);
+            on_progress(AssetProgress::new(i + 1, total, request.url()));
+        }
+
+        Ok(assets)
+    }
+
+    /// Returns an already-loaded asset without fetching
+    pub fn cached(&self, request: &AssetRequest) -> Option<Asset> {
+        self.cache
+            .borrow()
+            .get(&(request.kind(), request.url().to_string()))
+            .cloned()
+    }
+
+    pub fn is_cached(&self, request: &AssetRequest) -> bool {
+        self.cache
+            .borrow()
+            .contains_key(&(request.kind(), request.url().to_string()))
+    }
+
+    /// Drops a single asset from the cache so that it is fetched again on next load
+    pub fn evict(&self, request: &AssetRequest) -> Option<Asset> {
+        self.cache
+            .borrow_mut()
+            .remove(&(request.kind(), request.url().to_string()))
+    }
+
+    pub fn clear_cache(&self) {
+        self.cache.borrow_mut().clear();
+    }
+
+    fn start(&self, request: &AssetRequest) -> Result<PendingAsset, AssetError> {
+        if let Some(asset) = self.cached(request) {
+            return Ok(PendingAsset::Cached(asset));
+        }
+
+        let url = request.url().to_string();
+        match request.kind() {
+            AssetKind::Image => {
+                let image = HtmlImageElement::new().map_err(|_| AssetError::NoDocument)?;
+                // allows the image to be uploaded to WebGL when served from another origin
+                image.set_cross_origin(Some("anonymous"));
+                image.set_src(&url);
+                let decode = JsFuture::from(image.decode());
+                Ok(PendingAsset::Image { url, image, decode })
+            }
+            kind => {
+                let window = web_sys::window().ok_or(AssetError::NoWindow)?;
+                let response = JsFuture::from(window.fetch_with_str(&url));
+                Ok(PendingAsset::Fetch {
+                    url,
+                    kind,
+                    response,
+                })
+            }
+        }
+    }
+
+    async fn finish(&self, pending: PendingAsset) -> Result<Asset, AssetError> {
+        let (url, asset) = match pending {
+            PendingAsset::Cached(asset) => return Ok(asset),
+            PendingAsset::Image { url, image, decode } => {
+                decode.await.map_err(|e| AssetError::DecodeFailed {
+                    url: url.clone(),
+                    message: js_error_message(&e),
+                })?;
+                (url, Asset::Image(image))
+            }
+            PendingAsset::Fetch {
+                url,
+                kind,
+                response,
+            } => {
+                let response: Response = response
+                    .await
+                    .map_err(|e| AssetError::RequestFailed {
+                        url: url.clone(),
+                        message: js_error_message(&e),
+                    })?
+                    .dyn_into()
+                    .map_err(|e| AssetError::RequestFailed {
+                        url: url.clone(),
+                        message: js_error_message(&e),
+                    })?;
+
+                if !response.ok() {
+                    return Err(AssetError::BadStatus {
+                        url,
+                        status: response.status(),
+                    });
+                }
+
+                let asset = Self::read_response(&url, kind, &response).await?;
+                (url, asset)
+            }
+        };
+
+        self.cache
+            .borrow_mut()
+            .insert((asset.kind(), url), asset.clone());
+
+        Ok(asset)
+    }
+
+    async fn read_response(
+        url: &str,
+        kind: AssetKind,
+        response: &Response,
+    ) -> Result<Asset, AssetError> {
+        let decode_failed = |e: JsValue| AssetError::DecodeFailed {
+            url: url.to_string(),
+            message: js_error_message(&e),
+        };
+
+        match kind {
+            AssetKind::Text => {
+                let text = JsFuture::from(response.text().map_err(decode_failed)?)
+                    .await
+                    .map_err(decode_failed)?;
+                Ok(Asset::Text(text.as_string().unwrap_or_default()))
+            }
+            AssetKind::Bytes | AssetKind::Image => {
+                let buffer: ArrayBuffer =
+                    JsFuture::from(response.array_buffer().map_err(decode_failed)?)
+                        .await
+                        .map_err(decode_failed)?
+                        .dyn_into()
+                        .map_err(decode_failed)?;
+                Ok(Asset::Bytes(Uint8Array::new(&buffer).to_vec()))
+            }
+        }
+    }
+}
+
+fn js_error_message(value: &JsValue) -> String {
+    value
+        .dyn_ref::<js_sys::Error>()
+        .map(|error| String::from(error.message()))
+        .or_else(|| value.as_string())
+        .unwrap_or_else(|| format!("{value:?}"))
+}
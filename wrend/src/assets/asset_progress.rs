@@ -0,0 +1,41 @@
+/// Progress of a batch load, reported after each asset finishes
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AssetProgress {
+    loaded: usize,
+    total: usize,
+    url: String,
+}
+
+impl AssetProgress {
+    pub(crate) fn new(loaded: usize, total: usize, url: impl Into<String>) -> Self {
+        Self {
+            loaded,
+            total,
+            url: url.into(),
+        }
+    }
+
+    /// Number of assets that have finished loading so far
+    pub fn loaded(&self) -> usize {
+        self.loaded
+    }
+
+    /// Number of assets in the batch
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Url of the asset that just finished loading
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Progress from 0.0 to 1.0
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.loaded as f32 / self.total as f32
+        }
+    }
+}
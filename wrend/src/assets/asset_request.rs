@@ -0,0 +1,37 @@
+use crate::AssetKind;
+
+/// A single entry in a batch passed to [crate::AssetLoader::load_batch]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AssetRequest {
+    url: String,
+    kind: AssetKind,
+}
+
+impl AssetRequest {
+    pub fn new(url: impl Into<String>, kind: AssetKind) -> Self {
+        Self {
+            url: url.into(),
+            kind,
+        }
+    }
+
+    pub fn text(url: impl Into<String>) -> Self {
+        Self::new(url, AssetKind::Text)
+    }
+
+    pub fn bytes(url: impl Into<String>) -> Self {
+        Self::new(url, AssetKind::Bytes)
+    }
+
+    pub fn image(url: impl Into<String>) -> Self {
+        Self::new(url, AssetKind::Image)
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn kind(&self) -> AssetKind {
+        self.kind
+    }
+}
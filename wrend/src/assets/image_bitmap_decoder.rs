@@ -0,0 +1,182 @@
+use crate::AssetError;
+use js_sys::{Array, Function, Object, Promise, Reflect};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    fmt::Debug,
+    rc::Rc,
+};
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Blob, BlobPropertyBag, ImageBitmap, MessageEvent, Url, Worker};
+
+/// Source of the worker started by [ImageBitmapDecoder]: fetches each requested url and decodes
+/// it with `createImageBitmap`, then transfers the bitmap back to the main thread
+const IMAGE_BITMAP_WORKER_SOURCE: &str = r#"
+self.onmessage = async (event) => {
+    const { id, url, flipY } = event.data;
+    try {
+        const response = await fetch(url);
+        if (!response.ok) {
+            throw new Error(`Request returned status ${response.status}`);
+        }
+        const blob = await response.blob();
+        const bitmap = await createImageBitmap(blob, {
+            imageOrientation: flipY ? "flipY" : "none",
+            premultiplyAlpha: "none",
+        });
+        self.postMessage({ id, bitmap }, [bitmap]);
+    } catch (error) {
+        self.postMessage({ id, error: String((error && error.message) || error) });
+    }
+};
+"#;
+
+type PendingDecodes = Rc<RefCell<HashMap<u32, (Function, Function)>>>;
+
+struct ImageBitmapDecoderInner {
+    worker: Worker,
+    next_id: Cell<u32>,
+    pending: PendingDecodes,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+}
+
+impl Drop for ImageBitmapDecoderInner {
+    fn drop(&mut self) {
+        self.worker.terminate();
+    }
+}
+
+/// Fetches and decodes images in a Web Worker, so that large textures can be loaded without
+/// blocking the main thread.
+///
+/// Each image is decoded to an [ImageBitmap] and transferred (not copied) back to the main thread,
+/// ready to be uploaded with `tex_image_2d_with_u32_and_u32_and_image_bitmap` (see
+/// [crate::BitmapTexture]). Clones share the same worker, which is stopped when the last clone is dropped.
+#[derive(Clone)]
+pub struct ImageBitmapDecoder {
+    inner: Rc<ImageBitmapDecoderInner>,
+}
+
+impl ImageBitmapDecoder {
+    /// Starts the decoding worker
+    pub fn new() -> Result<Self, AssetError> {
+        let worker_failed = |err: JsValue| AssetError::WorkerFailed(format!("{err:?}"));
+        let options = BlobPropertyBag::new();
+        options.set_type("text/javascript");
+        let source = Blob::new_with_str_sequence_and_options(
+            &Array::of1(&JsValue::from_str(IMAGE_BITMAP_WORKER_SOURCE)),
+            &options,
+        )
+        .map_err(worker_failed)?;
+        let script_url = Url::create_object_url_with_blob(&source).map_err(worker_failed)?;
+        let worker = Worker::new(&script_url);
+        Url::revoke_object_url(&script_url).map_err(worker_failed)?;
+        let worker = worker.map_err(worker_failed)?;
+
+        let pending: PendingDecodes = Default::default();
+        let on_message = {
+            let pending = Rc::clone(&pending);
+            Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+                let data = event.data();
+                let Some(id) = Reflect::get(&data, &"id".into())
+                    .ok()
+                    .and_then(|id| id.as_f64())
+                else {
+                    return;
+                };
+                let Some((resolve, reject)) = pending.borrow_mut().remove(&(id as u32)) else {
+                    return;
+                };
+                let error = Reflect::get(&data, &"error".into()).unwrap_or_default();
+                let _ = if error.is_undefined() {
+                    let bitmap = Reflect::get(&data, &"bitmap".into()).unwrap_or_default();
+                    resolve.call1(&JsValue::NULL, &bitmap)
+                } else {
+                    reject.call1(&JsValue::NULL, &error)
+                };
+            })
+        };
+        worker.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+
+        Ok(Self {
+            inner: Rc::new(ImageBitmapDecoderInner {
+                worker,
+                next_id: Cell::new(0),
+                pending,
+                _on_message: on_message,
+            }),
+        })
+    }
+
+    /// Fetches and decodes `url` in the worker. Relative urls are resolved against the page.
+    ///
+    /// Bitmaps are not premultiplied. With `flip_y`, the first row of the bitmap is the bottom of
+    /// the image, which matches WebGL's texture coordinates.
+    pub async fn decode(
+        &self,
+        url: impl Into<String>,
+        flip_y: bool,
+    ) -> Result<ImageBitmap, AssetError> {
+        let url = url.into();
+        let request_failed = |err: JsValue| AssetError::RequestFailed {
+            url: url.clone(),
+            message: format!("{err:?}"),
+        };
+        let page_url = web_sys::window()
+            .ok_or(AssetError::NoWindow)?
+            .location()
+            .href()
+            .map_err(request_failed)?;
+        let absolute_url = Url::new_with_base(&url, &page_url)
+            .map_err(request_failed)?
+            .href();
+
+        let id = self.inner.next_id.get();
+        self.inner.next_id.set(id.wrapping_add(1));
+        let promise = Promise::new(&mut |resolve, reject| {
+            self.inner
+                .pending
+                .borrow_mut()
+                .insert(id, (resolve, reject));
+        });
+
+        let message = Object::new();
+        for (key, value) in [
+            ("id", JsValue::from(id)),
+            ("url", JsValue::from(absolute_url)),
+            ("flipY", JsValue::from(flip_y)),
+        ] {
+            Reflect::set(&message, &key.into(), &value).map_err(request_failed)?;
+        }
+        if let Err(err) = self.inner.worker.post_message(&message) {
+            self.inner.pending.borrow_mut().remove(&id);
+            return Err(request_failed(err));
+        }
+
+        JsFuture::from(promise)
+            .await
+            .map_err(|err| AssetError::DecodeFailed {
+                url: url.clone(),
+                message: err.as_string().unwrap_or_else(|| format!("{err:?}")),
+            })?
+            .dyn_into()
+            .map_err(|err| AssetError::DecodeFailed {
+                url: url.clone(),
+                message: format!("Worker did not return an ImageBitmap: {err:?}"),
+            })
+    }
+
+    /// Number of decodes that have been requested but not finished
+    pub fn pending_count(&self) -> usize {
+        self.inner.pending.borrow().len()
+    }
+}
+
+impl Debug for ImageBitmapDecoder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ImageBitmapDecoder")
+            .field("pending_count", &self.pending_count())
+            .finish()
+    }
+}
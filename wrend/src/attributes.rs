@@ -3,8 +3,11 @@ mod attribute_create_callback;
 mod attribute_create_callback_js;
 mod attribute_create_context;
 mod attribute_create_context_js;
+#[cfg(feature = "js")]
 mod attribute_js;
+mod attribute_key;
 mod attribute_link;
+#[cfg(feature = "js")]
 mod attribute_link_js;
 mod attribute_location;
 
@@ -13,7 +16,10 @@ pub use attribute_create_callback::*;
 pub use attribute_create_callback_js::*;
 pub use attribute_create_context::*;
 pub use attribute_create_context_js::*;
+#[cfg(feature = "js")]
 pub use attribute_js::*;
+pub use attribute_key::*;
 pub use attribute_link::*;
+#[cfg(feature = "js")]
 pub use attribute_link_js::*;
 pub use attribute_location::*;
@@ -1,6 +1,9 @@
-use crate::{AttributeJs, AttributeJsInner, AttributeLocation, Bridge, Id, IdName};
+#[cfg(feature = "js")]
+use crate::{AttributeJs, AttributeJsInner};
+use crate::{AttributeLocation, Bridge, Id, IdName};
 use std::fmt::Debug;
 use std::hash::Hash;
+#[cfg(feature = "js")]
 use wasm_bindgen::JsValue;
 use web_sys::WebGlBuffer;
 
@@ -11,6 +14,7 @@ pub struct Attribute<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + Id
     attribute_id: AttributeId,
     webgl_buffer: WebGlBuffer,
     attribute_location: AttributeLocation,
+    vertex_attrib_divisor: u32,
 }
 
 impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName>
@@ -23,6 +27,7 @@ impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName>
         attribute_id: AttributeId,
         webgl_buffer: WebGlBuffer,
         attribute_location: AttributeLocation,
+        vertex_attrib_divisor: u32,
     ) -> Self {
         let vao_id_bridge = vao_ids.into();
         Self {
@@ -31,6 +36,7 @@ impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName>
             attribute_id,
             webgl_buffer,
             attribute_location,
+            vertex_attrib_divisor,
         }
     }
 
@@ -42,6 +48,10 @@ impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName>
         &self.buffer_id
     }
 
+    pub fn attribute_id(&self) -> &AttributeId {
+        &self.attribute_id
+    }
+
     pub fn webgl_buffer(&self) -> &WebGlBuffer {
         &self.webgl_buffer
     }
@@ -49,6 +59,11 @@ impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName>
     pub fn attribute_location(&self) -> &AttributeLocation {
         &self.attribute_location
     }
+
+    /// See [crate::AttributeLink::set_vertex_attrib_divisor]
+    pub fn vertex_attrib_divisor(&self) -> u32 {
+        self.vertex_attrib_divisor
+    }
 }
 
 impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName> Debug
@@ -61,6 +76,7 @@ impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName> Debug
             .field("attribute_id", &self.attribute_id)
             .field("buffer", &self.webgl_buffer)
             .field("attribute_location", &self.attribute_location)
+            .field("vertex_attrib_divisor", &self.vertex_attrib_divisor)
             .finish()
     }
 }
@@ -83,6 +99,7 @@ impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName> PartialEq
             && self.attribute_id == other.attribute_id
             && self.webgl_buffer == other.webgl_buffer
             && self.attribute_location == other.attribute_location
+            && self.vertex_attrib_divisor == other.vertex_attrib_divisor
     }
 }
 
@@ -91,6 +108,7 @@ impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName> Eq
 {
 }
 
+#[cfg(feature = "js")]
 impl From<AttributeJsInner> for JsValue {
     fn from(attribute: AttributeJsInner) -> Self {
         let js_attribute: AttributeJs = attribute.into();
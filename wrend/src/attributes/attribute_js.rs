@@ -31,6 +31,11 @@ impl AttributeJs {
         self.deref().buffer_id().clone()
     }
 
+    #[wasm_bindgen(js_name = attributeId)]
+    pub fn attribute_id(&self) -> String {
+        self.deref().attribute_id().clone()
+    }
+
     #[wasm_bindgen(js_name = webglBuffer)]
     pub fn webgl_buffer(&self) -> WebGlBuffer {
         self.deref().webgl_buffer().clone()
@@ -40,6 +45,11 @@ impl AttributeJs {
     pub fn attribute_location(&self) -> AttributeLocation {
         *self.deref().attribute_location()
     }
+
+    #[wasm_bindgen(js_name = vertexAttribDivisor)]
+    pub fn vertex_attrib_divisor(&self) -> u32 {
+        self.deref().vertex_attrib_divisor()
+    }
 }
 
 impl Deref for AttributeJs {
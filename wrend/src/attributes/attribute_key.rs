@@ -0,0 +1,31 @@
+use crate::{Id, IdName};
+
+/// Identifies one binding of an attribute: the same [crate::AttributeLink] id can be bound to
+/// different buffers in different VAOs (e.g. ping-ponging particle state between two buffers),
+/// so attributes are stored per attribute id and VAO.
+///
+/// `vao_id` is `None` for attributes initialized on the default vertex array.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AttributeKey<AttributeId: Id + IdName, VertexArrayObjectId: Id> {
+    attribute_id: AttributeId,
+    vao_id: Option<VertexArrayObjectId>,
+}
+
+impl<AttributeId: Id + IdName, VertexArrayObjectId: Id>
+    AttributeKey<AttributeId, VertexArrayObjectId>
+{
+    pub fn new(attribute_id: AttributeId, vao_id: Option<VertexArrayObjectId>) -> Self {
+        Self {
+            attribute_id,
+            vao_id,
+        }
+    }
+
+    pub fn attribute_id(&self) -> &AttributeId {
+        &self.attribute_id
+    }
+
+    pub fn vao_id(&self) -> Option<&VertexArrayObjectId> {
+        self.vao_id.as_ref()
+    }
+}
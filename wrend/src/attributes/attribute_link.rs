@@ -1,7 +1,8 @@
 use crate::{
-    AttributeCreateCallback, AttributeCreateContext, AttributeLinkJs, AttributeLinkJsInner,
-    AttributeLocation, Bridge, Id, IdName,
+    AttributeCreateCallback, AttributeCreateContext, AttributeLocation, Bridge, Id, IdName,
 };
+#[cfg(feature = "js")]
+use crate::{AttributeLinkJs, AttributeLinkJsInner};
 use std::fmt::Debug;
 use std::hash::Hash;
 use web_sys::{WebGl2RenderingContext, WebGlBuffer};
@@ -12,6 +13,7 @@ pub struct AttributeLink<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id
     buffer_id: BufferId,
     attribute_id: AttributeId,
     attribute_create_callback: AttributeCreateCallback,
+    vertex_attrib_divisor: u32,
 }
 
 impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName>
@@ -29,9 +31,26 @@ impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName>
             buffer_id,
             attribute_id,
             attribute_create_callback: attribute_create_callback.into(),
+            vertex_attrib_divisor: 0,
         }
     }
 
+    /// Makes this an instanced attribute: it advances once every `divisor` instances, rather than
+    /// once per vertex (the default, `0`). The builder applies this with `vertexAttribDivisor`
+    /// after the create callback has run, in every VAO the attribute is linked to.
+    pub fn set_vertex_attrib_divisor(&mut self, divisor: u32) -> &mut Self {
+        self.vertex_attrib_divisor = divisor;
+        self
+    }
+
+    pub fn vertex_attrib_divisor(&self) -> u32 {
+        self.vertex_attrib_divisor
+    }
+
+    pub fn is_instanced(&self) -> bool {
+        self.vertex_attrib_divisor > 0
+    }
+
     pub fn vao_ids(&self) -> &[VertexArrayObjectId] {
         &self.vao_ids
     }
@@ -61,6 +80,21 @@ impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName>
         self.attribute_create_callback
             .call_with_into_js_arg(&attribute_create_context);
     }
+
+    /// The same link with its ids replaced (used when scoping links to a namespace)
+    pub(crate) fn map_ids(
+        self,
+        vao_id: impl Fn(&VertexArrayObjectId) -> VertexArrayObjectId,
+        buffer_id: impl Fn(&BufferId) -> BufferId,
+        attribute_id: impl Fn(&AttributeId) -> AttributeId,
+    ) -> Self {
+        Self {
+            vao_ids: self.vao_ids.iter().map(vao_id).collect(),
+            buffer_id: buffer_id(&self.buffer_id),
+            attribute_id: attribute_id(&self.attribute_id),
+            ..self
+        }
+    }
 }
 
 impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName> Debug
@@ -71,6 +105,7 @@ impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName> Debug
             .field("vao_ids", &self.vao_ids)
             .field("buffer_id", &self.buffer_id)
             .field("attribute_id", &self.attribute_id)
+            .field("vertex_attrib_divisor", &self.vertex_attrib_divisor)
             .field("update_callback", &"[not shown]")
             .field("should_update_callback", &"[not shown]")
             .finish()
@@ -84,6 +119,7 @@ impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName> Hash
         self.vao_ids.hash(state);
         self.buffer_id.hash(state);
         self.attribute_id.hash(state);
+        self.vertex_attrib_divisor.hash(state);
     }
 }
 
@@ -95,6 +131,7 @@ impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName> PartialEq
             && self.buffer_id == other.buffer_id
             && self.attribute_id == other.attribute_id
             && self.attribute_create_callback == other.attribute_create_callback
+            && self.vertex_attrib_divisor == other.vertex_attrib_divisor
     }
 }
 
@@ -103,6 +140,7 @@ impl<VertexArrayObjectId: Id, BufferId: Id, AttributeId: Id + IdName> Eq
 {
 }
 
+#[cfg(feature = "js")]
 impl From<AttributeLinkJs> for AttributeLinkJsInner {
     fn from(js_attribute_link: AttributeLinkJs) -> Self {
         js_attribute_link.into_inner()
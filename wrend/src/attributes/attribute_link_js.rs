@@ -44,6 +44,17 @@ impl AttributeLinkJs {
         self.deref().attribute_id().to_owned()
     }
 
+    /// Makes this an instanced attribute that advances once every `divisor` instances
+    #[wasm_bindgen(js_name = setVertexAttribDivisor)]
+    pub fn set_vertex_attrib_divisor(&mut self, divisor: u32) {
+        self.deref_mut().set_vertex_attrib_divisor(divisor);
+    }
+
+    #[wasm_bindgen(js_name = vertexAttribDivisor)]
+    pub fn vertex_attrib_divisor(&self) -> u32 {
+        self.deref().vertex_attrib_divisor()
+    }
+
     #[wasm_bindgen(js_name = createCallback)]
     pub fn create_callback(&self) -> Option<AttributeCreateCallbackJs> {
         self.deref().create_callback().js_inner_owned()
@@ -0,0 +1,13 @@
+mod cellular_automaton;
+mod edge_mode;
+mod larger_than_life_rule;
+mod life_rule;
+mod neighborhood_kernel;
+mod neighborhood_shape;
+
+pub use cellular_automaton::*;
+pub use edge_mode::*;
+pub use larger_than_life_rule::*;
+pub use life_rule::*;
+pub use neighborhood_kernel::*;
+pub use neighborhood_shape::*;
@@ -0,0 +1,451 @@
+use crate::{
+    EdgeMode, FramebufferAttachment, Id, IdName, LifeRule, RendererData, Rng, TextureCreateContext,
+    TextureLink,
+};
+use log::warn;
+use std::cell::{Cell, RefCell};
+use web_sys::{HtmlCanvasElement, WebGl2RenderingContext, WebGlFramebuffer, WebGlTexture};
+
+/// Fragment shader that advances a [CellularAutomaton] by one generation and/or paints cells with a brush.
+///
+/// Meant to be paired with [crate::COMPOSITING_VERTEX_SHADER].
+pub const CELLULAR_AUTOMATON_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+precision highp int;
+
+uniform highp sampler2D u_cells;
+// bitmasks of the neighbor counts that cause birth / survival
+uniform int u_birth;
+uniform int u_survival;
+uniform bool u_wrap;
+uniform bool u_step;
+// cell x, cell y, radius in cells, new state (negative for no brush)
+uniform vec4 u_brush;
+
+out vec4 out_color;
+
+float cell(ivec2 position, ivec2 size) {
+    if (u_wrap) {
+        position = (position + size) % size;
+    } else if (any(lessThan(position, ivec2(0))) || any(greaterThanEqual(position, size))) {
+        return 0.0;
+    }
+    return texelFetch(u_cells, position, 0).r;
+}
+
+void main() {
+    ivec2 size = textureSize(u_cells, 0);
+    ivec2 position = ivec2(gl_FragCoord.xy);
+    bool alive = cell(position, size) > 0.5;
+
+    if (u_step) {
+        int live_neighbors = 0;
+        for (int y = -1; y <= 1; y++) {
+            for (int x = -1; x <= 1; x++) {
+                if (x != 0 || y != 0) {
+                    live_neighbors += int(cell(position + ivec2(x, y), size) > 0.5);
+                }
+            }
+        }
+        int rule = alive ? u_survival : u_birth;
+        alive = ((rule >> live_neighbors) & 1) == 1;
+    }
+
+    if (u_brush.w >= 0.0 && distance(vec2(position), u_brush.xy) <= u_brush.z) {
+        alive = u_brush.w > 0.5;
+    }
+
+    out_color = vec4(vec3(alive ? 1.0 : 0.0), 1.0);
+}
+"#;
+
+/// A Life-like cellular automaton (see [LifeRule]) that runs entirely on the GPU.
+///
+/// The world is stored in a pair of `R8` textures that are ping-ponged every generation: live
+/// cells are `1.0` in the red channel, dead cells `0.0`, and row 0 is the bottom of the world.
+/// Its program and textures are added with [crate::RendererDataBuilder::add_cellular_automaton];
+/// it is advanced with [CellularAutomaton::step] and painted with [CellularAutomaton::paint].
+/// Draw it by sampling [CellularAutomaton::texture_id].
+#[derive(Debug, Clone)]
+pub struct CellularAutomaton<ProgramId: Id, TextureId: Id> {
+    program_id: ProgramId,
+    texture_ids: [TextureId; 2],
+    width: u32,
+    height: u32,
+    rule: LifeRule,
+    edge_mode: EdgeMode,
+    initial_cells: Option<Vec<u8>>,
+    generation: Cell<u64>,
+    framebuffers: RefCell<Vec<WebGlFramebuffer>>,
+}
+
+impl<ProgramId: Id, TextureId: Id> CellularAutomaton<ProgramId, TextureId> {
+    /// Creates an empty world of `width` x `height` cells running Conway's Game of Life
+    pub fn new(
+        program_id: ProgramId,
+        texture_ids: [TextureId; 2],
+        width: u32,
+        height: u32,
+    ) -> Self {
+        Self {
+            program_id,
+            texture_ids,
+            width: width.max(1),
+            height: height.max(1),
+            rule: LifeRule::default(),
+            edge_mode: EdgeMode::default(),
+            initial_cells: None,
+            generation: Cell::new(0),
+            framebuffers: Default::default(),
+        }
+    }
+
+    /// Can be changed at any time: takes effect on the next step
+    pub fn set_rule(&mut self, rule: LifeRule) -> &mut Self {
+        self.rule = rule;
+        self
+    }
+
+    /// Can be changed at any time: takes effect on the next step
+    pub fn set_edge_mode(&mut self, edge_mode: EdgeMode) -> &mut Self {
+        self.edge_mode = edge_mode;
+        self
+    }
+
+    /// Sets the starting cells, one byte per cell (non-zero is alive), row by row from the bottom.
+    /// Must be called before the textures are created.
+    pub fn set_initial_cells(&mut self, cells: Vec<u8>) -> &mut Self {
+        self.initial_cells = Some(cells);
+        self
+    }
+
+    /// Starts with each cell alive with a probability of `density`.
+    /// Must be called before the textures are created.
+    pub fn set_random_initial_cells(&mut self, density: f64, seed: u64) -> &mut Self {
+        let mut rng = Rng::new(seed);
+        let cells = (0..self.width * self.height)
+            .map(|_| if rng.chance(density) { 255 } else { 0 })
+            .collect();
+        self.set_initial_cells(cells)
+    }
+
+    pub fn program_id(&self) -> &ProgramId {
+        &self.program_id
+    }
+
+    pub fn rule(&self) -> LifeRule {
+        self.rule
+    }
+
+    pub fn edge_mode(&self) -> EdgeMode {
+        self.edge_mode
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Number of generations stepped so far
+    pub fn generation(&self) -> u64 {
+        self.generation.get()
+    }
+
+    /// Both textures of the ping-pong pair
+    pub fn texture_ids(&self) -> &[TextureId; 2] {
+        &self.texture_ids
+    }
+
+    /// The texture that holds the current generation
+    pub fn texture_id(&self) -> &TextureId {
+        &self.texture_ids[self.current()]
+    }
+
+    /// The cell under a point in client (CSS pixel) coordinates, e.g. from a pointer event,
+    /// assuming the world is stretched over the whole canvas
+    pub fn cell_at_client_position(
+        &self,
+        canvas: &HtmlCanvasElement,
+        client_x: f64,
+        client_y: f64,
+    ) -> Option<[u32; 2]> {
+        let rect = canvas.get_bounding_client_rect();
+        self.cell_at(
+            (client_x - rect.left()) / rect.width(),
+            1.0 - (client_y - rect.top()) / rect.height(),
+        )
+    }
+
+    /// The cell at a normalized position in the world, where `(0.0, 0.0)` is the bottom left
+    pub fn cell_at(&self, x: f64, y: f64) -> Option<[u32; 2]> {
+        if !(0.0..1.0).contains(&x) || !(0.0..1.0).contains(&y) {
+            return None;
+        }
+        Some([
+            (x * self.width as f64) as u32,
+            (y * self.height as f64) as u32,
+        ])
+    }
+
+    /// Links that create both textures, with the initial cells in the first one
+    pub fn texture_links(&self) -> [TextureLink<TextureId>; 2] {
+        let [first, second] = self.texture_ids.clone();
+        let cells = self.initial_cells.clone();
+        [
+            self.texture_link(first, cells),
+            self.texture_link(second, None),
+        ]
+    }
+
+    fn texture_link(
+        &self,
+        texture_id: TextureId,
+        cells: Option<Vec<u8>>,
+    ) -> TextureLink<TextureId> {
+        let (width, height) = (self.width, self.height);
+        TextureLink::new(texture_id, move |ctx: &TextureCreateContext| {
+            let gl = ctx.gl();
+            let texture = gl
+                .create_texture()
+                .expect("Should be able to create a texture for a CellularAutomaton");
+            let mut pixels = vec![0; (width * height) as usize];
+            if let Some(cells) = &cells {
+                for (pixel, cell) in pixels.iter_mut().zip(cells) {
+                    *pixel = if *cell > 0 { 255 } else { 0 };
+                }
+            }
+            gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&texture));
+            gl.pixel_storei(WebGl2RenderingContext::UNPACK_ALIGNMENT, 1);
+            gl.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+                WebGl2RenderingContext::TEXTURE_2D,
+                0,
+                WebGl2RenderingContext::R8 as i32,
+                width as i32,
+                height as i32,
+                0,
+                WebGl2RenderingContext::RED,
+                WebGl2RenderingContext::UNSIGNED_BYTE,
+                Some(&pixels),
+            )
+            .expect("Should be able to upload CellularAutomaton cells");
+            gl.pixel_storei(WebGl2RenderingContext::UNPACK_ALIGNMENT, 4);
+            for (parameter, value) in [
+                (
+                    WebGl2RenderingContext::TEXTURE_MIN_FILTER,
+                    WebGl2RenderingContext::NEAREST,
+                ),
+                (
+                    WebGl2RenderingContext::TEXTURE_MAG_FILTER,
+                    WebGl2RenderingContext::NEAREST,
+                ),
+                (
+                    WebGl2RenderingContext::TEXTURE_WRAP_S,
+                    WebGl2RenderingContext::CLAMP_TO_EDGE,
+                ),
+                (
+                    WebGl2RenderingContext::TEXTURE_WRAP_T,
+                    WebGl2RenderingContext::CLAMP_TO_EDGE,
+                ),
+            ] {
+                gl.tex_parameteri(WebGl2RenderingContext::TEXTURE_2D, parameter, value as i32);
+            }
+            gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, None);
+            texture
+        })
+    }
+
+    /// Advances the automaton by one generation. Leaves the canvas bound for drawing afterward.
+    pub fn step<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        UniformId: Id + IdName,
+        BufferId: Id,
+        AttributeId: Id + IdName,
+        FramebufferId: Id,
+        TransformFeedbackId: Id,
+        VertexArrayObjectId: Id,
+        UserCtx: Clone,
+    >(
+        &self,
+        renderer_data: &RendererData<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+    ) -> &Self {
+        self.draw_pass(renderer_data, true, [0.0, 0.0, 0.0, -1.0])
+    }
+
+    /// Sets every cell within `radius` cells of `cell` to alive or dead (e.g. from pointer input,
+    /// see [CellularAutomaton::cell_at_client_position]), without advancing a generation
+    pub fn paint<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        UniformId: Id + IdName,
+        BufferId: Id,
+        AttributeId: Id + IdName,
+        FramebufferId: Id,
+        TransformFeedbackId: Id,
+        VertexArrayObjectId: Id,
+        UserCtx: Clone,
+    >(
+        &self,
+        renderer_data: &RendererData<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+        [x, y]: [u32; 2],
+        radius: f32,
+        alive: bool,
+    ) -> &Self {
+        let state = if alive { 1.0 } else { 0.0 };
+        self.draw_pass(renderer_data, false, [x as f32, y as f32, radius, state])
+    }
+
+    /// Draws the current generation into the other texture of the pair
+    fn draw_pass<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        UniformId: Id + IdName,
+        BufferId: Id,
+        AttributeId: Id + IdName,
+        FramebufferId: Id,
+        TransformFeedbackId: Id,
+        VertexArrayObjectId: Id,
+        UserCtx: Clone,
+    >(
+        &self,
+        renderer_data: &RendererData<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+        step: bool,
+        brush: [f32; 4],
+    ) -> &Self {
+        let [first, second] = self.texture_ids();
+        let (Some(first), Some(second)) = (
+            renderer_data.webgl_texture(first),
+            renderer_data.webgl_texture(second),
+        ) else {
+            warn!(
+                "Could not find CellularAutomaton textures {:?}",
+                self.texture_ids()
+            );
+            return self;
+        };
+        let textures = [first, second];
+
+        let gl = renderer_data.gl();
+        let framebuffer = self.framebuffer(gl, textures, self.next());
+        let rule = self.rule();
+        let wrap = self.edge_mode() == EdgeMode::Wrap;
+        renderer_data.draw_fullscreen_pass_into(
+            self.program_id(),
+            Some(&framebuffer),
+            (self.width() as i32, self.height() as i32),
+            &[("u_cells", Some(textures[self.current()]))],
+            |gl, program| {
+                let location = |name| gl.get_uniform_location(program, name);
+                gl.uniform1i(location("u_birth").as_ref(), rule.birth_mask() as i32);
+                gl.uniform1i(location("u_survival").as_ref(), rule.survival_mask() as i32);
+                gl.uniform1i(location("u_wrap").as_ref(), wrap as i32);
+                gl.uniform1i(location("u_step").as_ref(), step as i32);
+                gl.uniform4fv_with_f32_array(location("u_brush").as_ref(), &brush);
+            },
+        );
+        self.advance();
+
+        gl.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, None);
+        let (width, height) = (gl.drawing_buffer_width(), gl.drawing_buffer_height());
+        gl.viewport(0, 0, width, height);
+        self
+    }
+
+    /// Index of the texture holding the current generation
+    pub(crate) fn current(&self) -> usize {
+        (self.generation.get() % 2) as usize
+    }
+
+    /// Index of the texture the next draw should render into
+    pub(crate) fn next(&self) -> usize {
+        1 - self.current()
+    }
+
+    /// Makes the texture that was just drawn into the current one
+    pub(crate) fn advance(&self) {
+        self.generation.set(self.generation.get() + 1);
+    }
+
+    /// Framebuffer that renders into one of the two textures
+    pub(crate) fn framebuffer(
+        &self,
+        gl: &WebGl2RenderingContext,
+        textures: [&WebGlTexture; 2],
+        index: usize,
+    ) -> WebGlFramebuffer {
+        let mut framebuffers = self.framebuffers.borrow_mut();
+        while framebuffers.len() < 2 {
+            let framebuffer = gl
+                .create_framebuffer()
+                .expect("Should be able to create a framebuffer for a CellularAutomaton");
+            gl.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, Some(&framebuffer));
+            FramebufferAttachment::new().attach(gl, textures[framebuffers.len()]);
+            framebuffers.push(framebuffer);
+        }
+        framebuffers[index].clone()
+    }
+
+    /// Deletes the framebuffers created for each texture (they are recreated if the automaton is used again)
+    pub fn delete_framebuffers(&self, gl: &WebGl2RenderingContext) {
+        for framebuffer in self.framebuffers.borrow_mut().drain(..) {
+            gl.delete_framebuffer(Some(&framebuffer));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CellularAutomaton;
+
+    #[test]
+    fn ping_pongs_and_maps_cells() {
+        let automaton = CellularAutomaton::new("life", ["cells_a", "cells_b"], 64, 32);
+        assert_eq!(automaton.texture_id(), &"cells_a");
+        automaton.advance();
+        assert_eq!(automaton.texture_id(), &"cells_b");
+        assert_eq!((automaton.current(), automaton.next()), (1, 0));
+
+        assert_eq!(automaton.cell_at(0.0, 0.0), Some([0, 0]));
+        assert_eq!(automaton.cell_at(0.5, 0.99), Some([32, 31]));
+        assert_eq!(automaton.cell_at(1.0, 0.5), None);
+    }
+}
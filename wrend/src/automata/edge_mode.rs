@@ -0,0 +1,9 @@
+/// How a [crate::CellularAutomaton] treats neighbors beyond the edges of its world
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EdgeMode {
+    /// Edges wrap around to the opposite side (a torus)
+    #[default]
+    Wrap,
+    /// Cells beyond the edges are always dead
+    Dead,
+}
@@ -0,0 +1,79 @@
+use std::ops::RangeInclusive;
+
+/// GLSL helper for applying a [LargerThanLifeRule], set through the `u_ltl_rule` uniform
+/// (see [LargerThanLifeRule::uniform_value]).
+///
+/// Use together with [crate::NEIGHBORHOOD_KERNEL_GLSL]:
+/// `ltl_next_state(alive, neighborhood_sum(u_state, cell))`
+pub const LARGER_THAN_LIFE_RULE_GLSL: &str = r#"
+// birth min, birth max, survival min, survival max
+uniform vec4 u_ltl_rule;
+
+bool ltl_next_state(bool alive, float sum) {
+    vec2 range = alive ? u_ltl_rule.zw : u_ltl_rule.xy;
+    return range.x <= sum && sum <= range.y;
+}
+"#;
+
+/// A Larger than Life rule: dead cells are born when their neighborhood sum is within `birth`,
+/// and live cells survive when it is within `survival`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LargerThanLifeRule {
+    birth: RangeInclusive<f32>,
+    survival: RangeInclusive<f32>,
+}
+
+impl LargerThanLifeRule {
+    /// "Bosco's rule" (R5,C0,M1,S34..58,B34..45,NM) for a radius 5 Moore neighborhood. Survival is
+    /// shifted down by one, since [crate::NeighborhoodKernel::new] excludes the center cell.
+    pub const BOSCO: LargerThanLifeRule = LargerThanLifeRule::new(34.0..=45.0, 33.0..=57.0);
+
+    /// Conway's Game of Life, for a radius 1 Moore neighborhood excluding the center cell
+    pub const LIFE: LargerThanLifeRule = LargerThanLifeRule::new(3.0..=3.0, 2.0..=3.0);
+
+    pub const fn new(birth: RangeInclusive<f32>, survival: RangeInclusive<f32>) -> Self {
+        Self { birth, survival }
+    }
+
+    pub fn birth(&self) -> &RangeInclusive<f32> {
+        &self.birth
+    }
+
+    pub fn survival(&self) -> &RangeInclusive<f32> {
+        &self.survival
+    }
+
+    /// Whether a cell is alive in the next generation
+    pub fn next_state(&self, alive: bool, neighborhood_sum: f32) -> bool {
+        if alive {
+            self.survival.contains(&neighborhood_sum)
+        } else {
+            self.birth.contains(&neighborhood_sum)
+        }
+    }
+
+    /// Value for the `u_ltl_rule` uniform of [LARGER_THAN_LIFE_RULE_GLSL]
+    pub fn uniform_value(&self) -> [f32; 4] {
+        [
+            *self.birth.start(),
+            *self.birth.end(),
+            *self.survival.start(),
+            *self.survival.end(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LargerThanLifeRule;
+
+    #[test]
+    fn applies_rule() {
+        let life = LargerThanLifeRule::LIFE;
+        assert!(life.next_state(false, 3.0));
+        assert!(!life.next_state(false, 2.0));
+        assert!(life.next_state(true, 2.0));
+        assert!(!life.next_state(true, 4.0));
+        assert_eq!(life.uniform_value(), [3.0, 3.0, 2.0, 3.0]);
+    }
+}
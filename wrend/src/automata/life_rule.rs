@@ -0,0 +1,113 @@
+use std::fmt::Display;
+
+/// A Life-like cellular automaton rule (e.g. `B3/S23` for Conway's Game of Life): a dead cell is
+/// born when its number of live Moore neighbors is in the birth set, and a live cell survives
+/// when it is in the survival set.
+///
+/// Each set is stored as a bitmask, where bit `n` is set if `n` neighbors (0 to 8) are included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LifeRule {
+    birth: u16,
+    survival: u16,
+}
+
+impl LifeRule {
+    /// Conway's Game of Life (`B3/S23`)
+    pub const CONWAY: LifeRule = LifeRule::new(1 << 3, 1 << 2 | 1 << 3);
+
+    /// HighLife (`B36/S23`), which has a self-replicating pattern
+    pub const HIGH_LIFE: LifeRule = LifeRule::new(1 << 3 | 1 << 6, 1 << 2 | 1 << 3);
+
+    /// Seeds (`B2/S`), in which every live cell dies each generation
+    pub const SEEDS: LifeRule = LifeRule::new(1 << 2, 0);
+
+    /// Day & Night (`B3678/S34678`), which treats live and dead cells symmetrically
+    pub const DAY_AND_NIGHT: LifeRule = LifeRule::new(
+        1 << 3 | 1 << 6 | 1 << 7 | 1 << 8,
+        1 << 3 | 1 << 4 | 1 << 6 | 1 << 7 | 1 << 8,
+    );
+
+    /// Creates a rule from birth and survival bitmasks. Bits above 8 are ignored.
+    pub const fn new(birth: u16, survival: u16) -> Self {
+        Self {
+            birth: birth & 0x1ff,
+            survival: survival & 0x1ff,
+        }
+    }
+
+    /// Parses rules in B/S notation, e.g. `B3/S23` (case-insensitive, in either order)
+    pub fn parse(rule: &str) -> Option<Self> {
+        let mut birth = None;
+        let mut survival = None;
+        for part in rule.trim().split('/') {
+            let mut chars = part.chars();
+            let set = match chars.next()?.to_ascii_uppercase() {
+                'B' => &mut birth,
+                'S' => &mut survival,
+                _ => return None,
+            };
+            let mut mask = 0;
+            for digit in chars {
+                match digit.to_digit(10) {
+                    Some(count) if count <= 8 => mask |= 1 << count,
+                    _ => return None,
+                }
+            }
+            if set.replace(mask).is_some() {
+                return None;
+            }
+        }
+        Some(Self::new(birth?, survival?))
+    }
+
+    pub fn birth_mask(&self) -> u16 {
+        self.birth
+    }
+
+    pub fn survival_mask(&self) -> u16 {
+        self.survival
+    }
+
+    /// Whether a cell is alive in the next generation, given its number of live neighbors
+    pub fn next_state(&self, alive: bool, live_neighbors: u32) -> bool {
+        let mask = if alive { self.survival } else { self.birth };
+        live_neighbors <= 8 && mask & (1 << live_neighbors) != 0
+    }
+}
+
+impl Default for LifeRule {
+    fn default() -> Self {
+        Self::CONWAY
+    }
+}
+
+impl Display for LifeRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let counts = |mask: u16| -> String {
+            (0..=8)
+                .filter(|count| mask & (1 << count) != 0)
+                .map(|count| char::from(b'0' + count as u8))
+                .collect()
+        };
+        write!(f, "B{}/S{}", counts(self.birth), counts(self.survival))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LifeRule;
+
+    #[test]
+    fn parses_and_formats_rules() {
+        assert_eq!(LifeRule::parse("B3/S23"), Some(LifeRule::CONWAY));
+        assert_eq!(LifeRule::parse("s23/b36"), Some(LifeRule::HIGH_LIFE));
+        assert_eq!(LifeRule::parse("B2/S"), Some(LifeRule::SEEDS));
+        assert_eq!(LifeRule::parse("B9/S23"), None);
+        assert_eq!(LifeRule::parse("B3"), None);
+        assert_eq!(LifeRule::DAY_AND_NIGHT.to_string(), "B3678/S34678");
+
+        assert!(LifeRule::CONWAY.next_state(false, 3));
+        assert!(LifeRule::CONWAY.next_state(true, 2));
+        assert!(!LifeRule::CONWAY.next_state(true, 4));
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{Id, NeighborhoodShape, TextureCreateContext, TextureLink};
+use js_sys::Float32Array;
+use web_sys::{WebGl2RenderingContext, WebGlTexture};
+
+/// GLSL helper for summing a cell's neighborhood using a [NeighborhoodKernel] texture.
+///
+/// Expects the kernel bound to `u_neighborhood_kernel`. `neighborhood_sum` adds up the red channel
+/// of every neighbor of `cell` in `state`, multiplied by its kernel weight, wrapping around the edges.
+/// Because the kernel is read at runtime, its radius and shape can change without recompiling the shader.
+pub const NEIGHBORHOOD_KERNEL_GLSL: &str = r#"
+uniform highp sampler2D u_neighborhood_kernel;
+
+float neighborhood_sum(sampler2D state, ivec2 cell) {
+    ivec2 state_size = textureSize(state, 0);
+    ivec2 kernel_size = textureSize(u_neighborhood_kernel, 0);
+    ivec2 radius = kernel_size / 2;
+    float sum = 0.0;
+    for (int y = 0; y < kernel_size.y; y++) {
+        for (int x = 0; x < kernel_size.x; x++) {
+            float weight = texelFetch(u_neighborhood_kernel, ivec2(x, y), 0).r;
+            if (weight == 0.0) {
+                continue;
+            }
+            ivec2 neighbor = (cell + ivec2(x, y) - radius + state_size) % state_size;
+            sum += weight * texelFetch(state, neighbor, 0).r;
+        }
+    }
+    return sum;
+}
+"#;
+
+/// Weights for every cell within `radius` of a center cell, for neighborhood-counting cellular automata
+/// (e.g. Larger than Life), uploaded as a square `R32F` texture for use with [NEIGHBORHOOD_KERNEL_GLSL].
+///
+/// The center cell is excluded by default.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NeighborhoodKernel {
+    radius: u32,
+    weights: Vec<f32>,
+}
+
+impl NeighborhoodKernel {
+    /// Kernel that weighs every cell inside `shape` as `1.0`, excluding the center
+    pub fn new(shape: NeighborhoodShape, radius: u32) -> Self {
+        Self::from_fn(radius, |dx, dy| {
+            if (dx, dy) != (0, 0) && shape.contains(dx, dy, radius) {
+                1.0
+            } else {
+                0.0
+            }
+        })
+    }
+
+    /// Kernel with custom weights, given each cell's offset from the center
+    pub fn from_fn(radius: u32, weight: impl Fn(i32, i32) -> f32) -> Self {
+        let r = radius as i32;
+        let weights = (-r..=r)
+            .flat_map(|dy| (-r..=r).map(move |dx| (dx, dy)))
+            .map(|(dx, dy)| weight(dx, dy))
+            .collect();
+        Self { radius, weights }
+    }
+
+    /// Includes the center cell in the neighborhood with weight `1.0`
+    pub fn with_center(mut self) -> Self {
+        let center = self.weights.len() / 2;
+        self.weights[center] = 1.0;
+        self
+    }
+
+    pub fn radius(&self) -> u32 {
+        self.radius
+    }
+
+    /// Width (and height) of the kernel texture
+    pub fn size(&self) -> u32 {
+        self.radius * 2 + 1
+    }
+
+    /// Weight of the cell at offset `(dx, dy)` from the center (`0.0` outside the kernel)
+    pub fn weight(&self, dx: i32, dy: i32) -> f32 {
+        let r = self.radius as i32;
+        if dx.abs() > r || dy.abs() > r {
+            return 0.0;
+        }
+        self.weights[((dy + r) * (2 * r + 1) + dx + r) as usize]
+    }
+
+    /// Row-major weights, starting from offset `(-radius, -radius)`
+    pub fn weights(&self) -> &[f32] {
+        &self.weights
+    }
+
+    /// Sum of all weights: the largest possible neighborhood sum
+    pub fn total_weight(&self) -> f32 {
+        self.weights.iter().sum()
+    }
+
+    /// Uploads the kernel into `texture` (resizing it if necessary) so that it can be swapped at runtime
+    pub fn upload(&self, gl: &WebGl2RenderingContext, texture: &WebGlTexture) {
+        let size = self.size() as i32;
+        let data = Float32Array::from(self.weights.as_slice());
+
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(texture));
+        gl.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_array_buffer_view(
+            WebGl2RenderingContext::TEXTURE_2D,
+            0,
+            WebGl2RenderingContext::R32F as i32,
+            size,
+            size,
+            0,
+            WebGl2RenderingContext::RED,
+            WebGl2RenderingContext::FLOAT,
+            Some(&data),
+        )
+        .expect("Should be able to upload neighborhood kernel texture data");
+        for parameter in [
+            WebGl2RenderingContext::TEXTURE_MIN_FILTER,
+            WebGl2RenderingContext::TEXTURE_MAG_FILTER,
+        ] {
+            gl.tex_parameteri(
+                WebGl2RenderingContext::TEXTURE_2D,
+                parameter,
+                WebGl2RenderingContext::NEAREST as i32,
+            );
+        }
+        for parameter in [
+            WebGl2RenderingContext::TEXTURE_WRAP_S,
+            WebGl2RenderingContext::TEXTURE_WRAP_T,
+        ] {
+            gl.tex_parameteri(
+                WebGl2RenderingContext::TEXTURE_2D,
+                parameter,
+                WebGl2RenderingContext::CLAMP_TO_EDGE as i32,
+            );
+        }
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, None);
+    }
+
+    /// Creates a link for a texture containing this kernel
+    pub fn texture_link<TextureId: Id>(&self, texture_id: TextureId) -> TextureLink<TextureId> {
+        let kernel = self.clone();
+        TextureLink::new(
+            texture_id,
+            move |ctx: &TextureCreateContext| -> WebGlTexture {
+                let gl = ctx.gl();
+                let texture = gl
+                    .create_texture()
+                    .expect("Should be able to create neighborhood kernel texture");
+                kernel.upload(gl, &texture);
+                texture
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NeighborhoodKernel;
+    use crate::NeighborhoodShape;
+
+    #[test]
+    fn generates_shaped_kernels() {
+        let moore = NeighborhoodKernel::new(NeighborhoodShape::Moore, 5);
+        assert_eq!(moore.size(), 11);
+        assert_eq!(moore.total_weight(), 120.0);
+        assert_eq!(moore.weight(0, 0), 0.0);
+        assert_eq!(moore.with_center().total_weight(), 121.0);
+
+        let von_neumann = NeighborhoodKernel::new(NeighborhoodShape::VonNeumann, 1);
+        assert_eq!(
+            von_neumann.weights(),
+            &[0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0]
+        );
+
+        let circular = NeighborhoodKernel::new(NeighborhoodShape::Circular, 2);
+        assert_eq!(circular.weight(2, 0), 1.0);
+        assert_eq!(circular.weight(2, 1), 1.0);
+        assert_eq!(circular.weight(2, 2), 0.0);
+        assert_eq!(circular.weight(3, 0), 0.0);
+    }
+}
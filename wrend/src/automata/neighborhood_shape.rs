@@ -0,0 +1,23 @@
+/// Which cells within a [crate::NeighborhoodKernel]'s radius count as neighbors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NeighborhoodShape {
+    /// Every cell in the surrounding square (Chebyshev distance)
+    #[default]
+    Moore,
+    /// Cells in a diamond around the center (Manhattan distance)
+    VonNeumann,
+    /// Cells whose centers lie within a circle around the center (Euclidean distance)
+    Circular,
+}
+
+impl NeighborhoodShape {
+    /// Whether the cell at offset `(dx, dy)` from the center is within `radius`
+    pub fn contains(&self, dx: i32, dy: i32, radius: u32) -> bool {
+        let radius = radius as i32;
+        match self {
+            NeighborhoodShape::Moore => dx.abs() <= radius && dy.abs() <= radius,
+            NeighborhoodShape::VonNeumann => dx.abs() + dy.abs() <= radius,
+            NeighborhoodShape::Circular => dx * dx + dy * dy <= radius * radius + radius,
+        }
+    }
+}
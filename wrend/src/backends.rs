@@ -0,0 +1,3 @@
+mod web_gl2_backend;
+
+pub(crate) use web_gl2_backend::*;
@@ -0,0 +1,152 @@
+use crate::{RendererBackend, RendererBackendError, ShaderCache, ShaderType};
+use js_sys::Array;
+use wasm_bindgen::JsValue;
+use web_sys::{
+    WebGl2RenderingContext, WebGlProgram, WebGlShader, WebGlTransformFeedback,
+    WebGlVertexArrayObject,
+};
+
+/// A [RendererBackend] that hands out the WebGL handles stored in [crate::RendererData]
+pub(crate) type WebGlHandlesBackend = dyn RendererBackend<
+    Shader = WebGlShader,
+    Program = WebGlProgram,
+    VertexArray = WebGlVertexArrayObject,
+    TransformFeedback = WebGlTransformFeedback,
+>;
+
+/// The [RendererBackend] used by [crate::RendererDataBuilder]: makes its calls on a
+/// `WebGl2RenderingContext`, reusing shaders and programs from a [ShaderCache] when one is set
+#[derive(Debug, Clone)]
+pub(crate) struct WebGl2Backend {
+    gl: WebGl2RenderingContext,
+    shader_cache: Option<ShaderCache>,
+}
+
+impl WebGl2Backend {
+    pub(crate) fn new(gl: WebGl2RenderingContext, shader_cache: Option<ShaderCache>) -> Self {
+        Self { gl, shader_cache }
+    }
+}
+
+impl RendererBackend for WebGl2Backend {
+    type Shader = WebGlShader;
+    type Program = WebGlProgram;
+    type VertexArray = WebGlVertexArrayObject;
+    type TransformFeedback = WebGlTransformFeedback;
+
+    fn compile_shader(
+        &self,
+        shader_type: ShaderType,
+        source: &str,
+    ) -> Result<Self::Shader, RendererBackendError> {
+        let gl = &self.gl;
+        let hash = ShaderCache::source_hash(shader_type, source);
+        if let Some(shader) = self
+            .shader_cache
+            .as_ref()
+            .and_then(|shader_cache| shader_cache.shader(gl, hash))
+        {
+            return Ok(shader);
+        }
+
+        let shader = gl
+            .create_shader(shader_type.into())
+            .ok_or(RendererBackendError::NoneReturned)?;
+
+        gl.shader_source(&shader, source);
+        gl.compile_shader(&shader);
+
+        // sources known to compile skip the status query, which blocks until compilation has finished
+        let is_known_to_compile = self
+            .shader_cache
+            .as_ref()
+            .is_some_and(|shader_cache| shader_cache.is_compiled(hash));
+        if is_known_to_compile
+            || gl
+                .get_shader_parameter(&shader, WebGl2RenderingContext::COMPILE_STATUS)
+                .as_bool()
+                .unwrap_or(false)
+        {
+            if let Some(shader_cache) = &self.shader_cache {
+                shader_cache.insert_shader(hash, shader.clone());
+            }
+            Ok(shader)
+        } else {
+            match gl.get_shader_info_log(&shader) {
+                Some(info_log) => Err(RendererBackendError::KnownError(info_log)),
+                None => Err(RendererBackendError::UnknownError),
+            }
+        }
+    }
+
+    fn link_program(
+        &self,
+        vertex_shader: &Self::Shader,
+        fragment_shader: &Self::Shader,
+        attribute_locations: &[(String, u32)],
+        transform_feedback_varyings: &[String],
+        cache_key: u64,
+    ) -> Result<Self::Program, RendererBackendError> {
+        let gl = &self.gl;
+        if let Some(program) = self
+            .shader_cache
+            .as_ref()
+            .and_then(|shader_cache| shader_cache.program(gl, cache_key))
+        {
+            return Ok(program);
+        }
+
+        let webgl_program = gl
+            .create_program()
+            .ok_or(RendererBackendError::NoneReturned)?;
+
+        for (attribute_name, attribute_location) in attribute_locations {
+            gl.bind_attrib_location(&webgl_program, *attribute_location, attribute_name);
+        }
+
+        gl.attach_shader(&webgl_program, vertex_shader);
+        gl.attach_shader(&webgl_program, fragment_shader);
+
+        if !transform_feedback_varyings.is_empty() {
+            let varyings: Array = transform_feedback_varyings
+                .iter()
+                .map(|varying| JsValue::from_str(varying))
+                .collect();
+            gl.transform_feedback_varyings(
+                &webgl_program,
+                &varyings,
+                WebGl2RenderingContext::INTERLEAVED_ATTRIBS,
+            )
+        }
+
+        gl.link_program(&webgl_program);
+
+        if gl
+            .get_program_parameter(&webgl_program, WebGl2RenderingContext::LINK_STATUS)
+            .as_bool()
+            .unwrap_or(false)
+        {
+            if let Some(shader_cache) = &self.shader_cache {
+                shader_cache.insert_program(cache_key, webgl_program.clone());
+            }
+            Ok(webgl_program)
+        } else {
+            match gl.get_program_info_log(&webgl_program) {
+                Some(info_log) => Err(RendererBackendError::KnownError(info_log)),
+                None => Err(RendererBackendError::UnknownError),
+            }
+        }
+    }
+
+    fn create_vertex_array(&self) -> Result<Self::VertexArray, RendererBackendError> {
+        self.gl
+            .create_vertex_array()
+            .ok_or(RendererBackendError::NoneReturned)
+    }
+
+    fn create_transform_feedback(&self) -> Result<Self::TransformFeedback, RendererBackendError> {
+        self.gl
+            .create_transform_feedback()
+            .ok_or(RendererBackendError::NoneReturned)
+    }
+}
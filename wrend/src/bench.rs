@@ -0,0 +1,7 @@
+mod bench_comparison;
+mod bench_result;
+mod bench_runner;
+
+pub use bench_comparison::*;
+pub use bench_result::*;
+pub use bench_runner::*;
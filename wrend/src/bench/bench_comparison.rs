@@ -0,0 +1,45 @@
+use crate::BenchResult;
+
+/// Compares a wrend operation against a hand-written `web-sys` equivalent
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub struct BenchComparison {
+    name: String,
+    wrend: BenchResult,
+    raw: BenchResult,
+}
+
+impl BenchComparison {
+    pub fn new(name: impl Into<String>, wrend: BenchResult, raw: BenchResult) -> Self {
+        Self {
+            name: name.into(),
+            wrend,
+            raw,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn wrend(&self) -> &BenchResult {
+        &self.wrend
+    }
+
+    pub fn raw(&self) -> &BenchResult {
+        &self.raw
+    }
+
+    /// Extra time (in ms) wrend spends per call, compared by median
+    pub fn overhead_ms(&self) -> f64 {
+        self.wrend.median_ms() - self.raw.median_ms()
+    }
+
+    /// How many times slower the wrend call is than the raw call (1.0 means no overhead)
+    pub fn overhead_ratio(&self) -> f64 {
+        let raw = self.raw.median_ms();
+        if raw == 0.0 {
+            return 1.0;
+        }
+        self.wrend.median_ms() / raw
+    }
+}
@@ -0,0 +1,73 @@
+/// Timing samples collected for a single benchmarked operation.
+///
+/// Each sample is the average time (in ms) of one call, measured over a batch of calls.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub struct BenchResult {
+    name: String,
+    samples: Vec<f64>,
+}
+
+impl BenchResult {
+    pub fn new(name: impl Into<String>, samples: Vec<f64>) -> Self {
+        Self {
+            name: name.into(),
+            samples,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn samples(&self) -> &[f64] {
+        &self.samples
+    }
+
+    pub fn mean_ms(&self) -> f64 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        self.samples.iter().sum::<f64>() / self.samples.len() as f64
+    }
+
+    /// Less sensitive than the mean to garbage collection pauses and other outliers
+    pub fn median_ms(&self) -> f64 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        let mut sorted = self.samples.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let middle = sorted.len() / 2;
+        if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2.0
+        } else {
+            sorted[middle]
+        }
+    }
+
+    pub fn min_ms(&self) -> f64 {
+        self.samples.iter().copied().reduce(f64::min).unwrap_or(0.0)
+    }
+
+    pub fn max_ms(&self) -> f64 {
+        self.samples.iter().copied().reduce(f64::max).unwrap_or(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BenchResult;
+
+    #[test]
+    fn summarizes_samples() {
+        let result = BenchResult::new("uniforms", vec![4.0, 1.0, 3.0, 2.0]);
+        assert_eq!(result.mean_ms(), 2.5);
+        assert_eq!(result.median_ms(), 2.5);
+        assert_eq!(result.min_ms(), 1.0);
+        assert_eq!(result.max_ms(), 4.0);
+
+        let empty = BenchResult::new("empty", Vec::new());
+        assert_eq!(empty.mean_ms(), 0.0);
+        assert_eq!(empty.median_ms(), 0.0);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{BenchComparison, BenchResult};
+use log::info;
+use web_sys::window;
+
+/// Measures wrend's per-frame overhead against raw `web-sys` calls.
+///
+/// `performance.now()` is too coarse to time a single WebGL call, so every sample
+/// times a whole batch of calls and records the average.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct BenchRunner {
+    warmup_batches: usize,
+    samples: usize,
+    batch_size: usize,
+    results: Vec<BenchComparison>,
+}
+
+impl Default for BenchRunner {
+    fn default() -> Self {
+        Self {
+            warmup_batches: Self::DEFAULT_WARMUP_BATCHES,
+            samples: Self::DEFAULT_SAMPLES,
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+            results: Vec::new(),
+        }
+    }
+}
+
+impl BenchRunner {
+    pub const DEFAULT_WARMUP_BATCHES: usize = 5;
+    pub const DEFAULT_SAMPLES: usize = 50;
+    pub const DEFAULT_BATCH_SIZE: usize = 100;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_warmup_batches(&mut self, warmup_batches: usize) -> &mut Self {
+        self.warmup_batches = warmup_batches;
+        self
+    }
+
+    pub fn set_samples(&mut self, samples: usize) -> &mut Self {
+        self.samples = samples;
+        self
+    }
+
+    pub fn set_batch_size(&mut self, batch_size: usize) -> &mut Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Times `f`, returning the average cost of a single call for each sample
+    pub fn measure(&self, name: impl Into<String>, mut f: impl FnMut()) -> BenchResult {
+        for _ in 0..self.warmup_batches * self.batch_size {
+            f();
+        }
+
+        let performance = window().unwrap().performance().unwrap();
+        let samples = (0..self.samples)
+            .map(|_| {
+                let start = performance.now();
+                for _ in 0..self.batch_size {
+                    f();
+                }
+                (performance.now() - start) / self.batch_size as f64
+            })
+            .collect();
+
+        BenchResult::new(name, samples)
+    }
+
+    /// Measures a wrend operation and its raw `web-sys` equivalent, then stores and logs the comparison
+    pub fn compare(
+        &mut self,
+        name: impl Into<String>,
+        wrend: impl FnMut(),
+        raw: impl FnMut(),
+    ) -> &BenchComparison {
+        let name = name.into();
+        let wrend = self.measure(format!("{name} (wrend)"), wrend);
+        let raw = self.measure(format!("{name} (raw)"), raw);
+        let comparison = BenchComparison::new(name, wrend, raw);
+
+        info!(
+            "{}: wrend {:.5}ms, raw {:.5}ms, overhead {:.5}ms ({:.2}x)",
+            comparison.name(),
+            comparison.wrend().median_ms(),
+            comparison.raw().median_ms(),
+            comparison.overhead_ms(),
+            comparison.overhead_ratio()
+        );
+
+        self.results.push(comparison);
+        self.results.last().unwrap()
+    }
+
+    pub fn results(&self) -> &[BenchComparison] {
+        &self.results
+    }
+}
@@ -0,0 +1,7 @@
+mod boids;
+mod boids_settings;
+mod boids_shaders;
+
+pub use boids::*;
+pub use boids_settings::*;
+pub use boids_shaders::*;
@@ -0,0 +1,498 @@
+use crate::{
+    AttributeCreateContext, BoidsSettings, BufferCreateContext, BufferPairLink, Id, IdName,
+    ProgramLink, RendererData, Rng, TextureCreateContext, TextureLink, TransformFeedbackLink,
+};
+use js_sys::Float32Array;
+use log::warn;
+use std::f32::consts::TAU;
+use web_sys::WebGl2RenderingContext;
+
+/// A flock of boids (Reynolds, 1987) simulated entirely on the GPU with transform feedback.
+///
+/// Each boid's position and velocity are stored as 4 interleaved floats in a [BufferPairLink], whose
+/// buffers are ping-ponged every step. Before each step, the current buffer is copied into an
+/// `RGBA32F` texture (on the GPU, through `PIXEL_UNPACK_BUFFER`), which the update shader samples to
+/// find each boid's neighbors.
+///
+/// Its programs, buffers, texture and transform feedback are added with
+/// [crate::RendererDataBuilder::add_boids]; it is advanced with [Boids::step] and drawn with
+/// [Boids::draw]. Cohesion, alignment and separation can be
+/// tweaked at any time with [Boids::set_settings].
+#[derive(Debug, Clone)]
+pub struct Boids<
+    ProgramId: Id,
+    BufferId: Id,
+    AttributeId: Id,
+    TextureId: Id,
+    TransformFeedbackId: Id,
+    VertexArrayObjectId: Id,
+> {
+    update_program_id: ProgramId,
+    draw_program_id: ProgramId,
+    buffer_ids: (BufferId, BufferId),
+    vao_ids: (VertexArrayObjectId, VertexArrayObjectId),
+    position_attribute_id: AttributeId,
+    velocity_attribute_id: AttributeId,
+    texture_id: TextureId,
+    transform_feedback_id: TransformFeedbackId,
+    count: u32,
+    seed: u64,
+    settings: BoidsSettings,
+}
+
+impl<
+        ProgramId: Id,
+        BufferId: Id,
+        AttributeId: Id + IdName,
+        TextureId: Id,
+        TransformFeedbackId: Id,
+        VertexArrayObjectId: Id,
+    > Boids<ProgramId, BufferId, AttributeId, TextureId, TransformFeedbackId, VertexArrayObjectId>
+{
+    /// Width of the position texture. Flocks are padded to fill whole rows of it.
+    pub const TEXTURE_WIDTH: u32 = 64;
+
+    /// Floats stored per boid: position and velocity
+    const COMPONENTS: u32 = 4;
+
+    /// Transform feedback outputs of the update program, in the same layout as the buffers
+    const VARYINGS: [&'static str; 2] = ["v_position", "v_velocity"];
+
+    /// Describes a flock of at least `count` boids with random positions and headings.
+    ///
+    /// `program_ids` are the update and draw programs, and `attribute_ids` are the position and
+    /// velocity attributes, which both programs read at locations 0 and 1.
+    pub fn new(
+        program_ids: (ProgramId, ProgramId),
+        buffer_ids: (BufferId, BufferId),
+        vao_ids: (VertexArrayObjectId, VertexArrayObjectId),
+        attribute_ids: (AttributeId, AttributeId),
+        texture_id: TextureId,
+        transform_feedback_id: TransformFeedbackId,
+        count: u32,
+    ) -> Self {
+        Self {
+            update_program_id: program_ids.0,
+            draw_program_id: program_ids.1,
+            buffer_ids,
+            vao_ids,
+            position_attribute_id: attribute_ids.0,
+            velocity_attribute_id: attribute_ids.1,
+            texture_id,
+            transform_feedback_id,
+            count: Self::padded_count(count),
+            seed: 0,
+            settings: BoidsSettings::default(),
+        }
+    }
+
+    /// Seeds the starting positions and headings. Must be called before the buffers are created.
+    pub fn set_seed(&mut self, seed: u64) -> &mut Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Number of boids in a flock created with `count`: a whole number of rows of the position texture
+    pub fn padded_count(count: u32) -> u32 {
+        count.max(1).div_ceil(Self::TEXTURE_WIDTH) * Self::TEXTURE_WIDTH
+    }
+
+    /// Interleaved positions and velocities, spread evenly over the world with random headings
+    pub(crate) fn initial_state(count: u32, seed: u64, max_speed: f32) -> Vec<f32> {
+        let mut rng = Rng::new(seed);
+        (0..count)
+            .flat_map(|_| {
+                let [x, y] = [rng.range_f32(-1.0, 1.0), rng.range_f32(-1.0, 1.0)];
+                let angle = rng.range_f32(0.0, TAU);
+                let speed = max_speed * rng.range_f32(0.25, 1.0);
+                [x, y, angle.cos() * speed, angle.sin() * speed]
+            })
+            .collect()
+    }
+
+    pub fn update_program_id(&self) -> &ProgramId {
+        &self.update_program_id
+    }
+
+    pub fn draw_program_id(&self) -> &ProgramId {
+        &self.draw_program_id
+    }
+
+    /// The id the buffer pair is looked up by (see [crate::RendererData::buffer_pair]), whose read
+    /// buffer holds the current positions and velocities, for drawing the flock yourself
+    pub fn pair_id(&self) -> &BufferId {
+        &self.buffer_ids.0
+    }
+
+    /// Texture holding the positions and velocities the last step started from
+    pub fn texture_id(&self) -> &TextureId {
+        &self.texture_id
+    }
+
+    pub fn transform_feedback_id(&self) -> &TransformFeedbackId {
+        &self.transform_feedback_id
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Width and height of the position texture, with one texel per boid
+    pub fn texture_size(&self) -> [u32; 2] {
+        [Self::TEXTURE_WIDTH, self.count / Self::TEXTURE_WIDTH]
+    }
+
+    pub fn settings(&self) -> BoidsSettings {
+        self.settings
+    }
+
+    /// Can be changed at any time: takes effect on the next step. The initial speeds use the
+    /// settings at the time the buffers are created.
+    pub fn set_settings(&mut self, settings: BoidsSettings) -> &mut Self {
+        self.settings = settings;
+        self
+    }
+
+    /// The update program, which writes each boid's next state with transform feedback, and the
+    /// draw program, both with the position and velocity attributes fixed at locations 0 and 1
+    pub fn program_links<VertexShaderId: Id, FragmentShaderId: Id>(
+        &self,
+        (update_vertex_shader_id, update_fragment_shader_id): (VertexShaderId, FragmentShaderId),
+        (draw_vertex_shader_id, draw_fragment_shader_id): (VertexShaderId, FragmentShaderId),
+    ) -> [ProgramLink<ProgramId, VertexShaderId, FragmentShaderId>; 2] {
+        let program_link =
+            |program_id: &ProgramId, vertex_shader_id, fragment_shader_id, varyings: &[&str]| {
+                let mut builder = ProgramLink::builder();
+                builder
+                    .set_program_id(program_id.clone())
+                    .set_vertex_shader_id(vertex_shader_id)
+                    .set_fragment_shader_id(fragment_shader_id)
+                    .set_transform_feedback_varyings(
+                        varyings.iter().map(ToString::to_string).collect::<Vec<_>>(),
+                    )
+                    .add_attribute_location(self.position_attribute_id.name(), 0)
+                    .add_attribute_location(self.velocity_attribute_id.name(), 1);
+                builder
+                    .build()
+                    .expect("Boids program links should have every id set")
+            };
+        [
+            program_link(
+                &self.update_program_id,
+                update_vertex_shader_id,
+                update_fragment_shader_id,
+                &Self::VARYINGS,
+            ),
+            program_link(
+                &self.draw_program_id,
+                draw_vertex_shader_id,
+                draw_fragment_shader_id,
+                &[],
+            ),
+        ]
+    }
+
+    /// Both buffers, filled with the initial state, each with a VAO reading positions and velocities
+    pub fn buffer_pair_link(&self) -> BufferPairLink<BufferId, VertexArrayObjectId, AttributeId> {
+        let state = Self::initial_state(self.count, self.seed, self.settings.max_speed());
+        let mut buffer_pair_link = BufferPairLink::new(
+            self.buffer_ids.clone(),
+            self.vao_ids.clone(),
+            move |ctx: &BufferCreateContext| {
+                let gl = ctx.gl();
+                let buffer = gl
+                    .create_buffer()
+                    .expect("Should be able to create a buffer for Boids");
+                gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, Some(&buffer));
+                gl.buffer_data_with_array_buffer_view(
+                    WebGl2RenderingContext::ARRAY_BUFFER,
+                    &Float32Array::from(state.as_slice()),
+                    WebGl2RenderingContext::DYNAMIC_COPY,
+                );
+                gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, None);
+                buffer
+            },
+        );
+        for (attribute_id, offset) in [
+            (self.position_attribute_id.clone(), 0),
+            (self.velocity_attribute_id.clone(), 8),
+        ] {
+            buffer_pair_link.add_attribute(attribute_id, move |ctx: &AttributeCreateContext| {
+                ctx.gl().vertex_attrib_pointer_with_i32(
+                    ctx.attribute_location().get(),
+                    2,
+                    WebGl2RenderingContext::FLOAT,
+                    false,
+                    (Self::COMPONENTS * 4) as i32,
+                    offset,
+                );
+            });
+        }
+        buffer_pair_link
+    }
+
+    /// The `RGBA32F` texture that the current buffer is copied into before each step
+    pub fn texture_link(&self) -> TextureLink<TextureId> {
+        let [width, height] = self.texture_size();
+        TextureLink::new(
+            self.texture_id.clone(),
+            move |ctx: &TextureCreateContext| {
+                let gl = ctx.gl();
+                let texture = gl
+                    .create_texture()
+                    .expect("Should be able to create a texture for Boids");
+                gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&texture));
+                gl.tex_storage_2d(
+                    WebGl2RenderingContext::TEXTURE_2D,
+                    1,
+                    WebGl2RenderingContext::RGBA32F,
+                    width as i32,
+                    height as i32,
+                );
+                for parameter in [
+                    WebGl2RenderingContext::TEXTURE_MIN_FILTER,
+                    WebGl2RenderingContext::TEXTURE_MAG_FILTER,
+                ] {
+                    gl.tex_parameteri(
+                        WebGl2RenderingContext::TEXTURE_2D,
+                        parameter,
+                        WebGl2RenderingContext::NEAREST as i32,
+                    );
+                }
+                gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, None);
+                texture
+            },
+        )
+    }
+
+    pub fn transform_feedback_link(&self) -> TransformFeedbackLink<TransformFeedbackId> {
+        TransformFeedbackLink::new(self.transform_feedback_id.clone())
+    }
+
+    /// Moves every boid forward by `dt` seconds, then swaps its buffer pair
+    pub fn step<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        UniformId: Id + IdName,
+        FramebufferId: Id,
+        UserCtx: Clone,
+    >(
+        &self,
+        renderer_data: &RendererData<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+        dt: f32,
+    ) -> &Self {
+        let Some(buffer_pair) = renderer_data.buffer_pair(self.pair_id()) else {
+            warn!("Could not find Boids buffer pair {:?}", self.pair_id());
+            return self;
+        };
+        let (read_buffer_id, read_vao_id) = buffer_pair.read();
+        let (write_buffer_id, _) = buffer_pair.write();
+        let (
+            Some(read_buffer),
+            Some(write_buffer),
+            Some(read_vao),
+            Some(texture),
+            Some(transform_feedback),
+            Some(program),
+        ) = (
+            renderer_data.buffer(read_buffer_id),
+            renderer_data.buffer(write_buffer_id),
+            renderer_data.vao(read_vao_id),
+            renderer_data.webgl_texture(self.texture_id()),
+            renderer_data.transform_feedback(self.transform_feedback_id()),
+            renderer_data.program(self.update_program_id()),
+        )
+        else {
+            warn!(
+                "Could not find every Boids resource for {:?}",
+                self.pair_id()
+            );
+            return self;
+        };
+
+        let gl = renderer_data.gl();
+        let [width, height] = self.texture_size();
+        gl.bind_buffer(
+            WebGl2RenderingContext::PIXEL_UNPACK_BUFFER,
+            Some(read_buffer.webgl_buffer()),
+        );
+        let saved_units = renderer_data.save_texture_units([0]);
+        gl.active_texture(WebGl2RenderingContext::TEXTURE0);
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(texture));
+        if let Err(err) = gl.tex_sub_image_2d_with_i32_and_i32_and_u32_and_type_and_i32(
+            WebGl2RenderingContext::TEXTURE_2D,
+            0,
+            0,
+            0,
+            width as i32,
+            height as i32,
+            WebGl2RenderingContext::RGBA,
+            WebGl2RenderingContext::FLOAT,
+            0,
+        ) {
+            warn!("Could not copy boids into their position texture: {err:?}");
+        }
+        gl.bind_buffer(WebGl2RenderingContext::PIXEL_UNPACK_BUFFER, None);
+
+        let location = |name| gl.get_uniform_location(program, name);
+        let settings = self.settings();
+        gl.use_program(Some(program));
+        gl.uniform1i(location("u_boids").as_ref(), 0);
+        gl.uniform1i(location("u_count").as_ref(), self.count() as i32);
+        gl.uniform1i(
+            location("u_max_neighbor_samples").as_ref(),
+            settings.max_neighbor_samples() as i32,
+        );
+        gl.uniform1f(location("u_dt").as_ref(), dt);
+        for (name, value) in [
+            ("u_cohesion", settings.cohesion()),
+            ("u_alignment", settings.alignment()),
+            ("u_separation", settings.separation()),
+            ("u_perception_radius", settings.perception_radius()),
+            ("u_separation_radius", settings.separation_radius()),
+            ("u_max_speed", settings.max_speed()),
+        ] {
+            gl.uniform1f(location(name).as_ref(), value);
+        }
+
+        gl.bind_vertex_array(Some(read_vao));
+        gl.bind_transform_feedback(
+            WebGl2RenderingContext::TRANSFORM_FEEDBACK,
+            Some(transform_feedback),
+        );
+        gl.bind_buffer_base(
+            WebGl2RenderingContext::TRANSFORM_FEEDBACK_BUFFER,
+            0,
+            Some(write_buffer.webgl_buffer()),
+        );
+        gl.enable(WebGl2RenderingContext::RASTERIZER_DISCARD);
+        gl.begin_transform_feedback(WebGl2RenderingContext::POINTS);
+        gl.draw_arrays(WebGl2RenderingContext::POINTS, 0, self.count() as i32);
+        gl.end_transform_feedback();
+        gl.disable(WebGl2RenderingContext::RASTERIZER_DISCARD);
+        gl.bind_buffer_base(WebGl2RenderingContext::TRANSFORM_FEEDBACK_BUFFER, 0, None);
+        gl.bind_transform_feedback(WebGl2RenderingContext::TRANSFORM_FEEDBACK, None);
+        gl.bind_vertex_array(None);
+        saved_units.restore(gl);
+
+        buffer_pair.swap();
+        self
+    }
+
+    /// Draws every boid as a point into whatever framebuffer is currently bound
+    pub fn draw<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        UniformId: Id + IdName,
+        FramebufferId: Id,
+        UserCtx: Clone,
+    >(
+        &self,
+        renderer_data: &RendererData<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+    ) -> &Self {
+        let (Some(buffer_pair), Some(program)) = (
+            renderer_data.buffer_pair(self.pair_id()),
+            renderer_data.program(self.draw_program_id()),
+        ) else {
+            warn!(
+                "Could not find every Boids resource for {:?}",
+                self.pair_id()
+            );
+            return self;
+        };
+        let Some(vao) = renderer_data.vao(buffer_pair.read().1) else {
+            warn!("Could not find Boids VAO {:?}", buffer_pair.read().1);
+            return self;
+        };
+
+        let gl = renderer_data.gl();
+        gl.use_program(Some(program));
+        gl.uniform1f(
+            gl.get_uniform_location(program, "u_point_size").as_ref(),
+            self.settings().point_size(),
+        );
+        gl.bind_vertex_array(Some(vao));
+        gl.draw_arrays(WebGl2RenderingContext::POINTS, 0, self.count() as i32);
+        gl.bind_vertex_array(None);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Boids;
+
+    type TestBoids = Boids<String, String, String, String, String, String>;
+
+    #[test]
+    fn pads_flock_and_generates_state() {
+        assert_eq!(TestBoids::padded_count(0), 64);
+        assert_eq!(TestBoids::padded_count(64), 64);
+        assert_eq!(TestBoids::padded_count(1000), 1024);
+
+        let state = TestBoids::initial_state(64, 7, 0.5);
+        assert_eq!(state.len(), 256);
+        assert_eq!(state, TestBoids::initial_state(64, 7, 0.5));
+        for boid in state.chunks(4) {
+            assert!(boid[0].abs() <= 1.0 && boid[1].abs() <= 1.0);
+            assert!(boid[2].hypot(boid[3]) <= 0.5 + 1e-6);
+        }
+    }
+
+    #[test]
+    fn links_programs_with_fixed_attribute_locations() {
+        let boids = TestBoids::new(
+            ("update".to_string(), "draw".to_string()),
+            ("a".to_string(), "b".to_string()),
+            ("vao_a".to_string(), "vao_b".to_string()),
+            ("a_position".to_string(), "a_velocity".to_string()),
+            "boids".to_string(),
+            "boids".to_string(),
+            100,
+        );
+        assert_eq!(boids.count(), 128);
+        assert_eq!(boids.pair_id(), "a");
+
+        let [update, draw] = boids.program_links(
+            ("update_vertex".to_string(), "update_fragment".to_string()),
+            ("draw_vertex".to_string(), "draw_fragment".to_string()),
+        );
+        assert_eq!(update.program_id(), "update");
+        assert_eq!(
+            update.transform_feedback_varyings(),
+            ["v_position", "v_velocity"]
+        );
+        assert!(draw.transform_feedback_varyings().is_empty());
+        for link in [&update, &draw] {
+            assert_eq!(
+                link.attribute_locations(),
+                [("a_position".to_string(), 0), ("a_velocity".to_string(), 1)]
+            );
+        }
+        assert_eq!(draw.vertex_shader_id(), "draw_vertex");
+    }
+}
@@ -0,0 +1,115 @@
+/// Tuning parameters for [crate::Boids], all of which can be changed while the flock is running.
+///
+/// Distances and speeds are in world units: the world spans -1.0 to 1.0 on both axes and wraps around.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct BoidsSettings {
+    cohesion: f32,
+    alignment: f32,
+    separation: f32,
+    perception_radius: f32,
+    separation_radius: f32,
+    max_speed: f32,
+    max_neighbor_samples: u32,
+    point_size: f32,
+}
+
+impl Default for BoidsSettings {
+    fn default() -> Self {
+        Self {
+            cohesion: 1.0,
+            alignment: 2.0,
+            separation: 0.02,
+            perception_radius: 0.15,
+            separation_radius: 0.04,
+            max_speed: 0.4,
+            max_neighbor_samples: 256,
+            point_size: 4.0,
+        }
+    }
+}
+
+impl BoidsSettings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How strongly each boid steers toward the center of its neighbors
+    pub fn cohesion(&self) -> f32 {
+        self.cohesion
+    }
+
+    pub fn set_cohesion(&mut self, cohesion: f32) -> &mut Self {
+        self.cohesion = cohesion;
+        self
+    }
+
+    /// How strongly each boid matches the heading of its neighbors
+    pub fn alignment(&self) -> f32 {
+        self.alignment
+    }
+
+    pub fn set_alignment(&mut self, alignment: f32) -> &mut Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// How strongly each boid steers away from neighbors within the separation radius
+    pub fn separation(&self) -> f32 {
+        self.separation
+    }
+
+    pub fn set_separation(&mut self, separation: f32) -> &mut Self {
+        self.separation = separation;
+        self
+    }
+
+    /// How far away other boids count as neighbors
+    pub fn perception_radius(&self) -> f32 {
+        self.perception_radius
+    }
+
+    pub fn set_perception_radius(&mut self, perception_radius: f32) -> &mut Self {
+        self.perception_radius = perception_radius.max(0.0);
+        self
+    }
+
+    /// How close neighbors have to be before boids steer away from them
+    pub fn separation_radius(&self) -> f32 {
+        self.separation_radius
+    }
+
+    pub fn set_separation_radius(&mut self, separation_radius: f32) -> &mut Self {
+        self.separation_radius = separation_radius.max(0.0);
+        self
+    }
+
+    pub fn max_speed(&self) -> f32 {
+        self.max_speed
+    }
+
+    pub fn set_max_speed(&mut self, max_speed: f32) -> &mut Self {
+        self.max_speed = max_speed.max(0.0);
+        self
+    }
+
+    /// Most other boids each boid looks at per step. Larger flocks are sampled with a stride,
+    /// which keeps each step's cost linear in the size of the flock.
+    pub fn max_neighbor_samples(&self) -> u32 {
+        self.max_neighbor_samples
+    }
+
+    pub fn set_max_neighbor_samples(&mut self, max_neighbor_samples: u32) -> &mut Self {
+        self.max_neighbor_samples = max_neighbor_samples.max(1);
+        self
+    }
+
+    /// Size each boid is drawn at, in pixels
+    pub fn point_size(&self) -> f32 {
+        self.point_size
+    }
+
+    pub fn set_point_size(&mut self, point_size: f32) -> &mut Self {
+        self.point_size = point_size.max(1.0);
+        self
+    }
+}
@@ -0,0 +1,116 @@
+/// Transform feedback shader that moves each boid one step, reading every other boid's position
+/// and velocity from `u_boids` (one RGBA32F texel per boid: position in `xy`, velocity in `zw`)
+pub const BOIDS_UPDATE_VERTEX_SHADER: &str = r#"#version 300 es
+precision highp float;
+precision highp int;
+
+layout(location = 0) in vec2 a_position;
+layout(location = 1) in vec2 a_velocity;
+
+uniform highp sampler2D u_boids;
+uniform int u_count;
+uniform int u_max_neighbor_samples;
+uniform float u_dt;
+uniform float u_cohesion;
+uniform float u_alignment;
+uniform float u_separation;
+uniform float u_perception_radius;
+uniform float u_separation_radius;
+uniform float u_max_speed;
+
+out vec2 v_position;
+out vec2 v_velocity;
+
+// shortest offset between two points in a world that wraps around at -1.0 and 1.0
+vec2 wrapped_offset(vec2 from, vec2 to) {
+    vec2 offset = to - from;
+    return offset - 2.0 * round(offset / 2.0);
+}
+
+void main() {
+    int width = textureSize(u_boids, 0).x;
+    int stride = max(1, u_count / u_max_neighbor_samples);
+
+    vec2 center = vec2(0.0);
+    vec2 heading = vec2(0.0);
+    vec2 away = vec2(0.0);
+    float neighbors = 0.0;
+
+    for (int i = gl_VertexID % stride; i < u_count; i += stride) {
+        if (i == gl_VertexID) {
+            continue;
+        }
+        vec4 other = texelFetch(u_boids, ivec2(i % width, i / width), 0);
+        vec2 offset = wrapped_offset(a_position, other.xy);
+        float dist = length(offset);
+        if (dist < u_perception_radius) {
+            center += offset;
+            heading += other.zw;
+            neighbors += 1.0;
+            if (dist < u_separation_radius && dist > 0.0) {
+                away -= offset / (dist * dist);
+            }
+        }
+    }
+
+    vec2 velocity = a_velocity;
+    if (neighbors > 0.0) {
+        vec2 steering = center / neighbors * u_cohesion
+            + (heading / neighbors - velocity) * u_alignment
+            + away * u_separation;
+        velocity += steering * u_dt;
+    }
+    float speed = length(velocity);
+    if (speed > u_max_speed) {
+        velocity *= u_max_speed / speed;
+    }
+
+    v_position = mod(a_position + velocity * u_dt + 1.0, 2.0) - 1.0;
+    v_velocity = velocity;
+}
+"#;
+
+/// Fragment shader for [BOIDS_UPDATE_VERTEX_SHADER], which never runs (rasterization is discarded)
+pub const BOIDS_UPDATE_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+out vec4 out_color;
+
+void main() {
+    out_color = vec4(0.0);
+}
+"#;
+
+/// Draws each boid as a point, colored by its heading
+pub const BOIDS_DRAW_VERTEX_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+layout(location = 0) in vec2 a_position;
+layout(location = 1) in vec2 a_velocity;
+
+uniform float u_point_size;
+
+out vec3 v_color;
+
+void main() {
+    float angle = atan(a_velocity.y, a_velocity.x);
+    v_color = 0.5 + 0.5 * cos(angle + vec3(0.0, 2.094, 4.189));
+    gl_PointSize = u_point_size;
+    gl_Position = vec4(a_position, 0.0, 1.0);
+}
+"#;
+
+/// Fragment shader for [BOIDS_DRAW_VERTEX_SHADER], which rounds off each point
+pub const BOIDS_DRAW_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+in vec3 v_color;
+out vec4 out_color;
+
+void main() {
+    if (length(gl_PointCoord - 0.5) > 0.5) {
+        discard;
+    }
+    out_color = vec4(v_color, 1.0);
+}
+"#;
@@ -3,15 +3,35 @@ mod buffer_create_callback;
 mod buffer_create_callback_js;
 mod buffer_create_context;
 mod buffer_create_context_js;
+mod buffer_data;
+#[cfg(feature = "js")]
 mod buffer_js;
 mod buffer_link;
+#[cfg(feature = "js")]
 mod buffer_link_js;
+mod buffer_pair;
+mod buffer_pair_link;
+#[cfg(feature = "js")]
+mod buffer_pair_link_js;
+mod ring_buffer;
+mod vertex_format;
+mod vertex_layout;
 
 pub use buffer::*;
 pub use buffer_create_callback::*;
 pub use buffer_create_callback_js::*;
 pub use buffer_create_context::*;
 pub use buffer_create_context_js::*;
+pub use buffer_data::*;
+#[cfg(feature = "js")]
 pub use buffer_js::*;
 pub use buffer_link::*;
+#[cfg(feature = "js")]
 pub use buffer_link_js::*;
+pub use buffer_pair::*;
+pub use buffer_pair_link::*;
+#[cfg(feature = "js")]
+pub use buffer_pair_link_js::*;
+pub use ring_buffer::*;
+pub use vertex_format::*;
+pub use vertex_layout::*;
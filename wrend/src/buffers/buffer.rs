@@ -1,6 +1,9 @@
-use crate::{BufferJs, BufferJsInner, Id};
+use crate::Id;
+#[cfg(feature = "js")]
+use crate::{BufferJs, BufferJsInner};
 use std::fmt::Debug;
 use std::hash::Hash;
+#[cfg(feature = "js")]
 use wasm_bindgen::JsValue;
 use web_sys::WebGlBuffer;
 
@@ -50,6 +53,7 @@ impl<BufferId: Id> PartialEq for Buffer<BufferId> {
 
 impl<BufferId: Id> Eq for Buffer<BufferId> {}
 
+#[cfg(feature = "js")]
 impl From<BufferJsInner> for JsValue {
     fn from(buffer: BufferJsInner) -> Self {
         let js_buffer: BufferJs = buffer.into();
@@ -0,0 +1,82 @@
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Generates `len` values for an initial buffer (e.g. particle positions) by calling `f` with each index.
+///
+/// With the `parallel` feature enabled, values are generated on rayon's thread pool. On wasm, this is
+/// only multi-threaded when built with the `atomics` target feature and a pool has been initialized
+/// from JavaScript (e.g. with `wasm-bindgen-rayon`'s `initThreadPool`); otherwise it runs on the current thread.
+///
+/// Because `f` may be called from several threads, it can't use `js_sys::Math::random`:
+/// use a seeded [crate::Rng] instead (see [generate_buffer_data_chunked]).
+pub fn generate_buffer_data<T: Send>(len: usize, f: impl Fn(usize) -> T + Sync + Send) -> Vec<T> {
+    #[cfg(feature = "parallel")]
+    {
+        (0..len).into_par_iter().map(f).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        (0..len).map(f).collect()
+    }
+}
+
+/// Like [generate_buffer_data], but fills the buffer in chunks of `chunk_size` values.
+///
+/// `f` receives the index of the chunk and the slice to fill, which is useful for seeding
+/// one [crate::Rng] per chunk so that the output is the same no matter how many threads were used.
+pub fn generate_buffer_data_chunked<T: Send + Clone + Default>(
+    len: usize,
+    chunk_size: usize,
+    f: impl Fn(usize, &mut [T]) + Sync + Send,
+) -> Vec<T> {
+    let mut data = vec![T::default(); len];
+    let chunk_size = chunk_size.max(1);
+
+    #[cfg(feature = "parallel")]
+    data.par_chunks_mut(chunk_size)
+        .enumerate()
+        .for_each(|(i, chunk)| f(i, chunk));
+    #[cfg(not(feature = "parallel"))]
+    data.chunks_mut(chunk_size)
+        .enumerate()
+        .for_each(|(i, chunk)| f(i, chunk));
+
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate_buffer_data, generate_buffer_data_chunked};
+    use crate::Rng;
+
+    #[test]
+    fn generates_same_data_regardless_of_chunk_size() {
+        let expected = generate_buffer_data(10, |i| i * 2);
+        assert_eq!(expected, vec![0, 2, 4, 6, 8, 10, 12, 14, 16, 18]);
+
+        // a chunk size of 0 is treated as 1
+        for chunk_size in [0, 1, 3, 4, 10, 16] {
+            let data = generate_buffer_data_chunked(10, chunk_size, |chunk_index, chunk| {
+                for (offset, value) in chunk.iter_mut().enumerate() {
+                    *value = (chunk_index * chunk_size.max(1) + offset) * 2;
+                }
+            });
+            assert_eq!(data, expected, "chunk_size: {chunk_size}");
+        }
+    }
+
+    #[test]
+    fn seeds_each_chunk_from_its_index() {
+        let fill = |i: usize, chunk: &mut [f32]| {
+            let mut rng = Rng::new(i as u64);
+            chunk.iter_mut().for_each(|value| *value = rng.next_f32());
+        };
+        let data = generate_buffer_data_chunked(10, 4, fill);
+        assert_eq!(data.len(), 10);
+        assert_eq!(data, generate_buffer_data_chunked(10, 4, fill));
+
+        let mut expected = vec![0.0; 4];
+        fill(2, &mut expected);
+        assert_eq!(&data[8..], &expected[..2]);
+    }
+}
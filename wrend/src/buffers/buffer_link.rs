@@ -30,6 +30,19 @@ impl<BufferId: Id> BufferLink<BufferId> {
         self.buffer_create_callback
             .call_with_into_js_arg_and_return(&buffer_create_context)
     }
+
+    /// Whether `other` is this same link (or a clone of it), as opposed to a link that merely shares its id
+    pub(crate) fn is_identical(&self, other: &Self) -> bool {
+        self == other && self.buffer_create_callback == other.buffer_create_callback
+    }
+
+    /// The same link with its id replaced (used when scoping links to a namespace)
+    pub(crate) fn map_ids(self, buffer_id: impl Fn(&BufferId) -> BufferId) -> Self {
+        Self {
+            buffer_id: buffer_id(&self.buffer_id),
+            ..self
+        }
+    }
 }
 
 impl<BufferId: Id> Debug for BufferLink<BufferId> {
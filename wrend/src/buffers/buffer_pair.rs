@@ -0,0 +1,97 @@
+use crate::Id;
+use std::cell::Cell;
+
+/// Two buffers (each with its own VAO) whose read/write roles alternate every frame, as created
+/// from a [crate::BufferPairLink].
+///
+/// Transform feedback reads from one buffer (through its VAO) while writing to the other, so after
+/// each update the roles are swapped with [crate::Renderer::swap_buffer_pair].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BufferPair<BufferId: Id, VertexArrayObjectId: Id> {
+    buffer_ids: [BufferId; 2],
+    vao_ids: [VertexArrayObjectId; 2],
+    swapped: Cell<bool>,
+}
+
+impl<BufferId: Id, VertexArrayObjectId: Id> BufferPair<BufferId, VertexArrayObjectId> {
+    pub fn new(buffer_ids: [BufferId; 2], vao_ids: [VertexArrayObjectId; 2]) -> Self {
+        Self {
+            buffer_ids,
+            vao_ids,
+            swapped: Cell::new(false),
+        }
+    }
+
+    /// The buffer to read from this frame, along with the VAO that reads from it
+    pub fn read(&self) -> (&BufferId, &VertexArrayObjectId) {
+        let i = self.read_index();
+        (&self.buffer_ids[i], &self.vao_ids[i])
+    }
+
+    /// The buffer to write to this frame (e.g. with transform feedback), along with its VAO
+    pub fn write(&self) -> (&BufferId, &VertexArrayObjectId) {
+        let i = 1 - self.read_index();
+        (&self.buffer_ids[i], &self.vao_ids[i])
+    }
+
+    /// Flips the read/write roles of the two buffers
+    pub fn swap(&self) -> &Self {
+        self.swapped.set(!self.swapped.get());
+        self
+    }
+
+    pub fn is_swapped(&self) -> bool {
+        self.swapped.get()
+    }
+
+    pub(crate) fn set_swapped(&self, swapped: bool) {
+        self.swapped.set(swapped);
+    }
+
+    pub fn buffer_ids(&self) -> &[BufferId; 2] {
+        &self.buffer_ids
+    }
+
+    pub fn vao_ids(&self) -> &[VertexArrayObjectId; 2] {
+        &self.vao_ids
+    }
+
+    fn read_index(&self) -> usize {
+        usize::from(self.swapped.get())
+    }
+
+    /// The same pair with its ids replaced (used when scoping a pair to a namespace)
+    pub(crate) fn map_ids(
+        &self,
+        buffer_id: impl Fn(&BufferId) -> BufferId,
+        vao_id: impl Fn(&VertexArrayObjectId) -> VertexArrayObjectId,
+    ) -> Self {
+        Self {
+            buffer_ids: self.buffer_ids.each_ref().map(buffer_id),
+            vao_ids: self.vao_ids.each_ref().map(vao_id),
+            swapped: self.swapped.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BufferPair;
+
+    #[test]
+    fn swaps_read_and_write_roles() {
+        let pair = BufferPair::new(
+            [String::from("a"), String::from("b")],
+            [String::from("vao_a"), String::from("vao_b")],
+        );
+        assert_eq!(pair.read(), (&"a".to_string(), &"vao_a".to_string()));
+        assert_eq!(pair.write(), (&"b".to_string(), &"vao_b".to_string()));
+
+        pair.swap();
+        assert_eq!(pair.read(), (&"b".to_string(), &"vao_b".to_string()));
+        assert_eq!(pair.write(), (&"a".to_string(), &"vao_a".to_string()));
+
+        pair.swap();
+        assert!(!pair.is_swapped());
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    AttributeCreateCallback, AttributeLink, BufferCreateCallback, BufferLink, BufferPair, Id,
+    IdName,
+};
+
+/// Declares two identically-initialized buffers for transform feedback ping-pong, plus a VAO for each.
+///
+/// Every attribute added with [BufferPairLink::add_attribute] is linked twice, once per buffer/VAO
+/// combination, so reading from either buffer is just a matter of binding the right VAO
+/// (see [crate::RendererData::buffer_pair]). The pair is identified by the id of its first buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BufferPairLink<BufferId: Id, VertexArrayObjectId: Id, AttributeId: Id + IdName> {
+    buffer_ids: [BufferId; 2],
+    vao_ids: [VertexArrayObjectId; 2],
+    buffer_create_callback: BufferCreateCallback,
+    attributes: Vec<(AttributeId, AttributeCreateCallback)>,
+}
+
+impl<BufferId: Id, VertexArrayObjectId: Id, AttributeId: Id + IdName>
+    BufferPairLink<BufferId, VertexArrayObjectId, AttributeId>
+{
+    /// `buffer_create_callback` is called once for each buffer
+    pub fn new(
+        buffer_ids: (BufferId, BufferId),
+        vao_ids: (VertexArrayObjectId, VertexArrayObjectId),
+        buffer_create_callback: impl Into<BufferCreateCallback>,
+    ) -> Self {
+        Self {
+            buffer_ids: [buffer_ids.0, buffer_ids.1],
+            vao_ids: [vao_ids.0, vao_ids.1],
+            buffer_create_callback: buffer_create_callback.into(),
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Links an attribute to both buffers, each in its own VAO
+    pub fn add_attribute(
+        &mut self,
+        attribute_id: impl Into<AttributeId>,
+        attribute_create_callback: impl Into<AttributeCreateCallback>,
+    ) -> &mut Self {
+        self.attributes
+            .push((attribute_id.into(), attribute_create_callback.into()));
+        self
+    }
+
+    /// The id the pair is looked up by: the id of its first buffer
+    pub fn pair_id(&self) -> &BufferId {
+        &self.buffer_ids[0]
+    }
+
+    pub fn buffer_ids(&self) -> &[BufferId; 2] {
+        &self.buffer_ids
+    }
+
+    pub fn vao_ids(&self) -> &[VertexArrayObjectId; 2] {
+        &self.vao_ids
+    }
+
+    pub(crate) fn buffer_links(&self) -> impl Iterator<Item = BufferLink<BufferId>> + '_ {
+        self.buffer_ids.iter().map(|buffer_id| {
+            BufferLink::new(buffer_id.clone(), self.buffer_create_callback.clone())
+        })
+    }
+
+    pub(crate) fn attribute_links(
+        &self,
+    ) -> impl Iterator<Item = AttributeLink<VertexArrayObjectId, BufferId, AttributeId>> + '_ {
+        self.attributes
+            .iter()
+            .flat_map(move |(attribute_id, attribute_create_callback)| {
+                self.buffer_ids
+                    .iter()
+                    .zip(&self.vao_ids)
+                    .map(move |(buffer_id, vao_id)| {
+                        AttributeLink::new(
+                            vec![vao_id.clone()],
+                            buffer_id.clone(),
+                            attribute_id.clone(),
+                            attribute_create_callback.clone(),
+                        )
+                    })
+            })
+    }
+
+    pub(crate) fn buffer_pair(&self) -> BufferPair<BufferId, VertexArrayObjectId> {
+        BufferPair::new(self.buffer_ids.clone(), self.vao_ids.clone())
+    }
+}
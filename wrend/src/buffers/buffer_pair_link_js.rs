@@ -0,0 +1,69 @@
+use std::ops::{Deref, DerefMut};
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{AttributeCreateCallbackJs, BufferCreateCallbackJs, BufferPairLink};
+
+pub type BufferPairLinkJsInner = BufferPairLink<String, String, String>;
+
+#[wasm_bindgen(inspectable, js_name = BufferPairLink)]
+pub struct BufferPairLinkJs(BufferPairLinkJsInner);
+
+#[wasm_bindgen(js_class = BufferPairLink)]
+impl BufferPairLinkJs {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        buffer_id_a: String,
+        buffer_id_b: String,
+        vao_id_a: String,
+        vao_id_b: String,
+        buffer_create_callback: BufferCreateCallbackJs,
+    ) -> Self {
+        Self(BufferPairLinkJsInner::new(
+            (buffer_id_a, buffer_id_b),
+            (vao_id_a, vao_id_b),
+            buffer_create_callback,
+        ))
+    }
+
+    #[wasm_bindgen(js_name = addAttribute)]
+    pub fn add_attribute(
+        &mut self,
+        attribute_id: String,
+        attribute_create_callback: AttributeCreateCallbackJs,
+    ) {
+        self.deref_mut()
+            .add_attribute(attribute_id, attribute_create_callback);
+    }
+
+    #[wasm_bindgen(js_name = pairId)]
+    pub fn pair_id(&self) -> String {
+        self.deref().pair_id().to_owned()
+    }
+}
+
+impl BufferPairLinkJs {
+    pub fn into_inner(self) -> BufferPairLinkJsInner {
+        self.0
+    }
+}
+
+impl Deref for BufferPairLinkJs {
+    type Target = BufferPairLinkJsInner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for BufferPairLinkJs {
+    fn deref_mut(&mut self) -> &mut BufferPairLinkJsInner {
+        &mut self.0
+    }
+}
+
+impl From<BufferPairLinkJs> for BufferPairLinkJsInner {
+    fn from(buffer_pair_link_js: BufferPairLinkJs) -> Self {
+        buffer_pair_link_js.into_inner()
+    }
+}
@@ -0,0 +1,211 @@
+use crate::VertexLayout;
+use js_sys::Float32Array;
+use web_sys::{WebGl2RenderingContext, WebGlBuffer, WebGlVertexArrayObject};
+
+/// Write head bookkeeping for a [RingBuffer], kept separate from WebGL so it can be reasoned about on its own
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct RingCursor {
+    capacity: usize,
+    head: usize,
+    len: usize,
+}
+
+impl RingCursor {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Advances past `count` new vertices, returning the `(slot, source_index, count)` runs to write.
+    /// Only the last `capacity` vertices are written, since older ones would be overwritten anyway.
+    pub(crate) fn advance(&mut self, count: usize) -> Vec<(usize, usize, usize)> {
+        let skipped = count.saturating_sub(self.capacity);
+        let mut source = skipped;
+        let mut remaining = count - skipped;
+        self.head = (self.head + skipped) % self.capacity;
+
+        let mut writes = Vec::new();
+        while remaining > 0 {
+            let run = remaining.min(self.capacity - self.head);
+            writes.push((self.head, source, run));
+            self.head = (self.head + run) % self.capacity;
+            self.len = (self.len + run).min(self.capacity);
+            source += run;
+            remaining -= run;
+        }
+        writes
+    }
+
+    /// `(first, count)` ranges to draw, oldest vertex first. When `seamless`, the first range ends
+    /// with the mirror of slot 0 so that line strips stay connected across the wraparound.
+    pub(crate) fn ranges(&self, seamless: bool) -> Vec<(usize, usize)> {
+        if self.len < self.capacity || self.head == 0 {
+            return vec![(0, self.len)];
+        }
+        let wrapped = self.capacity - self.head + usize::from(seamless);
+        vec![(self.head, wrapped), (0, self.head)]
+    }
+}
+
+/// A fixed-size vertex buffer for data that is appended every frame, such as real-time telemetry.
+///
+/// New vertices are written with `bufferSubData` at a moving write head, overwriting the oldest
+/// ones once the buffer is full, so nothing is ever reallocated. [RingBuffer::draw] issues up to
+/// two draws so that vertices are always drawn oldest first, and keeps line strips connected
+/// across the wraparound (the buffer has one extra slot that mirrors the first one).
+#[derive(Debug)]
+pub struct RingBuffer {
+    gl: WebGl2RenderingContext,
+    buffer: WebGlBuffer,
+    vao: WebGlVertexArrayObject,
+    layout: VertexLayout,
+    cursor: RingCursor,
+}
+
+impl RingBuffer {
+    /// Allocates room for `capacity` vertices of `layout` and a VAO that reads them.
+    /// Returns `None` if WebGL could not create the buffer or VAO.
+    pub fn new(gl: &WebGl2RenderingContext, layout: VertexLayout, capacity: usize) -> Option<Self> {
+        let cursor = RingCursor::new(capacity);
+        let buffer = gl.create_buffer()?;
+        let vao = gl.create_vertex_array()?;
+        gl.bind_vertex_array(Some(&vao));
+        gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, Some(&buffer));
+        gl.buffer_data_with_i32(
+            WebGl2RenderingContext::ARRAY_BUFFER,
+            (cursor.capacity + 1) as i32 * layout.stride(),
+            WebGl2RenderingContext::DYNAMIC_DRAW,
+        );
+        layout.apply(gl);
+        gl.bind_vertex_array(None);
+        gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, None);
+
+        Some(Self {
+            gl: gl.clone(),
+            buffer,
+            vao,
+            layout,
+            cursor,
+        })
+    }
+
+    /// Appends vertices of 32-bit floats (`layout.stride() / 4` floats per vertex)
+    pub fn push(&mut self, vertices: &[f32]) -> &mut Self {
+        let floats_per_vertex = (self.layout.stride() / 4).max(1) as usize;
+        self.write(
+            vertices.len() / floats_per_vertex,
+            |gl, offset, start, count| {
+                let data =
+                    &vertices[start * floats_per_vertex..(start + count) * floats_per_vertex];
+                gl.buffer_sub_data_with_i32_and_array_buffer_view(
+                    WebGl2RenderingContext::ARRAY_BUFFER,
+                    offset,
+                    &Float32Array::from(data),
+                );
+            },
+        )
+    }
+
+    /// Appends vertices packed as raw bytes, for layouts with non-float attributes
+    pub fn push_bytes(&mut self, vertices: &[u8]) -> &mut Self {
+        let stride = self.layout.stride().max(1) as usize;
+        self.write(vertices.len() / stride, |gl, offset, start, count| {
+            gl.buffer_sub_data_with_i32_and_u8_array(
+                WebGl2RenderingContext::ARRAY_BUFFER,
+                offset,
+                &vertices[start * stride..(start + count) * stride],
+            );
+        })
+    }
+
+    /// Calls `upload` with the byte offset, first source vertex, and vertex count of each run to write
+    fn write(
+        &mut self,
+        count: usize,
+        upload: impl Fn(&WebGl2RenderingContext, i32, usize, usize),
+    ) -> &mut Self {
+        let gl = &self.gl;
+        let stride = self.layout.stride();
+        gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, Some(&self.buffer));
+        for (slot, start, count) in self.cursor.advance(count) {
+            upload(gl, slot as i32 * stride, start, count);
+            if slot == 0 {
+                upload(gl, self.cursor.capacity as i32 * stride, start, 1);
+            }
+        }
+        gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, None);
+        self
+    }
+
+    /// Forgets every vertex, without clearing the buffer
+    pub fn clear(&mut self) -> &mut Self {
+        self.cursor = RingCursor::new(self.cursor.capacity);
+        self
+    }
+
+    /// Number of vertices currently stored, up to [RingBuffer::capacity]
+    pub fn len(&self) -> usize {
+        self.cursor.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cursor.len == 0
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.cursor.capacity
+    }
+
+    /// Slot the next vertex will be written to
+    pub fn head(&self) -> usize {
+        self.cursor.head
+    }
+
+    pub fn buffer(&self) -> &WebGlBuffer {
+        &self.buffer
+    }
+
+    pub fn vao(&self) -> &WebGlVertexArrayObject {
+        &self.vao
+    }
+
+    /// Draws every stored vertex with the current program, oldest first
+    pub fn draw(&self, mode: u32) -> &Self {
+        let gl = &self.gl;
+        let seamless = mode == WebGl2RenderingContext::LINE_STRIP;
+        gl.bind_vertex_array(Some(&self.vao));
+        for (first, count) in self.cursor.ranges(seamless) {
+            gl.draw_arrays(mode, first as i32, count as i32);
+        }
+        gl.bind_vertex_array(None);
+        self
+    }
+
+    /// Deletes the buffer and VAO
+    pub fn delete(&self) {
+        self.gl.delete_buffer(Some(&self.buffer));
+        self.gl.delete_vertex_array(Some(&self.vao));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RingCursor;
+
+    #[test]
+    fn wraps_writes_and_draws() {
+        let mut cursor = RingCursor::new(4);
+        assert_eq!(cursor.advance(3), vec![(0, 0, 3)]);
+        assert_eq!(cursor.ranges(true), vec![(0, 3)]);
+
+        assert_eq!(cursor.advance(2), vec![(3, 0, 1), (0, 1, 1)]);
+        assert_eq!(cursor.ranges(false), vec![(1, 3), (0, 1)]);
+        assert_eq!(cursor.ranges(true), vec![(1, 4), (0, 1)]);
+
+        assert_eq!(cursor.advance(6), vec![(3, 2, 1), (0, 3, 3)]);
+        assert_eq!(cursor.ranges(true), vec![(3, 2), (0, 3)]);
+    }
+}
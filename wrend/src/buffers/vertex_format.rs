@@ -0,0 +1,70 @@
+use web_sys::WebGl2RenderingContext;
+
+/// How each component of a vertex attribute is stored in a packed binary buffer
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VertexFormat {
+    Float32,
+    /// Half floats, e.g. for compressed positions or normals
+    Float16,
+    /// Unsigned bytes read as 0.0 to 1.0, e.g. for RGB colors
+    Uint8Norm,
+    /// Signed bytes read as -1.0 to 1.0
+    Int8Norm,
+    /// Unsigned shorts read as 0.0 to 1.0, e.g. for LiDAR intensity
+    Uint16Norm,
+    /// Signed shorts read as -1.0 to 1.0, e.g. for quantized positions
+    Int16Norm,
+    /// Unsigned bytes read as integers (`uint` attributes in GLSL)
+    Uint8,
+    /// Unsigned shorts read as integers (`uint` attributes in GLSL)
+    Uint16,
+    /// Unsigned ints read as integers (`uint` attributes in GLSL), e.g. for classifications or ids
+    Uint32,
+}
+
+impl VertexFormat {
+    /// Size of one component, in bytes
+    pub fn byte_size(&self) -> i32 {
+        match self {
+            VertexFormat::Uint8Norm | VertexFormat::Int8Norm | VertexFormat::Uint8 => 1,
+            VertexFormat::Float16
+            | VertexFormat::Uint16Norm
+            | VertexFormat::Int16Norm
+            | VertexFormat::Uint16 => 2,
+            VertexFormat::Float32 | VertexFormat::Uint32 => 4,
+        }
+    }
+
+    /// WebGL type passed to `vertexAttribPointer` or `vertexAttribIPointer`
+    pub fn gl_type(&self) -> u32 {
+        match self {
+            VertexFormat::Float32 => WebGl2RenderingContext::FLOAT,
+            VertexFormat::Float16 => WebGl2RenderingContext::HALF_FLOAT,
+            VertexFormat::Uint8Norm | VertexFormat::Uint8 => WebGl2RenderingContext::UNSIGNED_BYTE,
+            VertexFormat::Int8Norm => WebGl2RenderingContext::BYTE,
+            VertexFormat::Uint16Norm | VertexFormat::Uint16 => {
+                WebGl2RenderingContext::UNSIGNED_SHORT
+            }
+            VertexFormat::Int16Norm => WebGl2RenderingContext::SHORT,
+            VertexFormat::Uint32 => WebGl2RenderingContext::UNSIGNED_INT,
+        }
+    }
+
+    pub fn is_normalized(&self) -> bool {
+        matches!(
+            self,
+            VertexFormat::Uint8Norm
+                | VertexFormat::Int8Norm
+                | VertexFormat::Uint16Norm
+                | VertexFormat::Int16Norm
+        )
+    }
+
+    /// Whether the attribute is read as an integer, with `vertexAttribIPointer`
+    pub fn is_integer(&self) -> bool {
+        matches!(
+            self,
+            VertexFormat::Uint8 | VertexFormat::Uint16 | VertexFormat::Uint32
+        )
+    }
+}
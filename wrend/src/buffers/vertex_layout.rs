@@ -0,0 +1,141 @@
+use crate::VertexFormat;
+use web_sys::WebGl2RenderingContext;
+
+/// One attribute of a [VertexLayout]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct VertexLayoutAttribute {
+    pub location: u32,
+    pub components: i32,
+    pub format: VertexFormat,
+    /// Byte offset from the start of each vertex
+    pub offset: i32,
+}
+
+/// Describes how vertices are packed into a binary buffer, such as a `.bin` file fetched from a
+/// server, so the buffer can be uploaded as-is and read with [VertexLayout::apply].
+///
+/// ```ignore
+/// // xyz as floats, then rgb as bytes, padded to 16 bytes per point
+/// let mut layout = VertexLayout::new();
+/// layout
+///     .add(0, 3, VertexFormat::Float32)
+///     .add(1, 3, VertexFormat::Uint8Norm)
+///     .set_stride(16);
+/// ```
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct VertexLayout {
+    attributes: Vec<VertexLayoutAttribute>,
+    stride: Option<i32>,
+}
+
+impl VertexLayout {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an attribute after the previous ones, at the next 4-byte aligned offset
+    pub fn add(&mut self, location: u32, components: i32, format: VertexFormat) -> &mut Self {
+        let offset = self.packed_stride();
+        self.add_at(location, components, format, offset)
+    }
+
+    /// Adds an attribute at an explicit byte `offset` within each vertex
+    pub fn add_at(
+        &mut self,
+        location: u32,
+        components: i32,
+        format: VertexFormat,
+        offset: i32,
+    ) -> &mut Self {
+        self.attributes.push(VertexLayoutAttribute {
+            location,
+            components,
+            format,
+            offset,
+        });
+        self
+    }
+
+    /// Overrides the number of bytes per vertex, for layouts with padding after the last attribute
+    pub fn set_stride(&mut self, stride: i32) -> &mut Self {
+        self.stride = Some(stride);
+        self
+    }
+
+    pub fn attributes(&self) -> &[VertexLayoutAttribute] {
+        &self.attributes
+    }
+
+    /// Bytes from the start of one vertex to the start of the next
+    pub fn stride(&self) -> i32 {
+        self.stride.unwrap_or_else(|| self.packed_stride())
+    }
+
+    /// End of the furthest attribute, rounded up to 4 bytes (WebGL requires aligned strides)
+    fn packed_stride(&self) -> i32 {
+        let end = self
+            .attributes
+            .iter()
+            .map(|attribute| attribute.offset + attribute.components * attribute.format.byte_size())
+            .max()
+            .unwrap_or(0);
+        (end + 3) / 4 * 4
+    }
+
+    /// Number of whole vertices in `byte_length` bytes
+    pub fn vertex_count(&self, byte_length: usize) -> usize {
+        match self.stride() {
+            stride if stride > 0 => byte_length / stride as usize,
+            _ => 0,
+        }
+    }
+
+    /// Points every attribute at the buffer bound to `ARRAY_BUFFER`, recording them in the bound VAO
+    pub fn apply(&self, gl: &WebGl2RenderingContext) {
+        let stride = self.stride();
+        for attribute in &self.attributes {
+            gl.enable_vertex_attrib_array(attribute.location);
+            if attribute.format.is_integer() {
+                gl.vertex_attrib_i_pointer_with_i32(
+                    attribute.location,
+                    attribute.components,
+                    attribute.format.gl_type(),
+                    stride,
+                    attribute.offset,
+                );
+            } else {
+                gl.vertex_attrib_pointer_with_i32(
+                    attribute.location,
+                    attribute.components,
+                    attribute.format.gl_type(),
+                    attribute.format.is_normalized(),
+                    stride,
+                    attribute.offset,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VertexLayout;
+    use crate::VertexFormat;
+
+    #[test]
+    fn packs_attributes() {
+        let mut layout = VertexLayout::new();
+        layout
+            .add(0, 3, VertexFormat::Float32)
+            .add(1, 3, VertexFormat::Uint8Norm)
+            .add(2, 1, VertexFormat::Uint16);
+        let offsets: Vec<i32> = layout.attributes().iter().map(|a| a.offset).collect();
+        assert_eq!(offsets, vec![0, 12, 16]);
+        assert_eq!(layout.stride(), 20);
+        assert_eq!(layout.vertex_count(45), 2);
+
+        layout.set_stride(32);
+        assert_eq!(layout.vertex_count(64), 2);
+        assert_eq!(VertexLayout::new().vertex_count(64), 0);
+    }
+}
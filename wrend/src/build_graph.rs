@@ -0,0 +1,3 @@
+mod build_steps;
+
+pub(crate) use build_steps::*;
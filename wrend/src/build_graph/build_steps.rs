@@ -0,0 +1,176 @@
+use crate::{
+    preprocess_shader_source, CompileShaderError, RendererBackend, RendererBackendError,
+    ShaderHeaderOptions, ShaderType,
+};
+use std::fmt::Debug;
+
+/// Preprocesses a shader source and compiles it, mapping the line numbers in any info log back to
+/// the original source.
+///
+/// This is the `compile_shader` step that [crate::RendererDataBuilder] hands to
+/// [crate::build_programs], which decides the order shaders are compiled and programs are linked in.
+pub(crate) fn compile_shader<Backend: RendererBackend + ?Sized>(
+    backend: &Backend,
+    shader_id: &dyn Debug,
+    shader_type: ShaderType,
+    source: &str,
+    shader_header_options: &ShaderHeaderOptions,
+) -> Result<Backend::Shader, CompileShaderError> {
+    let source =
+        preprocess_shader_source(source, shader_type, shader_header_options).map_err(|error| {
+            CompileShaderError::InvalidSource {
+                shader_id: format!("{shader_id:#?}"),
+                error,
+            }
+        })?;
+
+    backend
+        .compile_shader(shader_type, source.source())
+        .map_err(|error| match error {
+            RendererBackendError::NoneReturned => CompileShaderError::NoShaderReturned {
+                shader_id: format!("{shader_id:#?}"),
+            },
+            RendererBackendError::KnownError(info_log) => CompileShaderError::KnownError {
+                shader_id: format!("{shader_id:#?}"),
+                error: source.map_info_log(&info_log),
+                source_context: source.source_context(&info_log),
+            },
+            RendererBackendError::UnknownError => CompileShaderError::UnknownError {
+                shader_id: format!("{shader_id:#?}"),
+            },
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compile_shader;
+    use crate::{
+        CompileShaderError, LinkProgramError, LinkStepError, RendererBackend, RendererBackendError,
+        RendererBuilderError, ShaderHeaderOptions, ShaderType,
+    };
+    use std::cell::RefCell;
+
+    /// Records the sources it's asked to compile. Sources that use `fail;` fail to compile.
+    #[derive(Default)]
+    struct MockBackend {
+        compiled_sources: RefCell<Vec<String>>,
+        unavailable: bool,
+    }
+
+    impl RendererBackend for MockBackend {
+        type Shader = ();
+        type Program = ();
+        type VertexArray = ();
+        type TransformFeedback = ();
+
+        fn compile_shader(
+            &self,
+            _shader_type: ShaderType,
+            source: &str,
+        ) -> Result<Self::Shader, RendererBackendError> {
+            self.compiled_sources.borrow_mut().push(source.to_string());
+            if self.unavailable {
+                return Err(RendererBackendError::NoneReturned);
+            }
+            match source.lines().position(|line| line.contains("fail;")) {
+                Some(index) => Err(RendererBackendError::KnownError(format!(
+                    "ERROR: 0:{}: 'fail' : undeclared identifier",
+                    index + 1
+                ))),
+                None => Ok(()),
+            }
+        }
+
+        fn link_program(
+            &self,
+            _vertex_shader: &Self::Shader,
+            _fragment_shader: &Self::Shader,
+            _attribute_locations: &[(String, u32)],
+            _transform_feedback_varyings: &[String],
+            _cache_key: u64,
+        ) -> Result<Self::Program, RendererBackendError> {
+            Ok(())
+        }
+
+        fn create_vertex_array(&self) -> Result<Self::VertexArray, RendererBackendError> {
+            Ok(())
+        }
+
+        fn create_transform_feedback(
+            &self,
+        ) -> Result<Self::TransformFeedback, RendererBackendError> {
+            Ok(())
+        }
+    }
+
+    fn compile(backend: &MockBackend, source: &str) -> Result<(), CompileShaderError> {
+        compile_shader(
+            backend,
+            &"red",
+            ShaderType::FragmentShader,
+            source,
+            &ShaderHeaderOptions::default(),
+        )
+    }
+
+    #[test]
+    fn reports_compile_errors_against_the_original_source() {
+        let backend = MockBackend::default();
+        let error = compile(&backend, "// red\nvoid main() { fail; }").unwrap_err();
+
+        // `#version 300 es` and a precision statement are injected above the original source
+        match error {
+            CompileShaderError::KnownError {
+                shader_id, error, ..
+            } => {
+                assert_eq!(shader_id, r#""red""#);
+                assert_eq!(error, "ERROR: 0:2: 'fail' : undeclared identifier");
+            }
+            error => panic!("unexpected error: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_sources_before_compiling_them() {
+        let backend = MockBackend::default();
+        let error = compile(
+            &backend,
+            "// quad\nattribute vec2 a_position;\nvoid main() {}",
+        )
+        .unwrap_err();
+
+        assert!(matches!(error, CompileShaderError::InvalidSource { .. }));
+        assert!(backend.compiled_sources.borrow().is_empty());
+    }
+
+    #[test]
+    fn reports_missing_shader_objects_by_id() {
+        let backend = MockBackend {
+            unavailable: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            compile(&backend, "void main() {}"),
+            Err(CompileShaderError::NoShaderReturned {
+                shader_id: r#""red""#.to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn reports_link_step_errors_as_link_program_errors() {
+        assert_eq!(
+            RendererBuilderError::from(LinkStepError::Backend(RendererBackendError::KnownError(
+                "Varyings over limit".to_string()
+            ))),
+            RendererBuilderError::LinkProgramError(LinkProgramError::KnownError(
+                "Varyings over limit".to_string()
+            ))
+        );
+        assert_eq!(
+            LinkProgramError::from(LinkStepError::FragmentShaderNotFound),
+            LinkProgramError::FragmentShaderNotFound
+        );
+    }
+}
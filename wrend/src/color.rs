@@ -0,0 +1,9 @@
+mod canvas_color_space;
+mod color_conversion;
+mod cosine_palette;
+mod gradient;
+
+pub use canvas_color_space::*;
+pub use color_conversion::*;
+pub use cosine_palette::*;
+pub use gradient::*;
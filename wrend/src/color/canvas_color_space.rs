@@ -0,0 +1,101 @@
+use js_sys::Reflect;
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+use web_sys::{window, WebGl2RenderingContext};
+
+/// Color space of a WebGL context's drawing buffer (`drawingBufferColorSpace`), or of the
+/// images it uploads to textures (`unpackColorSpace`).
+///
+/// Set with [crate::RendererDataBuilder::set_drawing_buffer_color_space] and
+/// [crate::RendererDataBuilder::set_unpack_color_space]. Browsers without support for these
+/// properties always use sRGB.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CanvasColorSpace {
+    #[default]
+    Srgb,
+    /// Wide-gamut color space used by most recent phone and laptop displays
+    DisplayP3,
+}
+
+impl CanvasColorSpace {
+    /// Name of the color space in the WebGL API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CanvasColorSpace::Srgb => "srgb",
+            CanvasColorSpace::DisplayP3 => "display-p3",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "srgb" => Some(CanvasColorSpace::Srgb),
+            "display-p3" => Some(CanvasColorSpace::DisplayP3),
+            _ => None,
+        }
+    }
+
+    /// Whether the browser lets the color spaces of this context be configured
+    pub fn is_configurable(gl: &WebGl2RenderingContext) -> bool {
+        Reflect::has(gl, &JsValue::from_str("drawingBufferColorSpace")).unwrap_or(false)
+    }
+
+    /// Whether the display can show (roughly) the whole Display P3 gamut
+    pub fn display_supports_p3() -> bool {
+        window()
+            .and_then(|window| window.match_media("(color-gamut: p3)").ok().flatten())
+            .is_some_and(|media_query_list| media_query_list.matches())
+    }
+
+    /// Display P3 if both the browser and display support it, otherwise sRGB
+    pub fn preferred(gl: &WebGl2RenderingContext) -> Self {
+        if Self::is_configurable(gl) && Self::display_supports_p3() {
+            CanvasColorSpace::DisplayP3
+        } else {
+            CanvasColorSpace::Srgb
+        }
+    }
+
+    /// Current drawing buffer color space of the context
+    pub fn drawing_buffer(gl: &WebGl2RenderingContext) -> Self {
+        Self::get(gl, "drawingBufferColorSpace")
+    }
+
+    /// Current unpack color space of the context
+    pub fn unpack(gl: &WebGl2RenderingContext) -> Self {
+        Self::get(gl, "unpackColorSpace")
+    }
+
+    /// Sets a color space property of the context, returning whether the browser supports it
+    pub(crate) fn set(&self, gl: &WebGl2RenderingContext, property: &str) -> bool {
+        let key = JsValue::from_str(property);
+        if !Reflect::has(gl, &key).unwrap_or(false) {
+            return false;
+        }
+        Reflect::set(gl, &key, &JsValue::from_str(self.as_str())).unwrap_or(false)
+            && Self::get(gl, property) == *self
+    }
+
+    fn get(gl: &WebGl2RenderingContext, property: &str) -> Self {
+        Reflect::get(gl, &JsValue::from_str(property))
+            .ok()
+            .and_then(|value| value.as_string())
+            .and_then(|name| Self::from_name(&name))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CanvasColorSpace;
+
+    #[test]
+    fn names_round_trip() {
+        for color_space in [CanvasColorSpace::Srgb, CanvasColorSpace::DisplayP3] {
+            assert_eq!(
+                CanvasColorSpace::from_name(color_space.as_str()),
+                Some(color_space)
+            );
+        }
+        assert_eq!(CanvasColorSpace::from_name("rec2020"), None);
+    }
+}
@@ -0,0 +1,92 @@
+//! Conversions between RGB, HSL, and HSV.
+//!
+//! All components are in the range 0.0 to 1.0, including hue (which wraps around).
+
+/// Converts hue, saturation, and lightness to RGB
+pub fn hsl_to_rgb([h, s, l]: [f32; 3]) -> [f32; 3] {
+    let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    from_hue_and_chroma(h, chroma, l - chroma / 2.0)
+}
+
+/// Converts hue, saturation, and value (brightness) to RGB
+pub fn hsv_to_rgb([h, s, v]: [f32; 3]) -> [f32; 3] {
+    let chroma = v * s;
+    from_hue_and_chroma(h, chroma, v - chroma)
+}
+
+/// Converts RGB to hue, saturation, and lightness
+pub fn rgb_to_hsl(rgb: [f32; 3]) -> [f32; 3] {
+    let (hue, max, min) = hue_max_min(rgb);
+    let l = (max + min) / 2.0;
+    let s = if max == min {
+        0.0
+    } else {
+        (max - min) / (1.0 - (2.0 * l - 1.0).abs())
+    };
+    [hue, s, l]
+}
+
+/// Converts RGB to hue, saturation, and value (brightness)
+pub fn rgb_to_hsv(rgb: [f32; 3]) -> [f32; 3] {
+    let (hue, max, min) = hue_max_min(rgb);
+    let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+    [hue, s, max]
+}
+
+fn from_hue_and_chroma(h: f32, chroma: f32, m: f32) -> [f32; 3] {
+    let h = h.rem_euclid(1.0) * 6.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let [r, g, b] = match h as u32 {
+        0 => [chroma, x, 0.0],
+        1 => [x, chroma, 0.0],
+        2 => [0.0, chroma, x],
+        3 => [0.0, x, chroma],
+        4 => [x, 0.0, chroma],
+        _ => [chroma, 0.0, x],
+    };
+    [r + m, g + m, b + m]
+}
+
+fn hue_max_min([r, g, b]: [f32; 3]) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+
+    (hue / 6.0, max, min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: [f32; 3], b: [f32; 3]) {
+        for i in 0..3 {
+            assert!((a[i] - b[i]).abs() < 1e-5, "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn converts_primary_colors() {
+        assert_close(hsl_to_rgb([0.0, 1.0, 0.5]), [1.0, 0.0, 0.0]);
+        assert_close(hsl_to_rgb([1.0 / 3.0, 1.0, 0.5]), [0.0, 1.0, 0.0]);
+        assert_close(hsv_to_rgb([2.0 / 3.0, 1.0, 1.0]), [0.0, 0.0, 1.0]);
+        assert_close(hsv_to_rgb([0.5, 0.0, 0.25]), [0.25, 0.25, 0.25]);
+    }
+
+    #[test]
+    fn round_trips() {
+        let rgb = [0.2, 0.6, 0.9];
+        assert_close(hsl_to_rgb(rgb_to_hsl(rgb)), rgb);
+        assert_close(hsv_to_rgb(rgb_to_hsv(rgb)), rgb);
+    }
+}
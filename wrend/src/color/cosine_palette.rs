@@ -0,0 +1,72 @@
+use std::f32::consts::TAU;
+
+/// Procedural color palette of the form `a + b * cos(2π * (c * t + d))`.
+///
+/// See <https://iquilezles.org/articles/palettes/> for an explanation of the parameters.
+/// The same formula can be evaluated in GLSL by uploading the four vectors as uniforms.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct CosinePalette {
+    a: [f32; 3],
+    b: [f32; 3],
+    c: [f32; 3],
+    d: [f32; 3],
+}
+
+impl CosinePalette {
+    /// Smooth rainbow
+    pub const RAINBOW: CosinePalette = CosinePalette::new(
+        [0.5, 0.5, 0.5],
+        [0.5, 0.5, 0.5],
+        [1.0, 1.0, 1.0],
+        [0.0, 0.33, 0.67],
+    );
+
+    /// Warm sunset tones
+    pub const SUNSET: CosinePalette = CosinePalette::new(
+        [0.5, 0.5, 0.5],
+        [0.5, 0.5, 0.5],
+        [1.0, 1.0, 0.5],
+        [0.8, 0.9, 0.3],
+    );
+
+    /// Cool ocean tones
+    pub const OCEAN: CosinePalette = CosinePalette::new(
+        [0.5, 0.5, 0.5],
+        [0.5, 0.5, 0.5],
+        [1.0, 1.0, 1.0],
+        [0.3, 0.2, 0.2],
+    );
+
+    /// Creates a palette from its offset (`a`), amplitude (`b`), frequency (`c`), and phase (`d`)
+    pub const fn new(a: [f32; 3], b: [f32; 3], c: [f32; 3], d: [f32; 3]) -> Self {
+        Self { a, b, c, d }
+    }
+
+    /// Offset, amplitude, frequency, and phase, in that order
+    pub fn parameters(&self) -> [[f32; 3]; 4] {
+        [self.a, self.b, self.c, self.d]
+    }
+
+    /// Evaluates the palette at `t`
+    pub fn sample(&self, t: f32) -> [f32; 3] {
+        let mut rgb = [0.0; 3];
+        for (i, channel) in rgb.iter_mut().enumerate() {
+            *channel = self.a[i] + self.b[i] * (TAU * (self.c[i] * t + self.d[i])).cos();
+        }
+        rgb
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CosinePalette;
+
+    #[test]
+    fn samples_palette() {
+        let palette = CosinePalette::new([0.5; 3], [0.5; 3], [1.0; 3], [0.0, 0.5, 0.25]);
+        let [r, g, b] = palette.sample(0.0);
+        assert!((r - 1.0).abs() < 1e-6);
+        assert!(g.abs() < 1e-6);
+        assert!((b - 0.5).abs() < 1e-6);
+    }
+}
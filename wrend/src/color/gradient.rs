@@ -0,0 +1,159 @@
+use crate::{F32x4, Id, TextureCreateContext, TextureLink};
+use web_sys::{WebGl2RenderingContext, WebGlTexture};
+
+/// A piecewise-linear RGBA gradient defined by color stops between 0.0 and 1.0
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub struct Gradient {
+    stops: Vec<(f32, [f32; 4])>,
+}
+
+impl Gradient {
+    /// Creates an empty gradient (which samples as transparent black)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a color stop at `position`. Stops may be added in any order, and stops at a NaN
+    /// position are ignored.
+    pub fn add_stop(&mut self, position: f32, rgba: [f32; 4]) -> &mut Self {
+        if position.is_nan() {
+            return self;
+        }
+        let index = self.stops.partition_point(|(p, _)| *p <= position);
+        self.stops.insert(index, (position, rgba));
+        self
+    }
+
+    /// Color stops, sorted by position
+    pub fn stops(&self) -> &[(f32, [f32; 4])] {
+        &self.stops
+    }
+
+    /// Evaluates the gradient at `t`, clamping to the first and last stops (a NaN `t` samples the
+    /// first stop)
+    pub fn sample(&self, t: f32) -> [f32; 4] {
+        let (first, last) = match (self.stops.first(), self.stops.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return [0.0; 4],
+        };
+        if t.is_nan() || t <= first.0 {
+            return first.1;
+        }
+        if t >= last.0 {
+            return last.1;
+        }
+
+        let next = self.stops.partition_point(|(p, _)| *p <= t);
+        let (start_position, start) = self.stops[next - 1];
+        let (end_position, end) = self.stops[next];
+        let amount = (t - start_position) / (end_position - start_position);
+
+        let mut rgba = [0.0; 4];
+        for (i, channel) in rgba.iter_mut().enumerate() {
+            *channel = start[i] + (end[i] - start[i]) * amount;
+        }
+        rgba
+    }
+
+    /// Samples the gradient `width` times into 8-bit RGBA pixels
+    pub fn to_rgba8(&self, width: u32) -> Vec<u8> {
+        let width = width.max(1);
+        (0..width)
+            .flat_map(|x| {
+                let t = if width == 1 {
+                    0.0
+                } else {
+                    x as f32 / (width - 1) as f32
+                };
+                F32x4::from_array(self.sample(t)).to_unorm8()
+            })
+            .collect()
+    }
+
+    /// Creates a link for a `width` x 1 `RGBA8` texture containing this gradient, with linear
+    /// filtering and clamped edges, ready to be used as a color lookup table in a shader
+    pub fn texture_link<TextureId: Id>(
+        &self,
+        texture_id: TextureId,
+        width: u32,
+    ) -> TextureLink<TextureId> {
+        let pixels = self.to_rgba8(width);
+        let width = width.max(1) as i32;
+
+        TextureLink::new(
+            texture_id,
+            move |ctx: &TextureCreateContext| -> WebGlTexture {
+                let gl = ctx.gl();
+                let texture = gl
+                    .create_texture()
+                    .expect("Should be able to create gradient texture");
+                gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&texture));
+                gl.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+                    WebGl2RenderingContext::TEXTURE_2D,
+                    0,
+                    WebGl2RenderingContext::RGBA8 as i32,
+                    width,
+                    1,
+                    0,
+                    WebGl2RenderingContext::RGBA,
+                    WebGl2RenderingContext::UNSIGNED_BYTE,
+                    Some(&pixels),
+                )
+                .expect("Should be able to upload gradient texture data");
+                for (parameter, value) in [
+                    (
+                        WebGl2RenderingContext::TEXTURE_MIN_FILTER,
+                        WebGl2RenderingContext::LINEAR,
+                    ),
+                    (
+                        WebGl2RenderingContext::TEXTURE_MAG_FILTER,
+                        WebGl2RenderingContext::LINEAR,
+                    ),
+                    (
+                        WebGl2RenderingContext::TEXTURE_WRAP_S,
+                        WebGl2RenderingContext::CLAMP_TO_EDGE,
+                    ),
+                    (
+                        WebGl2RenderingContext::TEXTURE_WRAP_T,
+                        WebGl2RenderingContext::CLAMP_TO_EDGE,
+                    ),
+                ] {
+                    gl.tex_parameteri(WebGl2RenderingContext::TEXTURE_2D, parameter, value as i32);
+                }
+                gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, None);
+
+                texture
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Gradient;
+
+    #[test]
+    fn samples_between_stops() {
+        let mut gradient = Gradient::new();
+        gradient
+            .add_stop(1.0, [1.0, 1.0, 1.0, 1.0])
+            .add_stop(0.0, [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(gradient.sample(0.25), [0.25, 0.25, 0.25, 1.0]);
+        assert_eq!(gradient.sample(-1.0), [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(
+            gradient.to_rgba8(3),
+            vec![0, 0, 0, 255, 128, 128, 128, 255, 255, 255, 255, 255]
+        );
+    }
+
+    #[test]
+    fn samples_nan_as_the_first_stop() {
+        let mut gradient = Gradient::new();
+        gradient
+            .add_stop(0.0, [1.0, 0.0, 0.0, 1.0])
+            .add_stop(f32::NAN, [0.0, 1.0, 0.0, 1.0])
+            .add_stop(1.0, [0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(gradient.stops().len(), 2);
+        assert_eq!(gradient.sample(f32::NAN), [1.0, 0.0, 0.0, 1.0]);
+    }
+}
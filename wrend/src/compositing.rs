@@ -0,0 +1,36 @@
+#[cfg(feature = "compositing")]
+mod blur_pyramid;
+#[cfg(feature = "compositing")]
+mod color_vision_deficiency;
+#[cfg(feature = "compositing")]
+mod color_vision_filter;
+#[cfg(feature = "compositing")]
+mod comparison_wipe;
+mod compositing_shaders;
+#[cfg(feature = "compositing")]
+mod cross_fade;
+mod output_adjustment;
+mod output_pass;
+#[cfg(feature = "compositing")]
+mod overlay_layer;
+#[cfg(feature = "compositing")]
+mod transition_side;
+
+pub(crate) use output_pass::*;
+
+#[cfg(feature = "compositing")]
+pub use blur_pyramid::*;
+#[cfg(feature = "compositing")]
+pub use color_vision_deficiency::*;
+#[cfg(feature = "compositing")]
+pub use color_vision_filter::*;
+#[cfg(feature = "compositing")]
+pub use comparison_wipe::*;
+pub use compositing_shaders::*;
+#[cfg(feature = "compositing")]
+pub use cross_fade::*;
+pub use output_adjustment::*;
+#[cfg(feature = "compositing")]
+pub use overlay_layer::*;
+#[cfg(feature = "compositing")]
+pub use transition_side::*;
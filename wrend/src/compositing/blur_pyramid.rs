@@ -0,0 +1,190 @@
+use crate::{FramebufferAttachment, Id, TextureCreateContext, TextureLink};
+use std::cell::RefCell;
+use web_sys::{WebGl2RenderingContext, WebGlFramebuffer, WebGlTexture};
+
+/// A mip chain of successively downsampled and blurred copies of a texture, used for
+/// dual-filter (Kawase) blurs and bloom.
+///
+/// The pyramid owns a texture whose mip level 0 is half the size of the source, along with a
+/// framebuffer for each of its levels (created on first use). Its programs and texture are added
+/// with [crate::RendererDataBuilder::add_blur_pyramid], and it is rendered with
+/// [crate::RendererData::render_blur_pyramid] (or with separate downsample and upsample passes,
+/// to add custom passes in between).
+///
+/// After a full render, mip level 0 holds the blurred source.
+#[derive(Debug, Clone)]
+pub struct BlurPyramid<ProgramId: Id, TextureId: Id> {
+    downsample_program_id: ProgramId,
+    upsample_program_id: ProgramId,
+    texture_id: TextureId,
+    width: u32,
+    height: u32,
+    levels: u32,
+    spread: f32,
+    framebuffers: RefCell<Vec<WebGlFramebuffer>>,
+}
+
+impl<ProgramId: Id, TextureId: Id> BlurPyramid<ProgramId, TextureId> {
+    /// Default number of mip levels, which blurs with a radius of roughly 2^5 source pixels
+    pub const DEFAULT_LEVELS: u32 = 5;
+
+    /// `source_width` and `source_height` are the size of the texture that will be blurred
+    pub fn new(
+        downsample_program_id: ProgramId,
+        upsample_program_id: ProgramId,
+        texture_id: TextureId,
+        source_width: u32,
+        source_height: u32,
+    ) -> Self {
+        let width = (source_width / 2).max(1);
+        let height = (source_height / 2).max(1);
+        Self {
+            downsample_program_id,
+            upsample_program_id,
+            texture_id,
+            width,
+            height,
+            levels: Self::DEFAULT_LEVELS.min(Self::max_levels(width, height)),
+            spread: 1.0,
+            framebuffers: Default::default(),
+        }
+    }
+
+    /// Most mip levels a texture of this size can have
+    pub fn max_levels(width: u32, height: u32) -> u32 {
+        u32::BITS - width.max(height).max(1).leading_zeros()
+    }
+
+    /// Sets the number of levels, limited to what the texture's size allows.
+    /// Must be called before the pyramid's texture is created.
+    pub fn set_levels(&mut self, levels: u32) -> &mut Self {
+        self.levels = levels.clamp(1, Self::max_levels(self.width, self.height));
+        self
+    }
+
+    /// Scales the distance between samples: values above 1.0 blur further at the cost of artifacts
+    pub fn set_spread(&mut self, spread: f32) -> &mut Self {
+        self.spread = spread.max(0.0);
+        self
+    }
+
+    pub fn downsample_program_id(&self) -> &ProgramId {
+        &self.downsample_program_id
+    }
+
+    pub fn upsample_program_id(&self) -> &ProgramId {
+        &self.upsample_program_id
+    }
+
+    /// The pyramid's texture, whose mip level 0 holds the result
+    pub fn texture_id(&self) -> &TextureId {
+        &self.texture_id
+    }
+
+    pub fn levels(&self) -> u32 {
+        self.levels
+    }
+
+    pub fn spread(&self) -> f32 {
+        self.spread
+    }
+
+    /// Size of a mip level of the pyramid's texture
+    pub fn level_size(&self, level: u32) -> (u32, u32) {
+        FramebufferAttachment::new()
+            .with_mip_level(level as i32)
+            .size(self.width, self.height)
+    }
+
+    /// Link that creates the pyramid's texture, with storage for every level
+    pub fn texture_link(&self) -> TextureLink<TextureId> {
+        let (width, height, levels) = (self.width, self.height, self.levels);
+        TextureLink::new(
+            self.texture_id.clone(),
+            move |ctx: &TextureCreateContext| {
+                let gl = ctx.gl();
+                let texture = gl
+                    .create_texture()
+                    .expect("Should be able to create a texture for a BlurPyramid");
+                gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&texture));
+                gl.tex_storage_2d(
+                    WebGl2RenderingContext::TEXTURE_2D,
+                    levels as i32,
+                    WebGl2RenderingContext::RGBA8,
+                    width as i32,
+                    height as i32,
+                );
+                for (parameter, value) in [
+                    (
+                        WebGl2RenderingContext::TEXTURE_MIN_FILTER,
+                        WebGl2RenderingContext::LINEAR,
+                    ),
+                    (
+                        WebGl2RenderingContext::TEXTURE_MAG_FILTER,
+                        WebGl2RenderingContext::LINEAR,
+                    ),
+                    (
+                        WebGl2RenderingContext::TEXTURE_WRAP_S,
+                        WebGl2RenderingContext::CLAMP_TO_EDGE,
+                    ),
+                    (
+                        WebGl2RenderingContext::TEXTURE_WRAP_T,
+                        WebGl2RenderingContext::CLAMP_TO_EDGE,
+                    ),
+                ] {
+                    gl.tex_parameteri(WebGl2RenderingContext::TEXTURE_2D, parameter, value as i32);
+                }
+                gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, None);
+                texture
+            },
+        )
+    }
+
+    /// Framebuffer that renders into `level` of the pyramid's texture
+    pub(crate) fn framebuffer(
+        &self,
+        gl: &WebGl2RenderingContext,
+        texture: &WebGlTexture,
+        level: u32,
+    ) -> WebGlFramebuffer {
+        let mut framebuffers = self.framebuffers.borrow_mut();
+        while framebuffers.len() <= level as usize {
+            let framebuffer = gl
+                .create_framebuffer()
+                .expect("Should be able to create a framebuffer for a BlurPyramid level");
+            gl.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, Some(&framebuffer));
+            FramebufferAttachment::new()
+                .with_mip_level(framebuffers.len() as i32)
+                .attach(gl, texture);
+            framebuffers.push(framebuffer);
+        }
+        framebuffers[level as usize].clone()
+    }
+
+    /// Deletes the framebuffers created for each level (they are recreated if the pyramid is used again)
+    pub fn delete_framebuffers(&self, gl: &WebGl2RenderingContext) {
+        for framebuffer in self.framebuffers.borrow_mut().drain(..) {
+            gl.delete_framebuffer(Some(&framebuffer));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BlurPyramid;
+
+    #[test]
+    fn levels_are_limited_by_size() {
+        let mut pyramid = BlurPyramid::new("down", "up", "bloom", 1920, 1080);
+        assert_eq!(pyramid.levels(), BlurPyramid::<&str, &str>::DEFAULT_LEVELS);
+        assert_eq!(pyramid.level_size(0), (960, 540));
+        assert_eq!(pyramid.level_size(4), (60, 33));
+
+        pyramid.set_levels(100);
+        assert_eq!(pyramid.levels(), 10);
+        assert_eq!(pyramid.level_size(9), (1, 1));
+
+        let tiny = BlurPyramid::new("down", "up", "bloom", 4, 2);
+        assert_eq!(tiny.levels(), 2);
+    }
+}
@@ -0,0 +1,74 @@
+/// A type of dichromatic color blindness that a [crate::ColorVisionFilter] can simulate
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum ColorVisionDeficiency {
+    /// Missing long-wavelength (red) cones
+    #[default]
+    Protanopia,
+    /// Missing medium-wavelength (green) cones
+    Deuteranopia,
+    /// Missing short-wavelength (blue) cones
+    Tritanopia,
+}
+
+impl ColorVisionDeficiency {
+    /// Simulation matrix for full severity in linear RGB, row by row
+    /// (from Machado, Oliveira & Fernandes, 2009)
+    fn full_matrix(&self) -> [[f32; 3]; 3] {
+        match self {
+            ColorVisionDeficiency::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            ColorVisionDeficiency::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            ColorVisionDeficiency::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        }
+    }
+
+    /// Simulation matrix in linear RGB for a `severity` from 0.0 (normal vision) to 1.0, in
+    /// column-major order for `uniformMatrix3fv`. Partial severities are interpolated from the
+    /// identity matrix, which approximates anomalous trichromacy.
+    pub fn matrix(&self, severity: f32) -> [f32; 9] {
+        let severity = severity.clamp(0.0, 1.0);
+        let full = self.full_matrix();
+        let mut matrix = [0.0; 9];
+        for column in 0..3 {
+            for row in 0..3 {
+                let identity = if row == column { 1.0 } else { 0.0 };
+                matrix[column * 3 + row] = identity + (full[row][column] - identity) * severity;
+            }
+        }
+        matrix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ColorVisionDeficiency;
+
+    #[test]
+    fn matrices_preserve_white() {
+        let identity = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+        for deficiency in [
+            ColorVisionDeficiency::Protanopia,
+            ColorVisionDeficiency::Deuteranopia,
+            ColorVisionDeficiency::Tritanopia,
+        ] {
+            assert_eq!(deficiency.matrix(0.0), identity);
+            let matrix = deficiency.matrix(1.0);
+            for row in 0..3 {
+                let white = matrix[row] + matrix[3 + row] + matrix[6 + row];
+                assert!((white - 1.0).abs() < 1e-3, "{deficiency:?}: {white}");
+            }
+        }
+        assert_eq!(ColorVisionDeficiency::Protanopia.matrix(1.0)[3], 1.052583);
+    }
+}
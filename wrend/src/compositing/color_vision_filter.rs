@@ -0,0 +1,53 @@
+use crate::{ColorVisionDeficiency, Id};
+
+/// Simulates how a texture (e.g. the output of a visualization) looks with a
+/// [ColorVisionDeficiency], to check that it stays readable for color-blind viewers.
+///
+/// The program is added with [crate::RendererDataBuilder::add_color_vision_filter] and drawn to
+/// the canvas with [crate::RendererData::draw_color_vision_filter]. Colors are simulated in linear
+/// RGB, so the texture is expected to contain sRGB-encoded colors, as 8-bit textures usually do.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct ColorVisionFilter<ProgramId: Id> {
+    program_id: ProgramId,
+    deficiency: ColorVisionDeficiency,
+    severity: f32,
+}
+
+impl<ProgramId: Id> ColorVisionFilter<ProgramId> {
+    /// Simulates `deficiency` at full severity
+    pub fn new(program_id: ProgramId, deficiency: ColorVisionDeficiency) -> Self {
+        Self {
+            program_id,
+            deficiency,
+            severity: 1.0,
+        }
+    }
+
+    pub fn set_deficiency(&mut self, deficiency: ColorVisionDeficiency) -> &mut Self {
+        self.deficiency = deficiency;
+        self
+    }
+
+    /// From 0.0 (normal vision) to 1.0 (dichromacy)
+    pub fn set_severity(&mut self, severity: f32) -> &mut Self {
+        self.severity = severity.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn program_id(&self) -> &ProgramId {
+        &self.program_id
+    }
+
+    pub fn deficiency(&self) -> ColorVisionDeficiency {
+        self.deficiency
+    }
+
+    pub fn severity(&self) -> f32 {
+        self.severity
+    }
+
+    /// Value of the `u_matrix` uniform in [crate::COLOR_VISION_FRAGMENT_SHADER]
+    pub fn matrix(&self) -> [f32; 9] {
+        self.deficiency.matrix(self.severity)
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{Id, PointerState};
+#[cfg(feature = "compositing")]
+use crate::{IdName, RendererData};
+use std::cell::Cell;
+
+/// A side-by-side comparison of two textures (e.g. a scene before and after a filter),
+/// split at a position that can be dragged with the pointer.
+///
+/// Drawn with a program added by [crate::RendererDataBuilder::add_comparison_wipe_program].
+/// See [ComparisonWipe::draw].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComparisonWipe<ProgramId: Id, TextureId: Id> {
+    program_id: ProgramId,
+    a: TextureId,
+    b: TextureId,
+    split: Cell<f32>,
+    follow_pointer: bool,
+    divider_width_px: f32,
+}
+
+impl<ProgramId: Id, TextureId: Id> ComparisonWipe<ProgramId, TextureId> {
+    /// `a` is shown left of the split and `b` right of it. The split starts in the middle.
+    pub fn new(program_id: ProgramId, a: TextureId, b: TextureId) -> Self {
+        Self {
+            program_id,
+            a,
+            b,
+            split: Cell::new(0.5),
+            follow_pointer: false,
+            divider_width_px: 2.0,
+        }
+    }
+
+    pub fn program_id(&self) -> &ProgramId {
+        &self.program_id
+    }
+
+    pub fn a(&self) -> &TextureId {
+        &self.a
+    }
+
+    pub fn b(&self) -> &TextureId {
+        &self.b
+    }
+
+    /// Normalized x position (0.0 is the left edge of the canvas) of the split
+    pub fn split(&self) -> f32 {
+        self.split.get()
+    }
+
+    pub fn set_split(&self, split: f32) -> &Self {
+        self.split.set(split.clamp(0.0, 1.0));
+        self
+    }
+
+    /// By default the split only moves while the pointer is held down (dragged).
+    /// When `follow_pointer` is `true`, it tracks the pointer whenever it moves over the canvas.
+    pub fn set_follow_pointer(&mut self, follow_pointer: bool) -> &mut Self {
+        self.follow_pointer = follow_pointer;
+        self
+    }
+
+    pub fn divider_width_px(&self) -> f32 {
+        self.divider_width_px
+    }
+
+    /// Width of the divider line drawn at the split. `0.0` hides it
+    pub fn set_divider_width_px(&mut self, divider_width_px: f32) -> &mut Self {
+        self.divider_width_px = divider_width_px.max(0.0);
+        self
+    }
+
+    /// Moves the split to the pointer, if it is being dragged (or followed)
+    pub fn update_from_pointer(&self, pointer_state: &PointerState) -> &Self {
+        if let Some([x, _]) = pointer_state.position() {
+            if self.follow_pointer || pointer_state.is_down() {
+                self.set_split(x);
+            }
+        }
+        self
+    }
+
+    /// Draws a [ComparisonWipe] to the canvas, first moving its split to the pointer if it is being dragged
+    #[cfg(feature = "compositing")]
+    pub fn draw<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        UniformId: Id + IdName,
+        BufferId: Id,
+        AttributeId: Id + IdName,
+        FramebufferId: Id,
+        TransformFeedbackId: Id,
+        VertexArrayObjectId: Id,
+        UserCtx: Clone,
+    >(
+        &self,
+        renderer_data: &RendererData<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+    ) -> &Self {
+        if let Some(pointer_tracker) = renderer_data.pointer_tracker() {
+            self.update_from_pointer(&pointer_tracker.state());
+        }
+
+        renderer_data.draw_fullscreen_pass(
+            self.program_id(),
+            &[
+                ("u_a", renderer_data.webgl_texture(self.a())),
+                ("u_b", renderer_data.webgl_texture(self.b())),
+            ],
+            |gl, program| {
+                let divider_width =
+                    self.divider_width_px() / gl.drawing_buffer_width().max(1) as f32;
+                gl.uniform1f(
+                    gl.get_uniform_location(program, "u_split").as_ref(),
+                    self.split(),
+                );
+                gl.uniform1f(
+                    gl.get_uniform_location(program, "u_divider_width").as_ref(),
+                    divider_width,
+                );
+            },
+        );
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ComparisonWipe;
+    use crate::PointerState;
+
+    #[test]
+    fn split_follows_dragged_pointer() {
+        let mut wipe = ComparisonWipe::new("wipe".to_string(), "a".to_string(), "b".to_string());
+        assert_eq!(wipe.split(), 0.5);
+
+        wipe.update_from_pointer(&PointerState::new(Some([0.2, 0.9]), false));
+        assert_eq!(wipe.split(), 0.5);
+        wipe.update_from_pointer(&PointerState::new(Some([0.2, 0.9]), true));
+        assert_eq!(wipe.split(), 0.2);
+
+        wipe.set_follow_pointer(true)
+            .update_from_pointer(&PointerState::new(Some([0.7, 0.1]), false));
+        assert_eq!(wipe.split(), 0.7);
+        wipe.update_from_pointer(&PointerState::new(None, false));
+        assert_eq!(wipe.split(), 0.7);
+        assert_eq!(wipe.set_split(3.0).split(), 1.0);
+    }
+}
@@ -0,0 +1,160 @@
+/// Attribute-less vertex shader for compositing passes: draws a fullscreen triangle
+/// (3 vertices, no VAO needed) and passes texture coordinates to the fragment shader as `v_uv`.
+pub const COMPOSITING_VERTEX_SHADER: &str = r#"#version 300 es
+out vec2 v_uv;
+
+void main() {
+    v_uv = vec2(float((gl_VertexID << 1) & 2), float(gl_VertexID & 2));
+    gl_Position = vec4(v_uv * 2.0 - 1.0, 0.0, 1.0);
+}
+"#;
+
+/// Blends `u_from` into `u_to` by `u_progress` (0.0 shows only `u_from`, 1.0 only `u_to`)
+#[cfg(feature = "compositing")]
+pub const CROSS_FADE_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform sampler2D u_from;
+uniform sampler2D u_to;
+uniform float u_progress;
+
+in vec2 v_uv;
+out vec4 out_color;
+
+void main() {
+    out_color = mix(texture(u_from, v_uv), texture(u_to, v_uv), u_progress);
+}
+"#;
+
+/// Shows `u_a` left of `u_split` (a normalized x position) and `u_b` to its right,
+/// with a divider `u_divider_width` (also normalized) wide
+#[cfg(feature = "compositing")]
+pub const COMPARISON_WIPE_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform sampler2D u_a;
+uniform sampler2D u_b;
+uniform float u_split;
+uniform float u_divider_width;
+
+in vec2 v_uv;
+out vec4 out_color;
+
+void main() {
+    vec4 color = v_uv.x < u_split ? texture(u_a, v_uv) : texture(u_b, v_uv);
+    float divider = step(abs(v_uv.x - u_split), u_divider_width * 0.5);
+    out_color = mix(color, vec4(1.0), divider);
+}
+"#;
+
+/// Samples a premultiplied-alpha overlay, scaled by `u_opacity`
+#[cfg(feature = "compositing")]
+pub const OVERLAY_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform sampler2D u_overlay;
+uniform float u_opacity;
+
+in vec2 v_uv;
+out vec4 out_color;
+
+void main() {
+    out_color = texture(u_overlay, v_uv) * u_opacity;
+}
+"#;
+
+/// Dual-filter (Kawase) downsample: averages a 5-tap pattern of `u_source` around each texel,
+/// `u_half_texel` (half a source texel, scaled by the blur spread) apart
+#[cfg(feature = "compositing")]
+pub const BLUR_DOWNSAMPLE_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform sampler2D u_source;
+uniform vec2 u_half_texel;
+
+in vec2 v_uv;
+out vec4 out_color;
+
+void main() {
+    vec4 sum = texture(u_source, v_uv) * 4.0;
+    sum += texture(u_source, v_uv - u_half_texel);
+    sum += texture(u_source, v_uv + u_half_texel);
+    sum += texture(u_source, v_uv + vec2(u_half_texel.x, -u_half_texel.y));
+    sum += texture(u_source, v_uv - vec2(u_half_texel.x, -u_half_texel.y));
+    out_color = sum / 8.0;
+}
+"#;
+
+/// Dual-filter (Kawase) upsample: a weighted 8-tap tent around each texel of `u_source`
+#[cfg(feature = "compositing")]
+pub const BLUR_UPSAMPLE_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform sampler2D u_source;
+uniform vec2 u_half_texel;
+
+in vec2 v_uv;
+out vec4 out_color;
+
+void main() {
+    vec4 sum = texture(u_source, v_uv + vec2(-u_half_texel.x * 2.0, 0.0));
+    sum += texture(u_source, v_uv + vec2(-u_half_texel.x, u_half_texel.y)) * 2.0;
+    sum += texture(u_source, v_uv + vec2(0.0, u_half_texel.y * 2.0));
+    sum += texture(u_source, v_uv + vec2(u_half_texel.x, u_half_texel.y)) * 2.0;
+    sum += texture(u_source, v_uv + vec2(u_half_texel.x * 2.0, 0.0));
+    sum += texture(u_source, v_uv + vec2(u_half_texel.x, -u_half_texel.y)) * 2.0;
+    sum += texture(u_source, v_uv + vec2(0.0, -u_half_texel.y * 2.0));
+    sum += texture(u_source, v_uv + vec2(-u_half_texel.x, -u_half_texel.y)) * 2.0;
+    out_color = sum / 12.0;
+}
+"#;
+
+/// Applies the color vision simulation matrix `u_matrix` to `u_source` in linear RGB, converting
+/// from and back to sRGB around it
+#[cfg(feature = "compositing")]
+pub const COLOR_VISION_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform sampler2D u_source;
+uniform mat3 u_matrix;
+
+in vec2 v_uv;
+out vec4 out_color;
+
+vec3 to_linear(vec3 srgb) {
+    return mix(srgb / 12.92, pow((srgb + 0.055) / 1.055, vec3(2.4)), step(0.04045, srgb));
+}
+
+vec3 to_srgb(vec3 linear) {
+    return mix(linear * 12.92, 1.055 * pow(linear, vec3(1.0 / 2.4)) - 0.055, step(0.0031308, linear));
+}
+
+void main() {
+    vec4 color = texture(u_source, v_uv);
+    vec3 simulated = clamp(u_matrix * to_linear(color.rgb), 0.0, 1.0);
+    out_color = vec4(to_srgb(simulated), color.a);
+}
+"#;
+
+/// Applies an [crate::OutputAdjustment] to `u_source` (a copy of the canvas): contrast around
+/// mid-gray, then `u_brightness`, then gamma. Colors are unpremultiplied first, since canvases are
+/// composited with premultiplied alpha by default.
+pub const OUTPUT_ADJUSTMENT_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform sampler2D u_source;
+uniform float u_brightness;
+uniform float u_contrast;
+uniform float u_gamma;
+
+in vec2 v_uv;
+out vec4 out_color;
+
+void main() {
+    vec4 color = texture(u_source, v_uv);
+    vec3 rgb = color.a > 0.0 ? color.rgb / color.a : color.rgb;
+    rgb = (rgb - 0.5) * u_contrast + 0.5 + u_brightness;
+    rgb = pow(clamp(rgb, 0.0, 1.0), vec3(1.0 / u_gamma));
+    out_color = vec4(rgb * color.a, color.a);
+}
+"#;
@@ -0,0 +1,184 @@
+use crate::{Easing, Id};
+#[cfg(feature = "compositing")]
+use crate::{IdName, RendererData, TransitionSide};
+
+/// Cross-fades from one pipeline's output to another's over a fixed duration,
+/// e.g. when switching between filters.
+///
+/// While a fade is running, both pipelines render into their own framebuffer and the outputs are
+/// blended onto the canvas with a program added by [crate::RendererDataBuilder::add_cross_fade_program].
+/// See [CrossFade::render].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossFade<ProgramId: Id, FramebufferId: Id, TextureId: Id> {
+    program_id: ProgramId,
+    from: (FramebufferId, TextureId),
+    to: (FramebufferId, TextureId),
+    duration_ms: f64,
+    easing: Easing,
+    start_ms: Option<f64>,
+}
+
+impl<ProgramId: Id, FramebufferId: Id, TextureId: Id>
+    CrossFade<ProgramId, FramebufferId, TextureId>
+{
+    /// Default duration of a fade, in milliseconds
+    pub const DEFAULT_DURATION_MS: f64 = 500.0;
+
+    /// `from` and `to` are the framebuffers each side is rendered into during a fade,
+    /// along with the textures attached to them
+    pub fn new(
+        program_id: ProgramId,
+        from: (FramebufferId, TextureId),
+        to: (FramebufferId, TextureId),
+    ) -> Self {
+        Self {
+            program_id,
+            from,
+            to,
+            duration_ms: Self::DEFAULT_DURATION_MS,
+            easing: Easing::SineInOut,
+            start_ms: None,
+        }
+    }
+
+    pub fn program_id(&self) -> &ProgramId {
+        &self.program_id
+    }
+
+    pub fn from(&self) -> (&FramebufferId, &TextureId) {
+        (&self.from.0, &self.from.1)
+    }
+
+    pub fn to(&self) -> (&FramebufferId, &TextureId) {
+        (&self.to.0, &self.to.1)
+    }
+
+    pub fn duration_ms(&self) -> f64 {
+        self.duration_ms
+    }
+
+    pub fn set_duration_ms(&mut self, duration_ms: f64) -> &mut Self {
+        self.duration_ms = duration_ms.max(0.0);
+        self
+    }
+
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    pub fn set_easing(&mut self, easing: Easing) -> &mut Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Starts a fade at `now_ms` (restarting any fade already in progress)
+    pub fn start(&mut self, now_ms: f64) -> &mut Self {
+        self.start_ms = Some(now_ms);
+        self
+    }
+
+    /// Jumps straight to the `to` side
+    pub fn cancel(&mut self) -> &mut Self {
+        self.start_ms = None;
+        self
+    }
+
+    /// Eased progress of the fade from 0.0 to 1.0, or `None` if no fade is running at `now_ms`
+    pub fn progress(&self, now_ms: f64) -> Option<f32> {
+        let elapsed = now_ms - self.start_ms?;
+        if elapsed >= self.duration_ms {
+            return None;
+        }
+        let t = (elapsed.max(0.0) / self.duration_ms) as f32;
+        Some(self.easing.apply(t))
+    }
+
+    pub fn is_active(&self, now_ms: f64) -> bool {
+        self.progress(now_ms).is_some()
+    }
+
+    /// Renders a [CrossFade]: while a fade is running, `render_side` is called once for each side with the
+    /// framebuffer it should render into, and the two results are blended onto the canvas.
+    /// Otherwise, `render_side` is only called for [TransitionSide::To], rendering straight to the canvas.
+    #[cfg(feature = "compositing")]
+    pub fn render<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        UniformId: Id + IdName,
+        BufferId: Id,
+        AttributeId: Id + IdName,
+        TransformFeedbackId: Id,
+        VertexArrayObjectId: Id,
+        UserCtx: Clone,
+    >(
+        &self,
+        renderer_data: &RendererData<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+        render_side: impl Fn(TransitionSide, Option<&FramebufferId>),
+    ) -> &Self {
+        let progress = match self.progress(renderer_data.frame_time()) {
+            Some(progress) => progress,
+            None => {
+                render_side(TransitionSide::To, None);
+                return self;
+            }
+        };
+
+        let (from_framebuffer_id, from_texture_id) = self.from();
+        let (to_framebuffer_id, to_texture_id) = self.to();
+        render_side(TransitionSide::From, Some(from_framebuffer_id));
+        render_side(TransitionSide::To, Some(to_framebuffer_id));
+
+        renderer_data.draw_fullscreen_pass(
+            self.program_id(),
+            &[
+                ("u_from", renderer_data.webgl_texture(from_texture_id)),
+                ("u_to", renderer_data.webgl_texture(to_texture_id)),
+            ],
+            |gl, program| {
+                gl.uniform1f(
+                    gl.get_uniform_location(program, "u_progress").as_ref(),
+                    progress,
+                );
+            },
+        );
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CrossFade;
+    use crate::Easing;
+
+    #[test]
+    fn progresses_over_duration() {
+        let mut fade = CrossFade::new(
+            "fade".to_string(),
+            ("a".to_string(), "a_texture".to_string()),
+            ("b".to_string(), "b_texture".to_string()),
+        );
+        fade.set_duration_ms(100.0).set_easing(Easing::Linear);
+        assert_eq!(fade.progress(0.0), None);
+
+        fade.start(1000.0);
+        assert_eq!(fade.progress(1000.0), Some(0.0));
+        assert_eq!(fade.progress(1025.0), Some(0.25));
+        assert!(fade.is_active(1099.0));
+        assert_eq!(fade.progress(1100.0), None);
+
+        fade.start(2000.0).cancel();
+        assert!(!fade.is_active(2000.0));
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{Id, SettingsError, SettingsStore};
+
+/// [SettingsStore] setting that [OutputAdjustment::brightness] is persisted under
+pub const OUTPUT_BRIGHTNESS_SETTING: &str = "output_brightness";
+/// [SettingsStore] setting that [OutputAdjustment::contrast] is persisted under
+pub const OUTPUT_CONTRAST_SETTING: &str = "output_contrast";
+/// [SettingsStore] setting that [OutputAdjustment::gamma] is persisted under
+pub const OUTPUT_GAMMA_SETTING: &str = "output_gamma";
+
+/// Brightness, contrast and gamma adjustments that let viewers tune the visibility of the final
+/// image (e.g. for low vision, or a dim screen), whatever the app's render callback draws.
+///
+/// The program is added with [crate::RendererDataBuilder::add_output_adjustment], and the
+/// adjustment is applied to the canvas at the end of every frame once set with
+/// [crate::RendererData::set_output_adjustment]. To remember a viewer's choice across page loads,
+/// see [crate::Renderer::persist_output_adjustment].
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct OutputAdjustment<ProgramId: Id> {
+    program_id: ProgramId,
+    brightness: f32,
+    contrast: f32,
+    gamma: f32,
+}
+
+impl<ProgramId: Id> OutputAdjustment<ProgramId> {
+    /// Leaves the image unchanged until adjusted
+    pub fn new(program_id: ProgramId) -> Self {
+        Self {
+            program_id,
+            brightness: 0.0,
+            contrast: 1.0,
+            gamma: 1.0,
+        }
+    }
+
+    pub fn program_id(&self) -> &ProgramId {
+        &self.program_id
+    }
+
+    pub fn brightness(&self) -> f32 {
+        self.brightness
+    }
+
+    /// Added to every color channel, from -1.0 to 1.0
+    pub fn set_brightness(&mut self, brightness: f32) -> &mut Self {
+        self.brightness = if brightness.is_nan() {
+            0.0
+        } else {
+            brightness.clamp(-1.0, 1.0)
+        };
+        self
+    }
+
+    pub fn contrast(&self) -> f32 {
+        self.contrast
+    }
+
+    /// Scales colors away from (above 1.0) or toward (below 1.0) mid-gray, from 0.0 to 4.0
+    pub fn set_contrast(&mut self, contrast: f32) -> &mut Self {
+        self.contrast = if contrast.is_nan() {
+            1.0
+        } else {
+            contrast.clamp(0.0, 4.0)
+        };
+        self
+    }
+
+    pub fn gamma(&self) -> f32 {
+        self.gamma
+    }
+
+    /// Brightens midtones when above 1.0 and darkens them below it, from 0.1 to 10.0
+    pub fn set_gamma(&mut self, gamma: f32) -> &mut Self {
+        self.gamma = if gamma.is_nan() {
+            1.0
+        } else {
+            gamma.clamp(0.1, 10.0)
+        };
+        self
+    }
+
+    /// Whether the adjustment would leave the image unchanged, in which case it is skipped
+    pub fn is_identity(&self) -> bool {
+        self.brightness == 0.0 && self.contrast == 1.0 && self.gamma == 1.0
+    }
+
+    /// Registers the brightness, contrast and gamma settings with `store`, using the current
+    /// values as their defaults
+    pub fn register_settings(&self, store: &SettingsStore) -> &Self {
+        store
+            .register(OUTPUT_BRIGHTNESS_SETTING, self.brightness)
+            .register(OUTPUT_CONTRAST_SETTING, self.contrast)
+            .register(OUTPUT_GAMMA_SETTING, self.gamma);
+        self
+    }
+
+    /// Reads the brightness, contrast and gamma from `store`, keeping the current value of any
+    /// that aren't registered
+    pub fn apply_settings(&mut self, store: &SettingsStore) -> &mut Self {
+        if let Some(brightness) = store.get_f32(OUTPUT_BRIGHTNESS_SETTING) {
+            self.set_brightness(brightness);
+        }
+        if let Some(contrast) = store.get_f32(OUTPUT_CONTRAST_SETTING) {
+            self.set_contrast(contrast);
+        }
+        if let Some(gamma) = store.get_f32(OUTPUT_GAMMA_SETTING) {
+            self.set_gamma(gamma);
+        }
+        self
+    }
+
+    /// Writes the brightness, contrast and gamma to `store`, which persists them.
+    ///
+    /// The settings must have been registered first (see [OutputAdjustment::register_settings]).
+    pub fn save_settings(&self, store: &SettingsStore) -> Result<(), SettingsError> {
+        store.set(OUTPUT_BRIGHTNESS_SETTING, self.brightness)?;
+        store.set(OUTPUT_CONTRAST_SETTING, self.contrast)?;
+        store.set(OUTPUT_GAMMA_SETTING, self.gamma)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_settings() {
+        let mut adjustment = OutputAdjustment::new(0u32);
+        assert!(adjustment.is_identity());
+
+        adjustment
+            .set_brightness(0.2)
+            .set_contrast(8.0)
+            .set_gamma(f32::NAN);
+        assert_eq!(adjustment.contrast(), 4.0);
+        assert_eq!(adjustment.gamma(), 1.0);
+
+        let store = SettingsStore::new("test");
+        adjustment.register_settings(&store);
+
+        let mut restored = OutputAdjustment::new(0u32);
+        restored.apply_settings(&store);
+        assert_eq!(restored, adjustment);
+        assert!(!restored.is_identity());
+    }
+}
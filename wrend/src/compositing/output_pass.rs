@@ -0,0 +1,94 @@
+use crate::{Id, OutputAdjustment};
+use std::cell::RefCell;
+use wasm_bindgen::JsValue;
+use web_sys::{WebGl2RenderingContext, WebGlTexture};
+
+/// The [OutputAdjustment] applied at the end of every frame, along with the texture that the
+/// canvas is copied into so that it can be drawn back through the adjustment
+#[derive(Debug, Clone)]
+pub(crate) struct OutputPass<ProgramId: Id> {
+    adjustment: RefCell<Option<OutputAdjustment<ProgramId>>>,
+    texture: RefCell<Option<WebGlTexture>>,
+}
+
+impl<ProgramId: Id> OutputPass<ProgramId> {
+    pub fn adjustment(&self) -> Option<OutputAdjustment<ProgramId>> {
+        self.adjustment.borrow().clone()
+    }
+
+    pub fn set_adjustment(&self, adjustment: Option<OutputAdjustment<ProgramId>>) -> &Self {
+        self.adjustment.replace(adjustment);
+        self
+    }
+
+    /// The texture to copy the canvas into, created the first time it is needed
+    pub fn texture(&self, gl: &WebGl2RenderingContext) -> Result<WebGlTexture, JsValue> {
+        let mut texture = self.texture.borrow_mut();
+        if let Some(texture) = texture.as_ref() {
+            return Ok(texture.clone());
+        }
+
+        let webgl_texture = gl
+            .create_texture()
+            .ok_or_else(|| JsValue::from_str("createTexture returned null"))?;
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&webgl_texture));
+        // the copy is the same size as the canvas, so it's sampled texel for texel
+        for (parameter, value) in [
+            (
+                WebGl2RenderingContext::TEXTURE_MIN_FILTER,
+                WebGl2RenderingContext::NEAREST,
+            ),
+            (
+                WebGl2RenderingContext::TEXTURE_MAG_FILTER,
+                WebGl2RenderingContext::NEAREST,
+            ),
+            (
+                WebGl2RenderingContext::TEXTURE_WRAP_S,
+                WebGl2RenderingContext::CLAMP_TO_EDGE,
+            ),
+            (
+                WebGl2RenderingContext::TEXTURE_WRAP_T,
+                WebGl2RenderingContext::CLAMP_TO_EDGE,
+            ),
+        ] {
+            gl.tex_parameteri(WebGl2RenderingContext::TEXTURE_2D, parameter, value as i32);
+        }
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, None);
+
+        *texture = Some(webgl_texture.clone());
+        Ok(webgl_texture)
+    }
+
+    /// The texture, if it has been created
+    pub fn created_texture(&self) -> Option<WebGlTexture> {
+        self.texture.borrow().clone()
+    }
+}
+
+impl<ProgramId: Id> Default for OutputPass<ProgramId> {
+    fn default() -> Self {
+        Self {
+            adjustment: RefCell::new(None),
+            texture: RefCell::new(None),
+        }
+    }
+}
+
+// adjustments are compared bitwise (like `FrameClock`'s times), so that comparisons are reflexive
+impl<ProgramId: Id> PartialEq for OutputPass<ProgramId> {
+    fn eq(&self, other: &Self) -> bool {
+        let key = |pass: &Self| {
+            pass.adjustment().map(|adjustment| {
+                (
+                    adjustment.program_id().clone(),
+                    adjustment.brightness().to_bits(),
+                    adjustment.contrast().to_bits(),
+                    adjustment.gamma().to_bits(),
+                )
+            })
+        };
+        key(self) == key(other) && self.created_texture() == other.created_texture()
+    }
+}
+
+impl<ProgramId: Id> Eq for OutputPass<ProgramId> {}
@@ -0,0 +1,119 @@
+use crate::Id;
+use std::cell::{Cell, RefCell};
+use wasm_bindgen::JsValue;
+use web_sys::{HtmlCanvasElement, WebGl2RenderingContext, WebGlTexture};
+
+/// A user-managed 2D canvas (UI, captions, charts, ...) that is composited over the WebGL output,
+/// so that it shows up in recordings and screenshots, which don't capture DOM overlays.
+///
+/// The canvas is only re-uploaded to the GPU after [OverlayLayer::mark_dirty] is called, so call it
+/// whenever you draw to the canvas. Composited with a program added by
+/// [crate::RendererDataBuilder::add_overlay_program]. See [crate::RendererData::draw_overlay].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverlayLayer<ProgramId: Id> {
+    program_id: ProgramId,
+    canvas: HtmlCanvasElement,
+    texture: RefCell<Option<WebGlTexture>>,
+    dirty: Cell<bool>,
+    opacity: f32,
+}
+
+impl<ProgramId: Id> OverlayLayer<ProgramId> {
+    pub fn new(program_id: ProgramId, canvas: HtmlCanvasElement) -> Self {
+        Self {
+            program_id,
+            canvas,
+            texture: RefCell::new(None),
+            dirty: Cell::new(true),
+            opacity: 1.0,
+        }
+    }
+
+    pub fn program_id(&self) -> &ProgramId {
+        &self.program_id
+    }
+
+    /// The 2D canvas to draw the overlay into
+    pub fn canvas(&self) -> &HtmlCanvasElement {
+        &self.canvas
+    }
+
+    /// Flags the canvas as changed, so that it is uploaded again the next time it is drawn
+    pub fn mark_dirty(&self) -> &Self {
+        self.dirty.set(true);
+        self
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.get()
+    }
+
+    pub fn opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    pub fn set_opacity(&mut self, opacity: f32) -> &mut Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Uploads the canvas if it is dirty, creating the layer's texture the first time
+    pub(crate) fn upload(&self, gl: &WebGl2RenderingContext) -> Result<WebGlTexture, JsValue> {
+        let mut texture = self.texture.borrow_mut();
+        if let (Some(texture), false) = (texture.as_ref(), self.dirty.get()) {
+            return Ok(texture.clone());
+        }
+
+        let webgl_texture = match texture.as_ref() {
+            Some(texture) => texture.clone(),
+            None => {
+                let webgl_texture = gl
+                    .create_texture()
+                    .ok_or_else(|| JsValue::from_str("createTexture returned null"))?;
+                gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&webgl_texture));
+                for (parameter, value) in [
+                    (
+                        WebGl2RenderingContext::TEXTURE_MIN_FILTER,
+                        WebGl2RenderingContext::LINEAR,
+                    ),
+                    (
+                        WebGl2RenderingContext::TEXTURE_MAG_FILTER,
+                        WebGl2RenderingContext::LINEAR,
+                    ),
+                    (
+                        WebGl2RenderingContext::TEXTURE_WRAP_S,
+                        WebGl2RenderingContext::CLAMP_TO_EDGE,
+                    ),
+                    (
+                        WebGl2RenderingContext::TEXTURE_WRAP_T,
+                        WebGl2RenderingContext::CLAMP_TO_EDGE,
+                    ),
+                ] {
+                    gl.tex_parameteri(WebGl2RenderingContext::TEXTURE_2D, parameter, value as i32);
+                }
+                *texture = Some(webgl_texture.clone());
+                webgl_texture
+            }
+        };
+
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&webgl_texture));
+        // canvas rows go top to bottom, and blending expects premultiplied alpha
+        gl.pixel_storei(WebGl2RenderingContext::UNPACK_FLIP_Y_WEBGL, 1);
+        gl.pixel_storei(WebGl2RenderingContext::UNPACK_PREMULTIPLY_ALPHA_WEBGL, 1);
+        let result = gl.tex_image_2d_with_u32_and_u32_and_html_canvas_element(
+            WebGl2RenderingContext::TEXTURE_2D,
+            0,
+            WebGl2RenderingContext::RGBA as i32,
+            WebGl2RenderingContext::RGBA,
+            WebGl2RenderingContext::UNSIGNED_BYTE,
+            &self.canvas,
+        );
+        gl.pixel_storei(WebGl2RenderingContext::UNPACK_FLIP_Y_WEBGL, 0);
+        gl.pixel_storei(WebGl2RenderingContext::UNPACK_PREMULTIPLY_ALPHA_WEBGL, 0);
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, None);
+        result?;
+
+        self.dirty.set(false);
+        Ok(webgl_texture)
+    }
+}
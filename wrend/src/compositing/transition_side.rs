@@ -0,0 +1,8 @@
+/// Which of the two pipelines involved in a transition is being rendered
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TransitionSide {
+    /// The pipeline being transitioned away from
+    From,
+    /// The pipeline being transitioned to (the only one rendered when no transition is running)
+    To,
+}
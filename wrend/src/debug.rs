@@ -0,0 +1,33 @@
+mod debug_edge;
+mod debug_info;
+mod debug_resource;
+mod debug_resource_kind;
+mod debug_shaders;
+mod debug_view;
+mod error_overlay;
+mod feedback_hazard;
+mod gl_capabilities;
+mod non_finite_callback;
+mod non_finite_check;
+mod non_finite_report;
+mod overlay_corner;
+mod program_validation_warning;
+mod texture_inspector;
+mod texture_inspector_channel;
+
+pub use debug_edge::*;
+pub use debug_info::*;
+pub use debug_resource::*;
+pub use debug_resource_kind::*;
+pub use debug_shaders::*;
+pub use debug_view::*;
+pub use error_overlay::*;
+pub use feedback_hazard::*;
+pub use gl_capabilities::*;
+pub use non_finite_callback::*;
+pub use non_finite_check::*;
+pub use non_finite_report::*;
+pub use overlay_corner::*;
+pub use program_validation_warning::*;
+pub use texture_inspector::*;
+pub use texture_inspector_channel::*;
@@ -0,0 +1,35 @@
+use crate::{escape_json, DebugResourceKind};
+
+/// A dependency between two resources in a [crate::DebugInfo], e.g. a uniform that is set in a program,
+/// or a framebuffer that renders into a texture
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DebugEdge {
+    pub(crate) from: (DebugResourceKind, String),
+    pub(crate) to: (DebugResourceKind, String),
+}
+
+impl DebugEdge {
+    pub(crate) fn new(from: (DebugResourceKind, String), to: (DebugResourceKind, String)) -> Self {
+        Self { from, to }
+    }
+
+    /// The kind and id of the dependent resource
+    pub fn from(&self) -> (DebugResourceKind, &str) {
+        (self.from.0, &self.from.1)
+    }
+
+    /// The kind and id of the resource it depends on
+    pub fn to(&self) -> (DebugResourceKind, &str) {
+        (self.to.0, &self.to.1)
+    }
+
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"from":{{"kind":"{}","id":"{}"}},"to":{{"kind":"{}","id":"{}"}}}}"#,
+            self.from.0,
+            escape_json(&self.from.1),
+            self.to.0,
+            escape_json(&self.to.1),
+        )
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{escape_json, DebugEdge, DebugResource, DebugResourceKind, GlCapabilities};
+use wasm_bindgen::JsValue;
+
+/// A snapshot of a [crate::RendererData] for inspecting it at runtime, e.g. from a devtools panel
+/// (see [crate::RendererData::debug_info]).
+///
+/// Resources and the dependencies between them form the pipeline graph. Ids are formatted with `Debug`,
+/// and the whole snapshot can be serialized with [DebugInfo::to_json] (or converted to a plain JavaScript object).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DebugInfo {
+    pub(crate) resources: Vec<DebugResource>,
+    pub(crate) edges: Vec<DebugEdge>,
+    pub(crate) frame_time_ms: f64,
+    pub(crate) traced_frames: u64,
+    pub(crate) trace_event_count: usize,
+    pub(crate) drawing_buffer_size: (i32, i32),
+    pub(crate) program_validation_warnings: Vec<String>,
+    pub(crate) capabilities: GlCapabilities,
+}
+
+impl DebugInfo {
+    /// Every resource, sorted by kind and then by id
+    pub fn resources(&self) -> &[DebugResource] {
+        &self.resources
+    }
+
+    /// Resources of a single kind
+    pub fn resources_of_kind(
+        &self,
+        kind: DebugResourceKind,
+    ) -> impl Iterator<Item = &DebugResource> {
+        self.resources
+            .iter()
+            .filter(move |resource| resource.kind() == kind)
+    }
+
+    /// Dependencies between resources
+    pub fn edges(&self) -> &[DebugEdge] {
+        &self.edges
+    }
+
+    /// Total size of all buffers, in bytes
+    pub fn total_buffer_bytes(&self) -> u64 {
+        self.resources
+            .iter()
+            .filter_map(|resource| resource.byte_length())
+            .map(u64::from)
+            .sum()
+    }
+
+    /// The time uniforms are updated with (see [crate::RendererData::frame_time])
+    pub fn frame_time_ms(&self) -> f64 {
+        self.frame_time_ms
+    }
+
+    /// Frames recorded by the [crate::FrameTracer] (`0` if tracing is disabled)
+    pub fn traced_frames(&self) -> u64 {
+        self.traced_frames
+    }
+
+    /// Spans currently held by the [crate::FrameTracer]
+    pub fn trace_event_count(&self) -> usize {
+        self.trace_event_count
+    }
+
+    pub fn drawing_buffer_size(&self) -> (i32, i32) {
+        self.drawing_buffer_size
+    }
+
+    /// See [crate::RendererData::program_validation_warnings]
+    pub fn program_validation_warnings(&self) -> &[String] {
+        &self.program_validation_warnings
+    }
+
+    pub fn capabilities(&self) -> &GlCapabilities {
+        &self.capabilities
+    }
+
+    pub fn to_json(&self) -> String {
+        let resources: Vec<String> = self.resources.iter().map(DebugResource::to_json).collect();
+        let edges: Vec<String> = self.edges.iter().map(DebugEdge::to_json).collect();
+        let warnings: Vec<String> = self
+            .program_validation_warnings
+            .iter()
+            .map(|warning| format!("\"{}\"", escape_json(warning)))
+            .collect();
+
+        format!(
+            r#"{{"resources":[{}],"edges":[{}],"stats":{{"frameTimeMs":{},"tracedFrames":{},"traceEventCount":{},"totalBufferBytes":{},"drawingBufferWidth":{},"drawingBufferHeight":{},"programValidationWarnings":[{}]}},"capabilities":{}}}"#,
+            resources.join(","),
+            edges.join(","),
+            self.frame_time_ms,
+            self.traced_frames,
+            self.trace_event_count,
+            self.total_buffer_bytes(),
+            self.drawing_buffer_size.0,
+            self.drawing_buffer_size.1,
+            warnings.join(","),
+            self.capabilities.to_json(),
+        )
+    }
+}
+
+impl From<DebugInfo> for JsValue {
+    fn from(debug_info: DebugInfo) -> Self {
+        js_sys::JSON::parse(&debug_info.to_json()).unwrap_or(JsValue::NULL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DebugInfo;
+    use crate::{DebugEdge, DebugResource, DebugResourceKind, GlCapabilities};
+
+    #[test]
+    fn serializes_debug_info_to_json() {
+        let buffer = DebugResource {
+            byte_length: Some(48),
+            ..DebugResource::new(DebugResourceKind::Buffer, String::from("\"positions\""))
+        };
+        let attribute = DebugResource {
+            name: Some(String::from("a_position")),
+            ..DebugResource::new(DebugResourceKind::Attribute, String::from("Position"))
+        };
+        let debug_info = DebugInfo {
+            resources: vec![buffer, attribute],
+            edges: vec![DebugEdge::new(
+                (DebugResourceKind::Attribute, String::from("Position")),
+                (DebugResourceKind::Buffer, String::from("\"positions\"")),
+            )],
+            frame_time_ms: 16.5,
+            drawing_buffer_size: (300, 150),
+            capabilities: GlCapabilities {
+                limits: vec![("maxTextureSize", 4096)],
+                extensions: vec![String::from("EXT_color_buffer_float")],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(debug_info.total_buffer_bytes(), 48);
+        assert_eq!(
+            debug_info.to_json(),
+            concat!(
+                r#"{"resources":[{"kind":"buffer","id":"\"positions\"","name":null,"byteLength":48,"width":null,"height":null,"textureUnit":null,"external":null},"#,
+                r#"{"kind":"attribute","id":"Position","name":"a_position","byteLength":null,"width":null,"height":null,"textureUnit":null,"external":null}],"#,
+                r#""edges":[{"from":{"kind":"attribute","id":"Position"},"to":{"kind":"buffer","id":"\"positions\""}}],"#,
+                r#""stats":{"frameTimeMs":16.5,"tracedFrames":0,"traceEventCount":0,"totalBufferBytes":48,"drawingBufferWidth":300,"drawingBufferHeight":150,"programValidationWarnings":[]},"#,
+                r#""capabilities":{"vendor":"","renderer":"","version":"","shadingLanguageVersion":"","limits":{"maxTextureSize":4096},"extensions":["EXT_color_buffer_float"]}}"#,
+            )
+        );
+    }
+}
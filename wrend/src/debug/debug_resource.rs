@@ -0,0 +1,91 @@
+use crate::{escape_json, DebugResourceKind, ExternalOwnership};
+
+/// One resource of a [crate::RendererData], as listed in a [crate::DebugInfo]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DebugResource {
+    pub(crate) kind: DebugResourceKind,
+    pub(crate) id: String,
+    pub(crate) name: Option<String>,
+    pub(crate) byte_length: Option<u32>,
+    pub(crate) size: Option<(u32, u32)>,
+    pub(crate) texture_unit: Option<u32>,
+    pub(crate) external: Option<ExternalOwnership>,
+}
+
+impl DebugResource {
+    pub(crate) fn new(kind: DebugResourceKind, id: String) -> Self {
+        Self {
+            kind,
+            id,
+            name: None,
+            byte_length: None,
+            size: None,
+            texture_unit: None,
+            external: None,
+        }
+    }
+
+    pub fn kind(&self) -> DebugResourceKind {
+        self.kind
+    }
+
+    /// The resource's id, formatted with `Debug`
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The name used in shaders (uniforms and attributes only)
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Size of a buffer's data store, in bytes
+    pub fn byte_length(&self) -> Option<u32> {
+        self.byte_length
+    }
+
+    /// Width and height of a texture, if known (WebGL can't be asked for them, so this is only
+    /// available for textures registered with an [crate::ExternalTextureDescriptor])
+    pub fn size(&self) -> Option<(u32, u32)> {
+        self.size
+    }
+
+    /// The unit a texture is bound to
+    pub fn texture_unit(&self) -> Option<u32> {
+        self.texture_unit
+    }
+
+    /// Set for resources created outside of wrend
+    pub fn external(&self) -> Option<ExternalOwnership> {
+        self.external
+    }
+
+    pub fn to_json(&self) -> String {
+        let optional_number =
+            |value: Option<u32>| value.map_or(String::from("null"), |value| value.to_string());
+        let (width, height) = match self.size {
+            Some((width, height)) => (Some(width), Some(height)),
+            None => (None, None),
+        };
+        format!(
+            r#"{{"kind":"{}","id":"{}","name":{},"byteLength":{},"width":{},"height":{},"textureUnit":{},"external":{}}}"#,
+            self.kind,
+            escape_json(&self.id),
+            self.name
+                .as_ref()
+                .map_or(String::from("null"), |name| format!(
+                    "\"{}\"",
+                    escape_json(name)
+                )),
+            optional_number(self.byte_length),
+            optional_number(width),
+            optional_number(height),
+            optional_number(self.texture_unit),
+            match self.external {
+                Some(ExternalOwnership::Borrowed) => "\"borrowed\"",
+                Some(ExternalOwnership::Owned) => "\"owned\"",
+                None => "null",
+            },
+        )
+    }
+}
@@ -0,0 +1,40 @@
+use std::fmt::Display;
+
+/// The type of a resource listed in a [crate::DebugInfo]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DebugResourceKind {
+    VertexShader,
+    FragmentShader,
+    Program,
+    Uniform,
+    Buffer,
+    Attribute,
+    VertexArrayObject,
+    Texture,
+    Framebuffer,
+    TransformFeedback,
+}
+
+impl DebugResourceKind {
+    /// camelCase name, as used in [crate::DebugInfo::to_json]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DebugResourceKind::VertexShader => "vertexShader",
+            DebugResourceKind::FragmentShader => "fragmentShader",
+            DebugResourceKind::Program => "program",
+            DebugResourceKind::Uniform => "uniform",
+            DebugResourceKind::Buffer => "buffer",
+            DebugResourceKind::Attribute => "attribute",
+            DebugResourceKind::VertexArrayObject => "vertexArrayObject",
+            DebugResourceKind::Texture => "texture",
+            DebugResourceKind::Framebuffer => "framebuffer",
+            DebugResourceKind::TransformFeedback => "transformFeedback",
+        }
+    }
+}
+
+impl Display for DebugResourceKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
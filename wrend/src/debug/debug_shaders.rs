@@ -0,0 +1,106 @@
+/// Shows `u_texture` for a [crate::TextureInspector]: each channel is normalized from `u_range`,
+/// then `u_channel` selects rgba (0, over a checkerboard), rgb (1), or a single channel as
+/// grayscale (2-5 for r, g, b, a).
+pub const TEXTURE_INSPECTOR_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform highp sampler2D u_texture;
+uniform vec2 u_range;
+uniform int u_channel;
+
+in vec2 v_uv;
+out vec4 out_color;
+
+void main() {
+    vec4 texel = texture(u_texture, v_uv);
+    vec4 value = clamp((texel - u_range.x) / (u_range.y - u_range.x), 0.0, 1.0);
+
+    if (u_channel == 0) {
+        vec2 cell = floor(gl_FragCoord.xy / 8.0);
+        float checker = mod(cell.x + cell.y, 2.0) == 0.0 ? 0.4 : 0.6;
+        // alpha is shown as stored, rather than remapped, since it controls the blend
+        float alpha = clamp(texel.a, 0.0, 1.0);
+        out_color = vec4(mix(vec3(checker), value.rgb, alpha), 1.0);
+    } else if (u_channel == 1) {
+        out_color = vec4(value.rgb, 1.0);
+    } else {
+        out_color = vec4(vec3(value[u_channel - 2]), 1.0);
+    }
+}
+"#;
+
+/// GLSL include for vertex shaders drawn with [crate::DebugView]: call
+/// `wrend_debug_view_vertex()` from `main` to give each triangle's corners the barycentric
+/// coordinates that wireframes are drawn from.
+///
+/// Corners are derived from `gl_VertexID`, so this only works for non-indexed `TRIANGLES` draws.
+pub const DEBUG_VIEW_VERTEX_GLSL: &str = r#"
+out vec3 v_wrend_barycentric;
+
+void wrend_debug_view_vertex() {
+    int corner = gl_VertexID % 3;
+    v_wrend_barycentric = vec3(corner == 0, corner == 1, corner == 2);
+}
+"#;
+
+/// GLSL include for fragment shaders drawn with [crate::DebugView]: pass the final color through
+/// `wrend_debug_view(color)`, which returns it unchanged unless a debug view is enabled.
+pub const DEBUG_VIEW_FRAGMENT_GLSL: &str = r#"
+uniform int u_wrend_debug_view;
+
+in vec3 v_wrend_barycentric;
+
+vec4 wrend_debug_view(vec4 color) {
+    if (u_wrend_debug_view == 1) {
+        vec3 distance = v_wrend_barycentric / max(fwidth(v_wrend_barycentric), vec3(1e-6));
+        float edge = 1.0 - clamp(min(min(distance.x, distance.y), distance.z) - 0.5, 0.0, 1.0);
+        return vec4(mix(color.rgb * 0.25, vec3(1.0), edge), 1.0);
+    }
+    if (u_wrend_debug_view == 2) {
+        // added once per fragment: 1 layer is dark red, ~10 layers are white
+        return vec4(0.1, 0.04, 0.02, 1.0);
+    }
+    return color;
+}
+"#;
+
+/// Flags NaN and infinite values for a [crate::NonFiniteCheck]: each fragment of a
+/// `u_grid_size` square target scans one block of `u_texture`, writing 1.0 to red if it contains
+/// a NaN and to green if it contains an infinity.
+///
+/// Values are classified from their bits, since `isnan` and `isinf` may be optimized away.
+pub const NON_FINITE_CHECK_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+precision highp int;
+
+uniform highp sampler2D u_texture;
+uniform int u_grid_size;
+
+out vec4 out_color;
+
+void main() {
+    ivec2 size = textureSize(u_texture, 0);
+    ivec2 cell = ivec2(gl_FragCoord.xy);
+    ivec2 start = cell * size / u_grid_size;
+    ivec2 end = (cell + 1) * size / u_grid_size;
+
+    bool has_nan = false;
+    bool has_infinity = false;
+    for (int y = start.y; y < end.y; y++) {
+        for (int x = start.x; x < end.x; x++) {
+            uvec4 bits = floatBitsToUint(texelFetch(u_texture, ivec2(x, y), 0));
+            for (int i = 0; i < 4; i++) {
+                if ((bits[i] & 0x7f800000u) == 0x7f800000u) {
+                    if ((bits[i] & 0x007fffffu) != 0u) {
+                        has_nan = true;
+                    } else {
+                        has_infinity = true;
+                    }
+                }
+            }
+        }
+    }
+
+    out_color = vec4(float(has_nan), float(has_infinity), 0.0, 1.0);
+}
+"#;
@@ -0,0 +1,61 @@
+use crate::{UniformGroup, UniformValue};
+
+/// Debug render mode for programs whose shaders use [crate::DEBUG_VIEW_VERTEX_GLSL] and
+/// [crate::DEBUG_VIEW_FRAGMENT_GLSL]. Set at runtime with [crate::RendererData::set_debug_view].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum DebugView {
+    /// Shaders output their usual color
+    #[default]
+    Normal,
+    /// Triangle edges are drawn over a darkened fill, to show how geometry is tessellated
+    Wireframe,
+    /// Every fragment adds the same amount of heat to the canvas (with additive blending), so
+    /// areas that are shaded many times per frame show up brighter. The app's own blend state is
+    /// restored at the end of each frame.
+    Overdraw,
+}
+
+impl DebugView {
+    /// Value of the `u_wrend_debug_view` uniform in [crate::DEBUG_VIEW_FRAGMENT_GLSL]
+    pub(crate) fn shader_index(&self) -> i32 {
+        match self {
+            DebugView::Normal => 0,
+            DebugView::Wireframe => 1,
+            DebugView::Overdraw => 2,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DebugView::Normal => "normal",
+            DebugView::Wireframe => "wireframe",
+            DebugView::Overdraw => "overdraw",
+        }
+    }
+
+    /// Parses the name returned by [DebugView::as_str]
+    pub fn from_name(name: &str) -> Option<Self> {
+        [DebugView::Normal, DebugView::Wireframe, DebugView::Overdraw]
+            .into_iter()
+            .find(|view| view.as_str() == name)
+    }
+}
+
+impl UniformGroup for DebugView {
+    fn uniforms(&self) -> Vec<(&'static str, UniformValue)> {
+        vec![("u_wrend_debug_view", UniformValue::Int(self.shader_index()))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DebugView;
+
+    #[test]
+    fn parses_names() {
+        for view in [DebugView::Normal, DebugView::Wireframe, DebugView::Overdraw] {
+            assert_eq!(DebugView::from_name(view.as_str()), Some(view));
+        }
+        assert_eq!(DebugView::from_name("Wireframe"), None);
+    }
+}
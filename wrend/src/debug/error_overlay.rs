@@ -0,0 +1,57 @@
+use crate::RendererBuilderError;
+use wasm_bindgen::JsValue;
+use web_sys::{Element, HtmlCanvasElement};
+
+const OVERLAY_ATTRIBUTE: &str = "data-wrend-error-overlay";
+const OVERLAY_STYLE: &str = "position: absolute; z-index: 1000; box-sizing: border-box; \
+    margin: 0; padding: 12px; overflow: auto; background: rgba(24, 0, 0, 0.9); color: #ff8080; \
+    font: 12px/1.4 monospace; white-space: pre-wrap;";
+
+/// Shows `message` in a `<pre>` laid over `canvas`, replacing any overlay already shown for it.
+///
+/// Used by [crate::RendererDataBuilder::set_error_overlay] so that build errors (including shader
+/// logs with their surrounding source lines) are visible without opening the console.
+pub fn show_error_overlay(canvas: &HtmlCanvasElement, message: &str) -> Result<(), JsValue> {
+    clear_error_overlay(canvas);
+
+    let document = canvas
+        .owner_document()
+        .ok_or_else(|| JsValue::from_str("Canvas is not attached to a document"))?;
+    let overlay = document.create_element("pre")?;
+    overlay.set_attribute(OVERLAY_ATTRIBUTE, "")?;
+    overlay.set_attribute(
+        "style",
+        &format!(
+            "{OVERLAY_STYLE} left: {}px; top: {}px; width: {}px; height: {}px;",
+            canvas.offset_left(),
+            canvas.offset_top(),
+            canvas.offset_width(),
+            canvas.offset_height()
+        ),
+    )?;
+    overlay.set_text_content(Some(message));
+    canvas.after_with_node_1(&overlay)?;
+
+    Ok(())
+}
+
+/// Removes the overlay shown for `canvas` by [show_error_overlay], if there is one
+pub fn clear_error_overlay(canvas: &HtmlCanvasElement) {
+    if let Some(overlay) = canvas
+        .next_element_sibling()
+        .filter(|sibling: &Element| sibling.has_attribute(OVERLAY_ATTRIBUTE))
+    {
+        overlay.remove();
+    }
+}
+
+/// Formats a build error for [show_error_overlay], keeping shader logs' line breaks intact
+pub(crate) fn error_overlay_message(err: &RendererBuilderError) -> String {
+    let details = match err {
+        RendererBuilderError::CompileShaderError(err) => err.to_string(),
+        RendererBuilderError::LinkProgramError(err) => err.to_string(),
+        RendererBuilderError::LinkGraphError(err) => err.to_string(),
+        err => err.to_string(),
+    };
+    format!("wrend: failed to build renderer\n\n{details}")
+}
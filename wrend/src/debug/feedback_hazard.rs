@@ -0,0 +1,63 @@
+use crate::Id;
+use std::fmt::Display;
+
+/// Describes a feedback loop between the bound framebuffer and a texture sampled by the current program.
+///
+/// Sampling from a texture while it is attached to the framebuffer being drawn to is undefined
+/// behavior in WebGL, and usually shows up as a black screen (along with a console warning in some browsers).
+///
+/// See [crate::RendererData::find_feedback_hazard]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct FeedbackHazard<FramebufferId: Id, TextureId: Id> {
+    framebuffer_id: FramebufferId,
+    texture_id: TextureId,
+    sampler_name: String,
+    texture_unit: u32,
+}
+
+impl<FramebufferId: Id, TextureId: Id> FeedbackHazard<FramebufferId, TextureId> {
+    /// Creates a new hazard description
+    pub fn new(
+        framebuffer_id: FramebufferId,
+        texture_id: TextureId,
+        sampler_name: String,
+        texture_unit: u32,
+    ) -> Self {
+        Self {
+            framebuffer_id,
+            texture_id,
+            sampler_name,
+            texture_unit,
+        }
+    }
+
+    /// The framebuffer that is currently bound for drawing
+    pub fn framebuffer_id(&self) -> &FramebufferId {
+        &self.framebuffer_id
+    }
+
+    /// The texture that is both attached to the framebuffer and being sampled
+    pub fn texture_id(&self) -> &TextureId {
+        &self.texture_id
+    }
+
+    /// Name of the sampler uniform that reads from the texture
+    pub fn sampler_name(&self) -> &str {
+        &self.sampler_name
+    }
+
+    /// Texture unit the sampler reads from
+    pub fn texture_unit(&self) -> u32 {
+        self.texture_unit
+    }
+}
+
+impl<FramebufferId: Id, TextureId: Id> Display for FeedbackHazard<FramebufferId, TextureId> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Feedback hazard: texture {:?} is attached to the bound framebuffer {:?} while also being sampled by `{}` (texture unit {})",
+            self.texture_id, self.framebuffer_id, self.sampler_name, self.texture_unit
+        )
+    }
+}
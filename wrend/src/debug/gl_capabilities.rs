@@ -0,0 +1,134 @@
+use crate::escape_json;
+use web_sys::WebGl2RenderingContext;
+
+type Gl = WebGl2RenderingContext;
+
+/// Limits and extensions of the WebGL2 implementation, as reported by the browser
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct GlCapabilities {
+    pub(crate) vendor: String,
+    pub(crate) renderer: String,
+    pub(crate) version: String,
+    pub(crate) shading_language_version: String,
+    pub(crate) limits: Vec<(&'static str, u32)>,
+    pub(crate) extensions: Vec<String>,
+}
+
+impl GlCapabilities {
+    /// Limits included in [GlCapabilities::limits], with the name they are reported under
+    const LIMITS: [(&'static str, u32); 13] = [
+        ("maxTextureSize", Gl::MAX_TEXTURE_SIZE),
+        ("maxCubeMapTextureSize", Gl::MAX_CUBE_MAP_TEXTURE_SIZE),
+        ("max3dTextureSize", Gl::MAX_3D_TEXTURE_SIZE),
+        ("maxArrayTextureLayers", Gl::MAX_ARRAY_TEXTURE_LAYERS),
+        ("maxRenderbufferSize", Gl::MAX_RENDERBUFFER_SIZE),
+        ("maxTextureImageUnits", Gl::MAX_TEXTURE_IMAGE_UNITS),
+        (
+            "maxCombinedTextureImageUnits",
+            Gl::MAX_COMBINED_TEXTURE_IMAGE_UNITS,
+        ),
+        ("maxVertexAttribs", Gl::MAX_VERTEX_ATTRIBS),
+        ("maxDrawBuffers", Gl::MAX_DRAW_BUFFERS),
+        ("maxColorAttachments", Gl::MAX_COLOR_ATTACHMENTS),
+        ("maxSamples", Gl::MAX_SAMPLES),
+        ("maxUniformBufferBindings", Gl::MAX_UNIFORM_BUFFER_BINDINGS),
+        (
+            "maxTransformFeedbackSeparateAttribs",
+            Gl::MAX_TRANSFORM_FEEDBACK_SEPARATE_ATTRIBS,
+        ),
+    ];
+
+    pub fn query(gl: &Gl) -> Self {
+        let string_parameter = |parameter| {
+            gl.get_parameter(parameter)
+                .ok()
+                .and_then(|value| value.as_string())
+                .unwrap_or_default()
+        };
+        let limits = Self::LIMITS
+            .iter()
+            .map(|(name, parameter)| {
+                let value = gl
+                    .get_parameter(*parameter)
+                    .ok()
+                    .and_then(|value| value.as_f64())
+                    .unwrap_or_default() as u32;
+                (*name, value)
+            })
+            .collect();
+        let extensions = gl
+            .get_supported_extensions()
+            .map(|extensions| {
+                extensions
+                    .iter()
+                    .filter_map(|extension| extension.as_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            vendor: string_parameter(Gl::VENDOR),
+            renderer: string_parameter(Gl::RENDERER),
+            version: string_parameter(Gl::VERSION),
+            shading_language_version: string_parameter(Gl::SHADING_LANGUAGE_VERSION),
+            limits,
+            extensions,
+        }
+    }
+
+    pub fn vendor(&self) -> &str {
+        &self.vendor
+    }
+
+    pub fn renderer(&self) -> &str {
+        &self.renderer
+    }
+
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    pub fn shading_language_version(&self) -> &str {
+        &self.shading_language_version
+    }
+
+    /// Implementation limits, e.g. `("maxTextureSize", 16384)`
+    pub fn limits(&self) -> &[(&'static str, u32)] {
+        &self.limits
+    }
+
+    /// A single limit by the name it is reported under
+    pub fn limit(&self, name: &str) -> Option<u32> {
+        self.limits
+            .iter()
+            .find(|(limit, _)| *limit == name)
+            .map(|(_, value)| *value)
+    }
+
+    /// Names of every supported extension
+    pub fn extensions(&self) -> &[String] {
+        &self.extensions
+    }
+
+    pub fn to_json(&self) -> String {
+        let limits: Vec<String> = self
+            .limits
+            .iter()
+            .map(|(name, value)| format!(r#""{name}":{value}"#))
+            .collect();
+        let extensions: Vec<String> = self
+            .extensions
+            .iter()
+            .map(|extension| format!("\"{}\"", escape_json(extension)))
+            .collect();
+        format!(
+            r#"{{"vendor":"{}","renderer":"{}","version":"{}","shadingLanguageVersion":"{}","limits":{{{}}},"extensions":[{}]}}"#,
+            escape_json(&self.vendor),
+            escape_json(&self.renderer),
+            escape_json(&self.version),
+            escape_json(&self.shading_language_version),
+            limits.join(","),
+            extensions.join(","),
+        )
+    }
+}
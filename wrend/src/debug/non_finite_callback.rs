@@ -0,0 +1,44 @@
+use crate::{Callback, NonFiniteReport};
+use js_sys::Function;
+use std::fmt::Debug;
+use std::{ops::Deref, rc::Rc};
+
+/// Called by a [crate::NonFiniteCheck] whenever a texture is found to contain NaN or infinite values.
+///
+/// JavaScript callbacks receive the report as a plain object.
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd)]
+pub struct NonFiniteCallback(Callback<dyn Fn(NonFiniteReport), Function>);
+
+impl Deref for NonFiniteCallback {
+    type Target = Callback<dyn Fn(NonFiniteReport), Function>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for NonFiniteCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("NonFiniteCallback").field(&self.0).finish()
+    }
+}
+
+impl<F: Fn(NonFiniteReport) + 'static> From<F> for NonFiniteCallback {
+    fn from(callback: F) -> Self {
+        Self(Callback::new_rs(
+            Rc::new(callback) as Rc<dyn Fn(NonFiniteReport)>
+        ))
+    }
+}
+
+impl<F: Fn(NonFiniteReport) + 'static> From<Rc<F>> for NonFiniteCallback {
+    fn from(callback: Rc<F>) -> Self {
+        Self(Callback::new_rs(callback as Rc<dyn Fn(NonFiniteReport)>))
+    }
+}
+
+impl From<Function> for NonFiniteCallback {
+    fn from(callback: Function) -> Self {
+        Self(Callback::new_js(callback))
+    }
+}
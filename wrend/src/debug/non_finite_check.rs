@@ -0,0 +1,35 @@
+use crate::{Id, NonFiniteCallback};
+
+/// Scans float textures for NaN and infinite values, which simulations (fluids, particles) tend
+/// to produce when they become unstable, and which otherwise just render as black.
+///
+/// The program is added with [crate::RendererDataBuilder::add_non_finite_check], and a texture
+/// is scanned with [crate::RendererData::check_non_finite], which calls the callback if anything
+/// is found. Each fragment of a [NonFiniteCheck::GRID_SIZE] square target flags one block of the
+/// texture, so only a few hundred bytes are read back, but this still stalls until the GPU has
+/// caught up, so it's best suited to debugging (or to checking every few seconds).
+#[derive(Debug, Clone, PartialEq)]
+pub struct NonFiniteCheck<ProgramId: Id> {
+    program_id: ProgramId,
+    callback: NonFiniteCallback,
+}
+
+impl<ProgramId: Id> NonFiniteCheck<ProgramId> {
+    /// Width and height of the grid of flags that is rendered and read back
+    pub const GRID_SIZE: u32 = 16;
+
+    pub fn new(program_id: ProgramId, callback: impl Into<NonFiniteCallback>) -> Self {
+        Self {
+            program_id,
+            callback: callback.into(),
+        }
+    }
+
+    pub fn program_id(&self) -> &ProgramId {
+        &self.program_id
+    }
+
+    pub fn callback(&self) -> &NonFiniteCallback {
+        &self.callback
+    }
+}
@@ -0,0 +1,118 @@
+use crate::escape_json;
+use wasm_bindgen::JsValue;
+
+/// Where a [crate::NonFiniteCheck] found NaN or infinite values.
+///
+/// The texture is divided into a grid of [crate::NonFiniteCheck::GRID_SIZE] cells in each
+/// direction, and cells are counted if any of their texels (in any channel) is affected, which
+/// gives a rough idea of how far the problem has spread.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NonFiniteReport {
+    pub(crate) texture_id: String,
+    pub(crate) frame: u64,
+    pub(crate) grid_size: u32,
+    pub(crate) nan_cells: u32,
+    pub(crate) infinity_cells: u32,
+}
+
+impl NonFiniteReport {
+    /// Builds a report from the RGBA8 flags read back from the check's target, where the red
+    /// channel marks cells containing NaN and the green channel cells containing infinity.
+    /// Returns `None` if no cell is flagged.
+    pub(crate) fn from_pixels(
+        texture_id: String,
+        frame: u64,
+        grid_size: u32,
+        pixels: &[u8],
+    ) -> Option<Self> {
+        let flagged = |channel: usize| {
+            pixels
+                .chunks_exact(4)
+                .filter(|pixel| pixel[channel] > 127)
+                .count() as u32
+        };
+        let report = Self {
+            texture_id,
+            frame,
+            grid_size,
+            nan_cells: flagged(0),
+            infinity_cells: flagged(1),
+        };
+        (report.has_nan() || report.has_infinity()).then_some(report)
+    }
+
+    /// Id of the scanned texture, formatted with `Debug`
+    pub fn texture_id(&self) -> &str {
+        &self.texture_id
+    }
+
+    /// Frame number according to the [crate::FrameTracer] (`0` if tracing is disabled)
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    pub fn grid_size(&self) -> u32 {
+        self.grid_size
+    }
+
+    /// Number of grid cells containing at least one NaN
+    pub fn nan_cells(&self) -> u32 {
+        self.nan_cells
+    }
+
+    /// Number of grid cells containing at least one positive or negative infinity
+    pub fn infinity_cells(&self) -> u32 {
+        self.infinity_cells
+    }
+
+    pub fn has_nan(&self) -> bool {
+        self.nan_cells > 0
+    }
+
+    pub fn has_infinity(&self) -> bool {
+        self.infinity_cells > 0
+    }
+
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"textureId":"{}","frame":{},"gridSize":{},"nanCells":{},"infinityCells":{}}}"#,
+            escape_json(&self.texture_id),
+            self.frame,
+            self.grid_size,
+            self.nan_cells,
+            self.infinity_cells,
+        )
+    }
+}
+
+impl From<NonFiniteReport> for JsValue {
+    fn from(report: NonFiniteReport) -> Self {
+        js_sys::JSON::parse(&report.to_json()).unwrap_or(JsValue::NULL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NonFiniteReport;
+
+    #[test]
+    fn counts_flagged_cells() {
+        let clean = [0, 0, 0, 255].repeat(4);
+        assert_eq!(
+            NonFiniteReport::from_pixels(String::from("\"velocity\""), 7, 2, &clean),
+            None
+        );
+
+        let mut pixels = clean;
+        pixels[0] = 255;
+        pixels[4] = 255;
+        pixels[9] = 255;
+        let report =
+            NonFiniteReport::from_pixels(String::from("\"velocity\""), 7, 2, &pixels).unwrap();
+        assert_eq!((report.nan_cells(), report.infinity_cells()), (2, 1));
+        assert_eq!(
+            report.to_json(),
+            r#"{"textureId":"\"velocity\"","frame":7,"gridSize":2,"nanCells":2,"infinityCells":1}"#
+        );
+    }
+}
@@ -0,0 +1,19 @@
+/// Corner of the canvas that a debug overlay (such as a [crate::TextureInspector]) is drawn in
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum OverlayCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+impl OverlayCorner {
+    pub fn is_left(&self) -> bool {
+        matches!(self, OverlayCorner::TopLeft | OverlayCorner::BottomLeft)
+    }
+
+    pub fn is_bottom(&self) -> bool {
+        matches!(self, OverlayCorner::BottomLeft | OverlayCorner::BottomRight)
+    }
+}
@@ -0,0 +1,184 @@
+use crate::Id;
+use std::fmt::Display;
+use web_sys::WebGl2RenderingContext;
+
+/// A problem found by the program validation pass (see [crate::RendererDataBuilder::set_validate_programs]).
+///
+/// None of these stop a renderer from being built, but each usually means that some draw call
+/// will render garbage (or nothing at all) without any other error.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum ProgramValidationWarning<ProgramId: Id> {
+    /// `gl.validateProgram` reported that the program can't run with the current state
+    ValidationFailed {
+        program_id: ProgramId,
+        info_log: String,
+    },
+    /// Several samplers read from the same texture unit (usually because none of them was set,
+    /// so they all read from unit 0). If their targets differ, every draw call with the program fails.
+    SharedTextureUnit {
+        program_id: ProgramId,
+        texture_unit: u32,
+        sampler_names: Vec<String>,
+        mixed_targets: bool,
+    },
+    /// A non-2D sampler reads from a unit that the renderer assigned to a 2D texture
+    TargetMismatch {
+        program_id: ProgramId,
+        sampler_name: String,
+        texture_unit: u32,
+    },
+}
+
+impl<ProgramId: Id> ProgramValidationWarning<ProgramId> {
+    /// The program the warning is about
+    pub fn program_id(&self) -> &ProgramId {
+        match self {
+            Self::ValidationFailed { program_id, .. }
+            | Self::SharedTextureUnit { program_id, .. }
+            | Self::TargetMismatch { program_id, .. } => program_id,
+        }
+    }
+}
+
+impl<ProgramId: Id> Display for ProgramValidationWarning<ProgramId> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ValidationFailed {
+                program_id,
+                info_log,
+            } => write!(f, "Program {program_id:?} failed validation: {info_log}"),
+            Self::SharedTextureUnit {
+                program_id,
+                texture_unit,
+                sampler_names,
+                mixed_targets,
+            } => write!(
+                f,
+                "Program {program_id:?} reads texture unit {texture_unit} from several samplers ({}){}",
+                sampler_names.join(", "),
+                if *mixed_targets {
+                    " of different types, so drawing with it fails"
+                } else {
+                    ""
+                }
+            ),
+            Self::TargetMismatch {
+                program_id,
+                sampler_name,
+                texture_unit,
+            } => write!(
+                f,
+                "Sampler `{sampler_name}` in program {program_id:?} is not a sampler2D, but texture unit {texture_unit} holds a 2D texture"
+            ),
+        }
+    }
+}
+
+/// Texture target that a sampler of the given GLSL type reads from
+pub(crate) fn sampler_target(gl_type: u32) -> Option<u32> {
+    match gl_type {
+        WebGl2RenderingContext::SAMPLER_2D
+        | WebGl2RenderingContext::SAMPLER_2D_SHADOW
+        | WebGl2RenderingContext::INT_SAMPLER_2D
+        | WebGl2RenderingContext::UNSIGNED_INT_SAMPLER_2D => {
+            Some(WebGl2RenderingContext::TEXTURE_2D)
+        }
+        WebGl2RenderingContext::SAMPLER_CUBE
+        | WebGl2RenderingContext::SAMPLER_CUBE_SHADOW
+        | WebGl2RenderingContext::INT_SAMPLER_CUBE
+        | WebGl2RenderingContext::UNSIGNED_INT_SAMPLER_CUBE => {
+            Some(WebGl2RenderingContext::TEXTURE_CUBE_MAP)
+        }
+        WebGl2RenderingContext::SAMPLER_3D
+        | WebGl2RenderingContext::INT_SAMPLER_3D
+        | WebGl2RenderingContext::UNSIGNED_INT_SAMPLER_3D => {
+            Some(WebGl2RenderingContext::TEXTURE_3D)
+        }
+        WebGl2RenderingContext::SAMPLER_2D_ARRAY
+        | WebGl2RenderingContext::SAMPLER_2D_ARRAY_SHADOW
+        | WebGl2RenderingContext::INT_SAMPLER_2D_ARRAY
+        | WebGl2RenderingContext::UNSIGNED_INT_SAMPLER_2D_ARRAY => {
+            Some(WebGl2RenderingContext::TEXTURE_2D_ARRAY)
+        }
+        _ => None,
+    }
+}
+
+/// Cross-checks the units of a program's samplers, given as `(name, target, unit)`.
+/// `is_2d_unit` tells whether the renderer bound a 2D texture to a unit.
+pub(crate) fn check_sampler_units<ProgramId: Id>(
+    program_id: &ProgramId,
+    samplers: &[(String, u32, u32)],
+    is_2d_unit: impl Fn(u32) -> bool,
+) -> Vec<ProgramValidationWarning<ProgramId>> {
+    let mut warnings = Vec::new();
+
+    let mut units: Vec<u32> = samplers.iter().map(|(_, _, unit)| *unit).collect();
+    units.sort_unstable();
+    units.dedup();
+    for texture_unit in units {
+        let sharing: Vec<&(String, u32, u32)> = samplers
+            .iter()
+            .filter(|(_, _, unit)| *unit == texture_unit)
+            .collect();
+        if sharing.len() > 1 {
+            warnings.push(ProgramValidationWarning::SharedTextureUnit {
+                program_id: program_id.clone(),
+                texture_unit,
+                sampler_names: sharing.iter().map(|(name, _, _)| name.clone()).collect(),
+                mixed_targets: sharing.iter().any(|(_, target, _)| *target != sharing[0].1),
+            });
+        }
+    }
+
+    for (sampler_name, target, texture_unit) in samplers {
+        if *target != WebGl2RenderingContext::TEXTURE_2D && is_2d_unit(*texture_unit) {
+            warnings.push(ProgramValidationWarning::TargetMismatch {
+                program_id: program_id.clone(),
+                sampler_name: sampler_name.clone(),
+                texture_unit: *texture_unit,
+            });
+        }
+    }
+
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_sampler_units, sampler_target, ProgramValidationWarning};
+    use web_sys::WebGl2RenderingContext as Gl;
+
+    #[test]
+    fn finds_shared_units_and_target_mismatches() {
+        let samplers = [
+            ("u_a".to_string(), Gl::TEXTURE_2D, 0),
+            ("u_b".to_string(), Gl::TEXTURE_CUBE_MAP, 0),
+            ("u_c".to_string(), Gl::TEXTURE_2D, 1),
+            ("u_d".to_string(), Gl::TEXTURE_3D, 2),
+        ];
+        let warnings = check_sampler_units(&"program", &samplers, |unit| unit != 2);
+
+        assert_eq!(
+            warnings,
+            vec![
+                ProgramValidationWarning::SharedTextureUnit {
+                    program_id: "program",
+                    texture_unit: 0,
+                    sampler_names: vec!["u_a".to_string(), "u_b".to_string()],
+                    mixed_targets: true,
+                },
+                ProgramValidationWarning::TargetMismatch {
+                    program_id: "program",
+                    sampler_name: "u_b".to_string(),
+                    texture_unit: 0,
+                },
+            ]
+        );
+        assert_eq!(
+            sampler_target(Gl::INT_SAMPLER_2D_ARRAY),
+            Some(Gl::TEXTURE_2D_ARRAY)
+        );
+        assert_eq!(sampler_target(Gl::FLOAT_VEC2), None);
+    }
+}
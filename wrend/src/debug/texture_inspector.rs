@@ -0,0 +1,215 @@
+use crate::{Id, IdName, OverlayCorner, RendererData, TextureInspectorChannel};
+use log::warn;
+use web_sys::WebGl2RenderingContext;
+
+/// Shows any registered texture in a corner of the canvas, e.g. to check the output of an
+/// intermediate pass without writing a temporary pass-through pipeline.
+///
+/// The program is added with [crate::RendererDataBuilder::add_texture_inspector] and drawn over
+/// the canvas with [TextureInspector::draw]. Nothing is drawn until a
+/// texture is selected with [TextureInspector::select].
+///
+/// Textures are sampled as floats, so float and depth textures can be shown by remapping
+/// [TextureInspector::range] to 0.0-1.0, but integer textures can't be shown.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct TextureInspector<ProgramId: Id, TextureId: Id> {
+    program_id: ProgramId,
+    texture_id: Option<TextureId>,
+    channel: TextureInspectorChannel,
+    range: [f32; 2],
+    corner: OverlayCorner,
+    scale: f32,
+    margin_px: u32,
+}
+
+impl<ProgramId: Id, TextureId: Id> TextureInspector<ProgramId, TextureId> {
+    /// Creates an inspector showing all channels from 0.0 to 1.0, in a quarter-size view in the
+    /// bottom right corner
+    pub fn new(program_id: ProgramId) -> Self {
+        Self {
+            program_id,
+            texture_id: None,
+            channel: TextureInspectorChannel::default(),
+            range: [0.0, 1.0],
+            corner: OverlayCorner::default(),
+            scale: 0.25,
+            margin_px: 8,
+        }
+    }
+
+    /// Shows `texture_id` from the next draw onwards
+    pub fn select(&mut self, texture_id: TextureId) -> &mut Self {
+        self.texture_id = Some(texture_id);
+        self
+    }
+
+    /// Stops showing a texture
+    pub fn clear_selection(&mut self) -> &mut Self {
+        self.texture_id = None;
+        self
+    }
+
+    pub fn set_channel(&mut self, channel: TextureInspectorChannel) -> &mut Self {
+        self.channel = channel;
+        self
+    }
+
+    /// Values mapped to black and white (or no and full intensity of a color channel).
+    /// Values outside of it are clamped.
+    pub fn set_range(&mut self, min: f32, max: f32) -> &mut Self {
+        self.range = [min, max];
+        self
+    }
+
+    pub fn set_corner(&mut self, corner: OverlayCorner) -> &mut Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Size of the view, as a fraction of the canvas size
+    pub fn set_scale(&mut self, scale: f32) -> &mut Self {
+        self.scale = scale.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Distance between the view and the edges of the canvas, in pixels
+    pub fn set_margin_px(&mut self, margin_px: u32) -> &mut Self {
+        self.margin_px = margin_px;
+        self
+    }
+
+    pub fn program_id(&self) -> &ProgramId {
+        &self.program_id
+    }
+
+    pub fn texture_id(&self) -> Option<&TextureId> {
+        self.texture_id.as_ref()
+    }
+
+    pub fn channel(&self) -> TextureInspectorChannel {
+        self.channel
+    }
+
+    pub fn range(&self) -> [f32; 2] {
+        self.range
+    }
+
+    pub fn corner(&self) -> OverlayCorner {
+        self.corner
+    }
+
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    pub fn margin_px(&self) -> u32 {
+        self.margin_px
+    }
+
+    /// The `(x, y, width, height)` viewport the view is drawn to on a canvas of this size,
+    /// with y measured from the bottom like WebGL viewports
+    pub fn viewport(&self, width: u32, height: u32) -> (i32, i32, i32, i32) {
+        let view_width = (width as f32 * self.scale).round() as i32;
+        let view_height = (height as f32 * self.scale).round() as i32;
+        let margin = self.margin_px as i32;
+        let x = if self.corner.is_left() {
+            margin
+        } else {
+            width as i32 - view_width - margin
+        };
+        let y = if self.corner.is_bottom() {
+            margin
+        } else {
+            height as i32 - view_height - margin
+        };
+        (x.max(0), y.max(0), view_width, view_height)
+    }
+
+    /// Draws the texture selected in a [TextureInspector] into its corner of the canvas,
+    /// over whatever has been drawn so far. Does nothing if no texture is selected.
+    pub fn draw<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        UniformId: Id + IdName,
+        BufferId: Id,
+        AttributeId: Id + IdName,
+        FramebufferId: Id,
+        TransformFeedbackId: Id,
+        VertexArrayObjectId: Id,
+        UserCtx: Clone,
+    >(
+        &self,
+        renderer_data: &RendererData<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+    ) -> &Self {
+        let Some(texture_id) = self.texture_id() else {
+            return self;
+        };
+        let Some(texture) = renderer_data.webgl_texture(texture_id) else {
+            warn!("Could not find texture {texture_id:?} to inspect");
+            return self;
+        };
+        let Some(program) = renderer_data.program(self.program_id()) else {
+            warn!(
+                "Could not find TextureInspector program {:?}",
+                self.program_id()
+            );
+            return self;
+        };
+        let gl = renderer_data.gl();
+        let (width, height) = (gl.drawing_buffer_width(), gl.drawing_buffer_height());
+        let (x, y, view_width, view_height) = self.viewport(width as u32, height as u32);
+        let [min, max] = self.range();
+        let location = |name| gl.get_uniform_location(program, name);
+
+        gl.use_program(Some(program));
+        let saved_units = renderer_data.save_texture_units([0]);
+        gl.active_texture(WebGl2RenderingContext::TEXTURE0);
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(texture));
+        gl.uniform1i(location("u_texture").as_ref(), 0);
+        gl.uniform2f(location("u_range").as_ref(), min, max);
+        gl.uniform1i(
+            location("u_channel").as_ref(),
+            self.channel().shader_index(),
+        );
+
+        gl.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, None);
+        gl.viewport(x, y, view_width, view_height);
+        renderer_data.draw_fullscreen_triangle(self.program_id());
+        gl.viewport(0, 0, width, height);
+        saved_units.restore(gl);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextureInspector;
+    use crate::OverlayCorner;
+
+    #[test]
+    fn viewport_is_placed_in_corner() {
+        let mut inspector = TextureInspector::<&str, &str>::new("inspector");
+        assert_eq!(inspector.viewport(800, 600), (592, 8, 200, 150));
+
+        inspector
+            .set_corner(OverlayCorner::TopLeft)
+            .set_scale(0.5)
+            .set_margin_px(0);
+        assert_eq!(inspector.viewport(800, 600), (0, 300, 400, 300));
+
+        inspector.set_corner(OverlayCorner::TopRight).set_scale(2.0);
+        assert_eq!(inspector.viewport(100, 50), (0, 0, 100, 50));
+    }
+}
@@ -0,0 +1,28 @@
+/// Which channels of a texture a [crate::TextureInspector] shows
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum TextureInspectorChannel {
+    /// All channels, with transparent areas shown over a checkerboard
+    #[default]
+    Rgba,
+    /// Color channels only, ignoring alpha
+    Rgb,
+    /// A single channel as grayscale. Depth textures store their depth in this channel.
+    Red,
+    Green,
+    Blue,
+    Alpha,
+}
+
+impl TextureInspectorChannel {
+    /// Value of the `u_channel` uniform in [crate::TEXTURE_INSPECTOR_FRAGMENT_SHADER]
+    pub(crate) fn shader_index(&self) -> i32 {
+        match self {
+            TextureInspectorChannel::Rgba => 0,
+            TextureInspectorChannel::Rgb => 1,
+            TextureInspectorChannel::Red => 2,
+            TextureInspectorChannel::Green => 3,
+            TextureInspectorChannel::Blue => 4,
+            TextureInspectorChannel::Alpha => 5,
+        }
+    }
+}
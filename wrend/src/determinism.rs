@@ -0,0 +1,9 @@
+mod determinism_checkpoint;
+mod determinism_harness;
+mod determinism_report;
+mod frame_divergence;
+
+pub use determinism_checkpoint::*;
+pub use determinism_harness::*;
+pub use determinism_report::*;
+pub use frame_divergence::*;
@@ -0,0 +1,29 @@
+use crate::FrameChecksum;
+
+/// Checksums of every render target, taken after a frame rendered by a [crate::DeterminismHarness]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub struct DeterminismCheckpoint {
+    pub(crate) frame: u64,
+    pub(crate) checksums: Vec<(Option<String>, FrameChecksum)>,
+}
+
+impl DeterminismCheckpoint {
+    /// Number of frames rendered when the checksums were taken (the first frame is `1`)
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Framebuffer id (formatted with `Debug`) and checksum of each target, with the canvas
+    /// (`None`) last
+    pub fn checksums(&self) -> &[(Option<String>, FrameChecksum)] {
+        &self.checksums
+    }
+
+    /// Checksum of a framebuffer, or of the canvas if `None`
+    pub fn checksum(&self, framebuffer_id: Option<&str>) -> Option<&FrameChecksum> {
+        self.checksums
+            .iter()
+            .find(|(id, _)| id.as_deref() == framebuffer_id)
+            .map(|(_, checksum)| checksum)
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{
+    DeterminismCheckpoint, DeterminismReport, DeterministicMode, Id, IdName, RendererData,
+};
+use log::warn;
+
+/// Renders the same pipeline twice in [DeterministicMode] and compares [crate::FrameChecksum]s
+/// of every framebuffer and the canvas at regular intervals, to check that output is
+/// reproducible (e.g. for generative art that must look the same every time it's rendered).
+///
+/// Framebuffers are checksummed over a region the size of the drawing buffer, and any that
+/// can't be checksummed (such as float framebuffers) are skipped.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct DeterminismHarness {
+    seed: u64,
+    fps: f64,
+    frames: u64,
+    interval: u64,
+}
+
+impl DeterminismHarness {
+    /// Renders 120 frames per run, taking checksums every 10 frames
+    pub fn new(seed: u64, fps: f64) -> Self {
+        Self {
+            seed,
+            fps,
+            frames: 120,
+            interval: 10,
+        }
+    }
+
+    /// Number of frames rendered in each run
+    pub fn set_frames(&mut self, frames: u64) -> &mut Self {
+        self.frames = frames;
+        self
+    }
+
+    /// Number of frames between checksums. Checksums are also always taken after the last frame.
+    pub fn set_interval(&mut self, interval: u64) -> &mut Self {
+        self.interval = interval.max(1);
+        self
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn fps(&self) -> f64 {
+        self.fps
+    }
+
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    pub fn interval(&self) -> u64 {
+        self.interval
+    }
+
+    /// Whether checksums are taken after rendering `frame` frames
+    pub fn is_checkpoint(&self, frame: u64) -> bool {
+        (1..=self.frames).contains(&frame)
+            && (frame.is_multiple_of(self.interval) || frame == self.frames)
+    }
+
+    /// Builds the pipeline with `build`, records a run, then drops it and does the same again,
+    /// comparing the two runs
+    pub fn run<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        ProgramId: Id,
+        UniformId: Id + IdName,
+        BufferId: Id,
+        AttributeId: Id + IdName,
+        TextureId: Id,
+        FramebufferId: Id,
+        TransformFeedbackId: Id,
+        VertexArrayObjectId: Id,
+        UserCtx: Clone + 'static,
+    >(
+        &self,
+        mut build: impl FnMut() -> RendererData<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+    ) -> DeterminismReport {
+        let first = self.record(&build());
+        let second = self.record(&build());
+        DeterminismReport::compare(self.frames, &first, &second)
+    }
+
+    /// Renders a single run in deterministic mode, returning the checksums taken along the way.
+    ///
+    /// Runs recorded separately (e.g. in different browsers) can be compared with
+    /// [DeterminismReport::compare]. The renderer's previous deterministic mode is restored afterward.
+    pub fn record<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        ProgramId: Id,
+        UniformId: Id + IdName,
+        BufferId: Id,
+        AttributeId: Id + IdName,
+        TextureId: Id,
+        FramebufferId: Id,
+        TransformFeedbackId: Id,
+        VertexArrayObjectId: Id,
+        UserCtx: Clone + 'static,
+    >(
+        &self,
+        renderer_data: &RendererData<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+    ) -> Vec<DeterminismCheckpoint> {
+        let previous_mode = renderer_data.deterministic_mode();
+        renderer_data.set_deterministic_mode(Some(DeterministicMode::new(self.seed, self.fps)));
+
+        let mut framebuffer_ids: Vec<(String, &FramebufferId)> = renderer_data
+            .framebuffers()
+            .keys()
+            .map(|framebuffer_id| (format!("{framebuffer_id:?}"), framebuffer_id))
+            .collect();
+        framebuffer_ids.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut checkpoints = Vec::new();
+        for frame in 1..=self.frames {
+            renderer_data.update_uniforms();
+            renderer_data.render();
+            if !self.is_checkpoint(frame) {
+                continue;
+            }
+
+            let mut checksums = Vec::new();
+            let targets = framebuffer_ids
+                .iter()
+                .map(|(name, framebuffer_id)| (Some(name), Some(*framebuffer_id)))
+                .chain([(None, None)]);
+            for (name, framebuffer_id) in targets {
+                match renderer_data.frame_checksum(framebuffer_id) {
+                    Ok(checksum) => checksums.push((name.cloned(), checksum)),
+                    Err(err) if frame == self.interval.min(self.frames) => {
+                        warn!(
+                            "Skipping {} in determinism checks: {err}",
+                            name.map_or("canvas", String::as_str)
+                        );
+                    }
+                    Err(_) => {}
+                }
+            }
+            checkpoints.push(DeterminismCheckpoint { frame, checksums });
+        }
+
+        renderer_data.set_deterministic_mode(previous_mode);
+        checkpoints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DeterminismHarness;
+
+    #[test]
+    fn takes_checkpoints_at_intervals_and_last_frame() {
+        let mut harness = DeterminismHarness::new(1, 60.0);
+        harness.set_frames(25).set_interval(10);
+        let checkpoints: Vec<u64> = (0..=30)
+            .filter(|&frame| harness.is_checkpoint(frame))
+            .collect();
+        assert_eq!(checkpoints, [10, 20, 25]);
+    }
+}
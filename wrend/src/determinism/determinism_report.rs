@@ -0,0 +1,153 @@
+use crate::{DeterminismCheckpoint, FrameDivergence};
+use std::fmt::Display;
+
+/// Outcome of comparing two runs with a [crate::DeterminismHarness]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub struct DeterminismReport {
+    frames: u64,
+    checkpoints: usize,
+    divergences: Vec<FrameDivergence>,
+}
+
+impl DeterminismReport {
+    /// Compares checkpoints taken by two runs, stopping at the first checkpoint where any
+    /// target differs (later differences usually follow from the first one)
+    pub fn compare(
+        frames: u64,
+        first: &[DeterminismCheckpoint],
+        second: &[DeterminismCheckpoint],
+    ) -> Self {
+        let mut checkpoints = 0;
+        for (a, b) in first.iter().zip(second) {
+            checkpoints += 1;
+            let mut framebuffer_ids: Vec<&Option<String>> =
+                a.checksums().iter().map(|(id, _)| id).collect();
+            for (id, _) in b.checksums() {
+                if !framebuffer_ids.contains(&id) {
+                    framebuffer_ids.push(id);
+                }
+            }
+            // the canvas is composited last, so framebuffers are compared before it
+            framebuffer_ids.sort_by_key(|id| id.is_none());
+
+            let divergences: Vec<FrameDivergence> = framebuffer_ids
+                .into_iter()
+                .filter_map(|id| {
+                    let first = a.checksum(id.as_deref()).copied();
+                    let second = b.checksum(id.as_deref()).copied();
+                    let same =
+                        matches!((first, second), (Some(x), Some(y)) if x.hash() == y.hash());
+                    (!same).then(|| FrameDivergence {
+                        frame: a.frame().max(b.frame()),
+                        framebuffer_id: id.clone(),
+                        first,
+                        second,
+                    })
+                })
+                .collect();
+
+            if !divergences.is_empty() {
+                return Self {
+                    frames,
+                    checkpoints,
+                    divergences,
+                };
+            }
+        }
+
+        Self {
+            frames,
+            checkpoints,
+            divergences: Vec::new(),
+        }
+    }
+
+    /// Frames rendered in each run
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    /// Number of checkpoints compared, up to and including the first divergent one
+    pub fn checkpoints(&self) -> usize {
+        self.checkpoints
+    }
+
+    pub fn is_deterministic(&self) -> bool {
+        self.divergences.is_empty()
+    }
+
+    /// Every target that differed at the first divergent checkpoint, framebuffers first
+    pub fn divergences(&self) -> &[FrameDivergence] {
+        &self.divergences
+    }
+
+    /// The first checkpoint frame at which the runs differed
+    pub fn first_divergent_frame(&self) -> Option<u64> {
+        self.divergences.first().map(FrameDivergence::frame)
+    }
+}
+
+impl Display for DeterminismReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(frame) = self.first_divergent_frame() else {
+            return write!(
+                f,
+                "Deterministic: {} checkpoints matched over {} frames",
+                self.checkpoints, self.frames
+            );
+        };
+        let targets: Vec<&str> = self
+            .divergences
+            .iter()
+            .map(|divergence| divergence.framebuffer_id().unwrap_or("canvas"))
+            .collect();
+        write!(
+            f,
+            "Diverged at frame {frame} of {} in {}",
+            self.frames,
+            targets.join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DeterminismReport;
+    use crate::{DeterminismCheckpoint, FrameChecksum};
+
+    fn checkpoint(frame: u64, blur: u8, canvas: u8) -> DeterminismCheckpoint {
+        DeterminismCheckpoint {
+            frame,
+            checksums: vec![
+                (
+                    Some(String::from("\"blur\"")),
+                    FrameChecksum::from_pixels(&[blur, 0, 0, 255]),
+                ),
+                (None, FrameChecksum::from_pixels(&[canvas, 0, 0, 255])),
+            ],
+        }
+    }
+
+    #[test]
+    fn reports_first_divergent_checkpoint() {
+        let first = [
+            checkpoint(10, 0, 0),
+            checkpoint(20, 0, 0),
+            checkpoint(30, 0, 0),
+        ];
+        let report = DeterminismReport::compare(30, &first, &first);
+        assert!(report.is_deterministic());
+        assert_eq!(report.checkpoints(), 3);
+
+        let second = [
+            checkpoint(10, 0, 0),
+            checkpoint(20, 0, 200),
+            checkpoint(30, 200, 200),
+        ];
+        let report = DeterminismReport::compare(30, &first, &second);
+        assert_eq!(report.first_divergent_frame(), Some(20));
+        assert_eq!(report.checkpoints(), 2);
+        assert_eq!(report.divergences().len(), 1);
+        assert_eq!(report.to_string(), "Diverged at frame 20 of 30 in canvas");
+    }
+}
@@ -0,0 +1,34 @@
+use crate::FrameChecksum;
+
+/// A render target whose checksum differed between the two runs of a [crate::DeterminismHarness]
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct FrameDivergence {
+    pub(crate) frame: u64,
+    pub(crate) framebuffer_id: Option<String>,
+    pub(crate) first: Option<FrameChecksum>,
+    pub(crate) second: Option<FrameChecksum>,
+}
+
+impl FrameDivergence {
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// The framebuffer that diverged (formatted with `Debug`), or `None` for the canvas.
+    ///
+    /// Each framebuffer is normally the output of one pass, so this identifies the pass that
+    /// first produced different output.
+    pub fn framebuffer_id(&self) -> Option<&str> {
+        self.framebuffer_id.as_deref()
+    }
+
+    /// Checksum from the first run (`None` if it couldn't be taken in that run)
+    pub fn first(&self) -> Option<&FrameChecksum> {
+        self.first.as_ref()
+    }
+
+    /// Checksum from the second run (`None` if it couldn't be taken in that run)
+    pub fn second(&self) -> Option<&FrameChecksum> {
+        self.second.as_ref()
+    }
+}
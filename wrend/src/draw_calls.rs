@@ -0,0 +1,7 @@
+mod command_list;
+mod draw_call;
+mod draw_primitive;
+
+pub use command_list::*;
+pub use draw_call::*;
+pub use draw_primitive::*;
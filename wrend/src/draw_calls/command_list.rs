@@ -0,0 +1,306 @@
+use crate::{DrawCall, Id};
+
+/// A recorded sequence of [DrawCall]s that can be replayed every frame with
+/// [crate::RendererData::replay].
+///
+/// Replaying refreshes each referenced uniform once, then issues the draws in order while
+/// skipping program, VAO, and framebuffer changes that would rebind the state that is already bound.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CommandList<
+    ProgramId: Id,
+    UniformId: Id,
+    TextureId: Id,
+    FramebufferId: Id,
+    VertexArrayObjectId: Id,
+> {
+    draw_calls: Vec<DrawCall<ProgramId, UniformId, TextureId, FramebufferId, VertexArrayObjectId>>,
+}
+
+/// Which pieces of state a draw in a [CommandList] needs to bind, given the draws before it
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub(crate) struct StateChanges {
+    pub(crate) program: bool,
+    pub(crate) vao: bool,
+    pub(crate) framebuffer: bool,
+}
+
+impl StateChanges {
+    /// Rebinds every piece of state the draw specifies
+    pub(crate) const ALL: StateChanges = StateChanges {
+        program: true,
+        vao: true,
+        framebuffer: true,
+    };
+}
+
+impl<ProgramId: Id, UniformId: Id, TextureId: Id, FramebufferId: Id, VertexArrayObjectId: Id>
+    CommandList<ProgramId, UniformId, TextureId, FramebufferId, VertexArrayObjectId>
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a draw call to the end of the list
+    pub fn record(
+        &mut self,
+        draw_call: DrawCall<ProgramId, UniformId, TextureId, FramebufferId, VertexArrayObjectId>,
+    ) -> &mut Self {
+        self.draw_calls.push(draw_call);
+        self
+    }
+
+    pub fn draw_calls(
+        &self,
+    ) -> &[DrawCall<ProgramId, UniformId, TextureId, FramebufferId, VertexArrayObjectId>] {
+        &self.draw_calls
+    }
+
+    pub fn len(&self) -> usize {
+        self.draw_calls.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.draw_calls.is_empty()
+    }
+
+    pub fn clear(&mut self) -> &mut Self {
+        self.draw_calls.clear();
+        self
+    }
+
+    /// Every uniform referenced by the list, without duplicates, in first-use order
+    pub fn uniform_ids(&self) -> Vec<&UniformId> {
+        let mut uniform_ids: Vec<&UniformId> = Vec::new();
+        for uniform_id in self.draw_calls.iter().flat_map(DrawCall::uniform_ids) {
+            if !uniform_ids.contains(&uniform_id) {
+                uniform_ids.push(uniform_id);
+            }
+        }
+        uniform_ids
+    }
+
+    /// Reorders draws to minimize state changes during replay, grouping draws that share a
+    /// program, then a VAO, then texture bindings.
+    ///
+    /// Draws are only reordered within runs that render into the same framebuffer, so passes that
+    /// render into a texture still happen before the draws that sample it. Draws marked with
+    /// [DrawCall::order_dependent] (e.g. blended draws) are never moved, and no draw is moved across them.
+    /// The sort is stable, so draws with identical state keep their relative order.
+    pub fn sort_by_state(&mut self) -> &mut Self {
+        let mut start = 0;
+        while start < self.draw_calls.len() {
+            if self.draw_calls[start].is_order_dependent() {
+                start += 1;
+                continue;
+            }
+
+            let framebuffer_id = self.draw_calls[start].framebuffer_id();
+            let end = self.draw_calls[start..]
+                .iter()
+                .position(|draw_call| {
+                    draw_call.is_order_dependent() || draw_call.framebuffer_id() != framebuffer_id
+                })
+                .map_or(self.draw_calls.len(), |offset| start + offset);
+
+            sort_run(&mut self.draw_calls[start..end]);
+            start = end;
+        }
+
+        self
+    }
+
+    /// For each draw, which state actually needs to be rebound during replay
+    pub(crate) fn state_changes(&self) -> Vec<StateChanges> {
+        let mut program = None;
+        let mut vao = None;
+        let mut framebuffer = None;
+
+        self.draw_calls
+            .iter()
+            .map(|draw_call| {
+                let mut changes = StateChanges::default();
+                if let Some(program_id) = draw_call.program_id() {
+                    changes.program = program != Some(program_id);
+                    program = Some(program_id);
+                }
+                if let Some(vao_id) = draw_call.vao_id() {
+                    changes.vao = vao != Some(vao_id);
+                    vao = Some(vao_id);
+                }
+                if let Some(framebuffer_id) = draw_call.framebuffer_id() {
+                    changes.framebuffer = framebuffer != Some(framebuffer_id);
+                    framebuffer = Some(framebuffer_id);
+                }
+                changes
+            })
+            .collect()
+    }
+}
+
+impl<ProgramId: Id, UniformId: Id, TextureId: Id, FramebufferId: Id, VertexArrayObjectId: Id>
+    Default for CommandList<ProgramId, UniformId, TextureId, FramebufferId, VertexArrayObjectId>
+{
+    fn default() -> Self {
+        Self {
+            draw_calls: Vec::new(),
+        }
+    }
+}
+
+impl<ProgramId: Id, UniformId: Id, TextureId: Id, FramebufferId: Id, VertexArrayObjectId: Id>
+    FromIterator<DrawCall<ProgramId, UniformId, TextureId, FramebufferId, VertexArrayObjectId>>
+    for CommandList<ProgramId, UniformId, TextureId, FramebufferId, VertexArrayObjectId>
+{
+    fn from_iter<
+        T: IntoIterator<
+            Item = DrawCall<ProgramId, UniformId, TextureId, FramebufferId, VertexArrayObjectId>,
+        >,
+    >(
+        iter: T,
+    ) -> Self {
+        Self {
+            draw_calls: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Stably sorts draws by the order in which their program, VAO, and textures first appear.
+///
+/// Ids only implement `Eq`, so first appearance is used in place of an ordering.
+fn sort_run<
+    ProgramId: Id,
+    UniformId: Id,
+    TextureId: Id,
+    FramebufferId: Id,
+    VertexArrayObjectId: Id,
+>(
+    draw_calls: &mut [DrawCall<
+        ProgramId,
+        UniformId,
+        TextureId,
+        FramebufferId,
+        VertexArrayObjectId,
+    >],
+) {
+    fn first_index<T: PartialEq>(seen: &mut Vec<T>, value: T) -> usize {
+        seen.iter()
+            .position(|existing| *existing == value)
+            .unwrap_or_else(|| {
+                seen.push(value);
+                seen.len() - 1
+            })
+    }
+
+    let mut programs = Vec::new();
+    let mut vaos = Vec::new();
+    let mut textures = Vec::new();
+    let keys: Vec<(usize, usize, usize)> = draw_calls
+        .iter()
+        .map(|draw_call| {
+            (
+                first_index(&mut programs, draw_call.program_id().cloned()),
+                first_index(&mut vaos, draw_call.vao_id().cloned()),
+                first_index(&mut textures, draw_call.texture_bindings().to_vec()),
+            )
+        })
+        .collect();
+
+    let mut indices: Vec<usize> = (0..draw_calls.len()).collect();
+    indices.sort_by_key(|&i| keys[i]);
+
+    let sorted: Vec<_> = indices.iter().map(|&i| draw_calls[i].clone()).collect();
+    draw_calls.clone_from_slice(&sorted);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandList, StateChanges};
+    use crate::DrawCall;
+
+    type TestDrawCall = DrawCall<String, String, String, String, String>;
+
+    fn draw(program: &str, vao: &str, framebuffer: Option<&str>) -> TestDrawCall {
+        let mut draw_call = TestDrawCall::new();
+        draw_call
+            .program(program.to_string())
+            .vao(vao.to_string())
+            .framebuffer(framebuffer.map(str::to_string))
+            .uniforms([String::from("u_time")]);
+        draw_call
+    }
+
+    #[test]
+    fn skips_redundant_state_changes() {
+        let list: CommandList<_, _, _, _, _> = [
+            draw("a", "quad", None),
+            draw("a", "quad", None),
+            draw("b", "quad", Some("fb")),
+            draw("a", "mesh", Some("fb")),
+        ]
+        .into_iter()
+        .collect();
+
+        let changed = |program, vao, framebuffer| StateChanges {
+            program,
+            vao,
+            framebuffer,
+        };
+        assert_eq!(
+            list.state_changes(),
+            vec![
+                changed(true, true, true),
+                changed(false, false, false),
+                changed(true, false, true),
+                changed(true, true, false),
+            ]
+        );
+        assert_eq!(list.uniform_ids(), vec!["u_time"]);
+    }
+
+    #[test]
+    fn sorts_by_state_within_framebuffer_runs() {
+        let mut blended = draw("b", "quad", None);
+        blended.order_dependent(true);
+        let mut list: CommandList<_, _, _, _, _> = [
+            draw("a", "quad", Some("fb")),
+            draw("b", "quad", Some("fb")),
+            draw("a", "mesh", Some("fb")),
+            draw("b", "quad", None),
+            draw("a", "quad", None),
+            blended,
+            draw("a", "quad", None),
+            draw("b", "quad", None),
+            draw("a", "quad", None),
+        ]
+        .into_iter()
+        .collect();
+
+        list.sort_by_state();
+
+        let order: Vec<(&str, &str)> = list
+            .draw_calls()
+            .iter()
+            .map(|draw_call| {
+                (
+                    draw_call.program_id().unwrap().as_str(),
+                    draw_call.vao_id().unwrap().as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                ("a", "quad"),
+                ("a", "mesh"),
+                ("b", "quad"),
+                ("b", "quad"),
+                ("a", "quad"),
+                ("b", "quad"),
+                ("a", "quad"),
+                ("a", "quad"),
+                ("b", "quad"),
+            ]
+        );
+        assert!(list.draw_calls()[5].is_order_dependent());
+    }
+}
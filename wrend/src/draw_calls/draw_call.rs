@@ -0,0 +1,202 @@
+use crate::{DrawPrimitive, Id, IdName, RendererData, Viewport};
+
+/// A self-describing draw: which program, VAO, framebuffer, viewport, uniforms, and textures
+/// it needs, and what to draw. All state changes are applied by [RendererData::draw],
+/// so every draw in an app goes through a single place.
+///
+/// ```ignore
+/// DrawCall::new()
+///     .program(ProgramId::Blur)
+///     .vao(VaoId::Quad)
+///     .framebuffer(Some(FramebufferId::Blurred))
+///     .uniforms([UniformId::Direction])
+///     .textures([(TextureId::Scene, 0)])
+///     .arrays(WebGl2RenderingContext::TRIANGLES, 0, 6)
+///     .execute(renderer_data);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DrawCall<
+    ProgramId: Id,
+    UniformId: Id,
+    TextureId: Id,
+    FramebufferId: Id,
+    VertexArrayObjectId: Id,
+> {
+    program_id: Option<ProgramId>,
+    vao_id: Option<VertexArrayObjectId>,
+    framebuffer_id: Option<Option<FramebufferId>>,
+    viewport: Option<Viewport>,
+    uniform_ids: Vec<UniformId>,
+    textures: Vec<(TextureId, u32)>,
+    primitive: Option<DrawPrimitive>,
+    order_dependent: bool,
+}
+
+impl<ProgramId: Id, UniformId: Id, TextureId: Id, FramebufferId: Id, VertexArrayObjectId: Id>
+    DrawCall<ProgramId, UniformId, TextureId, FramebufferId, VertexArrayObjectId>
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Program to draw with. If unset, the currently bound program is used.
+    pub fn program(&mut self, program_id: ProgramId) -> &mut Self {
+        self.program_id = Some(program_id);
+        self
+    }
+
+    /// VAO to draw with. If unset, the currently bound VAO is used.
+    pub fn vao(&mut self, vao_id: VertexArrayObjectId) -> &mut Self {
+        self.vao_id = Some(vao_id);
+        self
+    }
+
+    /// Framebuffer to draw into, where `None` is the canvas.
+    /// If this is never called, the currently bound framebuffer is used.
+    pub fn framebuffer(&mut self, framebuffer_id: Option<FramebufferId>) -> &mut Self {
+        self.framebuffer_id = Some(framebuffer_id);
+        self
+    }
+
+    /// Viewport to draw into. If unset, the current viewport is used.
+    pub fn viewport(&mut self, viewport: Viewport) -> &mut Self {
+        self.viewport = Some(viewport);
+        self
+    }
+
+    /// Uniforms to update (using their update callbacks) before drawing
+    pub fn uniforms(&mut self, uniform_ids: impl IntoIterator<Item = UniformId>) -> &mut Self {
+        self.uniform_ids.extend(uniform_ids);
+        self
+    }
+
+    /// Textures to bind, each paired with a texture unit
+    pub fn textures(&mut self, textures: impl IntoIterator<Item = (TextureId, u32)>) -> &mut Self {
+        self.textures.extend(textures);
+        self
+    }
+
+    /// Draws with `drawArrays`
+    pub fn arrays(&mut self, mode: u32, first: i32, count: i32) -> &mut Self {
+        self.primitive(DrawPrimitive::Arrays { mode, first, count })
+    }
+
+    /// Draws with `drawArraysInstanced`
+    pub fn arrays_instanced(
+        &mut self,
+        mode: u32,
+        first: i32,
+        count: i32,
+        instance_count: i32,
+    ) -> &mut Self {
+        self.primitive(DrawPrimitive::ArraysInstanced {
+            mode,
+            first,
+            count,
+            instance_count,
+        })
+    }
+
+    /// Draws with `drawElements`, where `offset` is in bytes
+    pub fn elements(&mut self, mode: u32, count: i32, index_type: u32, offset: i32) -> &mut Self {
+        self.primitive(DrawPrimitive::Elements {
+            mode,
+            count,
+            index_type,
+            offset,
+        })
+    }
+
+    pub fn primitive(&mut self, primitive: DrawPrimitive) -> &mut Self {
+        self.primitive = Some(primitive);
+        self
+    }
+
+    /// Marks this draw as depending on the draws before it (e.g. blended or depth-ordered draws),
+    /// so that [crate::CommandList::sort_by_state] never moves it or moves other draws across it
+    pub fn order_dependent(&mut self, order_dependent: bool) -> &mut Self {
+        self.order_dependent = order_dependent;
+        self
+    }
+
+    pub fn is_order_dependent(&self) -> bool {
+        self.order_dependent
+    }
+
+    pub fn program_id(&self) -> Option<&ProgramId> {
+        self.program_id.as_ref()
+    }
+
+    pub fn vao_id(&self) -> Option<&VertexArrayObjectId> {
+        self.vao_id.as_ref()
+    }
+
+    /// `None` if the framebuffer is left unchanged, `Some(None)` for the canvas
+    pub fn framebuffer_id(&self) -> Option<Option<&FramebufferId>> {
+        self.framebuffer_id.as_ref().map(Option::as_ref)
+    }
+
+    pub fn viewport_rect(&self) -> Option<Viewport> {
+        self.viewport
+    }
+
+    pub fn uniform_ids(&self) -> &[UniformId] {
+        &self.uniform_ids
+    }
+
+    pub fn texture_bindings(&self) -> &[(TextureId, u32)] {
+        &self.textures
+    }
+
+    pub fn draw_primitive(&self) -> Option<DrawPrimitive> {
+        self.primitive
+    }
+
+    /// Applies this draw's state and issues it. See [RendererData::draw]
+    pub fn execute<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        BufferId: Id,
+        AttributeId: Id + IdName,
+        TransformFeedbackId: Id,
+        UserCtx: Clone,
+    >(
+        &self,
+        renderer_data: &RendererData<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+    ) -> &Self
+    where
+        UniformId: IdName,
+    {
+        renderer_data.draw(self);
+        self
+    }
+}
+
+impl<ProgramId: Id, UniformId: Id, TextureId: Id, FramebufferId: Id, VertexArrayObjectId: Id>
+    Default for DrawCall<ProgramId, UniformId, TextureId, FramebufferId, VertexArrayObjectId>
+{
+    fn default() -> Self {
+        Self {
+            program_id: None,
+            vao_id: None,
+            framebuffer_id: None,
+            viewport: None,
+            uniform_ids: Vec::new(),
+            textures: Vec::new(),
+            primitive: None,
+            order_dependent: false,
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use web_sys::WebGl2RenderingContext;
+
+/// The WebGL draw function issued by a [crate::DrawCall]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DrawPrimitive {
+    /// `drawArrays`
+    Arrays { mode: u32, first: i32, count: i32 },
+    /// `drawArraysInstanced`
+    ArraysInstanced {
+        mode: u32,
+        first: i32,
+        count: i32,
+        instance_count: i32,
+    },
+    /// `drawElements`, where `offset` is in bytes
+    Elements {
+        mode: u32,
+        count: i32,
+        index_type: u32,
+        offset: i32,
+    },
+    /// `drawElementsInstanced`, where `offset` is in bytes
+    ElementsInstanced {
+        mode: u32,
+        count: i32,
+        index_type: u32,
+        offset: i32,
+        instance_count: i32,
+    },
+}
+
+impl DrawPrimitive {
+    /// Primitive mode, such as `TRIANGLES`
+    pub fn mode(&self) -> u32 {
+        match self {
+            DrawPrimitive::Arrays { mode, .. }
+            | DrawPrimitive::ArraysInstanced { mode, .. }
+            | DrawPrimitive::Elements { mode, .. }
+            | DrawPrimitive::ElementsInstanced { mode, .. } => *mode,
+        }
+    }
+
+    /// Issues the draw
+    pub fn draw(&self, gl: &WebGl2RenderingContext) {
+        match *self {
+            DrawPrimitive::Arrays { mode, first, count } => gl.draw_arrays(mode, first, count),
+            DrawPrimitive::ArraysInstanced {
+                mode,
+                first,
+                count,
+                instance_count,
+            } => gl.draw_arrays_instanced(mode, first, count, instance_count),
+            DrawPrimitive::Elements {
+                mode,
+                count,
+                index_type,
+                offset,
+            } => gl.draw_elements_with_i32(mode, count, index_type, offset),
+            DrawPrimitive::ElementsInstanced {
+                mode,
+                count,
+                index_type,
+                offset,
+                instance_count,
+            } => {
+                gl.draw_elements_instanced_with_i32(mode, count, index_type, offset, instance_count)
+            }
+        }
+    }
+}
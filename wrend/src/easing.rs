@@ -0,0 +1,9 @@
+mod easing_function;
+mod interpolate;
+mod keyframe;
+mod keyframe_track;
+
+pub use easing_function::*;
+pub use interpolate::*;
+pub use keyframe::*;
+pub use keyframe_track::*;
@@ -0,0 +1,156 @@
+use std::f32::consts::PI;
+
+/// Standard easing curves. Each maps progress `t` in 0.0..=1.0 to an eased value that starts
+/// at 0.0 and ends at 1.0 (elastic and back curves overshoot in between).
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Easing {
+    /// No easing
+    #[default]
+    Linear,
+    /// Jumps to the end value at the end of the segment
+    Step,
+    /// Quadratic acceleration from zero velocity
+    QuadIn,
+    /// Quadratic deceleration to zero velocity
+    QuadOut,
+    /// Quadratic acceleration until halfway, then deceleration
+    QuadInOut,
+    /// Cubic acceleration from zero velocity
+    CubicIn,
+    /// Cubic deceleration to zero velocity
+    CubicOut,
+    /// Cubic acceleration until halfway, then deceleration
+    CubicInOut,
+    /// Sinusoidal acceleration from zero velocity
+    SineIn,
+    /// Sinusoidal deceleration to zero velocity
+    SineOut,
+    /// Sinusoidal acceleration until halfway, then deceleration
+    SineInOut,
+    /// Exponential acceleration from zero velocity
+    ExpoIn,
+    /// Exponential deceleration to zero velocity
+    ExpoOut,
+    /// Pulls back slightly before accelerating
+    BackIn,
+    /// Overshoots slightly before settling
+    BackOut,
+    /// Springs past the end value before settling
+    ElasticOut,
+    /// Bounces against the end value before settling
+    BounceOut,
+}
+
+impl Easing {
+    /// Evaluates the easing curve. `t` is clamped to 0.0..=1.0
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::Step => {
+                if t < 1.0 {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::QuadInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Easing::CubicInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::SineIn => 1.0 - (t * PI / 2.0).cos(),
+            Easing::SineOut => (t * PI / 2.0).sin(),
+            Easing::SineInOut => -((PI * t).cos() - 1.0) / 2.0,
+            Easing::ExpoIn => {
+                if t == 0.0 {
+                    0.0
+                } else {
+                    2f32.powf(10.0 * t - 10.0)
+                }
+            }
+            Easing::ExpoOut => {
+                if t == 1.0 {
+                    1.0
+                } else {
+                    1.0 - 2f32.powf(-10.0 * t)
+                }
+            }
+            Easing::BackIn => {
+                const C1: f32 = 1.70158;
+                (C1 + 1.0) * t * t * t - C1 * t * t
+            }
+            Easing::BackOut => {
+                const C1: f32 = 1.70158;
+                1.0 + (C1 + 1.0) * (t - 1.0).powi(3) + C1 * (t - 1.0).powi(2)
+            }
+            Easing::ElasticOut => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else {
+                    2f32.powf(-10.0 * t) * ((t * 10.0 - 0.75) * (2.0 * PI / 3.0)).sin() + 1.0
+                }
+            }
+            Easing::BounceOut => {
+                const N: f32 = 7.5625;
+                const D: f32 = 2.75;
+                if t < 1.0 / D {
+                    N * t * t
+                } else if t < 2.0 / D {
+                    let t = t - 1.5 / D;
+                    N * t * t + 0.75
+                } else if t < 2.5 / D {
+                    let t = t - 2.25 / D;
+                    N * t * t + 0.9375
+                } else {
+                    let t = t - 2.625 / D;
+                    N * t * t + 0.984375
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Easing;
+
+    #[test]
+    fn curves_start_at_zero_and_end_at_one() {
+        for easing in [
+            Easing::Linear,
+            Easing::QuadIn,
+            Easing::QuadOut,
+            Easing::QuadInOut,
+            Easing::CubicIn,
+            Easing::CubicOut,
+            Easing::CubicInOut,
+            Easing::SineIn,
+            Easing::SineOut,
+            Easing::SineInOut,
+            Easing::ExpoIn,
+            Easing::ExpoOut,
+            Easing::BackIn,
+            Easing::BackOut,
+            Easing::ElasticOut,
+            Easing::BounceOut,
+        ] {
+            assert!(easing.apply(0.0).abs() < 1e-5, "{easing:?}");
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-5, "{easing:?}");
+        }
+        assert_eq!(Easing::QuadInOut.apply(0.5), 0.5);
+    }
+}
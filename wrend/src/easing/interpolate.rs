@@ -0,0 +1,27 @@
+/// Values that can be linearly interpolated, such as uniform values animated by a [crate::KeyframeTrack]
+pub trait Interpolate: Clone {
+    /// Interpolates between `self` (at 0.0) and `other` (at 1.0)
+    fn interpolate(&self, other: &Self, amount: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        self + (other - self) * amount
+    }
+}
+
+impl Interpolate for f64 {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        self + (other - self) * amount as f64
+    }
+}
+
+impl<const N: usize> Interpolate for [f32; N] {
+    fn interpolate(&self, other: &Self, amount: f32) -> Self {
+        let mut result = *self;
+        for (i, value) in result.iter_mut().enumerate() {
+            *value = value.interpolate(&other[i], amount);
+        }
+        result
+    }
+}
@@ -0,0 +1,41 @@
+use crate::Easing;
+
+/// A value at a point in time. The keyframe's easing curve is used for the segment that
+/// *ends* at this keyframe.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Keyframe<T> {
+    time: f64,
+    value: T,
+    easing: Easing,
+}
+
+impl<T> Keyframe<T> {
+    /// Creates a keyframe that is reached linearly
+    pub fn new(time: f64, value: T) -> Self {
+        Self::new_with_easing(time, value, Easing::Linear)
+    }
+
+    /// Creates a keyframe that is reached using the given easing curve
+    pub fn new_with_easing(time: f64, value: T, easing: Easing) -> Self {
+        Self {
+            time,
+            value,
+            easing,
+        }
+    }
+
+    /// Time of the keyframe (in whatever unit the track is sampled with, e.g. seconds)
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// Value at this keyframe
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Easing curve used to approach this keyframe
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+}
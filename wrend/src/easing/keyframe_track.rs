@@ -0,0 +1,113 @@
+use crate::{Interpolate, Keyframe};
+
+/// A sequence of keyframes that can be sampled at any time, e.g. from within a uniform
+/// update callback using `ctx.now()`.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct KeyframeTrack<T: Interpolate> {
+    keyframes: Vec<Keyframe<T>>,
+    looping: bool,
+}
+
+impl<T: Interpolate> KeyframeTrack<T> {
+    /// Creates an empty track
+    pub fn new() -> Self {
+        Self {
+            keyframes: Vec::new(),
+            looping: false,
+        }
+    }
+
+    /// Adds a keyframe. Keyframes may be added in any order.
+    pub fn add_keyframe(&mut self, keyframe: Keyframe<T>) -> &mut Self {
+        let index = self
+            .keyframes
+            .partition_point(|existing| existing.time() <= keyframe.time());
+        self.keyframes.insert(index, keyframe);
+        self
+    }
+
+    /// Keyframes, sorted by time
+    pub fn keyframes(&self) -> &[Keyframe<T>] {
+        &self.keyframes
+    }
+
+    /// Whether sampling past the last keyframe wraps back to the first
+    pub fn looping(&self) -> bool {
+        self.looping
+    }
+
+    /// See [KeyframeTrack::looping]
+    pub fn set_looping(&mut self, looping: bool) -> &mut Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Time of the last keyframe
+    pub fn duration(&self) -> f64 {
+        self.keyframes.last().map_or(0.0, Keyframe::time)
+    }
+
+    /// Evaluates the track at `time`, holding the first and last values outside of the track
+    /// (unless the track is looping). Returns `None` if the track has no keyframes.
+    pub fn sample(&self, time: f64) -> Option<T> {
+        let first = self.keyframes.first()?;
+        let last = self.keyframes.last()?;
+        let duration = self.duration();
+
+        let time = if self.looping && duration > first.time() {
+            first.time() + (time - first.time()).rem_euclid(duration - first.time())
+        } else {
+            time
+        };
+
+        if time <= first.time() {
+            return Some(first.value().clone());
+        }
+        if time >= last.time() {
+            return Some(last.value().clone());
+        }
+
+        let next = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.time() <= time);
+        let start = &self.keyframes[next - 1];
+        let end = &self.keyframes[next];
+        let progress = ((time - start.time()) / (end.time() - start.time())) as f32;
+
+        Some(
+            start
+                .value()
+                .interpolate(end.value(), end.easing().apply(progress)),
+        )
+    }
+}
+
+impl<T: Interpolate> Default for KeyframeTrack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyframeTrack;
+    use crate::{Easing, Keyframe};
+
+    #[test]
+    fn samples_keyframes() {
+        let mut track = KeyframeTrack::new();
+        track
+            .add_keyframe(Keyframe::new(2.0, [1.0, 0.0]))
+            .add_keyframe(Keyframe::new(0.0, [0.0, 1.0]))
+            .add_keyframe(Keyframe::new_with_easing(3.0, [2.0, 0.0], Easing::Step));
+
+        assert_eq!(track.sample(-1.0), Some([0.0, 1.0]));
+        assert_eq!(track.sample(1.0), Some([0.5, 0.5]));
+        assert_eq!(track.sample(2.5), Some([1.0, 0.0]));
+        assert_eq!(track.sample(10.0), Some([2.0, 0.0]));
+
+        track.set_looping(true);
+        assert_eq!(track.sample(4.0), Some([0.5, 0.5]));
+        assert_eq!(KeyframeTrack::<f32>::new().sample(1.0), None);
+    }
+}
@@ -0,0 +1,9 @@
+mod fluid_impulse;
+mod fluid_settings;
+mod fluid_shaders;
+mod fluid_simulation;
+
+pub use fluid_impulse::*;
+pub use fluid_settings::*;
+pub use fluid_shaders::*;
+pub use fluid_simulation::*;
@@ -0,0 +1,31 @@
+/// A splat of force and dye added to a [crate::FluidSimulation], e.g. where the pointer is dragged
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct FluidImpulse {
+    position: [f32; 2],
+    velocity: [f32; 2],
+    color: [f32; 3],
+}
+
+impl FluidImpulse {
+    /// `position` is normalized with the origin in the bottom-left corner (like [crate::PointerState]),
+    /// and `velocity` is in simulation cells per second
+    pub fn new(position: [f32; 2], velocity: [f32; 2], color: [f32; 3]) -> Self {
+        Self {
+            position,
+            velocity,
+            color,
+        }
+    }
+
+    pub fn position(&self) -> [f32; 2] {
+        self.position
+    }
+
+    pub fn velocity(&self) -> [f32; 2] {
+        self.velocity
+    }
+
+    pub fn color(&self) -> [f32; 3] {
+        self.color
+    }
+}
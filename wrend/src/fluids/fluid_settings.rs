@@ -0,0 +1,143 @@
+/// Tuning parameters for a [crate::FluidSimulation].
+///
+/// Everything except the resolution and number of pressure iterations can be changed while the
+/// simulation is running (see [crate::FluidSimulation::set_settings]).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct FluidSettings {
+    sim_resolution: (u32, u32),
+    dye_resolution: (u32, u32),
+    pressure_iterations: u32,
+    pressure_decay: f32,
+    velocity_dissipation: f32,
+    dye_dissipation: f32,
+    curl_strength: f32,
+    splat_radius: f32,
+    splat_force: f32,
+    render_to_canvas: bool,
+}
+
+impl Default for FluidSettings {
+    fn default() -> Self {
+        Self {
+            sim_resolution: (128, 128),
+            dye_resolution: (1024, 1024),
+            pressure_iterations: 20,
+            pressure_decay: 0.8,
+            velocity_dissipation: 0.2,
+            dye_dissipation: 1.0,
+            curl_strength: 30.0,
+            splat_radius: 0.0025,
+            splat_force: 6000.0,
+            render_to_canvas: true,
+        }
+    }
+}
+
+impl FluidSettings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Size of the velocity and pressure textures. Fixed once the simulation is built.
+    pub fn sim_resolution(&self) -> (u32, u32) {
+        self.sim_resolution
+    }
+
+    pub fn set_sim_resolution(&mut self, width: u32, height: u32) -> &mut Self {
+        self.sim_resolution = (width.max(1), height.max(1));
+        self
+    }
+
+    /// Size of the dye texture, which is usually much larger than the simulation. Fixed once the simulation is built.
+    pub fn dye_resolution(&self) -> (u32, u32) {
+        self.dye_resolution
+    }
+
+    pub fn set_dye_resolution(&mut self, width: u32, height: u32) -> &mut Self {
+        self.dye_resolution = (width.max(1), height.max(1));
+        self
+    }
+
+    /// Number of Jacobi iterations used to solve for pressure each step: more iterations make the
+    /// fluid less compressible. Each iteration is its own render pass, so this is fixed once the
+    /// simulation is built.
+    pub fn pressure_iterations(&self) -> u32 {
+        self.pressure_iterations
+    }
+
+    pub fn set_pressure_iterations(&mut self, pressure_iterations: u32) -> &mut Self {
+        self.pressure_iterations = pressure_iterations.max(1);
+        self
+    }
+
+    /// How much of the previous step's pressure is kept as the starting guess for the next one
+    pub fn pressure_decay(&self) -> f32 {
+        self.pressure_decay
+    }
+
+    pub fn set_pressure_decay(&mut self, pressure_decay: f32) -> &mut Self {
+        self.pressure_decay = pressure_decay.clamp(0.0, 1.0);
+        self
+    }
+
+    /// How quickly the fluid slows down, per second
+    pub fn velocity_dissipation(&self) -> f32 {
+        self.velocity_dissipation
+    }
+
+    pub fn set_velocity_dissipation(&mut self, velocity_dissipation: f32) -> &mut Self {
+        self.velocity_dissipation = velocity_dissipation.max(0.0);
+        self
+    }
+
+    /// How quickly the dye fades, per second
+    pub fn dye_dissipation(&self) -> f32 {
+        self.dye_dissipation
+    }
+
+    pub fn set_dye_dissipation(&mut self, dye_dissipation: f32) -> &mut Self {
+        self.dye_dissipation = dye_dissipation.max(0.0);
+        self
+    }
+
+    /// Strength of vorticity confinement, which restores the small swirls lost to numerical damping
+    pub fn curl_strength(&self) -> f32 {
+        self.curl_strength
+    }
+
+    pub fn set_curl_strength(&mut self, curl_strength: f32) -> &mut Self {
+        self.curl_strength = curl_strength.max(0.0);
+        self
+    }
+
+    /// Size of each [crate::FluidImpulse], relative to the simulation area
+    pub fn splat_radius(&self) -> f32 {
+        self.splat_radius
+    }
+
+    pub fn set_splat_radius(&mut self, splat_radius: f32) -> &mut Self {
+        self.splat_radius = splat_radius.max(f32::EPSILON);
+        self
+    }
+
+    /// Multiplies the distance the pointer moved into the velocity of the impulses it creates
+    pub fn splat_force(&self) -> f32 {
+        self.splat_force
+    }
+
+    pub fn set_splat_force(&mut self, splat_force: f32) -> &mut Self {
+        self.splat_force = splat_force;
+        self
+    }
+
+    /// Whether the simulation's render graph ends with a pass that draws the dye to the canvas.
+    /// Fixed once the simulation is built.
+    pub fn render_to_canvas(&self) -> bool {
+        self.render_to_canvas
+    }
+
+    pub fn set_render_to_canvas(&mut self, render_to_canvas: bool) -> &mut Self {
+        self.render_to_canvas = render_to_canvas;
+        self
+    }
+}
@@ -0,0 +1,179 @@
+// Fragment shaders for each pass of a [crate::FluidSimulation], all paired with [crate::COMPOSITING_VERTEX_SHADER].
+// `u_texel` is the size of one texel of the simulation (velocity and pressure) textures.
+
+/// Most impulses that can be applied in a single step
+pub const FLUID_MAX_IMPULSES: usize = 16;
+
+/// Adds Gaussian splats to `u_target` (velocity or dye)
+pub const FLUID_SPLAT_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+#define MAX_IMPULSES 16
+
+uniform sampler2D u_target;
+uniform float u_aspect;
+uniform float u_radius;
+uniform int u_impulse_count;
+uniform vec2 u_impulse_positions[MAX_IMPULSES];
+uniform vec3 u_impulse_values[MAX_IMPULSES];
+
+in vec2 v_uv;
+out vec4 out_color;
+
+void main() {
+    vec3 value = texture(u_target, v_uv).xyz;
+    for (int i = 0; i < u_impulse_count; i++) {
+        vec2 offset = v_uv - u_impulse_positions[i];
+        offset.x *= u_aspect;
+        value += exp(-dot(offset, offset) / u_radius) * u_impulse_values[i];
+    }
+    out_color = vec4(value, 1.0);
+}
+"#;
+
+/// Computes the curl (vorticity) of the velocity field
+pub const FLUID_CURL_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform sampler2D u_velocity;
+uniform vec2 u_texel;
+
+in vec2 v_uv;
+out vec4 out_color;
+
+void main() {
+    float left = texture(u_velocity, v_uv - vec2(u_texel.x, 0.0)).y;
+    float right = texture(u_velocity, v_uv + vec2(u_texel.x, 0.0)).y;
+    float top = texture(u_velocity, v_uv + vec2(0.0, u_texel.y)).x;
+    float bottom = texture(u_velocity, v_uv - vec2(0.0, u_texel.y)).x;
+    out_color = vec4(0.5 * (right - left - top + bottom), 0.0, 0.0, 1.0);
+}
+"#;
+
+/// Vorticity confinement: pushes the velocity field to spin around areas of high curl
+pub const FLUID_VORTICITY_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform sampler2D u_velocity;
+uniform sampler2D u_curl;
+uniform vec2 u_texel;
+uniform float u_curl_strength;
+uniform float u_dt;
+
+in vec2 v_uv;
+out vec4 out_color;
+
+void main() {
+    float left = texture(u_curl, v_uv - vec2(u_texel.x, 0.0)).x;
+    float right = texture(u_curl, v_uv + vec2(u_texel.x, 0.0)).x;
+    float top = texture(u_curl, v_uv + vec2(0.0, u_texel.y)).x;
+    float bottom = texture(u_curl, v_uv - vec2(0.0, u_texel.y)).x;
+    float center = texture(u_curl, v_uv).x;
+
+    vec2 force = 0.5 * vec2(abs(top) - abs(bottom), abs(right) - abs(left));
+    force /= length(force) + 0.0001;
+    force *= u_curl_strength * center;
+    force.y *= -1.0;
+
+    vec2 velocity = texture(u_velocity, v_uv).xy + force * u_dt;
+    out_color = vec4(clamp(velocity, -1000.0, 1000.0), 0.0, 1.0);
+}
+"#;
+
+/// Computes the divergence of the velocity field, treating the edges as solid walls
+pub const FLUID_DIVERGENCE_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform sampler2D u_velocity;
+uniform vec2 u_texel;
+
+in vec2 v_uv;
+out vec4 out_color;
+
+void main() {
+    vec2 center = texture(u_velocity, v_uv).xy;
+    float left = v_uv.x - u_texel.x < 0.0 ? -center.x : texture(u_velocity, v_uv - vec2(u_texel.x, 0.0)).x;
+    float right = v_uv.x + u_texel.x > 1.0 ? -center.x : texture(u_velocity, v_uv + vec2(u_texel.x, 0.0)).x;
+    float top = v_uv.y + u_texel.y > 1.0 ? -center.y : texture(u_velocity, v_uv + vec2(0.0, u_texel.y)).y;
+    float bottom = v_uv.y - u_texel.y < 0.0 ? -center.y : texture(u_velocity, v_uv - vec2(0.0, u_texel.y)).y;
+    out_color = vec4(0.5 * (right - left + top - bottom), 0.0, 0.0, 1.0);
+}
+"#;
+
+/// One Jacobi iteration of the pressure solve. `u_pressure_scale` decays the previous step's
+/// pressure on the first iteration and is `1.0` afterward.
+pub const FLUID_PRESSURE_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform sampler2D u_pressure;
+uniform sampler2D u_divergence;
+uniform vec2 u_texel;
+uniform float u_pressure_scale;
+
+in vec2 v_uv;
+out vec4 out_color;
+
+void main() {
+    float left = texture(u_pressure, v_uv - vec2(u_texel.x, 0.0)).x;
+    float right = texture(u_pressure, v_uv + vec2(u_texel.x, 0.0)).x;
+    float top = texture(u_pressure, v_uv + vec2(0.0, u_texel.y)).x;
+    float bottom = texture(u_pressure, v_uv - vec2(0.0, u_texel.y)).x;
+    float divergence = texture(u_divergence, v_uv).x;
+    float pressure = (left + right + top + bottom) * u_pressure_scale - divergence;
+    out_color = vec4(pressure * 0.25, 0.0, 0.0, 1.0);
+}
+"#;
+
+/// Subtracts the pressure gradient from the velocity field, making it divergence-free
+pub const FLUID_GRADIENT_SUBTRACT_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform sampler2D u_pressure;
+uniform sampler2D u_velocity;
+uniform vec2 u_texel;
+
+in vec2 v_uv;
+out vec4 out_color;
+
+void main() {
+    float left = texture(u_pressure, v_uv - vec2(u_texel.x, 0.0)).x;
+    float right = texture(u_pressure, v_uv + vec2(u_texel.x, 0.0)).x;
+    float top = texture(u_pressure, v_uv + vec2(0.0, u_texel.y)).x;
+    float bottom = texture(u_pressure, v_uv - vec2(0.0, u_texel.y)).x;
+    vec2 velocity = texture(u_velocity, v_uv).xy - vec2(right - left, top - bottom);
+    out_color = vec4(velocity, 0.0, 1.0);
+}
+"#;
+
+/// Semi-Lagrangian advection of `u_source` (velocity or dye) along the velocity field
+pub const FLUID_ADVECT_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform sampler2D u_velocity;
+uniform sampler2D u_source;
+uniform vec2 u_texel;
+uniform float u_dt;
+uniform float u_dissipation;
+
+in vec2 v_uv;
+out vec4 out_color;
+
+void main() {
+    vec2 coord = v_uv - u_dt * texture(u_velocity, v_uv).xy * u_texel;
+    out_color = texture(u_source, coord) / (1.0 + u_dissipation * u_dt);
+}
+"#;
+
+/// Draws the dye
+pub const FLUID_DISPLAY_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform sampler2D u_dye;
+
+in vec2 v_uv;
+out vec4 out_color;
+
+void main() {
+    out_color = vec4(texture(u_dye, v_uv).rgb, 1.0);
+}
+"#;
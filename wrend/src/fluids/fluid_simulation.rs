@@ -0,0 +1,498 @@
+use crate::{
+    create_program, FluidImpulse, FluidSettings, FluidSimulationError, PointerState, RenderGraph,
+    RenderGraphTextureDescriptor, RenderPass, RenderPassContext, COMPOSITING_VERTEX_SHADER,
+    FLUID_ADVECT_FRAGMENT_SHADER, FLUID_CURL_FRAGMENT_SHADER, FLUID_DISPLAY_FRAGMENT_SHADER,
+    FLUID_DIVERGENCE_FRAGMENT_SHADER, FLUID_GRADIENT_SUBTRACT_FRAGMENT_SHADER, FLUID_MAX_IMPULSES,
+    FLUID_PRESSURE_FRAGMENT_SHADER, FLUID_SPLAT_FRAGMENT_SHADER, FLUID_VORTICITY_FRAGMENT_SHADER,
+};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use web_sys::{WebGl2RenderingContext, WebGlProgram, WebGlTexture, WebGlVertexArrayObject};
+
+/// Longest time step simulated at once, so that a stalled tab doesn't blow up the simulation
+const MAX_DT: f32 = 1.0 / 30.0;
+
+/// A 2D stable-fluids solver (Stam, 1999) that runs on the GPU as a [RenderGraph].
+///
+/// Each [FluidSimulation::step] splats any pending impulses into the velocity and dye textures,
+/// applies vorticity confinement, projects the velocity field to be divergence-free (with one
+/// pass per Jacobi pressure iteration), and then advects velocity and dye along it. Unless
+/// disabled with [FluidSettings::set_render_to_canvas], the dye is then drawn to the canvas.
+///
+/// Requires `EXT_color_buffer_float`, since every texture is a half float texture.
+#[derive(Debug)]
+pub struct FluidSimulation {
+    gl: WebGl2RenderingContext,
+    graph: RenderGraph,
+    state: Rc<RefCell<FluidState>>,
+    programs: Vec<WebGlProgram>,
+    vao: WebGlVertexArrayObject,
+}
+
+/// Simulation state shared with the render pass callbacks
+#[derive(Debug, Default)]
+struct FluidState {
+    settings: FluidSettings,
+    impulses: Vec<FluidImpulse>,
+    dt: f32,
+    last_pointer_position: Option<[f32; 2]>,
+}
+
+impl FluidSimulation {
+    pub fn new(
+        gl: &WebGl2RenderingContext,
+        settings: FluidSettings,
+    ) -> Result<Self, FluidSimulationError> {
+        if gl
+            .get_extension("EXT_color_buffer_float")
+            .ok()
+            .flatten()
+            .is_none()
+        {
+            return Err(FluidSimulationError::FloatRenderingNotSupported);
+        }
+        let vao = gl
+            .create_vertex_array()
+            .ok_or(FluidSimulationError::CreateVertexArray)?;
+
+        let layout = fluid_pass_layout(settings.pressure_iterations(), settings.render_to_canvas());
+        let mut programs = HashMap::new();
+        for pass in &layout {
+            if !programs.contains_key(pass.kind.program_name()) {
+                let program = compile_program(gl, pass.kind)?;
+                programs.insert(pass.kind.program_name(), program);
+            }
+        }
+
+        let (sim_width, sim_height) = settings.sim_resolution();
+        let (dye_width, dye_height) = settings.dye_resolution();
+        let half_float = |width: u32, height: u32, internal_format, format, filter| {
+            RenderGraphTextureDescriptor::new(
+                width as i32,
+                height as i32,
+                internal_format,
+                format,
+                WebGl2RenderingContext::HALF_FLOAT,
+                filter,
+            )
+        };
+        let vector = |width, height| {
+            half_float(
+                width,
+                height,
+                WebGl2RenderingContext::RGBA16F,
+                WebGl2RenderingContext::RGBA,
+                WebGl2RenderingContext::LINEAR,
+            )
+        };
+        let scalar = half_float(
+            sim_width,
+            sim_height,
+            WebGl2RenderingContext::R16F,
+            WebGl2RenderingContext::RED,
+            WebGl2RenderingContext::NEAREST,
+        );
+
+        let state = Rc::new(RefCell::new(FluidState {
+            settings,
+            ..Default::default()
+        }));
+        let texel = [1.0 / sim_width as f32, 1.0 / sim_height as f32];
+        let aspect = sim_width as f32 / sim_height as f32;
+
+        let mut builder = RenderGraph::builder();
+        builder
+            .add_texture("velocity", vector(sim_width, sim_height))
+            .add_texture("curl", scalar)
+            .add_texture("divergence", scalar)
+            .add_texture("pressure", scalar)
+            .add_texture("dye", vector(dye_width, dye_height));
+
+        for pass in layout {
+            let program = programs[pass.kind.program_name()].clone();
+            let vao = vao.clone();
+            let state = Rc::clone(&state);
+            let kind = pass.kind;
+            let reads = pass.reads.clone();
+
+            let mut render_pass = RenderPass::new(pass.name, move |ctx: &RenderPassContext| {
+                let gl = ctx.gl();
+                gl.use_program(Some(&program));
+                gl.bind_vertex_array(Some(&vao));
+                for (unit, (sampler_name, texture_name)) in reads.iter().enumerate() {
+                    ctx.bind_textures(&[(texture_name, unit as u32)]);
+                    gl.uniform1i(
+                        gl.get_uniform_location(&program, sampler_name).as_ref(),
+                        unit as i32,
+                    );
+                }
+                gl.active_texture(WebGl2RenderingContext::TEXTURE0);
+                kind.set_uniforms(gl, &program, &state.borrow(), texel, aspect);
+                gl.draw_arrays(WebGl2RenderingContext::TRIANGLES, 0, 3);
+                gl.bind_vertex_array(None);
+            });
+            for (_, texture_name) in &pass.reads {
+                render_pass.add_read(*texture_name);
+            }
+            render_pass.set_writes(pass.writes);
+            builder.add_pass(render_pass);
+        }
+
+        Ok(Self {
+            gl: gl.clone(),
+            graph: builder.build(gl)?,
+            state,
+            programs: programs.into_values().collect(),
+            vao,
+        })
+    }
+
+    /// Advances the simulation by `dt` seconds (limited to 1/30th of a second), applying and then
+    /// clearing any pending impulses
+    pub fn step(&mut self, dt: f32) -> &mut Self {
+        self.state.borrow_mut().dt = dt.clamp(0.0, MAX_DT);
+        self.graph.execute();
+        self.state.borrow_mut().impulses.clear();
+        self
+    }
+
+    /// Queues an impulse for the next step. At most [FLUID_MAX_IMPULSES] are applied per step:
+    /// beyond that, the oldest impulses are dropped.
+    pub fn add_impulse(&self, impulse: FluidImpulse) -> &Self {
+        let mut state = self.state.borrow_mut();
+        if state.impulses.len() == FLUID_MAX_IMPULSES {
+            state.impulses.remove(0);
+        }
+        state.impulses.push(impulse);
+        self
+    }
+
+    /// Adds an impulse of `color` dye wherever the pointer is dragged, pushing the fluid in the
+    /// direction it moved. Call this once per frame, e.g. with the state of the renderer's
+    /// [crate::PointerTracker].
+    pub fn update_from_pointer(&self, pointer: &PointerState, color: [f32; 3]) -> &Self {
+        let position = pointer.position().filter(|_| pointer.is_down());
+        let last_position = self.state.borrow_mut().last_pointer_position.take();
+        self.state.borrow_mut().last_pointer_position = position;
+
+        if let (Some(position), Some(last_position)) = (position, last_position) {
+            if position != last_position {
+                let force = self.state.borrow().settings.splat_force();
+                let velocity = [
+                    (position[0] - last_position[0]) * force,
+                    (position[1] - last_position[1]) * force,
+                ];
+                self.add_impulse(FluidImpulse::new(position, velocity, color));
+            }
+        }
+        self
+    }
+
+    pub fn settings(&self) -> FluidSettings {
+        self.state.borrow().settings
+    }
+
+    /// Updates the settings that can change while the simulation is running: resolutions,
+    /// pressure iterations and rendering to the canvas are kept as they were when it was built
+    pub fn set_settings(&self, settings: FluidSettings) -> &Self {
+        let mut state = self.state.borrow_mut();
+        let current = state.settings;
+        let mut settings = settings;
+        let (sim_width, sim_height) = current.sim_resolution();
+        let (dye_width, dye_height) = current.dye_resolution();
+        settings
+            .set_sim_resolution(sim_width, sim_height)
+            .set_dye_resolution(dye_width, dye_height)
+            .set_pressure_iterations(current.pressure_iterations())
+            .set_render_to_canvas(current.render_to_canvas());
+        state.settings = settings;
+        self
+    }
+
+    /// The graph that runs the simulation, e.g. to trace its passes with [RenderGraph::set_frame_tracer]
+    pub fn graph(&self) -> &RenderGraph {
+        &self.graph
+    }
+
+    pub fn graph_mut(&mut self) -> &mut RenderGraph {
+        &mut self.graph
+    }
+
+    /// Current velocity field, in simulation cells per second (in the red and green channels)
+    pub fn velocity_texture(&self) -> Option<&WebGlTexture> {
+        self.graph.texture("velocity")
+    }
+
+    /// Current dye, for drawing the fluid yourself
+    pub fn dye_texture(&self) -> Option<&WebGlTexture> {
+        self.graph.texture("dye")
+    }
+
+    /// Deletes the simulation's programs and VAO. The simulation can't be stepped afterward.
+    pub fn delete(&self) {
+        for program in &self.programs {
+            self.gl.delete_program(Some(program));
+        }
+        self.gl.delete_vertex_array(Some(&self.vao));
+    }
+}
+
+/// What a pass of the simulation does, which determines its program and uniforms
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FluidPassKind {
+    SplatVelocity,
+    SplatDye,
+    Curl,
+    Vorticity,
+    Divergence,
+    Pressure { first_iteration: bool },
+    GradientSubtract,
+    AdvectVelocity,
+    AdvectDye,
+    Display,
+}
+
+impl FluidPassKind {
+    fn program_name(&self) -> &'static str {
+        match self {
+            FluidPassKind::SplatVelocity | FluidPassKind::SplatDye => "splat",
+            FluidPassKind::Curl => "curl",
+            FluidPassKind::Vorticity => "vorticity",
+            FluidPassKind::Divergence => "divergence",
+            FluidPassKind::Pressure { .. } => "pressure",
+            FluidPassKind::GradientSubtract => "gradient_subtract",
+            FluidPassKind::AdvectVelocity | FluidPassKind::AdvectDye => "advect",
+            FluidPassKind::Display => "display",
+        }
+    }
+
+    fn fragment_shader(&self) -> &'static str {
+        match self {
+            FluidPassKind::SplatVelocity | FluidPassKind::SplatDye => FLUID_SPLAT_FRAGMENT_SHADER,
+            FluidPassKind::Curl => FLUID_CURL_FRAGMENT_SHADER,
+            FluidPassKind::Vorticity => FLUID_VORTICITY_FRAGMENT_SHADER,
+            FluidPassKind::Divergence => FLUID_DIVERGENCE_FRAGMENT_SHADER,
+            FluidPassKind::Pressure { .. } => FLUID_PRESSURE_FRAGMENT_SHADER,
+            FluidPassKind::GradientSubtract => FLUID_GRADIENT_SUBTRACT_FRAGMENT_SHADER,
+            FluidPassKind::AdvectVelocity | FluidPassKind::AdvectDye => {
+                FLUID_ADVECT_FRAGMENT_SHADER
+            }
+            FluidPassKind::Display => FLUID_DISPLAY_FRAGMENT_SHADER,
+        }
+    }
+
+    fn set_uniforms(
+        &self,
+        gl: &WebGl2RenderingContext,
+        program: &WebGlProgram,
+        state: &FluidState,
+        texel: [f32; 2],
+        aspect: f32,
+    ) {
+        let location = |name| gl.get_uniform_location(program, name);
+        let settings = &state.settings;
+        gl.uniform2fv_with_f32_array(location("u_texel").as_ref(), &texel);
+        gl.uniform1f(location("u_dt").as_ref(), state.dt);
+
+        match self {
+            FluidPassKind::SplatVelocity | FluidPassKind::SplatDye => {
+                let mut positions = Vec::with_capacity(state.impulses.len() * 2);
+                let mut values = Vec::with_capacity(state.impulses.len() * 3);
+                for impulse in &state.impulses {
+                    positions.extend(impulse.position());
+                    values.extend(match self {
+                        FluidPassKind::SplatVelocity => {
+                            let [x, y] = impulse.velocity();
+                            [x, y, 0.0]
+                        }
+                        _ => impulse.color(),
+                    });
+                }
+                gl.uniform1f(location("u_aspect").as_ref(), aspect);
+                gl.uniform1f(location("u_radius").as_ref(), settings.splat_radius());
+                gl.uniform1i(
+                    location("u_impulse_count").as_ref(),
+                    state.impulses.len() as i32,
+                );
+                if !state.impulses.is_empty() {
+                    gl.uniform2fv_with_f32_array(
+                        location("u_impulse_positions").as_ref(),
+                        &positions,
+                    );
+                    gl.uniform3fv_with_f32_array(location("u_impulse_values").as_ref(), &values);
+                }
+            }
+            FluidPassKind::Vorticity => {
+                gl.uniform1f(
+                    location("u_curl_strength").as_ref(),
+                    settings.curl_strength(),
+                );
+            }
+            FluidPassKind::Pressure { first_iteration } => {
+                let scale = if *first_iteration {
+                    settings.pressure_decay()
+                } else {
+                    1.0
+                };
+                gl.uniform1f(location("u_pressure_scale").as_ref(), scale);
+            }
+            FluidPassKind::AdvectVelocity => {
+                gl.uniform1f(
+                    location("u_dissipation").as_ref(),
+                    settings.velocity_dissipation(),
+                );
+            }
+            FluidPassKind::AdvectDye => {
+                gl.uniform1f(
+                    location("u_dissipation").as_ref(),
+                    settings.dye_dissipation(),
+                );
+            }
+            FluidPassKind::Curl
+            | FluidPassKind::Divergence
+            | FluidPassKind::GradientSubtract
+            | FluidPassKind::Display => {}
+        }
+    }
+}
+
+/// A pass of the simulation: the textures it samples (as `(sampler name, texture name)`) and the texture it writes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FluidPassLayout {
+    name: String,
+    kind: FluidPassKind,
+    reads: Vec<(&'static str, &'static str)>,
+    writes: Option<&'static str>,
+}
+
+/// Every pass of the simulation, in the order they need to run
+pub(crate) fn fluid_pass_layout(
+    pressure_iterations: u32,
+    render_to_canvas: bool,
+) -> Vec<FluidPassLayout> {
+    let pass = |name: &str, kind, reads: &[(&'static str, &'static str)], writes| FluidPassLayout {
+        name: name.to_string(),
+        kind,
+        reads: reads.to_vec(),
+        writes,
+    };
+
+    let mut passes = vec![
+        pass(
+            "fluid_splat_velocity",
+            FluidPassKind::SplatVelocity,
+            &[("u_target", "velocity")],
+            Some("velocity"),
+        ),
+        pass(
+            "fluid_splat_dye",
+            FluidPassKind::SplatDye,
+            &[("u_target", "dye")],
+            Some("dye"),
+        ),
+        pass(
+            "fluid_curl",
+            FluidPassKind::Curl,
+            &[("u_velocity", "velocity")],
+            Some("curl"),
+        ),
+        pass(
+            "fluid_vorticity",
+            FluidPassKind::Vorticity,
+            &[("u_velocity", "velocity"), ("u_curl", "curl")],
+            Some("velocity"),
+        ),
+        pass(
+            "fluid_divergence",
+            FluidPassKind::Divergence,
+            &[("u_velocity", "velocity")],
+            Some("divergence"),
+        ),
+    ];
+    for iteration in 0..pressure_iterations.max(1) {
+        passes.push(pass(
+            &format!("fluid_pressure_{iteration}"),
+            FluidPassKind::Pressure {
+                first_iteration: iteration == 0,
+            },
+            &[("u_pressure", "pressure"), ("u_divergence", "divergence")],
+            Some("pressure"),
+        ));
+    }
+    passes.extend([
+        pass(
+            "fluid_gradient_subtract",
+            FluidPassKind::GradientSubtract,
+            &[("u_pressure", "pressure"), ("u_velocity", "velocity")],
+            Some("velocity"),
+        ),
+        pass(
+            "fluid_advect_velocity",
+            FluidPassKind::AdvectVelocity,
+            &[("u_velocity", "velocity"), ("u_source", "velocity")],
+            Some("velocity"),
+        ),
+        pass(
+            "fluid_advect_dye",
+            FluidPassKind::AdvectDye,
+            &[("u_velocity", "velocity"), ("u_source", "dye")],
+            Some("dye"),
+        ),
+    ]);
+    if render_to_canvas {
+        passes.push(pass(
+            "fluid_display",
+            FluidPassKind::Display,
+            &[("u_dye", "dye")],
+            None,
+        ));
+    }
+    passes
+}
+
+fn compile_program(
+    gl: &WebGl2RenderingContext,
+    kind: FluidPassKind,
+) -> Result<WebGlProgram, FluidSimulationError> {
+    let pass = kind.program_name();
+    create_program(
+        gl,
+        COMPOSITING_VERTEX_SHADER,
+        kind.fragment_shader(),
+        &[],
+        |error| FluidSimulationError::CompileShader {
+            pass: pass.to_string(),
+            error,
+        },
+        |error| FluidSimulationError::LinkProgram {
+            pass: pass.to_string(),
+            error,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fluid_pass_layout;
+    use crate::{pass_order, RenderPass, RenderPassContext};
+
+    #[test]
+    fn graph_runs_passes_in_layout_order() {
+        let layout = fluid_pass_layout(4, true);
+        assert_eq!(layout.len(), 13);
+
+        let passes: Vec<RenderPass> = layout
+            .iter()
+            .map(|pass| {
+                let mut render_pass =
+                    RenderPass::new(pass.name.clone(), |_: &RenderPassContext| {});
+                for (_, texture_name) in &pass.reads {
+                    render_pass.add_read(*texture_name);
+                }
+                render_pass.set_writes(pass.writes);
+                render_pass
+            })
+            .collect();
+        let order: Vec<usize> = (0..passes.len()).collect();
+        assert_eq!(pass_order(&passes), order);
+    }
+}
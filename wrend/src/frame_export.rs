@@ -0,0 +1,25 @@
+#[cfg(feature = "apng")]
+mod apng_encoder;
+#[cfg(feature = "apng")]
+mod apng_options;
+mod captured_frame;
+mod frame_blob;
+mod frame_capture_options;
+mod frame_checksum;
+#[cfg(feature = "gif")]
+mod gif_encoder;
+#[cfg(feature = "gif")]
+mod gif_options;
+
+#[cfg(feature = "apng")]
+pub use apng_encoder::*;
+#[cfg(feature = "apng")]
+pub use apng_options::*;
+pub use captured_frame::*;
+pub use frame_blob::*;
+pub use frame_capture_options::*;
+pub use frame_checksum::*;
+#[cfg(feature = "gif")]
+pub use gif_encoder::*;
+#[cfg(feature = "gif")]
+pub use gif_options::*;
@@ -0,0 +1,73 @@
+use crate::{frame_durations_ms, ApngCompression, ApngOptions, CapturedFrame, FrameExportError};
+use png::{BitDepth, ColorType, Compression, Encoder};
+
+/// Encodes captured frames as an animated PNG, with each frame shown until the next one's timestamp.
+///
+/// Unlike GIF, APNG keeps full 8-bit RGBA color, so gradients and soft edges don't band.
+/// All frames must be the same size.
+pub fn encode_apng(
+    frames: &[CapturedFrame],
+    options: &ApngOptions,
+) -> Result<Vec<u8>, FrameExportError> {
+    let first = frames.first().ok_or(FrameExportError::NoFrames)?;
+    let (width, height) = (first.width(), first.height());
+    let encode_error = |err: png::EncodingError| FrameExportError::EncodingFailed(err.to_string());
+
+    let mut apng = Vec::new();
+    let mut encoder = Encoder::new(&mut apng, width, height);
+    encoder.set_color(ColorType::Rgba);
+    encoder.set_depth(BitDepth::Eight);
+    encoder.set_compression(match options.compression() {
+        ApngCompression::Fast => Compression::Fast,
+        ApngCompression::Default => Compression::Default,
+        ApngCompression::Best => Compression::Best,
+    });
+    // 0 plays means loop forever
+    let num_plays = if options.repeat() { 0 } else { 1 };
+    encoder
+        .set_animated(frames.len() as u32, num_plays)
+        .map_err(encode_error)?;
+
+    let mut writer = encoder.write_header().map_err(encode_error)?;
+    let durations_ms = frame_durations_ms(frames, 100.0);
+    for (captured_frame, duration_ms) in frames.iter().zip(durations_ms) {
+        if (captured_frame.width(), captured_frame.height()) != (width, height) {
+            return Err(FrameExportError::FrameSizeMismatch {
+                expected: width as usize * height as usize * 4,
+                actual: captured_frame.pixels().len(),
+            });
+        }
+
+        let delay_ms = duration_ms.round().clamp(1.0, u16::MAX as f64) as u16;
+        writer
+            .set_frame_delay(delay_ms, 1000)
+            .map_err(encode_error)?;
+        writer
+            .write_image_data(captured_frame.pixels())
+            .map_err(encode_error)?;
+    }
+    writer.finish().map_err(encode_error)?;
+
+    Ok(apng)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::encode_apng;
+    use crate::{ApngOptions, CapturedFrame};
+
+    #[test]
+    fn encodes_animated_png() {
+        let frames: Vec<_> = [[255, 0, 0, 255], [0, 0, 255, 128]]
+            .iter()
+            .enumerate()
+            .map(|(i, color)| CapturedFrame::new(2, 2, i as f64 * 50.0, color.repeat(4)).unwrap())
+            .collect();
+
+        let apng = encode_apng(&frames, &ApngOptions::default()).unwrap();
+        assert_eq!(&apng[..8], b"\x89PNG\r\n\x1a\n");
+        assert!(apng.windows(4).any(|chunk| chunk == b"acTL"));
+        assert!(apng.windows(4).any(|chunk| chunk == b"fdAT"));
+        assert!(encode_apng(&[], &ApngOptions::default()).is_err());
+    }
+}
@@ -0,0 +1,50 @@
+/// How hard the APNG encoder tries to shrink each frame. APNG is lossless, so this only trades
+/// encoding time against file size.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum ApngCompression {
+    Fast,
+    #[default]
+    Default,
+    Best,
+}
+
+/// Settings for [crate::encode_apng]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ApngOptions {
+    repeat: bool,
+    compression: ApngCompression,
+}
+
+impl ApngOptions {
+    pub fn new() -> Self {
+        Self {
+            repeat: true,
+            compression: ApngCompression::Default,
+        }
+    }
+
+    /// Whether the animation loops forever (the default) or plays once
+    pub fn repeat(&self) -> bool {
+        self.repeat
+    }
+
+    pub fn set_repeat(&mut self, repeat: bool) -> &mut Self {
+        self.repeat = repeat;
+        self
+    }
+
+    pub fn compression(&self) -> ApngCompression {
+        self.compression
+    }
+
+    pub fn set_compression(&mut self, compression: ApngCompression) -> &mut Self {
+        self.compression = compression;
+        self
+    }
+}
+
+impl Default for ApngOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
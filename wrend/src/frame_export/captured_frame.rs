@@ -0,0 +1,136 @@
+use crate::FrameExportError;
+use web_sys::WebGl2RenderingContext;
+
+/// One frame read back from the drawing buffer, as tightly packed RGBA8 pixels with the
+/// top row first (the order image formats expect, and the reverse of WebGL's).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CapturedFrame {
+    width: u32,
+    height: u32,
+    time_ms: f64,
+    pixels: Vec<u8>,
+}
+
+impl CapturedFrame {
+    /// `pixels` must be top-row-first RGBA8 data of size `width * height * 4`
+    pub fn new(
+        width: u32,
+        height: u32,
+        time_ms: f64,
+        pixels: Vec<u8>,
+    ) -> Result<Self, FrameExportError> {
+        let expected = width as usize * height as usize * 4;
+        if pixels.len() != expected {
+            return Err(FrameExportError::FrameSizeMismatch {
+                expected,
+                actual: pixels.len(),
+            });
+        }
+
+        Ok(Self {
+            width,
+            height,
+            time_ms,
+            pixels,
+        })
+    }
+
+    /// Reads the whole drawing buffer (call this in the same task as the render, before the browser
+    /// composites the canvas, unless the context was created with `preserveDrawingBuffer`)
+    pub fn read_drawing_buffer(
+        gl: &WebGl2RenderingContext,
+        time_ms: f64,
+    ) -> Result<Self, FrameExportError> {
+        let width = gl.drawing_buffer_width().max(0) as u32;
+        let height = gl.drawing_buffer_height().max(0) as u32;
+        let mut pixels = vec![0; width as usize * height as usize * 4];
+
+        gl.bind_framebuffer(WebGl2RenderingContext::READ_FRAMEBUFFER, None);
+        gl.read_pixels_with_opt_u8_array(
+            0,
+            0,
+            width as i32,
+            height as i32,
+            WebGl2RenderingContext::RGBA,
+            WebGl2RenderingContext::UNSIGNED_BYTE,
+            Some(&mut pixels),
+        )
+        .map_err(|err| FrameExportError::ReadPixelsFailed(format!("{err:?}")))?;
+
+        flip_rows(&mut pixels, width as usize * 4);
+        Self::new(width, height, time_ms, pixels)
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The (possibly simulated) time at which this frame was rendered
+    pub fn time_ms(&self) -> f64 {
+        self.time_ms
+    }
+
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    pub fn into_pixels(self) -> Vec<u8> {
+        self.pixels
+    }
+}
+
+/// How long each frame should be shown for, based on the time between consecutive frames.
+/// The last frame is shown as long as the one before it (or `fallback_ms` if there is only one).
+pub fn frame_durations_ms(frames: &[CapturedFrame], fallback_ms: f64) -> Vec<f64> {
+    let mut durations: Vec<f64> = frames
+        .windows(2)
+        .map(|pair| (pair[1].time_ms - pair[0].time_ms).max(0.0))
+        .collect();
+    if !frames.is_empty() {
+        durations.push(durations.last().copied().unwrap_or(fallback_ms));
+    }
+    durations
+}
+
+/// Reverses the order of rows in place
+pub(crate) fn flip_rows(pixels: &mut [u8], row_len: usize) {
+    if row_len == 0 {
+        return;
+    }
+    let rows = pixels.len() / row_len;
+    for row in 0..rows / 2 {
+        let (top, bottom) = pixels.split_at_mut((rows - row - 1) * row_len);
+        top[row * row_len..(row + 1) * row_len].swap_with_slice(&mut bottom[..row_len]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{flip_rows, frame_durations_ms, CapturedFrame};
+
+    #[test]
+    fn flips_rows() {
+        let mut pixels = vec![1, 1, 2, 2, 3, 3];
+        flip_rows(&mut pixels, 2);
+        assert_eq!(pixels, vec![3, 3, 2, 2, 1, 1]);
+
+        let mut pixels = vec![1, 2, 3, 4];
+        flip_rows(&mut pixels, 2);
+        assert_eq!(pixels, vec![3, 4, 1, 2]);
+    }
+
+    #[test]
+    fn derives_durations_from_timestamps() {
+        let frame = |time_ms| CapturedFrame::new(1, 1, time_ms, vec![0; 4]).unwrap();
+        assert_eq!(
+            frame_durations_ms(&[frame(0.0), frame(40.0), frame(100.0)], 10.0),
+            vec![40.0, 60.0, 60.0]
+        );
+        assert_eq!(frame_durations_ms(&[frame(5.0)], 10.0), vec![10.0]);
+        assert!(CapturedFrame::new(2, 2, 0.0, vec![0; 4]).is_err());
+    }
+}
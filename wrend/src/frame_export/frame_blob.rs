@@ -0,0 +1,13 @@
+use crate::FrameExportError;
+use js_sys::{Array, Uint8Array};
+use web_sys::{Blob, BlobPropertyBag};
+
+/// Wraps encoded bytes in a Blob with the given MIME type, e.g. for downloading or uploading
+pub fn bytes_to_blob(bytes: &[u8], mime_type: &str) -> Result<Blob, FrameExportError> {
+    let parts = Array::of1(&Uint8Array::from(bytes));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+
+    Blob::new_with_u8_array_sequence_and_options(&parts, &options)
+        .map_err(|err| FrameExportError::BlobCreationFailed(format!("{err:?}")))
+}
@@ -0,0 +1,99 @@
+use crate::AnimationLoop;
+
+/// Which frames to capture for an exported animation: `frame_count` frames at a fixed timestep
+/// of `1000 / fps` milliseconds, starting at `start_ms`.
+///
+/// Frames are rendered with a simulated clock, so the result doesn't depend on how long rendering
+/// actually takes, and uniforms see exactly these timestamps.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct FrameCaptureOptions {
+    frame_count: u32,
+    fps: f64,
+    start_ms: f64,
+}
+
+impl FrameCaptureOptions {
+    pub fn new(frame_count: u32, fps: f64) -> Self {
+        Self {
+            frame_count,
+            fps: fps.max(f64::EPSILON),
+            start_ms: 0.0,
+        }
+    }
+
+    /// Exactly one pass through `animation_loop`, starting at its beginning, at roughly `fps` frames per second.
+    ///
+    /// The frame rate is adjusted so that a whole number of frames fills the loop, so exported
+    /// GIFs and APNGs repeat without a seam.
+    pub fn for_loop(animation_loop: &AnimationLoop, fps: f64) -> Self {
+        let duration_ms = animation_loop.duration_ms();
+        let frame_count = (duration_ms * fps / 1000.0).round().max(1.0);
+        Self {
+            frame_count: frame_count as u32,
+            fps: frame_count * 1000.0 / duration_ms,
+            start_ms: animation_loop.start_ms(),
+        }
+    }
+
+    pub fn frame_count(&self) -> u32 {
+        self.frame_count
+    }
+
+    pub fn fps(&self) -> f64 {
+        self.fps
+    }
+
+    pub fn start_ms(&self) -> f64 {
+        self.start_ms
+    }
+
+    pub fn set_start_ms(&mut self, start_ms: f64) -> &mut Self {
+        self.start_ms = start_ms;
+        self
+    }
+
+    pub fn frame_duration_ms(&self) -> f64 {
+        1000.0 / self.fps
+    }
+
+    /// Timestamp of every frame to capture
+    pub fn frame_times(&self) -> impl Iterator<Item = f64> {
+        let Self { start_ms, .. } = *self;
+        let frame_duration_ms = self.frame_duration_ms();
+        (0..self.frame_count).map(move |frame| start_ms + frame as f64 * frame_duration_ms)
+    }
+}
+
+impl Default for FrameCaptureOptions {
+    /// Two seconds at 30fps
+    fn default() -> Self {
+        Self::new(60, 30.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FrameCaptureOptions;
+    use crate::AnimationLoop;
+
+    #[test]
+    fn steps_at_fixed_intervals() {
+        let mut options = FrameCaptureOptions::new(3, 20.0);
+        options.set_start_ms(100.0);
+        assert_eq!(
+            options.frame_times().collect::<Vec<_>>(),
+            vec![100.0, 150.0, 200.0]
+        );
+    }
+
+    #[test]
+    fn captures_exactly_one_loop() {
+        let options = FrameCaptureOptions::for_loop(&AnimationLoop::new(1000.0, 250.0), 24.0);
+        assert_eq!(options.frame_count(), 24);
+        assert_eq!(options.start_ms(), 250.0);
+
+        let options = FrameCaptureOptions::for_loop(&AnimationLoop::new(100.0, 0.0), 24.0);
+        assert_eq!(options.frame_count(), 2);
+        assert_eq!(options.frame_times().collect::<Vec<_>>(), vec![0.0, 50.0]);
+    }
+}
@@ -0,0 +1,85 @@
+use std::fmt::Display;
+
+/// A cheap fingerprint of a rendered frame, for noticing when output changes unexpectedly at
+/// runtime (e.g. between versions of an app, or between browsers).
+///
+/// The frame is first downsampled to [FrameChecksum::SIZE] x [FrameChecksum::SIZE] pixels on the
+/// GPU, and each channel is quantized to [FrameChecksum::QUANTIZE_BITS] bits before hashing, so
+/// that tiny rasterization differences between GPUs are less likely to change the checksum.
+/// Compare [FrameChecksum::average_color] as well to tell how much a frame changed.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct FrameChecksum {
+    hash: u64,
+    average_color: [f32; 4],
+}
+
+impl FrameChecksum {
+    /// Width and height of the downsampled frame that is hashed
+    pub const SIZE: i32 = 32;
+
+    /// Bits kept from each 8-bit channel before hashing
+    pub const QUANTIZE_BITS: u32 = 6;
+
+    /// Hashes RGBA8 pixels with 64-bit FNV-1a, after quantizing each channel
+    pub fn from_pixels(pixels: &[u8]) -> Self {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+        let shift = 8 - Self::QUANTIZE_BITS;
+
+        let mut hash = OFFSET_BASIS;
+        let mut sums = [0u64; 4];
+        for (i, value) in pixels.iter().enumerate() {
+            hash ^= u64::from(value >> shift);
+            hash = hash.wrapping_mul(PRIME);
+            sums[i % 4] += u64::from(*value);
+        }
+
+        let pixel_count = (pixels.len() / 4).max(1) as f32;
+        let average_color = sums.map(|sum| sum as f32 / pixel_count / 255.0);
+        Self {
+            hash,
+            average_color,
+        }
+    }
+
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Mean RGBA of the frame, from 0.0 to 1.0
+    pub fn average_color(&self) -> [f32; 4] {
+        self.average_color
+    }
+
+    /// Largest difference in any channel of the average color, from 0.0 (identical) to 1.0
+    pub fn average_difference(&self, other: &FrameChecksum) -> f32 {
+        self.average_color
+            .iter()
+            .zip(other.average_color)
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, f32::max)
+    }
+}
+
+impl Display for FrameChecksum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:016x}", self.hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FrameChecksum;
+
+    #[test]
+    fn ignores_small_differences() {
+        let a = FrameChecksum::from_pixels(&[0, 128, 255, 255, 0, 0, 0, 255]);
+        let b = FrameChecksum::from_pixels(&[1, 129, 254, 255, 0, 0, 0, 255]);
+        let c = FrameChecksum::from_pixels(&[0, 0, 255, 255, 0, 0, 0, 255]);
+        assert_eq!(a.hash(), b.hash());
+        assert_ne!(a.hash(), c.hash());
+        assert_eq!(a.average_color(), [0.0, 64.0 / 255.0, 0.5, 1.0]);
+        assert_eq!(a.to_string().len(), 16);
+        assert!((a.average_difference(&c) - 64.0 / 255.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{frame_durations_ms, CapturedFrame, FrameExportError, GifOptions};
+use gif::{Encoder, Frame, Repeat};
+
+/// Encodes captured frames as an animated GIF, with each frame shown until the next one's timestamp.
+///
+/// All frames must be the same size. GIFs have a 256-color palette per frame, so gradients will band.
+pub fn encode_gif(
+    frames: &[CapturedFrame],
+    options: &GifOptions,
+) -> Result<Vec<u8>, FrameExportError> {
+    let first = frames.first().ok_or(FrameExportError::NoFrames)?;
+    let (width, height) = (first.width(), first.height());
+    let too_large = |_| FrameExportError::FrameTooLarge { width, height };
+    let gif_width = u16::try_from(width).map_err(too_large)?;
+    let gif_height = u16::try_from(height).map_err(too_large)?;
+
+    let encode_error = |err: gif::EncodingError| FrameExportError::EncodingFailed(err.to_string());
+    let mut encoder = Encoder::new(Vec::new(), gif_width, gif_height, &[]).map_err(encode_error)?;
+    if options.repeat() {
+        encoder.set_repeat(Repeat::Infinite).map_err(encode_error)?;
+    }
+
+    let durations_ms = frame_durations_ms(frames, 100.0);
+    for (captured_frame, duration_ms) in frames.iter().zip(durations_ms) {
+        if (captured_frame.width(), captured_frame.height()) != (width, height) {
+            return Err(FrameExportError::FrameSizeMismatch {
+                expected: width as usize * height as usize * 4,
+                actual: captured_frame.pixels().len(),
+            });
+        }
+
+        let mut pixels = captured_frame.pixels().to_vec();
+        let mut frame = Frame::from_rgba_speed(gif_width, gif_height, &mut pixels, options.speed());
+        // GIF delays are in hundredths of a second
+        frame.delay = (duration_ms / 10.0).round().clamp(1.0, u16::MAX as f64) as u16;
+        encoder.write_frame(&frame).map_err(encode_error)?;
+    }
+
+    encoder
+        .into_inner()
+        .map_err(|err| FrameExportError::EncodingFailed(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::encode_gif;
+    use crate::{CapturedFrame, GifOptions};
+
+    #[test]
+    fn encodes_animated_gif() {
+        let frames: Vec<_> = [[255, 0, 0, 255], [0, 0, 255, 255]]
+            .iter()
+            .enumerate()
+            .map(|(i, color)| CapturedFrame::new(2, 2, i as f64 * 50.0, color.repeat(4)).unwrap())
+            .collect();
+
+        let gif = encode_gif(&frames, &GifOptions::default()).unwrap();
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert_eq!(*gif.last().unwrap(), 0x3B);
+        assert!(encode_gif(&[], &GifOptions::default()).is_err());
+    }
+}
@@ -0,0 +1,41 @@
+/// Settings for [crate::encode_gif]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GifOptions {
+    repeat: bool,
+    speed: i32,
+}
+
+impl GifOptions {
+    pub fn new() -> Self {
+        Self {
+            repeat: true,
+            speed: 10,
+        }
+    }
+
+    /// Whether the GIF loops forever (the default) or plays once
+    pub fn repeat(&self) -> bool {
+        self.repeat
+    }
+
+    pub fn set_repeat(&mut self, repeat: bool) -> &mut Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Palette quantization speed from 1 (best quality, slowest) to 30 (fastest). Defaults to 10
+    pub fn speed(&self) -> i32 {
+        self.speed
+    }
+
+    pub fn set_speed(&mut self, speed: i32) -> &mut Self {
+        self.speed = speed.clamp(1, 30);
+        self
+    }
+}
+
+impl Default for GifOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,17 +1,25 @@
 mod framebuffer;
+mod framebuffer_attachment;
 mod framebuffer_create_callback;
 mod framebuffer_create_callback_js;
 mod framebuffer_create_context;
 mod framebuffer_create_context_js;
+#[cfg(feature = "js")]
 mod framebuffer_js;
 mod framebuffer_link;
+#[cfg(feature = "js")]
 mod framebuffer_link_js;
+mod invalidate_framebuffer;
 
 pub use framebuffer::*;
+pub use framebuffer_attachment::*;
 pub use framebuffer_create_callback::*;
 pub use framebuffer_create_callback_js::*;
 pub use framebuffer_create_context::*;
 pub use framebuffer_create_context_js::*;
+#[cfg(feature = "js")]
 pub use framebuffer_js::*;
 pub use framebuffer_link::*;
+#[cfg(feature = "js")]
 pub use framebuffer_link_js::*;
+pub(crate) use invalidate_framebuffer::*;
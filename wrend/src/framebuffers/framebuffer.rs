@@ -1,6 +1,9 @@
-use crate::{FramebufferJs, FramebufferJsInner, Id};
+use crate::Id;
+#[cfg(feature = "js")]
+use crate::{FramebufferJs, FramebufferJsInner};
 use std::fmt::Debug;
 use std::hash::Hash;
+#[cfg(feature = "js")]
 use wasm_bindgen::JsValue;
 use web_sys::WebGlFramebuffer;
 
@@ -51,6 +54,7 @@ impl<FramebufferId: Id> PartialEq for Framebuffer<FramebufferId> {
 
 impl<FramebufferId: Id> Eq for Framebuffer<FramebufferId> {}
 
+#[cfg(feature = "js")]
 impl From<FramebufferJsInner> for JsValue {
     fn from(framebuffer: FramebufferJsInner) -> Self {
         let js_framebuffer: FramebufferJs = framebuffer.into();
@@ -0,0 +1,77 @@
+use web_sys::{WebGl2RenderingContext, WebGlTexture};
+
+/// Which part of a [crate::FramebufferLink]'s texture a framebuffer renders into:
+/// a mip level and, for 3D and array textures, a layer.
+///
+/// When a link has an attachment, its texture is (re)attached to `COLOR_ATTACHMENT0` after the
+/// create callback runs, so the callback only needs to create the framebuffer.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub struct FramebufferAttachment {
+    mip_level: i32,
+    layer: Option<i32>,
+}
+
+impl FramebufferAttachment {
+    /// Attaches mip level 0 of a 2D texture
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_mip_level(mut self, mip_level: i32) -> Self {
+        self.mip_level = mip_level;
+        self
+    }
+
+    /// Attaches a single layer of a 3D or 2D array texture
+    pub fn with_layer(mut self, layer: i32) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    pub fn mip_level(&self) -> i32 {
+        self.mip_level
+    }
+
+    pub fn layer(&self) -> Option<i32> {
+        self.layer
+    }
+
+    /// Size of the attached mip level, given the size of the texture's base level
+    pub fn size(&self, width: u32, height: u32) -> (u32, u32) {
+        let shift = self.mip_level.clamp(0, 31) as u32;
+        ((width >> shift).max(1), (height >> shift).max(1))
+    }
+
+    /// Attaches the texture to `COLOR_ATTACHMENT0` of the currently bound framebuffer
+    pub fn attach(&self, gl: &WebGl2RenderingContext, texture: &WebGlTexture) {
+        match self.layer {
+            Some(layer) => gl.framebuffer_texture_layer(
+                WebGl2RenderingContext::FRAMEBUFFER,
+                WebGl2RenderingContext::COLOR_ATTACHMENT0,
+                Some(texture),
+                self.mip_level,
+                layer,
+            ),
+            None => gl.framebuffer_texture_2d(
+                WebGl2RenderingContext::FRAMEBUFFER,
+                WebGl2RenderingContext::COLOR_ATTACHMENT0,
+                WebGl2RenderingContext::TEXTURE_2D,
+                Some(texture),
+                self.mip_level,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FramebufferAttachment;
+
+    #[test]
+    fn size_halves_per_mip_level_down_to_one() {
+        let attachment = FramebufferAttachment::new().with_mip_level(3);
+        assert_eq!(attachment.size(800, 600), (100, 75));
+        assert_eq!(attachment.with_mip_level(10).size(800, 600), (1, 1));
+        assert_eq!(FramebufferAttachment::new().size(800, 600), (800, 600));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::FramebufferAttachment;
 use web_sys::{WebGl2RenderingContext, WebGlTexture};
 
 /// Context used when creating a Framebuffer object--passed into the callback as the first argument
@@ -8,6 +9,7 @@ pub struct FramebufferCreateContext {
     /// This is the texture that was specified in the link
     /// and which will be associated with the Framebuffer
     webgl_texture: Option<WebGlTexture>,
+    attachment: Option<FramebufferAttachment>,
 }
 
 impl FramebufferCreateContext {
@@ -17,9 +19,15 @@ impl FramebufferCreateContext {
             gl,
             now,
             webgl_texture,
+            attachment: None,
         }
     }
 
+    pub(crate) fn with_attachment(mut self, attachment: Option<FramebufferAttachment>) -> Self {
+        self.attachment = attachment;
+        self
+    }
+
     pub fn gl(&self) -> &WebGl2RenderingContext {
         &self.gl
     }
@@ -31,4 +39,10 @@ impl FramebufferCreateContext {
     pub fn webgl_texture(&self) -> &Option<WebGlTexture> {
         &self.webgl_texture
     }
+
+    /// Mip level and layer of the texture that the link renders into, if it specified one.
+    /// The texture is attached there automatically after the create callback returns.
+    pub fn attachment(&self) -> Option<FramebufferAttachment> {
+        self.attachment
+    }
 }
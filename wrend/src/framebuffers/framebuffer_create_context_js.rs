@@ -27,6 +27,20 @@ impl FramebufferCreateContextJs {
     pub fn webgl_texture(&self) -> Option<WebGlTexture> {
         self.deref().webgl_texture().to_owned()
     }
+
+    #[wasm_bindgen(js_name = mipLevel)]
+    pub fn mip_level(&self) -> i32 {
+        self.deref()
+            .attachment()
+            .map(|attachment| attachment.mip_level())
+            .unwrap_or_default()
+    }
+
+    pub fn layer(&self) -> Option<i32> {
+        self.deref()
+            .attachment()
+            .and_then(|attachment| attachment.layer())
+    }
 }
 
 impl FramebufferCreateContextJs {
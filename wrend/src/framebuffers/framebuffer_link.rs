@@ -1,4 +1,6 @@
-use crate::{FramebufferCreateCallback, FramebufferCreateContext, Id, IdDefault};
+use crate::{
+    FramebufferAttachment, FramebufferCreateCallback, FramebufferCreateContext, Id, IdDefault,
+};
 use std::fmt::Debug;
 use std::hash::Hash;
 use web_sys::{WebGl2RenderingContext, WebGlFramebuffer, WebGlTexture};
@@ -8,6 +10,7 @@ pub struct FramebufferLink<FramebufferId: Id, TextureId: Id = IdDefault> {
     framebuffer_id: FramebufferId,
     texture_id: Option<TextureId>,
     framebuffer_create_callback: FramebufferCreateCallback,
+    attachment: Option<FramebufferAttachment>,
 }
 
 impl<FramebufferId: Id, TextureId: Id> FramebufferLink<FramebufferId, TextureId> {
@@ -20,6 +23,7 @@ impl<FramebufferId: Id, TextureId: Id> FramebufferLink<FramebufferId, TextureId>
             framebuffer_id,
             framebuffer_create_callback: framebuffer_create_callback.into(),
             texture_id,
+            attachment: None,
         }
     }
 
@@ -31,15 +35,61 @@ impl<FramebufferId: Id, TextureId: Id> FramebufferLink<FramebufferId, TextureId>
         self.texture_id.clone()
     }
 
+    /// See [FramebufferAttachment]
+    pub fn attachment(&self) -> Option<FramebufferAttachment> {
+        self.attachment
+    }
+
+    /// Renders into a specific mip level (and layer) of the texture, see [FramebufferAttachment]
+    pub fn set_attachment(&mut self, attachment: FramebufferAttachment) -> &mut Self {
+        self.attachment = Some(attachment);
+        self
+    }
+
     pub fn create_framebuffer(
         &self,
         gl: WebGl2RenderingContext,
         now: f64,
         texture: Option<WebGlTexture>,
     ) -> WebGlFramebuffer {
-        let framebuffer_create_context = FramebufferCreateContext::new(gl, now, texture);
-        self.framebuffer_create_callback
-            .call_with_into_js_arg_and_return(&framebuffer_create_context)
+        let framebuffer_create_context =
+            FramebufferCreateContext::new(gl.clone(), now, texture.clone())
+                .with_attachment(self.attachment);
+        let webgl_framebuffer = self
+            .framebuffer_create_callback
+            .call_with_into_js_arg_and_return(&framebuffer_create_context);
+
+        if let (Some(attachment), Some(texture)) = (self.attachment, texture) {
+            gl.bind_framebuffer(
+                WebGl2RenderingContext::FRAMEBUFFER,
+                Some(&webgl_framebuffer),
+            );
+            attachment.attach(&gl, &texture);
+            gl.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, None);
+        }
+
+        webgl_framebuffer
+    }
+
+    /// Whether `other` is this same link (or a clone of it), as opposed to a link that merely shares its id
+    pub(crate) fn is_identical(&self, other: &Self) -> bool {
+        self == other
+            && self.texture_id == other.texture_id
+            && self.attachment == other.attachment
+            && self.framebuffer_create_callback == other.framebuffer_create_callback
+    }
+
+    /// The same link with its ids replaced (used when scoping links to a namespace)
+    pub(crate) fn map_ids(
+        self,
+        framebuffer_id: impl Fn(&FramebufferId) -> FramebufferId,
+        texture_id: impl Fn(&TextureId) -> TextureId,
+    ) -> Self {
+        Self {
+            framebuffer_id: framebuffer_id(&self.framebuffer_id),
+            texture_id: self.texture_id.as_ref().map(texture_id),
+            ..self
+        }
     }
 }
 
@@ -47,6 +97,7 @@ impl<FramebufferId: Id, TextureId: Id> Debug for FramebufferLink<FramebufferId,
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("FramebufferLink")
             .field("framebuffer_id", &self.framebuffer_id)
+            .field("attachment", &self.attachment)
             .finish()
     }
 }
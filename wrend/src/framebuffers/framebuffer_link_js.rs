@@ -3,7 +3,7 @@ use std::ops::{Deref, DerefMut};
 use wasm_bindgen::prelude::wasm_bindgen;
 use web_sys::{WebGl2RenderingContext, WebGlFramebuffer, WebGlTexture};
 
-use crate::{FramebufferCreateCallbackJs, FramebufferLink};
+use crate::{FramebufferAttachment, FramebufferCreateCallbackJs, FramebufferLink};
 
 pub type FramebufferLinkJsInner = FramebufferLink<String, String>;
 
@@ -36,6 +36,30 @@ impl FramebufferLinkJs {
         self.deref().texture_id()
     }
 
+    /// Renders into a specific mip level and (for 3D and array textures) layer of the texture
+    #[wasm_bindgen(js_name = setAttachment)]
+    pub fn set_attachment(&mut self, mip_level: i32, layer: Option<i32>) {
+        let attachment = FramebufferAttachment::new().with_mip_level(mip_level);
+        self.deref_mut().set_attachment(match layer {
+            Some(layer) => attachment.with_layer(layer),
+            None => attachment,
+        });
+    }
+
+    #[wasm_bindgen(js_name = mipLevel)]
+    pub fn mip_level(&self) -> i32 {
+        self.deref()
+            .attachment()
+            .map(|attachment| attachment.mip_level())
+            .unwrap_or_default()
+    }
+
+    pub fn layer(&self) -> Option<i32> {
+        self.deref()
+            .attachment()
+            .and_then(|attachment| attachment.layer())
+    }
+
     #[wasm_bindgen(js_name = createFramebuffer)]
     pub fn create_framebuffer(
         &self,
@@ -0,0 +1,34 @@
+use web_sys::{WebGl2RenderingContext, WebGlFramebuffer};
+
+/// Tells the driver that the contents of some of a framebuffer's attachments (or the canvas's,
+/// if `None`) are no longer needed, so tiled GPUs can skip writing them back to memory.
+///
+/// The draw framebuffer binding is restored afterwards.
+pub(crate) fn invalidate_framebuffer(
+    gl: &WebGl2RenderingContext,
+    framebuffer: Option<&WebGlFramebuffer>,
+    attachments: &[u32],
+) {
+    if attachments.is_empty() {
+        return;
+    }
+
+    let previous = gl
+        .get_parameter(WebGl2RenderingContext::DRAW_FRAMEBUFFER_BINDING)
+        .ok()
+        .filter(|value| !value.is_null())
+        .map(wasm_bindgen::JsCast::unchecked_into::<WebGlFramebuffer>);
+    let attachments: js_sys::Array = attachments
+        .iter()
+        .copied()
+        .map(wasm_bindgen::JsValue::from)
+        .collect();
+
+    gl.bind_framebuffer(WebGl2RenderingContext::DRAW_FRAMEBUFFER, framebuffer);
+    if let Err(err) =
+        gl.invalidate_framebuffer(WebGl2RenderingContext::DRAW_FRAMEBUFFER, &attachments)
+    {
+        log::warn!("Could not invalidate framebuffer: {err:?}");
+    }
+    gl.bind_framebuffer(WebGl2RenderingContext::DRAW_FRAMEBUFFER, previous.as_ref());
+}
@@ -0,0 +1,11 @@
+mod cube;
+mod fullscreen_triangle;
+mod geometry_data;
+mod plane;
+mod uv_sphere;
+
+pub use cube::*;
+pub use fullscreen_triangle::*;
+pub use geometry_data::*;
+pub use plane::*;
+pub use uv_sphere::*;
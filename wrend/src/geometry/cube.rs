@@ -0,0 +1,51 @@
+use crate::GeometryData;
+
+/// A cube centered at the origin with sides of length `size`.
+///
+/// Each face has its own 4 vertices so that normals and UVs are flat per face.
+pub fn cube(size: f32) -> GeometryData {
+    let h = size / 2.0;
+    // (normal, tangent u axis, tangent v axis)
+    let faces: [([f32; 3], [f32; 3], [f32; 3]); 6] = [
+        ([1.0, 0.0, 0.0], [0.0, 0.0, -1.0], [0.0, 1.0, 0.0]),
+        ([-1.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 1.0, 0.0]),
+        ([0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, -1.0]),
+        ([0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]),
+        ([0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]),
+        ([0.0, 0.0, -1.0], [-1.0, 0.0, 0.0], [0.0, 1.0, 0.0]),
+    ];
+    let mut positions = Vec::with_capacity(6 * 4 * 3);
+    let mut normals = Vec::with_capacity(6 * 4 * 3);
+    let mut uvs = Vec::with_capacity(6 * 4 * 2);
+    let mut indices = Vec::with_capacity(6 * 6);
+
+    for (face, (normal, u_axis, v_axis)) in faces.iter().enumerate() {
+        for (u, v) in [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)] {
+            let su = (u * 2.0 - 1.0) * h;
+            let sv = (v * 2.0 - 1.0) * h;
+            for axis in 0..3 {
+                positions.push(normal[axis] * h + u_axis[axis] * su + v_axis[axis] * sv);
+            }
+            normals.extend_from_slice(normal);
+            uvs.extend_from_slice(&[u, v]);
+        }
+
+        let base = face as u32 * 4;
+        indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+    }
+
+    GeometryData::new(positions, normals, uvs, indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cube;
+
+    #[test]
+    fn builds_cube_faces() {
+        let cube = cube(2.0);
+        assert_eq!(cube.vertex_count(), 24);
+        assert_eq!(cube.index_count(), 36);
+        assert!(cube.positions().iter().all(|p| p.abs() == 1.0));
+    }
+}
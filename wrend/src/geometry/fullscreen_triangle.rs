@@ -0,0 +1,13 @@
+use crate::GeometryData;
+
+/// A single triangle that covers all of clip space when drawn without any transformation.
+///
+/// UVs are scaled so that the visible part of the triangle spans 0.0 to 1.0.
+pub fn fullscreen_triangle() -> GeometryData {
+    GeometryData::new(
+        vec![-1.0, -1.0, 0.0, 3.0, -1.0, 0.0, -1.0, 3.0, 0.0],
+        vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0],
+        vec![0.0, 0.0, 2.0, 0.0, 0.0, 2.0],
+        vec![0, 1, 2],
+    )
+}
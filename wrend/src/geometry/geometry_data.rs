@@ -0,0 +1,79 @@
+/// Vertex and index data produced by the geometry generators.
+///
+/// Attributes are stored in separate (non-interleaved) arrays so that each can be uploaded to
+/// its own buffer, or they can be combined with [GeometryData::interleaved]. Triangles are wound
+/// counter-clockwise when viewed from the outside.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub struct GeometryData {
+    positions: Vec<f32>,
+    normals: Vec<f32>,
+    uvs: Vec<f32>,
+    indices: Vec<u32>,
+}
+
+impl GeometryData {
+    /// Number of components in each position (`vec3`)
+    pub const POSITION_SIZE: i32 = 3;
+    /// Number of components in each normal (`vec3`)
+    pub const NORMAL_SIZE: i32 = 3;
+    /// Number of components in each texture coordinate (`vec2`)
+    pub const UV_SIZE: i32 = 2;
+    /// Number of floats per vertex in [GeometryData::interleaved]
+    pub const INTERLEAVED_SIZE: i32 = Self::POSITION_SIZE + Self::NORMAL_SIZE + Self::UV_SIZE;
+
+    /// Creates geometry from raw attribute data
+    pub fn new(positions: Vec<f32>, normals: Vec<f32>, uvs: Vec<f32>, indices: Vec<u32>) -> Self {
+        Self {
+            positions,
+            normals,
+            uvs,
+            indices,
+        }
+    }
+
+    /// Vertex positions (x, y, z)
+    pub fn positions(&self) -> &[f32] {
+        &self.positions
+    }
+
+    /// Unit-length vertex normals (x, y, z)
+    pub fn normals(&self) -> &[f32] {
+        &self.normals
+    }
+
+    /// Texture coordinates (u, v), from 0.0 to 1.0
+    pub fn uvs(&self) -> &[f32] {
+        &self.uvs
+    }
+
+    /// Triangle indices, for use with `drawElements` and `UNSIGNED_INT`
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+
+    /// Number of unique vertices
+    pub fn vertex_count(&self) -> usize {
+        self.positions.len() / Self::POSITION_SIZE as usize
+    }
+
+    /// Number of indices (3 per triangle)
+    pub fn index_count(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Vertex data laid out as `[position, normal, uv]` per vertex.
+    ///
+    /// The stride is `INTERLEAVED_SIZE * 4` bytes, with normals at byte offset 12 and uvs at byte offset 24.
+    pub fn interleaved(&self) -> Vec<f32> {
+        let mut interleaved =
+            Vec::with_capacity(self.vertex_count() * Self::INTERLEAVED_SIZE as usize);
+
+        for i in 0..self.vertex_count() {
+            interleaved.extend_from_slice(&self.positions[i * 3..i * 3 + 3]);
+            interleaved.extend_from_slice(&self.normals[i * 3..i * 3 + 3]);
+            interleaved.extend_from_slice(&self.uvs[i * 2..i * 2 + 2]);
+        }
+
+        interleaved
+    }
+}
@@ -0,0 +1,51 @@
+use crate::GeometryData;
+
+/// A flat plane in the XY plane, centered at the origin and facing +Z.
+///
+/// `width_segments` and `height_segments` control how many quads the plane is divided into
+/// along each axis (a minimum of 1 is used).
+pub fn plane(width: f32, height: f32, width_segments: u32, height_segments: u32) -> GeometryData {
+    let width_segments = width_segments.max(1);
+    let height_segments = height_segments.max(1);
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut uvs = Vec::new();
+    let mut indices = Vec::new();
+
+    for y in 0..=height_segments {
+        let v = y as f32 / height_segments as f32;
+        for x in 0..=width_segments {
+            let u = x as f32 / width_segments as f32;
+            positions.extend_from_slice(&[(u - 0.5) * width, (v - 0.5) * height, 0.0]);
+            normals.extend_from_slice(&[0.0, 0.0, 1.0]);
+            uvs.extend_from_slice(&[u, v]);
+        }
+    }
+
+    let row = width_segments + 1;
+    for y in 0..height_segments {
+        for x in 0..width_segments {
+            let a = y * row + x;
+            let b = a + 1;
+            let c = a + row;
+            let d = c + 1;
+            indices.extend_from_slice(&[a, b, d, a, d, c]);
+        }
+    }
+
+    GeometryData::new(positions, normals, uvs, indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::plane;
+
+    #[test]
+    fn subdivides_plane() {
+        let plane = plane(2.0, 1.0, 4, 2);
+        assert_eq!(plane.vertex_count(), 5 * 3);
+        assert_eq!(plane.index_count(), 4 * 2 * 6);
+        assert_eq!(&plane.positions()[..3], &[-1.0, -0.5, 0.0]);
+        assert_eq!(plane.interleaved().len(), 15 * 8);
+    }
+}
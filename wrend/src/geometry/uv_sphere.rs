@@ -0,0 +1,66 @@
+use crate::GeometryData;
+use std::f32::consts::PI;
+
+/// A sphere centered at the origin, built from latitude/longitude rings.
+///
+/// `segments` is the number of divisions around the equator and `rings` the number from pole
+/// to pole (minimums of 3 and 2 are used, respectively).
+pub fn uv_sphere(radius: f32, segments: u32, rings: u32) -> GeometryData {
+    let segments = segments.max(3);
+    let rings = rings.max(2);
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut uvs = Vec::new();
+    let mut indices = Vec::new();
+
+    for ring in 0..=rings {
+        let v = ring as f32 / rings as f32;
+        let theta = v * PI;
+        for segment in 0..=segments {
+            let u = segment as f32 / segments as f32;
+            let phi = u * 2.0 * PI;
+            let normal = [
+                -phi.cos() * theta.sin(),
+                theta.cos(),
+                phi.sin() * theta.sin(),
+            ];
+            positions.extend(normal.iter().map(|n| n * radius));
+            normals.extend_from_slice(&normal);
+            uvs.extend_from_slice(&[u, 1.0 - v]);
+        }
+    }
+
+    let row = segments + 1;
+    for ring in 0..rings {
+        for segment in 0..segments {
+            let a = ring * row + segment;
+            let b = a + 1;
+            let c = a + row;
+            let d = c + 1;
+            if ring != 0 {
+                indices.extend_from_slice(&[a, c, b]);
+            }
+            if ring != rings - 1 {
+                indices.extend_from_slice(&[b, c, d]);
+            }
+        }
+    }
+
+    GeometryData::new(positions, normals, uvs, indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::uv_sphere;
+
+    #[test]
+    fn builds_sphere_on_radius() {
+        let sphere = uv_sphere(2.0, 8, 4);
+        assert_eq!(sphere.vertex_count(), 9 * 5);
+        assert_eq!(sphere.index_count(), (8 * 4 * 2 - 8 * 2) * 3);
+        for position in sphere.positions().chunks(3) {
+            let length = position.iter().map(|p| p * p).sum::<f32>().sqrt();
+            assert!((length - 2.0).abs() < 1e-5);
+        }
+    }
+}
@@ -1,7 +1,7 @@
-mod id;
 mod id_default;
-mod id_name;
+mod namespaced;
+mod namespaced_id;
 
-pub use id::*;
 pub use id_default::*;
-pub use id_name::*;
+pub use namespaced::*;
+pub use namespaced_id::*;
@@ -1,6 +1,6 @@
 use wasm_bindgen::prelude::wasm_bindgen;
 
-use super::{id::Id, id_name::IdName};
+use crate::{Id, IdName};
 
 /// Default ID that can be used when no id has been specified by the consuming application
 #[wasm_bindgen]
@@ -0,0 +1,95 @@
+use crate::{Id, IdName, NamespacedId};
+use std::fmt::Debug;
+
+/// Wraps any [Id] so that it can be scoped to a namespace.
+///
+/// Ids that aren't in a namespace belong to the app itself. Nested namespaces are separated by `/`,
+/// with the outermost namespace first. The [IdName] of the inner id is used as-is, so uniforms and
+/// attributes keep the names that shaders refer to them by.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct Namespaced<I: Id> {
+    namespace: Option<String>,
+    id: I,
+}
+
+impl<I: Id> Namespaced<I> {
+    /// An id scoped to `namespace`, e.g. for looking up a resource that a plugin added
+    pub fn new(namespace: impl Into<String>, id: impl Into<I>) -> Self {
+        Self {
+            namespace: Some(namespace.into()),
+            id: id.into(),
+        }
+    }
+
+    /// The id without its namespace
+    pub fn id(&self) -> &I {
+        &self.id
+    }
+}
+
+impl<I: Id> NamespacedId for Namespaced<I> {
+    fn in_namespace(&self, namespace: &str) -> Self {
+        let namespace = match &self.namespace {
+            Some(inner) => format!("{namespace}/{inner}"),
+            None => namespace.to_string(),
+        };
+        Self {
+            namespace: Some(namespace),
+            id: self.id.clone(),
+        }
+    }
+
+    fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+}
+
+impl<I: Id> Id for Namespaced<I> {}
+
+impl<I: Id + IdName> IdName for Namespaced<I> {
+    fn name(&self) -> String {
+        self.id.name()
+    }
+}
+
+impl<I: Id> From<I> for Namespaced<I> {
+    fn from(id: I) -> Self {
+        Self {
+            namespace: None,
+            id,
+        }
+    }
+}
+
+impl From<&str> for Namespaced<String> {
+    fn from(id: &str) -> Self {
+        Self::from(id.to_string())
+    }
+}
+
+impl<I: Id> Debug for Namespaced<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.namespace {
+            Some(namespace) => write!(f, "{namespace}/{:?}", self.id),
+            None => write!(f, "{:?}", self.id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Namespaced;
+    use crate::{IdName, NamespacedId};
+
+    #[test]
+    fn scopes_ids_without_changing_names() {
+        let id = Namespaced::<String>::from("u_time");
+        let scoped = id.in_namespace("bloom");
+
+        assert_ne!(id, scoped);
+        assert_eq!(scoped, Namespaced::new("bloom", "u_time"));
+        assert_eq!(scoped.name(), "u_time");
+        assert_eq!(scoped.in_namespace("post").namespace(), Some("post/bloom"));
+        assert_eq!(format!("{scoped:?}"), "bloom/\"u_time\"");
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{Id, IdDefault};
+
+/// Ids that can be scoped to a namespace, so that resources added by different plugins or presets
+/// don't collide (see [crate::RendererDataBuilder::add_namespace]).
+///
+/// Scoping an id must not change its [crate::IdName], since that is the name used in shaders.
+pub trait NamespacedId: Id {
+    /// The same id, scoped to `namespace` (inside of any namespace it was already in)
+    fn in_namespace(&self, namespace: &str) -> Self;
+
+    /// The namespace the id is scoped to, if any
+    fn namespace(&self) -> Option<&str>;
+}
+
+impl NamespacedId for IdDefault {
+    fn in_namespace(&self, _: &str) -> Self {
+        IdDefault
+    }
+
+    fn namespace(&self) -> Option<&str> {
+        None
+    }
+}
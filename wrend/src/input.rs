@@ -0,0 +1,9 @@
+mod mouse_uniform_space;
+mod pointer_state;
+mod pointer_tracker;
+mod screen_space;
+
+pub use mouse_uniform_space::*;
+pub use pointer_state::*;
+pub use pointer_tracker::*;
+pub use screen_space::*;
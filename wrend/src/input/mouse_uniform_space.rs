@@ -0,0 +1,25 @@
+use crate::PointerState;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Coordinate space used when uploading the pointer position to a mouse uniform.
+///
+/// See [crate::RendererDataBuilder::add_mouse_uniform]
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum MouseUniformSpace {
+    /// -1.0 to 1.0 on both axes, with (0, 0) at the center of the canvas
+    #[default]
+    ClipSpace,
+    /// Drawing buffer pixels, with (0, 0) at the bottom-left corner (the same as `gl_FragCoord`)
+    Pixels,
+}
+
+impl MouseUniformSpace {
+    /// Converts a pointer state into a `vec2` value in this coordinate space
+    pub fn value(&self, pointer_state: &PointerState, width: f32, height: f32) -> [f32; 2] {
+        match self {
+            MouseUniformSpace::ClipSpace => pointer_state.clip_space_position(),
+            MouseUniformSpace::Pixels => pointer_state.pixel_position(width, height),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+/// Snapshot of the pointer's most recent state relative to a canvas.
+///
+/// Positions are stored normalized (0.0 to 1.0) with the origin in the bottom-left corner,
+/// matching WebGL's coordinate conventions, so that they stay valid when the canvas is resized.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct PointerState {
+    position: Option<[f32; 2]>,
+    down_position: Option<[f32; 2]>,
+    is_down: bool,
+}
+
+impl PointerState {
+    /// Creates a new pointer state from a normalized, bottom-left-origin position
+    pub fn new(position: Option<[f32; 2]>, is_down: bool) -> Self {
+        Self {
+            position,
+            down_position: None,
+            is_down,
+        }
+    }
+
+    /// Last known normalized position of the pointer, or `None` if the pointer has never entered the canvas
+    pub fn position(&self) -> Option<[f32; 2]> {
+        self.position
+    }
+
+    /// Normalized position of the pointer the last time it was pressed down, if ever
+    pub fn down_position(&self) -> Option<[f32; 2]> {
+        self.down_position
+    }
+
+    /// Whether the primary pointer button is currently held down
+    pub fn is_down(&self) -> bool {
+        self.is_down
+    }
+
+    /// Position of the pointer in clip space (-1.0 to 1.0). Defaults to the center of the canvas.
+    pub fn clip_space_position(&self) -> [f32; 2] {
+        let [x, y] = self.position.unwrap_or([0.5, 0.5]);
+        [x * 2.0 - 1.0, y * 2.0 - 1.0]
+    }
+
+    /// Position of the pointer in pixels, given the size of the drawing buffer. Defaults to the center of the canvas.
+    pub fn pixel_position(&self, width: f32, height: f32) -> [f32; 2] {
+        let [x, y] = self.position.unwrap_or([0.5, 0.5]);
+        [x * width, y * height]
+    }
+
+    pub(crate) fn set_position(&mut self, position: [f32; 2]) -> &mut Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub(crate) fn set_down_position(&mut self, down_position: [f32; 2]) -> &mut Self {
+        self.down_position = Some(down_position);
+        self
+    }
+
+    pub(crate) fn set_is_down(&mut self, is_down: bool) -> &mut Self {
+        self.is_down = is_down;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PointerState;
+
+    #[test]
+    fn defaults_to_center_of_canvas() {
+        let state = PointerState::default();
+        assert_eq!(state.clip_space_position(), [0.0, 0.0]);
+        assert_eq!(state.pixel_position(200.0, 100.0), [100.0, 50.0]);
+    }
+
+    #[test]
+    fn converts_normalized_position() {
+        let state = PointerState::new(Some([1.0, 0.25]), true);
+        assert_eq!(state.clip_space_position(), [1.0, -0.5]);
+        assert_eq!(state.pixel_position(200.0, 100.0), [200.0, 25.0]);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{Listener, PointerState, ScreenSpace};
+use std::{cell::RefCell, rc::Rc};
+use web_sys::{HtmlCanvasElement, Node, PointerEvent};
+
+/// Tracks the pointer's position and button state over a canvas.
+///
+/// Listeners are removed automatically once every clone of the tracker has been dropped.
+#[derive(Debug, Clone)]
+pub struct PointerTracker {
+    state: Rc<RefCell<PointerState>>,
+    _listeners: Rc<Vec<Listener<Node, PointerEvent>>>,
+}
+
+impl PointerTracker {
+    /// Starts tracking pointer events on the given canvas
+    pub fn new(canvas: &HtmlCanvasElement) -> Self {
+        Self::new_with_state(canvas, Default::default())
+    }
+
+    /// Starts tracking pointer events on the given canvas, writing into an existing shared state
+    pub(crate) fn new_with_state(
+        canvas: &HtmlCanvasElement,
+        state: Rc<RefCell<PointerState>>,
+    ) -> Self {
+        let normalized_position = {
+            let canvas = canvas.clone();
+            move |e: &PointerEvent| {
+                // measured per event, since the canvas may have moved or been resized by CSS
+                ScreenSpace::from_canvas(&canvas)
+                    .client_to_normalized([e.client_x() as f32, e.client_y() as f32])
+            }
+        };
+
+        let pointer_move_listener = {
+            let state = Rc::clone(&state);
+            let normalized_position = normalized_position.clone();
+            Listener::new(
+                canvas.clone().into(),
+                "pointermove",
+                move |e: PointerEvent| {
+                    state.borrow_mut().set_position(normalized_position(&e));
+                },
+            )
+        };
+
+        let pointer_down_listener = {
+            let state = Rc::clone(&state);
+            Listener::new(
+                canvas.clone().into(),
+                "pointerdown",
+                move |e: PointerEvent| {
+                    let position = normalized_position(&e);
+                    state
+                        .borrow_mut()
+                        .set_position(position)
+                        .set_down_position(position)
+                        .set_is_down(true);
+                },
+            )
+        };
+
+        let make_release_listener = |name: &'static str| {
+            let state = Rc::clone(&state);
+            Listener::new(canvas.clone().into(), name, move |_: PointerEvent| {
+                state.borrow_mut().set_is_down(false);
+            })
+        };
+
+        let listeners = vec![
+            pointer_move_listener,
+            pointer_down_listener,
+            make_release_listener("pointerup"),
+            make_release_listener("pointerleave"),
+        ];
+
+        Self {
+            state,
+            _listeners: Rc::new(listeners),
+        }
+    }
+
+    /// Gets the most recent pointer state
+    pub fn state(&self) -> PointerState {
+        *self.state.borrow()
+    }
+}
+
+impl PartialEq for PointerTracker {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl Eq for PointerTracker {}
@@ -0,0 +1,196 @@
+use web_sys::HtmlCanvasElement;
+
+/// Describes how a canvas maps between the coordinate spaces involved in pointer input
+/// and rendering:
+///
+/// - **CSS pixels**: page coordinates, as reported by `clientX` / `clientY` on DOM events
+/// - **Device pixels**: physical screen pixels (CSS pixels * `devicePixelRatio`)
+/// - **Buffer pixels**: pixels of the canvas's drawing buffer (`canvas.width` x `canvas.height`),
+///   with the origin in the bottom-left corner, matching `gl_FragCoord` and `gl.viewport`
+/// - **Normalized**: 0.0 to 1.0 across the canvas, with the origin in the bottom-left corner
+/// - **Clip space**: -1.0 to 1.0 across the canvas
+///
+/// Because the drawing buffer can be any size relative to the canvas's displayed (CSS) size,
+/// converting event coordinates directly into buffer pixels is a common source of offset
+/// mouse interactions. This type accounts for the canvas's position on the page, its CSS
+/// scaling, and the device pixel ratio.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ScreenSpace {
+    css_left: f32,
+    css_top: f32,
+    css_width: f32,
+    css_height: f32,
+    buffer_width: f32,
+    buffer_height: f32,
+    device_pixel_ratio: f32,
+}
+
+impl ScreenSpace {
+    /// Creates a screen space from explicit measurements. `css_rect` is `[left, top, width, height]`
+    /// of the canvas in CSS pixels, relative to the viewport.
+    pub fn new(css_rect: [f32; 4], buffer_size: [u32; 2], device_pixel_ratio: f32) -> Self {
+        let [css_left, css_top, css_width, css_height] = css_rect;
+        Self {
+            css_left,
+            css_top,
+            css_width: css_width.max(f32::EPSILON),
+            css_height: css_height.max(f32::EPSILON),
+            buffer_width: buffer_size[0].max(1) as f32,
+            buffer_height: buffer_size[1].max(1) as f32,
+            device_pixel_ratio: if device_pixel_ratio > 0.0 {
+                device_pixel_ratio
+            } else {
+                1.0
+            },
+        }
+    }
+
+    /// Measures a canvas's current on-page rect, drawing buffer size, and the window's `devicePixelRatio`
+    pub fn from_canvas(canvas: &HtmlCanvasElement) -> Self {
+        let rect = canvas.get_bounding_client_rect();
+        let device_pixel_ratio = web_sys::window()
+            .map(|window| window.device_pixel_ratio())
+            .unwrap_or(1.0);
+        Self::new(
+            [
+                rect.left() as f32,
+                rect.top() as f32,
+                rect.width() as f32,
+                rect.height() as f32,
+            ],
+            [canvas.width(), canvas.height()],
+            device_pixel_ratio as f32,
+        )
+    }
+
+    pub fn device_pixel_ratio(&self) -> f32 {
+        self.device_pixel_ratio
+    }
+
+    /// Displayed size of the canvas in CSS pixels
+    pub fn css_size(&self) -> [f32; 2] {
+        [self.css_width, self.css_height]
+    }
+
+    /// Size of the canvas's drawing buffer
+    pub fn buffer_size(&self) -> [f32; 2] {
+        [self.buffer_width, self.buffer_height]
+    }
+
+    /// Number of drawing buffer pixels per CSS pixel on each axis
+    pub fn buffer_scale(&self) -> [f32; 2] {
+        [
+            self.buffer_width / self.css_width,
+            self.buffer_height / self.css_height,
+        ]
+    }
+
+    /// Drawing buffer size that would match the canvas's displayed size 1:1 in device pixels
+    pub fn display_buffer_size(&self) -> [u32; 2] {
+        [
+            (self.css_width * self.device_pixel_ratio).round().max(1.0) as u32,
+            (self.css_height * self.device_pixel_ratio).round().max(1.0) as u32,
+        ]
+    }
+
+    pub fn css_to_device(&self, css: f32) -> f32 {
+        css * self.device_pixel_ratio
+    }
+
+    pub fn device_to_css(&self, device: f32) -> f32 {
+        device / self.device_pixel_ratio
+    }
+
+    /// Converts viewport CSS coordinates (e.g. `clientX` / `clientY`) to normalized canvas coordinates
+    pub fn client_to_normalized(&self, client: [f32; 2]) -> [f32; 2] {
+        [
+            (client[0] - self.css_left) / self.css_width,
+            1.0 - (client[1] - self.css_top) / self.css_height,
+        ]
+    }
+
+    /// Converts viewport CSS coordinates (e.g. `clientX` / `clientY`) to drawing buffer pixels
+    pub fn client_to_buffer(&self, client: [f32; 2]) -> [f32; 2] {
+        self.normalized_to_buffer(self.client_to_normalized(client))
+    }
+
+    /// Converts viewport CSS coordinates (e.g. `clientX` / `clientY`) to clip space
+    pub fn client_to_clip(&self, client: [f32; 2]) -> [f32; 2] {
+        Self::normalized_to_clip(self.client_to_normalized(client))
+    }
+
+    /// Converts drawing buffer pixels back to viewport CSS coordinates
+    pub fn buffer_to_client(&self, buffer: [f32; 2]) -> [f32; 2] {
+        let [x, y] = self.buffer_to_normalized(buffer);
+        [
+            self.css_left + x * self.css_width,
+            self.css_top + (1.0 - y) * self.css_height,
+        ]
+    }
+
+    pub fn normalized_to_buffer(&self, normalized: [f32; 2]) -> [f32; 2] {
+        [
+            normalized[0] * self.buffer_width,
+            normalized[1] * self.buffer_height,
+        ]
+    }
+
+    pub fn buffer_to_normalized(&self, buffer: [f32; 2]) -> [f32; 2] {
+        [
+            buffer[0] / self.buffer_width,
+            buffer[1] / self.buffer_height,
+        ]
+    }
+
+    pub fn buffer_to_clip(&self, buffer: [f32; 2]) -> [f32; 2] {
+        Self::normalized_to_clip(self.buffer_to_normalized(buffer))
+    }
+
+    pub fn clip_to_buffer(&self, clip: [f32; 2]) -> [f32; 2] {
+        self.normalized_to_buffer(Self::clip_to_normalized(clip))
+    }
+
+    pub fn normalized_to_clip(normalized: [f32; 2]) -> [f32; 2] {
+        [normalized[0] * 2.0 - 1.0, normalized[1] * 2.0 - 1.0]
+    }
+
+    pub fn clip_to_normalized(clip: [f32; 2]) -> [f32; 2] {
+        [(clip[0] + 1.0) * 0.5, (clip[1] + 1.0) * 0.5]
+    }
+}
+
+/// Resizes a canvas's drawing buffer to match its displayed size in device pixels.
+///
+/// Returns `true` if the canvas was resized, in which case any viewports should be updated.
+pub fn resize_canvas_to_display_size(canvas: &HtmlCanvasElement) -> bool {
+    let [width, height] = ScreenSpace::from_canvas(canvas).display_buffer_size();
+    if canvas.width() == width && canvas.height() == height {
+        return false;
+    }
+    canvas.set_width(width);
+    canvas.set_height(height);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScreenSpace;
+
+    #[test]
+    fn converts_between_spaces() {
+        // a 200x100 css canvas at (10, 20), rendered at half resolution on a 2x display
+        let space = ScreenSpace::new([10.0, 20.0, 200.0, 100.0], [100, 50], 2.0);
+
+        assert_eq!(space.buffer_scale(), [0.5, 0.5]);
+        assert_eq!(space.display_buffer_size(), [400, 200]);
+        assert_eq!(space.css_to_device(3.0), 6.0);
+
+        assert_eq!(space.client_to_normalized([10.0, 20.0]), [0.0, 1.0]);
+        assert_eq!(space.client_to_normalized([110.0, 120.0]), [0.5, 0.0]);
+        assert_eq!(space.client_to_buffer([110.0, 70.0]), [50.0, 25.0]);
+        assert_eq!(space.client_to_clip([210.0, 20.0]), [1.0, 1.0]);
+        assert_eq!(space.buffer_to_client([50.0, 25.0]), [110.0, 70.0]);
+        assert_eq!(space.clip_to_buffer([0.0, 0.0]), [50.0, 25.0]);
+        assert_eq!(space.buffer_to_clip([100.0, 0.0]), [1.0, -1.0]);
+    }
+}
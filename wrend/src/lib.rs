@@ -51,6 +51,40 @@
 //!
 //! A long term goal of `wrend` is to provide matchable errors (or `catch`able errors in JavaScript) for all fallible operations.
 //!
+//! # Features
+//!
+//! All features except `parallel`, `simd`, `gif`, `apng`, `idb`, and `derive` are enabled by default. Rust-only apps that care about binary size can disable
+//! `default-features` and opt back into only what they use:
+//!
+//! - `js`: the JavaScript bindings (`Renderer`, `RendererData`, and all link classes exported through wasm-bindgen)
+//! - `recording`: recording canvas output to video with the `MediaRecorder` API
+//! - `shader-toy`: the built-in ShaderToy-compatible shader pack ([`RendererDataBuilder::add_shader_toy_program`])
+//! - `compositing`: cross fades, blur pyramids, comparison wipes, overlays, and color vision simulation filters
+//! - `automata`: GPU cellular automata (`CellularAutomaton`)
+//! - `fluids`: a stable-fluids simulation preset (`FluidSimulation`)
+//! - `boids`: a GPU boids preset (`Boids`)
+//! - `sdf`: MSDF font loading and SDF text rendering (`SdfFont`, `SdfText`)
+//! - `tweak-panel`: a dat.gui-style control panel generated from a [`SettingsStore`] (`TweakPanel`)
+//!
+//! The `parallel` feature is opt-in: it generates large initial buffers on a rayon thread pool
+//! (see [`generate_buffer_data`]), falling back to a single thread where wasm threads aren't available.
+//!
+//! The `simd` feature is also opt-in: when the crate is built with `-C target-feature=+simd128`, CPU-side noise
+//! and color generation (e.g. [`Noise::perlin_rgba8`], [`Gradient::to_rgba8`]) process four values at a time.
+//! Without it, the same code runs one lane at a time and produces the same results.
+//!
+//! The `gif` feature adds a pure-Rust GIF encoder: [`Renderer::export_gif`] renders a fixed number of frames
+//! at a fixed timestep, reads each one back, and returns the animation as a `Blob`. The `apng` feature does the same
+//! with a lossless animated PNG encoder ([`Renderer::export_apng`]), which keeps full color for gradient-heavy shader art.
+//!
+//! The `idb` feature adds [`SnapshotStore`], which saves [`StateSnapshot`]s (see [`Renderer::export_state`])
+//! to IndexedDB so that simulation state survives page reloads.
+//!
+//! The `derive` feature re-exports `#[derive(UniformGroup)]` from the companion `wrend-derive` crate, which maps
+//! struct fields to uniforms so that a whole set of parameters can be uploaded with [`Renderer::set_uniform_group`].
+//! It also re-exports `#[derive(WrendId)]` and `#[derive(WrendIdName)]`, which implement [`Id`] (and optionally `Default`)
+//! and [`IdName`] for id enums.
+//!
 //! # Example
 //!
 //! The following is a "Hello, triangle!" example (the equivalent of "Hello, world!" for WebGL)
@@ -247,43 +281,123 @@
 //! ## Future Work
 //!
 //! Currently, wrend only supports build pipelines where all resources are initialized up front.
-//! That is, no *new* textures, buffers, uniforms can be added after the pipeline has been initialized.
+//! Adding *new* textures, buffers, or uniforms after the pipeline has been initialized means rebuilding it
+//! with [`Renderer::rebuild_with`], which at least reuses every resource whose link didn't change.
 
 mod animation;
+mod assets;
 mod attributes;
+#[cfg(feature = "automata")]
+mod automata;
+mod backends;
+mod bench;
+#[cfg(feature = "boids")]
+mod boids;
 mod buffers;
+mod build_graph;
 mod callbacks;
+mod color;
+mod compositing;
 mod constants;
+mod debug;
+mod determinism;
+mod draw_calls;
+mod easing;
+#[cfg(feature = "fluids")]
+mod fluids;
+mod frame_export;
 mod framebuffers;
+mod geometry;
 mod ids;
+mod input;
 mod math;
+mod plotting;
+mod point_clouds;
+mod polylines;
 mod programs;
+mod quality;
+#[cfg(feature = "recording")]
 mod recording;
+mod render_graph;
 mod renderer_data;
 mod renderers;
+#[cfg(feature = "sdf")]
+mod sdf;
+mod settings;
+#[cfg(feature = "shader-toy")]
+mod shader_toy;
 mod shaders;
+mod snapshots;
+mod stats;
 mod textures;
+mod tile_streaming;
 mod transform_feedback;
+#[cfg(feature = "tweak-panel")]
+mod tweak_panel;
 mod types;
 mod uniforms;
 mod utils;
+mod viewports;
+mod visualization;
 
-pub(crate) use recording::*;
+pub(crate) use backends::*;
+pub(crate) use build_graph::*;
 
 pub use animation::*;
+pub use assets::*;
 pub use attributes::*;
+#[cfg(feature = "automata")]
+pub use automata::*;
+pub use bench::*;
+#[cfg(feature = "boids")]
+pub use boids::*;
 pub use buffers::*;
 pub use callbacks::*;
+pub use color::*;
+pub use compositing::*;
 pub use constants::*;
+pub use debug::*;
+pub use determinism::*;
+pub use draw_calls::*;
+pub use easing::*;
+#[cfg(feature = "fluids")]
+pub use fluids::*;
+pub use frame_export::*;
 pub use framebuffers::*;
+pub use geometry::*;
 pub use ids::*;
+pub use input::*;
 pub use math::*;
+pub use plotting::*;
+pub use point_clouds::*;
+pub use polylines::*;
 pub use programs::*;
+pub use quality::*;
+#[cfg(feature = "recording")]
+pub use recording::*;
+pub use render_graph::*;
 pub use renderer_data::*;
 pub use renderers::*;
+#[cfg(feature = "sdf")]
+pub use sdf::*;
+pub use settings::*;
+#[cfg(feature = "shader-toy")]
+pub use shader_toy::*;
 pub use shaders::*;
+pub use snapshots::*;
+pub use stats::*;
 pub use textures::*;
+pub use tile_streaming::*;
 pub use transform_feedback::*;
+#[cfg(feature = "tweak-panel")]
+pub use tweak_panel::*;
 pub use types::*;
 pub use uniforms::*;
 pub use utils::*;
+pub use viewports::*;
+pub use visualization::*;
+
+pub use wrend_core::*;
+
+#[cfg(feature = "derive")]
+pub use wrend_derive::{UniformGroup, WrendId, WrendIdName};
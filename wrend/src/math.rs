@@ -1,8 +1,15 @@
+mod f32x4;
 mod m4;
+mod noise;
+mod rng;
 mod utils;
 mod vec3;
 
+pub(crate) use f32x4::*;
+
 pub use m4::*;
+pub use noise::*;
+pub use rng::*;
 pub use utils::*;
 pub use vec3::*;
 
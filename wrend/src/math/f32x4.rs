@@ -0,0 +1,181 @@
+//! A 4-lane `f32` vector used by the CPU noise and color utilities.
+//!
+//! With the `simd` feature enabled and the crate built with `-C target-feature=+simd128`, lanes are processed
+//! with WebAssembly SIMD128 instructions; otherwise each lane is processed in turn, with the same results.
+//! A wasm module can't detect SIMD support from the inside, so apps that need to support engines without it
+//! should build both variants and pick one from JavaScript (e.g. with `wasm-feature-detect`).
+
+use std::ops::{Add, Mul, Sub};
+
+#[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+use core::arch::wasm32::*;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct F32x4(
+    #[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))] v128,
+    #[cfg(not(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128")))] [f32; 4],
+);
+
+#[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+impl F32x4 {
+    pub fn splat(value: f32) -> Self {
+        Self(f32x4_splat(value))
+    }
+
+    pub fn from_array([a, b, c, d]: [f32; 4]) -> Self {
+        Self(f32x4(a, b, c, d))
+    }
+
+    pub fn to_array(self) -> [f32; 4] {
+        [
+            f32x4_extract_lane::<0>(self.0),
+            f32x4_extract_lane::<1>(self.0),
+            f32x4_extract_lane::<2>(self.0),
+            f32x4_extract_lane::<3>(self.0),
+        ]
+    }
+
+    pub fn floor(self) -> Self {
+        Self(f32x4_floor(self.0))
+    }
+
+    pub fn min(self, other: Self) -> Self {
+        Self(f32x4_pmin(self.0, other.0))
+    }
+
+    pub fn max(self, other: Self) -> Self {
+        Self(f32x4_pmax(self.0, other.0))
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+impl Add for F32x4 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(f32x4_add(self.0, other.0))
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+impl Sub for F32x4 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(f32x4_sub(self.0, other.0))
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+impl Mul for F32x4 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self(f32x4_mul(self.0, other.0))
+    }
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128")))]
+impl F32x4 {
+    pub fn splat(value: f32) -> Self {
+        Self([value; 4])
+    }
+
+    pub fn from_array(values: [f32; 4]) -> Self {
+        Self(values)
+    }
+
+    pub fn to_array(self) -> [f32; 4] {
+        self.0
+    }
+
+    pub fn floor(self) -> Self {
+        Self(self.0.map(f32::floor))
+    }
+
+    /// Matches `f32x4_pmin`: returns `other` only if it is strictly less than `self`
+    pub fn min(self, other: Self) -> Self {
+        self.zip(other, |a, b| if b < a { b } else { a })
+    }
+
+    /// Matches `f32x4_pmax`: returns `other` only if it is strictly greater than `self`
+    pub fn max(self, other: Self) -> Self {
+        self.zip(other, |a, b| if a < b { b } else { a })
+    }
+
+    fn zip(self, other: Self, f: impl Fn(f32, f32) -> f32) -> Self {
+        let [a, b, c, d] = self.0;
+        let [e, g, h, i] = other.0;
+        Self([f(a, e), f(b, g), f(c, h), f(d, i)])
+    }
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128")))]
+impl Add for F32x4 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.zip(other, |a, b| a + b)
+    }
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128")))]
+impl Sub for F32x4 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.zip(other, |a, b| a - b)
+    }
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128")))]
+impl Mul for F32x4 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.zip(other, |a, b| a * b)
+    }
+}
+
+impl F32x4 {
+    pub fn clamp(self, min: f32, max: f32) -> Self {
+        self.max(Self::splat(min)).min(Self::splat(max))
+    }
+
+    /// Linearly interpolates between `self` and `other`
+    pub fn lerp(self, other: Self, t: Self) -> Self {
+        self + (other - self) * t
+    }
+
+    /// Perlin's quintic fade curve: `6t^5 - 15t^4 + 10t^3`
+    pub fn fade(self) -> Self {
+        let t = self;
+        t * t * t * (t * (t * Self::splat(6.0) - Self::splat(15.0)) + Self::splat(10.0))
+    }
+
+    /// Maps 0.0..=1.0 to 0..=255, rounding to nearest (values outside the range are clamped)
+    pub fn to_unorm8(self) -> [u8; 4] {
+        (self.clamp(0.0, 1.0) * Self::splat(255.0) + Self::splat(0.5))
+            .floor()
+            .to_array()
+            .map(|value| value as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32x4;
+
+    #[test]
+    fn computes_lane_wise() {
+        let a = F32x4::from_array([0.25, -1.5, 2.0, 0.5]);
+        let b = F32x4::splat(2.0);
+        assert_eq!((a * b + b - a).to_array(), [2.25, 0.5, 4.0, 2.5]);
+        assert_eq!(a.floor().to_array(), [0.0, -2.0, 2.0, 0.0]);
+        assert_eq!(a.clamp(0.0, 1.0).to_array(), [0.25, 0.0, 1.0, 0.5]);
+        assert_eq!(
+            F32x4::from_array([0.0, 0.5, 1.0, 2.0]).to_unorm8(),
+            [0, 128, 255, 255]
+        );
+    }
+}
@@ -0,0 +1,323 @@
+use crate::{F32x4, Rng};
+
+const F2: f64 = 0.366_025_403_784_438_6; // (sqrt(3) - 1) / 2
+const G2: f64 = 0.211_324_865_405_187_1; // (3 - sqrt(3)) / 6
+
+const GRADIENTS_2D: [[f64; 2]; 8] = [
+    [1.0, 0.0],
+    [-1.0, 0.0],
+    [0.0, 1.0],
+    [0.0, -1.0],
+    [
+        std::f64::consts::FRAC_1_SQRT_2,
+        std::f64::consts::FRAC_1_SQRT_2,
+    ],
+    [
+        -std::f64::consts::FRAC_1_SQRT_2,
+        std::f64::consts::FRAC_1_SQRT_2,
+    ],
+    [
+        std::f64::consts::FRAC_1_SQRT_2,
+        -std::f64::consts::FRAC_1_SQRT_2,
+    ],
+    [
+        -std::f64::consts::FRAC_1_SQRT_2,
+        -std::f64::consts::FRAC_1_SQRT_2,
+    ],
+];
+
+const GRADIENTS_3D: [[f64; 3]; 12] = [
+    [1.0, 1.0, 0.0],
+    [-1.0, 1.0, 0.0],
+    [1.0, -1.0, 0.0],
+    [-1.0, -1.0, 0.0],
+    [1.0, 0.0, 1.0],
+    [-1.0, 0.0, 1.0],
+    [1.0, 0.0, -1.0],
+    [-1.0, 0.0, -1.0],
+    [0.0, 1.0, 1.0],
+    [0.0, -1.0, 1.0],
+    [0.0, 1.0, -1.0],
+    [0.0, -1.0, -1.0],
+];
+
+/// Seeded gradient noise evaluated on the CPU, e.g. for generating noise textures or
+/// initial particle positions. All functions return values in roughly -1.0..=1.0.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Noise {
+    permutation: [u8; 512],
+}
+
+impl Noise {
+    /// Creates a noise generator whose permutation table is shuffled with the given seed
+    pub fn new(seed: u64) -> Self {
+        Self::from_rng(Rng::new(seed))
+    }
+
+    /// Like [Noise::new], but generators with the same seed and different streams are independent
+    /// (see [Rng::new_with_stream])
+    pub fn new_with_stream(seed: u64, stream: u64) -> Self {
+        Self::from_rng(Rng::new_with_stream(seed, stream))
+    }
+
+    fn from_rng(mut rng: Rng) -> Self {
+        let mut table: [u8; 256] = [0; 256];
+        for (i, value) in table.iter_mut().enumerate() {
+            *value = i as u8;
+        }
+        for i in (1..table.len()).rev() {
+            let j = (rng.next_u32() as usize) % (i + 1);
+            table.swap(i, j);
+        }
+
+        let mut permutation = [0; 512];
+        for (i, value) in permutation.iter_mut().enumerate() {
+            *value = table[i & 255];
+        }
+
+        Self { permutation }
+    }
+
+    fn hash(&self, x: i64, y: i64) -> usize {
+        let x = (x & 255) as usize;
+        let y = (y & 255) as usize;
+        self.permutation[x + self.permutation[y] as usize] as usize
+    }
+
+    fn hash_3d(&self, x: i64, y: i64, z: i64) -> usize {
+        let z = (z & 255) as usize;
+        let y = ((y & 255) as usize + self.permutation[z] as usize) & 255;
+        self.permutation[(x & 255) as usize + self.permutation[y] as usize] as usize
+    }
+
+    /// 2D Perlin noise
+    pub fn perlin_2d(&self, x: f64, y: f64) -> f64 {
+        let x0 = x.floor();
+        let y0 = y.floor();
+        let (xf, yf) = (x - x0, y - y0);
+        let (xi, yi) = (x0 as i64, y0 as i64);
+
+        let dot = |ix: i64, iy: i64, dx: f64, dy: f64| {
+            let gradient = GRADIENTS_2D[self.hash(ix, iy) % GRADIENTS_2D.len()];
+            gradient[0] * dx + gradient[1] * dy
+        };
+
+        let u = fade(xf);
+        let v = fade(yf);
+        let bottom = lerp(dot(xi, yi, xf, yf), dot(xi + 1, yi, xf - 1.0, yf), u);
+        let top = lerp(
+            dot(xi, yi + 1, xf, yf - 1.0),
+            dot(xi + 1, yi + 1, xf - 1.0, yf - 1.0),
+            u,
+        );
+
+        // max magnitude of 2D perlin noise with unit gradients is sqrt(0.5)
+        lerp(bottom, top, v) * std::f64::consts::SQRT_2
+    }
+
+    /// 3D Perlin noise
+    pub fn perlin_3d(&self, x: f64, y: f64, z: f64) -> f64 {
+        let (x0, y0, z0) = (x.floor(), y.floor(), z.floor());
+        let (xf, yf, zf) = (x - x0, y - y0, z - z0);
+        let (xi, yi, zi) = (x0 as i64, y0 as i64, z0 as i64);
+
+        let dot = |ix: i64, iy: i64, iz: i64, dx: f64, dy: f64, dz: f64| {
+            let gradient = GRADIENTS_3D[self.hash_3d(ix, iy, iz) % GRADIENTS_3D.len()];
+            gradient[0] * dx + gradient[1] * dy + gradient[2] * dz
+        };
+
+        let (u, v, w) = (fade(xf), fade(yf), fade(zf));
+        let x00 = lerp(
+            dot(xi, yi, zi, xf, yf, zf),
+            dot(xi + 1, yi, zi, xf - 1.0, yf, zf),
+            u,
+        );
+        let x10 = lerp(
+            dot(xi, yi + 1, zi, xf, yf - 1.0, zf),
+            dot(xi + 1, yi + 1, zi, xf - 1.0, yf - 1.0, zf),
+            u,
+        );
+        let x01 = lerp(
+            dot(xi, yi, zi + 1, xf, yf, zf - 1.0),
+            dot(xi + 1, yi, zi + 1, xf - 1.0, yf, zf - 1.0),
+            u,
+        );
+        let x11 = lerp(
+            dot(xi, yi + 1, zi + 1, xf, yf - 1.0, zf - 1.0),
+            dot(xi + 1, yi + 1, zi + 1, xf - 1.0, yf - 1.0, zf - 1.0),
+            u,
+        );
+
+        lerp(lerp(x00, x10, v), lerp(x01, x11, v), w).clamp(-1.0, 1.0)
+    }
+
+    /// 2D simplex noise
+    pub fn simplex_2d(&self, x: f64, y: f64) -> f64 {
+        let skew = (x + y) * F2;
+        let i = (x + skew).floor();
+        let j = (y + skew).floor();
+        let unskew = (i + j) * G2;
+        let x0 = x - (i - unskew);
+        let y0 = y - (j - unskew);
+
+        let (i1, j1) = if x0 > y0 { (1, 0) } else { (0, 1) };
+        let x1 = x0 - i1 as f64 + G2;
+        let y1 = y0 - j1 as f64 + G2;
+        let x2 = x0 - 1.0 + 2.0 * G2;
+        let y2 = y0 - 1.0 + 2.0 * G2;
+
+        let (i, j) = (i as i64, j as i64);
+        let corner = |ix: i64, iy: i64, dx: f64, dy: f64| {
+            let t = 0.5 - dx * dx - dy * dy;
+            if t < 0.0 {
+                0.0
+            } else {
+                let gradient = GRADIENTS_2D[self.hash(ix, iy) % GRADIENTS_2D.len()];
+                t.powi(4) * (gradient[0] * dx + gradient[1] * dy)
+            }
+        };
+
+        let n =
+            corner(i, j, x0, y0) + corner(i + i1, j + j1, x1, y1) + corner(i + 1, j + 1, x2, y2);
+
+        (n * 99.204_334).clamp(-1.0, 1.0)
+    }
+
+    /// Sums `octaves` layers of 2D Perlin noise, each at double the frequency and half the
+    /// amplitude of the previous one (fractal Brownian motion). Normalized to -1.0..=1.0.
+    pub fn fbm_2d(&self, x: f64, y: f64, octaves: u32) -> f64 {
+        let mut total = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+        let mut max = 0.0;
+        for _ in 0..octaves {
+            total += self.perlin_2d(x * frequency, y * frequency) * amplitude;
+            max += amplitude;
+            amplitude *= 0.5;
+            frequency *= 2.0;
+        }
+        if max == 0.0 {
+            0.0
+        } else {
+            total / max
+        }
+    }
+
+    /// 2D Perlin noise for four points at once, in `f32` precision.
+    ///
+    /// Uses SIMD when the `simd` feature is enabled (see the crate-level docs), and otherwise matches
+    /// [`Noise::perlin_2d`] to within `f32` rounding.
+    pub fn perlin_2d_x4(&self, x: [f32; 4], y: [f32; 4]) -> [f32; 4] {
+        let (x, y) = (F32x4::from_array(x), F32x4::from_array(y));
+        let (x0, y0) = (x.floor(), y.floor());
+        let (xf, yf) = (x - x0, y - y0);
+        let (xi, yi) = (
+            x0.to_array().map(|x| x as i64),
+            y0.to_array().map(|y| y as i64),
+        );
+
+        // gradient lookups are a gather, so they stay per-lane
+        let gradients = |ox: i64, oy: i64| {
+            let mut gx = [0.0; 4];
+            let mut gy = [0.0; 4];
+            for lane in 0..4 {
+                let gradient =
+                    GRADIENTS_2D[self.hash(xi[lane] + ox, yi[lane] + oy) % GRADIENTS_2D.len()];
+                gx[lane] = gradient[0] as f32;
+                gy[lane] = gradient[1] as f32;
+            }
+            (F32x4::from_array(gx), F32x4::from_array(gy))
+        };
+        let dot = |ox: i64, oy: i64| {
+            let (gx, gy) = gradients(ox, oy);
+            let dx = xf - F32x4::splat(ox as f32);
+            let dy = yf - F32x4::splat(oy as f32);
+            gx * dx + gy * dy
+        };
+
+        let u = xf.fade();
+        let v = yf.fade();
+        let bottom = dot(0, 0).lerp(dot(1, 0), u);
+        let top = dot(0, 1).lerp(dot(1, 1), u);
+
+        (bottom.lerp(top, v) * F32x4::splat(std::f32::consts::SQRT_2)).to_array()
+    }
+
+    /// Generates tightly packed RGBA8 texture data filled with 2D Perlin noise,
+    /// where `scale` is the number of noise cells across the texture's width
+    pub fn perlin_rgba8(&self, width: u32, height: u32, scale: f64) -> Vec<u8> {
+        let mut data = Vec::with_capacity((width * height * 4) as usize);
+        let step = (scale / width as f64) as f32;
+        for y in 0..height {
+            let ny = y as f32 * step;
+            let mut x = 0;
+            while x < width {
+                let nx = [0, 1, 2, 3].map(|lane| (x + lane) as f32 * step);
+                let noise = F32x4::from_array(self.perlin_2d_x4(nx, [ny; 4]));
+                let values = (noise * F32x4::splat(0.5) + F32x4::splat(0.5)).to_unorm8();
+                let lanes = (width - x).min(4) as usize;
+                for &value in &values[..lanes] {
+                    data.extend_from_slice(&[value, value, value, 255]);
+                }
+                x += 4;
+            }
+        }
+        data
+    }
+}
+
+impl Default for Noise {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Noise;
+
+    #[test]
+    fn noise_is_deterministic_and_bounded() {
+        let a = Noise::new(1);
+        let b = Noise::new(1);
+        for i in 0..200 {
+            let x = i as f64 * 0.37;
+            let y = i as f64 * 0.91;
+            assert_eq!(a.perlin_2d(x, y), b.perlin_2d(x, y));
+            for value in [
+                a.perlin_2d(x, y),
+                a.perlin_3d(x, y, x - y),
+                a.simplex_2d(x, y),
+                a.fbm_2d(x, y, 4),
+            ] {
+                assert!((-1.0..=1.0).contains(&value));
+            }
+        }
+        // gradient noise is zero at lattice points
+        assert_eq!(a.perlin_2d(3.0, 5.0), 0.0);
+        assert_eq!(a.perlin_rgba8(4, 2, 1.0).len(), 32);
+        assert_eq!(a.perlin_rgba8(5, 3, 1.0).len(), 60);
+    }
+
+    #[test]
+    fn perlin_x4_matches_scalar() {
+        let noise = Noise::new(7);
+        for i in 0..50 {
+            let x = [0, 1, 2, 3].map(|lane| (i * 4 + lane) as f32 * 0.173 - 3.0);
+            let y = [0, 1, 2, 3].map(|lane| (i + lane) as f32 * 0.61 - 7.0);
+            let simd = noise.perlin_2d_x4(x, y);
+            for lane in 0..4 {
+                let scalar = noise.perlin_2d(x[lane] as f64, y[lane] as f64);
+                assert!((simd[lane] as f64 - scalar).abs() < 1e-4);
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::f64::consts::PI;
+
+const MULTIPLIER: u64 = 6364136223846793005;
+const DEFAULT_INCREMENT: u64 = 1442695040888963407;
+
+/// Small, seedable PCG32 random number generator.
+///
+/// Unlike `js_sys::Math::random`, the same seed always produces the same sequence, so initial
+/// particle buffers and noise textures can be generated deterministically.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Rng {
+    state: u64,
+    increment: u64,
+}
+
+impl Rng {
+    /// Creates a generator from a seed
+    pub fn new(seed: u64) -> Self {
+        Self::new_with_stream(seed, DEFAULT_INCREMENT >> 1)
+    }
+
+    /// Creates a generator from a seed and a stream id. Generators with the same seed
+    /// but different streams produce independent sequences.
+    pub fn new_with_stream(seed: u64, stream: u64) -> Self {
+        let mut rng = Self {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+        rng.next_u32();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.next_u32();
+        rng
+    }
+
+    /// Returns the next random `u32`
+    pub fn next_u32(&mut self) -> u32 {
+        let old_state = self.state;
+        self.state = old_state
+            .wrapping_mul(MULTIPLIER)
+            .wrapping_add(self.increment);
+        let xor_shifted = (((old_state >> 18) ^ old_state) >> 27) as u32;
+        let rotation = (old_state >> 59) as u32;
+        xor_shifted.rotate_right(rotation)
+    }
+
+    /// Returns the next random `u64`
+    pub fn next_u64(&mut self) -> u64 {
+        ((self.next_u32() as u64) << 32) | self.next_u32() as u64
+    }
+
+    /// Returns a random `f32` in 0.0..1.0
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// Returns a random `f64` in 0.0..1.0
+    pub fn next_f64(&mut self) -> f64 {
+        let value = ((self.next_u32() as u64) << 21) ^ (self.next_u32() as u64 >> 11);
+        value as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a random `f32` in `min..max`
+    pub fn range_f32(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+
+    /// Returns a random `f64` in `min..max`
+    pub fn range_f64(&mut self, min: f64, max: f64) -> f64 {
+        min + (max - min) * self.next_f64()
+    }
+
+    /// Returns a random boolean that is `true` with the given probability
+    pub fn chance(&mut self, probability: f64) -> bool {
+        self.next_f64() < probability
+    }
+
+    /// Returns a random point inside the unit sphere
+    pub fn point_in_unit_sphere(&mut self) -> [f64; 3] {
+        let theta = self.next_f64() * 2.0 * PI;
+        let phi = (2.0 * self.next_f64() - 1.0).acos();
+        let r = self.next_f64().cbrt();
+        [
+            r * phi.sin() * theta.cos(),
+            r * phi.sin() * theta.sin(),
+            r * phi.cos(),
+        ]
+    }
+
+    /// Returns `count` random `f32` values in `min..max`, e.g. for an initial particle buffer
+    pub fn vec_f32(&mut self, count: usize, min: f32, max: f32) -> Vec<f32> {
+        (0..count).map(|_| self.range_f32(min, max)).collect()
+    }
+}
+
+impl Default for Rng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rng;
+
+    #[test]
+    fn same_seed_produces_same_sequence() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let mut c = Rng::new(43);
+        let a_values: Vec<u32> = (0..8).map(|_| a.next_u32()).collect();
+        let b_values: Vec<u32> = (0..8).map(|_| b.next_u32()).collect();
+        let c_values: Vec<u32> = (0..8).map(|_| c.next_u32()).collect();
+        assert_eq!(a_values, b_values);
+        assert_ne!(a_values, c_values);
+    }
+
+    #[test]
+    fn floats_are_in_range() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            let value = rng.next_f32();
+            assert!((0.0..1.0).contains(&value));
+            let value = rng.next_f64();
+            assert!((0.0..1.0).contains(&value));
+            let value = rng.range_f32(-2.0, 3.0);
+            assert!((-2.0..3.0).contains(&value));
+        }
+    }
+}
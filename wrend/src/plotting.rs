@@ -0,0 +1,9 @@
+mod line_series;
+mod plot_bounds;
+mod plotting_shaders;
+mod scatter_plot;
+
+pub use line_series::*;
+pub use plot_bounds::*;
+pub use plotting_shaders::*;
+pub use scatter_plot::*;
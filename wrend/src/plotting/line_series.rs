@@ -0,0 +1,152 @@
+use crate::{
+    create_program, PlotBounds, PlotError, LINE_SERIES_FRAGMENT_SHADER, LINE_SERIES_VERTEX_SHADER,
+};
+use js_sys::Float32Array;
+use std::collections::VecDeque;
+use web_sys::{WebGl2RenderingContext, WebGlBuffer, WebGlProgram, WebGlVertexArrayObject};
+
+/// A line chart of up to `capacity` samples that can be appended to every frame.
+///
+/// The buffer is allocated once at full capacity. Once it is full, pushing a sample drops the
+/// oldest one, so the series scrolls like a live telemetry plot. Samples are uploaded lazily, the
+/// next time the series is drawn.
+#[derive(Debug)]
+pub struct LineSeries {
+    gl: WebGl2RenderingContext,
+    program: WebGlProgram,
+    vao: WebGlVertexArrayObject,
+    buffer: WebGlBuffer,
+    samples: VecDeque<[f32; 2]>,
+    capacity: usize,
+    color: [f32; 4],
+    dirty: bool,
+}
+
+impl LineSeries {
+    pub fn new(gl: &WebGl2RenderingContext, capacity: usize) -> Result<Self, PlotError> {
+        let capacity = capacity.max(2);
+        let program = create_program(
+            gl,
+            LINE_SERIES_VERTEX_SHADER,
+            LINE_SERIES_FRAGMENT_SHADER,
+            &[],
+            |error| PlotError::CompileShader {
+                program: "line series".to_string(),
+                error,
+            },
+            |error| PlotError::LinkProgram {
+                program: "line series".to_string(),
+                error,
+            },
+        )?;
+        let buffer = gl.create_buffer().ok_or(PlotError::CreateBuffer)?;
+        let vao = gl
+            .create_vertex_array()
+            .ok_or(PlotError::CreateVertexArray)?;
+        gl.bind_vertex_array(Some(&vao));
+        gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, Some(&buffer));
+        gl.buffer_data_with_i32(
+            WebGl2RenderingContext::ARRAY_BUFFER,
+            (capacity * 2 * 4) as i32,
+            WebGl2RenderingContext::DYNAMIC_DRAW,
+        );
+        gl.enable_vertex_attrib_array(0);
+        gl.vertex_attrib_pointer_with_i32(0, 2, WebGl2RenderingContext::FLOAT, false, 0, 0);
+        gl.bind_vertex_array(None);
+        gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, None);
+
+        Ok(Self {
+            gl: gl.clone(),
+            program,
+            vao,
+            buffer,
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+            color: [0.9, 0.4, 0.2, 1.0],
+            dirty: false,
+        })
+    }
+
+    /// Appends samples, in data units, dropping the oldest samples past the series' capacity
+    pub fn push(&mut self, samples: &[[f32; 2]]) -> &mut Self {
+        for sample in samples {
+            if self.samples.len() == self.capacity {
+                self.samples.pop_front();
+            }
+            self.samples.push_back(*sample);
+        }
+        self.dirty = true;
+        self
+    }
+
+    pub fn clear(&mut self) -> &mut Self {
+        self.samples.clear();
+        self.dirty = true;
+        self
+    }
+
+    pub fn set_color(&mut self, color: [f32; 4]) -> &mut Self {
+        self.color = color;
+        self
+    }
+
+    pub fn samples(&self) -> &VecDeque<[f32; 2]> {
+        &self.samples
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn color(&self) -> [f32; 4] {
+        self.color
+    }
+
+    /// Bounds that fit every sample currently in the series
+    pub fn fit_bounds(&self, padding: f32) -> PlotBounds {
+        let (front, back) = self.samples.as_slices();
+        PlotBounds::fit(&[front, back].concat(), padding)
+    }
+
+    /// Draws the series as a line strip into whatever framebuffer is currently bound
+    pub fn draw(&mut self, bounds: &PlotBounds) -> &mut Self {
+        let gl = &self.gl;
+        if self.dirty {
+            let (front, back) = self.samples.as_slices();
+            let data = Float32Array::from([front, back].concat().as_flattened());
+            gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, Some(&self.buffer));
+            gl.buffer_sub_data_with_i32_and_array_buffer_view(
+                WebGl2RenderingContext::ARRAY_BUFFER,
+                0,
+                &data,
+            );
+            gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, None);
+            self.dirty = false;
+        }
+        if self.samples.len() < 2 {
+            return self;
+        }
+
+        let [r, g, b, a] = self.color;
+        let location = |name| gl.get_uniform_location(&self.program, name);
+        gl.use_program(Some(&self.program));
+        gl.uniform4fv_with_f32_array(location("u_bounds").as_ref(), &bounds.uniform_value());
+        gl.uniform4f(location("u_color").as_ref(), r, g, b, a);
+        gl.bind_vertex_array(Some(&self.vao));
+        gl.draw_arrays(
+            WebGl2RenderingContext::LINE_STRIP,
+            0,
+            self.samples.len() as i32,
+        );
+        gl.bind_vertex_array(None);
+        self
+    }
+
+    /// Deletes every WebGL object owned by the series
+    pub fn delete(&self) {
+        let gl = &self.gl;
+        gl.delete_program(Some(&self.program));
+        gl.delete_vertex_array(Some(&self.vao));
+        gl.delete_buffer(Some(&self.buffer));
+    }
+}
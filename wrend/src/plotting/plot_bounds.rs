@@ -0,0 +1,102 @@
+/// The rectangle of data space shown by a plot, mapped onto the whole viewport
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct PlotBounds {
+    min: [f32; 2],
+    max: [f32; 2],
+}
+
+impl Default for PlotBounds {
+    /// Shows -1.0 to 1.0 on both axes, the same as clip space
+    fn default() -> Self {
+        Self::new([-1.0, -1.0], [1.0, 1.0])
+    }
+}
+
+impl PlotBounds {
+    pub fn new(min: [f32; 2], max: [f32; 2]) -> Self {
+        Self { min, max }
+    }
+
+    /// Smallest bounds containing every point, grown by `padding` (a fraction of each axis' span)
+    /// on all sides. Empty or degenerate data gets a span of 1.0 around it.
+    pub fn fit(points: &[[f32; 2]], padding: f32) -> Self {
+        let Some(first) = points.first() else {
+            return Self::default();
+        };
+        let (mut min, mut max) = (*first, *first);
+        for point in points {
+            for axis in 0..2 {
+                min[axis] = min[axis].min(point[axis]);
+                max[axis] = max[axis].max(point[axis]);
+            }
+        }
+        for axis in 0..2 {
+            let span = max[axis] - min[axis];
+            let margin = if span > 0.0 { span * padding } else { 0.5 };
+            min[axis] -= margin;
+            max[axis] += margin;
+        }
+        Self { min, max }
+    }
+
+    pub fn min(&self) -> [f32; 2] {
+        self.min
+    }
+
+    pub fn max(&self) -> [f32; 2] {
+        self.max
+    }
+
+    /// Moves the bounds by `delta`, in data units
+    pub fn pan(&mut self, delta: [f32; 2]) -> &mut Self {
+        let [dx, dy] = delta;
+        self.min = [self.min[0] + dx, self.min[1] + dy];
+        self.max = [self.max[0] + dx, self.max[1] + dy];
+        self
+    }
+
+    /// Scales the bounds around `center` (in data units): factors below 1.0 zoom in
+    pub fn zoom(&mut self, center: [f32; 2], factor: f32) -> &mut Self {
+        let scale = |[x, y]: [f32; 2]| {
+            [
+                center[0] + (x - center[0]) * factor,
+                center[1] + (y - center[1]) * factor,
+            ]
+        };
+        self.min = scale(self.min);
+        self.max = scale(self.max);
+        self
+    }
+
+    /// Where a point in data space lands in clip space
+    pub fn to_clip(&self, point: [f32; 2]) -> [f32; 2] {
+        let mut clip = [0.0; 2];
+        for axis in 0..2 {
+            clip[axis] =
+                (point[axis] - self.min[axis]) / (self.max[axis] - self.min[axis]) * 2.0 - 1.0;
+        }
+        clip
+    }
+
+    /// Value of the plotting shaders' `u_bounds` uniform: `[min_x, min_y, max_x, max_y]`
+    pub fn uniform_value(&self) -> [f32; 4] {
+        [self.min[0], self.min[1], self.max[0], self.max[1]]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PlotBounds;
+
+    #[test]
+    fn fits_and_maps_points() {
+        let bounds = PlotBounds::fit(&[[0.0, 10.0], [4.0, 20.0], [2.0, 15.0]], 0.0);
+        assert_eq!(bounds.uniform_value(), [0.0, 10.0, 4.0, 20.0]);
+        assert_eq!(bounds.to_clip([2.0, 20.0]), [0.0, 1.0]);
+
+        let mut bounds = PlotBounds::fit(&[[1.0, 1.0]], 0.1);
+        assert_eq!(bounds.uniform_value(), [0.5, 0.5, 1.5, 1.5]);
+        bounds.zoom([1.0, 1.0], 2.0).pan([1.0, 0.0]);
+        assert_eq!(bounds.uniform_value(), [1.0, 0.0, 3.0, 2.0]);
+    }
+}
@@ -0,0 +1,62 @@
+/// Draws each point of a [crate::ScatterPlot] at its data position, with its own size (in pixels) and color
+pub const SCATTER_PLOT_VERTEX_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+layout(location = 0) in vec2 a_position;
+layout(location = 1) in float a_size;
+layout(location = 2) in vec4 a_color;
+
+uniform vec4 u_bounds;
+
+out vec4 v_color;
+
+void main() {
+    vec2 clip = (a_position - u_bounds.xy) / (u_bounds.zw - u_bounds.xy) * 2.0 - 1.0;
+    gl_Position = vec4(clip, 0.0, 1.0);
+    gl_PointSize = a_size;
+    v_color = a_color;
+}
+"#;
+
+/// Antialiased round points
+pub const SCATTER_PLOT_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+in vec4 v_color;
+out vec4 out_color;
+
+void main() {
+    float distance_to_edge = 0.5 - length(gl_PointCoord - 0.5);
+    float coverage = clamp(distance_to_edge / fwidth(distance_to_edge), 0.0, 1.0);
+    if (coverage <= 0.0) {
+        discard;
+    }
+    out_color = vec4(v_color.rgb, v_color.a * coverage);
+}
+"#;
+
+/// Draws a [crate::LineSeries] as a line strip in data space
+pub const LINE_SERIES_VERTEX_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+layout(location = 0) in vec2 a_position;
+
+uniform vec4 u_bounds;
+
+void main() {
+    vec2 clip = (a_position - u_bounds.xy) / (u_bounds.zw - u_bounds.xy) * 2.0 - 1.0;
+    gl_Position = vec4(clip, 0.0, 1.0);
+}
+"#;
+
+pub const LINE_SERIES_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform vec4 u_color;
+
+out vec4 out_color;
+
+void main() {
+    out_color = u_color;
+}
+"#;
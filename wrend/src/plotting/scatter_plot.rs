@@ -0,0 +1,213 @@
+use crate::{
+    create_program, PlotBounds, PlotError, SCATTER_PLOT_FRAGMENT_SHADER, SCATTER_PLOT_VERTEX_SHADER,
+};
+use js_sys::Float32Array;
+use web_sys::{WebGl2RenderingContext, WebGlBuffer, WebGlProgram, WebGlVertexArrayObject};
+
+/// Draws a large set of points as antialiased circles, with optional per-point size and color.
+///
+/// Positions, sizes, and colors each live in their own buffer (at attribute locations 0, 1 and 2),
+/// so they can be replaced independently, or written to directly (e.g. by transform feedback)
+/// through [ScatterPlot::position_buffer] and friends. Points without their own size or color
+/// use [ScatterPlot::set_point_size] and [ScatterPlot::set_color].
+#[derive(Debug)]
+pub struct ScatterPlot {
+    gl: WebGl2RenderingContext,
+    program: WebGlProgram,
+    vao: WebGlVertexArrayObject,
+    position_buffer: WebGlBuffer,
+    size_buffer: WebGlBuffer,
+    color_buffer: WebGlBuffer,
+    count: u32,
+    has_sizes: bool,
+    has_colors: bool,
+    point_size: f32,
+    color: [f32; 4],
+}
+
+impl ScatterPlot {
+    const POSITION_LOCATION: u32 = 0;
+    const SIZE_LOCATION: u32 = 1;
+    const COLOR_LOCATION: u32 = 2;
+
+    pub fn new(gl: &WebGl2RenderingContext) -> Result<Self, PlotError> {
+        let program = create_program(
+            gl,
+            SCATTER_PLOT_VERTEX_SHADER,
+            SCATTER_PLOT_FRAGMENT_SHADER,
+            &[],
+            |error| PlotError::CompileShader {
+                program: "scatter plot".to_string(),
+                error,
+            },
+            |error| PlotError::LinkProgram {
+                program: "scatter plot".to_string(),
+                error,
+            },
+        )?;
+        let vao = gl
+            .create_vertex_array()
+            .ok_or(PlotError::CreateVertexArray)?;
+        let create_buffer = |location, size| -> Result<WebGlBuffer, PlotError> {
+            let buffer = gl.create_buffer().ok_or(PlotError::CreateBuffer)?;
+            gl.bind_vertex_array(Some(&vao));
+            gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, Some(&buffer));
+            gl.vertex_attrib_pointer_with_i32(
+                location,
+                size,
+                WebGl2RenderingContext::FLOAT,
+                false,
+                0,
+                0,
+            );
+            gl.bind_vertex_array(None);
+            gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, None);
+            Ok(buffer)
+        };
+        let position_buffer = create_buffer(Self::POSITION_LOCATION, 2)?;
+        let size_buffer = create_buffer(Self::SIZE_LOCATION, 1)?;
+        let color_buffer = create_buffer(Self::COLOR_LOCATION, 4)?;
+
+        Ok(Self {
+            gl: gl.clone(),
+            program,
+            vao,
+            position_buffer,
+            size_buffer,
+            color_buffer,
+            count: 0,
+            has_sizes: false,
+            has_colors: false,
+            point_size: 4.0,
+            color: [0.2, 0.5, 0.9, 1.0],
+        })
+    }
+
+    fn upload(&self, buffer: &WebGlBuffer, data: &[f32]) {
+        let gl = &self.gl;
+        gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, Some(buffer));
+        gl.buffer_data_with_array_buffer_view(
+            WebGl2RenderingContext::ARRAY_BUFFER,
+            &Float32Array::from(data),
+            WebGl2RenderingContext::DYNAMIC_DRAW,
+        );
+        gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, None);
+    }
+
+    /// Replaces every point's position, in data units
+    pub fn set_positions(&mut self, positions: &[[f32; 2]]) -> &mut Self {
+        self.upload(&self.position_buffer, positions.as_flattened());
+        self.count = positions.len() as u32;
+        self
+    }
+
+    /// Sizes of each point in pixels, or `None` to draw every point at [ScatterPlot::point_size]
+    pub fn set_sizes(&mut self, sizes: Option<&[f32]>) -> &mut Self {
+        if let Some(sizes) = sizes {
+            self.upload(&self.size_buffer, sizes);
+        }
+        self.has_sizes = sizes.is_some();
+        self
+    }
+
+    /// Colors of each point, or `None` to draw every point in [ScatterPlot::color]
+    pub fn set_colors(&mut self, colors: Option<&[[f32; 4]]>) -> &mut Self {
+        if let Some(colors) = colors {
+            self.upload(&self.color_buffer, colors.as_flattened());
+        }
+        self.has_colors = colors.is_some();
+        self
+    }
+
+    /// Draws `count` points from buffers that were filled elsewhere, without uploading anything.
+    /// Sizes and colors are read from their buffers when `has_sizes` and `has_colors` are set.
+    pub fn set_count(&mut self, count: u32, has_sizes: bool, has_colors: bool) -> &mut Self {
+        self.count = count;
+        self.has_sizes = has_sizes;
+        self.has_colors = has_colors;
+        self
+    }
+
+    pub fn set_point_size(&mut self, point_size: f32) -> &mut Self {
+        self.point_size = point_size;
+        self
+    }
+
+    pub fn set_color(&mut self, color: [f32; 4]) -> &mut Self {
+        self.color = color;
+        self
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    pub fn point_size(&self) -> f32 {
+        self.point_size
+    }
+
+    pub fn color(&self) -> [f32; 4] {
+        self.color
+    }
+
+    /// Buffer of `vec2` positions, read at attribute location 0
+    pub fn position_buffer(&self) -> &WebGlBuffer {
+        &self.position_buffer
+    }
+
+    /// Buffer of `float` sizes, read at attribute location 1
+    pub fn size_buffer(&self) -> &WebGlBuffer {
+        &self.size_buffer
+    }
+
+    /// Buffer of `vec4` colors, read at attribute location 2
+    pub fn color_buffer(&self) -> &WebGlBuffer {
+        &self.color_buffer
+    }
+
+    /// Draws every point into whatever framebuffer is currently bound, alpha blended
+    pub fn draw(&self, bounds: &PlotBounds) -> &Self {
+        let gl = &self.gl;
+        gl.use_program(Some(&self.program));
+        gl.uniform4fv_with_f32_array(
+            gl.get_uniform_location(&self.program, "u_bounds").as_ref(),
+            &bounds.uniform_value(),
+        );
+
+        gl.bind_vertex_array(Some(&self.vao));
+        gl.enable_vertex_attrib_array(Self::POSITION_LOCATION);
+        if self.has_sizes {
+            gl.enable_vertex_attrib_array(Self::SIZE_LOCATION);
+        } else {
+            gl.disable_vertex_attrib_array(Self::SIZE_LOCATION);
+            gl.vertex_attrib1f(Self::SIZE_LOCATION, self.point_size);
+        }
+        if self.has_colors {
+            gl.enable_vertex_attrib_array(Self::COLOR_LOCATION);
+        } else {
+            let [r, g, b, a] = self.color;
+            gl.disable_vertex_attrib_array(Self::COLOR_LOCATION);
+            gl.vertex_attrib4f(Self::COLOR_LOCATION, r, g, b, a);
+        }
+
+        gl.enable(WebGl2RenderingContext::BLEND);
+        gl.blend_func(
+            WebGl2RenderingContext::SRC_ALPHA,
+            WebGl2RenderingContext::ONE_MINUS_SRC_ALPHA,
+        );
+        gl.draw_arrays(WebGl2RenderingContext::POINTS, 0, self.count as i32);
+        gl.disable(WebGl2RenderingContext::BLEND);
+        gl.bind_vertex_array(None);
+        self
+    }
+
+    /// Deletes every WebGL object owned by the plot
+    pub fn delete(&self) {
+        let gl = &self.gl;
+        gl.delete_program(Some(&self.program));
+        gl.delete_vertex_array(Some(&self.vao));
+        for buffer in [&self.position_buffer, &self.size_buffer, &self.color_buffer] {
+            gl.delete_buffer(Some(buffer));
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod point_cloud;
+mod point_cloud_shaders;
+
+pub use point_cloud::*;
+pub use point_cloud_shaders::*;
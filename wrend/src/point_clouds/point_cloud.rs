@@ -0,0 +1,166 @@
+use crate::{AssetLoader, PointCloudError, VertexLayout};
+use js_sys::Uint8Array;
+use web_sys::{WebGl2RenderingContext, WebGlBuffer, WebGlVertexArrayObject};
+
+/// A GPU buffer of packed binary points (such as LiDAR scans), described by a [VertexLayout].
+///
+/// The buffer is allocated once, then filled in chunks with [PointCloud::append] or
+/// [PointCloud::append_bytes] (e.g. as a `fetch` body streams in), so huge clouds never need a
+/// second copy in memory and can be drawn while they load. Only whole points are drawn.
+///
+/// Draw it with any program whose attribute locations match the layout, such as
+/// [crate::POINT_CLOUD_VERTEX_SHADER] and [crate::POINT_CLOUD_FRAGMENT_SHADER].
+#[derive(Debug)]
+pub struct PointCloud {
+    gl: WebGl2RenderingContext,
+    buffer: WebGlBuffer,
+    vao: WebGlVertexArrayObject,
+    layout: VertexLayout,
+    capacity: usize,
+    uploaded: usize,
+}
+
+impl PointCloud {
+    /// Size of each upload made by [PointCloud::load]
+    pub const CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+    /// Allocates a buffer of `capacity` bytes and a VAO that reads it with `layout`
+    pub fn new(
+        gl: &WebGl2RenderingContext,
+        layout: VertexLayout,
+        capacity: usize,
+    ) -> Result<Self, PointCloudError> {
+        let buffer = gl.create_buffer().ok_or(PointCloudError::CreateBuffer)?;
+        let vao = gl
+            .create_vertex_array()
+            .ok_or(PointCloudError::CreateVertexArray)?;
+        gl.bind_vertex_array(Some(&vao));
+        gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, Some(&buffer));
+        gl.buffer_data_with_i32(
+            WebGl2RenderingContext::ARRAY_BUFFER,
+            capacity as i32,
+            WebGl2RenderingContext::STATIC_DRAW,
+        );
+        layout.apply(gl);
+        gl.bind_vertex_array(None);
+        gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, None);
+
+        Ok(Self {
+            gl: gl.clone(),
+            buffer,
+            vao,
+            layout,
+            capacity,
+            uploaded: 0,
+        })
+    }
+
+    /// Fetches a packed binary file and uploads it in chunks of [PointCloud::CHUNK_SIZE]
+    pub async fn load(
+        gl: &WebGl2RenderingContext,
+        asset_loader: &AssetLoader,
+        url: impl Into<String>,
+        layout: VertexLayout,
+    ) -> Result<Self, PointCloudError> {
+        let bytes = asset_loader.load_bytes(url).await?;
+        let mut point_cloud = Self::new(gl, layout, bytes.len())?;
+        for chunk in bytes.chunks(Self::CHUNK_SIZE) {
+            point_cloud.append_bytes(chunk)?;
+        }
+        Ok(point_cloud)
+    }
+
+    /// Uploads a chunk of JavaScript bytes (such as a chunk read from a `ReadableStream`) after
+    /// the bytes uploaded so far, without copying it into wasm memory
+    pub fn append(&mut self, chunk: &Uint8Array) -> Result<&mut Self, PointCloudError> {
+        let length = chunk.length() as usize;
+        self.reserve(length)?;
+        self.upload(|gl| {
+            gl.buffer_sub_data_with_i32_and_array_buffer_view(
+                WebGl2RenderingContext::ARRAY_BUFFER,
+                self.uploaded as i32,
+                chunk,
+            )
+        });
+        self.uploaded += length;
+        Ok(self)
+    }
+
+    /// Uploads a chunk of bytes after the bytes uploaded so far
+    pub fn append_bytes(&mut self, chunk: &[u8]) -> Result<&mut Self, PointCloudError> {
+        self.reserve(chunk.len())?;
+        self.upload(|gl| {
+            gl.buffer_sub_data_with_i32_and_u8_array(
+                WebGl2RenderingContext::ARRAY_BUFFER,
+                self.uploaded as i32,
+                chunk,
+            )
+        });
+        self.uploaded += chunk.len();
+        Ok(self)
+    }
+
+    fn reserve(&self, length: usize) -> Result<(), PointCloudError> {
+        let requested = self.uploaded + length;
+        if requested > self.capacity {
+            return Err(PointCloudError::CapacityExceeded {
+                capacity: self.capacity,
+                requested,
+            });
+        }
+        Ok(())
+    }
+
+    fn upload(&self, upload: impl FnOnce(&WebGl2RenderingContext)) {
+        let gl = &self.gl;
+        gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, Some(&self.buffer));
+        upload(gl);
+        gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, None);
+    }
+
+    /// Forgets every uploaded point, so the buffer can be refilled from the start
+    pub fn reset(&mut self) -> &mut Self {
+        self.uploaded = 0;
+        self
+    }
+
+    /// Number of whole points uploaded so far
+    pub fn point_count(&self) -> usize {
+        self.layout.vertex_count(self.uploaded)
+    }
+
+    /// Fraction of the buffer that has been filled, from 0.0 to 1.0
+    pub fn progress(&self) -> f32 {
+        if self.capacity == 0 {
+            return 1.0;
+        }
+        self.uploaded as f32 / self.capacity as f32
+    }
+
+    pub fn layout(&self) -> &VertexLayout {
+        &self.layout
+    }
+
+    pub fn buffer(&self) -> &WebGlBuffer {
+        &self.buffer
+    }
+
+    pub fn vao(&self) -> &WebGlVertexArrayObject {
+        &self.vao
+    }
+
+    /// Draws every uploaded point with the current program
+    pub fn draw(&self) -> &Self {
+        let gl = &self.gl;
+        gl.bind_vertex_array(Some(&self.vao));
+        gl.draw_arrays(WebGl2RenderingContext::POINTS, 0, self.point_count() as i32);
+        gl.bind_vertex_array(None);
+        self
+    }
+
+    /// Deletes the buffer and VAO
+    pub fn delete(&self) {
+        self.gl.delete_buffer(Some(&self.buffer));
+        self.gl.delete_vertex_array(Some(&self.vao));
+    }
+}
@@ -0,0 +1,71 @@
+/// Projects points (position at location 0, color at location 1) with `u_view` and
+/// `u_projection`, sizing them by distance: `u_point_size` world units at `u_viewport_height`
+/// pixels tall, clamped to `u_size_range` pixels
+pub const POINT_CLOUD_VERTEX_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+layout(location = 0) in vec3 a_position;
+layout(location = 1) in vec4 a_color;
+
+uniform mat4 u_view;
+uniform mat4 u_projection;
+uniform float u_point_size;
+uniform float u_viewport_height;
+uniform vec2 u_size_range;
+
+out vec4 v_color;
+
+void main() {
+    vec4 view_position = u_view * vec4(a_position, 1.0);
+    gl_Position = u_projection * view_position;
+    // projection[1][1] is cot(fov / 2) for perspective projections
+    float attenuated = u_point_size * u_projection[1][1] * u_viewport_height * 0.5 / max(-view_position.z, 1e-4);
+    gl_PointSize = clamp(attenuated, u_size_range.x, u_size_range.y);
+    v_color = a_color;
+}
+"#;
+
+/// Draws round, opaque points, writing a depth that can be read back for [POINT_CLOUD_EDL_GLSL]
+pub const POINT_CLOUD_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+in vec4 v_color;
+out vec4 out_color;
+
+void main() {
+    vec2 offset = gl_PointCoord - 0.5;
+    if (dot(offset, offset) > 0.25) {
+        discard;
+    }
+    out_color = vec4(v_color.rgb, 1.0);
+}
+"#;
+
+/// GLSL include for eye-dome lighting, which shades point clouds without normals by darkening
+/// points that are further away than their neighbors.
+///
+/// `edl_shade` takes a depth texture rendered alongside the cloud (with perspective depth between
+/// `near` and `far`) and returns a factor to multiply the color by.
+pub const POINT_CLOUD_EDL_GLSL: &str = r#"
+float edl_linear_depth(sampler2D depth_texture, vec2 uv, float near, float far) {
+    float depth = texture(depth_texture, uv).r;
+    if (depth >= 1.0) {
+        return far;
+    }
+    float z = depth * 2.0 - 1.0;
+    return 2.0 * near * far / (far + near - z * (far - near));
+}
+
+float edl_shade(sampler2D depth_texture, vec2 uv, float radius, float strength, float near, float far) {
+    vec2 texel = radius / vec2(textureSize(depth_texture, 0));
+    float center = log2(edl_linear_depth(depth_texture, uv, near, far));
+    float obscurance = 0.0;
+    for (int i = 0; i < 8; i++) {
+        float angle = float(i) * 0.78539816;
+        vec2 neighbor_uv = uv + vec2(cos(angle), sin(angle)) * texel;
+        float neighbor = log2(edl_linear_depth(depth_texture, neighbor_uv, near, far));
+        obscurance += max(0.0, center - neighbor);
+    }
+    return exp(-obscurance / 8.0 * strength);
+}
+"#;
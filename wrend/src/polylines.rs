@@ -0,0 +1,11 @@
+mod line_cap;
+mod line_join;
+mod polyline;
+mod polyline_mesh;
+mod polyline_style;
+
+pub use line_cap::*;
+pub use line_join::*;
+pub use polyline::*;
+pub use polyline_mesh::*;
+pub use polyline_style::*;
@@ -0,0 +1,11 @@
+/// How the open ends of a [crate::Polyline] are drawn
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum LineCap {
+    /// Ends exactly at the first and last points
+    #[default]
+    Butt,
+    /// Extends past each end by half the line's width
+    Square,
+    /// Ends in a half circle
+    Round,
+}
@@ -0,0 +1,11 @@
+/// How the outside corner between two segments of a [crate::Polyline] is filled
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum LineJoin {
+    /// Extends both edges until they meet, falling back to [LineJoin::Bevel] past the miter limit
+    #[default]
+    Miter,
+    /// Cuts the corner off with a single triangle
+    Bevel,
+    /// Rounds the corner with a fan of triangles
+    Round,
+}
@@ -0,0 +1,336 @@
+use crate::{LineCap, LineJoin, PolylineMesh, PolylineStyle};
+use std::f32::consts::PI;
+
+/// A 2D line through a list of points, optionally closed and with a width per point.
+///
+/// [Polyline::tessellate] turns it into triangles with the joins and caps of a [PolylineStyle],
+/// which can then be drawn with an ordinary fragment shader.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub struct Polyline {
+    points: Vec<[f32; 2]>,
+    widths: Option<Vec<f32>>,
+    closed: bool,
+}
+
+type Vec2 = [f32; 2];
+
+fn add(a: Vec2, b: Vec2) -> Vec2 {
+    [a[0] + b[0], a[1] + b[1]]
+}
+
+fn sub(a: Vec2, b: Vec2) -> Vec2 {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn scale(a: Vec2, s: f32) -> Vec2 {
+    [a[0] * s, a[1] * s]
+}
+
+fn length(a: Vec2) -> f32 {
+    a[0].hypot(a[1])
+}
+
+/// Left-hand normal of a unit direction
+fn normal(direction: Vec2) -> Vec2 {
+    [-direction[1], direction[0]]
+}
+
+impl Polyline {
+    pub fn new(points: impl Into<Vec<[f32; 2]>>) -> Self {
+        Self {
+            points: points.into(),
+            widths: None,
+            closed: false,
+        }
+    }
+
+    /// Scales the style's width at each point, interpolating along each segment.
+    /// Points without a width use 1.0.
+    pub fn set_widths(&mut self, widths: impl Into<Vec<f32>>) -> &mut Self {
+        self.widths = Some(widths.into());
+        self
+    }
+
+    /// Connects the last point back to the first, with a join instead of caps
+    pub fn set_closed(&mut self, closed: bool) -> &mut Self {
+        self.closed = closed;
+        self
+    }
+
+    pub fn points(&self) -> &[[f32; 2]] {
+        &self.points
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Triangulates the line. Consecutive duplicate points are ignored.
+    pub fn tessellate(&self, style: &PolylineStyle) -> PolylineMesh {
+        let mut mesh = PolylineMesh::default();
+        let mut points: Vec<(Vec2, f32)> = Vec::with_capacity(self.points.len());
+        for (i, &point) in self.points.iter().enumerate() {
+            let width_scale = self
+                .widths
+                .as_ref()
+                .and_then(|widths| widths.get(i).copied())
+                .unwrap_or(1.0);
+            let half_width = style.width() * width_scale * 0.5;
+            match points.last() {
+                Some((last, _)) if length(sub(point, *last)) <= f32::EPSILON => {}
+                _ => points.push((point, half_width)),
+            }
+        }
+        if self.closed
+            && points.len() > 2
+            && length(sub(points[0].0, points[points.len() - 1].0)) <= f32::EPSILON
+        {
+            points.pop();
+        }
+        if points.len() < 2 {
+            return mesh;
+        }
+        let closed = self.closed && points.len() > 2;
+        let segment_count = if closed {
+            points.len()
+        } else {
+            points.len() - 1
+        };
+
+        let mut distances = Vec::with_capacity(segment_count + 1);
+        let mut directions = Vec::with_capacity(segment_count);
+        let mut distance = 0.0;
+        for i in 0..segment_count {
+            let (from, _) = points[i];
+            let (to, _) = points[(i + 1) % points.len()];
+            let offset = sub(to, from);
+            let segment_length = length(offset);
+            distances.push(distance);
+            directions.push(scale(offset, 1.0 / segment_length));
+            distance += segment_length;
+        }
+        distances.push(distance);
+
+        for i in 0..segment_count {
+            let (from, from_width) = points[i];
+            let (to, to_width) = points[(i + 1) % points.len()];
+            let n = normal(directions[i]);
+            let a = mesh.push_vertex(add(from, scale(n, from_width)), distances[i], 1.0);
+            let b = mesh.push_vertex(sub(from, scale(n, from_width)), distances[i], -1.0);
+            let c = mesh.push_vertex(add(to, scale(n, to_width)), distances[i + 1], 1.0);
+            let d = mesh.push_vertex(sub(to, scale(n, to_width)), distances[i + 1], -1.0);
+            mesh.push_triangle(a, b, c);
+            mesh.push_triangle(c, b, d);
+        }
+
+        let joints = if closed {
+            0..segment_count
+        } else {
+            1..segment_count
+        };
+        for i in joints {
+            let incoming = directions[(i + segment_count - 1) % segment_count];
+            let outgoing = directions[i];
+            let (point, half_width) = points[i];
+            Self::join(
+                &mut mesh,
+                style,
+                point,
+                half_width,
+                distances[i],
+                incoming,
+                outgoing,
+            );
+        }
+
+        if !closed {
+            let last = points.len() - 1;
+            Self::cap(
+                &mut mesh,
+                style,
+                points[0].0,
+                points[0].1,
+                0.0,
+                scale(directions[0], -1.0),
+            );
+            Self::cap(
+                &mut mesh,
+                style,
+                points[last].0,
+                points[last].1,
+                distance,
+                directions[segment_count - 1],
+            );
+        }
+
+        mesh
+    }
+
+    /// Fills the outside corner at `point` between segments heading `incoming` and `outgoing`
+    fn join(
+        mesh: &mut PolylineMesh,
+        style: &PolylineStyle,
+        point: Vec2,
+        half_width: f32,
+        distance: f32,
+        incoming: Vec2,
+        outgoing: Vec2,
+    ) {
+        let cross = incoming[0] * outgoing[1] - incoming[1] * outgoing[0];
+        if cross.abs() <= 1e-6 && incoming[0] * outgoing[0] + incoming[1] * outgoing[1] > 0.0 {
+            return;
+        }
+        // the outside of a left turn is on the right
+        let side = if cross > 0.0 { -1.0 } else { 1.0 };
+        let from = scale(normal(incoming), side);
+        let to = scale(normal(outgoing), side);
+        let center = mesh.push_vertex(point, distance, 0.0);
+        let start = mesh.push_vertex(add(point, scale(from, half_width)), distance, side);
+        let end = mesh.push_vertex(add(point, scale(to, half_width)), distance, side);
+
+        match style.join() {
+            LineJoin::Miter => {
+                let bisector = add(from, to);
+                let bisector_length = length(bisector);
+                let miter_scale = if bisector_length > f32::EPSILON {
+                    let bisector = scale(bisector, 1.0 / bisector_length);
+                    1.0 / (bisector[0] * from[0] + bisector[1] * from[1])
+                } else {
+                    f32::INFINITY
+                };
+                if miter_scale <= style.miter_limit() {
+                    let tip = add(
+                        point,
+                        scale(bisector, miter_scale * half_width / bisector_length),
+                    );
+                    let tip = mesh.push_vertex(tip, distance, side);
+                    mesh.push_triangle(center, start, tip);
+                    mesh.push_triangle(center, tip, end);
+                } else {
+                    mesh.push_triangle(center, start, end);
+                }
+            }
+            LineJoin::Bevel => mesh.push_triangle(center, start, end),
+            LineJoin::Round => {
+                let start_angle = from[1].atan2(from[0]);
+                let mut sweep = to[1].atan2(to[0]) - start_angle;
+                if sweep > PI {
+                    sweep -= 2.0 * PI;
+                } else if sweep < -PI {
+                    sweep += 2.0 * PI;
+                }
+                Self::fan(
+                    mesh,
+                    style,
+                    point,
+                    half_width,
+                    distance,
+                    side,
+                    [center, start, end],
+                    start_angle,
+                    sweep,
+                );
+            }
+        }
+    }
+
+    /// Extends the end of the line at `point`, where `direction` points away from the line
+    fn cap(
+        mesh: &mut PolylineMesh,
+        style: &PolylineStyle,
+        point: Vec2,
+        half_width: f32,
+        distance: f32,
+        direction: Vec2,
+    ) {
+        let n = normal(direction);
+        match style.cap() {
+            LineCap::Butt => {}
+            LineCap::Square => {
+                let extension = scale(direction, half_width);
+                let a = mesh.push_vertex(add(point, scale(n, half_width)), distance, 1.0);
+                let b = mesh.push_vertex(sub(point, scale(n, half_width)), distance, -1.0);
+                let c = mesh.push_vertex(
+                    add(add(point, extension), scale(n, half_width)),
+                    distance,
+                    1.0,
+                );
+                let d = mesh.push_vertex(
+                    sub(add(point, extension), scale(n, half_width)),
+                    distance,
+                    -1.0,
+                );
+                mesh.push_triangle(a, b, c);
+                mesh.push_triangle(c, b, d);
+            }
+            LineCap::Round => {
+                let center = mesh.push_vertex(point, distance, 0.0);
+                let start = mesh.push_vertex(add(point, scale(n, half_width)), distance, 1.0);
+                let end = mesh.push_vertex(sub(point, scale(n, half_width)), distance, -1.0);
+                Self::fan(
+                    mesh,
+                    style,
+                    point,
+                    half_width,
+                    distance,
+                    1.0,
+                    [center, start, end],
+                    n[1].atan2(n[0]),
+                    -PI,
+                );
+            }
+        }
+    }
+
+    /// Triangles from `center` around an arc of `sweep` radians, between the existing vertices `start` and `end`
+    #[allow(clippy::too_many_arguments)]
+    fn fan(
+        mesh: &mut PolylineMesh,
+        style: &PolylineStyle,
+        point: Vec2,
+        radius: f32,
+        distance: f32,
+        side: f32,
+        [center, start, end]: [u32; 3],
+        start_angle: f32,
+        sweep: f32,
+    ) {
+        let segments = ((style.round_segments() as f32 * sweep.abs() / PI).ceil() as u32).max(1);
+        let mut previous = start;
+        for step in 1..segments {
+            let angle = start_angle + sweep * step as f32 / segments as f32;
+            let vertex = mesh.push_vertex(
+                add(point, [angle.cos() * radius, angle.sin() * radius]),
+                distance,
+                side,
+            );
+            mesh.push_triangle(center, previous, vertex);
+            previous = vertex;
+        }
+        mesh.push_triangle(center, previous, end);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LineCap, LineJoin, Polyline, PolylineStyle};
+
+    #[test]
+    fn tessellates_joins_and_caps() {
+        let mut style = PolylineStyle::new();
+        style.set_width(2.0).set_join(LineJoin::Miter);
+
+        let straight = Polyline::new(vec![[0.0, 0.0], [1.0, 0.0], [2.0, 0.0]]).tessellate(&style);
+        assert_eq!(straight.vertex_count(), 8);
+        assert_eq!(straight.index_count(), 12);
+
+        let corner = Polyline::new(vec![[0.0, 0.0], [2.0, 0.0], [2.0, 2.0]]).tessellate(&style);
+        assert_eq!(corner.index_count(), 12 + 6);
+        let tip = &corner.vertices()[corner.vertices().len() - 4..][..2];
+        assert!((tip[0] - 3.0).abs() < 1e-5 && (tip[1] + 1.0).abs() < 1e-5);
+
+        style.set_join(LineJoin::Bevel).set_cap(LineCap::Round);
+        let capped = Polyline::new(vec![[0.0, 0.0], [2.0, 0.0], [2.0, 2.0]]).tessellate(&style);
+        assert_eq!(capped.index_count(), 12 + 3 + 2 * 8 * 3);
+    }
+}
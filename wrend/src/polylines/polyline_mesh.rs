@@ -0,0 +1,102 @@
+use crate::{BufferCreateContext, BufferLink, DrawCall, Id};
+use js_sys::{Float32Array, Uint32Array};
+use std::rc::Rc;
+use web_sys::WebGl2RenderingContext;
+
+/// Triangles produced by [crate::Polyline::tessellate].
+///
+/// Each vertex is `[x, y, distance, side]`: its position, the distance along the line (for dashes
+/// and gradients), and which side of the line it is on, from -1.0 (right) to 1.0 (left).
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub struct PolylineMesh {
+    vertices: Vec<f32>,
+    indices: Vec<u32>,
+}
+
+impl PolylineMesh {
+    /// Number of floats per vertex in [PolylineMesh::vertices]
+    pub const VERTEX_SIZE: i32 = 4;
+
+    /// Interleaved vertex data. Distances are at byte offset 8 and sides at byte offset 12.
+    pub fn vertices(&self) -> &[f32] {
+        &self.vertices
+    }
+
+    /// Triangle indices, for use with `drawElements` and `UNSIGNED_INT`
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len() / Self::VERTEX_SIZE as usize
+    }
+
+    pub fn index_count(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// A link that uploads [PolylineMesh::vertices] into an `ARRAY_BUFFER`
+    pub fn vertex_buffer_link<BufferId: Id>(&self, buffer_id: BufferId) -> BufferLink<BufferId> {
+        let vertices = Rc::new(self.vertices.clone());
+        BufferLink::new(buffer_id, move |ctx: &BufferCreateContext| {
+            let gl = ctx.gl();
+            let buffer = gl.create_buffer().unwrap();
+            gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, Some(&buffer));
+            gl.buffer_data_with_array_buffer_view(
+                WebGl2RenderingContext::ARRAY_BUFFER,
+                &Float32Array::from(vertices.as_slice()),
+                WebGl2RenderingContext::STATIC_DRAW,
+            );
+            buffer
+        })
+    }
+
+    /// A link that uploads [PolylineMesh::indices] into an `ELEMENT_ARRAY_BUFFER`.
+    ///
+    /// The element buffer is recorded by whichever VAO is bound when the buffer is created.
+    pub fn index_buffer_link<BufferId: Id>(&self, buffer_id: BufferId) -> BufferLink<BufferId> {
+        let indices = Rc::new(self.indices.clone());
+        BufferLink::new(buffer_id, move |ctx: &BufferCreateContext| {
+            let gl = ctx.gl();
+            let buffer = gl.create_buffer().unwrap();
+            gl.bind_buffer(WebGl2RenderingContext::ELEMENT_ARRAY_BUFFER, Some(&buffer));
+            gl.buffer_data_with_array_buffer_view(
+                WebGl2RenderingContext::ELEMENT_ARRAY_BUFFER,
+                &Uint32Array::from(indices.as_slice()),
+                WebGl2RenderingContext::STATIC_DRAW,
+            );
+            buffer
+        })
+    }
+
+    /// A draw call for every triangle in the mesh. Set its program and VAO before executing it.
+    pub fn draw_call<
+        ProgramId: Id,
+        UniformId: Id,
+        TextureId: Id,
+        FramebufferId: Id,
+        VertexArrayObjectId: Id,
+    >(
+        &self,
+    ) -> DrawCall<ProgramId, UniformId, TextureId, FramebufferId, VertexArrayObjectId> {
+        let mut draw_call = DrawCall::new();
+        draw_call.elements(
+            WebGl2RenderingContext::TRIANGLES,
+            self.indices.len() as i32,
+            WebGl2RenderingContext::UNSIGNED_INT,
+            0,
+        );
+        draw_call
+    }
+
+    pub(crate) fn push_vertex(&mut self, position: [f32; 2], distance: f32, side: f32) -> u32 {
+        let index = self.vertex_count() as u32;
+        self.vertices
+            .extend_from_slice(&[position[0], position[1], distance, side]);
+        index
+    }
+
+    pub(crate) fn push_triangle(&mut self, a: u32, b: u32, c: u32) {
+        self.indices.extend_from_slice(&[a, b, c]);
+    }
+}
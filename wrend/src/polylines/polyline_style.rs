@@ -0,0 +1,77 @@
+use crate::{LineCap, LineJoin};
+
+/// How a [crate::Polyline] is tessellated
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct PolylineStyle {
+    width: f32,
+    join: LineJoin,
+    cap: LineCap,
+    miter_limit: f32,
+    round_segments: u32,
+}
+
+impl PolylineStyle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Width of the line, in the same units as its points. Scaled by each point's width, if any.
+    pub fn set_width(&mut self, width: f32) -> &mut Self {
+        self.width = width;
+        self
+    }
+
+    pub fn set_join(&mut self, join: LineJoin) -> &mut Self {
+        self.join = join;
+        self
+    }
+
+    pub fn set_cap(&mut self, cap: LineCap) -> &mut Self {
+        self.cap = cap;
+        self
+    }
+
+    /// Longest a miter can be, as a multiple of half the line's width, before it is beveled
+    pub fn set_miter_limit(&mut self, miter_limit: f32) -> &mut Self {
+        self.miter_limit = miter_limit;
+        self
+    }
+
+    /// Number of triangles used for a half circle in round joins and caps
+    pub fn set_round_segments(&mut self, round_segments: u32) -> &mut Self {
+        self.round_segments = round_segments.max(1);
+        self
+    }
+
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    pub fn join(&self) -> LineJoin {
+        self.join
+    }
+
+    pub fn cap(&self) -> LineCap {
+        self.cap
+    }
+
+    pub fn miter_limit(&self) -> f32 {
+        self.miter_limit
+    }
+
+    pub fn round_segments(&self) -> u32 {
+        self.round_segments
+    }
+}
+
+impl Default for PolylineStyle {
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            join: LineJoin::default(),
+            cap: LineCap::default(),
+            miter_limit: 4.0,
+            round_segments: 8,
+        }
+    }
+}
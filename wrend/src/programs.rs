@@ -1,7 +1,11 @@
 mod program_link;
+#[cfg(feature = "js")]
 mod program_link_builder_js;
+#[cfg(feature = "js")]
 mod program_link_js;
 
 pub use program_link::*;
+#[cfg(feature = "js")]
 pub use program_link_builder_js::*;
+#[cfg(feature = "js")]
 pub use program_link_js::*;
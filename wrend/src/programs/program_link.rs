@@ -1,9 +1,10 @@
-use crate::{
-    Id, ProgramLinkBuilderJsInner, ProgramLinkJs, ProgramLinkJsBuilder, ProgramLinkJsInner,
-};
+use crate::Id;
+#[cfg(feature = "js")]
+use crate::{ProgramLinkBuilderJsInner, ProgramLinkJs, ProgramLinkJsBuilder, ProgramLinkJsInner};
 use std::fmt::Debug;
 use std::hash::Hash;
 use thiserror::Error;
+#[cfg(feature = "js")]
 use wasm_bindgen::JsValue;
 
 /// This contains an id for a pair of shaders: one vertex shader and one fragment
@@ -14,6 +15,7 @@ pub struct ProgramLink<ProgramId: Id, VertexShaderId: Id, FragmentShaderId: Id>
     vertex_shader_id: VertexShaderId,
     fragment_shader_id: FragmentShaderId,
     transform_feedback_varyings: Vec<String>,
+    attribute_locations: Vec<(String, u32)>,
 }
 
 impl<ProgramId: Id, VertexShaderId: Id, FragmentShaderId: Id>
@@ -29,6 +31,7 @@ impl<ProgramId: Id, VertexShaderId: Id, FragmentShaderId: Id>
             vertex_shader_id,
             fragment_shader_id,
             transform_feedback_varyings: Default::default(),
+            attribute_locations: Default::default(),
         }
     }
 
@@ -48,9 +51,29 @@ impl<ProgramId: Id, VertexShaderId: Id, FragmentShaderId: Id>
         &self.transform_feedback_varyings
     }
 
+    /// Attribute locations (by attribute name) that are bound before this program is linked
+    pub fn attribute_locations(&self) -> &[(String, u32)] {
+        &self.attribute_locations
+    }
+
     pub fn builder() -> ProgramLinkBuilder<ProgramId, VertexShaderId, FragmentShaderId> {
         ProgramLinkBuilder::default()
     }
+
+    /// The same link with its ids replaced (used when scoping links to a namespace)
+    pub(crate) fn map_ids(
+        self,
+        program_id: impl Fn(&ProgramId) -> ProgramId,
+        vertex_shader_id: impl Fn(&VertexShaderId) -> VertexShaderId,
+        fragment_shader_id: impl Fn(&FragmentShaderId) -> FragmentShaderId,
+    ) -> Self {
+        Self {
+            program_id: program_id(&self.program_id),
+            vertex_shader_id: vertex_shader_id(&self.vertex_shader_id),
+            fragment_shader_id: fragment_shader_id(&self.fragment_shader_id),
+            ..self
+        }
+    }
 }
 
 impl<ProgramId: Id, VertexShaderId: Id, FragmentShaderId: Id> Hash
@@ -78,6 +101,7 @@ impl<ProgramId: Id, VertexShaderId: Id, FragmentShaderId: Id> Eq
 {
 }
 
+#[cfg(feature = "js")]
 impl From<ProgramLink<String, String, String>> for JsValue {
     fn from(program_link: ProgramLink<String, String, String>) -> Self {
         let js_program_link: ProgramLinkJs = program_link.into();
@@ -101,6 +125,7 @@ pub struct ProgramLinkBuilder<ProgramId: Id, VertexShaderId: Id, FragmentShaderI
     vertex_shader_id: Option<VertexShaderId>,
     fragment_shader_id: Option<FragmentShaderId>,
     transform_feedback_varyings: Vec<String>,
+    attribute_locations: Vec<(String, u32)>,
 }
 
 impl<ProgramId: Id, VertexShaderId: Id, FragmentShaderId: Id>
@@ -133,6 +158,21 @@ impl<ProgramId: Id, VertexShaderId: Id, FragmentShaderId: Id>
         self
     }
 
+    /// Fixes the location of an attribute, which is bound with `bindAttribLocation` before linking.
+    ///
+    /// Every program that declares a location for an attribute must declare the same one, so that
+    /// VAOs holding that attribute can be shared between them. Attributes without a declared
+    /// location are given the lowest location that no declared attribute uses.
+    pub fn add_attribute_location(
+        &mut self,
+        attribute_name: impl Into<String>,
+        location: u32,
+    ) -> &mut Self {
+        self.attribute_locations
+            .push((attribute_name.into(), location));
+        self
+    }
+
     pub fn build(
         self,
     ) -> Result<ProgramLink<ProgramId, VertexShaderId, FragmentShaderId>, ProgramLinkBuildError>
@@ -146,6 +186,7 @@ impl<ProgramId: Id, VertexShaderId: Id, FragmentShaderId: Id>
                 .fragment_shader_id
                 .ok_or(ProgramLinkBuildError::NoFragmentShaderId)?,
             transform_feedback_varyings: self.transform_feedback_varyings,
+            attribute_locations: self.attribute_locations,
         })
     }
 }
@@ -159,6 +200,7 @@ impl<ProgramId: Id, VertexShaderId: Id, FragmentShaderId: Id> Default
             vertex_shader_id: Default::default(),
             fragment_shader_id: Default::default(),
             transform_feedback_varyings: Default::default(),
+            attribute_locations: Default::default(),
         }
     }
 }
@@ -183,6 +225,7 @@ impl<ProgramId: Id, VertexShaderId: Id, FragmentShaderId: Id> PartialEq
     }
 }
 
+#[cfg(feature = "js")]
 impl From<ProgramLinkBuilderJsInner> for JsValue {
     fn from(program_link_builder: ProgramLinkBuilderJsInner) -> Self {
         let js_program_link_builder: ProgramLinkJsBuilder = program_link_builder.into();
@@ -190,6 +233,7 @@ impl From<ProgramLinkBuilderJsInner> for JsValue {
     }
 }
 
+#[cfg(feature = "js")]
 impl From<ProgramLinkJs> for ProgramLinkJsInner {
     fn from(js_program_link: ProgramLinkJs) -> Self {
         js_program_link.into_inner()
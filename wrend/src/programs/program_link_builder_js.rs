@@ -42,6 +42,12 @@ impl ProgramLinkJsBuilder {
             .set_transform_feedback_varyings(transform_feedback_varyings);
     }
 
+    #[wasm_bindgen(js_name = addAttributeLocation)]
+    pub fn add_attribute_location(&mut self, attribute_name: String, location: u32) {
+        self.deref_mut()
+            .add_attribute_location(attribute_name, location);
+    }
+
     pub fn build(self) -> Result<ProgramLinkJs, String> {
         self.0
             .build()
@@ -0,0 +1,12 @@
+mod quality_hint_callback;
+mod quality_hint_listeners;
+mod quality_hint_options;
+mod quality_hints;
+mod quality_monitor;
+
+pub(crate) use quality_hint_listeners::*;
+pub(crate) use quality_monitor::*;
+
+pub use quality_hint_callback::*;
+pub use quality_hint_options::*;
+pub use quality_hints::*;
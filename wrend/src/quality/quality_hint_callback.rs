@@ -0,0 +1,45 @@
+use crate::{Callback, QualityHints};
+use js_sys::Function;
+use std::fmt::Debug;
+use std::{ops::Deref, rc::Rc};
+
+/// Called by a [crate::Renderer] whenever its [QualityHints] change.
+///
+/// JavaScript callbacks receive an object of the form
+/// `{ batteryLevel, charging, devicePixelRatio, lowBattery, resolutionScale, reduced }`.
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd)]
+pub struct QualityHintCallback(Callback<dyn Fn(QualityHints), Function>);
+
+impl Deref for QualityHintCallback {
+    type Target = Callback<dyn Fn(QualityHints), Function>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for QualityHintCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("QualityHintCallback").field(&self.0).finish()
+    }
+}
+
+impl<F: Fn(QualityHints) + 'static> From<F> for QualityHintCallback {
+    fn from(callback: F) -> Self {
+        Self(Callback::new_rs(
+            Rc::new(callback) as Rc<dyn Fn(QualityHints)>
+        ))
+    }
+}
+
+impl<F: Fn(QualityHints) + 'static> From<Rc<F>> for QualityHintCallback {
+    fn from(callback: Rc<F>) -> Self {
+        Self(Callback::new_rs(callback as Rc<dyn Fn(QualityHints)>))
+    }
+}
+
+impl From<Function> for QualityHintCallback {
+    fn from(callback: Function) -> Self {
+        Self(Callback::new_js(callback))
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{update_quality_monitor, Listener, QualityMonitor};
+use std::{cell::RefCell, fmt::Debug, rc::Rc};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{window, BatteryManager, Window};
+
+/// Keeps a [QualityMonitor] up to date with the battery (via `navigator.getBattery`, where
+/// supported) and the device pixel ratio (which changes with zoom or when moving the window
+/// between displays, and always fires a `resize`). Listeners are removed when dropped.
+pub(crate) struct QualityHintListeners {
+    _resize_listener: Listener<Window>,
+    battery_listeners: Rc<RefCell<Vec<Listener<BatteryManager>>>>,
+}
+
+impl QualityHintListeners {
+    pub(crate) fn new(quality_monitor: Rc<RefCell<QualityMonitor>>) -> Result<Self, JsValue> {
+        let window = window().ok_or_else(|| JsValue::from_str("No window available"))?;
+
+        update_quality_monitor(&quality_monitor, |quality_monitor| {
+            quality_monitor.set_device_pixel_ratio(window.device_pixel_ratio())
+        });
+        let resize_listener = {
+            let quality_monitor = Rc::clone(&quality_monitor);
+            let resize_window = window.clone();
+            Listener::new(window.clone(), "resize", move |_: JsValue| {
+                update_quality_monitor(&quality_monitor, |quality_monitor| {
+                    quality_monitor.set_device_pixel_ratio(resize_window.device_pixel_ratio())
+                });
+            })
+        };
+
+        let battery_listeners = Rc::new(RefCell::new(Vec::new()));
+        let navigator = window.navigator();
+        let get_battery = js_sys::Reflect::get(&navigator, &JsValue::from_str("getBattery"))
+            .ok()
+            .and_then(|get_battery| get_battery.dyn_into::<js_sys::Function>().ok());
+        if let Some(get_battery) = get_battery {
+            let battery_listeners = Rc::clone(&battery_listeners);
+            wasm_bindgen_futures::spawn_local(async move {
+                let battery = match get_battery.call0(&navigator) {
+                    Ok(promise) => JsFuture::from(js_sys::Promise::from(promise)).await,
+                    Err(err) => Err(err),
+                };
+                let battery: BatteryManager = match battery {
+                    Ok(battery) => battery.unchecked_into(),
+                    Err(err) => {
+                        log::warn!("Could not read the battery status: {err:?}");
+                        return;
+                    }
+                };
+
+                let update = {
+                    let battery = battery.clone();
+                    move || {
+                        update_quality_monitor(&quality_monitor, |quality_monitor| {
+                            quality_monitor.set_battery(battery.level(), battery.charging())
+                        })
+                    }
+                };
+                update();
+                // the listeners were dropped before the battery status arrived
+                if Rc::strong_count(&battery_listeners) == 1 {
+                    return;
+                }
+                let update = Rc::new(update);
+                let listeners = ["levelchange", "chargingchange"].map(|name| {
+                    let update = Rc::clone(&update);
+                    Listener::new(battery.clone(), name, move |_: JsValue| update())
+                });
+                battery_listeners.borrow_mut().extend(listeners);
+            });
+        }
+
+        Ok(Self {
+            _resize_listener: resize_listener,
+            battery_listeners,
+        })
+    }
+}
+
+impl Debug for QualityHintListeners {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QualityHintListeners")
+            .field("battery_listeners", &self.battery_listeners.borrow().len())
+            .finish()
+    }
+}
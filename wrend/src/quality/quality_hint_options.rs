@@ -0,0 +1,59 @@
+/// When a [crate::Renderer] should suggest rendering at reduced quality to save power.
+/// Enable it with [crate::Renderer::set_quality_hints].
+///
+/// Browsers don't expose the operating system's battery saver mode, so a low battery that isn't
+/// charging is used in its place.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct QualityHintOptions {
+    low_battery_level: f64,
+    low_battery_resolution_scale: f64,
+    max_device_pixel_ratio: Option<f64>,
+}
+
+impl QualityHintOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Battery level in `[0, 1]` at or below which quality is reduced while discharging. Defaults to `0.2`
+    pub fn low_battery_level(&self) -> f64 {
+        self.low_battery_level
+    }
+
+    pub fn set_low_battery_level(&mut self, low_battery_level: f64) -> &mut Self {
+        self.low_battery_level = low_battery_level.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Resolution scale suggested while the battery is low. Defaults to `0.5`
+    pub fn low_battery_resolution_scale(&self) -> f64 {
+        self.low_battery_resolution_scale
+    }
+
+    pub fn set_low_battery_resolution_scale(&mut self, resolution_scale: f64) -> &mut Self {
+        self.low_battery_resolution_scale = resolution_scale.clamp(f64::EPSILON, 1.0);
+        self
+    }
+
+    /// Device pixel ratio above which the resolution scale is reduced, so that e.g. a 3x phone
+    /// display renders at most 2 pixels per CSS pixel. Unlimited by default
+    pub fn max_device_pixel_ratio(&self) -> Option<f64> {
+        self.max_device_pixel_ratio
+    }
+
+    pub fn set_max_device_pixel_ratio(&mut self, max_device_pixel_ratio: Option<f64>) -> &mut Self {
+        self.max_device_pixel_ratio = max_device_pixel_ratio
+            .map(|max_device_pixel_ratio| max_device_pixel_ratio.max(f64::EPSILON));
+        self
+    }
+}
+
+impl Default for QualityHintOptions {
+    fn default() -> Self {
+        Self {
+            low_battery_level: 0.2,
+            low_battery_resolution_scale: 0.5,
+            max_device_pixel_ratio: None,
+        }
+    }
+}
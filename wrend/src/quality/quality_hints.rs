@@ -0,0 +1,121 @@
+use crate::QualityHintOptions;
+use wasm_bindgen::JsValue;
+
+/// The device's power state and the quality a [crate::Renderer] suggests rendering at because of it.
+///
+/// Apply [QualityHints::resolution_scale] when sizing the canvas or render targets, and use
+/// [QualityHints::is_reduced] to show a "reduced quality" indicator.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct QualityHints {
+    battery_level: Option<f64>,
+    charging: Option<bool>,
+    device_pixel_ratio: f64,
+    low_battery: bool,
+    resolution_scale: f64,
+}
+
+impl QualityHints {
+    /// Computes hints for the given battery state (`None` where the Battery Status API is
+    /// unavailable) and device pixel ratio
+    pub fn new(
+        options: &QualityHintOptions,
+        battery_level: Option<f64>,
+        charging: Option<bool>,
+        device_pixel_ratio: f64,
+    ) -> Self {
+        let low_battery = charging == Some(false)
+            && battery_level.is_some_and(|level| level <= options.low_battery_level());
+
+        let mut resolution_scale = 1.0;
+        if low_battery {
+            resolution_scale *= options.low_battery_resolution_scale();
+        }
+        if let Some(max_device_pixel_ratio) = options.max_device_pixel_ratio() {
+            if device_pixel_ratio > max_device_pixel_ratio {
+                resolution_scale *= max_device_pixel_ratio / device_pixel_ratio;
+            }
+        }
+
+        Self {
+            battery_level,
+            charging,
+            device_pixel_ratio,
+            low_battery,
+            resolution_scale,
+        }
+    }
+
+    /// Battery level in `[0, 1]`, if known
+    pub fn battery_level(&self) -> Option<f64> {
+        self.battery_level
+    }
+
+    /// Whether the battery is charging, if known
+    pub fn charging(&self) -> Option<bool> {
+        self.charging
+    }
+
+    pub fn device_pixel_ratio(&self) -> f64 {
+        self.device_pixel_ratio
+    }
+
+    /// Whether the battery is low and discharging
+    pub fn low_battery(&self) -> bool {
+        self.low_battery
+    }
+
+    /// Suggested multiplier for the render resolution, in `(0, 1]`
+    pub fn resolution_scale(&self) -> f64 {
+        self.resolution_scale
+    }
+
+    /// Whether the suggested quality is lower than full quality
+    pub fn is_reduced(&self) -> bool {
+        self.resolution_scale < 1.0
+    }
+}
+
+impl Default for QualityHints {
+    fn default() -> Self {
+        Self::new(&QualityHintOptions::default(), None, None, 1.0)
+    }
+}
+
+impl From<QualityHints> for JsValue {
+    fn from(hints: QualityHints) -> Self {
+        let or_null = |value: Option<String>| value.unwrap_or_else(|| "null".to_string());
+        js_sys::JSON::parse(&format!(
+            r#"{{"batteryLevel":{},"charging":{},"devicePixelRatio":{},"lowBattery":{},"resolutionScale":{},"reduced":{}}}"#,
+            or_null(hints.battery_level.map(|level| level.to_string())),
+            or_null(hints.charging.map(|charging| charging.to_string())),
+            hints.device_pixel_ratio,
+            hints.low_battery,
+            hints.resolution_scale,
+            hints.is_reduced(),
+        ))
+        .unwrap_or(JsValue::NULL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QualityHints;
+    use crate::QualityHintOptions;
+
+    #[test]
+    fn reduces_resolution_on_low_battery_and_high_dpr() {
+        let mut options = QualityHintOptions::new();
+        options.set_max_device_pixel_ratio(Some(2.0));
+
+        let hints = QualityHints::new(&options, Some(0.8), Some(false), 2.0);
+        assert!(!hints.is_reduced());
+
+        // charging, or an unknown charging state, never counts as low battery
+        assert!(!QualityHints::new(&options, Some(0.1), Some(true), 1.0).is_reduced());
+        assert!(!QualityHints::new(&options, Some(0.1), None, 1.0).is_reduced());
+
+        let hints = QualityHints::new(&options, Some(0.1), Some(false), 3.0);
+        assert!(hints.low_battery());
+        assert!((hints.resolution_scale() - 0.5 * 2.0 / 3.0).abs() < 1e-9);
+    }
+}
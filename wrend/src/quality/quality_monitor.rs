@@ -0,0 +1,85 @@
+use crate::{QualityHintCallback, QualityHintOptions, QualityHints};
+use std::cell::RefCell;
+
+/// Holds the latest battery state and device pixel ratio and recomputes [QualityHints] from them
+#[derive(Debug, Clone, Default)]
+pub(crate) struct QualityMonitor {
+    options: Option<QualityHintOptions>,
+    battery_level: Option<f64>,
+    charging: Option<bool>,
+    device_pixel_ratio: Option<f64>,
+    hints: QualityHints,
+    callback: Option<QualityHintCallback>,
+}
+
+impl QualityMonitor {
+    pub(crate) fn options(&self) -> Option<QualityHintOptions> {
+        self.options
+    }
+
+    /// Returns the new hints if they changed
+    pub(crate) fn set_options(
+        &mut self,
+        options: Option<QualityHintOptions>,
+    ) -> Option<QualityHints> {
+        self.options = options;
+        self.update()
+    }
+
+    pub(crate) fn hints(&self) -> QualityHints {
+        self.hints
+    }
+
+    pub(crate) fn callback(&self) -> Option<QualityHintCallback> {
+        self.callback.clone()
+    }
+
+    pub(crate) fn set_callback(&mut self, callback: Option<QualityHintCallback>) {
+        self.callback = callback;
+    }
+
+    /// Returns the new hints if they changed
+    pub(crate) fn set_battery(&mut self, level: f64, charging: bool) -> Option<QualityHints> {
+        self.battery_level = Some(level);
+        self.charging = Some(charging);
+        self.update()
+    }
+
+    /// Returns the new hints if they changed
+    pub(crate) fn set_device_pixel_ratio(
+        &mut self,
+        device_pixel_ratio: f64,
+    ) -> Option<QualityHints> {
+        self.device_pixel_ratio = Some(device_pixel_ratio);
+        self.update()
+    }
+
+    fn update(&mut self) -> Option<QualityHints> {
+        let hints = match self.options {
+            Some(options) => QualityHints::new(
+                &options,
+                self.battery_level,
+                self.charging,
+                self.device_pixel_ratio.unwrap_or(1.0),
+            ),
+            None => QualityHints::default(),
+        };
+        if hints == self.hints {
+            return None;
+        }
+        self.hints = hints;
+        Some(hints)
+    }
+}
+
+/// Applies a change, then calls the quality hint callback if the hints changed
+pub(crate) fn update_quality_monitor(
+    quality_monitor: &RefCell<QualityMonitor>,
+    change: impl FnOnce(&mut QualityMonitor) -> Option<QualityHints>,
+) {
+    let changed = change(&mut quality_monitor.borrow_mut());
+    let callback = quality_monitor.borrow().callback();
+    if let (Some(hints), Some(callback)) = (changed, callback) {
+        callback.call_with_js_arg(hints);
+    }
+}
@@ -1,7 +1,12 @@
+mod audio_node_track;
 mod recording_data;
+mod recording_state_callback;
 mod recording_url;
 
 pub(crate) use recording_data::*;
 
+pub use audio_node_track::*;
+pub use recording_state_callback::*;
+
 // contains only functions, so should be accessed via it's module
 pub(crate) mod recording_handlers;
@@ -0,0 +1,22 @@
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{AudioContext, AudioNode, MediaStreamTrack};
+
+/// Routes the output of an `AudioNode` into a `MediaStreamTrack` that can be mixed into
+/// recordings with [crate::Renderer::initialize_recorder_with_audio].
+///
+/// The node stays connected to its other outputs. Fails if the node doesn't belong to a
+/// realtime `AudioContext` (e.g. it was created by an `OfflineAudioContext`).
+pub fn audio_node_track(audio_node: &AudioNode) -> Result<MediaStreamTrack, JsValue> {
+    let audio_context: AudioContext = audio_node.context().dyn_into().map_err(|_| {
+        JsValue::from_str("Audio node must belong to a realtime AudioContext to be recorded")
+    })?;
+    let destination = audio_context.create_media_stream_destination()?;
+    audio_node.connect_with_audio_node(&destination)?;
+
+    destination
+        .stream()
+        .get_audio_tracks()
+        .get(0)
+        .dyn_into()
+        .map_err(|_| JsValue::from_str("MediaStreamAudioDestinationNode has no audio track"))
+}
@@ -1,11 +1,11 @@
-use crate::Listener;
-use js_sys::{Array, Uint8Array};
+use crate::{download_blob, Listener, RecordingStateCallback};
+use js_sys::{Array, Function, Uint8Array};
 use log::info;
 use std::{any::Any, ops::Deref};
 use wasm_bindgen::{convert::FromWasmAbi, prelude::wasm_bindgen, JsCast};
 use web_sys::{
-    Blob, BlobPropertyBag, EventTarget, HtmlAnchorElement, HtmlCanvasElement, MediaRecorder,
-    MediaRecorderOptions, MediaStream, Url,
+    Blob, BlobPropertyBag, EventTarget, HtmlCanvasElement, MediaRecorder, MediaRecorderOptions,
+    MediaStream, MediaStreamTrack,
 };
 
 #[wasm_bindgen(module = "/src/recording/captureStream.js")]
@@ -21,6 +21,13 @@ pub(crate) struct RecordingData {
     /// It is only necessary to store the Listener, which removes event listeners when it is dropped
     listeners: Vec<Box<dyn Any>>,
     is_recording: bool,
+    /// Whether the finished recording should be downloaded automatically once it stops
+    auto_download: bool,
+    /// Set when a stop has been requested, until the final `dataavailable` event has been handled
+    awaiting_final_data: bool,
+    state_callbacks: Vec<RecordingStateCallback>,
+    /// Promise `resolve` functions waiting on the final Blob of the current recording
+    pending_blob_resolvers: Vec<Function>,
 }
 
 impl RecordingData {
@@ -29,19 +36,23 @@ impl RecordingData {
     pub const VIDEO_TYPE: &'static str = "video/webm";
 
     /// Creates a `MediaStream` and `MediaRecorder` that is ready to being recording video
-    /// from the canvas.
-    pub fn new(canvas: impl AsRef<HtmlCanvasElement>) -> Self {
+    /// from the canvas, mixed with any audio tracks supplied.
+    pub fn new(canvas: impl AsRef<HtmlCanvasElement>, audio_tracks: &[MediaStreamTrack]) -> Self {
         let canvas = canvas.as_ref();
         let media_stream = captureStreamFromCanvas(canvas.clone());
+        for audio_track in audio_tracks {
+            media_stream.add_track(audio_track);
+        }
+        let audio_codec = if audio_tracks.is_empty() { "" } else { ",opus" };
 
         // see https://developer.mozilla.org/en-US/docs/Web/Media/Formats/Video_codecs#codec_details
         // this Codec is not ideal, but it's one of the few that's broadly supported by both Chrome and Firefox
         let mut media_recorder_options = MediaRecorderOptions::new();
-        let mime_type_vp9 = format!("{}; codecs=vp9", Self::VIDEO_TYPE);
+        let mime_type_vp9 = format!("{}; codecs=vp9{audio_codec}", Self::VIDEO_TYPE);
         let mime_type = if MediaRecorder::is_type_supported(&mime_type_vp9) {
             mime_type_vp9
         } else {
-            format!("{}; codecs=vp8", Self::VIDEO_TYPE)
+            format!("{}; codecs=vp8{audio_codec}", Self::VIDEO_TYPE)
         };
         media_recorder_options.mime_type(&mime_type);
         media_recorder_options.bits_per_second(u32::MAX);
@@ -59,44 +70,34 @@ impl RecordingData {
             recorded_chunks: Vec::new(),
             listeners: Vec::new(),
             is_recording: false,
+            auto_download: true,
+            awaiting_final_data: false,
+            state_callbacks: Vec::new(),
+            pending_blob_resolvers: Vec::new(),
         }
     }
 
-    pub fn download_video(&self) {
-        let window = web_sys::window().unwrap();
-        let document = window.document().unwrap();
-        let body = document.body().unwrap();
-        let a: HtmlAnchorElement = document.create_element("a").unwrap().dyn_into().unwrap();
-        a.style().set_css_text("display: none;");
-        a.set_download("canvas.webm");
-        body.append_child(&a).unwrap();
+    /// Builds a Blob out of all data that has been recorded so far
+    pub fn recording_blob(&self) -> Blob {
         let recorded_chunks = self.recorded_chunks().as_slice();
 
         // data must be passed to blob constructor inside of a javascript array
         let blob_parts = Array::new_with_length(1);
 
-        // it is unsafe to get a raw view into WebAssembly memory, but because this memory gets immediately
-        // used, downloaded, and then view is discarded, it is safe so long as no new allocations are
+        // it is unsafe to get a raw view into WebAssembly memory, but because the Blob constructor
+        // copies the data immediately, it is safe so long as no new allocations are
         // made in between acquiring the view and using it
         let uint8_array = unsafe { Uint8Array::view(recorded_chunks) };
         blob_parts.set(0, uint8_array.dyn_into().unwrap());
 
         let mut blob_property_bag = BlobPropertyBag::new();
         blob_property_bag.type_(RecordingData::VIDEO_TYPE);
-        let blob = Blob::new_with_buffer_source_sequence_and_options(
-            blob_parts.as_ref(),
-            &blob_property_bag,
-        )
-        .unwrap();
-
-        let url = Url::create_object_url_with_blob(&blob).unwrap();
-
-        a.set_href(&url);
-        a.click();
+        Blob::new_with_buffer_source_sequence_and_options(blob_parts.as_ref(), &blob_property_bag)
+            .unwrap()
+    }
 
-        // release url from window memory when done to prevent memory leak
-        // (this does not get released automatically, unlike most of web memory)
-        Url::revoke_object_url(&url).unwrap();
+    pub fn download_video(&self) {
+        download_blob(&self.recording_blob(), "canvas.webm").unwrap();
     }
 
     pub fn add_event_listener<
@@ -138,4 +139,36 @@ impl RecordingData {
     pub fn set_is_recording(&mut self, is_recording: bool) {
         self.is_recording = is_recording;
     }
+
+    pub fn auto_download(&self) -> bool {
+        self.auto_download
+    }
+
+    pub fn set_auto_download(&mut self, auto_download: bool) {
+        self.auto_download = auto_download;
+    }
+
+    pub fn awaiting_final_data(&self) -> bool {
+        self.awaiting_final_data
+    }
+
+    pub fn set_awaiting_final_data(&mut self, awaiting_final_data: bool) {
+        self.awaiting_final_data = awaiting_final_data;
+    }
+
+    pub fn add_state_callback(&mut self, callback: RecordingStateCallback) {
+        self.state_callbacks.push(callback);
+    }
+
+    pub fn state_callbacks(&self) -> &Vec<RecordingStateCallback> {
+        &self.state_callbacks
+    }
+
+    pub fn add_pending_blob_resolver(&mut self, resolve: Function) {
+        self.pending_blob_resolvers.push(resolve);
+    }
+
+    pub fn take_pending_blob_resolvers(&mut self) -> Vec<Function> {
+        std::mem::take(&mut self.pending_blob_resolvers)
+    }
 }
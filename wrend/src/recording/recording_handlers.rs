@@ -2,17 +2,36 @@ use crate::{Listener, RecordingData};
 use js_sys::{ArrayBuffer, Uint8Array};
 use log::{error, info};
 use std::{cell::RefCell, rc::Rc};
-use wasm_bindgen::JsCast;
+use wasm_bindgen::{JsCast, JsValue};
 use wasm_bindgen_futures::JsFuture;
 use web_sys::{BlobEvent, Event, MediaRecorder, MediaRecorderErrorEvent};
 
+/// Updates the recording state and notifies any state callbacks if it changed.
+///
+/// Callbacks are run after the `RefCell` borrow is released, so that they are free
+/// to query the recorder themselves.
+fn update_is_recording(recording_data: &Rc<RefCell<RecordingData>>, is_recording: bool) {
+    let callbacks = {
+        let mut recording_data = recording_data.borrow_mut();
+        if recording_data.is_recording() == is_recording {
+            return;
+        }
+        recording_data.set_is_recording(is_recording);
+        recording_data.state_callbacks().clone()
+    };
+
+    for callback in callbacks {
+        callback.call_with_js_arg(is_recording);
+    }
+}
+
 pub(crate) fn make_handle_start(
     media_recorder: MediaRecorder,
     recording_data: Rc<RefCell<RecordingData>>,
 ) -> Listener<MediaRecorder, Event> {
     Listener::new(media_recorder, "start", move |_: Event| {
         info!("Recording started");
-        recording_data.borrow_mut().set_is_recording(true);
+        update_is_recording(&recording_data, true);
     })
 }
 
@@ -22,7 +41,7 @@ pub(crate) fn make_handle_stop(
 ) -> Listener<MediaRecorder, Event> {
     Listener::new(media_recorder, "stop", move |_: Event| {
         info!("Recording stopped");
-        recording_data.borrow_mut().set_is_recording(false);
+        update_is_recording(&recording_data, false);
     })
 }
 
@@ -53,7 +72,21 @@ pub(crate) fn make_handle_dataavailable(
                 // means that some data can accidentally be omitted if the file is downloaded before this final
                 // `dataavailable` event was emitted after a `stop`.
                 if !recording_data.borrow().is_recording() {
-                    recording_data.borrow().download_video();
+                    recording_data.borrow_mut().set_awaiting_final_data(false);
+
+                    if recording_data.borrow().auto_download() {
+                        recording_data.borrow().download_video();
+                    }
+
+                    let resolvers = recording_data.borrow_mut().take_pending_blob_resolvers();
+                    if !resolvers.is_empty() {
+                        let blob = recording_data.borrow().recording_blob();
+                        for resolve in resolvers {
+                            if let Err(err) = resolve.call1(&JsValue::NULL, &blob) {
+                                error!("Error resolving recording Blob: {err:?}");
+                            }
+                        }
+                    }
                 }
             })
         }
@@ -69,7 +102,7 @@ pub(crate) fn make_handle_error(
         "error",
         move |e: MediaRecorderErrorEvent| {
             error!("Error occurred while recording video: {:?}", e);
-            recording_data.borrow_mut().set_is_recording(false);
+            update_is_recording(&recording_data, false);
         },
     )
 }
@@ -80,7 +113,7 @@ pub(crate) fn make_handle_pause(
 ) -> Listener<MediaRecorder, Event> {
     Listener::new(media_recorder, "pause", move |_: Event| {
         info!("Recording paused");
-        recording_data.borrow_mut().set_is_recording(false);
+        update_is_recording(&recording_data, false);
     })
 }
 
@@ -90,6 +123,6 @@ pub(crate) fn make_handle_resume(
 ) -> Listener<MediaRecorder, Event> {
     Listener::new(media_recorder, "resume", move |_: Event| {
         info!("Recording resumed");
-        recording_data.borrow_mut().set_is_recording(true);
+        update_is_recording(&recording_data, true);
     })
 }
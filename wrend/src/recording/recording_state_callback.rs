@@ -0,0 +1,44 @@
+use crate::Callback;
+use js_sys::Function;
+use std::fmt::Debug;
+use std::{ops::Deref, rc::Rc};
+
+/// Called whenever the recorder starts, stops, pauses or resumes.
+///
+/// Receives `true` when the recorder is now recording and `false` otherwise.
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd)]
+pub struct RecordingStateCallback(Callback<dyn Fn(bool), Function>);
+
+impl Deref for RecordingStateCallback {
+    type Target = Callback<dyn Fn(bool), Function>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for RecordingStateCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RecordingStateCallback")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+impl<F: Fn(bool) + 'static> From<F> for RecordingStateCallback {
+    fn from(callback: F) -> Self {
+        Self(Callback::new_rs(Rc::new(callback) as Rc<dyn Fn(bool)>))
+    }
+}
+
+impl<F: Fn(bool) + 'static> From<Rc<F>> for RecordingStateCallback {
+    fn from(callback: Rc<F>) -> Self {
+        Self(Callback::new_rs(callback as Rc<dyn Fn(bool)>))
+    }
+}
+
+impl From<Function> for RecordingStateCallback {
+    fn from(callback: Function) -> Self {
+        Self(Callback::new_js(callback))
+    }
+}
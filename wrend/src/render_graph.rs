@@ -0,0 +1,20 @@
+mod render_graph;
+mod render_graph_builder;
+mod render_graph_texture;
+mod render_graph_texture_descriptor;
+mod render_pass;
+mod render_pass_callback;
+mod render_pass_context;
+mod render_pass_predicate;
+mod render_pass_predicate_context;
+
+pub(crate) use render_graph_texture::*;
+
+pub use render_graph::*;
+pub use render_graph_builder::*;
+pub use render_graph_texture_descriptor::*;
+pub use render_pass::*;
+pub use render_pass_callback::*;
+pub use render_pass_context::*;
+pub use render_pass_predicate::*;
+pub use render_pass_predicate_context::*;
@@ -0,0 +1,369 @@
+use crate::{
+    invalidate_framebuffer, Callback, FrameTracer, RenderGraphBuilder, RenderGraphTexture,
+    RenderPass, RenderPassContext, RenderPassPredicateContext, TraceCategory,
+};
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+use web_sys::{window, WebGl2RenderingContext, WebGlTexture};
+
+/// A set of named textures and the passes that render into them.
+///
+/// The graph owns its textures and framebuffers: passes only declare which textures they read
+/// and write, and the graph binds the right framebuffer and viewport before each pass runs.
+/// Passes that read and write the same texture are automatically ping-ponged.
+///
+/// A graph is typically built once after the [crate::RendererData] is built and then executed
+/// from the render callback.
+#[derive(Debug, Clone)]
+pub struct RenderGraph {
+    gl: WebGl2RenderingContext,
+    textures: HashMap<String, RenderGraphTexture>,
+    passes: Vec<RenderPass>,
+    order: Vec<usize>,
+    disabled_passes: HashSet<String>,
+    frame_tracer: Option<FrameTracer>,
+    invalidate_transient_textures: bool,
+}
+
+impl RenderGraph {
+    /// Creates a new builder for a graph
+    pub fn builder() -> RenderGraphBuilder {
+        RenderGraphBuilder::default()
+    }
+
+    pub(crate) fn new(
+        gl: WebGl2RenderingContext,
+        textures: HashMap<String, RenderGraphTexture>,
+        passes: Vec<RenderPass>,
+        order: Vec<usize>,
+    ) -> Self {
+        Self {
+            gl,
+            textures,
+            passes,
+            order,
+            disabled_passes: HashSet::new(),
+            frame_tracer: None,
+            invalidate_transient_textures: true,
+        }
+    }
+
+    /// Gets the readable side of a graph texture: the most recently completed output of the
+    /// pass that writes it. For ping-pong textures, this alternates every time the texture is written.
+    pub fn texture(&self, name: &str) -> Option<&WebGlTexture> {
+        self.textures
+            .get(name)
+            .map(|texture| texture.read_texture())
+    }
+
+    /// Whether the graph allocated two copies of this texture because a pass reads and writes it
+    pub fn is_ping_pong(&self, name: &str) -> bool {
+        self.textures
+            .get(name)
+            .is_some_and(|texture| texture.is_ping_pong())
+    }
+
+    /// All passes, in the order they were added
+    pub fn passes(&self) -> &[RenderPass] {
+        &self.passes
+    }
+
+    /// Passes in the order they are executed every frame.
+    ///
+    /// Passes that run once at build time are not included.
+    pub fn execution_order(&self) -> Vec<&RenderPass> {
+        self.order.iter().map(|&i| &self.passes[i]).collect()
+    }
+
+    /// Enables or disables a pass at runtime. Disabled passes are skipped, along with any
+    /// passes whose output was only read by disabled passes.
+    ///
+    /// Returns `false` if no pass has the given name.
+    pub fn set_pass_enabled(&mut self, name: &str, enabled: bool) -> bool {
+        if !self.passes.iter().any(|pass| pass.name() == name) {
+            return false;
+        }
+        if enabled {
+            self.disabled_passes.remove(name);
+        } else {
+            self.disabled_passes.insert(name.to_string());
+        }
+        true
+    }
+
+    /// Whether the pass has been disabled with [RenderGraph::set_pass_enabled].
+    ///
+    /// This does not account for the pass's predicate.
+    pub fn is_pass_enabled(&self, name: &str) -> bool {
+        !self.disabled_passes.contains(name)
+    }
+
+    /// Records a span for every executed pass, e.g. into the [RendererData::frame_tracer](crate::RendererData::frame_tracer)
+    pub fn set_frame_tracer(&mut self, frame_tracer: FrameTracer) -> &mut Self {
+        self.frame_tracer = Some(frame_tracer);
+        self
+    }
+
+    /// Whether textures that are only read within a frame are invalidated after their last read.
+    ///
+    /// Enabled by default, which saves tiled mobile GPUs from writing the textures back to memory.
+    /// Disable this if transient textures are read with [RenderGraph::texture] after the graph runs.
+    pub fn set_invalidate_transient_textures(&mut self, invalidate: bool) -> &mut Self {
+        self.invalidate_transient_textures = invalidate;
+        self
+    }
+
+    /// See [RenderGraph::set_invalidate_transient_textures]
+    pub fn invalidate_transient_textures(&self) -> bool {
+        self.invalidate_transient_textures
+    }
+
+    /// Runs every per-frame pass once, in dependency order.
+    ///
+    /// Passes that are disabled or whose predicate returns `false` are skipped, as are passes
+    /// that only feed skipped passes.
+    pub fn execute(&mut self) {
+        self.execute_passes_where_active(None);
+    }
+
+    /// Like [RenderGraph::execute], but predicates can read `user_ctx` through
+    /// [RenderPassPredicateContext::user_ctx] (e.g. the [crate::RendererData::user_ctx] of the
+    /// renderer the graph draws for).
+    pub fn execute_with_user_ctx<UserCtx: 'static>(&mut self, user_ctx: &UserCtx) {
+        self.execute_passes_where_active(Some(user_ctx));
+    }
+
+    fn execute_passes_where_active(&mut self, user_ctx: Option<&dyn Any>) {
+        let active: Vec<bool> =
+            self.passes
+                .iter()
+                .map(|pass| {
+                    self.is_pass_enabled(pass.name())
+                        && match pass.predicate() {
+                            Some(predicate) => predicate.evaluate(
+                                &RenderPassPredicateContext::new(self, pass.name(), user_ctx),
+                            ),
+                            None => true,
+                        }
+                })
+                .collect();
+        let active = prune_unneeded_passes(&self.passes, active);
+
+        let order: Vec<usize> = self.order.iter().copied().filter(|&i| active[i]).collect();
+        let invalidations = if self.invalidate_transient_textures {
+            transient_texture_invalidations(&self.passes, &order)
+        } else {
+            HashMap::new()
+        };
+        self.execute_passes(&order, &invalidations);
+    }
+
+    /// Runs the given passes in order, invalidating textures after the passes they are keyed by
+    pub(crate) fn execute_passes(
+        &mut self,
+        pass_indices: &[usize],
+        invalidations: &HashMap<usize, Vec<String>>,
+    ) {
+        if pass_indices.is_empty() {
+            return;
+        }
+
+        let now = window().unwrap().performance().unwrap().now();
+
+        for &i in pass_indices {
+            self.execute_pass(i, now);
+
+            for name in invalidations.get(&i).into_iter().flatten() {
+                if let Some(texture) = self.textures.get(name) {
+                    invalidate_framebuffer(
+                        &self.gl,
+                        Some(texture.read_framebuffer()),
+                        &[WebGl2RenderingContext::COLOR_ATTACHMENT0],
+                    );
+                }
+            }
+        }
+
+        self.gl
+            .bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, None);
+    }
+
+    fn execute_pass(&mut self, pass_index: usize, now: f64) {
+        let pass = &self.passes[pass_index];
+        let gl = &self.gl;
+        let output = pass.writes().and_then(|name| self.textures.get(name));
+
+        match output {
+            Some(texture) => {
+                gl.bind_framebuffer(
+                    WebGl2RenderingContext::FRAMEBUFFER,
+                    Some(texture.write_framebuffer()),
+                );
+                let descriptor = texture.descriptor();
+                gl.viewport(0, 0, descriptor.width(), descriptor.height());
+            }
+            None => {
+                gl.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, None);
+                gl.viewport(0, 0, gl.drawing_buffer_width(), gl.drawing_buffer_height());
+            }
+        }
+
+        let ctx = RenderPassContext::new(gl, self, pass.name(), now);
+        let run_pass = || match &*pass.callback() {
+            Callback::Rust(rust_callback) => (rust_callback)(&ctx),
+            Callback::Js(js_callback) => {
+                if let Err(err) = js_callback.call0(&wasm_bindgen::JsValue::NULL) {
+                    log::error!("Render pass {:?} produced an error: {err:?}", pass.name());
+                }
+            }
+        };
+        match &self.frame_tracer {
+            Some(frame_tracer) => frame_tracer.span(pass.name(), TraceCategory::Pass, run_pass),
+            None => run_pass(),
+        }
+
+        if let Some(name) = pass.writes().map(str::to_string) {
+            if let Some(texture) = self.textures.get_mut(&name) {
+                texture.swap();
+            }
+        }
+    }
+}
+
+/// Deactivates passes whose output texture is read by other passes, but only by inactive ones.
+///
+/// Passes that render to the canvas, or to a texture that no pass reads (and which is
+/// presumably consumed outside of the graph), are always kept if active.
+pub(crate) fn prune_unneeded_passes(passes: &[RenderPass], mut active: Vec<bool>) -> Vec<bool> {
+    loop {
+        let mut changed = false;
+
+        for i in 0..passes.len() {
+            if !active[i] {
+                continue;
+            }
+            let written = match passes[i].writes() {
+                Some(written) => written,
+                None => continue,
+            };
+            let mut readers = passes
+                .iter()
+                .enumerate()
+                .filter(|(j, pass)| *j != i && pass.reads().iter().any(|read| read == written))
+                .peekable();
+            let has_readers = readers.peek().is_some();
+            let has_active_readers = readers.any(|(j, _)| active[j]);
+
+            if has_readers && !has_active_readers {
+                active[i] = false;
+                changed = true;
+            }
+        }
+
+        if !changed {
+            return active;
+        }
+    }
+}
+
+/// For each pass in `order`, the textures whose contents can be discarded once the pass has run,
+/// because no later pass reads them this frame.
+///
+/// Only textures written earlier in the same frame are transient: textures that are read before
+/// they are written (including ping-pong textures), written more than once, written by passes
+/// that run once at build time, or never read by another pass all keep their contents.
+pub(crate) fn transient_texture_invalidations(
+    passes: &[RenderPass],
+    order: &[usize],
+) -> HashMap<usize, Vec<String>> {
+    let mut persistent: HashSet<&str> = passes
+        .iter()
+        .filter(|pass| pass.run_once_at_build())
+        .filter_map(RenderPass::writes)
+        .collect();
+    let mut written = HashSet::new();
+    let mut last_reads = HashMap::new();
+
+    for &i in order {
+        let pass = &passes[i];
+        for read in pass.reads() {
+            if written.contains(read.as_str()) {
+                last_reads.insert(read.as_str(), i);
+            } else {
+                persistent.insert(read);
+            }
+        }
+        if let Some(write) = pass.writes() {
+            if !written.insert(write) {
+                persistent.insert(write);
+            }
+        }
+    }
+
+    let mut invalidations: HashMap<usize, Vec<String>> = HashMap::new();
+    for (name, i) in last_reads {
+        if !persistent.contains(name) {
+            invalidations.entry(i).or_default().push(name.to_string());
+        }
+    }
+    for names in invalidations.values_mut() {
+        names.sort();
+    }
+    invalidations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{prune_unneeded_passes, transient_texture_invalidations};
+    use crate::{RenderPass, RenderPassContext};
+
+    fn pass(name: &str, reads: &[&str], writes: Option<&str>) -> RenderPass {
+        let mut pass = RenderPass::new(name, |_: &RenderPassContext| {});
+        for read in reads {
+            pass.add_read(*read);
+        }
+        pass.set_writes(writes);
+        pass
+    }
+
+    #[test]
+    fn prunes_passes_that_only_feed_inactive_passes() {
+        let passes = [
+            pass("scene", &[], Some("scene")),
+            pass("bright", &["scene"], Some("bright")),
+            pass("bloom", &["bright"], Some("bloom")),
+            pass("composite", &["scene", "bloom"], None),
+            pass("export", &[], Some("export")),
+        ];
+
+        let all = vec![true; passes.len()];
+        assert_eq!(prune_unneeded_passes(&passes, all.clone()), all);
+
+        // disabling the composite prunes everything feeding it, but not the unread export
+        let pruned = prune_unneeded_passes(&passes, vec![true, true, true, false, true]);
+        assert_eq!(pruned, vec![false, false, false, false, true]);
+
+        // disabling bloom prunes `bright`, but `scene` is still needed by the composite
+        let pruned = prune_unneeded_passes(&passes, vec![true, true, false, true, true]);
+        assert_eq!(pruned, vec![true, false, false, true, true]);
+    }
+
+    #[test]
+    fn invalidates_transient_textures_after_their_last_read() {
+        let passes = [
+            pass("scene", &[], Some("scene")),
+            pass("bright", &["scene"], Some("bright")),
+            pass("bloom", &["bright"], Some("bloom")),
+            pass("trail", &["trail", "scene"], Some("trail")),
+            pass("composite", &["scene", "bloom", "trail", "history"], None),
+            pass("history", &["scene"], Some("history")),
+        ];
+
+        let invalidations = transient_texture_invalidations(&passes, &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(invalidations.len(), 3);
+        assert_eq!(invalidations[&2], vec!["bright".to_string()]);
+        assert_eq!(invalidations[&4], vec!["bloom".to_string()]);
+        // the feedback `trail` and the previous frame's `history` carry over between frames
+        assert_eq!(invalidations[&5], vec!["scene".to_string()]);
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{
+    RenderGraph, RenderGraphError, RenderGraphTexture, RenderGraphTextureDescriptor, RenderPass,
+};
+use std::collections::{HashMap, HashSet};
+use web_sys::WebGl2RenderingContext;
+
+/// Collects textures and passes and allocates all GPU resources for a [RenderGraph]
+#[derive(Debug, Clone, Default)]
+pub struct RenderGraphBuilder {
+    textures: Vec<(String, RenderGraphTextureDescriptor)>,
+    passes: Vec<RenderPass>,
+}
+
+impl RenderGraphBuilder {
+    /// Declares a named texture that passes can read from and write to
+    pub fn add_texture(
+        &mut self,
+        name: impl Into<String>,
+        descriptor: RenderGraphTextureDescriptor,
+    ) -> &mut Self {
+        self.textures.push((name.into(), descriptor));
+        self
+    }
+
+    /// Adds a pass to the graph. Passes run in an order that satisfies their texture dependencies,
+    /// falling back to the order in which they were added.
+    pub fn add_pass(&mut self, pass: impl Into<RenderPass>) -> &mut Self {
+        self.passes.push(pass.into());
+        self
+    }
+
+    /// Validates the graph and allocates its textures and framebuffers.
+    ///
+    /// Any texture that is both read and written by the same pass is allocated twice, so that
+    /// the pass can read last frame's output while writing the next one.
+    ///
+    /// Passes marked with [RenderPass::set_run_once_at_build] are executed here, before returning.
+    pub fn build(&self, gl: &WebGl2RenderingContext) -> Result<RenderGraph, RenderGraphError> {
+        let mut pass_names = HashSet::new();
+        for pass in &self.passes {
+            if !pass_names.insert(pass.name()) {
+                return Err(RenderGraphError::DuplicatePass(pass.name().to_string()));
+            }
+
+            for texture_name in pass.reads().iter().map(String::as_str).chain(pass.writes()) {
+                if !self.textures.iter().any(|(name, _)| name == texture_name) {
+                    return Err(RenderGraphError::TextureNotFound {
+                        pass: pass.name().to_string(),
+                        texture: texture_name.to_string(),
+                    });
+                }
+            }
+        }
+
+        let mut textures = HashMap::new();
+        for (name, descriptor) in &self.textures {
+            let ping_pong = self
+                .passes
+                .iter()
+                .any(|pass| pass.is_feedback() && pass.writes() == Some(name));
+            let texture = RenderGraphTexture::new(gl, name, *descriptor, ping_pong)?;
+            textures.insert(name.clone(), texture);
+        }
+
+        let (build_order, frame_order) = pass_order(&self.passes)
+            .into_iter()
+            .partition(|&i| self.passes[i].run_once_at_build());
+
+        let mut graph = RenderGraph::new(gl.clone(), textures, self.passes.clone(), frame_order);
+        graph.execute_passes(&build_order, &HashMap::new());
+
+        Ok(graph)
+    }
+}
+
+/// Sorts passes so that every pass runs after the passes that write the textures it reads.
+///
+/// Ties (and cycles, where a pass necessarily reads the previous frame's output) are resolved
+/// using the order in which the passes were added.
+pub(crate) fn pass_order(passes: &[RenderPass]) -> Vec<usize> {
+    let depends_on = |reader: &RenderPass, writer: &RenderPass| {
+        reader != writer
+            && writer
+                .writes()
+                .is_some_and(|written| reader.reads().iter().any(|read| read == written))
+    };
+
+    let mut order = Vec::with_capacity(passes.len());
+    let mut remaining: Vec<usize> = (0..passes.len()).collect();
+
+    while !remaining.is_empty() {
+        let next = remaining
+            .iter()
+            .position(|&i| {
+                !remaining
+                    .iter()
+                    .any(|&j| depends_on(&passes[i], &passes[j]))
+            })
+            .unwrap_or(0);
+        order.push(remaining.remove(next));
+    }
+
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pass_order;
+    use crate::{RenderPass, RenderPassContext};
+
+    fn pass(name: &str, reads: &[&str], writes: Option<&str>) -> RenderPass {
+        let mut pass = RenderPass::new(name, |_: &RenderPassContext| {});
+        for read in reads {
+            pass.add_read(*read);
+        }
+        pass.set_writes(writes);
+        pass
+    }
+
+    #[test]
+    fn orders_passes_by_dependency() {
+        let passes = [
+            pass("present", &["blurred"], None),
+            pass("blur", &["scene"], Some("blurred")),
+            pass("scene", &[], Some("scene")),
+        ];
+        assert_eq!(pass_order(&passes), vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn keeps_declaration_order_for_feedback_loops() {
+        let passes = [
+            pass("simulate", &["state"], Some("state")),
+            pass("present", &["state"], None),
+        ];
+        assert_eq!(pass_order(&passes), vec![0, 1]);
+        assert!(passes[0].is_feedback());
+        assert!(!passes[1].is_feedback());
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{RenderGraphError, RenderGraphTextureDescriptor};
+use web_sys::{WebGl2RenderingContext, WebGlFramebuffer, WebGlTexture};
+
+/// A texture owned by a render graph, along with the framebuffer used to render into it.
+///
+/// Ping-pong textures hold two copies that alternate between being read and written.
+#[derive(Debug, Clone)]
+pub(crate) struct RenderGraphTexture {
+    descriptor: RenderGraphTextureDescriptor,
+    textures: Vec<WebGlTexture>,
+    framebuffers: Vec<WebGlFramebuffer>,
+    read_index: usize,
+}
+
+impl RenderGraphTexture {
+    pub(crate) fn new(
+        gl: &WebGl2RenderingContext,
+        name: &str,
+        descriptor: RenderGraphTextureDescriptor,
+        ping_pong: bool,
+    ) -> Result<Self, RenderGraphError> {
+        let count = if ping_pong { 2 } else { 1 };
+        let mut textures = Vec::with_capacity(count);
+        let mut framebuffers = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            let texture = gl
+                .create_texture()
+                .ok_or_else(|| RenderGraphError::CreateTexture(name.to_string()))?;
+            gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&texture));
+            gl.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+                WebGl2RenderingContext::TEXTURE_2D,
+                0,
+                descriptor.internal_format() as i32,
+                descriptor.width(),
+                descriptor.height(),
+                0,
+                descriptor.format(),
+                descriptor.data_type(),
+                None,
+            )
+            .map_err(|_| RenderGraphError::CreateTexture(name.to_string()))?;
+            for (parameter, value) in [
+                (
+                    WebGl2RenderingContext::TEXTURE_MIN_FILTER,
+                    descriptor.filter(),
+                ),
+                (
+                    WebGl2RenderingContext::TEXTURE_MAG_FILTER,
+                    descriptor.filter(),
+                ),
+                (
+                    WebGl2RenderingContext::TEXTURE_WRAP_S,
+                    WebGl2RenderingContext::CLAMP_TO_EDGE,
+                ),
+                (
+                    WebGl2RenderingContext::TEXTURE_WRAP_T,
+                    WebGl2RenderingContext::CLAMP_TO_EDGE,
+                ),
+            ] {
+                gl.tex_parameteri(WebGl2RenderingContext::TEXTURE_2D, parameter, value as i32);
+            }
+            gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, None);
+
+            let framebuffer = gl
+                .create_framebuffer()
+                .ok_or_else(|| RenderGraphError::CreateFramebuffer(name.to_string()))?;
+            gl.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, Some(&framebuffer));
+            gl.framebuffer_texture_2d(
+                WebGl2RenderingContext::FRAMEBUFFER,
+                WebGl2RenderingContext::COLOR_ATTACHMENT0,
+                WebGl2RenderingContext::TEXTURE_2D,
+                Some(&texture),
+                0,
+            );
+            gl.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, None);
+
+            textures.push(texture);
+            framebuffers.push(framebuffer);
+        }
+
+        Ok(Self {
+            descriptor,
+            textures,
+            framebuffers,
+            read_index: 0,
+        })
+    }
+
+    pub(crate) fn descriptor(&self) -> &RenderGraphTextureDescriptor {
+        &self.descriptor
+    }
+
+    pub(crate) fn is_ping_pong(&self) -> bool {
+        self.textures.len() > 1
+    }
+
+    /// The side of the texture that holds the most recently completed output
+    pub(crate) fn read_texture(&self) -> &WebGlTexture {
+        &self.textures[self.read_index]
+    }
+
+    /// The framebuffer attached to the readable side of the texture
+    pub(crate) fn read_framebuffer(&self) -> &WebGlFramebuffer {
+        &self.framebuffers[self.read_index]
+    }
+
+    /// The framebuffer that should be rendered into (the opposite side for ping-pong textures)
+    pub(crate) fn write_framebuffer(&self) -> &WebGlFramebuffer {
+        &self.framebuffers[self.write_index()]
+    }
+
+    /// Makes the most recently written side readable
+    pub(crate) fn swap(&mut self) {
+        self.read_index = self.write_index();
+    }
+
+    fn write_index(&self) -> usize {
+        (self.read_index + 1) % self.textures.len()
+    }
+}
@@ -0,0 +1,95 @@
+use web_sys::WebGl2RenderingContext;
+
+/// Describes how a texture owned by a [crate::RenderGraph] should be allocated
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct RenderGraphTextureDescriptor {
+    width: i32,
+    height: i32,
+    internal_format: u32,
+    format: u32,
+    data_type: u32,
+    filter: u32,
+}
+
+impl RenderGraphTextureDescriptor {
+    /// Creates a new descriptor from raw WebGL enums
+    pub fn new(
+        width: i32,
+        height: i32,
+        internal_format: u32,
+        format: u32,
+        data_type: u32,
+        filter: u32,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            internal_format,
+            format,
+            data_type,
+            filter,
+        }
+    }
+
+    /// An 8-bit RGBA texture with linear filtering
+    pub fn rgba8(width: i32, height: i32) -> Self {
+        Self::new(
+            width,
+            height,
+            WebGl2RenderingContext::RGBA8,
+            WebGl2RenderingContext::RGBA,
+            WebGl2RenderingContext::UNSIGNED_BYTE,
+            WebGl2RenderingContext::LINEAR,
+        )
+    }
+
+    /// A 32-bit float RGBA texture with nearest filtering.
+    ///
+    /// Rendering to this format requires the `EXT_color_buffer_float` extension.
+    pub fn rgba32f(width: i32, height: i32) -> Self {
+        Self::new(
+            width,
+            height,
+            WebGl2RenderingContext::RGBA32F,
+            WebGl2RenderingContext::RGBA,
+            WebGl2RenderingContext::FLOAT,
+            WebGl2RenderingContext::NEAREST,
+        )
+    }
+
+    /// Width of the texture in pixels
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    /// Height of the texture in pixels
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// Sized internal format, such as `RGBA8`
+    pub fn internal_format(&self) -> u32 {
+        self.internal_format
+    }
+
+    /// Pixel format, such as `RGBA`
+    pub fn format(&self) -> u32 {
+        self.format
+    }
+
+    /// Pixel data type, such as `UNSIGNED_BYTE`
+    pub fn data_type(&self) -> u32 {
+        self.data_type
+    }
+
+    /// Minification and magnification filter, such as `LINEAR`
+    pub fn filter(&self) -> u32 {
+        self.filter
+    }
+
+    /// See [RenderGraphTextureDescriptor::filter]
+    pub fn set_filter(&mut self, filter: u32) -> &mut Self {
+        self.filter = filter;
+        self
+    }
+}
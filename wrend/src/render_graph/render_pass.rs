@@ -0,0 +1,101 @@
+use crate::{RenderPassCallback, RenderPassPredicate};
+
+/// A single step of a [crate::RenderGraph]: a callback that reads from any number of graph
+/// textures and writes to one graph texture (or to the canvas).
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct RenderPass {
+    name: String,
+    reads: Vec<String>,
+    writes: Option<String>,
+    callback: RenderPassCallback,
+    run_once_at_build: bool,
+    predicate: Option<RenderPassPredicate>,
+}
+
+impl RenderPass {
+    /// Creates a pass that renders to the canvas and reads no graph textures
+    pub fn new(name: impl Into<String>, callback: impl Into<RenderPassCallback>) -> Self {
+        Self {
+            name: name.into(),
+            reads: Vec::new(),
+            writes: None,
+            callback: callback.into(),
+            run_once_at_build: false,
+            predicate: None,
+        }
+    }
+
+    /// Unique name of the pass
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Names of the graph textures this pass samples from
+    pub fn reads(&self) -> &[String] {
+        &self.reads
+    }
+
+    /// Declares that this pass samples from a graph texture.
+    ///
+    /// If the pass also writes to the same texture, the graph allocates a second copy of it
+    /// and alternates between the two every time the pass runs (ping-pong).
+    pub fn add_read(&mut self, texture_name: impl Into<String>) -> &mut Self {
+        self.reads.push(texture_name.into());
+        self
+    }
+
+    /// Name of the graph texture this pass renders into, or `None` for the canvas
+    pub fn writes(&self) -> Option<&str> {
+        self.writes.as_deref()
+    }
+
+    /// See [RenderPass::writes]
+    pub fn set_writes(&mut self, texture_name: Option<impl Into<String>>) -> &mut Self {
+        self.writes = texture_name.map(Into::into);
+        self
+    }
+
+    /// Whether the pass reads and writes the same texture
+    pub fn is_feedback(&self) -> bool {
+        self.writes
+            .as_ref()
+            .is_some_and(|writes| self.reads.contains(writes))
+    }
+
+    /// The callback that issues this pass's draw calls
+    pub fn callback(&self) -> RenderPassCallback {
+        self.callback.clone()
+    }
+
+    /// Whether this pass runs exactly once, while the graph is being built, instead of every frame
+    pub fn run_once_at_build(&self) -> bool {
+        self.run_once_at_build
+    }
+
+    /// See [RenderPass::run_once_at_build].
+    ///
+    /// Useful for one-time initialization renders (e.g. generating a noise texture), which would
+    /// otherwise cause a visible hitch in the first animation frame. Run-once passes still
+    /// execute in dependency order relative to each other.
+    pub fn set_run_once_at_build(&mut self, run_once_at_build: bool) -> &mut Self {
+        self.run_once_at_build = run_once_at_build;
+        self
+    }
+
+    /// Predicate that decides, every frame, whether this pass should run
+    pub fn predicate(&self) -> Option<RenderPassPredicate> {
+        self.predicate.clone()
+    }
+
+    /// See [RenderPass::predicate].
+    ///
+    /// When the predicate returns `false`, the pass is skipped for that frame, along with any
+    /// passes whose output was only needed by it.
+    pub fn set_predicate(
+        &mut self,
+        predicate: Option<impl Into<RenderPassPredicate>>,
+    ) -> &mut Self {
+        self.predicate = predicate.map(Into::into);
+        self
+    }
+}
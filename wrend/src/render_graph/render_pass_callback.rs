@@ -0,0 +1,42 @@
+use crate::{Callback, RenderPassContext};
+use js_sys::Function;
+use std::fmt::Debug;
+use std::{ops::Deref, rc::Rc};
+
+/// Callback that issues the draw calls for a single [crate::RenderPass]
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd)]
+pub struct RenderPassCallback(Callback<dyn Fn(&RenderPassContext), Function>);
+
+impl Deref for RenderPassCallback {
+    type Target = Callback<dyn Fn(&RenderPassContext), Function>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for RenderPassCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RenderPassCallback").field(&self.0).finish()
+    }
+}
+
+impl<F: Fn(&RenderPassContext) + 'static> From<F> for RenderPassCallback {
+    fn from(callback: F) -> Self {
+        Self(Callback::new_rs(
+            Rc::new(callback) as Rc<dyn Fn(&RenderPassContext)>
+        ))
+    }
+}
+
+impl<F: Fn(&RenderPassContext) + 'static> From<Rc<F>> for RenderPassCallback {
+    fn from(callback: Rc<F>) -> Self {
+        Self(Callback::new_rs(callback as Rc<dyn Fn(&RenderPassContext)>))
+    }
+}
+
+impl From<Function> for RenderPassCallback {
+    fn from(callback: Function) -> Self {
+        Self(Callback::new_js(callback))
+    }
+}
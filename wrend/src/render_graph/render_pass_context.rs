@@ -0,0 +1,76 @@
+use crate::RenderGraph;
+use web_sys::{WebGl2RenderingContext, WebGlTexture};
+
+/// Context passed to a [crate::RenderPassCallback] while its pass executes.
+///
+/// By the time the callback runs, the pass's output framebuffer is already bound and the
+/// viewport matches the size of the output.
+#[derive(Debug, Clone, Copy)]
+pub struct RenderPassContext<'a> {
+    gl: &'a WebGl2RenderingContext,
+    graph: &'a RenderGraph,
+    pass_name: &'a str,
+    now: f64,
+}
+
+impl<'a> RenderPassContext<'a> {
+    /// Creates a new context for the given pass
+    pub fn new(
+        gl: &'a WebGl2RenderingContext,
+        graph: &'a RenderGraph,
+        pass_name: &'a str,
+        now: f64,
+    ) -> Self {
+        Self {
+            gl,
+            graph,
+            pass_name,
+            now,
+        }
+    }
+
+    /// The WebGL2 context
+    pub fn gl(&self) -> &WebGl2RenderingContext {
+        self.gl
+    }
+
+    /// The graph that is executing
+    pub fn graph(&self) -> &RenderGraph {
+        self.graph
+    }
+
+    /// Name of the pass that is executing
+    pub fn pass_name(&self) -> &str {
+        self.pass_name
+    }
+
+    /// Time the frame started, from `performance.now()`
+    pub fn now(&self) -> f64 {
+        self.now
+    }
+
+    /// Gets the readable side of a graph texture. See [RenderGraph::texture]
+    pub fn texture(&self, name: &str) -> Option<&WebGlTexture> {
+        self.graph.texture(name)
+    }
+
+    /// Binds the readable side of each graph texture to its texture unit.
+    ///
+    /// Textures that do not exist in the graph are skipped with a warning.
+    pub fn bind_textures(&self, textures: &[(&str, u32)]) {
+        for (name, texture_unit) in textures {
+            match self.texture(name) {
+                Some(texture) => {
+                    self.gl
+                        .active_texture(WebGl2RenderingContext::TEXTURE0 + texture_unit);
+                    self.gl
+                        .bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(texture));
+                }
+                None => log::warn!(
+                    "Pass {:?} tried to bind unknown graph texture {name:?}",
+                    self.pass_name
+                ),
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{Callback, RenderPassPredicateContext};
+use js_sys::{Function, Object};
+use std::fmt::Debug;
+use std::{ops::Deref, rc::Rc};
+use wasm_bindgen::JsValue;
+
+/// Decides, every frame, whether a [crate::RenderPass] should run
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd)]
+pub struct RenderPassPredicate(Callback<dyn Fn(&RenderPassPredicateContext) -> bool, Function>);
+
+impl RenderPassPredicate {
+    /// Evaluates the predicate.
+    ///
+    /// JavaScript predicates are called with the pass name and the user context (or `undefined`
+    /// if the graph wasn't given one, or it isn't a JavaScript value). Those that throw or return
+    /// a non-boolean are treated as `false`.
+    pub fn evaluate(&self, ctx: &RenderPassPredicateContext) -> bool {
+        match &self.0 {
+            Callback::Rust(rust_callback) => (rust_callback)(ctx),
+            Callback::Js(js_callback) => {
+                let user_ctx = ctx
+                    .user_ctx::<JsValue>()
+                    .cloned()
+                    .or_else(|| ctx.user_ctx::<Object>().map(JsValue::from))
+                    .unwrap_or(JsValue::UNDEFINED);
+                js_callback
+                    .call2(
+                        &JsValue::NULL,
+                        &JsValue::from_str(ctx.pass_name()),
+                        &user_ctx,
+                    )
+                    .ok()
+                    .and_then(|value| value.as_bool())
+                    .unwrap_or(false)
+            }
+        }
+    }
+}
+
+impl Deref for RenderPassPredicate {
+    type Target = Callback<dyn Fn(&RenderPassPredicateContext) -> bool, Function>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for RenderPassPredicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RenderPassPredicate").field(&self.0).finish()
+    }
+}
+
+impl<F: Fn(&RenderPassPredicateContext) -> bool + 'static> From<F> for RenderPassPredicate {
+    fn from(callback: F) -> Self {
+        Self(Callback::new_rs(
+            Rc::new(callback) as Rc<dyn Fn(&RenderPassPredicateContext) -> bool>
+        ))
+    }
+}
+
+impl<F: Fn(&RenderPassPredicateContext) -> bool + 'static> From<Rc<F>> for RenderPassPredicate {
+    fn from(callback: Rc<F>) -> Self {
+        Self(Callback::new_rs(
+            callback as Rc<dyn Fn(&RenderPassPredicateContext) -> bool>,
+        ))
+    }
+}
+
+impl From<Function> for RenderPassPredicate {
+    fn from(callback: Function) -> Self {
+        Self(Callback::new_js(callback))
+    }
+}
@@ -0,0 +1,39 @@
+use crate::RenderGraph;
+use std::any::Any;
+
+/// Context passed to a [crate::RenderPassPredicate] when deciding whether its pass runs this frame
+#[derive(Debug, Clone, Copy)]
+pub struct RenderPassPredicateContext<'a> {
+    graph: &'a RenderGraph,
+    pass_name: &'a str,
+    user_ctx: Option<&'a dyn Any>,
+}
+
+impl<'a> RenderPassPredicateContext<'a> {
+    /// Creates a new context for the given pass
+    pub fn new(graph: &'a RenderGraph, pass_name: &'a str, user_ctx: Option<&'a dyn Any>) -> Self {
+        Self {
+            graph,
+            pass_name,
+            user_ctx,
+        }
+    }
+
+    /// The graph that is executing
+    pub fn graph(&self) -> &RenderGraph {
+        self.graph
+    }
+
+    /// Name of the pass the predicate belongs to
+    pub fn pass_name(&self) -> &str {
+        self.pass_name
+    }
+
+    /// The value given to [RenderGraph::execute_with_user_ctx], if it is a `UserCtx`.
+    ///
+    /// Returns `None` when the graph was run with [RenderGraph::execute].
+    pub fn user_ctx<UserCtx: 'static>(&self) -> Option<&UserCtx> {
+        self.user_ctx
+            .and_then(|user_ctx| user_ctx.downcast_ref::<UserCtx>())
+    }
+}
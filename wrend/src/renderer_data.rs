@@ -1,7 +1,22 @@
+mod external_ownership;
+mod external_resources;
+mod frame_clock;
+mod frame_scope;
 mod renderer_data;
+#[cfg(feature = "js")]
 mod renderer_data_builder_js;
+#[cfg(feature = "js")]
 mod renderer_data_js;
+mod saved_blend_state;
 
+pub(crate) use external_resources::*;
+pub(crate) use frame_clock::*;
+pub(crate) use frame_scope::*;
+pub(crate) use saved_blend_state::*;
+
+pub use external_ownership::*;
 pub use renderer_data::*;
+#[cfg(feature = "js")]
 pub use renderer_data_builder_js::*;
+#[cfg(feature = "js")]
 pub use renderer_data_js::*;
@@ -0,0 +1,12 @@
+/// Who deletes a WebGL object that was created outside of wrend and registered with a
+/// [crate::RendererData] (e.g. with [crate::RendererData::register_external_texture])
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum ExternalOwnership {
+    /// The code that created the object keeps ownership and must delete it, after unregistering
+    /// it. wrend never deletes it.
+    #[default]
+    Borrowed,
+    /// Ownership is transferred to wrend, which deletes the object once it is unregistered or
+    /// replaced by another object under the same id.
+    Owned,
+}
@@ -0,0 +1,69 @@
+use crate::{ExternalOwnership, ExternalTextureDescriptor, Id};
+use std::collections::HashMap;
+
+/// Which of a [crate::RendererData]'s buffers, textures and framebuffers were created outside of
+/// wrend, and who owns them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ExternalResources<BufferId: Id, TextureId: Id, FramebufferId: Id> {
+    pub(crate) buffers: HashMap<BufferId, ExternalOwnership>,
+    pub(crate) textures: HashMap<TextureId, (ExternalTextureDescriptor, ExternalOwnership)>,
+    pub(crate) framebuffers: HashMap<FramebufferId, ExternalOwnership>,
+}
+
+impl<BufferId: Id, TextureId: Id, FramebufferId: Id>
+    ExternalResources<BufferId, TextureId, FramebufferId>
+{
+    pub(crate) fn is_borrowed_buffer(&self, buffer_id: &BufferId) -> bool {
+        self.buffers.get(buffer_id) == Some(&ExternalOwnership::Borrowed)
+    }
+
+    pub(crate) fn is_borrowed_texture(&self, texture_id: &TextureId) -> bool {
+        self.textures
+            .get(texture_id)
+            .is_some_and(|(_, ownership)| *ownership == ExternalOwnership::Borrowed)
+    }
+
+    pub(crate) fn is_borrowed_framebuffer(&self, framebuffer_id: &FramebufferId) -> bool {
+        self.framebuffers.get(framebuffer_id) == Some(&ExternalOwnership::Borrowed)
+    }
+}
+
+impl<BufferId: Id, TextureId: Id, FramebufferId: Id> Default
+    for ExternalResources<BufferId, TextureId, FramebufferId>
+{
+    fn default() -> Self {
+        Self {
+            buffers: HashMap::new(),
+            textures: HashMap::new(),
+            framebuffers: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExternalResources;
+    use crate::{ExternalOwnership, ExternalTextureDescriptor};
+
+    #[test]
+    fn only_borrowed_resources_are_protected() {
+        let mut resources = ExternalResources::<&str, &str, &str>::default();
+        resources
+            .buffers
+            .insert("borrowed", ExternalOwnership::Borrowed);
+        resources.buffers.insert("owned", ExternalOwnership::Owned);
+        resources.textures.insert(
+            "video",
+            (
+                ExternalTextureDescriptor::new(640, 480, 0x8058),
+                ExternalOwnership::default(),
+            ),
+        );
+
+        assert!(resources.is_borrowed_buffer(&"borrowed"));
+        assert!(!resources.is_borrowed_buffer(&"owned"));
+        assert!(!resources.is_borrowed_buffer(&"created_by_wrend"));
+        assert!(resources.is_borrowed_texture(&"video"));
+        assert!(!resources.is_borrowed_framebuffer(&"video"));
+    }
+}
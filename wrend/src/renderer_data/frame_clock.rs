@@ -0,0 +1,108 @@
+use crate::{AnimationLoop, DeterministicMode, LoopTime, TimeScale};
+use std::cell::Cell;
+use web_sys::window;
+
+/// Where [crate::RendererData] gets the current time from when updating uniforms:
+/// `performance.now()`, unless a fixed time has been set (e.g. to render a specific instant),
+/// or a [DeterministicMode] is counting frames instead. Real time can also be slowed down (e.g. for
+/// reduced motion), in which case it continues from the current time at the new speed.
+///
+/// Also keeps track of the current [AnimationLoop], since loop time is derived from the same clock.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FrameClock {
+    time_override: Cell<Option<f64>>,
+    animation_loop: Cell<Option<AnimationLoop>>,
+    deterministic_mode: Cell<Option<DeterministicMode>>,
+    frame: Cell<u64>,
+    time_scale: Cell<TimeScale>,
+}
+
+impl FrameClock {
+    /// Current time in milliseconds
+    pub fn now(&self) -> f64 {
+        if let Some(time_override) = self.time_override.get() {
+            return time_override;
+        }
+        match self.deterministic_mode.get() {
+            Some(deterministic_mode) => deterministic_mode.time_at_frame(self.frame.get()),
+            None => self.time_scale.get().at(Self::real_now()),
+        }
+    }
+
+    fn real_now() -> f64 {
+        window().unwrap().performance().unwrap().now()
+    }
+
+    pub fn time_scale(&self) -> f64 {
+        self.time_scale.get().scale()
+    }
+
+    /// Negative scales are treated as `0.0`, which freezes time
+    pub fn set_time_scale(&self, time_scale: f64) -> &Self {
+        if time_scale != self.time_scale() {
+            let rescaled = self.time_scale.get().rescaled(time_scale, Self::real_now());
+            self.time_scale.set(rescaled);
+        }
+        self
+    }
+
+    pub fn time_override(&self) -> Option<f64> {
+        self.time_override.get()
+    }
+
+    pub fn set_time_override(&self, time_override: Option<f64>) -> &Self {
+        self.time_override.set(time_override);
+        self
+    }
+
+    pub fn animation_loop(&self) -> Option<AnimationLoop> {
+        self.animation_loop.get()
+    }
+
+    pub fn set_animation_loop(&self, animation_loop: Option<AnimationLoop>) -> &Self {
+        self.animation_loop.set(animation_loop);
+        self
+    }
+
+    pub fn loop_time_at(&self, now: f64) -> Option<LoopTime> {
+        self.animation_loop
+            .get()
+            .map(|animation_loop| animation_loop.time_at(now))
+    }
+
+    pub fn deterministic_mode(&self) -> Option<DeterministicMode> {
+        self.deterministic_mode.get()
+    }
+
+    /// Also restarts the frame count, so that the first frame afterward is at time 0
+    pub fn set_deterministic_mode(&self, deterministic_mode: Option<DeterministicMode>) -> &Self {
+        self.deterministic_mode.set(deterministic_mode);
+        self.frame.set(0);
+        self
+    }
+
+    /// Number of frames rendered since deterministic mode was enabled
+    pub fn frame(&self) -> u64 {
+        self.frame.get()
+    }
+
+    /// Steps deterministic time forward after a frame, unless a fixed time is being rendered
+    pub fn advance_frame(&self) -> &Self {
+        if self.deterministic_mode.get().is_some() && self.time_override.get().is_none() {
+            self.frame.set(self.frame.get() + 1);
+        }
+        self
+    }
+}
+
+impl PartialEq for FrameClock {
+    fn eq(&self, other: &Self) -> bool {
+        self.time_override.get().map(f64::to_bits) == other.time_override.get().map(f64::to_bits)
+            && self.animation_loop.get() == other.animation_loop.get()
+            && self.deterministic_mode.get() == other.deterministic_mode.get()
+            && self.frame.get() == other.frame.get()
+            && self.time_scale.get() == other.time_scale.get()
+    }
+}
+
+impl Eq for FrameClock {}
@@ -0,0 +1,28 @@
+use crate::SavedBlendState;
+
+/// Returned by [crate::RendererData::begin_frame] and handed back to
+/// [crate::RendererData::finish_frame], so that every render path (Rust or JavaScript callbacks)
+/// does the same work around the render callback
+#[derive(Debug)]
+pub(crate) struct FrameScope {
+    start_ms: f64,
+    blend_state: Option<SavedBlendState>,
+}
+
+impl FrameScope {
+    pub(crate) fn new(start_ms: f64, blend_state: Option<SavedBlendState>) -> Self {
+        Self {
+            start_ms,
+            blend_state,
+        }
+    }
+
+    pub(crate) fn start_ms(&self) -> f64 {
+        self.start_ms
+    }
+
+    /// Blend state to restore, if the frame changed it
+    pub(crate) fn blend_state(&self) -> Option<SavedBlendState> {
+        self.blend_state
+    }
+}
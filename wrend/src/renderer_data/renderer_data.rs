@@ -1,19 +1,71 @@
+#[cfg(any(feature = "gif", feature = "apng"))]
+use crate::bytes_to_blob;
 use crate::{
-    Attribute, AttributeLink, Bridge, Buffer, BufferLink, BuildRendererError, Callback,
-    CompileShaderError, CreateAttributeError, CreateBufferError, CreateTextureError,
-    CreateTransformFeedbackError, CreateUniformError, CreateVAOError, Framebuffer, FramebufferLink,
-    GetContextCallback, Id, IdDefault, IdName, LinkProgramError, ProgramLink, RenderCallback,
-    Renderer, RendererBuilderError, RendererDataJs, RendererDataJsInner, SaveContextError,
-    ShaderType, Texture, TextureLink, TransformFeedbackLink, Uniform, UniformContext, UniformLink,
-    WebGlContextError,
+    blit_framebuffer, build_programs, check_complete, check_copy_compatible, check_sampler_units,
+    clear_error_overlay, clear_texture_level, compile_shader, component_type, copy_texture_region,
+    create_transform_feedbacks, create_vertex_arrays, error_overlay_message,
+    invalidate_framebuffer, sampler_target, show_error_overlay, AnimationLoop, Attribute,
+    AttributeKey, AttributeLink, BitmapTexture, BlitFilter, Bridge, Buffer, BufferLink, BufferPair,
+    BufferPairLink, BufferSnapshot, BuildRendererError, Callback, CanvasColorSpace, CapturedFrame,
+    ClearValue, CommandList, CopyTextureError, CreateAttributeError, CreateBufferError,
+    CreateTextureError, CreateTransformFeedbackError, CreateUniformError, CreateVAOError,
+    DebugEdge, DebugInfo, DebugResource, DebugResourceKind, DebugView, DeterministicMode, DrawCall,
+    ExternalOwnership, ExternalResourceError, ExternalResources, ExternalTextureDescriptor,
+    FeedbackHazard, FrameCaptureOptions, FrameChecksum, FrameClock, FrameExportError, FrameScope,
+    FrameTracer, FrameWatchdog, Framebuffer, FramebufferLink, GetContextCallback, GlCapabilities,
+    Heatmap, Id, IdDefault, IdName, LinkGraph, LinkProgramError, LongFrameCallback,
+    LongFrameReport, LoopTime, MouseUniformSpace, NamespacedId, Noise, NonFiniteCheck,
+    NonFiniteReport, OutputAdjustment, OutputPass, PointerState, PointerTracker, ProgramDescriptor,
+    ProgramLink, ProgramSources, ProgramValidationWarning, RenderCallback, RenderHooks, Renderer,
+    RendererBuilderError, Rng, SamplerTextureUnits, SaveContextError, SavedBindings,
+    SavedBlendState, SavedTextureUnits, ScratchFramebuffer, ShaderCache, ShaderHeaderOptions,
+    ShaderType, StateChanges, StateIds, StateSnapshot, StateSnapshotError, Texture, TextureGuard,
+    TextureInspector, TextureLink, TextureSnapshot, TextureUnits, TraceCategory,
+    TransformFeedbackLink, Uniform, UniformContext, UniformDefault, UniformGroup,
+    UniformGroupCache, UniformLink, VectorField, VectorFieldMode, ViewContext, ViewLayout,
+    Viewport, WebGl2Backend, WebGlContextError, WebGlHandlesBackend, COMPOSITING_VERTEX_SHADER,
+    DEFAULT_COLOR_ATTACHMENT, HEATMAP_FRAGMENT_SHADER, NON_FINITE_CHECK_FRAGMENT_SHADER,
+    OUTPUT_ADJUSTMENT_FRAGMENT_SHADER, TEXTURE_INSPECTOR_FRAGMENT_SHADER,
+    VECTOR_FIELD_ARROWS_FRAGMENT_SHADER, VECTOR_FIELD_ARROWS_VERTEX_SHADER,
+    VECTOR_FIELD_LIC_FRAGMENT_SHADER,
+};
+#[cfg(feature = "apng")]
+use crate::{encode_apng, ApngOptions};
+#[cfg(feature = "gif")]
+use crate::{encode_gif, GifOptions};
+#[cfg(feature = "shader-toy")]
+use crate::{wrap_shader_toy_source, ShaderToyUniform, SHADER_TOY_VERTEX_SHADER};
+#[cfg(feature = "compositing")]
+use crate::{
+    BlurPyramid, ColorVisionFilter, OverlayLayer, BLUR_DOWNSAMPLE_FRAGMENT_SHADER,
+    BLUR_UPSAMPLE_FRAGMENT_SHADER, COLOR_VISION_FRAGMENT_SHADER, COMPARISON_WIPE_FRAGMENT_SHADER,
+    CROSS_FADE_FRAGMENT_SHADER, OVERLAY_FRAGMENT_SHADER,
+};
+#[cfg(feature = "boids")]
+use crate::{
+    Boids, BOIDS_DRAW_FRAGMENT_SHADER, BOIDS_DRAW_VERTEX_SHADER, BOIDS_UPDATE_FRAGMENT_SHADER,
+    BOIDS_UPDATE_VERTEX_SHADER,
+};
+#[cfg(feature = "automata")]
+use crate::{CellularAutomaton, CELLULAR_AUTOMATON_FRAGMENT_SHADER};
+#[cfg(feature = "js")]
+use crate::{RendererDataJs, RendererDataJsInner};
+
+use js_sys::{Date, Float32Array, Reflect};
+use log::{info, warn};
+use std::{
+    cell::{Cell, RefCell},
+    collections::{HashMap, HashSet},
+    rc::Rc,
 };
-
-use std::collections::{HashMap, HashSet};
 
 use wasm_bindgen::{JsCast, JsValue};
+#[cfg(any(feature = "gif", feature = "apng"))]
+use web_sys::Blob;
 use web_sys::{
-    window, HtmlAnchorElement, HtmlCanvasElement, WebGl2RenderingContext, WebGlProgram,
-    WebGlShader, WebGlTransformFeedback, WebGlVertexArrayObject,
+    window, HtmlAnchorElement, HtmlCanvasElement, WebGl2RenderingContext, WebGlBuffer,
+    WebGlFramebuffer, WebGlProgram, WebGlShader, WebGlTexture, WebGlTransformFeedback,
+    WebGlVertexArrayObject,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -48,14 +100,63 @@ pub struct RendererData<
         VertexArrayObjectId,
         UserCtx,
     >,
-    uniforms: HashMap<UniformId, Uniform<ProgramId, UniformId>>,
+    /// Stored contiguously, since every uniform is visited each frame by [RendererData::update_uniforms]
+    uniforms: Vec<Uniform<ProgramId, UniformId>>,
+    uniform_indices: HashMap<UniformId, usize>,
+    uniform_group_cache: UniformGroupCache<ProgramId>,
+    debug_view: Cell<DebugView>,
+    output_pass: OutputPass<ProgramId>,
     user_ctx: Option<UserCtx>,
-    attributes: HashMap<AttributeId, Attribute<VertexArrayObjectId, BufferId, AttributeId>>,
+    attributes: HashMap<
+        AttributeKey<AttributeId, VertexArrayObjectId>,
+        Attribute<VertexArrayObjectId, BufferId, AttributeId>,
+    >,
     buffers: HashMap<BufferId, Buffer<BufferId>>,
+    buffer_pairs: HashMap<BufferId, BufferPair<BufferId, VertexArrayObjectId>>,
     textures: HashMap<TextureId, Texture<TextureId>>,
+    texture_units: TextureUnits<TextureId>,
+    program_validation_warnings: Vec<ProgramValidationWarning<ProgramId>>,
     vertex_array_objects: HashMap<VertexArrayObjectId, WebGlVertexArrayObject>,
+    empty_vao: WebGlVertexArrayObject,
     framebuffers: HashMap<FramebufferId, Framebuffer<FramebufferId>>,
+    external_resources: ExternalResources<BufferId, TextureId, FramebufferId>,
+    /// Owns any shaders and programs that came from it, which are therefore never deleted by this `RendererData`
+    shader_cache: Option<ShaderCache>,
     transform_feedbacks: HashMap<TransformFeedbackId, WebGlTransformFeedback>,
+    pointer_tracker: Option<PointerTracker>,
+    frame_tracer: FrameTracer,
+    frame_watchdog: Option<FrameWatchdog>,
+    frame_clock: FrameClock,
+    before_render_hooks: RenderHooks<
+        RenderCallback<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+    >,
+    after_render_hooks: RenderHooks<
+        RenderCallback<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+    >,
 }
 
 /// Public API
@@ -135,10 +236,23 @@ impl<
     }
 
     pub fn uniform(&self, uniform_id: &UniformId) -> Option<&Uniform<ProgramId, UniformId>> {
-        self.uniforms.get(uniform_id)
+        self.uniform_indices
+            .get(uniform_id)
+            .map(|&index| &self.uniforms[index])
     }
 
-    pub fn uniforms(&self) -> &HashMap<UniformId, Uniform<ProgramId, UniformId>> {
+    /// All uniforms, by id.
+    ///
+    /// Uniforms are stored in a `Vec` (see [RendererData::uniforms_in_order]), so this map is built on each call.
+    pub fn uniforms(&self) -> HashMap<&UniformId, &Uniform<ProgramId, UniformId>> {
+        self.uniforms
+            .iter()
+            .map(|uniform| (uniform.uniform_id(), uniform))
+            .collect()
+    }
+
+    /// All uniforms, in the order their links were added
+    pub fn uniforms_in_order(&self) -> &[Uniform<ProgramId, UniformId>] {
         &self.uniforms
     }
 
@@ -150,16 +264,51 @@ impl<
         &self.buffers
     }
 
+    /// Gets an attribute by id, as long as every VAO it's bound in uses the same buffer.
+    ///
+    /// If the same attribute id is bound to different buffers in different VAOs, this returns `None`:
+    /// use [RendererData::attribute_for_vao] to pick a specific binding instead.
     pub fn attribute(
         &self,
         attribute_id: &AttributeId,
     ) -> Option<&Attribute<VertexArrayObjectId, BufferId, AttributeId>> {
-        self.attributes.get(attribute_id)
+        let mut bindings = self
+            .attributes
+            .values()
+            .filter(|attribute| attribute.attribute_id() == attribute_id);
+        let first = bindings.next()?;
+        bindings
+            .all(|attribute| attribute.buffer_id() == first.buffer_id())
+            .then_some(first)
+    }
+
+    /// Gets the attribute bound in a particular VAO (or in the default vertex array, if `vao_id` is `None`)
+    pub fn attribute_for_vao(
+        &self,
+        attribute_id: &AttributeId,
+        vao_id: Option<&VertexArrayObjectId>,
+    ) -> Option<&Attribute<VertexArrayObjectId, BufferId, AttributeId>> {
+        self.attributes
+            .get(&AttributeKey::new(attribute_id.clone(), vao_id.cloned()))
+    }
+
+    /// Every binding of an attribute id, one per VAO it was linked to
+    pub fn attributes_with_id<'a>(
+        &'a self,
+        attribute_id: &'a AttributeId,
+    ) -> impl Iterator<Item = &'a Attribute<VertexArrayObjectId, BufferId, AttributeId>> {
+        self.attributes
+            .iter()
+            .filter(move |(key, _)| key.attribute_id() == attribute_id)
+            .map(|(_, attribute)| attribute)
     }
 
     pub fn attributes(
         &self,
-    ) -> &HashMap<AttributeId, Attribute<VertexArrayObjectId, BufferId, AttributeId>> {
+    ) -> &HashMap<
+        AttributeKey<AttributeId, VertexArrayObjectId>,
+        Attribute<VertexArrayObjectId, BufferId, AttributeId>,
+    > {
         &self.attributes
     }
 
@@ -171,6 +320,206 @@ impl<
         &self.textures
     }
 
+    /// Color space that the canvas is actually displayed in
+    pub fn drawing_buffer_color_space(&self) -> CanvasColorSpace {
+        CanvasColorSpace::drawing_buffer(self.gl())
+    }
+
+    /// Texture unit assigned to a texture when the renderer was built (see [TextureUnits])
+    pub fn texture_unit(&self, texture_id: &TextureId) -> Option<u32> {
+        self.texture_units.unit(texture_id)
+    }
+
+    pub fn texture_units(&self) -> &TextureUnits<TextureId> {
+        &self.texture_units
+    }
+
+    /// Problems found while building, if [RendererDataBuilder::set_validate_programs] was enabled
+    pub fn program_validation_warnings(&self) -> &[ProgramValidationWarning<ProgramId>] {
+        &self.program_validation_warnings
+    }
+
+    /// Collects every resource, the dependencies between them, some stats, and the capabilities of the
+    /// WebGL implementation, e.g. for showing in a devtools panel.
+    ///
+    /// Buffer sizes, program shaders, and framebuffer color attachments are queried from WebGL
+    /// (restoring any bindings that are changed to do so), so this shouldn't be called every frame.
+    pub fn debug_info(&self) -> DebugInfo {
+        type Gl = WebGl2RenderingContext;
+        let gl = self.gl();
+        let mut resources = Vec::new();
+        let mut edges = Vec::new();
+        let id = |kind: DebugResourceKind, id: &dyn std::fmt::Debug| (kind, format!("{id:?}"));
+
+        for vertex_shader_id in self.vertex_shaders.keys() {
+            resources.push(DebugResource::new(
+                DebugResourceKind::VertexShader,
+                format!("{vertex_shader_id:?}"),
+            ));
+        }
+        for fragment_shader_id in self.fragment_shaders.keys() {
+            resources.push(DebugResource::new(
+                DebugResourceKind::FragmentShader,
+                format!("{fragment_shader_id:?}"),
+            ));
+        }
+        for (program_id, program) in &self.programs {
+            resources.push(DebugResource::new(
+                DebugResourceKind::Program,
+                format!("{program_id:?}"),
+            ));
+            let attached_shaders = gl.get_attached_shaders(program).unwrap_or_default();
+            let is_attached =
+                |shader: &JsValue| attached_shaders.iter().any(|attached| &attached == shader);
+            for (vertex_shader_id, shader) in &self.vertex_shaders {
+                if is_attached(shader) {
+                    edges.push(DebugEdge::new(
+                        id(DebugResourceKind::Program, program_id),
+                        id(DebugResourceKind::VertexShader, vertex_shader_id),
+                    ));
+                }
+            }
+            for (fragment_shader_id, shader) in &self.fragment_shaders {
+                if is_attached(shader) {
+                    edges.push(DebugEdge::new(
+                        id(DebugResourceKind::Program, program_id),
+                        id(DebugResourceKind::FragmentShader, fragment_shader_id),
+                    ));
+                }
+            }
+        }
+
+        for uniform in &self.uniforms {
+            let uniform_id = uniform.uniform_id();
+            resources.push(DebugResource {
+                name: Some(uniform_id.name()),
+                ..DebugResource::new(DebugResourceKind::Uniform, format!("{uniform_id:?}"))
+            });
+            for program_id in uniform.program_ids() {
+                edges.push(DebugEdge::new(
+                    id(DebugResourceKind::Uniform, uniform_id),
+                    id(DebugResourceKind::Program, program_id),
+                ));
+            }
+        }
+
+        let previous_copy_read_buffer = gl
+            .get_parameter(Gl::COPY_READ_BUFFER_BINDING)
+            .ok()
+            .and_then(|buffer| buffer.dyn_into::<WebGlBuffer>().ok());
+        for (buffer_id, buffer) in &self.buffers {
+            gl.bind_buffer(Gl::COPY_READ_BUFFER, Some(buffer.webgl_buffer()));
+            let byte_length = gl
+                .get_buffer_parameter(Gl::COPY_READ_BUFFER, Gl::BUFFER_SIZE)
+                .as_f64()
+                .map(|byte_length| byte_length as u32);
+            resources.push(DebugResource {
+                byte_length,
+                external: self.external_resources.buffers.get(buffer_id).copied(),
+                ..DebugResource::new(DebugResourceKind::Buffer, format!("{buffer_id:?}"))
+            });
+        }
+        gl.bind_buffer(Gl::COPY_READ_BUFFER, previous_copy_read_buffer.as_ref());
+
+        for attribute in self.attributes.values() {
+            let attribute_id = attribute.attribute_id();
+            resources.push(DebugResource {
+                name: Some(attribute_id.name()),
+                ..DebugResource::new(DebugResourceKind::Attribute, format!("{attribute_id:?}"))
+            });
+            edges.push(DebugEdge::new(
+                id(DebugResourceKind::Attribute, attribute_id),
+                id(DebugResourceKind::Buffer, attribute.buffer_id()),
+            ));
+            for vao_id in attribute.vao_ids() {
+                edges.push(DebugEdge::new(
+                    id(DebugResourceKind::VertexArrayObject, vao_id),
+                    id(DebugResourceKind::Attribute, attribute_id),
+                ));
+            }
+        }
+        for vao_id in self.vertex_array_objects.keys() {
+            resources.push(DebugResource::new(
+                DebugResourceKind::VertexArrayObject,
+                format!("{vao_id:?}"),
+            ));
+        }
+
+        for texture_id in self.textures.keys() {
+            let external = self.external_resources.textures.get(texture_id);
+            resources.push(DebugResource {
+                size: external
+                    .map(|(descriptor, _)| (descriptor.width() as u32, descriptor.height() as u32)),
+                texture_unit: self.texture_units.unit(texture_id),
+                external: external.map(|(_, ownership)| *ownership),
+                ..DebugResource::new(DebugResourceKind::Texture, format!("{texture_id:?}"))
+            });
+        }
+
+        let previous_read_framebuffer = gl
+            .get_parameter(Gl::READ_FRAMEBUFFER_BINDING)
+            .ok()
+            .and_then(|framebuffer| framebuffer.dyn_into::<WebGlFramebuffer>().ok());
+        for (framebuffer_id, framebuffer) in &self.framebuffers {
+            resources.push(DebugResource {
+                external: self
+                    .external_resources
+                    .framebuffers
+                    .get(framebuffer_id)
+                    .copied(),
+                ..DebugResource::new(
+                    DebugResourceKind::Framebuffer,
+                    format!("{framebuffer_id:?}"),
+                )
+            });
+            gl.bind_framebuffer(Gl::READ_FRAMEBUFFER, Some(framebuffer.webgl_framebuffer()));
+            let Ok(attachment) = gl.get_framebuffer_attachment_parameter(
+                Gl::READ_FRAMEBUFFER,
+                Gl::COLOR_ATTACHMENT0,
+                Gl::FRAMEBUFFER_ATTACHMENT_OBJECT_NAME,
+            ) else {
+                continue;
+            };
+            for (texture_id, texture) in &self.textures {
+                let texture: &JsValue = texture.webgl_texture();
+                if &attachment == texture {
+                    edges.push(DebugEdge::new(
+                        id(DebugResourceKind::Framebuffer, framebuffer_id),
+                        id(DebugResourceKind::Texture, texture_id),
+                    ));
+                }
+            }
+        }
+        gl.bind_framebuffer(Gl::READ_FRAMEBUFFER, previous_read_framebuffer.as_ref());
+
+        for transform_feedback_id in self.transform_feedbacks.keys() {
+            resources.push(DebugResource::new(
+                DebugResourceKind::TransformFeedback,
+                format!("{transform_feedback_id:?}"),
+            ));
+        }
+
+        resources.sort_by(|a, b| (a.kind, &a.id).cmp(&(b.kind, &b.id)));
+        resources.dedup();
+        edges.sort_by(|a, b| (a.from(), a.to()).cmp(&(b.from(), b.to())));
+        edges.dedup();
+
+        DebugInfo {
+            resources,
+            edges,
+            frame_time_ms: self.frame_time(),
+            traced_frames: self.frame_tracer.frame(),
+            trace_event_count: self.frame_tracer.event_count(),
+            drawing_buffer_size: (gl.drawing_buffer_width(), gl.drawing_buffer_height()),
+            program_validation_warnings: self
+                .program_validation_warnings
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            capabilities: GlCapabilities::query(gl),
+        }
+    }
+
     pub fn textures_by_id(
         &self,
         texture_ids: impl Into<Bridge<TextureId>>,
@@ -194,6 +543,194 @@ impl<
         self.framebuffers.get(framebuffer_id)
     }
 
+    pub fn framebuffers(&self) -> &HashMap<FramebufferId, Framebuffer<FramebufferId>> {
+        &self.framebuffers
+    }
+
+    /// Lends a texture to code outside of wrend, such as a third-party WebGL widget sharing the
+    /// context, until the returned [TextureGuard] is dropped.
+    ///
+    /// The guard restores the WebGL state that wrend relies on (bindings, including those of every
+    /// assigned texture unit, capabilities, blending and pixel storage) along with the texture's
+    /// sampling parameters. Returns `None` if the texture doesn't exist.
+    pub fn lend_texture(&self, texture_id: &TextureId) -> Option<TextureGuard> {
+        let texture = self.texture(texture_id)?.webgl_texture().clone();
+        let target = self
+            .external_texture(texture_id)
+            .map_or(WebGl2RenderingContext::TEXTURE_2D, |(descriptor, _)| {
+                descriptor.target()
+            });
+        let units: Vec<u32> = self.texture_units.iter().map(|(_, unit)| unit).collect();
+        Some(TextureGuard::new(self.gl(), texture, target, &units))
+    }
+
+    /// Makes a texture created outside of wrend (e.g. by another library sharing the same WebGL
+    /// context) available under `texture_id`, so that it can be bound, copied and rendered into
+    /// like any texture created from a [crate::TextureLink].
+    ///
+    /// `ownership` decides whether wrend deletes the texture once it is unregistered or replaced.
+    /// Registered textures are kept when the renderer is rebuilt, unless the new configuration
+    /// creates a texture with the same id. Registering under an id that a wrend-created texture
+    /// already uses is an error.
+    pub fn register_external_texture(
+        &mut self,
+        texture_id: TextureId,
+        webgl_texture: WebGlTexture,
+        descriptor: ExternalTextureDescriptor,
+        ownership: ExternalOwnership,
+    ) -> Result<&mut Self, ExternalResourceError> {
+        match self.external_resources.textures.get(&texture_id) {
+            None if self.textures.contains_key(&texture_id) => {
+                return Err(ExternalResourceError::TextureIdInUse(format!(
+                    "{texture_id:?}"
+                )));
+            }
+            Some((_, ExternalOwnership::Owned)) => {
+                let previous = self.textures[&texture_id].webgl_texture();
+                if *previous != webgl_texture {
+                    self.gl.delete_texture(Some(previous));
+                }
+            }
+            _ => {}
+        }
+
+        self.textures.insert(
+            texture_id.clone(),
+            Texture::new(texture_id.clone(), webgl_texture),
+        );
+        self.external_resources
+            .textures
+            .insert(texture_id, (descriptor, ownership));
+        Ok(self)
+    }
+
+    /// Removes a texture registered with [RendererData::register_external_texture], deleting it
+    /// if wrend owns it. Returns `false` if no external texture was registered under `texture_id`.
+    pub fn unregister_external_texture(&mut self, texture_id: &TextureId) -> bool {
+        let Some((_, ownership)) = self.external_resources.textures.remove(texture_id) else {
+            return false;
+        };
+        if let Some(texture) = self.textures.remove(texture_id) {
+            if ownership == ExternalOwnership::Owned {
+                self.gl.delete_texture(Some(texture.webgl_texture()));
+            }
+        }
+        true
+    }
+
+    /// The description and ownership of a texture registered with [RendererData::register_external_texture]
+    pub fn external_texture(
+        &self,
+        texture_id: &TextureId,
+    ) -> Option<(ExternalTextureDescriptor, ExternalOwnership)> {
+        self.external_resources.textures.get(texture_id).copied()
+    }
+
+    /// Like [RendererData::register_external_texture], but for a buffer
+    pub fn register_external_buffer(
+        &mut self,
+        buffer_id: BufferId,
+        webgl_buffer: WebGlBuffer,
+        ownership: ExternalOwnership,
+    ) -> Result<&mut Self, ExternalResourceError> {
+        match self.external_resources.buffers.get(&buffer_id) {
+            None if self.buffers.contains_key(&buffer_id) => {
+                return Err(ExternalResourceError::BufferIdInUse(format!(
+                    "{buffer_id:?}"
+                )));
+            }
+            Some(ExternalOwnership::Owned) => {
+                let previous = self.buffers[&buffer_id].webgl_buffer();
+                if *previous != webgl_buffer {
+                    self.gl.delete_buffer(Some(previous));
+                }
+            }
+            _ => {}
+        }
+
+        self.buffers.insert(
+            buffer_id.clone(),
+            Buffer::new(buffer_id.clone(), webgl_buffer),
+        );
+        self.external_resources.buffers.insert(buffer_id, ownership);
+        Ok(self)
+    }
+
+    /// Like [RendererData::unregister_external_texture], but for a buffer
+    pub fn unregister_external_buffer(&mut self, buffer_id: &BufferId) -> bool {
+        let Some(ownership) = self.external_resources.buffers.remove(buffer_id) else {
+            return false;
+        };
+        if let Some(buffer) = self.buffers.remove(buffer_id) {
+            if ownership == ExternalOwnership::Owned {
+                self.gl.delete_buffer(Some(buffer.webgl_buffer()));
+            }
+        }
+        true
+    }
+
+    /// The ownership of a buffer registered with [RendererData::register_external_buffer]
+    pub fn external_buffer(&self, buffer_id: &BufferId) -> Option<ExternalOwnership> {
+        self.external_resources.buffers.get(buffer_id).copied()
+    }
+
+    /// Like [RendererData::register_external_texture], but for a framebuffer
+    pub fn register_external_framebuffer(
+        &mut self,
+        framebuffer_id: FramebufferId,
+        webgl_framebuffer: WebGlFramebuffer,
+        ownership: ExternalOwnership,
+    ) -> Result<&mut Self, ExternalResourceError> {
+        match self.external_resources.framebuffers.get(&framebuffer_id) {
+            None if self.framebuffers.contains_key(&framebuffer_id) => {
+                return Err(ExternalResourceError::FramebufferIdInUse(format!(
+                    "{framebuffer_id:?}"
+                )));
+            }
+            Some(ExternalOwnership::Owned) => {
+                let previous = self.framebuffers[&framebuffer_id].webgl_framebuffer();
+                if *previous != webgl_framebuffer {
+                    self.gl.delete_framebuffer(Some(previous));
+                }
+            }
+            _ => {}
+        }
+
+        self.framebuffers.insert(
+            framebuffer_id.clone(),
+            Framebuffer::new(framebuffer_id.clone(), webgl_framebuffer),
+        );
+        self.external_resources
+            .framebuffers
+            .insert(framebuffer_id, ownership);
+        Ok(self)
+    }
+
+    /// Like [RendererData::unregister_external_texture], but for a framebuffer
+    pub fn unregister_external_framebuffer(&mut self, framebuffer_id: &FramebufferId) -> bool {
+        let Some(ownership) = self.external_resources.framebuffers.remove(framebuffer_id) else {
+            return false;
+        };
+        if let Some(framebuffer) = self.framebuffers.remove(framebuffer_id) {
+            if ownership == ExternalOwnership::Owned {
+                self.gl
+                    .delete_framebuffer(Some(framebuffer.webgl_framebuffer()));
+            }
+        }
+        true
+    }
+
+    /// The ownership of a framebuffer registered with [RendererData::register_external_framebuffer]
+    pub fn external_framebuffer(
+        &self,
+        framebuffer_id: &FramebufferId,
+    ) -> Option<ExternalOwnership> {
+        self.external_resources
+            .framebuffers
+            .get(framebuffer_id)
+            .copied()
+    }
+
     pub fn transform_feedback(
         &self,
         transform_feedback_id: &TransformFeedbackId,
@@ -205,6 +742,156 @@ impl<
         self.vertex_array_objects.get(vao_id)
     }
 
+    /// Gets a pair of ping-pong buffers by the id of its first buffer (see [BufferPairLink])
+    pub fn buffer_pair(
+        &self,
+        pair_id: &BufferId,
+    ) -> Option<&BufferPair<BufferId, VertexArrayObjectId>> {
+        self.buffer_pairs.get(pair_id)
+    }
+
+    pub fn buffer_pairs(&self) -> &HashMap<BufferId, BufferPair<BufferId, VertexArrayObjectId>> {
+        &self.buffer_pairs
+    }
+
+    /// Flips which buffer of a pair is read from and which is written to.
+    ///
+    /// Typically called once per frame, after drawing with transform feedback.
+    pub fn swap_buffer_pair(&self, pair_id: &BufferId) -> &Self {
+        if let Some(buffer_pair) = self.buffer_pairs.get(pair_id) {
+            buffer_pair.swap();
+        }
+        self
+    }
+
+    /// Reads back the contents of the listed textures and buffers into a [StateSnapshot].
+    ///
+    /// Like any readback, this stalls until the GPU has caught up.
+    pub fn export_state(
+        &self,
+        state_ids: &StateIds<TextureId, BufferId>,
+    ) -> Result<StateSnapshot, StateSnapshotError>
+    where
+        TextureId: IdName,
+        BufferId: IdName,
+    {
+        let gl = self.gl();
+        let textures = state_ids
+            .textures()
+            .iter()
+            .map(|(texture_id, width, height)| {
+                let texture = self
+                    .texture(texture_id)
+                    .ok_or_else(|| StateSnapshotError::TextureNotFound(texture_id.name()))?;
+                TextureSnapshot::read(
+                    gl,
+                    texture_id.name(),
+                    texture.webgl_texture(),
+                    *width,
+                    *height,
+                )
+            })
+            .collect::<Result<_, _>>()?;
+        let buffers = state_ids
+            .buffers()
+            .iter()
+            .map(|buffer_id| {
+                let buffer = self
+                    .buffer(buffer_id)
+                    .ok_or_else(|| StateSnapshotError::BufferNotFound(buffer_id.name()))?;
+                Ok(BufferSnapshot::read(
+                    gl,
+                    buffer_id.name(),
+                    buffer.webgl_buffer(),
+                ))
+            })
+            .collect::<Result<_, StateSnapshotError>>()?;
+
+        Ok(StateSnapshot::new(textures, buffers))
+    }
+
+    /// Writes every texture and buffer in a [StateSnapshot] back into the resource with the same name.
+    ///
+    /// Textures must be at least as large as when they were saved. Buffers are resized to fit.
+    pub fn import_state(&self, snapshot: &StateSnapshot) -> Result<(), StateSnapshotError>
+    where
+        TextureId: IdName,
+        BufferId: IdName,
+    {
+        let gl = self.gl();
+        for texture_snapshot in snapshot.textures() {
+            let texture = self
+                .textures
+                .iter()
+                .find(|(texture_id, _)| texture_id.name() == texture_snapshot.name())
+                .map(|(_, texture)| texture)
+                .ok_or_else(|| {
+                    StateSnapshotError::TextureNotFound(texture_snapshot.name().to_string())
+                })?;
+            texture_snapshot.write(gl, texture.webgl_texture())?;
+        }
+        for buffer_snapshot in snapshot.buffers() {
+            let buffer = self
+                .buffers
+                .iter()
+                .find(|(buffer_id, _)| buffer_id.name() == buffer_snapshot.name())
+                .map(|(_, buffer)| buffer)
+                .ok_or_else(|| {
+                    StateSnapshotError::BufferNotFound(buffer_snapshot.name().to_string())
+                })?;
+            buffer_snapshot.write(gl, buffer.webgl_buffer());
+        }
+
+        Ok(())
+    }
+
+    /// Copies the first `count` floats of a buffer (e.g. one written by transform feedback) back to the CPU.
+    ///
+    /// A fence is inserted and flushed before reading, so the result includes every draw issued so
+    /// far, but this still stalls until the GPU has caught up: use it for snapshots and debugging
+    /// rather than every frame. The buffer must not be bound to an active transform feedback.
+    /// Returns an empty `Vec` if there is no buffer with this id.
+    pub fn read_transform_feedback_buffer(&self, buffer_id: &BufferId, count: u32) -> Vec<f32> {
+        let Some(buffer) = self.buffers.get(buffer_id) else {
+            warn!("Could not find buffer {buffer_id:?} to read back");
+            return Vec::new();
+        };
+        let gl = self.gl();
+
+        if let Some(sync) = gl.fence_sync(WebGl2RenderingContext::SYNC_GPU_COMMANDS_COMPLETE, 0) {
+            gl.flush();
+            gl.client_wait_sync_with_u32(&sync, WebGl2RenderingContext::SYNC_FLUSH_COMMANDS_BIT, 0);
+            gl.delete_sync(Some(&sync));
+        }
+
+        let data = Float32Array::new_with_length(count);
+        gl.bind_buffer(
+            WebGl2RenderingContext::COPY_READ_BUFFER,
+            Some(buffer.webgl_buffer()),
+        );
+        gl.get_buffer_sub_data_with_i32_and_array_buffer_view(
+            WebGl2RenderingContext::COPY_READ_BUFFER,
+            0,
+            &data,
+        );
+        gl.bind_buffer(WebGl2RenderingContext::COPY_READ_BUFFER, None);
+
+        data.to_vec()
+    }
+
+    /// Gets the pointer tracker attached to the canvas, if any mouse uniforms were registered
+    pub fn pointer_tracker(&self) -> Option<&PointerTracker> {
+        self.pointer_tracker.as_ref()
+    }
+
+    /// Records frame, pass, and uniform update timings once enabled, for export to Chrome tracing.
+    ///
+    /// Can be cloned and handed to a [crate::RenderGraph] with [crate::RenderGraph::set_frame_tracer]
+    /// so that its passes show up in the same trace.
+    pub fn frame_tracer(&self) -> &FrameTracer {
+        &self.frame_tracer
+    }
+
     // @todo - enable ctx to be returned unconditionally (depending on if it's set or not)
     pub fn user_ctx(&self) -> Option<&UserCtx> {
         self.user_ctx.as_ref()
@@ -233,92 +920,1691 @@ impl<
         self
     }
 
+    /// Applies the state described by a [DrawCall] and issues its draw.
+    ///
+    /// Uniforms are updated first (since updating a uniform switches programs), followed by
+    /// the program, VAO, framebuffer, viewport, and texture bindings. Any state the draw call
+    /// does not specify is left as-is.
+    pub fn draw(
+        &self,
+        draw_call: &DrawCall<ProgramId, UniformId, TextureId, FramebufferId, VertexArrayObjectId>,
+    ) -> &Self {
+        for uniform_id in draw_call.uniform_ids() {
+            self.update_uniform(uniform_id);
+        }
+
+        self.apply_draw_call(draw_call, StateChanges::ALL);
+
+        self
+    }
+
+    /// Replays a recorded [CommandList].
+    ///
+    /// Every uniform referenced by the list is updated once up front, and then each draw is
+    /// issued in order, skipping program, VAO, and framebuffer changes that would rebind the
+    /// state left by the previous draw.
+    pub fn replay(
+        &self,
+        command_list: &CommandList<
+            ProgramId,
+            UniformId,
+            TextureId,
+            FramebufferId,
+            VertexArrayObjectId,
+        >,
+    ) -> &Self {
+        for uniform_id in command_list.uniform_ids() {
+            self.update_uniform(uniform_id);
+        }
+
+        // updating uniforms unbinds the current program, so the first draw always rebinds it
+        for (draw_call, changes) in command_list
+            .draw_calls()
+            .iter()
+            .zip(command_list.state_changes())
+        {
+            self.apply_draw_call(draw_call, changes);
+        }
+
+        self
+    }
+
+    fn apply_draw_call(
+        &self,
+        draw_call: &DrawCall<ProgramId, UniformId, TextureId, FramebufferId, VertexArrayObjectId>,
+        changes: StateChanges,
+    ) {
+        let gl = self.gl();
+
+        if let Some(program_id) = draw_call.program_id().filter(|_| changes.program) {
+            self.use_program(program_id);
+        }
+
+        if let Some(vao_id) = draw_call.vao_id().filter(|_| changes.vao) {
+            self.use_vao(vao_id);
+        }
+
+        if let Some(framebuffer_id) = draw_call.framebuffer_id().filter(|_| changes.framebuffer) {
+            self.use_framebuffer(framebuffer_id);
+        }
+
+        if let Some(viewport) = draw_call.viewport_rect() {
+            gl.viewport(
+                viewport.x(),
+                viewport.y(),
+                viewport.width(),
+                viewport.height(),
+            );
+        }
+
+        self.bind_textures(draw_call.texture_bindings());
+
+        if let Some(primitive) = draw_call.draw_primitive() {
+            primitive.draw(gl);
+        }
+    }
+
+    /// Binds each texture to its texture unit, replacing sequences of `active_texture`/`bind_texture`
+    /// calls in render callbacks. Leaves the last texture unit in the list active.
+    pub fn bind_textures(&self, textures: &[(TextureId, u32)]) -> &Self {
+        let gl = self.gl();
+        for (texture_id, texture_unit) in textures {
+            let texture = self.texture(texture_id).unwrap_or_else(|| {
+                panic!("Error in `bind_textures`: No corresponding Texture found for TextureId: {texture_id:?}")
+            });
+            gl.active_texture(WebGl2RenderingContext::TEXTURE0 + texture_unit);
+            gl.bind_texture(
+                WebGl2RenderingContext::TEXTURE_2D,
+                Some(texture.webgl_texture()),
+            );
+        }
+
+        self
+    }
+
+    /// Binds a framebuffer for drawing, where `None` binds the canvas
+    pub fn use_framebuffer(&self, framebuffer_id: Option<&FramebufferId>) -> &Self {
+        let framebuffer = framebuffer_id.map(|framebuffer_id| {
+            self.framebuffer(framebuffer_id)
+                .unwrap_or_else(|| panic!("Error in `use_framebuffer`: No corresponding Framebuffer found for FramebufferId: {framebuffer_id:?}"))
+                .webgl_framebuffer()
+        });
+
+        self.gl()
+            .bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, framebuffer);
+
+        self
+    }
+
     /// Updates a single uniform using the previously given update function. If no function was supplied,
     /// then this is a no-op.
     ///
     /// Calls "use_program" on the appropriate program before each uniform's update function (so this is not
     /// necessary to do within the callback itself, unless you need to change programs, for whatever reason).
     pub fn update_uniform(&self, uniform_id: &UniformId) -> &Self {
-        let now = Self::now();
-        let _user_ctx = self.user_ctx();
-        let gl = self.gl();
-        let programs = &self.programs;
         let uniform = self
-            .uniforms
-            .get(uniform_id)
+            .uniform(uniform_id)
             .expect("UniformId should exist in registered uniforms");
 
-        uniform.update(gl, now, programs);
+        let now = self.frame_clock.now();
+        uniform.update_with_loop_time(self.gl(), now, self.frame_clock.loop_time_at(now));
 
         self
     }
 
     /// Iterates through all saved uniforms and updates them using their associated update callbacks.
+    ///
+    /// Only uniforms that are due according to their [crate::UniformUpdateFrequency] are updated.
     pub fn update_uniforms(&self) -> &Self {
-        for uniform_id in self.uniforms.keys() {
-            self.update_uniform(uniform_id);
-        }
+        self.frame_tracer
+            .span("update_uniforms", TraceCategory::Uniforms, || {
+                let gl = self.gl();
+                let now = self.frame_clock.now();
+                let loop_time = self.frame_clock.loop_time_at(now);
+                for uniform in &self.uniforms {
+                    if uniform.is_due(now) {
+                        uniform.update_with_loop_time(gl, now, loop_time);
+                    }
+                }
+            });
 
         self
     }
 
-    /// Note: if a JavaScript `render` callback has been supplied, then this function will NOT call
-    /// try to call it with `RendererData` passed in, because doing so would require copying all internal
-    /// data in order to pass that data into JavaScript, which would be very slow for large `RenderData` objects.
+    /// Forces a uniform to be updated on the next call to [RendererData::update_uniforms],
+    /// which is the only way [crate::UniformUpdateFrequency::OnDemand] uniforms get updated.
+    pub fn invalidate_uniform(&self, uniform_id: &UniformId) -> &Self {
+        self.uniform(uniform_id)
+            .unwrap_or_else(|| {
+                panic!("Error in `invalidate_uniform`: No corresponding uniform found for UniformId: {uniform_id:?}")
+            })
+            .invalidate();
+
+        self
+    }
+
+    /// Uploads every uniform in `group` whose value has changed since it was last set with this method,
+    /// to every program that declares a uniform of that name. Programs that don't use a uniform are skipped.
     ///
-    /// If you want to render using a JavaScript `render` callback, instead consider using the `RendererJs` struct
+    /// Uniform locations are looked up once per program and cached. The previously used program is
+    /// restored afterward. Returns the number of values that were uploaded.
+    pub fn set_uniform_group(&self, group: &impl UniformGroup) -> usize {
+        let gl = self.gl();
+        let previous_program = gl
+            .get_parameter(WebGl2RenderingContext::CURRENT_PROGRAM)
+            .ok()
+            .and_then(|program| program.dyn_into::<WebGlProgram>().ok());
+        let uniforms = group.uniforms();
+        let mut uploaded = 0;
+
+        for (program_id, program) in &self.programs {
+            let mut program_in_use = false;
+            for (name, value) in &uniforms {
+                let Some(location) = self
+                    .uniform_group_cache
+                    .location(gl, program_id, program, name)
+                else {
+                    continue;
+                };
+                if !self.uniform_group_cache.record(program_id, name, *value) {
+                    continue;
+                }
+                if !program_in_use {
+                    gl.use_program(Some(program));
+                    program_in_use = true;
+                }
+                value.upload(gl, &location);
+                uploaded += 1;
+            }
+        }
+
+        if uploaded > 0 {
+            gl.use_program(previous_program.as_ref());
+        }
+
+        uploaded
+    }
+
+    /// Makes the next call to [RendererData::set_uniform_group] upload every value, even unchanged ones
+    /// (e.g. after the same uniforms have been overwritten by update callbacks or external code)
+    pub fn invalidate_uniform_groups(&self) -> &Self {
+        self.uniform_group_cache.clear_values();
+        self
+    }
+
+    /// Switches every program that uses [crate::DEBUG_VIEW_FRAGMENT_GLSL] to `debug_view`.
+    ///
+    /// While [DebugView::Overdraw] is set, [RendererData::render] enables additive blending around
+    /// the render callback, so the canvas should be cleared to black for counts to be accurate.
+    pub fn set_debug_view(&self, debug_view: DebugView) -> &Self {
+        self.debug_view.set(debug_view);
+        self.set_uniform_group(&debug_view);
+        self
+    }
+
+    pub fn debug_view(&self) -> DebugView {
+        self.debug_view.get()
+    }
+
+    /// Applies `output_adjustment` to everything drawn to the canvas, at the end of every frame
+    /// (after the render callback and any hooks), or stops adjusting the output if `None`.
+    ///
+    /// Its program must have been added with [RendererDataBuilder::add_output_adjustment].
+    pub fn set_output_adjustment(
+        &self,
+        output_adjustment: Option<OutputAdjustment<ProgramId>>,
+    ) -> &Self {
+        self.output_pass.set_adjustment(output_adjustment);
+        self
+    }
+
+    pub fn output_adjustment(&self) -> Option<OutputAdjustment<ProgramId>> {
+        self.output_pass.adjustment()
+    }
+
+    /// Copies the canvas and draws it back through the current [OutputAdjustment], if any
+    fn apply_output_adjustment(&self) {
+        let adjustment = match self.output_pass.adjustment() {
+            Some(adjustment) if !adjustment.is_identity() => adjustment,
+            _ => return,
+        };
+        let gl = self.gl();
+        let texture = match self.output_pass.texture(gl) {
+            Ok(texture) => texture,
+            Err(err) => {
+                warn!("Could not create output adjustment texture: {err:?}");
+                return;
+            }
+        };
+
+        self.frame_tracer
+            .span("output adjustment", TraceCategory::Pass, || {
+                self.copy_canvas_into(&texture);
+
+                // the adjusted image should replace the canvas exactly, whatever state the render
+                // callback left behind
+                let capabilities = [
+                    WebGl2RenderingContext::BLEND,
+                    WebGl2RenderingContext::DEPTH_TEST,
+                    WebGl2RenderingContext::SCISSOR_TEST,
+                    WebGl2RenderingContext::STENCIL_TEST,
+                ];
+                let enabled: Vec<u32> = capabilities
+                    .into_iter()
+                    .filter(|capability| gl.is_enabled(*capability))
+                    .collect();
+                for capability in &enabled {
+                    gl.disable(*capability);
+                }
+
+                self.draw_fullscreen_pass(
+                    adjustment.program_id(),
+                    &[("u_source", Some(&texture))],
+                    |gl, program| {
+                        for (name, value) in [
+                            ("u_brightness", adjustment.brightness()),
+                            ("u_contrast", adjustment.contrast()),
+                            ("u_gamma", adjustment.gamma()),
+                        ] {
+                            gl.uniform1f(gl.get_uniform_location(program, name).as_ref(), value);
+                        }
+                    },
+                );
+
+                for capability in enabled {
+                    gl.enable(capability);
+                }
+            });
+    }
+
+    /// Note: if a JavaScript `render` callback has been supplied, then this function will NOT call
+    /// try to call it with `RendererData` passed in, because doing so would require copying all internal
+    /// data in order to pass that data into JavaScript, which would be very slow for large `RenderData` objects.
+    ///
+    /// If you want to render using a JavaScript `render` callback, instead consider using the `RendererJs` struct
     /// (exported to JavaScript as `Renderer`) OR the `RendererDataJs` struct (exported to JavaScript as `RendererData`),
     /// since these two functions automatically pass in `RendererData` if the types are compatible with JavaScript.
     pub fn render(&self) -> &Self {
-        self.render_callback.call_with_rust_arg(self);
+        let frame = self.begin_frame();
+        self.frame_tracer.span("render", TraceCategory::Frame, || {
+            for hook in self.before_render_hooks.hooks() {
+                hook.call_with_rust_arg(self);
+            }
+            self.render_callback.call_with_rust_arg(self);
+            for hook in self.after_render_hooks.hooks() {
+                hook.call_with_rust_arg(self);
+            }
+        });
+        self.finish_frame(frame);
+        self
+    }
+
+    /// Starts timing and tracing a frame, and sets up any state that the current [DebugView] needs.
+    ///
+    /// Every render path must pass the result to [RendererData::finish_frame] once the render
+    /// callback and hooks have run.
+    pub(crate) fn begin_frame(&self) -> FrameScope {
+        let start = Self::now();
+        self.frame_tracer.begin_frame();
+        let blend_state = (self.debug_view() == DebugView::Overdraw).then(|| {
+            let blend_state = SavedBlendState::save(&self.gl);
+            self.gl.enable(WebGl2RenderingContext::BLEND);
+            self.gl.blend_equation(WebGl2RenderingContext::FUNC_ADD);
+            self.gl
+                .blend_func(WebGl2RenderingContext::ONE, WebGl2RenderingContext::ONE);
+            blend_state
+        });
+        FrameScope::new(start, blend_state)
+    }
+
+    /// Ends a frame started with [RendererData::begin_frame]: restores the app's blend state,
+    /// applies the [OutputAdjustment], reports long frames, and advances the frame clock
+    pub(crate) fn finish_frame(&self, frame: FrameScope) {
+        if let Some(blend_state) = frame.blend_state() {
+            blend_state.restore(&self.gl);
+        }
+        self.apply_output_adjustment();
+        self.check_frame_watchdog(frame.start_ms());
+        self.advance_frame_clock();
+    }
+
+    /// Updates uniforms and renders as if the current time were `time_ms`, e.g. to show a specific
+    /// instant of a timeline. The real clock is used again afterward.
+    pub fn render_at(&self, time_ms: f64) -> &Self {
+        let previous_time_override = self.frame_clock.time_override();
+        self.frame_clock.set_time_override(Some(time_ms));
+        self.update_uniforms();
+        self.render();
+        self.frame_clock.set_time_override(previous_time_override);
+        self
+    }
+
+    /// The time (in milliseconds) that uniforms are updated with: `performance.now()`, unless a
+    /// fixed time or [DeterministicMode] is in effect
+    pub fn frame_time(&self) -> f64 {
+        self.frame_clock.now()
+    }
+
+    /// The fixed time (in milliseconds) that uniforms are updated with, if one has been set
+    pub fn time_override(&self) -> Option<f64> {
+        self.frame_clock.time_override()
+    }
+
+    /// Freezes the clock used for uniform updates at `time_override`, or resumes using
+    /// `performance.now()` if `None`
+    pub fn set_time_override(&self, time_override: Option<f64>) -> &Self {
+        self.frame_clock.set_time_override(time_override);
+        self
+    }
+
+    /// Speed that real time passes at for uniform updates, e.g. `0.5` for half speed.
+    ///
+    /// Time carries on from its current value at the new speed, so animations don't jump.
+    /// Negative values are treated as `0.0`, which freezes time. This has no effect on a fixed time
+    /// or [DeterministicMode].
+    pub fn set_time_scale(&self, time_scale: f64) -> &Self {
+        self.frame_clock.set_time_scale(time_scale);
+        self
+    }
+
+    pub fn time_scale(&self) -> f64 {
+        self.frame_clock.time_scale()
+    }
+
+    /// Makes time loop every `animation_loop.duration_ms()` milliseconds (see [RendererData::loop_time]),
+    /// or stops looping if `None`
+    pub fn set_animation_loop(&self, animation_loop: Option<AnimationLoop>) -> &Self {
+        self.frame_clock.set_animation_loop(animation_loop);
+        self
+    }
+
+    pub fn animation_loop(&self) -> Option<AnimationLoop> {
+        self.frame_clock.animation_loop()
+    }
+
+    /// Normalized time and loop count within the current [AnimationLoop], if there is one.
+    ///
+    /// Uniform callbacks can read the same value from [UniformContext::loop_time].
+    pub fn loop_time(&self) -> Option<LoopTime> {
+        self.frame_clock.loop_time_at(self.frame_clock.now())
+    }
+
+    /// Starts an [AnimationLoop] of `duration_ms` milliseconds from the current time
+    pub fn start_loop(&self, duration_ms: f64) -> &Self {
+        self.set_animation_loop(Some(AnimationLoop::new(
+            duration_ms,
+            self.frame_clock.now(),
+        )))
+    }
+
+    /// Makes rendering reproducible (see [DeterministicMode]), or returns to real time if `None`.
+    ///
+    /// Time restarts from 0 whenever this is called.
+    pub fn set_deterministic_mode(&self, deterministic_mode: Option<DeterministicMode>) -> &Self {
+        self.frame_clock.set_deterministic_mode(deterministic_mode);
+        self
+    }
+
+    pub fn deterministic_mode(&self) -> Option<DeterministicMode> {
+        self.frame_clock.deterministic_mode()
+    }
+
+    /// Number of frames rendered since deterministic mode was last set
+    pub fn deterministic_frame(&self) -> u64 {
+        self.frame_clock.frame()
+    }
+
+    /// A random number generator for one use of randomness (e.g. one initial buffer).
+    ///
+    /// In deterministic mode, this is seeded from the mode's seed and `stream`.
+    /// Otherwise, it is seeded from the current time (`Date.now()`) and `stream`.
+    pub fn rng(&self, stream: u64) -> Rng {
+        match self.deterministic_mode() {
+            Some(deterministic_mode) => deterministic_mode.rng(stream),
+            None => Rng::new_with_stream(time_seed(), stream),
+        }
+    }
+
+    /// Noise seeded like [RendererData::rng]
+    pub fn noise(&self, stream: u64) -> Noise {
+        match self.deterministic_mode() {
+            Some(deterministic_mode) => deterministic_mode.noise(stream),
+            None => Noise::new_with_stream(time_seed(), stream),
+        }
+    }
+
+    /// Renders (see [RendererData::render_at]) and reads back each frame described by `options`,
+    /// with uniforms seeing each frame's simulated timestamp instead of the real time.
+    pub fn capture_frames(
+        &self,
+        options: &FrameCaptureOptions,
+    ) -> Result<Vec<CapturedFrame>, FrameExportError> {
+        options
+            .frame_times()
+            .map(|time_ms| {
+                self.render_at(time_ms);
+                CapturedFrame::read_drawing_buffer(self.gl(), time_ms)
+            })
+            .collect()
+    }
+
+    /// Captures frames (see [RendererData::capture_frames]) and encodes them as an animated GIF
+    #[cfg(feature = "gif")]
+    pub fn export_gif(
+        &self,
+        capture_options: &FrameCaptureOptions,
+        gif_options: &GifOptions,
+    ) -> Result<Blob, FrameExportError> {
+        let frames = self.capture_frames(capture_options)?;
+        let gif = encode_gif(&frames, gif_options)?;
+        bytes_to_blob(&gif, "image/gif")
+    }
+
+    /// Captures frames (see [RendererData::capture_frames]) and encodes them as an animated PNG
+    #[cfg(feature = "apng")]
+    pub fn export_apng(
+        &self,
+        capture_options: &FrameCaptureOptions,
+        apng_options: &ApngOptions,
+    ) -> Result<Blob, FrameExportError> {
+        let frames = self.capture_frames(capture_options)?;
+        let apng = encode_apng(&frames, apng_options)?;
+        bytes_to_blob(&apng, "image/apng")
+    }
+
+    /// Renders each view of a layout into its own region of the canvas within a single frame.
+    ///
+    /// Before `callback` is called for a view, the viewport and scissor rect are set to the view's
+    /// region, so that draws and clears only affect that part of the canvas. Per-view camera
+    /// uniforms can be selected using [ViewContext::index]. The full viewport is restored afterward.
+    pub fn render_views(
+        &self,
+        layout: &ViewLayout,
+        callback: impl Fn(&Self, &ViewContext),
+    ) -> &Self {
+        let gl = self.gl();
+        let full = Viewport::full(gl);
+        let was_scissor_enabled = gl.is_enabled(WebGl2RenderingContext::SCISSOR_TEST);
+
+        gl.enable(WebGl2RenderingContext::SCISSOR_TEST);
+        for (i, (view, viewport)) in layout
+            .views()
+            .iter()
+            .zip(layout.viewports(full.width(), full.height()))
+            .enumerate()
+        {
+            viewport.apply(gl);
+            callback(self, &ViewContext::new(i, view, viewport));
+        }
+
+        full.apply(gl);
+        if !was_scissor_enabled {
+            gl.disable(WebGl2RenderingContext::SCISSOR_TEST);
+        }
+
+        self
+    }
+
+    /// Copies the bottom-left `width` x `height` region of one texture into another using
+    /// `copyTexSubImage2D`, without needing a pass-through draw call (e.g. to capture the previous frame).
+    ///
+    /// Both textures must be color-renderable, and integer formats can only be copied into integer
+    /// formats of the same signedness.
+    pub fn copy_texture(
+        &self,
+        src_texture_id: &TextureId,
+        dst_texture_id: &TextureId,
+        width: i32,
+        height: i32,
+    ) -> Result<(), CopyTextureError> {
+        let texture = |texture_id: &TextureId| {
+            self.texture(texture_id)
+                .map(|texture| texture.webgl_texture())
+                .ok_or_else(|| CopyTextureError::TextureNotFound(format!("{texture_id:?}")))
+        };
+
+        copy_texture_region(
+            self.gl(),
+            texture(src_texture_id)?,
+            texture(dst_texture_id)?,
+            width,
+            height,
+        )
+    }
+
+    /// Copies the canvas's current contents into a texture with `copyTexImage2D`, resizing the
+    /// texture to match the canvas (it becomes an `RGBA8` texture, or `RGB8` if the context was
+    /// created without `alpha`). Useful for feedback effects
+    /// that need the last frame's output, since the canvas is cleared after every frame unless
+    /// `preserveDrawingBuffer` is enabled.
+    ///
+    /// Must be called in the same task as the frame was drawn in (e.g. at the end of the render
+    /// callback, or in an after-render hook), before the browser presents the canvas.
+    pub fn capture_canvas_to_texture(
+        &self,
+        texture_id: &TextureId,
+    ) -> Result<(), CopyTextureError> {
+        let texture = self
+            .texture(texture_id)
+            .ok_or_else(|| CopyTextureError::TextureNotFound(format!("{texture_id:?}")))?;
+        self.copy_canvas_into(texture.webgl_texture());
+
+        Ok(())
+    }
+
+    /// See [RendererData::capture_canvas_to_texture]
+    fn copy_canvas_into(&self, texture: &WebGlTexture) {
+        let gl = self.gl();
+        let read_framebuffer = gl
+            .get_parameter(WebGl2RenderingContext::READ_FRAMEBUFFER_BINDING)
+            .ok()
+            .and_then(|framebuffer| framebuffer.dyn_into::<WebGlFramebuffer>().ok());
+        // the copy can't add channels that the canvas doesn't have
+        let has_alpha = gl
+            .get_context_attributes()
+            .and_then(|attributes| Reflect::get(&attributes, &JsValue::from_str("alpha")).ok())
+            .and_then(|alpha| alpha.as_bool())
+            .unwrap_or(true);
+        let internal_format = if has_alpha {
+            WebGl2RenderingContext::RGBA8
+        } else {
+            WebGl2RenderingContext::RGB8
+        };
+
+        let saved_units = self.save_texture_units([]);
+        gl.bind_framebuffer(WebGl2RenderingContext::READ_FRAMEBUFFER, None);
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(texture));
+        gl.copy_tex_image_2d(
+            WebGl2RenderingContext::TEXTURE_2D,
+            0,
+            internal_format,
+            0,
+            0,
+            gl.drawing_buffer_width(),
+            gl.drawing_buffer_height(),
+            0,
+        );
+        saved_units.restore(gl);
+        gl.bind_framebuffer(
+            WebGl2RenderingContext::READ_FRAMEBUFFER,
+            read_framebuffer.as_ref(),
+        );
+    }
+
+    /// Clears every texel of a texture to `value` (e.g. to reset simulation state) by attaching
+    /// it to a temporary framebuffer. Integer formats must be cleared with `ClearValue::Int` or
+    /// `ClearValue::Uint`.
+    pub fn clear_texture(
+        &self,
+        texture_id: &TextureId,
+        value: impl Into<ClearValue>,
+    ) -> Result<(), CopyTextureError> {
+        let texture = self
+            .texture(texture_id)
+            .ok_or_else(|| CopyTextureError::TextureNotFound(format!("{texture_id:?}")))?;
+
+        clear_texture_level(self.gl(), texture.webgl_texture(), value.into())
+    }
+
+    /// Copies a region of one framebuffer's color buffer into a region of another using `blitFramebuffer`,
+    /// scaling with `filter` if the regions differ in size. `None` refers to the canvas.
+    pub fn blit(
+        &self,
+        src_framebuffer_id: Option<&FramebufferId>,
+        dst_framebuffer_id: Option<&FramebufferId>,
+        src_rect: Viewport,
+        dst_rect: Viewport,
+        filter: BlitFilter,
+    ) -> Result<(), CopyTextureError> {
+        let framebuffer = |framebuffer_id: Option<&FramebufferId>| match framebuffer_id {
+            None => Ok(None),
+            Some(framebuffer_id) => self
+                .framebuffer(framebuffer_id)
+                .map(|framebuffer| Some(framebuffer.webgl_framebuffer()))
+                .ok_or_else(|| {
+                    CopyTextureError::FramebufferNotFound(format!("{framebuffer_id:?}"))
+                }),
+        };
+
+        blit_framebuffer(
+            self.gl(),
+            framebuffer(src_framebuffer_id)?,
+            framebuffer(dst_framebuffer_id)?,
+            src_rect,
+            dst_rect,
+            filter,
+        )
+    }
+
+    /// Computes a [FrameChecksum] of a framebuffer's color buffer (or the canvas, if `None`),
+    /// assuming it is the size of the drawing buffer. See [RendererData::frame_checksum_region].
+    pub fn frame_checksum(
+        &self,
+        framebuffer_id: Option<&FramebufferId>,
+    ) -> Result<FrameChecksum, CopyTextureError> {
+        let gl = self.gl();
+        let rect = Viewport::new(0, 0, gl.drawing_buffer_width(), gl.drawing_buffer_height());
+        self.frame_checksum_region(framebuffer_id, rect)
+    }
+
+    /// Computes a [FrameChecksum] of a region of a framebuffer's color buffer (or the canvas, if `None`).
+    ///
+    /// The region is downsampled on the GPU with a linear blit, so only a few kilobytes are read back.
+    /// The canvas must be read in the same task as the frame was drawn in, unless the context was
+    /// created with `preserveDrawingBuffer`.
+    pub fn frame_checksum_region(
+        &self,
+        framebuffer_id: Option<&FramebufferId>,
+        rect: Viewport,
+    ) -> Result<FrameChecksum, CopyTextureError> {
+        let source = match framebuffer_id {
+            None => None,
+            Some(framebuffer_id) => Some(
+                self.framebuffer(framebuffer_id)
+                    .ok_or_else(|| {
+                        CopyTextureError::FramebufferNotFound(format!("{framebuffer_id:?}"))
+                    })?
+                    .webgl_framebuffer(),
+            ),
+        };
+
+        let gl = self.gl();
+        let size = FrameChecksum::SIZE;
+        let bindings = SavedBindings::save(gl);
+        let texture = gl
+            .create_texture()
+            .ok_or(CopyTextureError::CreateFramebuffer)?;
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&texture));
+        gl.tex_storage_2d(
+            WebGl2RenderingContext::TEXTURE_2D,
+            1,
+            WebGl2RenderingContext::RGBA8,
+            size,
+            size,
+        );
+
+        let result = (|| {
+            let _downsampled =
+                ScratchFramebuffer::new(gl, WebGl2RenderingContext::DRAW_FRAMEBUFFER, &texture)?;
+            gl.bind_framebuffer(WebGl2RenderingContext::READ_FRAMEBUFFER, source);
+            check_complete(gl, WebGl2RenderingContext::READ_FRAMEBUFFER)?;
+            let attachment = match source {
+                Some(_) => WebGl2RenderingContext::COLOR_ATTACHMENT0,
+                None => DEFAULT_COLOR_ATTACHMENT,
+            };
+            check_copy_compatible(
+                component_type(gl, WebGl2RenderingContext::READ_FRAMEBUFFER, attachment),
+                WebGl2RenderingContext::UNSIGNED_NORMALIZED,
+                BlitFilter::Linear,
+            )?;
+            gl.blit_framebuffer(
+                rect.x(),
+                rect.y(),
+                rect.x() + rect.width(),
+                rect.y() + rect.height(),
+                0,
+                0,
+                size,
+                size,
+                WebGl2RenderingContext::COLOR_BUFFER_BIT,
+                WebGl2RenderingContext::LINEAR,
+            );
+
+            let _readback =
+                ScratchFramebuffer::new(gl, WebGl2RenderingContext::READ_FRAMEBUFFER, &texture)?;
+            let mut pixels = vec![0; (size * size * 4) as usize];
+            if let Err(err) = gl.read_pixels_with_opt_u8_array(
+                0,
+                0,
+                size,
+                size,
+                WebGl2RenderingContext::RGBA,
+                WebGl2RenderingContext::UNSIGNED_BYTE,
+                Some(&mut pixels),
+            ) {
+                warn!("Could not read back pixels for a frame checksum: {err:?}");
+            }
+            Ok(FrameChecksum::from_pixels(&pixels))
+        })();
+
+        bindings.restore(gl);
+        gl.delete_texture(Some(&texture));
+        result
+    }
+
+    /// Scans a float texture for NaN and infinite values with a [NonFiniteCheck], calling its
+    /// callback with the report if any are found.
+    ///
+    /// Bindings disturbed by the scan (framebuffers, the `TEXTURE0` texture, the program, and the
+    /// viewport) are restored afterward.
+    pub fn check_non_finite(
+        &self,
+        check: &NonFiniteCheck<ProgramId>,
+        texture_id: &TextureId,
+    ) -> Result<Option<NonFiniteReport>, CopyTextureError> {
+        let texture = self
+            .webgl_texture(texture_id)
+            .ok_or_else(|| CopyTextureError::TextureNotFound(format!("{texture_id:?}")))?;
+        let Some(program) = self.program(check.program_id()) else {
+            warn!(
+                "Could not find NonFiniteCheck program {:?}",
+                check.program_id()
+            );
+            return Ok(None);
+        };
+
+        let gl = self.gl();
+        let grid_size = NonFiniteCheck::<ProgramId>::GRID_SIZE;
+        let size = grid_size as i32;
+        gl.active_texture(WebGl2RenderingContext::TEXTURE0);
+        let bindings = SavedBindings::save(gl);
+        let previous_program = gl
+            .get_parameter(WebGl2RenderingContext::CURRENT_PROGRAM)
+            .ok()
+            .and_then(|program| program.dyn_into::<WebGlProgram>().ok());
+        let blend_enabled = gl.is_enabled(WebGl2RenderingContext::BLEND);
+        let flags = gl
+            .create_texture()
+            .ok_or(CopyTextureError::CreateFramebuffer)?;
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&flags));
+        gl.tex_storage_2d(
+            WebGl2RenderingContext::TEXTURE_2D,
+            1,
+            WebGl2RenderingContext::RGBA8,
+            size,
+            size,
+        );
+
+        let result = (|| {
+            let _target = ScratchFramebuffer::new(gl, WebGl2RenderingContext::FRAMEBUFFER, &flags)?;
+            gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(texture));
+            gl.use_program(Some(program));
+            gl.uniform1i(gl.get_uniform_location(program, "u_texture").as_ref(), 0);
+            gl.uniform1i(
+                gl.get_uniform_location(program, "u_grid_size").as_ref(),
+                size,
+            );
+            gl.disable(WebGl2RenderingContext::BLEND);
+            gl.viewport(0, 0, size, size);
+            gl.bind_vertex_array(Some(&self.empty_vao));
+            gl.draw_arrays(WebGl2RenderingContext::TRIANGLES, 0, 3);
+            gl.bind_vertex_array(None);
+
+            let mut pixels = vec![0; (size * size * 4) as usize];
+            if let Err(err) = gl.read_pixels_with_opt_u8_array(
+                0,
+                0,
+                size,
+                size,
+                WebGl2RenderingContext::RGBA,
+                WebGl2RenderingContext::UNSIGNED_BYTE,
+                Some(&mut pixels),
+            ) {
+                warn!("Could not read back non-finite flags: {err:?}");
+            }
+            Ok(NonFiniteReport::from_pixels(
+                format!("{texture_id:?}"),
+                self.frame_tracer.frame(),
+                grid_size,
+                &pixels,
+            ))
+        })();
+
+        bindings.restore(gl);
+        gl.use_program(previous_program.as_ref());
+        if blend_enabled {
+            gl.enable(WebGl2RenderingContext::BLEND);
+        }
+        gl.viewport(0, 0, gl.drawing_buffer_width(), gl.drawing_buffer_height());
+        gl.delete_texture(Some(&flags));
+
+        if let Ok(Some(report)) = &result {
+            check.callback().call_with_js_arg(report.clone());
+        }
+        result
+    }
+
+    /// Hints that the contents of a framebuffer's attachments (or the canvas's, if `None`) are no
+    /// longer needed, e.g. a depth buffer once the frame's geometry has been drawn.
+    ///
+    /// This lets tiled mobile GPUs skip writing the attachments back to memory. `attachments` are
+    /// WebGL enums such as `COLOR_ATTACHMENT0` and `DEPTH_ATTACHMENT`, or `COLOR`, `DEPTH` and
+    /// `STENCIL` for the canvas. Returns `false` if the framebuffer could not be found.
+    pub fn invalidate_framebuffer(
+        &self,
+        framebuffer_id: Option<&FramebufferId>,
+        attachments: &[u32],
+    ) -> bool {
+        let framebuffer = match framebuffer_id {
+            None => None,
+            Some(framebuffer_id) => match self.framebuffer(framebuffer_id) {
+                Some(framebuffer) => Some(framebuffer.webgl_framebuffer()),
+                None => return false,
+            },
+        };
+        invalidate_framebuffer(self.gl(), framebuffer, attachments);
+        true
+    }
+
+    /// Draws a program registered with [RendererDataBuilder::add_shader_toy_program] to the canvas.
+    ///
+    /// All of the program's uniforms are updated first, and each supplied texture is bound to the
+    /// texture unit of the `iChannel` with the same index (only the first 4 textures are used).
+    #[cfg(feature = "shader-toy")]
+    pub fn draw_shader_toy(
+        &self,
+        program_id: &ProgramId,
+        channel_texture_ids: &[TextureId],
+    ) -> &Self {
+        let gl = self.gl();
+
+        let now = self.frame_clock.now();
+        let loop_time = self.frame_clock.loop_time_at(now);
+        for uniform in &self.uniforms {
+            if uniform.program_ids().contains(program_id) {
+                uniform.update_with_loop_time(gl, now, loop_time);
+            }
+        }
+
+        self.use_program(program_id);
+        gl.bind_vertex_array(Some(&self.empty_vao));
+
+        let saved_units = self.save_texture_units(0..channel_texture_ids.len().min(4) as u32);
+        for (unit, texture_id) in channel_texture_ids.iter().take(4).enumerate() {
+            gl.active_texture(WebGl2RenderingContext::TEXTURE0 + unit as u32);
+            gl.bind_texture(
+                WebGl2RenderingContext::TEXTURE_2D,
+                self.texture(texture_id)
+                    .map(|texture| texture.webgl_texture()),
+            );
+        }
+        gl.active_texture(WebGl2RenderingContext::TEXTURE0);
+
+        gl.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, None);
+        gl.viewport(0, 0, gl.drawing_buffer_width(), gl.drawing_buffer_height());
+        gl.draw_arrays(WebGl2RenderingContext::TRIANGLES, 0, 3);
+        gl.bind_vertex_array(None);
+        saved_units.restore(gl);
+
+        self
+    }
+
+    /// Blurs a texture with a [BlurPyramid]: downsamples it through every level of the pyramid,
+    /// then upsamples back up to level 0, which then holds the result.
+    ///
+    /// `source_texture_id` should be about twice the size of the pyramid's level 0.
+    /// Leaves the canvas bound for drawing afterward.
+    #[cfg(feature = "compositing")]
+    pub fn render_blur_pyramid(
+        &self,
+        pyramid: &BlurPyramid<ProgramId, TextureId>,
+        source_texture_id: &TextureId,
+    ) -> &Self {
+        self.downsample_blur_pyramid(pyramid, source_texture_id)
+            .upsample_blur_pyramid(pyramid)
+    }
+
+    /// Fills every level of a [BlurPyramid] with a blurred, downsampled copy of the level above it
+    /// (or of `source_texture_id`, for level 0)
+    #[cfg(feature = "compositing")]
+    pub fn downsample_blur_pyramid(
+        &self,
+        pyramid: &BlurPyramid<ProgramId, TextureId>,
+        source_texture_id: &TextureId,
+    ) -> &Self {
+        let Some(texture) = self.webgl_texture(pyramid.texture_id()) else {
+            warn!(
+                "Could not find BlurPyramid texture {:?}",
+                pyramid.texture_id()
+            );
+            return self;
+        };
+
+        for level in 0..pyramid.levels() {
+            let (source, source_level) = match level {
+                0 => (self.webgl_texture(source_texture_id), None),
+                _ => (Some(texture), Some(level - 1)),
+            };
+            self.draw_blur_pyramid_level(
+                pyramid,
+                pyramid.downsample_program_id(),
+                texture,
+                source,
+                source_level,
+                level,
+            );
+        }
+
+        self.restore_blur_pyramid_levels(pyramid, texture)
+    }
+
+    /// Upsamples each level of a [BlurPyramid] into the level above it, from the smallest level to level 0
+    #[cfg(feature = "compositing")]
+    pub fn upsample_blur_pyramid(&self, pyramid: &BlurPyramid<ProgramId, TextureId>) -> &Self {
+        let Some(texture) = self.webgl_texture(pyramid.texture_id()) else {
+            warn!(
+                "Could not find BlurPyramid texture {:?}",
+                pyramid.texture_id()
+            );
+            return self;
+        };
+
+        for level in (0..pyramid.levels().saturating_sub(1)).rev() {
+            self.draw_blur_pyramid_level(
+                pyramid,
+                pyramid.upsample_program_id(),
+                texture,
+                Some(texture),
+                Some(level + 1),
+                level,
+            );
+        }
+
+        self.restore_blur_pyramid_levels(pyramid, texture)
+    }
+
+    /// Draws into `level` of the pyramid, reading from `source_level` of its texture (or from
+    /// `source`, if `None`). Sampling is limited to the source level, so that drawing doesn't form
+    /// a feedback loop.
+    #[cfg(feature = "compositing")]
+    fn draw_blur_pyramid_level(
+        &self,
+        pyramid: &BlurPyramid<ProgramId, TextureId>,
+        program_id: &ProgramId,
+        texture: &WebGlTexture,
+        source: Option<&WebGlTexture>,
+        source_level: Option<u32>,
+        level: u32,
+    ) {
+        let gl = self.gl();
+        let (source_width, source_height) = match source_level {
+            Some(source_level) => pyramid.level_size(source_level),
+            None => {
+                let (width, height) = pyramid.level_size(0);
+                (width * 2, height * 2)
+            }
+        };
+        if let Some(source_level) = source_level {
+            let saved_units = self.save_texture_units([]);
+            gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(texture));
+            gl.tex_parameteri(
+                WebGl2RenderingContext::TEXTURE_2D,
+                WebGl2RenderingContext::TEXTURE_BASE_LEVEL,
+                source_level as i32,
+            );
+            gl.tex_parameteri(
+                WebGl2RenderingContext::TEXTURE_2D,
+                WebGl2RenderingContext::TEXTURE_MAX_LEVEL,
+                source_level as i32,
+            );
+            saved_units.restore(gl);
+        }
+
+        let framebuffer = pyramid.framebuffer(gl, texture, level);
+        let (width, height) = pyramid.level_size(level);
+        let half_texel = [
+            0.5 / source_width as f32 * pyramid.spread(),
+            0.5 / source_height as f32 * pyramid.spread(),
+        ];
+        self.draw_fullscreen_pass_into(
+            program_id,
+            Some(&framebuffer),
+            (width as i32, height as i32),
+            &[("u_source", source)],
+            |gl, program| {
+                gl.uniform2fv_with_f32_array(
+                    gl.get_uniform_location(program, "u_half_texel").as_ref(),
+                    &half_texel,
+                );
+            },
+        );
+        gl.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, None);
+    }
+
+    /// Makes every level of the pyramid's texture available for sampling again
+    #[cfg(feature = "compositing")]
+    fn restore_blur_pyramid_levels(
+        &self,
+        pyramid: &BlurPyramid<ProgramId, TextureId>,
+        texture: &WebGlTexture,
+    ) -> &Self {
+        let gl = self.gl();
+        let saved_units = self.save_texture_units([]);
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(texture));
+        gl.tex_parameteri(
+            WebGl2RenderingContext::TEXTURE_2D,
+            WebGl2RenderingContext::TEXTURE_BASE_LEVEL,
+            0,
+        );
+        gl.tex_parameteri(
+            WebGl2RenderingContext::TEXTURE_2D,
+            WebGl2RenderingContext::TEXTURE_MAX_LEVEL,
+            pyramid.levels() as i32 - 1,
+        );
+        saved_units.restore(gl);
+        let (width, height) = (gl.drawing_buffer_width(), gl.drawing_buffer_height());
+        gl.viewport(0, 0, width, height);
+        self
+    }
+
+    /// Uploads the pending bitmap of a [BitmapTexture], if any, closing the bitmap afterward.
+    /// Returns whether anything was uploaded.
+    pub fn upload_bitmap_texture(&self, bitmap_texture: &BitmapTexture<TextureId>) -> bool {
+        if !bitmap_texture.is_dirty() {
+            return false;
+        }
+        let Some(texture) = self.webgl_texture(bitmap_texture.texture_id()) else {
+            warn!(
+                "Could not find BitmapTexture texture {:?}",
+                bitmap_texture.texture_id()
+            );
+            return false;
+        };
+        let Some(bitmap) = bitmap_texture.take_bitmap() else {
+            return false;
+        };
+
+        let gl = self.gl();
+        let saved_units = self.save_texture_units([]);
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(texture));
+        let result = gl.tex_image_2d_with_u32_and_u32_and_image_bitmap(
+            WebGl2RenderingContext::TEXTURE_2D,
+            0,
+            WebGl2RenderingContext::RGBA as i32,
+            WebGl2RenderingContext::RGBA,
+            WebGl2RenderingContext::UNSIGNED_BYTE,
+            &bitmap,
+        );
+        saved_units.restore(gl);
+        bitmap.close();
+
+        if let Err(err) = result {
+            warn!(
+                "Could not upload bitmap to texture {:?}: {err:?}",
+                bitmap_texture.texture_id()
+            );
+            return false;
+        }
+        true
+    }
+
+    /// Draws `source_texture_id` to the canvas as it would look with the [ColorVisionFilter]'s
+    /// color vision deficiency
+    #[cfg(feature = "compositing")]
+    pub fn draw_color_vision_filter(
+        &self,
+        filter: &ColorVisionFilter<ProgramId>,
+        source_texture_id: &TextureId,
+    ) -> &Self {
+        self.draw_fullscreen_pass(
+            filter.program_id(),
+            &[("u_source", self.webgl_texture(source_texture_id))],
+            |gl, program| {
+                gl.uniform_matrix3fv_with_f32_array(
+                    gl.get_uniform_location(program, "u_matrix").as_ref(),
+                    false,
+                    &filter.matrix(),
+                );
+            },
+        )
+    }
+
+    /// Composites an [OverlayLayer]'s 2D canvas over whatever has been drawn to the WebGL canvas so far,
+    /// re-uploading it first if it has been marked dirty. Call this last, so the overlay ends up on top
+    /// (and in recordings, which don't capture DOM overlays).
+    #[cfg(feature = "compositing")]
+    pub fn draw_overlay(&self, overlay: &OverlayLayer<ProgramId>) -> &Self {
+        let gl = self.gl();
+        let texture = match overlay.upload(gl) {
+            Ok(texture) => texture,
+            Err(err) => {
+                warn!("Could not upload overlay canvas: {err:?}");
+                return self;
+            }
+        };
+
+        let blend_was_enabled = gl.is_enabled(WebGl2RenderingContext::BLEND);
+        gl.enable(WebGl2RenderingContext::BLEND);
+        // the overlay is uploaded with premultiplied alpha
+        gl.blend_func(
+            WebGl2RenderingContext::ONE,
+            WebGl2RenderingContext::ONE_MINUS_SRC_ALPHA,
+        );
+
+        self.draw_fullscreen_pass(
+            overlay.program_id(),
+            &[("u_overlay", Some(&texture))],
+            |gl, program| {
+                gl.uniform1f(
+                    gl.get_uniform_location(program, "u_opacity").as_ref(),
+                    overlay.opacity(),
+                );
+            },
+        );
+
+        if !blend_was_enabled {
+            gl.disable(WebGl2RenderingContext::BLEND);
+        }
+
+        self
+    }
+
+    pub(crate) fn webgl_texture(&self, texture_id: &TextureId) -> Option<&WebGlTexture> {
+        self.texture(texture_id)
+            .map(|texture| texture.webgl_texture())
+    }
+
+    /// A vertex array with no attributes, for passes that generate their vertices in the shader
+    pub(crate) fn empty_vao(&self) -> &WebGlVertexArrayObject {
+        &self.empty_vao
+    }
+
+    /// Saves the bindings of the units in `units` (and of the active unit) that have been assigned
+    /// to a texture, for internal passes that borrow texture units (see [SavedTextureUnits])
+    pub(crate) fn save_texture_units(
+        &self,
+        units: impl IntoIterator<Item = u32>,
+    ) -> SavedTextureUnits {
+        SavedTextureUnits::save(self.gl(), units, |unit| {
+            self.texture_units.texture_id(unit).is_some()
+        })
+    }
+
+    /// Draws a single triangle that covers the whole viewport, using an internal VAO with no attributes.
+    ///
+    /// The vertex shader is expected to derive its positions from `gl_VertexID` (vertices 0, 1 and 2),
+    /// as [COMPOSITING_VERTEX_SHADER] does. Draws into whatever framebuffer and viewport are currently bound,
+    /// and leaves the program in use afterward. Uniforms and textures should be set beforehand.
+    pub fn draw_fullscreen_triangle(&self, program_id: &ProgramId) -> &Self {
+        let gl = self.gl();
+        self.use_program(program_id);
+        gl.bind_vertex_array(Some(&self.empty_vao));
+        gl.draw_arrays(WebGl2RenderingContext::TRIANGLES, 0, 3);
+        gl.bind_vertex_array(None);
+        self
+    }
+
+    /// Draws a fullscreen triangle to the canvas with a program built from [COMPOSITING_VERTEX_SHADER],
+    /// binding each texture to consecutive texture units along with the named sampler uniform.
+    /// `set_uniforms` is called with the program in use, to set any other uniforms.
+    ///
+    /// Textures assigned to those units by [TextureUnits] are bound to them again after drawing.
+    pub(crate) fn draw_fullscreen_pass(
+        &self,
+        program_id: &ProgramId,
+        textures: &[(&str, Option<&WebGlTexture>)],
+        set_uniforms: impl FnOnce(&WebGl2RenderingContext, &WebGlProgram),
+    ) -> &Self {
+        let gl = self.gl();
+        let size = (gl.drawing_buffer_width(), gl.drawing_buffer_height());
+        self.draw_fullscreen_pass_into(program_id, None, size, textures, set_uniforms)
+    }
+
+    /// Same as [RendererData::draw_fullscreen_pass], but draws into `framebuffer` (or the canvas,
+    /// if `None`) with a viewport of `size`
+    pub(crate) fn draw_fullscreen_pass_into(
+        &self,
+        program_id: &ProgramId,
+        framebuffer: Option<&WebGlFramebuffer>,
+        (width, height): (i32, i32),
+        textures: &[(&str, Option<&WebGlTexture>)],
+        set_uniforms: impl FnOnce(&WebGl2RenderingContext, &WebGlProgram),
+    ) -> &Self {
+        let gl = self.gl();
+        let program = match self.program(program_id) {
+            Some(program) => program,
+            None => {
+                warn!("Could not find compositing program {program_id:?}");
+                return self;
+            }
+        };
+
+        gl.use_program(Some(program));
+        gl.bind_vertex_array(Some(&self.empty_vao));
+
+        let saved_units = self.save_texture_units(0..textures.len() as u32);
+        for (unit, (sampler_name, texture)) in textures.iter().enumerate() {
+            gl.active_texture(WebGl2RenderingContext::TEXTURE0 + unit as u32);
+            gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, *texture);
+            gl.uniform1i(
+                gl.get_uniform_location(program, sampler_name).as_ref(),
+                unit as i32,
+            );
+        }
+        gl.active_texture(WebGl2RenderingContext::TEXTURE0);
+        set_uniforms(gl, program);
+
+        gl.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, framebuffer);
+        gl.viewport(0, 0, width, height);
+        gl.draw_arrays(WebGl2RenderingContext::TRIANGLES, 0, 3);
+        gl.bind_vertex_array(None);
+        saved_units.restore(gl);
+
+        self
+    }
+
+    /// Checks whether the current program samples from a texture that is attached to the currently
+    /// bound framebuffer (a feedback loop, which is undefined behavior in WebGL).
+    ///
+    /// This queries WebGL state, so it is relatively slow and is meant for debugging: call it
+    /// right before a draw call that is producing unexpected output. Any hazard found is also
+    /// logged as a warning.
+    pub fn find_feedback_hazard(&self) -> Option<FeedbackHazard<FramebufferId, TextureId>> {
+        let gl = self.gl();
+
+        let bound_framebuffer: WebGlFramebuffer = gl
+            .get_parameter(WebGl2RenderingContext::DRAW_FRAMEBUFFER_BINDING)
+            .ok()?
+            .dyn_into()
+            .ok()?;
+        let framebuffer_id = self
+            .framebuffers
+            .values()
+            .find(|framebuffer| *framebuffer.webgl_framebuffer() == bound_framebuffer)?
+            .framebuffer_id()
+            .clone();
+
+        let attached_texture: WebGlTexture = gl
+            .get_framebuffer_attachment_parameter(
+                WebGl2RenderingContext::DRAW_FRAMEBUFFER,
+                WebGl2RenderingContext::COLOR_ATTACHMENT0,
+                WebGl2RenderingContext::FRAMEBUFFER_ATTACHMENT_OBJECT_NAME,
+            )
+            .ok()?
+            .dyn_into()
+            .ok()?;
+        let texture_id = self
+            .textures
+            .values()
+            .find(|texture| *texture.webgl_texture() == attached_texture)?
+            .texture_id()
+            .clone();
+
+        let program: WebGlProgram = gl
+            .get_parameter(WebGl2RenderingContext::CURRENT_PROGRAM)
+            .ok()?
+            .dyn_into()
+            .ok()?;
+        let active_texture = gl
+            .get_parameter(WebGl2RenderingContext::ACTIVE_TEXTURE)
+            .ok()?
+            .as_f64()? as u32;
+        let num_uniforms = gl
+            .get_program_parameter(&program, WebGl2RenderingContext::ACTIVE_UNIFORMS)
+            .as_f64()? as u32;
+
+        let hazard = (0..num_uniforms).find_map(|i| {
+            let info = gl.get_active_uniform(&program, i)?;
+            if info.type_() != WebGl2RenderingContext::SAMPLER_2D {
+                return None;
+            }
+            let location = gl.get_uniform_location(&program, &info.name())?;
+            let texture_unit = gl.get_uniform(&program, &location).as_f64()? as u32;
+
+            gl.active_texture(WebGl2RenderingContext::TEXTURE0 + texture_unit);
+            let bound_texture = gl
+                .get_parameter(WebGl2RenderingContext::TEXTURE_BINDING_2D)
+                .ok()?;
+
+            (&bound_texture == AsRef::<JsValue>::as_ref(&attached_texture)).then(|| {
+                FeedbackHazard::new(
+                    framebuffer_id.clone(),
+                    texture_id.clone(),
+                    info.name(),
+                    texture_unit,
+                )
+            })
+        });
+
+        gl.active_texture(active_texture);
+
+        if let Some(hazard) = &hazard {
+            warn!("{hazard}");
+        }
+
+        hazard
+    }
+
+    pub fn save_image(&self) {
+        let window = window().unwrap();
+        let document = window.document().unwrap();
+        let body = document.body().unwrap();
+        let a: HtmlAnchorElement = document.create_element("a").unwrap().dyn_into().unwrap();
+        let data_url = self
+            .canvas
+            .to_data_url()
+            .unwrap()
+            .replace("image/png", "image/octet-stream");
+
+        a.style().set_css_text("display: none;");
+        a.set_href(&data_url);
+        a.set_download("image.png");
+
+        body.append_child(&a).unwrap();
+        a.click();
+        body.remove_child(&a).unwrap();
+    }
+
+    /// Moves Renderer into a `Renderer` struct, providing additional functionality like
+    /// managed animations and recording.
+    pub fn into_renderer(
+        self,
+    ) -> Renderer<
+        VertexShaderId,
+        FragmentShaderId,
+        ProgramId,
+        UniformId,
+        BufferId,
+        AttributeId,
+        TextureId,
+        FramebufferId,
+        TransformFeedbackId,
+        VertexArrayObjectId,
+        UserCtx,
+    > {
+        self.into()
+    }
+
+    /// Deletes every WebGL object owned by this `RendererData` that `next` doesn't also use,
+    /// for when `next` replaces it after a rebuild. Borrowed external resources are never deleted,
+    /// and neither are shaders and programs owned by a [ShaderCache], since other renderers
+    /// (or later builds) may be using them.
+    pub(crate) fn delete_objects_not_in(&self, next: &Self) {
+        let gl = &self.gl;
+        let is_cached_shader = |shader: &WebGlShader| {
+            self.shader_cache
+                .as_ref()
+                .is_some_and(|shader_cache| shader_cache.owns_shader(shader))
+        };
+        let is_cached_program = |program: &WebGlProgram| {
+            self.shader_cache
+                .as_ref()
+                .is_some_and(|shader_cache| shader_cache.owns_program(program))
+        };
+
+        for shader in self.vertex_shaders.values() {
+            if !is_cached_shader(shader) && !next.vertex_shaders.values().any(|next| next == shader)
+            {
+                gl.delete_shader(Some(shader));
+            }
+        }
+        for shader in self.fragment_shaders.values() {
+            if !is_cached_shader(shader)
+                && !next.fragment_shaders.values().any(|next| next == shader)
+            {
+                gl.delete_shader(Some(shader));
+            }
+        }
+        for program in self.programs.values() {
+            if !is_cached_program(program) && !next.programs.values().any(|next| next == program) {
+                gl.delete_program(Some(program));
+            }
+        }
+        for (buffer_id, buffer) in &self.buffers {
+            if self.external_resources.is_borrowed_buffer(buffer_id) {
+                continue;
+            }
+            let buffer = buffer.webgl_buffer();
+            if !next
+                .buffers
+                .values()
+                .any(|next| next.webgl_buffer() == buffer)
+            {
+                gl.delete_buffer(Some(buffer));
+            }
+        }
+        for (texture_id, texture) in &self.textures {
+            if self.external_resources.is_borrowed_texture(texture_id) {
+                continue;
+            }
+            let texture = texture.webgl_texture();
+            if !next
+                .textures
+                .values()
+                .any(|next| next.webgl_texture() == texture)
+            {
+                gl.delete_texture(Some(texture));
+            }
+        }
+        for (framebuffer_id, framebuffer) in &self.framebuffers {
+            if self
+                .external_resources
+                .is_borrowed_framebuffer(framebuffer_id)
+            {
+                continue;
+            }
+            let framebuffer = framebuffer.webgl_framebuffer();
+            if !next
+                .framebuffers
+                .values()
+                .any(|next| next.webgl_framebuffer() == framebuffer)
+            {
+                gl.delete_framebuffer(Some(framebuffer));
+            }
+        }
+        for transform_feedback in self.transform_feedbacks.values() {
+            if !next
+                .transform_feedbacks
+                .values()
+                .any(|next| next == transform_feedback)
+            {
+                gl.delete_transform_feedback(Some(transform_feedback));
+            }
+        }
+        for vao in self.vertex_array_objects.values() {
+            if !next.vertex_array_objects.values().any(|next| next == vao) {
+                gl.delete_vertex_array(Some(vao));
+            }
+        }
+        if self.empty_vao != next.empty_vao {
+            gl.delete_vertex_array(Some(&self.empty_vao));
+        }
+        if let Some(texture) = self.output_pass.created_texture() {
+            if next.output_pass.created_texture().as_ref() != Some(&texture) {
+                gl.delete_texture(Some(&texture));
+            }
+        }
+    }
+
+    /// Gets current DOMHighResTimeStamp from performance.now()
+    ///
+    /// WebGL is limited to an f32, so using performance.now() (for now) to limit the size of the f64
+    pub(crate) fn now() -> f64 {
+        window().unwrap().performance().unwrap().now()
+    }
+
+    /// Counts a rendered frame for [DeterministicMode]
+    fn advance_frame_clock(&self) {
+        self.frame_clock.advance_frame();
+    }
+
+    /// Calls the [FrameWatchdog]'s callback with a [LongFrameReport] if the frame that started
+    /// at `start_ms` ran past its threshold
+    fn check_frame_watchdog(&self, start_ms: f64) {
+        let Some(frame_watchdog) = &self.frame_watchdog else {
+            return;
+        };
+        let duration_ms = Self::now() - start_ms;
+        if !frame_watchdog.is_exceeded(duration_ms) {
+            return;
+        }
+
+        let report = self.long_frame_report(duration_ms, frame_watchdog.threshold_ms());
+        frame_watchdog.callback().call_with_js_arg(report);
+    }
+
+    /// Captures the currently bound resources and any pending GL errors
+    fn long_frame_report(&self, duration_ms: f64, threshold_ms: f64) -> LongFrameReport {
+        let gl = self.gl();
+        let frame = self.frame_tracer.frame();
+        let bound = |parameter: u32| gl.get_parameter(parameter).ok();
+
+        let program_id = bound(WebGl2RenderingContext::CURRENT_PROGRAM).and_then(|program| {
+            self.programs
+                .iter()
+                .find(|(_, webgl_program)| AsRef::<JsValue>::as_ref(*webgl_program) == &program)
+                .map(|(program_id, _)| format!("{program_id:?}"))
+        });
+        let framebuffer_id =
+            bound(WebGl2RenderingContext::FRAMEBUFFER_BINDING).and_then(|framebuffer| {
+                self.framebuffers
+                    .iter()
+                    .find(|(_, f)| AsRef::<JsValue>::as_ref(f.webgl_framebuffer()) == &framebuffer)
+                    .map(|(framebuffer_id, _)| format!("{framebuffer_id:?}"))
+            });
+        let vao_id = bound(WebGl2RenderingContext::VERTEX_ARRAY_BINDING).and_then(|vao| {
+            self.vertex_array_objects
+                .iter()
+                .find(|(_, webgl_vao)| AsRef::<JsValue>::as_ref(*webgl_vao) == &vao)
+                .map(|(vao_id, _)| format!("{vao_id:?}"))
+        });
+
+        let active_texture = bound(WebGl2RenderingContext::ACTIVE_TEXTURE)
+            .and_then(|unit| unit.as_f64())
+            .map(|unit| unit as u32)
+            .unwrap_or(WebGl2RenderingContext::TEXTURE0);
+        let texture_units = bound(WebGl2RenderingContext::MAX_COMBINED_TEXTURE_IMAGE_UNITS)
+            .and_then(|units| units.as_f64())
+            .unwrap_or_default() as u32;
+        let bound_textures = (0..texture_units)
+            .filter_map(|unit| {
+                gl.active_texture(WebGl2RenderingContext::TEXTURE0 + unit);
+                let texture = bound(WebGl2RenderingContext::TEXTURE_BINDING_2D)?;
+                self.textures
+                    .iter()
+                    .find(|(_, t)| AsRef::<JsValue>::as_ref(t.webgl_texture()) == &texture)
+                    .map(|(texture_id, _)| (unit, format!("{texture_id:?}")))
+            })
+            .collect();
+        gl.active_texture(active_texture);
+
+        // `getError` only reports one error per call, so drain the queue (with an upper bound, in case of context loss)
+        let gl_errors = (0..32)
+            .map(|_| gl.get_error())
+            .take_while(|error| *error != WebGl2RenderingContext::NO_ERROR)
+            .collect();
+
+        let events = self
+            .frame_tracer
+            .events()
+            .into_iter()
+            .filter(|event| self.frame_tracer.is_enabled() && event.frame() == frame)
+            .collect();
+
+        LongFrameReport {
+            frame,
+            duration_ms,
+            threshold_ms,
+            program_id,
+            framebuffer_id,
+            vao_id,
+            active_texture_unit: active_texture - WebGl2RenderingContext::TEXTURE0,
+            bound_textures,
+            gl_errors,
+            events,
+        }
+    }
+
+    /// Callbacks that run at the start of every [RendererData::render], in order
+    pub fn before_render_hooks(
+        &self,
+    ) -> &RenderHooks<
+        RenderCallback<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+    > {
+        &self.before_render_hooks
+    }
+
+    /// Callbacks that run at the end of every [RendererData::render], in order
+    pub fn after_render_hooks(
+        &self,
+    ) -> &RenderHooks<
+        RenderCallback<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+    > {
+        &self.after_render_hooks
+    }
+
+    /// Registers a callback to run before the render callback on every frame (e.g. to snapshot input or upload
+    /// video frames). Adding a hook under an existing key replaces it.
+    pub fn add_before_render_hook(
+        &mut self,
+        key: impl Into<String>,
+        hook: impl Into<
+            RenderCallback<
+                VertexShaderId,
+                FragmentShaderId,
+                ProgramId,
+                UniformId,
+                BufferId,
+                AttributeId,
+                TextureId,
+                FramebufferId,
+                TransformFeedbackId,
+                VertexArrayObjectId,
+                UserCtx,
+            >,
+        >,
+    ) -> &mut Self {
+        self.before_render_hooks.insert(key, hook.into());
         self
     }
 
-    pub fn save_image(&self) {
-        let window = window().unwrap();
-        let document = window.document().unwrap();
-        let body = document.body().unwrap();
-        let a: HtmlAnchorElement = document.create_element("a").unwrap().dyn_into().unwrap();
-        let data_url = self
-            .canvas
-            .to_data_url()
-            .unwrap()
-            .replace("image/png", "image/octet-stream");
-
-        a.style().set_css_text("display: none;");
-        a.set_href(&data_url);
-        a.set_download("image.png");
-
-        body.append_child(&a).unwrap();
-        a.click();
-        body.remove_child(&a).unwrap();
+    /// Registers a callback to run after the render callback on every frame (e.g. to collect stats).
+    /// Adding a hook under an existing key replaces it.
+    pub fn add_after_render_hook(
+        &mut self,
+        key: impl Into<String>,
+        hook: impl Into<
+            RenderCallback<
+                VertexShaderId,
+                FragmentShaderId,
+                ProgramId,
+                UniformId,
+                BufferId,
+                AttributeId,
+                TextureId,
+                FramebufferId,
+                TransformFeedbackId,
+                VertexArrayObjectId,
+                UserCtx,
+            >,
+        >,
+    ) -> &mut Self {
+        self.after_render_hooks.insert(key, hook.into());
+        self
     }
 
-    /// Moves Renderer into a `Renderer` struct, providing additional functionality like
-    /// managed animations and recording.
-    pub fn into_renderer(
-        self,
-    ) -> Renderer<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    > {
-        self.into()
+    pub fn remove_before_render_hook(&mut self, key: &str) -> &mut Self {
+        self.before_render_hooks.remove(key);
+        self
     }
 
-    /// Gets current DOMHighResTimeStamp from performance.now()
-    ///
-    /// WebGL is limited to an f32, so using performance.now() (for now) to limit the size of the f64
-    fn now() -> f64 {
-        window().unwrap().performance().unwrap().now()
+    pub fn remove_after_render_hook(&mut self, key: &str) -> &mut Self {
+        self.after_render_hooks.remove(key);
+        self
     }
 
     pub fn render_callback(
@@ -372,6 +2658,7 @@ impl<
     }
 }
 
+#[cfg(feature = "js")]
 impl From<RendererDataJsInner> for JsValue {
     fn from(renderer_data: RendererDataJsInner) -> Self {
         let js_renderer: RendererDataJs = renderer_data.into();
@@ -379,6 +2666,15 @@ impl From<RendererDataJsInner> for JsValue {
     }
 }
 
+/// Collects every link and callback needed to build a [RendererData].
+///
+/// Building runs callbacks in a fixed order: shaders are compiled and programs linked, then
+/// buffers and attributes are created, then textures are created and each pinned texture is bound
+/// to its unit (see [TextureUnits]), then framebuffers are created, and only then are uniforms
+/// initialized. Uniform initialize callbacks can therefore rely on a texture being bound to its
+/// unit as soon as they ask for it, and should set sampler uniforms with [crate::UniformContext::texture_unit]
+/// rather than binding textures themselves: any binding they change on an assigned unit is
+/// restored (with a warning) before the next uniform is initialized.
 #[derive(Debug, Clone)]
 pub struct RendererDataBuilder<
     VertexShaderId: Id = IdDefault,
@@ -401,15 +2697,23 @@ pub struct RendererDataBuilder<
     fragment_shaders: HashMap<FragmentShaderId, WebGlShader>,
     program_links: HashSet<ProgramLink<ProgramId, VertexShaderId, FragmentShaderId>>,
     programs: HashMap<ProgramId, WebGlProgram>,
-    uniform_links: HashSet<UniformLink<ProgramId, UniformId>>,
-    uniforms: HashMap<UniformId, Uniform<ProgramId, UniformId>>,
+    // kept in the order they were added, which is the order of `RendererData::uniforms`
+    uniform_links: Vec<UniformLink<ProgramId, UniformId>>,
+    uniforms: Vec<Uniform<ProgramId, UniformId>>,
+    uniform_indices: HashMap<UniformId, usize>,
     buffer_links: HashSet<BufferLink<BufferId>>,
     buffers: HashMap<BufferId, Buffer<BufferId>>,
+    buffer_pairs: HashMap<BufferId, BufferPair<BufferId, VertexArrayObjectId>>,
     attribute_links: HashSet<AttributeLink<VertexArrayObjectId, BufferId, AttributeId>>,
     attribute_locations: HashMap<AttributeId, u32>,
-    attributes: HashMap<AttributeId, Attribute<VertexArrayObjectId, BufferId, AttributeId>>,
+    attributes: HashMap<
+        AttributeKey<AttributeId, VertexArrayObjectId>,
+        Attribute<VertexArrayObjectId, BufferId, AttributeId>,
+    >,
     texture_links: HashSet<TextureLink<TextureId>>,
     textures: HashMap<TextureId, Texture<TextureId>>,
+    texture_units: TextureUnits<TextureId>,
+    sampler_texture_units: Rc<RefCell<SamplerTextureUnits>>,
     framebuffer_links: HashSet<FramebufferLink<FramebufferId, TextureId>>,
     framebuffers: HashMap<FramebufferId, Framebuffer<FramebufferId>>,
     render_callback: Option<
@@ -430,9 +2734,21 @@ pub struct RendererDataBuilder<
     user_ctx: Option<UserCtx>,
     vertex_array_object_links: HashSet<VertexArrayObjectId>,
     vertex_array_objects: HashMap<VertexArrayObjectId, WebGlVertexArrayObject>,
+    empty_vao: Option<WebGlVertexArrayObject>,
     transform_feedback_links: HashSet<TransformFeedbackLink<TransformFeedbackId>>,
     transform_feedbacks: HashMap<TransformFeedbackId, WebGlTransformFeedback>,
     get_context_callback: GetContextCallback,
+    pointer_state: Option<Rc<RefCell<PointerState>>>,
+    pointer_tracker: Option<PointerTracker>,
+    shader_header_options: ShaderHeaderOptions,
+    frame_watchdog: Option<FrameWatchdog>,
+    error_overlay: bool,
+    shader_cache: Option<ShaderCache>,
+    drawing_buffer_color_space: Option<CanvasColorSpace>,
+    unpack_color_space: Option<CanvasColorSpace>,
+    validate_programs: bool,
+    program_validation_warnings: Vec<ProgramValidationWarning<ProgramId>>,
+    uniform_namespaces: HashMap<UniformId, String>,
 }
 
 /// Public API
@@ -572,7 +2888,10 @@ impl<
         &mut self,
         uniform_link: impl Into<UniformLink<ProgramId, UniformId>>,
     ) -> &mut Self {
-        self.uniform_links.insert(uniform_link.into());
+        let uniform_link = uniform_link.into();
+        if !self.uniform_links.contains(&uniform_link) {
+            self.uniform_links.push(uniform_link);
+        }
 
         self
     }
@@ -587,176 +2906,1126 @@ impl<
         for uniform_link in uniform_links {
             self.add_uniform_link(uniform_link);
         }
-
+
+        self
+    }
+
+    /// Saves a link that will be used to build a WebGL buffer at build time.
+    pub fn add_buffer_link(&mut self, buffer_link: impl Into<BufferLink<BufferId>>) -> &mut Self {
+        self.buffer_links.insert(buffer_link.into());
+
+        self
+    }
+
+    pub fn add_buffer_links(
+        &mut self,
+        buffer_links: impl Into<Bridge<BufferLink<BufferId>>>,
+    ) -> &mut Self {
+        let buffer_link_bridge: Bridge<_> = buffer_links.into();
+        let buffer_links: Vec<_> = buffer_link_bridge.into();
+
+        for buffer_link in buffer_links {
+            self.add_buffer_link(buffer_link);
+        }
+
+        self
+    }
+
+    /// Saves a link that will be used to build a a WebGL attribute at build time.
+    pub fn add_attribute_link(
+        &mut self,
+        attribute_link: impl Into<AttributeLink<VertexArrayObjectId, BufferId, AttributeId>>,
+    ) -> &mut Self {
+        let attribute_link = attribute_link.into();
+        let attribute_id = attribute_link.attribute_id().to_owned();
+        // the lowest free location, since links may have been removed before a rebuild
+        let new_attribute_location = (0..)
+            .find(|location| {
+                !self
+                    .attribute_locations
+                    .values()
+                    .any(|used| used == location)
+            })
+            .unwrap_or_default();
+        self.attribute_links.insert(attribute_link);
+        self.attribute_locations
+            .entry(attribute_id)
+            .or_insert(new_attribute_location);
+
+        self
+    }
+
+    /// Saves both buffers of a [BufferPairLink], a VAO for each, and every attribute in both VAOs,
+    /// so that transform feedback can read from one buffer while writing to the other.
+    pub fn add_buffer_pair_link(
+        &mut self,
+        buffer_pair_link: impl Into<BufferPairLink<BufferId, VertexArrayObjectId, AttributeId>>,
+    ) -> &mut Self {
+        let buffer_pair_link = buffer_pair_link.into();
+        self.add_buffer_links(buffer_pair_link.buffer_links().collect::<Vec<_>>());
+        self.add_vao_links(buffer_pair_link.vao_ids().to_vec());
+        self.add_attribute_links(buffer_pair_link.attribute_links().collect::<Vec<_>>());
+        self.buffer_pairs.insert(
+            buffer_pair_link.pair_id().clone(),
+            buffer_pair_link.buffer_pair(),
+        );
+
+        self
+    }
+
+    pub fn add_attribute_links(
+        &mut self,
+        attribute_links: impl Into<Bridge<AttributeLink<VertexArrayObjectId, BufferId, AttributeId>>>,
+    ) -> &mut Self {
+        let attribute_link_bridge: Bridge<_> = attribute_links.into();
+        let attribute_links: Vec<_> = attribute_link_bridge.into();
+
+        for attribute_link in attribute_links {
+            self.add_attribute_link(attribute_link);
+        }
+
+        self
+    }
+
+    /// Saves a link that will be used to build a buffer/attribute pair at build time.
+    pub fn add_texture_link(
+        &mut self,
+        texture_link: impl Into<TextureLink<TextureId>>,
+    ) -> &mut Self {
+        self.texture_links.insert(texture_link.into());
+
+        self
+    }
+
+    pub fn add_texture_links(
+        &mut self,
+        texture_links: impl Into<Bridge<TextureLink<TextureId>>>,
+    ) -> &mut Self {
+        let texture_link_bridge: Bridge<_> = texture_links.into();
+        let texture_links: Vec<_> = texture_link_bridge.into();
+
+        for texture_link in texture_links {
+            self.add_texture_link(texture_link);
+        }
+
+        self
+    }
+
+    /// Saves a link that will be used to build a framebuffer at build time
+    pub fn add_framebuffer_link(
+        &mut self,
+        framebuffer_link: impl Into<FramebufferLink<FramebufferId, TextureId>>,
+    ) -> &mut Self {
+        self.framebuffer_links.insert(framebuffer_link.into());
+
+        self
+    }
+
+    pub fn add_framebuffer_links(
+        &mut self,
+        framebuffer_links: impl Into<Bridge<FramebufferLink<FramebufferId, TextureId>>>,
+    ) -> &mut Self {
+        let framebuffer_link_bridge: Bridge<_> = framebuffer_links.into();
+        let framebuffer_links: Vec<_> = framebuffer_link_bridge.into();
+
+        for framebuffer_link in framebuffer_links {
+            self.add_framebuffer_link(framebuffer_link);
+        }
+
+        self
+    }
+
+    /// Saves a link that will be used to build a transformFeedback at build time
+    pub fn add_transform_feedback_link(
+        &mut self,
+        transform_feedback_link: impl Into<TransformFeedbackLink<TransformFeedbackId>>,
+    ) -> &mut Self {
+        self.transform_feedback_links
+            .insert(transform_feedback_link.into());
+
+        self
+    }
+
+    pub fn add_transform_feedback_links(
+        &mut self,
+        transform_feedback_links: impl Into<Bridge<TransformFeedbackLink<TransformFeedbackId>>>,
+    ) -> &mut Self {
+        let transform_feedback_link_bridge: Bridge<_> = transform_feedback_links.into();
+        let transform_feedback_links: Vec<_> = transform_feedback_link_bridge.into();
+
+        for transform_feedback_link in transform_feedback_links {
+            self.add_transform_feedback_link(transform_feedback_link);
+        }
+
+        self
+    }
+
+    /// Saves an id that will be used to create a VAO at build time
+    ///
+    /// This VAO can then be referenced by `AttributeLink`s. A VAO doesn't need any attributes:
+    /// one with no `AttributeLink`s is valid, and is what draws that generate their geometry from
+    /// `gl_VertexID` should bind (see also [RendererData::draw_fullscreen_triangle]).
+    pub fn add_vao_link(
+        &mut self,
+        vertex_array_object_id: impl Into<VertexArrayObjectId>,
+    ) -> &mut Self {
+        self.vertex_array_object_links
+            .insert(vertex_array_object_id.into());
+
+        self
+    }
+
+    pub fn add_vao_links(
+        &mut self,
+        vao_links: impl Into<Bridge<VertexArrayObjectId>>,
+    ) -> &mut Self {
+        let vao_link_bridge: Bridge<_> = vao_links.into();
+        let vao_links: Vec<_> = vao_link_bridge.into();
+        let vao_links: Vec<VertexArrayObjectId> = vao_links.into_iter().collect();
+
+        for vao_link in vao_links {
+            self.add_vao_link(vao_link);
+        }
+
+        self
+    }
+
+    /// Removes a vertex shader source, e.g. before calling [Renderer::rebuild_with]
+    pub fn remove_vertex_shader_src(&mut self, vertex_shader_id: &VertexShaderId) -> &mut Self {
+        self.vertex_shader_sources.remove(vertex_shader_id);
+        self
+    }
+
+    /// Removes a fragment shader source, e.g. before calling [Renderer::rebuild_with]
+    pub fn remove_fragment_shader_src(
+        &mut self,
+        fragment_shader_id: &FragmentShaderId,
+    ) -> &mut Self {
+        self.fragment_shader_sources.remove(fragment_shader_id);
+        self
+    }
+
+    /// Removes the link for a program
+    pub fn remove_program_link(&mut self, program_id: &ProgramId) -> &mut Self {
+        self.program_links
+            .retain(|program_link| program_link.program_id() != program_id);
+        self
+    }
+
+    /// Removes the link for a uniform
+    pub fn remove_uniform_link(&mut self, uniform_id: &UniformId) -> &mut Self {
+        self.uniform_links
+            .retain(|uniform_link| uniform_link.uniform_id() != uniform_id);
+        self
+    }
+
+    /// Removes the link for a buffer
+    pub fn remove_buffer_link(&mut self, buffer_id: &BufferId) -> &mut Self {
+        self.buffer_links
+            .retain(|buffer_link| buffer_link.buffer_id() != buffer_id);
+        self
+    }
+
+    /// Removes every link for an attribute (in all VAOs), freeing up its attribute location
+    pub fn remove_attribute_link(&mut self, attribute_id: &AttributeId) -> &mut Self {
+        self.attribute_links
+            .retain(|attribute_link| attribute_link.attribute_id() != attribute_id);
+        self.attribute_locations.remove(attribute_id);
+        self
+    }
+
+    /// Removes a buffer pair, along with the buffers, VAOs, and attribute links it created
+    pub fn remove_buffer_pair_link(&mut self, pair_id: &BufferId) -> &mut Self {
+        if let Some(buffer_pair) = self.buffer_pairs.remove(pair_id) {
+            for buffer_id in buffer_pair.buffer_ids() {
+                self.remove_buffer_link(buffer_id);
+            }
+            for vao_id in buffer_pair.vao_ids() {
+                self.remove_vao_link(vao_id);
+            }
+            self.attribute_links.retain(|attribute_link| {
+                !buffer_pair
+                    .buffer_ids()
+                    .contains(attribute_link.buffer_id())
+            });
+        }
         self
     }
 
-    /// Saves a link that will be used to build a WebGL buffer at build time.
-    pub fn add_buffer_link(&mut self, buffer_link: impl Into<BufferLink<BufferId>>) -> &mut Self {
-        self.buffer_links.insert(buffer_link.into());
+    /// Removes the link for a texture
+    pub fn remove_texture_link(&mut self, texture_id: &TextureId) -> &mut Self {
+        self.texture_links
+            .retain(|texture_link| texture_link.texture_id() != texture_id);
+        self
+    }
 
+    /// Removes the link for a framebuffer
+    pub fn remove_framebuffer_link(&mut self, framebuffer_id: &FramebufferId) -> &mut Self {
+        self.framebuffer_links
+            .retain(|framebuffer_link| framebuffer_link.framebuffer_id() != framebuffer_id);
         self
     }
 
-    pub fn add_buffer_links(
+    /// Removes the link for a transform feedback object
+    pub fn remove_transform_feedback_link(
         &mut self,
-        buffer_links: impl Into<Bridge<BufferLink<BufferId>>>,
+        transform_feedback_id: &TransformFeedbackId,
     ) -> &mut Self {
-        let buffer_link_bridge: Bridge<_> = buffer_links.into();
-        let buffer_links: Vec<_> = buffer_link_bridge.into();
-
-        for buffer_link in buffer_links {
-            self.add_buffer_link(buffer_link);
-        }
+        self.transform_feedback_links
+            .retain(|transform_feedback_link| {
+                transform_feedback_link.transform_feedback_id() != transform_feedback_id
+            });
+        self
+    }
 
+    /// Removes a VAO (attributes that still refer to it will fail validation at build time)
+    pub fn remove_vao_link(&mut self, vao_id: &VertexArrayObjectId) -> &mut Self {
+        self.vertex_array_object_links.remove(vao_id);
         self
     }
 
-    /// Saves a link that will be used to build a a WebGL attribute at build time.
-    pub fn add_attribute_link(
+    /// Registers a `vec2` uniform that is kept in sync with the pointer's position over the canvas.
+    ///
+    /// Pointer listeners are attached to the canvas at build time and are removed when the
+    /// `RendererData` is dropped. The uniform is re-uploaded every time uniforms are updated.
+    pub fn add_mouse_uniform(
         &mut self,
-        attribute_link: impl Into<AttributeLink<VertexArrayObjectId, BufferId, AttributeId>>,
+        program_ids: impl Into<Bridge<ProgramId>>,
+        uniform_id: UniformId,
+        mouse_uniform_space: MouseUniformSpace,
     ) -> &mut Self {
-        let attribute_link = attribute_link.into();
-        let attribute_id = attribute_link.attribute_id().to_owned();
-        let new_attribute_location = self.attribute_links.len() as u32;
-        self.attribute_links.insert(attribute_link);
-        self.attribute_locations
-            .insert(attribute_id, new_attribute_location);
+        let pointer_state = Rc::clone(self.pointer_state.get_or_insert_with(Default::default));
+
+        let mut uniform_link =
+            UniformLink::new(program_ids, uniform_id, move |ctx: &UniformContext| {
+                let gl = ctx.gl();
+                let [x, y] = mouse_uniform_space.value(
+                    &pointer_state.borrow(),
+                    gl.drawing_buffer_width() as f32,
+                    gl.drawing_buffer_height() as f32,
+                );
+                gl.uniform2f(Some(ctx.uniform_location()), x, y);
+            });
+        uniform_link.set_use_init_callback_for_update(true);
 
-        self
+        self.add_uniform_link(uniform_link)
     }
 
-    pub fn add_attribute_links(
+    /// Adds a program built from a ShaderToy-style fragment shader (one that defines `mainImage`).
+    ///
+    /// The source is wrapped with the standard ShaderToy uniform declarations and paired with a
+    /// fullscreen-triangle vertex shader, which is saved under `vertex_shader_id`. Every ShaderToy
+    /// uniform referenced in the source is registered using the id returned by `uniform_id` (and
+    /// looked up by its ShaderToy name, e.g. `iTime`), so uniform ids must be unique to this program.
+    /// `iMouse` is driven by the pointer tracker.
+    ///
+    /// Draw the program with [RendererData::draw_shader_toy].
+    #[cfg(feature = "shader-toy")]
+    pub fn add_shader_toy_program(
         &mut self,
-        attribute_links: impl Into<Bridge<AttributeLink<VertexArrayObjectId, BufferId, AttributeId>>>,
+        program_id: ProgramId,
+        vertex_shader_id: VertexShaderId,
+        fragment_shader_id: FragmentShaderId,
+        shader_toy_src: impl AsRef<str>,
+        uniform_id: impl Fn(ShaderToyUniform) -> UniformId,
     ) -> &mut Self {
-        let attribute_link_bridge: Bridge<_> = attribute_links.into();
-        let attribute_links: Vec<_> = attribute_link_bridge.into();
+        let shader_toy_src = shader_toy_src.as_ref();
+
+        self.add_vertex_shader_src(
+            vertex_shader_id.clone(),
+            SHADER_TOY_VERTEX_SHADER.to_string(),
+        )
+        .add_fragment_shader_src(
+            fragment_shader_id.clone(),
+            wrap_shader_toy_source(shader_toy_src),
+        )
+        .add_program_link(ProgramLink::new(
+            program_id.clone(),
+            vertex_shader_id,
+            fragment_shader_id,
+        ));
+
+        for shader_toy_uniform in ShaderToyUniform::ALL {
+            if !shader_toy_uniform.is_used_in(shader_toy_src) {
+                continue;
+            }
 
-        for attribute_link in attribute_links {
-            self.add_attribute_link(attribute_link);
+            let mut uniform_link = match shader_toy_uniform {
+                ShaderToyUniform::Resolution => UniformLink::new(
+                    program_id.clone(),
+                    uniform_id(shader_toy_uniform),
+                    |ctx: &UniformContext| {
+                        let gl = ctx.gl();
+                        let width = gl.drawing_buffer_width() as f32;
+                        let height = gl.drawing_buffer_height() as f32;
+                        gl.uniform3f(Some(ctx.uniform_location()), width, height, 1.0);
+                    },
+                ),
+                ShaderToyUniform::Time => {
+                    let start = Cell::new(None);
+                    UniformLink::new(
+                        program_id.clone(),
+                        uniform_id(shader_toy_uniform),
+                        move |ctx: &UniformContext| {
+                            let start = start.get().unwrap_or_else(|| {
+                                start.set(Some(ctx.now()));
+                                ctx.now()
+                            });
+                            let seconds = ((ctx.now() - start) / 1000.0) as f32;
+                            ctx.gl().uniform1f(Some(ctx.uniform_location()), seconds);
+                        },
+                    )
+                }
+                ShaderToyUniform::TimeDelta => {
+                    let previous = Cell::new(None);
+                    UniformLink::new(
+                        program_id.clone(),
+                        uniform_id(shader_toy_uniform),
+                        move |ctx: &UniformContext| {
+                            let previous = previous.replace(Some(ctx.now())).unwrap_or(ctx.now());
+                            let seconds = ((ctx.now() - previous) / 1000.0) as f32;
+                            ctx.gl().uniform1f(Some(ctx.uniform_location()), seconds);
+                        },
+                    )
+                }
+                ShaderToyUniform::Frame => {
+                    let frame = Cell::new(0);
+                    UniformLink::new(
+                        program_id.clone(),
+                        uniform_id(shader_toy_uniform),
+                        move |ctx: &UniformContext| {
+                            ctx.gl()
+                                .uniform1i(Some(ctx.uniform_location()), frame.get());
+                            frame.set(frame.get() + 1);
+                        },
+                    )
+                }
+                ShaderToyUniform::Mouse => {
+                    let pointer_state =
+                        Rc::clone(self.pointer_state.get_or_insert_with(Default::default));
+                    UniformLink::new(
+                        program_id.clone(),
+                        uniform_id(shader_toy_uniform),
+                        move |ctx: &UniformContext| {
+                            let gl = ctx.gl();
+                            let [x, y, z, w] = ShaderToyUniform::mouse_value(
+                                &pointer_state.borrow(),
+                                gl.drawing_buffer_width() as f32,
+                                gl.drawing_buffer_height() as f32,
+                            );
+                            gl.uniform4f(Some(ctx.uniform_location()), x, y, z, w);
+                        },
+                    )
+                }
+                channel => {
+                    let texture_unit = channel.texture_unit().unwrap_or_default();
+                    UniformLink::new(
+                        program_id.clone(),
+                        uniform_id(shader_toy_uniform),
+                        move |ctx: &UniformContext| {
+                            ctx.gl()
+                                .uniform1i(Some(ctx.uniform_location()), texture_unit);
+                        },
+                    )
+                }
+            };
+
+            uniform_link.set_name(shader_toy_uniform.name());
+            if shader_toy_uniform.texture_unit().is_none() {
+                uniform_link.set_use_init_callback_for_update(true);
+            }
+
+            self.add_uniform_link(uniform_link);
         }
 
         self
     }
 
-    /// Saves a link that will be used to build a buffer/attribute pair at build time.
-    pub fn add_texture_link(
+    /// Adds the program that blends the two sides of a [CrossFade]
+    #[cfg(feature = "compositing")]
+    pub fn add_cross_fade_program(
         &mut self,
-        texture_link: impl Into<TextureLink<TextureId>>,
+        program_id: ProgramId,
+        vertex_shader_id: VertexShaderId,
+        fragment_shader_id: FragmentShaderId,
     ) -> &mut Self {
-        self.texture_links.insert(texture_link.into());
+        self.add_vertex_shader_src(
+            vertex_shader_id.clone(),
+            COMPOSITING_VERTEX_SHADER.to_string(),
+        )
+        .add_fragment_shader_src(
+            fragment_shader_id.clone(),
+            CROSS_FADE_FRAGMENT_SHADER.to_string(),
+        )
+        .add_program_link(ProgramLink::new(
+            program_id,
+            vertex_shader_id,
+            fragment_shader_id,
+        ))
+    }
 
-        self
+    /// Adds the downsample and upsample programs of a [BlurPyramid], along with its texture
+    #[cfg(feature = "compositing")]
+    pub fn add_blur_pyramid(
+        &mut self,
+        pyramid: &BlurPyramid<ProgramId, TextureId>,
+        vertex_shader_id: VertexShaderId,
+        downsample_fragment_shader_id: FragmentShaderId,
+        upsample_fragment_shader_id: FragmentShaderId,
+    ) -> &mut Self {
+        self.add_vertex_shader_src(
+            vertex_shader_id.clone(),
+            COMPOSITING_VERTEX_SHADER.to_string(),
+        )
+        .add_fragment_shader_src(
+            downsample_fragment_shader_id.clone(),
+            BLUR_DOWNSAMPLE_FRAGMENT_SHADER.to_string(),
+        )
+        .add_fragment_shader_src(
+            upsample_fragment_shader_id.clone(),
+            BLUR_UPSAMPLE_FRAGMENT_SHADER.to_string(),
+        )
+        .add_program_link(ProgramLink::new(
+            pyramid.downsample_program_id().clone(),
+            vertex_shader_id.clone(),
+            downsample_fragment_shader_id,
+        ))
+        .add_program_link(ProgramLink::new(
+            pyramid.upsample_program_id().clone(),
+            vertex_shader_id,
+            upsample_fragment_shader_id,
+        ))
+        .add_texture_link(pyramid.texture_link())
     }
 
-    pub fn add_texture_links(
+    /// Adds the program of a [CellularAutomaton], along with both of its textures
+    #[cfg(feature = "automata")]
+    pub fn add_cellular_automaton(
         &mut self,
-        texture_links: impl Into<Bridge<TextureLink<TextureId>>>,
+        automaton: &CellularAutomaton<ProgramId, TextureId>,
+        vertex_shader_id: VertexShaderId,
+        fragment_shader_id: FragmentShaderId,
     ) -> &mut Self {
-        let texture_link_bridge: Bridge<_> = texture_links.into();
-        let texture_links: Vec<_> = texture_link_bridge.into();
+        let [first, second] = automaton.texture_links();
+        self.add_vertex_shader_src(
+            vertex_shader_id.clone(),
+            COMPOSITING_VERTEX_SHADER.to_string(),
+        )
+        .add_fragment_shader_src(
+            fragment_shader_id.clone(),
+            CELLULAR_AUTOMATON_FRAGMENT_SHADER.to_string(),
+        )
+        .add_program_link(ProgramLink::new(
+            automaton.program_id().clone(),
+            vertex_shader_id,
+            fragment_shader_id,
+        ))
+        .add_texture_link(first)
+        .add_texture_link(second)
+    }
 
-        for texture_link in texture_links {
-            self.add_texture_link(texture_link);
-        }
+    /// Adds the update and draw programs of a [Boids] flock, along with its buffer pair, position
+    /// texture and transform feedback
+    #[cfg(feature = "boids")]
+    pub fn add_boids(
+        &mut self,
+        boids: &Boids<
+            ProgramId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+        >,
+        update_shader_ids: (VertexShaderId, FragmentShaderId),
+        draw_shader_ids: (VertexShaderId, FragmentShaderId),
+    ) -> &mut Self {
+        let [update_program_link, draw_program_link] =
+            boids.program_links(update_shader_ids.clone(), draw_shader_ids.clone());
+        self.add_vertex_shader_src(update_shader_ids.0, BOIDS_UPDATE_VERTEX_SHADER.to_string())
+            .add_fragment_shader_src(
+                update_shader_ids.1,
+                BOIDS_UPDATE_FRAGMENT_SHADER.to_string(),
+            )
+            .add_vertex_shader_src(draw_shader_ids.0, BOIDS_DRAW_VERTEX_SHADER.to_string())
+            .add_fragment_shader_src(draw_shader_ids.1, BOIDS_DRAW_FRAGMENT_SHADER.to_string())
+            .add_program_link(update_program_link)
+            .add_program_link(draw_program_link)
+            .add_buffer_pair_link(boids.buffer_pair_link())
+            .add_texture_link(boids.texture_link())
+            .add_transform_feedback_link(boids.transform_feedback_link())
+    }
 
-        self
+    /// Adds the program that draws a [Heatmap]
+    pub fn add_heatmap(
+        &mut self,
+        heatmap: &Heatmap<ProgramId, TextureId>,
+        vertex_shader_id: VertexShaderId,
+        fragment_shader_id: FragmentShaderId,
+    ) -> &mut Self {
+        self.add_vertex_shader_src(
+            vertex_shader_id.clone(),
+            COMPOSITING_VERTEX_SHADER.to_string(),
+        )
+        .add_fragment_shader_src(
+            fragment_shader_id.clone(),
+            HEATMAP_FRAGMENT_SHADER.to_string(),
+        )
+        .add_program_link(ProgramLink::new(
+            heatmap.program_id().clone(),
+            vertex_shader_id,
+            fragment_shader_id,
+        ))
     }
 
-    /// Saves a link that will be used to build a framebuffer at build time
-    pub fn add_framebuffer_link(
+    /// Adds the program that draws a [VectorField] in its [VectorFieldMode]
+    pub fn add_vector_field(
         &mut self,
-        framebuffer_link: impl Into<FramebufferLink<FramebufferId, TextureId>>,
+        field: &VectorField<ProgramId>,
+        vertex_shader_id: VertexShaderId,
+        fragment_shader_id: FragmentShaderId,
     ) -> &mut Self {
-        self.framebuffer_links.insert(framebuffer_link.into());
+        let (vertex_shader_src, fragment_shader_src) = match field.mode() {
+            VectorFieldMode::Arrows => (
+                VECTOR_FIELD_ARROWS_VERTEX_SHADER,
+                VECTOR_FIELD_ARROWS_FRAGMENT_SHADER,
+            ),
+            VectorFieldMode::LineIntegralConvolution => {
+                (COMPOSITING_VERTEX_SHADER, VECTOR_FIELD_LIC_FRAGMENT_SHADER)
+            }
+        };
+        self.add_vertex_shader_src(vertex_shader_id.clone(), vertex_shader_src.to_string())
+            .add_fragment_shader_src(fragment_shader_id.clone(), fragment_shader_src.to_string())
+            .add_program_link(ProgramLink::new(
+                field.program_id().clone(),
+                vertex_shader_id,
+                fragment_shader_id,
+            ))
+    }
 
-        self
+    /// Adds the program that scans textures for a [NonFiniteCheck]
+    pub fn add_non_finite_check(
+        &mut self,
+        check: &NonFiniteCheck<ProgramId>,
+        vertex_shader_id: VertexShaderId,
+        fragment_shader_id: FragmentShaderId,
+    ) -> &mut Self {
+        self.add_vertex_shader_src(
+            vertex_shader_id.clone(),
+            COMPOSITING_VERTEX_SHADER.to_string(),
+        )
+        .add_fragment_shader_src(
+            fragment_shader_id.clone(),
+            NON_FINITE_CHECK_FRAGMENT_SHADER.to_string(),
+        )
+        .add_program_link(ProgramLink::new(
+            check.program_id().clone(),
+            vertex_shader_id,
+            fragment_shader_id,
+        ))
     }
 
-    pub fn add_framebuffer_links(
+    /// Adds the program that draws a [TextureInspector]
+    pub fn add_texture_inspector(
         &mut self,
-        framebuffer_links: impl Into<Bridge<FramebufferLink<FramebufferId, TextureId>>>,
+        inspector: &TextureInspector<ProgramId, TextureId>,
+        vertex_shader_id: VertexShaderId,
+        fragment_shader_id: FragmentShaderId,
     ) -> &mut Self {
-        let framebuffer_link_bridge: Bridge<_> = framebuffer_links.into();
-        let framebuffer_links: Vec<_> = framebuffer_link_bridge.into();
+        self.add_vertex_shader_src(
+            vertex_shader_id.clone(),
+            COMPOSITING_VERTEX_SHADER.to_string(),
+        )
+        .add_fragment_shader_src(
+            fragment_shader_id.clone(),
+            TEXTURE_INSPECTOR_FRAGMENT_SHADER.to_string(),
+        )
+        .add_program_link(ProgramLink::new(
+            inspector.program_id().clone(),
+            vertex_shader_id,
+            fragment_shader_id,
+        ))
+    }
 
-        for framebuffer_link in framebuffer_links {
-            self.add_framebuffer_link(framebuffer_link);
-        }
+    /// Adds the program that draws a [ColorVisionFilter]
+    #[cfg(feature = "compositing")]
+    pub fn add_color_vision_filter(
+        &mut self,
+        filter: &ColorVisionFilter<ProgramId>,
+        vertex_shader_id: VertexShaderId,
+        fragment_shader_id: FragmentShaderId,
+    ) -> &mut Self {
+        self.add_vertex_shader_src(
+            vertex_shader_id.clone(),
+            COMPOSITING_VERTEX_SHADER.to_string(),
+        )
+        .add_fragment_shader_src(
+            fragment_shader_id.clone(),
+            COLOR_VISION_FRAGMENT_SHADER.to_string(),
+        )
+        .add_program_link(ProgramLink::new(
+            filter.program_id().clone(),
+            vertex_shader_id,
+            fragment_shader_id,
+        ))
+    }
 
-        self
+    /// Adds the program that applies an [OutputAdjustment] (see [RendererData::set_output_adjustment])
+    pub fn add_output_adjustment(
+        &mut self,
+        adjustment: &OutputAdjustment<ProgramId>,
+        vertex_shader_id: VertexShaderId,
+        fragment_shader_id: FragmentShaderId,
+    ) -> &mut Self {
+        self.add_vertex_shader_src(
+            vertex_shader_id.clone(),
+            COMPOSITING_VERTEX_SHADER.to_string(),
+        )
+        .add_fragment_shader_src(
+            fragment_shader_id.clone(),
+            OUTPUT_ADJUSTMENT_FRAGMENT_SHADER.to_string(),
+        )
+        .add_program_link(ProgramLink::new(
+            adjustment.program_id().clone(),
+            vertex_shader_id,
+            fragment_shader_id,
+        ))
     }
 
-    /// Saves a link that will be used to build a transformFeedback at build time
-    pub fn add_transform_feedback_link(
+    /// Adds the program that draws a [ComparisonWipe], and starts tracking the pointer so the wipe can be dragged
+    #[cfg(feature = "compositing")]
+    pub fn add_comparison_wipe_program(
         &mut self,
-        transform_feedback_link: impl Into<TransformFeedbackLink<TransformFeedbackId>>,
+        program_id: ProgramId,
+        vertex_shader_id: VertexShaderId,
+        fragment_shader_id: FragmentShaderId,
     ) -> &mut Self {
-        self.transform_feedback_links
-            .insert(transform_feedback_link.into());
+        self.pointer_state.get_or_insert_with(Default::default);
+
+        self.add_vertex_shader_src(
+            vertex_shader_id.clone(),
+            COMPOSITING_VERTEX_SHADER.to_string(),
+        )
+        .add_fragment_shader_src(
+            fragment_shader_id.clone(),
+            COMPARISON_WIPE_FRAGMENT_SHADER.to_string(),
+        )
+        .add_program_link(ProgramLink::new(
+            program_id,
+            vertex_shader_id,
+            fragment_shader_id,
+        ))
+    }
+
+    /// Adds the program that composites an [OverlayLayer] over the final output
+    #[cfg(feature = "compositing")]
+    pub fn add_overlay_program(
+        &mut self,
+        program_id: ProgramId,
+        vertex_shader_id: VertexShaderId,
+        fragment_shader_id: FragmentShaderId,
+    ) -> &mut Self {
+        self.add_vertex_shader_src(
+            vertex_shader_id.clone(),
+            COMPOSITING_VERTEX_SHADER.to_string(),
+        )
+        .add_fragment_shader_src(
+            fragment_shader_id.clone(),
+            OVERLAY_FRAGMENT_SHADER.to_string(),
+        )
+        .add_program_link(ProgramLink::new(
+            program_id,
+            vertex_shader_id,
+            fragment_shader_id,
+        ))
+    }
 
+    /// Configures how `#version` directives and precision qualifiers are injected into
+    /// shader sources before compilation. See [ShaderHeaderOptions]
+    pub fn set_shader_header_options(
+        &mut self,
+        shader_header_options: ShaderHeaderOptions,
+    ) -> &mut Self {
+        self.shader_header_options = shader_header_options;
         self
     }
 
-    pub fn add_transform_feedback_links(
+    pub fn set_get_context_callback(
         &mut self,
-        transform_feedback_links: impl Into<Bridge<TransformFeedbackLink<TransformFeedbackId>>>,
+        get_context_callback: impl Into<GetContextCallback>,
     ) -> &mut Self {
-        let transform_feedback_link_bridge: Bridge<_> = transform_feedback_links.into();
-        let transform_feedback_links: Vec<_> = transform_feedback_link_bridge.into();
+        self.get_context_callback = get_context_callback.into();
+        self
+    }
 
-        for transform_feedback_link in transform_feedback_links {
-            self.add_transform_feedback_link(transform_feedback_link);
-        }
+    /// After every uniform has been initialized, runs `gl.validateProgram` on each program and
+    /// checks that its samplers read from distinct texture units of the right type, logging a
+    /// warning for every problem found (see [RendererData::program_validation_warnings]).
+    ///
+    /// Validation is slow, so this is best enabled only during development.
+    pub fn set_validate_programs(&mut self, validate_programs: bool) -> &mut Self {
+        self.validate_programs = validate_programs;
+        self
+    }
 
+    /// Sets the color space that the canvas is displayed in, e.g. to use the wider gamut of
+    /// Display P3 screens (see [CanvasColorSpace::preferred]). Falls back to sRGB, with a warning,
+    /// if the browser doesn't support it.
+    pub fn set_drawing_buffer_color_space(&mut self, color_space: CanvasColorSpace) -> &mut Self {
+        self.drawing_buffer_color_space = Some(color_space);
         self
     }
 
-    /// Saves an id that will be used to create a VAO at build time
+    /// Sets the color space that images are converted to when uploaded to textures
+    pub fn set_unpack_color_space(&mut self, color_space: CanvasColorSpace) -> &mut Self {
+        self.unpack_color_space = Some(color_space);
+        self
+    }
+
+    /// Reserves a texture unit for a texture, instead of letting [TextureUnits] choose one
     ///
-    /// This VAO can then be referenced by `AttributeLink`s
-    pub fn add_vao_link(
-        &mut self,
-        vertex_array_object_id: impl Into<VertexArrayObjectId>,
-    ) -> &mut Self {
-        self.vertex_array_object_links
-            .insert(vertex_array_object_id.into());
+    /// Building fails with [CreateTextureError::TextureUnitOutOfRange] if `unit` isn't below
+    /// `MAX_COMBINED_TEXTURE_IMAGE_UNITS`.
+    pub fn set_texture_unit(&mut self, texture_id: TextureId, unit: u32) -> &mut Self {
+        self.texture_units.pin(texture_id, unit);
+        self
+    }
 
+    /// Reuses shaders and programs compiled from identical sources by an earlier build (see [ShaderCache])
+    pub fn set_shader_cache(&mut self, shader_cache: ShaderCache) -> &mut Self {
+        self.shader_cache = Some(shader_cache);
         self
     }
 
-    pub fn add_vao_links(
+    /// In debug builds, shows build errors (such as shader compile logs) over the canvas when
+    /// building or rebuilding fails, instead of leaving it blank. Has no effect in release builds.
+    pub fn set_error_overlay(&mut self, enabled: bool) -> &mut Self {
+        self.error_overlay = enabled;
+        self
+    }
+
+    /// Calls `callback` with a [LongFrameReport] of the pipeline's state whenever a call to `render`
+    /// takes longer than `threshold_ms`. See [FrameWatchdog]
+    pub fn set_frame_watchdog(
         &mut self,
-        vao_links: impl Into<Bridge<VertexArrayObjectId>>,
+        threshold_ms: f64,
+        callback: impl Into<LongFrameCallback>,
     ) -> &mut Self {
-        let vao_link_bridge: Bridge<_> = vao_links.into();
-        let vao_links: Vec<_> = vao_link_bridge.into();
-        let vao_links: Vec<VertexArrayObjectId> = vao_links.into_iter().collect();
+        self.frame_watchdog = Some(FrameWatchdog::new(threshold_ms, callback));
+        self
+    }
 
-        for vao_link in vao_links {
-            self.add_vao_link(vao_link);
+    /// Compiles all vertex shaders and fragment shaders.
+    /// Links together any programs that have been specified.
+    /// Outputs the final RendererData, wrapped in a top-level Renderer.
+    pub fn build_renderer(
+        self,
+    ) -> Result<
+        Renderer<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+        RendererBuilderError,
+    > {
+        let build_config = self.clone();
+        let renderer_data = self.build_renderer_data()?;
+        let mut renderer: Renderer<_, _, _, _, _, _, _, _, _, _, _> = renderer_data.into();
+        renderer.set_build_config(build_config);
+
+        Ok(renderer)
+    }
+
+    /// Builds new `RendererData` from this builder, reusing every WebGL object of `previous` whose
+    /// link (and any resource it depends on) is unchanged from `previous_config`.
+    ///
+    /// Shaders are unchanged when their source is identical, and links when they are the same
+    /// link or a clone of it. VAOs, attributes, and uniforms are cheap to create and are always rebuilt.
+    /// Nothing is deleted here: see [RendererData::delete_objects_not_in].
+    pub(crate) fn rebuild_renderer_data(
+        mut self,
+        previous_config: &Self,
+        previous: &RendererData<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+    ) -> Result<
+        RendererData<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+        RendererBuilderError,
+    > {
+        self.empty_vao = Some(previous.empty_vao.clone());
+
+        for (id, src) in &self.vertex_shader_sources {
+            if previous_config.vertex_shader_sources.get(id) == Some(src) {
+                if let Some(shader) = previous.vertex_shaders.get(id) {
+                    self.vertex_shaders.insert(id.clone(), shader.clone());
+                }
+            }
+        }
+        for (id, src) in &self.fragment_shader_sources {
+            if previous_config.fragment_shader_sources.get(id) == Some(src) {
+                if let Some(shader) = previous.fragment_shaders.get(id) {
+                    self.fragment_shaders.insert(id.clone(), shader.clone());
+                }
+            }
+        }
+
+        // attribute locations are bound at link time, so any change to them relinks every program
+        let declared_attribute_locations = |config: &Self| {
+            config
+                .program_links
+                .iter()
+                .flat_map(|program_link| program_link.attribute_locations().iter().cloned())
+                .collect::<HashSet<_>>()
+        };
+        if self.attribute_locations == previous_config.attribute_locations
+            && declared_attribute_locations(&self) == declared_attribute_locations(previous_config)
+        {
+            for program_link in &self.program_links {
+                let unchanged =
+                    previous_config
+                        .program_links
+                        .get(program_link)
+                        .is_some_and(|previous_link| {
+                            previous_link.transform_feedback_varyings()
+                                == program_link.transform_feedback_varyings()
+                        })
+                        && self
+                            .vertex_shaders
+                            .contains_key(program_link.vertex_shader_id())
+                        && self
+                            .fragment_shaders
+                            .contains_key(program_link.fragment_shader_id());
+                let program_id = program_link.program_id();
+                if let (true, Some(program)) = (unchanged, previous.programs.get(program_id)) {
+                    self.programs.insert(program_id.clone(), program.clone());
+                }
+            }
+        }
+
+        for buffer_link in &self.buffer_links {
+            let buffer_id = buffer_link.buffer_id();
+            let unchanged = previous_config
+                .buffer_links
+                .get(buffer_link)
+                .is_some_and(|previous_link| previous_link.is_identical(buffer_link));
+            if let (true, Some(buffer)) = (unchanged, previous.buffers.get(buffer_id)) {
+                self.buffers.insert(buffer_id.clone(), buffer.clone());
+            }
+        }
+
+        for texture_link in &self.texture_links {
+            let texture_id = texture_link.texture_id();
+            let unchanged = previous_config.texture_links.contains(texture_link);
+            if let (true, Some(texture)) = (unchanged, previous.textures.get(texture_id)) {
+                self.textures.insert(texture_id.clone(), texture.clone());
+            }
+        }
+
+        // keep texture units stable, so that sampler uniforms set by callbacks stay valid
+        for (texture_id, unit) in previous.texture_units.iter() {
+            if self.texture_units.unit(texture_id).is_none()
+                && self.texture_units.texture_id(unit).is_none()
+            {
+                self.texture_units.pin(texture_id.clone(), unit);
+            }
+        }
+
+        for framebuffer_link in &self.framebuffer_links {
+            let framebuffer_id = framebuffer_link.framebuffer_id();
+            let unchanged = previous_config
+                .framebuffer_links
+                .get(framebuffer_link)
+                .is_some_and(|previous_link| previous_link.is_identical(framebuffer_link))
+                && framebuffer_link
+                    .texture_id()
+                    .into_iter()
+                    .all(|texture_id| self.textures.contains_key(&texture_id));
+            if let (true, Some(framebuffer)) =
+                (unchanged, previous.framebuffers.get(framebuffer_id))
+            {
+                self.framebuffers
+                    .insert(framebuffer_id.clone(), framebuffer.clone());
+            }
+        }
+
+        for transform_feedback_link in &self.transform_feedback_links {
+            let transform_feedback_id = transform_feedback_link.transform_feedback_id();
+            let unchanged = previous_config
+                .transform_feedback_links
+                .contains(transform_feedback_link);
+            if let (true, Some(transform_feedback)) = (
+                unchanged,
+                previous.transform_feedbacks.get(transform_feedback_id),
+            ) {
+                self.transform_feedbacks
+                    .insert(transform_feedback_id.clone(), transform_feedback.clone());
+            }
+        }
+
+        let mut renderer_data = self.build_renderer_data()?;
+        renderer_data.frame_tracer = previous.frame_tracer.clone();
+        renderer_data.frame_clock = previous.frame_clock.clone();
+        renderer_data.output_pass = previous.output_pass.clone();
+        renderer_data.before_render_hooks = previous.before_render_hooks.clone();
+        renderer_data.after_render_hooks = previous.after_render_hooks.clone();
+        // resources registered from outside of wrend aren't part of the build config, so they're
+        // carried over unless the new config creates a resource with the same id
+        let external = &previous.external_resources;
+        for (buffer_id, ownership) in &external.buffers {
+            if let (false, Some(buffer)) = (
+                renderer_data.buffers.contains_key(buffer_id),
+                previous.buffers.get(buffer_id),
+            ) {
+                renderer_data
+                    .buffers
+                    .insert(buffer_id.clone(), buffer.clone());
+                renderer_data
+                    .external_resources
+                    .buffers
+                    .insert(buffer_id.clone(), *ownership);
+            }
+        }
+        for (texture_id, external_texture) in &external.textures {
+            if let (false, Some(texture)) = (
+                renderer_data.textures.contains_key(texture_id),
+                previous.textures.get(texture_id),
+            ) {
+                renderer_data
+                    .textures
+                    .insert(texture_id.clone(), texture.clone());
+                renderer_data
+                    .external_resources
+                    .textures
+                    .insert(texture_id.clone(), *external_texture);
+            }
+        }
+        for (framebuffer_id, ownership) in &external.framebuffers {
+            if let (false, Some(framebuffer)) = (
+                renderer_data.framebuffers.contains_key(framebuffer_id),
+                previous.framebuffers.get(framebuffer_id),
+            ) {
+                renderer_data
+                    .framebuffers
+                    .insert(framebuffer_id.clone(), framebuffer.clone());
+                renderer_data
+                    .external_resources
+                    .framebuffers
+                    .insert(framebuffer_id.clone(), *ownership);
+            }
+        }
+        // keep reading from the same side of any pair that survived the rebuild
+        for (pair_id, buffer_pair) in &renderer_data.buffer_pairs {
+            if let Some(previous_pair) = previous.buffer_pairs.get(pair_id) {
+                buffer_pair.set_swapped(previous_pair.is_swapped());
+            }
+        }
+
+        Ok(renderer_data)
+    }
+
+    /// Describes how all links added so far refer to one another, without touching WebGL.
+    ///
+    /// This graph is validated at the start of every build.
+    pub fn link_graph(
+        &self,
+    ) -> LinkGraph<
+        VertexShaderId,
+        FragmentShaderId,
+        ProgramId,
+        UniformId,
+        BufferId,
+        AttributeId,
+        TextureId,
+        FramebufferId,
+        VertexArrayObjectId,
+    > {
+        let mut graph = LinkGraph::new();
+        for vertex_shader_id in self.vertex_shader_sources.keys() {
+            graph.add_vertex_shader(vertex_shader_id.clone());
         }
-
-        self
-    }
-
-    pub fn set_get_context_callback(
-        &mut self,
-        get_context_callback: impl Into<GetContextCallback>,
-    ) -> &mut Self {
-        self.get_context_callback = get_context_callback.into();
-        self
+        for fragment_shader_id in self.fragment_shader_sources.keys() {
+            graph.add_fragment_shader(fragment_shader_id.clone());
+        }
+        for program_link in &self.program_links {
+            graph.add_program(
+                program_link.program_id().clone(),
+                program_link.vertex_shader_id().clone(),
+                program_link.fragment_shader_id().clone(),
+            );
+        }
+        for uniform_link in &self.uniform_links {
+            graph.add_uniform(
+                uniform_link.uniform_id().clone(),
+                uniform_link.name(),
+                uniform_link.program_ids().clone(),
+            );
+        }
+        for (uniform_id, namespace) in &self.uniform_namespaces {
+            graph.set_uniform_namespace(uniform_id.clone(), namespace.clone());
+        }
+        for buffer_link in &self.buffer_links {
+            graph.add_buffer(buffer_link.buffer_id().clone());
+        }
+        for vao_id in &self.vertex_array_object_links {
+            graph.add_vao(vao_id.clone());
+        }
+        for attribute_link in &self.attribute_links {
+            graph.add_attribute(
+                attribute_link.attribute_id().clone(),
+                attribute_link.attribute_id().name(),
+                attribute_link.buffer_id().clone(),
+                attribute_link.vao_ids().to_vec(),
+            );
+        }
+        for texture_link in &self.texture_links {
+            graph.add_texture(texture_link.texture_id().clone());
+        }
+        for framebuffer_link in &self.framebuffer_links {
+            graph.add_framebuffer(
+                framebuffer_link.framebuffer_id().clone(),
+                framebuffer_link.texture_id(),
+            );
+        }
+        graph
     }
 
     /// Compiles all vertex shaders and fragment shaders.
     /// Links together any programs that have been specified.
-    /// Outputs the final RendererData, wrapped in a top-level Renderer.
-    pub fn build_renderer(
+    /// Outputs the final RendererData.
+    pub fn build_renderer_data(
         self,
     ) -> Result<
-        Renderer<
+        RendererData<
             VertexShaderId,
             FragmentShaderId,
             ProgramId,
@@ -771,14 +4040,218 @@ impl<
         >,
         RendererBuilderError,
     > {
-        let renderer_data = self.build_renderer_data()?;
-        Ok(renderer_data.into())
+        let overlay_canvas = if cfg!(debug_assertions) && self.error_overlay {
+            self.canvas.clone()
+        } else {
+            None
+        };
+        let result = self.build_renderer_data_without_overlay();
+        if let Some(canvas) = overlay_canvas {
+            match &result {
+                Ok(_) => clear_error_overlay(&canvas),
+                Err(err) => {
+                    if let Err(overlay_err) =
+                        show_error_overlay(&canvas, &error_overlay_message(err))
+                    {
+                        warn!("Could not show error overlay: {overlay_err:?}");
+                    }
+                }
+            }
+        }
+
+        result
     }
+}
 
-    /// Compiles all vertex shaders and fragment shaders.
-    /// Links together any programs that have been specified.
-    /// Outputs the final RendererData.
-    pub fn build_renderer_data(
+/// Namespaces
+impl<
+        VertexShaderId: NamespacedId,
+        FragmentShaderId: NamespacedId,
+        ProgramId: NamespacedId,
+        UniformId: NamespacedId + IdName,
+        BufferId: NamespacedId,
+        AttributeId: NamespacedId + IdName,
+        TextureId: NamespacedId,
+        FramebufferId: NamespacedId,
+        TransformFeedbackId: NamespacedId,
+        VertexArrayObjectId: NamespacedId,
+        UserCtx: Clone,
+    >
+    RendererDataBuilder<
+        VertexShaderId,
+        FragmentShaderId,
+        ProgramId,
+        UniformId,
+        BufferId,
+        AttributeId,
+        TextureId,
+        FramebufferId,
+        TransformFeedbackId,
+        VertexArrayObjectId,
+        UserCtx,
+    >
+{
+    /// Adds the links of a plugin or preset, scoping every resource that it defines to `namespace`,
+    /// so that its ids can't collide with the app's own (or another plugin's) ids.
+    ///
+    /// `build` is called with an empty builder, which the plugin adds its shaders and links to as usual.
+    /// Ids of resources that it adds are then scoped to `namespace` (see [NamespacedId::in_namespace]),
+    /// while references to resources that it didn't add (e.g. the app's scene texture) are kept as-is.
+    /// Uniform and attribute names are not affected, so shaders don't need to change.
+    ///
+    /// Only shaders, links, and mouse uniforms are taken from the plugin's builder. Its resources can be
+    /// looked up afterward with the scoped ids, e.g. `Namespaced::new("bloom", "blur")`.
+    pub fn add_namespace(&mut self, namespace: &str, build: impl FnOnce(&mut Self)) -> &mut Self {
+        let mut plugin = Self {
+            pointer_state: self.pointer_state.clone(),
+            ..Self::default()
+        };
+        build(&mut plugin);
+        if plugin.pointer_state.is_some() {
+            self.pointer_state = plugin.pointer_state.take();
+        }
+
+        let vertex_shader_ids = plugin.vertex_shader_sources.keys().cloned().collect();
+        let fragment_shader_ids = plugin.fragment_shader_sources.keys().cloned().collect();
+        let program_ids = plugin
+            .program_links
+            .iter()
+            .map(|link| link.program_id().clone())
+            .collect();
+        let uniform_ids = plugin
+            .uniform_links
+            .iter()
+            .map(|link| link.uniform_id().clone())
+            .collect();
+        let buffer_ids = plugin
+            .buffer_links
+            .iter()
+            .map(|link| link.buffer_id().clone())
+            .collect();
+        let attribute_ids = plugin
+            .attribute_links
+            .iter()
+            .map(|link| link.attribute_id().clone())
+            .collect();
+        let texture_ids = plugin
+            .texture_links
+            .iter()
+            .map(|link| link.texture_id().clone())
+            .collect();
+        let framebuffer_ids = plugin
+            .framebuffer_links
+            .iter()
+            .map(|link| link.framebuffer_id().clone())
+            .collect();
+        let transform_feedback_ids = plugin
+            .transform_feedback_links
+            .iter()
+            .map(|link| link.transform_feedback_id().clone())
+            .collect();
+        let vao_ids = plugin.vertex_array_object_links.clone();
+
+        let vertex_shader_id = scope_defined_ids(&vertex_shader_ids, namespace);
+        let fragment_shader_id = scope_defined_ids(&fragment_shader_ids, namespace);
+        let program_id = scope_defined_ids(&program_ids, namespace);
+        let uniform_id = scope_defined_ids(&uniform_ids, namespace);
+        let buffer_id = scope_defined_ids(&buffer_ids, namespace);
+        let attribute_id = scope_defined_ids(&attribute_ids, namespace);
+        let texture_id = scope_defined_ids(&texture_ids, namespace);
+        let framebuffer_id = scope_defined_ids(&framebuffer_ids, namespace);
+        let transform_feedback_id = scope_defined_ids(&transform_feedback_ids, namespace);
+        let vao_id = scope_defined_ids(&vao_ids, namespace);
+
+        for (id, src) in plugin.vertex_shader_sources {
+            self.add_vertex_shader_src(vertex_shader_id(&id), src);
+        }
+        for (id, src) in plugin.fragment_shader_sources {
+            self.add_fragment_shader_src(fragment_shader_id(&id), src);
+        }
+        for link in plugin.program_links {
+            self.add_program_link(link.map_ids(
+                &program_id,
+                &vertex_shader_id,
+                &fragment_shader_id,
+            ));
+        }
+        for link in plugin.uniform_links {
+            let link = link.map_ids(&program_id, &uniform_id);
+            if let Some(namespace) = link.uniform_id().namespace() {
+                self.uniform_namespaces
+                    .insert(link.uniform_id().clone(), namespace.to_string());
+            }
+            self.add_uniform_link(link);
+        }
+        for link in plugin.buffer_links {
+            self.add_buffer_link(link.map_ids(&buffer_id));
+        }
+        for id in &plugin.vertex_array_object_links {
+            self.add_vao_link(vao_id(id));
+        }
+        for link in plugin.attribute_links {
+            self.add_attribute_link(link.map_ids(&vao_id, &buffer_id, &attribute_id));
+        }
+        for (pair_id, buffer_pair) in &plugin.buffer_pairs {
+            self.buffer_pairs
+                .insert(buffer_id(pair_id), buffer_pair.map_ids(&buffer_id, &vao_id));
+        }
+        for link in plugin.texture_links {
+            self.add_texture_link(link.map_ids(&texture_id));
+        }
+        for link in plugin.framebuffer_links {
+            self.add_framebuffer_link(link.map_ids(&framebuffer_id, &texture_id));
+        }
+        for link in plugin.transform_feedback_links {
+            self.add_transform_feedback_link(link.map_ids(&transform_feedback_id));
+        }
+
+        self
+    }
+}
+
+/// Scopes ids that are in `defined` to `namespace`, leaving all other ids as they are
+fn scope_defined_ids<'a, I: NamespacedId>(
+    defined: &'a HashSet<I>,
+    namespace: &'a str,
+) -> impl Fn(&I) -> I + 'a {
+    move |id| {
+        if defined.contains(id) {
+            id.in_namespace(namespace)
+        } else {
+            id.clone()
+        }
+    }
+}
+
+/// Private API
+impl<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        ProgramId: Id,
+        UniformId: Id + IdName,
+        BufferId: Id,
+        AttributeId: Id + IdName,
+        TextureId: Id,
+        FramebufferId: Id,
+        TransformFeedbackId: Id,
+        VertexArrayObjectId: Id,
+        UserCtx: Clone,
+    >
+    RendererDataBuilder<
+        VertexShaderId,
+        FragmentShaderId,
+        ProgramId,
+        UniformId,
+        BufferId,
+        AttributeId,
+        TextureId,
+        FramebufferId,
+        TransformFeedbackId,
+        VertexArrayObjectId,
+        UserCtx,
+    >
+{
+    fn build_renderer_data_without_overlay(
         mut self,
     ) -> Result<
         RendererData<
@@ -796,18 +4269,33 @@ impl<
         >,
         RendererBuilderError,
     > {
+        let link_graph = self.link_graph();
+        link_graph.validate()?;
+        for warning in link_graph.id_name_warnings() {
+            warn!("{warning}");
+        }
+
         // the order here is fairly important
         self.save_webgl_context_from_canvas()?;
-        self.compile_fragment_shaders()?;
-        self.compile_vertex_shaders()?;
-        self.create_vaos()?;
-        self.link_programs()?;
+        let gl = self.gl.clone().ok_or(BuildRendererError::NoContext)?;
+        let backend: Box<WebGlHandlesBackend> =
+            Box::new(WebGl2Backend::new(gl, self.shader_cache.clone()));
+        self.create_vaos(backend.as_ref())?;
+        self.apply_program_attribute_locations()?;
+        self.build_programs(backend.as_ref())?;
         self.create_buffers()?;
         self.create_attributes()?;
-        self.create_uniforms()?;
+        // textures are created (and pinned ones bound to their units) before any uniform is
+        // initialized, so that initialize callbacks can point sampler uniforms at them (see `TextureUnits`)
         self.create_textures()?;
+        self.assign_texture_units()?;
         self.create_framebuffers()?;
-        self.create_transform_feedbacks()?;
+        self.create_uniforms()?;
+        self.sampler_texture_units.borrow_mut().finish();
+        self.initialize_unlinked_uniforms()?;
+        self.validate_programs()?;
+        self.create_transform_feedbacks(backend.as_ref())?;
+        self.create_pointer_tracker()?;
 
         let renderer_data = RendererData {
             canvas: self.canvas.ok_or(BuildRendererError::NoCanvas)?,
@@ -820,46 +4308,33 @@ impl<
                 .ok_or(BuildRendererError::NoRenderCallback)?,
             user_ctx: self.user_ctx,
             uniforms: self.uniforms,
+            uniform_indices: self.uniform_indices,
+            uniform_group_cache: UniformGroupCache::default(),
+            debug_view: Cell::new(DebugView::default()),
+            output_pass: OutputPass::default(),
             buffers: self.buffers,
+            buffer_pairs: self.buffer_pairs,
             textures: self.textures,
+            texture_units: self.texture_units,
+            program_validation_warnings: self.program_validation_warnings,
             framebuffers: self.framebuffers,
+            external_resources: ExternalResources::default(),
+            shader_cache: self.shader_cache,
             attributes: self.attributes,
             vertex_array_objects: self.vertex_array_objects,
+            empty_vao: self.empty_vao.ok_or(CreateVAOError::NoneWasReturned)?,
             transform_feedbacks: self.transform_feedbacks,
+            pointer_tracker: self.pointer_tracker,
+            frame_tracer: FrameTracer::new(),
+            frame_watchdog: self.frame_watchdog,
+            frame_clock: FrameClock::default(),
+            before_render_hooks: RenderHooks::new(),
+            after_render_hooks: RenderHooks::new(),
         };
 
         Ok(renderer_data)
     }
-}
 
-/// Private API
-impl<
-        VertexShaderId: Id,
-        FragmentShaderId: Id,
-        ProgramId: Id,
-        UniformId: Id + IdName,
-        BufferId: Id,
-        AttributeId: Id + IdName,
-        TextureId: Id,
-        FramebufferId: Id,
-        TransformFeedbackId: Id,
-        VertexArrayObjectId: Id,
-        UserCtx: Clone,
-    >
-    RendererDataBuilder<
-        VertexShaderId,
-        FragmentShaderId,
-        ProgramId,
-        UniformId,
-        BufferId,
-        AttributeId,
-        TextureId,
-        FramebufferId,
-        TransformFeedbackId,
-        VertexArrayObjectId,
-        UserCtx,
-    >
-{
     /// Gets the WebGL2 context from the canvas saved in state and saves the context in state
     fn save_webgl_context_from_canvas(&mut self) -> Result<&mut Self, RendererBuilderError> {
         let canvas = self
@@ -868,6 +4343,16 @@ impl<
             .ok_or(SaveContextError::CanvasReturnedNoContext)?
             .to_owned();
         let gl = self.context_from_canvas(canvas)?;
+        for (property, color_space) in [
+            ("drawingBufferColorSpace", self.drawing_buffer_color_space),
+            ("unpackColorSpace", self.unpack_color_space),
+        ] {
+            if let Some(color_space) = color_space {
+                if !color_space.set(&gl, property) {
+                    warn!("This browser does not support setting {property} to {color_space:?}: using sRGB instead");
+                }
+            }
+        }
         self.gl = Some(gl);
 
         Ok(self)
@@ -891,56 +4376,134 @@ impl<
         Ok(gl)
     }
 
-    /// Takes the list of fragment shader sources and their ids and saves compiled `WebGlShader`s to state
-    fn compile_fragment_shaders(&mut self) -> Result<&mut Self, CompileShaderError> {
-        for (id, fragment_shader_src) in self.fragment_shader_sources.iter() {
-            let fragment_shader =
-                self.compile_shader(id.clone(), ShaderType::FragmentShader, fragment_shader_src)?;
-            self.fragment_shaders.insert((*id).clone(), fragment_shader);
-        }
+    fn create_transform_feedbacks(
+        &mut self,
+        backend: &WebGlHandlesBackend,
+    ) -> Result<&mut Self, CreateTransformFeedbackError> {
+        create_transform_feedbacks(
+            backend,
+            self.transform_feedback_links
+                .iter()
+                .map(|transform_feedback_link| transform_feedback_link.transform_feedback_id()),
+            &mut self.transform_feedbacks,
+        )
+        .map_err(|_| CreateTransformFeedbackError::NoneWasReturned)?;
 
         Ok(self)
     }
 
-    /// Takes the list of vertex shader sources and their ids and saves compiled `WebGlShader`s to state
-    fn compile_vertex_shaders(&mut self) -> Result<&mut Self, CompileShaderError> {
-        for (id, vertex_shader_src) in self.vertex_shader_sources.iter() {
-            let vertex_shader =
-                self.compile_shader(id.clone(), ShaderType::VertexShader, vertex_shader_src)?;
-            self.vertex_shaders.insert((*id).clone(), vertex_shader);
+    /// Moves attributes to the locations declared by [ProgramLink]s, and any other attribute off of
+    /// those locations, so that every program and VAO agrees on where each attribute is
+    fn apply_program_attribute_locations(&mut self) -> Result<&mut Self, LinkProgramError> {
+        let mut declared_locations: HashMap<&str, u32> = HashMap::new();
+        for program_link in &self.program_links {
+            let mut program_locations: HashMap<u32, &str> = HashMap::new();
+            for (attribute_name, location) in program_link.attribute_locations() {
+                if let Some(other_location) = declared_locations.insert(attribute_name, *location) {
+                    if other_location != *location {
+                        return Err(LinkProgramError::ConflictingAttributeLocations {
+                            attribute_name: attribute_name.clone(),
+                            location: *location,
+                            other_location,
+                        });
+                    }
+                }
+                if let Some(other_attribute_name) =
+                    program_locations.insert(*location, attribute_name)
+                {
+                    if other_attribute_name != attribute_name {
+                        return Err(LinkProgramError::DuplicateAttributeLocation {
+                            program_id: format!("{:?}", program_link.program_id()),
+                            attribute_name: attribute_name.clone(),
+                            other_attribute_name: other_attribute_name.to_string(),
+                            location: *location,
+                        });
+                    }
+                }
+            }
         }
 
-        Ok(self)
-    }
-
-    fn create_transform_feedbacks(&mut self) -> Result<&mut Self, CreateTransformFeedbackError> {
-        let gl = self
-            .gl
-            .as_ref()
-            .ok_or(CreateTransformFeedbackError::NoContext)?;
+        if declared_locations.is_empty() {
+            return Ok(self);
+        }
 
-        for transform_feedback_link in self.transform_feedback_links.iter() {
-            let transform_feedback_id = transform_feedback_link.transform_feedback_id().clone();
-            let webgl_transform_feedback = gl
-                .create_transform_feedback()
-                .ok_or(CreateTransformFeedbackError::NoneWasReturned)?;
-            self.transform_feedbacks
-                .insert(transform_feedback_id, webgl_transform_feedback);
+        let mut moved_attribute_ids = Vec::new();
+        for (attribute_id, location) in self.attribute_locations.iter_mut() {
+            match declared_locations.get(attribute_id.name().as_str()) {
+                Some(declared_location) => *location = *declared_location,
+                None if declared_locations.values().any(|used| used == location) => {
+                    moved_attribute_ids.push(attribute_id.clone());
+                }
+                None => {}
+            }
+        }
+        moved_attribute_ids.sort_by_cached_key(|attribute_id| attribute_id.name());
+        for attribute_id in moved_attribute_ids {
+            let free_location = (0..)
+                .find(|location| {
+                    !self
+                        .attribute_locations
+                        .values()
+                        .any(|used| used == location)
+                        && !declared_locations.values().any(|used| used == location)
+                })
+                .unwrap_or_default();
+            self.attribute_locations.insert(attribute_id, free_location);
         }
 
         Ok(self)
     }
 
-    /// Links together all of the vertex & fragment shaders that have been saved
+    /// Compiles all of the vertex & fragment shader sources, then links them together
     /// according to any ProgramLinks that were provided.
     ///
     /// If a ProgramLink does not correspond to an actual shader, returns an Error.
-    fn link_programs(&mut self) -> Result<&mut Self, LinkProgramError> {
-        for program_link in self.program_links.iter() {
-            let program = self.link_program(program_link)?;
-            let program_id = program_link.program_id();
-            self.programs.insert(program_id.clone(), program);
-        }
+    fn build_programs(
+        &mut self,
+        backend: &WebGlHandlesBackend,
+    ) -> Result<&mut Self, RendererBuilderError> {
+        let attribute_locations: Vec<(String, u32)> = self
+            .attribute_locations
+            .iter()
+            .map(|(attribute_id, location)| (attribute_id.name(), *location))
+            .collect();
+        let program_descriptors: Vec<_> = self
+            .program_links
+            .iter()
+            .map(|program_link| ProgramDescriptor {
+                program_id: program_link.program_id(),
+                vertex_shader_id: program_link.vertex_shader_id(),
+                fragment_shader_id: program_link.fragment_shader_id(),
+                attribute_locations: program_link.attribute_locations(),
+                transform_feedback_varyings: program_link.transform_feedback_varyings(),
+                cache_key: self.program_hash(program_link),
+            })
+            .collect();
+
+        let sources = ProgramSources {
+            vertex_shader_sources: &self.vertex_shader_sources,
+            fragment_shader_sources: &self.fragment_shader_sources,
+            programs: &program_descriptors,
+            attribute_locations: &attribute_locations,
+        };
+        let shader_header_options = &self.shader_header_options;
+        build_programs(
+            backend,
+            &sources,
+            |backend, shader_id, shader_type, source| {
+                compile_shader(
+                    backend,
+                    shader_id,
+                    shader_type,
+                    source,
+                    shader_header_options,
+                )
+                .map_err(RendererBuilderError::from)
+            },
+            &mut self.vertex_shaders,
+            &mut self.fragment_shaders,
+            &mut self.programs,
+        )?;
 
         Ok(self)
     }
@@ -951,15 +4514,16 @@ impl<
         uniform_link: &UniformLink<ProgramId, UniformId>,
     ) -> Result<Uniform<ProgramId, UniformId>, CreateUniformError> {
         let uniform_id = uniform_link.uniform_id().clone();
+        let uniform_name = uniform_link.name();
         let program_ids = uniform_link.program_ids().clone();
         let use_init_callback_for_update = uniform_link.use_init_callback_for_update();
+        let update_frequency = uniform_link.update_frequency();
         let gl = self.gl.as_ref().ok_or(CreateUniformError::NoContext)?;
         let now = Self::now();
-        let _user_ctx = self.user_ctx.as_ref().map(Clone::clone);
         let initialize_callback = uniform_link.initialize_callback();
         let should_update_callback = uniform_link.should_update_callback();
         let update_callback = uniform_link.update_callback();
-        let mut uniform_locations = HashMap::new();
+        let mut contexts = Vec::with_capacity(program_ids.len());
 
         for program_id in &program_ids {
             let program = self
@@ -969,15 +4533,19 @@ impl<
 
             gl.use_program(Some(program));
 
-            let uniform_location = gl
-                .get_uniform_location(program, &uniform_id.name())
-                .ok_or_else(|| CreateUniformError::UniformLocationNotFound {
-                    uniform_id: uniform_id.name(),
-                    program_id: format!("{program_id:?}"),
-                })?;
-            let uniform_context = UniformContext::new(gl.clone(), now, uniform_location.clone());
+            let uniform_location =
+                gl.get_uniform_location(program, &uniform_name)
+                    .ok_or_else(|| CreateUniformError::UniformLocationNotFound {
+                        uniform_id: uniform_name.clone(),
+                        program_id: format!("{program_id:?}"),
+                    })?;
+            let mut uniform_context = UniformContext::new(gl.clone(), now, uniform_location);
+            uniform_context.set_texture_units(Rc::clone(&self.sampler_texture_units));
+
+            let bindings_before = self.texture_unit_bindings(gl);
             initialize_callback.call_with_into_js_arg(&uniform_context);
-            uniform_locations.insert(program_id.to_owned(), uniform_location.clone());
+            self.restore_texture_unit_bindings(gl, &uniform_id, bindings_before);
+            contexts.push((program_id.to_owned(), program.clone(), uniform_context));
 
             gl.use_program(None);
         }
@@ -985,16 +4553,48 @@ impl<
         let uniform = Uniform::new(
             program_ids,
             uniform_id,
-            uniform_locations,
+            contexts,
             initialize_callback,
             update_callback,
             should_update_callback,
             use_init_callback_for_update,
+            update_frequency,
         );
 
         Ok(uniform)
     }
 
+    /// Warns about (and undoes) any change an initialize callback made to the textures bound to
+    /// units assigned by the allocator, since other uniforms rely on those bindings
+    fn restore_texture_unit_bindings(
+        &self,
+        gl: &WebGl2RenderingContext,
+        uniform_id: &UniformId,
+        bindings_before: Vec<(u32, JsValue)>,
+    ) {
+        let bindings_after = self.texture_unit_bindings(gl);
+        for ((unit, before), (_, after)) in bindings_before.into_iter().zip(bindings_after) {
+            if before == after {
+                continue;
+            }
+            let texture_id = self.texture_units.texture_id(unit);
+            warn!(
+                "Initialize callback for uniform {:?} rebound texture unit {unit}, which is assigned to texture {texture_id:?}: \
+                restoring it. Use `UniformContext::texture_unit` instead, or `set_texture_unit` to reserve the unit.",
+                uniform_id.name()
+            );
+            let active_texture = gl.get_parameter(WebGl2RenderingContext::ACTIVE_TEXTURE);
+            gl.active_texture(WebGl2RenderingContext::TEXTURE0 + unit);
+            gl.bind_texture(
+                WebGl2RenderingContext::TEXTURE_2D,
+                before.dyn_ref::<WebGlTexture>(),
+            );
+            if let Some(active_texture) = active_texture.ok().and_then(|unit| unit.as_f64()) {
+                gl.active_texture(active_texture as u32);
+            }
+        }
+    }
+
     /// Creates all WebGL buffers, using the passed in BufferLinks
     fn create_buffers(&mut self) -> Result<&mut Self, CreateBufferError> {
         let gl = self.gl.as_ref().ok_or(CreateBufferError::NoContext)?;
@@ -1002,6 +4602,9 @@ impl<
 
         for buffer_link in &self.buffer_links {
             let buffer_id = buffer_link.buffer_id().clone();
+            if self.buffers.contains_key(&buffer_id) {
+                continue;
+            }
             let webgl_buffer = buffer_link.create_buffer(gl.clone(), now);
             let buffer = Buffer::new(buffer_id.clone(), webgl_buffer);
             self.buffers.insert(buffer_id, buffer);
@@ -1010,15 +4613,14 @@ impl<
         Ok(self)
     }
 
-    fn create_vaos(&mut self) -> Result<&mut Self, CreateVAOError> {
-        let gl = self.gl.as_ref().ok_or(CreateVAOError::NoContext)?;
-
-        for vao_id in self.vertex_array_object_links.iter() {
-            let vao = gl
-                .create_vertex_array()
-                .ok_or(CreateVAOError::NoneWasReturned)?;
-            self.vertex_array_objects.insert(vao_id.to_owned(), vao);
-        }
+    fn create_vaos(&mut self, backend: &WebGlHandlesBackend) -> Result<&mut Self, CreateVAOError> {
+        create_vertex_arrays(
+            backend,
+            &self.vertex_array_object_links,
+            &mut self.vertex_array_objects,
+            &mut self.empty_vao,
+        )
+        .map_err(|_| CreateVAOError::NoneWasReturned)?;
 
         Ok(self)
     }
@@ -1027,7 +4629,6 @@ impl<
     fn create_attributes(&mut self) -> Result<&mut Self, CreateAttributeError> {
         let gl = self.gl.as_ref().ok_or(CreateAttributeError::NoContext)?;
         let now = Self::now();
-        let _user_ctx = self.user_ctx.clone();
 
         for attribute_link in &self.attribute_links {
             let vao_ids = attribute_link.vao_ids();
@@ -1058,6 +4659,12 @@ impl<
                     webgl_buffer.clone(),
                     attribute_location.into(),
                 );
+                if attribute_link.is_instanced() {
+                    gl.vertex_attrib_divisor(
+                        *attribute_location,
+                        attribute_link.vertex_attrib_divisor(),
+                    );
+                }
                 gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, None);
             } else {
                 // initialize attribute for each VAO that it is linked to
@@ -1079,6 +4686,12 @@ impl<
                         webgl_buffer.clone(),
                         attribute_location.into(),
                     );
+                    if attribute_link.is_instanced() {
+                        gl.vertex_attrib_divisor(
+                            *attribute_location,
+                            attribute_link.vertex_attrib_divisor(),
+                        );
+                    }
                     gl.bind_vertex_array(None);
                     gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, None);
                 }
@@ -1090,9 +4703,20 @@ impl<
                 attribute_id.clone(),
                 webgl_buffer.clone(),
                 attribute_location.into(),
+                attribute_link.vertex_attrib_divisor(),
             );
 
-            self.attributes.insert(attribute_id, attribute);
+            if vao_ids.is_empty() {
+                self.attributes
+                    .insert(AttributeKey::new(attribute_id, None), attribute);
+            } else {
+                for vao_id in vao_ids {
+                    self.attributes.insert(
+                        AttributeKey::new(attribute_id.clone(), Some(vao_id.clone())),
+                        attribute.clone(),
+                    );
+                }
+            }
         }
 
         Ok(self)
@@ -1106,6 +4730,9 @@ impl<
 
         for texture_link in &self.texture_links {
             let texture_id = texture_link.texture_id().clone();
+            if self.textures.contains_key(&texture_id) {
+                continue;
+            }
             let webgl_texture = texture_link.create_texture(gl.clone(), now, canvas.clone());
             let texture = Texture::new(texture_id.clone(), webgl_texture);
 
@@ -1115,14 +4742,107 @@ impl<
         Ok(self)
     }
 
+    /// Binds every pinned texture to its unit, leaving `TEXTURE0` active. Other textures are only
+    /// given a unit once a sampler asks for one (see [SamplerTextureUnits]), so pipelines with more
+    /// textures than texture units still build.
+    ///
+    /// Fails if a pinned unit isn't below `MAX_COMBINED_TEXTURE_IMAGE_UNITS`.
+    fn assign_texture_units(&mut self) -> Result<&mut Self, CreateTextureError> {
+        let gl = self.gl.as_ref().ok_or(CreateTextureError::NoContext)?;
+        // WebGL2 guarantees at least 32 combined texture image units
+        let max_units = gl
+            .get_parameter(WebGl2RenderingContext::MAX_COMBINED_TEXTURE_IMAGE_UNITS)
+            .ok()
+            .and_then(|max_units| max_units.as_f64())
+            .map_or(32, |max_units| max_units as u32);
+
+        let textures = &self.textures;
+        self.texture_units
+            .retain(|texture_id| textures.contains_key(texture_id));
+
+        if let Some((texture_id, unit)) = self.texture_units.out_of_range(max_units) {
+            return Err(CreateTextureError::TextureUnitOutOfRange {
+                texture_id: format!("{texture_id:?}"),
+                unit,
+                max_units,
+            });
+        }
+
+        for (texture_id, unit) in self.texture_units.iter() {
+            gl.active_texture(WebGl2RenderingContext::TEXTURE0 + unit);
+            gl.bind_texture(
+                WebGl2RenderingContext::TEXTURE_2D,
+                Some(self.textures[texture_id].webgl_texture()),
+            );
+        }
+        gl.active_texture(WebGl2RenderingContext::TEXTURE0);
+
+        let unassigned = self
+            .textures
+            .iter()
+            .filter(|(texture_id, _)| self.texture_units.unit(texture_id).is_none())
+            .map(|(texture_id, texture)| {
+                (format!("{texture_id:?}"), texture.webgl_texture().clone())
+            })
+            .collect();
+        self.sampler_texture_units = Rc::new(RefCell::new(SamplerTextureUnits::new(
+            self.texture_units.by_debug_name(),
+            unassigned,
+            max_units,
+        )));
+
+        Ok(self)
+    }
+
+    /// Records the units that samplers requested for textures while their uniforms were initialized
+    fn sync_sampler_texture_units(
+        textures: &HashMap<TextureId, Texture<TextureId>>,
+        texture_units: &mut TextureUnits<TextureId>,
+        sampler_texture_units: &SamplerTextureUnits,
+    ) {
+        for texture_id in textures.keys() {
+            if texture_units.unit(texture_id).is_some() {
+                continue;
+            }
+            if let Some(unit) = sampler_texture_units
+                .units()
+                .unit(&format!("{texture_id:?}"))
+            {
+                texture_units.pin(texture_id.clone(), unit);
+            }
+        }
+    }
+
+    /// Textures currently bound to each assigned texture unit (restoring the active unit afterward)
+    fn texture_unit_bindings(&self, gl: &WebGl2RenderingContext) -> Vec<(u32, JsValue)> {
+        let active_texture = gl.get_parameter(WebGl2RenderingContext::ACTIVE_TEXTURE);
+        let bindings = self
+            .texture_units
+            .iter()
+            .map(|(_, unit)| {
+                gl.active_texture(WebGl2RenderingContext::TEXTURE0 + unit);
+                let texture = gl
+                    .get_parameter(WebGl2RenderingContext::TEXTURE_BINDING_2D)
+                    .unwrap_or(JsValue::NULL);
+                (unit, texture)
+            })
+            .collect();
+        if let Some(active_texture) = active_texture.ok().and_then(|unit| unit.as_f64()) {
+            gl.active_texture(active_texture as u32);
+        }
+        bindings
+    }
+
     /// Creates a WebGL Framebuffer for each FramebufferLink that was supplied using the callback
     fn create_framebuffers(&mut self) -> Result<&mut Self, CreateBufferError> {
         let gl = self.gl.as_ref().ok_or(CreateBufferError::NoContext)?;
         let now = Self::now();
-        let _user_ctx = self.user_ctx.clone();
 
         for framebuffer_link in &self.framebuffer_links {
             let framebuffer_id = framebuffer_link.framebuffer_id().clone();
+            if self.framebuffers.contains_key(&framebuffer_id) {
+                continue;
+            }
             let webgl_texture = framebuffer_link
                 .texture_id()
                 .and_then(|texture_id| self.textures.get(&texture_id))
@@ -1144,68 +4864,144 @@ impl<
         for uniform_link in self.uniform_links.iter() {
             let uniform_id = uniform_link.uniform_id().clone();
             let uniform = self.create_uniform(uniform_link)?;
-            self.uniforms.insert(uniform_id, uniform);
+            Self::sync_sampler_texture_units(
+                &self.textures,
+                &mut self.texture_units,
+                &self.sampler_texture_units.borrow(),
+            );
+            match self.uniform_indices.get(&uniform_id) {
+                Some(&index) => self.uniforms[index] = uniform,
+                None => {
+                    self.uniform_indices.insert(uniform_id, self.uniforms.len());
+                    self.uniforms.push(uniform);
+                }
+            }
         }
 
         Ok(self)
     }
 
-    fn link_program(
-        &self,
-        program_link: &ProgramLink<ProgramId, VertexShaderId, FragmentShaderId>,
-    ) -> Result<WebGlProgram, LinkProgramError> {
-        let gl = self.gl.as_ref().ok_or(LinkProgramError::NoContext)?;
+    /// Reflects over every program's active uniforms and sets any that have no corresponding
+    /// [UniformLink] to a known default (zero, or identity for matrices), so that they don't depend
+    /// on driver-specific initial values. Samplers and uniforms in uniform blocks are left alone.
+    fn initialize_unlinked_uniforms(&mut self) -> Result<&mut Self, CreateUniformError> {
+        let gl = self.gl.as_ref().ok_or(CreateUniformError::NoContext)?;
+
+        for (program_id, program) in &self.programs {
+            let linked_names: HashSet<String> = self
+                .uniform_links
+                .iter()
+                .filter(|uniform_link| uniform_link.program_ids().contains(program_id))
+                .map(|uniform_link| uniform_link.name())
+                .collect();
+            let num_uniforms = gl
+                .get_program_parameter(program, WebGl2RenderingContext::ACTIVE_UNIFORMS)
+                .as_f64()
+                .unwrap_or_default() as u32;
 
-        let vertex_shader_id = program_link.vertex_shader_id();
-        let vertex_shader = self
-            .vertex_shaders
-            .get(vertex_shader_id)
-            .ok_or(LinkProgramError::VertexShaderNotFound)?;
+            gl.use_program(Some(program));
 
-        let fragment_shader_id = program_link.fragment_shader_id();
-        let fragment_shader = self
-            .fragment_shaders
-            .get(fragment_shader_id)
-            .ok_or(LinkProgramError::FragmentShaderNotFound)?;
+            for i in 0..num_uniforms {
+                let Some(info) = gl.get_active_uniform(program, i) else {
+                    continue;
+                };
+                let name = info.name();
+                let base_name = name.strip_suffix("[0]").unwrap_or(&name);
+                if linked_names.contains(&name) || linked_names.contains(base_name) {
+                    continue;
+                }
+                let Some(default) = UniformDefault::from_gl_type(info.type_()) else {
+                    continue;
+                };
+                let Some(location) = gl.get_uniform_location(program, &name) else {
+                    continue;
+                };
+
+                default.apply(gl, &location, info.size());
+                info!("Uniform {name:?} in program {program_id:?} has no UniformLink: initialized to {default}");
+            }
 
-        // @todo - make this not have to clone the slice
-        let transform_feedback_varyings = program_link.transform_feedback_varyings().to_vec();
+            gl.use_program(None);
+        }
 
-        let webgl_program = gl.create_program().ok_or(LinkProgramError::NoProgram)?;
+        Ok(self)
+    }
 
-        // assign attribute locations
-        for (attribute_id, attribute_location) in self.attribute_locations.iter() {
-            gl.bind_attrib_location(&webgl_program, *attribute_location, &attribute_id.name());
+    /// See [RendererDataBuilder::set_validate_programs]
+    fn validate_programs(&mut self) -> Result<&mut Self, CreateUniformError> {
+        if !self.validate_programs {
+            return Ok(self);
         }
+        let gl = self.gl.as_ref().ok_or(CreateUniformError::NoContext)?;
+        let mut warnings = Vec::new();
+
+        for (program_id, program) in &self.programs {
+            gl.validate_program(program);
+            let is_valid = gl
+                .get_program_parameter(program, WebGl2RenderingContext::VALIDATE_STATUS)
+                .as_bool()
+                .unwrap_or(false);
+            if !is_valid {
+                warnings.push(ProgramValidationWarning::ValidationFailed {
+                    program_id: program_id.clone(),
+                    info_log: gl.get_program_info_log(program).unwrap_or_default(),
+                });
+            }
 
-        gl.attach_shader(&webgl_program, vertex_shader);
-        gl.attach_shader(&webgl_program, fragment_shader);
+            let num_uniforms = gl
+                .get_program_parameter(program, WebGl2RenderingContext::ACTIVE_UNIFORMS)
+                .as_f64()
+                .unwrap_or_default() as u32;
+            let mut samplers = Vec::new();
+            for i in 0..num_uniforms {
+                let Some(info) = gl.get_active_uniform(program, i) else {
+                    continue;
+                };
+                let Some(target) = sampler_target(info.type_()) else {
+                    continue;
+                };
+                let name = info.name();
+                let base_name = name.strip_suffix("[0]").unwrap_or(&name);
+                for element in 0..info.size() {
+                    let element_name = if info.size() > 1 {
+                        format!("{base_name}[{element}]")
+                    } else {
+                        name.clone()
+                    };
+                    let Some(location) = gl.get_uniform_location(program, &element_name) else {
+                        continue;
+                    };
+                    let unit = gl
+                        .get_uniform(program, &location)
+                        .as_f64()
+                        .unwrap_or_default() as u32;
+                    samplers.push((element_name, target, unit));
+                }
+            }
 
-        if !transform_feedback_varyings.is_empty() {
-            let varyings_js_value = JsValue::from_serde(&transform_feedback_varyings)
-                .map_err(|_| LinkProgramError::CouldNotConvertVaryingsToArray)?;
-            gl.transform_feedback_varyings(
-                &webgl_program,
-                &varyings_js_value,
-                WebGl2RenderingContext::INTERLEAVED_ATTRIBS,
-            )
+            let texture_units = &self.texture_units;
+            warnings.extend(check_sampler_units(program_id, &samplers, |unit| {
+                texture_units.texture_id(unit).is_some()
+            }));
         }
 
-        gl.link_program(&webgl_program);
+        for warning in &warnings {
+            warn!("{warning}");
+        }
+        self.program_validation_warnings = warnings;
 
-        if gl
-            .get_program_parameter(&webgl_program, WebGl2RenderingContext::LINK_STATUS)
-            .as_bool()
-            .unwrap_or(false)
-        {
-            Ok(webgl_program)
-        } else {
-            let inner_error = match gl.get_program_info_log(&webgl_program) {
-                Some(known_error) => LinkProgramError::KnownError(known_error),
-                None => LinkProgramError::UnknownError,
-            };
-            Err(inner_error)?
+        Ok(self)
+    }
+
+    /// Attaches pointer listeners to the canvas if any mouse uniforms were registered
+    fn create_pointer_tracker(&mut self) -> Result<&mut Self, BuildRendererError> {
+        if let Some(pointer_state) = &self.pointer_state {
+            let canvas = self.canvas.as_ref().ok_or(BuildRendererError::NoCanvas)?;
+            let pointer_tracker = PointerTracker::new_with_state(canvas, Rc::clone(pointer_state));
+            self.pointer_tracker = Some(pointer_tracker);
         }
+
+        Ok(self)
     }
 
     /// Gets current DOMHighResTimeStamp from performance.now()
@@ -1215,44 +5011,31 @@ impl<
         window().unwrap().performance().unwrap().now()
     }
 
-    /// Takes the string source of a shader and compiles to using the current WebGL2RenderingContext
-    fn compile_shader<ShaderId: Id>(
+    /// Identifies a program in the [ShaderCache] by everything that affects linking: both shader
+    /// sources (and how they are preprocessed), transform feedback varyings, and attribute locations
+    fn program_hash(
         &self,
-        shader_id: ShaderId,
-        shader_type: ShaderType,
-        source: &str,
-    ) -> Result<WebGlShader, CompileShaderError> {
-        let gl = self.gl.as_ref().ok_or(CompileShaderError::NoContext {
-            shader_id: format!("{shader_id:#?}"),
-        })?;
-
-        let shader =
-            gl.create_shader(shader_type.into())
-                .ok_or(CompileShaderError::NoShaderReturned {
-                    shader_id: format!("{shader_id:#?}"),
-                })?;
-
-        gl.shader_source(&shader, source);
-        gl.compile_shader(&shader);
-
-        if gl
-            .get_shader_parameter(&shader, WebGl2RenderingContext::COMPILE_STATUS)
-            .as_bool()
-            .unwrap_or(false)
-        {
-            Ok(shader)
-        } else {
-            let inner_error = match gl.get_shader_info_log(&shader) {
-                Some(known_error) => CompileShaderError::KnownError {
-                    shader_id: format!("{shader_id:#?}"),
-                    error: known_error,
-                },
-                None => CompileShaderError::UnknownError {
-                    shader_id: format!("{shader_id:#?}"),
-                },
-            };
-            Err(inner_error)?
-        }
+        program_link: &ProgramLink<ProgramId, VertexShaderId, FragmentShaderId>,
+    ) -> u64 {
+        let mut attribute_locations: Vec<(String, u32)> = self
+            .attribute_locations
+            .iter()
+            .map(|(attribute_id, location)| (attribute_id.name(), *location))
+            .collect();
+        attribute_locations.sort();
+
+        let key = format!(
+            "{:?}|{:?}|{:?}|{:?}|{:?}|{:?}",
+            self.vertex_shader_sources
+                .get(program_link.vertex_shader_id()),
+            self.fragment_shader_sources
+                .get(program_link.fragment_shader_id()),
+            self.shader_header_options,
+            program_link.transform_feedback_varyings(),
+            attribute_locations,
+            program_link.attribute_locations()
+        );
+        ShaderCache::source_hash(ShaderType::VertexShader, &key)
     }
 }
 
@@ -1297,8 +5080,10 @@ impl<
             user_ctx: Default::default(),
             uniform_links: Default::default(),
             uniforms: Default::default(),
+            uniform_indices: Default::default(),
             buffer_links: Default::default(),
             buffers: Default::default(),
+            buffer_pairs: Default::default(),
             texture_links: Default::default(),
             textures: Default::default(),
             framebuffer_links: Default::default(),
@@ -1307,10 +5092,29 @@ impl<
             attributes: Default::default(),
             vertex_array_object_links: Default::default(),
             vertex_array_objects: Default::default(),
+            empty_vao: Default::default(),
             transform_feedbacks: Default::default(),
             transform_feedback_links: Default::default(),
             get_context_callback: Default::default(),
             attribute_locations: Default::default(),
+            pointer_state: Default::default(),
+            pointer_tracker: Default::default(),
+            shader_header_options: Default::default(),
+            frame_watchdog: Default::default(),
+            error_overlay: Default::default(),
+            shader_cache: Default::default(),
+            texture_units: Default::default(),
+            sampler_texture_units: Default::default(),
+            validate_programs: Default::default(),
+            drawing_buffer_color_space: Default::default(),
+            unpack_color_space: Default::default(),
+            program_validation_warnings: Default::default(),
+            uniform_namespaces: Default::default(),
         }
     }
 }
+
+/// Seed for [RendererData::rng] and [RendererData::noise] outside of deterministic mode
+fn time_seed() -> u64 {
+    Date::now() as u64
+}
@@ -1,7 +1,8 @@
 use crate::{
-    AttributeLinkJs, BufferLinkJs, FramebufferLinkJs, ProgramLinkJs, RenderCallbackJs,
-    RendererDataBuilder, RendererDataJs, RendererJs, TextureJs, TextureLinkJs,
-    TransformFeedbackLinkJs, UniformLinkJs,
+    utils, AttributeLinkJs, BufferLinkJs, BufferPairLinkJs, CanvasColorSpace, FramebufferLinkJs,
+    MouseUniformSpace, NonFiniteCheck, OutputAdjustment, ProgramLinkJs, RenderCallbackJs,
+    RendererDataBuilder, RendererDataJs, RendererJs, ShaderCache, ShaderCacheJs, StringArray,
+    TextureJs, TextureLinkJs, TransformFeedbackLinkJs, UniformLinkJs,
 };
 use js_sys::{Function, Object};
 
@@ -73,11 +74,75 @@ impl RendererDataBuilderJs {
         self.deref_mut().add_uniform_link(uniform_link);
     }
 
+    #[wasm_bindgen(js_name = addMouseUniform)]
+    pub fn add_mouse_uniform(
+        &mut self,
+        program_ids: StringArray,
+        uniform_id: String,
+        mouse_uniform_space: MouseUniformSpace,
+    ) {
+        let program_ids = utils::js_array_to_vec_strings(&program_ids);
+        self.deref_mut()
+            .add_mouse_uniform(program_ids, uniform_id, mouse_uniform_space);
+    }
+
+    #[cfg(feature = "shader-toy")]
+    #[wasm_bindgen(js_name = addShaderToyProgram)]
+    pub fn add_shader_toy_program(
+        &mut self,
+        program_id: String,
+        vertex_shader_id: String,
+        fragment_shader_id: String,
+        shader_toy_src: String,
+    ) {
+        let uniform_namespace = program_id.clone();
+        self.deref_mut().add_shader_toy_program(
+            program_id,
+            vertex_shader_id,
+            fragment_shader_id,
+            shader_toy_src,
+            // ids are scoped to the program, so that each ShaderToy program keeps its own uniforms
+            |shader_toy_uniform| format!("{uniform_namespace}.{}", shader_toy_uniform.name()),
+        );
+    }
+
+    /// Adds the program used by `Renderer.checkNonFinite`
+    #[wasm_bindgen(js_name = addNonFiniteCheck)]
+    pub fn add_non_finite_check(
+        &mut self,
+        program_id: String,
+        vertex_shader_id: String,
+        fragment_shader_id: String,
+    ) {
+        // the callback is supplied when checking, so it isn't needed here
+        let check = NonFiniteCheck::new(program_id, |_| {});
+        self.deref_mut()
+            .add_non_finite_check(&check, vertex_shader_id, fragment_shader_id);
+    }
+
+    /// Adds the program used by `Renderer.setOutputAdjustment`
+    #[wasm_bindgen(js_name = addOutputAdjustment)]
+    pub fn add_output_adjustment(
+        &mut self,
+        program_id: String,
+        vertex_shader_id: String,
+        fragment_shader_id: String,
+    ) {
+        let adjustment = OutputAdjustment::new(program_id);
+        self.deref_mut()
+            .add_output_adjustment(&adjustment, vertex_shader_id, fragment_shader_id);
+    }
+
     #[wasm_bindgen(js_name = addBufferLink)]
     pub fn add_buffer_link(&mut self, buffer_link: BufferLinkJs) {
         self.deref_mut().add_buffer_link(buffer_link);
     }
 
+    #[wasm_bindgen(js_name = addBufferPairLink)]
+    pub fn add_buffer_pair_link(&mut self, buffer_pair_link: BufferPairLinkJs) {
+        self.deref_mut().add_buffer_pair_link(buffer_pair_link);
+    }
+
     #[wasm_bindgen(js_name = addAttributeLink)]
     pub fn add_attribute_link(&mut self, attribute_link: AttributeLinkJs) {
         self.deref_mut().add_attribute_link(attribute_link);
@@ -110,6 +175,49 @@ impl RendererDataBuilderJs {
             .set_get_context_callback(get_context_callback);
     }
 
+    /// In debug builds, shows build errors over the canvas instead of leaving it blank
+    #[wasm_bindgen(js_name = setErrorOverlay)]
+    pub fn set_error_overlay(&mut self, enabled: bool) {
+        self.deref_mut().set_error_overlay(enabled);
+    }
+
+    /// Reuses shaders and programs compiled from identical sources by an earlier build.
+    /// The same cache can be shared between builders (e.g. across hot reloads).
+    #[wasm_bindgen(js_name = setShaderCache)]
+    pub fn set_shader_cache(&mut self, shader_cache: &ShaderCacheJs) {
+        self.deref_mut()
+            .set_shader_cache(ShaderCache::clone(shader_cache));
+    }
+
+    /// Checks each program with `gl.validateProgram` and for samplers that share a texture unit
+    #[wasm_bindgen(js_name = setValidatePrograms)]
+    pub fn set_validate_programs(&mut self, validate_programs: bool) {
+        self.deref_mut().set_validate_programs(validate_programs);
+    }
+
+    #[wasm_bindgen(js_name = setDrawingBufferColorSpace)]
+    pub fn set_drawing_buffer_color_space(&mut self, color_space: CanvasColorSpace) {
+        self.deref_mut().set_drawing_buffer_color_space(color_space);
+    }
+
+    #[wasm_bindgen(js_name = setUnpackColorSpace)]
+    pub fn set_unpack_color_space(&mut self, color_space: CanvasColorSpace) {
+        self.deref_mut().set_unpack_color_space(color_space);
+    }
+
+    /// Reserves a texture unit for a texture, instead of letting the renderer choose one
+    #[wasm_bindgen(js_name = setTextureUnit)]
+    pub fn set_texture_unit(&mut self, texture_id: String, unit: u32) {
+        self.deref_mut().set_texture_unit(texture_id, unit);
+    }
+
+    /// Calls `callback` with a plain object describing the pipeline's state whenever
+    /// a frame takes longer than `threshold_ms`
+    #[wasm_bindgen(js_name = setFrameWatchdog)]
+    pub fn set_frame_watchdog(&mut self, threshold_ms: f64, callback: Function) {
+        self.deref_mut().set_frame_watchdog(threshold_ms, callback);
+    }
+
     #[wasm_bindgen(js_name = buildRendererData)]
     pub fn build_renderer_data(self) -> Result<RendererDataJs, String> {
         self.0
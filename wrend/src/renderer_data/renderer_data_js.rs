@@ -1,11 +1,14 @@
 use crate::{
-    utils, AttributeJs, AttributeMap, BufferJs, BufferMap, FramebufferJs, RenderCallback,
-    RendererData, RendererDataBuilderJs, RendererJs, RendererJsInner, StringArray, TextureJs,
-    TextureJsArray, TextureMap, UniformJs, UniformMap, WebGlProgramMap, WebGlShaderMap,
+    utils, AttributeJs, AttributeMap, BlitFilter, BufferJs, BufferMap, Callback, CanvasColorSpace,
+    ClearValue, FramebufferJs, Id, IdName, RenderCallback, RendererData, RendererDataBuilderJs,
+    RendererJs, RendererJsInner, StateIdsJs, StateSnapshotJs, StringArray, TextureJs,
+    TextureJsArray, TextureMap, TraceCategory, UniformJs, UniformMap, Viewport, WebGlProgramMap,
+    WebGlShaderMap,
 };
 use js_sys::{Array, Map, Object};
 use log::error;
 use std::{
+    any::Any,
     cell::RefCell,
     ops::{Deref, DerefMut},
     rc::Rc,
@@ -115,9 +118,9 @@ impl RendererDataJs {
     pub fn uniforms(&self) -> UniformMap {
         let map = Map::new();
 
-        for (key, uniform) in self.deref().borrow().uniforms().iter() {
+        for uniform in self.deref().borrow().uniforms_in_order() {
             let js_uniform: UniformJs = uniform.into();
-            map.set(&JsValue::from_str(key), &js_uniform.into());
+            map.set(&JsValue::from_str(uniform.uniform_id()), &js_uniform.into());
         }
 
         map.dyn_into()
@@ -147,12 +150,27 @@ impl RendererDataJs {
             .map(Into::into)
     }
 
+    /// Gets the attribute bound in a particular VAO (or in the default vertex array, if `vaoId` is omitted)
+    #[wasm_bindgen(js_name = attributeForVao)]
+    pub fn attribute_for_vao(
+        &self,
+        attribute_id: String,
+        vao_id: Option<String>,
+    ) -> Option<AttributeJs> {
+        self.deref()
+            .borrow()
+            .attribute_for_vao(&attribute_id, vao_id.as_ref())
+            .map(Into::into)
+    }
+
     pub fn attributes(&self) -> AttributeMap {
         let map = Map::new();
 
-        for (key, value) in self.deref().borrow().attributes().iter() {
+        // attributes bound to different buffers in different VAOs share a key here:
+        // use `attributeForVao` to look those up individually
+        for value in self.deref().borrow().attributes().values() {
             let attribute: AttributeJs = value.into();
-            map.set(&JsValue::from_str(key), &attribute.into());
+            map.set(&JsValue::from_str(value.attribute_id()), &attribute.into());
         }
 
         map.dyn_into()
@@ -163,6 +181,29 @@ impl RendererDataJs {
         self.deref().borrow().texture(&texture_id).map(Into::into)
     }
 
+    #[wasm_bindgen(js_name = drawingBufferColorSpace)]
+    pub fn drawing_buffer_color_space(&self) -> CanvasColorSpace {
+        self.deref().borrow().drawing_buffer_color_space()
+    }
+
+    #[wasm_bindgen(js_name = textureUnit)]
+    pub fn texture_unit(&self, texture_id: String) -> Option<u32> {
+        self.deref().borrow().texture_unit(&texture_id)
+    }
+
+    /// Descriptions of the problems found by `setValidatePrograms`
+    #[wasm_bindgen(js_name = programValidationWarnings)]
+    pub fn program_validation_warnings(&self) -> StringArray {
+        let warnings: Vec<String> = self
+            .deref()
+            .borrow()
+            .program_validation_warnings()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        utils::strings_to_js_array(&warnings)
+    }
+
     pub fn textures(&self) -> TextureMap {
         let map = Map::new();
 
@@ -218,6 +259,56 @@ impl RendererDataJs {
         self.deref().borrow().vao(&vao_id).map(Clone::clone)
     }
 
+    /// Flips the read/write roles of a buffer pair (see `BufferPairLink`)
+    #[wasm_bindgen(js_name = swapBufferPair)]
+    pub fn swap_buffer_pair(&self, pair_id: String) {
+        self.deref().borrow().swap_buffer_pair(&pair_id);
+    }
+
+    /// Reads back the listed textures and buffers so they can be saved and restored later
+    #[wasm_bindgen(js_name = exportState)]
+    pub fn export_state(&self, state_ids: &StateIdsJs) -> Result<StateSnapshotJs, String> {
+        self.deref()
+            .borrow()
+            .export_state(state_ids)
+            .map(Into::into)
+            .map_err(|err| err.to_string())
+    }
+
+    #[wasm_bindgen(js_name = importState)]
+    pub fn import_state(&self, snapshot: &StateSnapshotJs) -> Result<(), String> {
+        self.deref()
+            .borrow()
+            .import_state(snapshot)
+            .map_err(|err| err.to_string())
+    }
+
+    /// Copies the first `count` floats of a buffer back to the CPU (stalls until the GPU catches up)
+    #[wasm_bindgen(js_name = readTransformFeedbackBuffer)]
+    pub fn read_transform_feedback_buffer(&self, buffer_id: String, count: u32) -> Vec<f32> {
+        self.deref()
+            .borrow()
+            .read_transform_feedback_buffer(&buffer_id, count)
+    }
+
+    /// The `[bufferId, VAOId]` of a buffer pair to read from this frame
+    #[wasm_bindgen(js_name = bufferPairRead)]
+    pub fn buffer_pair_read(&self, pair_id: String) -> Option<StringArray> {
+        self.deref()
+            .borrow()
+            .buffer_pair(&pair_id)
+            .map(|buffer_pair| buffer_pair_ids_to_js(buffer_pair.read()))
+    }
+
+    /// The `[bufferId, VAOId]` of a buffer pair to write to this frame
+    #[wasm_bindgen(js_name = bufferPairWrite)]
+    pub fn buffer_pair_write(&self, pair_id: String) -> Option<StringArray> {
+        self.deref()
+            .borrow()
+            .buffer_pair(&pair_id)
+            .map(|buffer_pair| buffer_pair_ids_to_js(buffer_pair.write()))
+    }
+
     #[wasm_bindgen(js_name = userCtx)]
     pub fn user_ctx(&self) -> Option<Object> {
         self.deref().borrow().user_ctx().map(Clone::clone)
@@ -233,6 +324,41 @@ impl RendererDataJs {
         self.deref().borrow().use_vao(&vao_id);
     }
 
+    /// Binds a framebuffer for drawing. Binds the canvas if no id is given.
+    #[wasm_bindgen(js_name = bindFramebuffer)]
+    pub fn bind_framebuffer(&self, framebuffer_id: Option<String>) {
+        self.deref()
+            .borrow()
+            .use_framebuffer(framebuffer_id.as_ref());
+    }
+
+    #[wasm_bindgen(js_name = drawArrays)]
+    pub fn draw_arrays(&self, mode: u32, first: i32, count: i32) {
+        self.deref().borrow().gl().draw_arrays(mode, first, count);
+    }
+
+    /// Draws a viewport-covering triangle with an attribute-less VAO (positions should come from `gl_VertexID`)
+    #[wasm_bindgen(js_name = drawFullscreenTriangle)]
+    pub fn draw_fullscreen_triangle(&self, program_id: String) {
+        self.deref().borrow().draw_fullscreen_triangle(&program_id);
+    }
+
+    #[wasm_bindgen(js_name = drawArraysInstanced)]
+    pub fn draw_arrays_instanced(&self, mode: u32, first: i32, count: i32, instance_count: i32) {
+        self.deref()
+            .borrow()
+            .gl()
+            .draw_arrays_instanced(mode, first, count, instance_count);
+    }
+
+    #[wasm_bindgen(js_name = drawElements)]
+    pub fn draw_elements(&self, mode: u32, count: i32, index_type: u32, offset: i32) {
+        self.deref()
+            .borrow()
+            .gl()
+            .draw_elements_with_i32(mode, count, index_type, offset);
+    }
+
     #[wasm_bindgen(js_name = updateUniform)]
     pub fn update_uniform(&self, uniform_id: String) {
         self.deref().borrow().update_uniform(&uniform_id);
@@ -243,22 +369,130 @@ impl RendererDataJs {
         self.deref().borrow().update_uniforms();
     }
 
+    #[wasm_bindgen(js_name = invalidateUniform)]
+    pub fn invalidate_uniform(&self, uniform_id: String) {
+        self.deref().borrow().invalidate_uniform(&uniform_id);
+    }
+
     // `render` does not deref to the internal `RendererData` here, because its much less complex (and much faster) to
     // pass `RendererDataJs` as an argument to the `render` function here at this level , rather than converting
     // back into a `RendererDataJs` from within the `RendererData` struct.
     pub fn render(&self) {
         let render_callback = self.deref().borrow().render_callback();
         if let Some(js_callback) = render_callback.js().as_ref() {
-            // Internals of `RendererDataJs` are stored behind an `Rc`, so this is a cheap operation
-            let js_value: JsValue = self.clone().into();
-            if let Err(err) = js_callback.call1(&JsValue::NULL, &js_value) {
-                error!("Error occurred while calling JavaScript `render` callback: {err:?}");
-            }
+            self.render_frame(|| {
+                // Internals of `RendererDataJs` are stored behind an `Rc`, so this is a cheap operation
+                let js_value: JsValue = self.clone().into();
+                if let Err(err) = js_callback.call1(&JsValue::NULL, &js_value) {
+                    error!("Error occurred while calling JavaScript `render` callback: {err:?}");
+                }
+            });
         } else {
             error!("`render_js` was called without any `js_callback` to call. This is a no-op.")
         }
     }
 
+    /// Updates uniforms and renders as if the current time were `timeMs`
+    #[wasm_bindgen(js_name = renderAt)]
+    pub fn render_at(&self, time_ms: f64) {
+        let previous_time_override = self.deref().borrow().time_override();
+        self.deref().borrow().set_time_override(Some(time_ms));
+        self.deref().borrow().update_uniforms();
+        self.render();
+        self.deref()
+            .borrow()
+            .set_time_override(previous_time_override);
+    }
+
+    /// Binds the texture at each index of `texture_ids` to the texture unit at the same index of `texture_units`
+    #[wasm_bindgen(js_name = bindTextures)]
+    pub fn bind_textures(&self, texture_ids: StringArray, texture_units: Vec<u32>) {
+        let textures: Vec<(String, u32)> = utils::js_array_to_vec_strings(&texture_ids)
+            .into_iter()
+            .zip(texture_units)
+            .collect();
+        self.deref().borrow().bind_textures(&textures);
+    }
+
+    /// Copies the canvas's current contents into a texture (resizing it to match the canvas)
+    #[wasm_bindgen(js_name = captureCanvasToTexture)]
+    pub fn capture_canvas_to_texture(&self, texture_id: String) -> Result<(), String> {
+        self.deref()
+            .borrow()
+            .capture_canvas_to_texture(&texture_id)
+            .map_err(|err| err.to_string())
+    }
+
+    #[wasm_bindgen(js_name = copyTexture)]
+    pub fn copy_texture(
+        &self,
+        src_texture_id: String,
+        dst_texture_id: String,
+        width: i32,
+        height: i32,
+    ) -> Result<(), String> {
+        self.deref()
+            .borrow()
+            .copy_texture(&src_texture_id, &dst_texture_id, width, height)
+            .map_err(|err| err.to_string())
+    }
+
+    /// Clears a texture with 4 float components (for normalized and floating point formats)
+    #[wasm_bindgen(js_name = clearTexture)]
+    pub fn clear_texture(&self, texture_id: String, value: Vec<f32>) -> Result<(), String> {
+        let value = match value[..] {
+            [r, g, b, a, ..] => ClearValue::Float([r, g, b, a]),
+            _ => return Err(String::from("Clear values must have 4 components")),
+        };
+        self.deref()
+            .borrow()
+            .clear_texture(&texture_id, value)
+            .map_err(|err| err.to_string())
+    }
+
+    /// Rects are `[x, y, width, height]` in pixels. An `undefined` framebuffer id refers to the canvas.
+    pub fn blit(
+        &self,
+        src_framebuffer_id: Option<String>,
+        dst_framebuffer_id: Option<String>,
+        src_rect: Vec<i32>,
+        dst_rect: Vec<i32>,
+        filter: BlitFilter,
+    ) -> Result<(), String> {
+        let rect = |rect: &[i32]| match rect {
+            [x, y, width, height, ..] => Ok(Viewport::new(*x, *y, *width, *height)),
+            _ => Err(String::from("Blit rects must have 4 components")),
+        };
+        self.deref()
+            .borrow()
+            .blit(
+                src_framebuffer_id.as_ref(),
+                dst_framebuffer_id.as_ref(),
+                rect(&src_rect)?,
+                rect(&dst_rect)?,
+                filter,
+            )
+            .map_err(|err| err.to_string())
+    }
+
+    #[cfg(feature = "shader-toy")]
+    #[wasm_bindgen(js_name = drawShaderToy)]
+    pub fn draw_shader_toy(&self, program_id: String, channel_texture_ids: StringArray) {
+        let channel_texture_ids = utils::js_array_to_vec_strings(&channel_texture_ids);
+        self.deref()
+            .borrow()
+            .draw_shader_toy(&program_id, &channel_texture_ids);
+    }
+
+    /// Returns a description of the feedback hazard for the next draw call, if there is one
+    #[wasm_bindgen(js_name = findFeedbackHazard)]
+    pub fn find_feedback_hazard(&self) -> Option<String> {
+        self.deref()
+            .borrow()
+            .find_feedback_hazard()
+            .map(|hazard| hazard.to_string())
+    }
+
     #[wasm_bindgen(js_name = saveImage)]
     pub fn save_image(&self) {
         self.deref().borrow().save_image();
@@ -275,6 +509,106 @@ impl RendererDataJs {
         self.0
     }
 
+    /// A JavaScript handle to `renderer_data`, if its types are the ones the JavaScript API uses.
+    ///
+    /// This is how generic code decides whether a JavaScript callback can be handed a `RendererData`.
+    pub(crate) fn from_renderer_data<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        ProgramId: Id,
+        UniformId: Id + IdName,
+        BufferId: Id,
+        AttributeId: Id + IdName,
+        TextureId: Id,
+        FramebufferId: Id,
+        TransformFeedbackId: Id,
+        VertexArrayObjectId: Id,
+        UserCtx: Clone + 'static,
+    >(
+        renderer_data: &Rc<
+            RefCell<
+                RendererData<
+                    VertexShaderId,
+                    FragmentShaderId,
+                    ProgramId,
+                    UniformId,
+                    BufferId,
+                    AttributeId,
+                    TextureId,
+                    FramebufferId,
+                    TransformFeedbackId,
+                    VertexArrayObjectId,
+                    UserCtx,
+                >,
+            >,
+        >,
+    ) -> Option<Self> {
+        (renderer_data as &dyn Any)
+            .downcast_ref::<Rc<RefCell<RendererDataJsInner>>>()
+            .map(|renderer_data| Self(Rc::clone(renderer_data)))
+    }
+
+    /// Calls a render callback or hook: JavaScript callbacks receive this `RendererData` handle,
+    /// and Rust callbacks receive the inner `RendererData`
+    pub(crate) fn call_render_callback(
+        &self,
+        callback: &RenderCallback<
+            String,
+            String,
+            String,
+            String,
+            String,
+            String,
+            String,
+            String,
+            String,
+            String,
+            Object,
+        >,
+    ) {
+        match &**callback {
+            Callback::Rust(rust_callback) => (rust_callback)(&self.deref().borrow()),
+            Callback::Js(js_callback) => {
+                // Internals of `RendererDataJs` are stored behind an `Rc`, so this is a cheap operation
+                let js_value: JsValue = self.clone().into();
+                if let Err(err) = js_callback.call1(&JsValue::NULL, &js_value) {
+                    error!("Error occurred while calling JavaScript `render` callback: {err:?}");
+                }
+            }
+        }
+    }
+
+    /// Runs `render` between the before- and after-render hooks.
+    ///
+    /// The hook lists are cloned first, so that hooks can add or remove hooks while running.
+    /// Runs `render` between the render hooks, wrapped in the same frame setup and teardown as
+    /// [crate::RendererData::render] (debug views, output adjustment, tracing, etc.)
+    pub(crate) fn render_frame(&self, render: impl FnOnce()) {
+        let frame = self.deref().borrow().begin_frame();
+        let frame_tracer = self.deref().borrow().frame_tracer().clone();
+        frame_tracer.span("render", TraceCategory::Frame, || {
+            self.with_render_hooks(render)
+        });
+        self.deref().borrow().finish_frame(frame);
+    }
+
+    pub(crate) fn with_render_hooks(&self, render: impl FnOnce()) {
+        let (before_render_hooks, after_render_hooks) = {
+            let renderer_data = self.deref().borrow();
+            (
+                renderer_data.before_render_hooks().clone(),
+                renderer_data.after_render_hooks().clone(),
+            )
+        };
+        for hook in before_render_hooks.hooks() {
+            self.call_render_callback(hook);
+        }
+        render();
+        for hook in after_render_hooks.hooks() {
+            self.call_render_callback(hook);
+        }
+    }
+
     pub fn render_callback(
         &self,
     ) -> RenderCallback<
@@ -327,3 +661,10 @@ impl From<RendererDataJs> for RendererJs {
         renderer.into()
     }
 }
+
+/// Converts one side of a [crate::BufferPair] into a `[bufferId, VAOId]` array
+pub(crate) fn buffer_pair_ids_to_js((buffer_id, vao_id): (&String, &String)) -> StringArray {
+    Array::of2(&JsValue::from_str(buffer_id), &JsValue::from_str(vao_id))
+        .dyn_into()
+        .expect("Should be able to convert Array of buffer pair ids into StringArray")
+}
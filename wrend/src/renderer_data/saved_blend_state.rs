@@ -0,0 +1,44 @@
+use web_sys::WebGl2RenderingContext as Gl;
+
+/// Blend state that a frame changes (e.g. for [crate::DebugView::Overdraw]), so that whatever
+/// the app had set up before rendering can be restored afterward
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SavedBlendState {
+    enabled: bool,
+    src_rgb: u32,
+    dst_rgb: u32,
+    src_alpha: u32,
+    dst_alpha: u32,
+    equation_rgb: u32,
+    equation_alpha: u32,
+}
+
+impl SavedBlendState {
+    pub(crate) fn save(gl: &Gl) -> Self {
+        let get = |pname, default| {
+            gl.get_parameter(pname)
+                .ok()
+                .and_then(|value| value.as_f64())
+                .map_or(default, |value| value as u32)
+        };
+        Self {
+            enabled: gl.is_enabled(Gl::BLEND),
+            src_rgb: get(Gl::BLEND_SRC_RGB, Gl::ONE),
+            dst_rgb: get(Gl::BLEND_DST_RGB, Gl::ZERO),
+            src_alpha: get(Gl::BLEND_SRC_ALPHA, Gl::ONE),
+            dst_alpha: get(Gl::BLEND_DST_ALPHA, Gl::ZERO),
+            equation_rgb: get(Gl::BLEND_EQUATION_RGB, Gl::FUNC_ADD),
+            equation_alpha: get(Gl::BLEND_EQUATION_ALPHA, Gl::FUNC_ADD),
+        }
+    }
+
+    pub(crate) fn restore(&self, gl: &Gl) {
+        if self.enabled {
+            gl.enable(Gl::BLEND);
+        } else {
+            gl.disable(Gl::BLEND);
+        }
+        gl.blend_func_separate(self.src_rgb, self.dst_rgb, self.src_alpha, self.dst_alpha);
+        gl.blend_equation_separate(self.equation_rgb, self.equation_alpha);
+    }
+}
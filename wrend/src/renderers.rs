@@ -1,15 +1,23 @@
 mod errors;
+mod frame_info;
+mod frame_stream;
 mod get_context_callback;
 mod get_context_callback_js;
 mod render_callback;
 mod render_callback_js;
+mod render_hooks;
 mod renderer;
+#[cfg(feature = "js")]
 mod renderer_js;
 
 pub use errors::*;
+pub use frame_info::*;
+pub use frame_stream::*;
 pub use get_context_callback::*;
 pub use get_context_callback_js::*;
 pub use render_callback::*;
 pub use render_callback_js::*;
+pub use render_hooks::*;
 pub use renderer::*;
+#[cfg(feature = "js")]
 pub use renderer_js::*;
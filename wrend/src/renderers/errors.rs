@@ -1,5 +1,7 @@
+mod asset_error;
 mod build_renderer_error;
 mod compile_shader_error;
+mod copy_texture_error;
 mod create_attribute_error;
 mod create_buffer_error;
 mod create_framebuffer_error;
@@ -7,13 +9,31 @@ mod create_texture_error;
 mod create_transform_feedback_error;
 mod create_uniform_error;
 mod create_vao_error;
+mod external_resource_error;
+#[cfg(feature = "fluids")]
+mod fluid_simulation_error;
+mod frame_export_error;
 mod link_program_error;
+mod plot_error;
+mod point_cloud_error;
+mod render_graph_error;
 mod renderer_builder_error;
 mod save_context_error;
+#[cfg(feature = "sdf")]
+mod sdf_text_error;
+mod settings_error;
+mod shader_source_error;
+#[cfg(feature = "idb")]
+mod snapshot_store_error;
+mod state_snapshot_error;
+#[cfg(feature = "tweak-panel")]
+mod tweak_panel_error;
 mod webgl_context_error;
 
+pub use asset_error::*;
 pub use build_renderer_error::*;
 pub use compile_shader_error::*;
+pub use copy_texture_error::*;
 pub use create_attribute_error::*;
 pub use create_buffer_error::*;
 pub use create_framebuffer_error::*;
@@ -21,7 +41,23 @@ pub use create_texture_error::*;
 pub use create_transform_feedback_error::*;
 pub use create_uniform_error::*;
 pub use create_vao_error::*;
+pub use external_resource_error::*;
+#[cfg(feature = "fluids")]
+pub use fluid_simulation_error::*;
+pub use frame_export_error::*;
 pub use link_program_error::*;
+pub use plot_error::*;
+pub use point_cloud_error::*;
+pub use render_graph_error::*;
 pub use renderer_builder_error::*;
 pub use save_context_error::*;
+#[cfg(feature = "sdf")]
+pub use sdf_text_error::*;
+pub use settings_error::*;
+pub use shader_source_error::*;
+#[cfg(feature = "idb")]
+pub use snapshot_store_error::*;
+pub use state_snapshot_error::*;
+#[cfg(feature = "tweak-panel")]
+pub use tweak_panel_error::*;
 pub use webgl_context_error::*;
@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum AssetError {
+    #[error("No window was available to fetch assets with")]
+    NoWindow,
+    #[error("No document was available to create an image element with")]
+    NoDocument,
+    #[error("Request for {url:?} failed: {message}")]
+    RequestFailed { url: String, message: String },
+    #[error("Request for {url:?} returned status {status}")]
+    BadStatus { url: String, status: u16 },
+    #[error("Could not decode {url:?}: {message}")]
+    DecodeFailed { url: String, message: String },
+    #[error("Could not start the image decoding worker: {0}")]
+    WorkerFailed(String),
+}
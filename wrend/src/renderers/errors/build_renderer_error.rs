@@ -8,4 +8,6 @@ pub enum BuildRendererError {
     NoContext,
     #[error("No RenderCallback was supplied")]
     NoRenderCallback,
+    #[error("Only renderers built with a RendererDataBuilder can be rebuilt")]
+    NoBuildConfig,
 }
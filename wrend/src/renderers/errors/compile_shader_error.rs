@@ -1,3 +1,4 @@
+use crate::ShaderSourceError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
@@ -6,8 +7,17 @@ pub enum CompileShaderError {
     NoContext { shader_id: String },
     #[error("{shader_id:?}: Call to WebGL2RenderingContext returned None")]
     NoShaderReturned { shader_id: String },
-    #[error("{shader_id:?}: {error:?}")]
-    KnownError { shader_id: String, error: String },
+    #[error("{shader_id:?}: {error}\n{source_context}")]
+    KnownError {
+        shader_id: String,
+        error: String,
+        source_context: String,
+    },
+    #[error("{shader_id:?}: {error}")]
+    InvalidSource {
+        shader_id: String,
+        error: ShaderSourceError,
+    },
     #[error("{shader_id:?}: An unknown error occurred.")]
     UnknownError { shader_id: String },
 }
@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+/// Errors from copying, blitting, or clearing textures
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum CopyTextureError {
+    #[error("No texture was found with id {0}")]
+    TextureNotFound(String),
+    #[error("No framebuffer was found with id {0}")]
+    FramebufferNotFound(String),
+    #[error("Could not create scratch framebuffer")]
+    CreateFramebuffer,
+    #[error("Framebuffer is incomplete (status {status:#x})")]
+    IncompleteFramebuffer { status: u32 },
+    #[error("Cannot copy between color buffers with component types {src_component_type:#x} and {dst_component_type:#x}")]
+    IncompatibleFormats {
+        src_component_type: u32,
+        dst_component_type: u32,
+    },
+    #[error("Integer color formats cannot be copied with linear filtering")]
+    IntegerLinearFilter,
+    #[error("Clear value does not match the texture's component type ({component_type:#x})")]
+    ClearValueMismatch { component_type: u32 },
+}
@@ -6,4 +6,12 @@ pub enum CreateTextureError {
     NoContext,
     #[error("No Canvas was provided")]
     NoCanvas,
+    #[error("WebGL could not create a texture")]
+    TextureCreationFailed,
+    #[error("Texture {texture_id} was given texture unit {unit}, but only {max_units} texture units are available")]
+    TextureUnitOutOfRange {
+        texture_id: String,
+        unit: u32,
+        max_units: u32,
+    },
 }
@@ -0,0 +1,12 @@
+use thiserror::Error;
+
+/// Errors from registering resources created outside of wrend (see [crate::ExternalOwnership])
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum ExternalResourceError {
+    #[error("Texture id {0} is already used by a texture created by wrend")]
+    TextureIdInUse(String),
+    #[error("Buffer id {0} is already used by a buffer created by wrend")]
+    BufferIdInUse(String),
+    #[error("Framebuffer id {0} is already used by a framebuffer created by wrend")]
+    FramebufferIdInUse(String),
+}
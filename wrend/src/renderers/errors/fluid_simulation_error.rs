@@ -0,0 +1,16 @@
+use crate::RenderGraphError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum FluidSimulationError {
+    #[error("Rendering to floating point textures (EXT_color_buffer_float) is not supported")]
+    FloatRenderingNotSupported,
+    #[error("Could not compile the {pass} shader: {error}")]
+    CompileShader { pass: String, error: String },
+    #[error("Could not link the {pass} program: {error}")]
+    LinkProgram { pass: String, error: String },
+    #[error("Could not create a vertex array object")]
+    CreateVertexArray,
+    #[error(transparent)]
+    RenderGraph(#[from] RenderGraphError),
+}
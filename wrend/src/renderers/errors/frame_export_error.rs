@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum FrameExportError {
+    #[error("No frames were captured")]
+    NoFrames,
+    #[error("Frame has {actual} bytes of pixel data, but its size requires {expected}")]
+    FrameSizeMismatch { expected: usize, actual: usize },
+    #[error("Frames of {width}x{height} are too large for this format")]
+    FrameTooLarge { width: u32, height: u32 },
+    #[error("Could not read pixels from the drawing buffer: {0}")]
+    ReadPixelsFailed(String),
+    #[error("Could not encode frames: {0}")]
+    EncodingFailed(String),
+    #[error("Could not create Blob: {0}")]
+    BlobCreationFailed(String),
+}
@@ -1,3 +1,4 @@
+use crate::{LinkStepError, RendererBackendError};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
@@ -16,6 +17,37 @@ pub enum LinkProgramError {
     KnownError(String),
     #[error("Varyings could not be converted into a JavaScript array")]
     CouldNotConvertVaryingsToArray,
+    #[error("Attribute {attribute_name:?} was given location {location} by one ProgramLink and location {other_location} by another")]
+    ConflictingAttributeLocations {
+        attribute_name: String,
+        location: u32,
+        other_location: u32,
+    },
+    #[error("ProgramLink {program_id} binds both {attribute_name:?} and {other_attribute_name:?} to location {location}")]
+    DuplicateAttributeLocation {
+        program_id: String,
+        attribute_name: String,
+        other_attribute_name: String,
+        location: u32,
+    },
     #[error("An unknown error occurred")]
     UnknownError,
 }
+
+impl From<LinkStepError> for LinkProgramError {
+    fn from(error: LinkStepError) -> Self {
+        match error {
+            LinkStepError::VertexShaderNotFound => LinkProgramError::VertexShaderNotFound,
+            LinkStepError::FragmentShaderNotFound => LinkProgramError::FragmentShaderNotFound,
+            LinkStepError::Backend(RendererBackendError::NoneReturned) => {
+                LinkProgramError::NoProgram
+            }
+            LinkStepError::Backend(RendererBackendError::KnownError(info_log)) => {
+                LinkProgramError::KnownError(info_log)
+            }
+            LinkStepError::Backend(RendererBackendError::UnknownError) => {
+                LinkProgramError::UnknownError
+            }
+        }
+    }
+}
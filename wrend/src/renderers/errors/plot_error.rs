@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum PlotError {
+    #[error("Could not compile the {program} shaders: {error}")]
+    CompileShader { program: String, error: String },
+    #[error("Could not link the {program} program: {error}")]
+    LinkProgram { program: String, error: String },
+    #[error("Could not create a buffer")]
+    CreateBuffer,
+    #[error("Could not create a vertex array object")]
+    CreateVertexArray,
+}
@@ -0,0 +1,14 @@
+use crate::AssetError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum PointCloudError {
+    #[error("Could not create a buffer")]
+    CreateBuffer,
+    #[error("Could not create a vertex array object")]
+    CreateVertexArray,
+    #[error("Point cloud buffer holds {capacity} bytes, but {requested} were uploaded")]
+    CapacityExceeded { capacity: usize, requested: usize },
+    #[error(transparent)]
+    Asset(#[from] AssetError),
+}
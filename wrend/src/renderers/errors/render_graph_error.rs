@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum RenderGraphError {
+    #[error("Could not create texture {0:?}")]
+    CreateTexture(String),
+    #[error("Could not create framebuffer for texture {0:?}")]
+    CreateFramebuffer(String),
+    #[error("Pass {pass:?} refers to texture {texture:?}, which was never added to the graph")]
+    TextureNotFound { pass: String, texture: String },
+    #[error("More than one pass is named {0:?}")]
+    DuplicatePass(String),
+}
@@ -1,7 +1,8 @@
 use crate::{
     BuildRendererError, CompileShaderError, CreateAttributeError, CreateBufferError,
     CreateFramebufferError, CreateTextureError, CreateTransformFeedbackError, CreateUniformError,
-    CreateVAOError, LinkProgramError, SaveContextError, WebGlContextError,
+    CreateVAOError, LinkGraphError, LinkProgramError, LinkStepError, SaveContextError,
+    WebGlContextError,
 };
 use thiserror::Error;
 
@@ -11,6 +12,8 @@ pub enum RendererBuilderError {
     WebGlContextError(#[from] WebGlContextError),
     #[error("Error occurred while building the RendererData {0:?}")]
     RendererBuildError(#[from] BuildRendererError),
+    #[error("Links refer to resources that were never added: {0}")]
+    LinkGraphError(#[from] LinkGraphError),
     #[error("Error occurred while compiling shader: {0:?}")]
     CompileShaderError(#[from] CompileShaderError),
     #[error("Error occurred while linking program: {0:?}")]
@@ -32,3 +35,9 @@ pub enum RendererBuilderError {
     #[error("Error occurred while trying to create transform feedback: {0:?}")]
     CreateTransformFeedbackError(#[from] CreateTransformFeedbackError),
 }
+
+impl From<LinkStepError> for RendererBuilderError {
+    fn from(error: LinkStepError) -> Self {
+        RendererBuilderError::LinkProgramError(error.into())
+    }
+}
@@ -0,0 +1,24 @@
+use crate::AssetError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum SdfTextError {
+    #[error("Font metadata is not valid JSON: {0}")]
+    InvalidJson(String),
+    #[error("Font metadata is missing {0:?}")]
+    MissingField(String),
+    #[error(transparent)]
+    Asset(#[from] AssetError),
+    #[error("Could not compile the SDF text shaders: {0}")]
+    CompileShader(String),
+    #[error("Could not link the SDF text program: {0}")]
+    LinkProgram(String),
+    #[error("Could not create a buffer")]
+    CreateBuffer,
+    #[error("Could not create a vertex array object")]
+    CreateVertexArray,
+    #[error("Could not create the atlas texture")]
+    CreateTexture,
+    #[error("Could not upload the atlas image: {0}")]
+    UploadAtlas(String),
+}
@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum SettingsError {
+    #[error("localStorage is not available")]
+    NoStorage,
+    #[error("Could not write settings to localStorage: {0}")]
+    Write(String),
+    #[error("Setting {0:?} was never registered")]
+    NotRegistered(String),
+    #[error("Value for setting {0:?} is a different kind than its default")]
+    WrongKind(String),
+}
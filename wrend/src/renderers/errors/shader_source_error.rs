@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum ShaderSourceError {
+    #[error(
+        "`#version {version}` is not supported: wrend requires `#version 300 es` (WebGL2) shaders"
+    )]
+    UnsupportedVersion { version: String },
+    #[error("Line {line}: `{token}` is WebGL1 (GLSL ES 1.00) syntax. Use `in`/`out` instead of `attribute`/`varying`, `texture` instead of `texture2D`/`textureCube`, and declare an `out vec4` instead of writing to `gl_FragColor`")]
+    LegacySyntax { token: String, line: usize },
+}
@@ -0,0 +1,20 @@
+use crate::StateSnapshotError;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Errors from saving or loading snapshots with a [crate::SnapshotStore]
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum SnapshotStoreError {
+    #[error("IndexedDB is not available")]
+    NoIndexedDb,
+    #[error("IndexedDB request failed: {0}")]
+    Request(String),
+    #[error(transparent)]
+    Snapshot(#[from] StateSnapshotError),
+}
+
+impl SnapshotStoreError {
+    pub(crate) fn from_js(err: JsValue) -> Self {
+        Self::Request(format!("{err:?}"))
+    }
+}
@@ -0,0 +1,29 @@
+use crate::CopyTextureError;
+use thiserror::Error;
+
+/// Errors from exporting, importing, or (de)serializing a [crate::StateSnapshot]
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum StateSnapshotError {
+    #[error("No texture was found with id {0}")]
+    TextureNotFound(String),
+    #[error("No buffer was found with id {0}")]
+    BufferNotFound(String),
+    #[error(transparent)]
+    Texture(#[from] CopyTextureError),
+    #[error("Textures with component type {0:#x} cannot be saved in a snapshot")]
+    UnsupportedFormat(u32),
+    #[error("Could not create a texture to upload snapshot data into")]
+    CreateTexture,
+    #[error("Could not read pixels from texture: {0}")]
+    ReadPixelsFailed(String),
+    #[error("Could not upload pixels to texture: {0}")]
+    UploadPixelsFailed(String),
+    #[error("Texture data has {actual} bytes, but its size requires {expected}")]
+    TextureSizeMismatch { expected: usize, actual: usize },
+    #[error("Not a snapshot, or one written by an incompatible version")]
+    InvalidHeader,
+    #[error("Snapshot data ended unexpectedly")]
+    UnexpectedEnd,
+    #[error("Snapshot contains an invalid name")]
+    InvalidName,
+}
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq, Clone, Hash)]
+pub enum TweakPanelError {
+    #[error("No document was available to build the tweak panel in")]
+    NoDocument,
+    #[error("Could not create <{0}> element")]
+    CreateElement(&'static str),
+    #[error("Could not append element to the tweak panel")]
+    AppendElement,
+}
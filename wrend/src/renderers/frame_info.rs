@@ -0,0 +1,50 @@
+use crate::CapturedFrame;
+
+/// One rendered frame, as yielded by a [crate::FrameStream]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameInfo {
+    index: u64,
+    time_ms: f64,
+    delta_ms: Option<f64>,
+    captured_frame: Option<CapturedFrame>,
+}
+
+impl FrameInfo {
+    pub fn new(
+        index: u64,
+        time_ms: f64,
+        delta_ms: Option<f64>,
+        captured_frame: Option<CapturedFrame>,
+    ) -> Self {
+        Self {
+            index,
+            time_ms,
+            delta_ms,
+            captured_frame,
+        }
+    }
+
+    /// Number of frames rendered before this one since the stream was created
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// The time uniforms were updated with for this frame (see [crate::RendererData::frame_time])
+    pub fn time_ms(&self) -> f64 {
+        self.time_ms
+    }
+
+    /// Time since the previous frame, or `None` for the first frame of the stream
+    pub fn delta_ms(&self) -> Option<f64> {
+        self.delta_ms
+    }
+
+    /// The canvas's pixels, if the stream was created with [crate::Renderer::frames_with_readback]
+    pub fn captured_frame(&self) -> Option<&CapturedFrame> {
+        self.captured_frame.as_ref()
+    }
+
+    pub fn into_captured_frame(self) -> Option<CapturedFrame> {
+        self.captured_frame
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{CapturedFrame, FrameInfo};
+use log::warn;
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    fmt::Debug,
+    future::Future,
+    rc::{Rc, Weak},
+    task::{Context, Poll, Waker},
+};
+use web_sys::WebGl2RenderingContext;
+
+/// Frames waiting to be consumed, shared between a [FrameStream] and its [FrameStreamSender]
+#[derive(Debug, Default)]
+struct FrameStreamState {
+    frames: VecDeque<FrameInfo>,
+    capacity: usize,
+    next_index: u64,
+    last_time_ms: Option<f64>,
+    dropped_frames: u64,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+impl FrameStreamState {
+    fn push(&mut self, time_ms: f64, captured_frame: Option<CapturedFrame>) {
+        let delta_ms = self.last_time_ms.map(|last_time_ms| time_ms - last_time_ms);
+        let frame = FrameInfo::new(self.next_index, time_ms, delta_ms, captured_frame);
+        self.next_index += 1;
+        self.last_time_ms = Some(time_ms);
+
+        if self.frames.len() >= self.capacity {
+            self.frames.pop_front();
+            self.dropped_frames += 1;
+        }
+        self.frames.push_back(frame);
+        self.wake();
+    }
+
+    fn close(&mut self) {
+        self.closed = true;
+        self.wake();
+    }
+
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// An asynchronous sequence of rendered frames, created with [crate::Renderer::frames] or
+/// [crate::Renderer::frames_with_readback].
+///
+/// Frames are buffered until they are consumed. If the consumer falls more than
+/// [FrameStream::capacity] frames behind, the oldest frames are dropped (see [FrameStream::dropped_frames]).
+/// The stream ends when the renderer is dropped, and stops receiving frames when it is dropped itself.
+///
+/// With the `stream` feature enabled, this implements `futures_core::Stream`, so it can be used
+/// with the combinators from `futures` and similar crates.
+pub struct FrameStream {
+    state: Rc<RefCell<FrameStreamState>>,
+    unsubscribe: Option<Box<dyn FnOnce()>>,
+}
+
+impl FrameStream {
+    /// Number of frames buffered by default
+    pub const DEFAULT_CAPACITY: usize = 8;
+
+    /// Creates a stream along with the sender that feeds it. `unsubscribe` is called when the stream is dropped
+    pub(crate) fn new(
+        capacity: usize,
+        readback: bool,
+        unsubscribe: impl FnOnce() + 'static,
+    ) -> (Self, FrameStreamSender) {
+        let state = Rc::new(RefCell::new(FrameStreamState {
+            capacity: capacity.max(1),
+            ..Default::default()
+        }));
+        let sender = FrameStreamSender {
+            state: Rc::downgrade(&state),
+            readback,
+        };
+        let stream = Self {
+            state,
+            unsubscribe: Some(Box::new(unsubscribe)),
+        };
+        (stream, sender)
+    }
+
+    /// Waits for the next frame, or returns `None` once the renderer has been dropped
+    pub fn next_frame(&mut self) -> impl Future<Output = Option<FrameInfo>> + '_ {
+        std::future::poll_fn(|cx| self.poll_next_frame(cx))
+    }
+
+    /// Takes the oldest buffered frame without waiting
+    pub fn try_next_frame(&mut self) -> Option<FrameInfo> {
+        self.state.borrow_mut().frames.pop_front()
+    }
+
+    pub fn poll_next_frame(&mut self, cx: &mut Context<'_>) -> Poll<Option<FrameInfo>> {
+        let mut state = self.state.borrow_mut();
+        if let Some(frame) = state.frames.pop_front() {
+            return Poll::Ready(Some(frame));
+        }
+        if state.closed {
+            return Poll::Ready(None);
+        }
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+
+    /// Maximum number of frames buffered before the oldest are dropped
+    pub fn capacity(&self) -> usize {
+        self.state.borrow().capacity
+    }
+
+    /// Number of frames dropped because the consumer fell behind
+    pub fn dropped_frames(&self) -> u64 {
+        self.state.borrow().dropped_frames
+    }
+
+    /// Whether the renderer has been dropped, so that no more frames will arrive
+    pub fn is_closed(&self) -> bool {
+        self.state.borrow().closed
+    }
+}
+
+impl Drop for FrameStream {
+    fn drop(&mut self) {
+        if let Some(unsubscribe) = self.unsubscribe.take() {
+            unsubscribe();
+        }
+    }
+}
+
+impl Debug for FrameStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FrameStream")
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+#[cfg(feature = "stream")]
+impl futures_core::Stream for FrameStream {
+    type Item = FrameInfo;
+
+    fn poll_next(self: std::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().poll_next_frame(cx)
+    }
+}
+
+/// Feeds a [FrameStream] from an after-render hook, and ends the stream when the hook is dropped
+#[derive(Debug)]
+pub(crate) struct FrameStreamSender {
+    state: Weak<RefCell<FrameStreamState>>,
+    readback: bool,
+}
+
+impl FrameStreamSender {
+    pub(crate) fn send(&self, gl: &WebGl2RenderingContext, time_ms: f64) {
+        let Some(state) = self.state.upgrade() else {
+            return;
+        };
+        let captured_frame = self
+            .readback
+            .then(|| CapturedFrame::read_drawing_buffer(gl, time_ms))
+            .and_then(|captured_frame| {
+                captured_frame
+                    .map_err(|err| warn!("Could not read back a streamed frame: {err:?}"))
+                    .ok()
+            });
+        state.borrow_mut().push(time_ms, captured_frame);
+    }
+}
+
+impl Drop for FrameStreamSender {
+    fn drop(&mut self) {
+        if let Some(state) = self.state.upgrade() {
+            if let Ok(mut state) = state.try_borrow_mut() {
+                state.close();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FrameStream;
+    use std::{
+        cell::Cell,
+        rc::Rc,
+        task::{Context, Poll, Waker},
+    };
+
+    #[test]
+    fn buffers_frames_until_the_sender_is_dropped() {
+        let unsubscribed = Rc::new(Cell::new(false));
+        let (mut stream, sender) = FrameStream::new(2, false, {
+            let unsubscribed = Rc::clone(&unsubscribed);
+            move || unsubscribed.set(true)
+        });
+        let mut cx = Context::from_waker(Waker::noop());
+        assert_eq!(stream.poll_next_frame(&mut cx), Poll::Pending);
+
+        let state = sender.state.upgrade().unwrap();
+        for time_ms in [0.0, 16.0, 32.0] {
+            state.borrow_mut().push(time_ms, None);
+        }
+        drop(sender);
+        assert_eq!(stream.dropped_frames(), 1);
+
+        let frame = stream.try_next_frame().unwrap();
+        assert_eq!((frame.index(), frame.delta_ms()), (1, Some(16.0)));
+        let frame = match stream.poll_next_frame(&mut cx) {
+            Poll::Ready(Some(frame)) => frame,
+            other => panic!("expected a frame, got {other:?}"),
+        };
+        assert_eq!(frame.time_ms(), 32.0);
+        assert_eq!(stream.poll_next_frame(&mut cx), Poll::Ready(None));
+
+        drop(stream);
+        assert!(unsubscribed.get());
+    }
+}
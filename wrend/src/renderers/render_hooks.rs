@@ -0,0 +1,80 @@
+/// An ordered list of callbacks, each registered under a key so that it can be replaced or removed
+/// later without affecting the others.
+///
+/// Used for [crate::Renderer::add_before_render_hook] and [crate::Renderer::add_after_render_hook].
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd)]
+pub struct RenderHooks<Hook> {
+    hooks: Vec<(String, Hook)>,
+}
+
+impl<Hook> RenderHooks<Hook> {
+    pub fn new() -> Self {
+        Self { hooks: Vec::new() }
+    }
+
+    /// Adds a hook to the end of the list. If a hook is already registered under `key`,
+    /// it is replaced and keeps its position.
+    pub fn insert(&mut self, key: impl Into<String>, hook: Hook) -> &mut Self {
+        let key = key.into();
+        match self.hooks.iter_mut().find(|(existing, _)| *existing == key) {
+            Some((_, existing_hook)) => *existing_hook = hook,
+            None => self.hooks.push((key, hook)),
+        }
+        self
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<Hook> {
+        let index = self
+            .hooks
+            .iter()
+            .position(|(existing, _)| existing == key)?;
+        Some(self.hooks.remove(index).1)
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.hooks.iter().any(|(existing, _)| existing == key)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.hooks.iter().map(|(key, _)| key.as_str())
+    }
+
+    /// Hooks in the order they should be called
+    pub fn hooks(&self) -> impl Iterator<Item = &Hook> {
+        self.hooks.iter().map(|(_, hook)| hook)
+    }
+
+    pub fn len(&self) -> usize {
+        self.hooks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+}
+
+impl<Hook> Default for RenderHooks<Hook> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RenderHooks;
+
+    #[test]
+    fn keeps_insertion_order_and_replaces_by_key() {
+        let mut hooks = RenderHooks::new();
+        hooks
+            .insert("stats", 1)
+            .insert("input", 2)
+            .insert("video", 3);
+        hooks.insert("input", 4);
+        assert_eq!(hooks.hooks().copied().collect::<Vec<_>>(), vec![1, 4, 3]);
+
+        assert_eq!(hooks.remove("stats"), Some(1));
+        assert_eq!(hooks.remove("stats"), None);
+        assert_eq!(hooks.keys().collect::<Vec<_>>(), vec!["input", "video"]);
+    }
+}
@@ -1,19 +1,50 @@
+#[cfg(feature = "apng")]
+use crate::ApngOptions;
+#[cfg(feature = "gif")]
+use crate::GifOptions;
 use crate::{
-    recording_handlers, AnimationCallback, AnimationData, Attribute, Buffer, Framebuffer, Id,
-    IdName, RecordingData, RenderCallback, RendererData, RendererDataBuilder, RendererJs,
-    RendererJsInner, Texture, Uniform,
+    prefers_reduced_motion, update_power_saving, update_quality_monitor, AnimationCallback,
+    AnimationData, AnimationLoop, Attribute, BitmapTexture, BlitFilter, Buffer, BufferPair,
+    BuildRendererError, CanvasColorSpace, CapturedFrame, ClearValue, CommandList, CopyTextureError,
+    DebugInfo, DebugView, DeterministicMode, DrawCall, ExternalOwnership, ExternalResourceError,
+    ExternalTextureDescriptor, FeedbackHazard, FrameCaptureOptions, FrameChecksum,
+    FrameExportError, FrameStream, FrameTracer, Framebuffer, Id, IdName, LoopCallback, Noise,
+    NonFiniteCheck, NonFiniteReport, OutputAdjustment, PowerSavingCallback, PowerSavingListeners,
+    PowerSavingOptions, PowerSavingState, ProgramValidationWarning, QualityHintCallback,
+    QualityHintListeners, QualityHintOptions, QualityHints, QualityMonitor, ReducedMotionListener,
+    ReducedMotionOptions, RenderCallback, RendererBuilderError, RendererData, RendererDataBuilder,
+    Rng, SettingValue, SettingsStore, SettingsSubscription, StateIds, StateSnapshot,
+    StateSnapshotError, Texture, TextureGuard, Uniform, UniformGroup, ViewContext, ViewLayout,
+    Viewport, OUTPUT_BRIGHTNESS_SETTING, OUTPUT_CONTRAST_SETTING, OUTPUT_GAMMA_SETTING,
 };
+#[cfg(feature = "recording")]
+use crate::{recording_handlers, RecordingData, RecordingStateCallback};
+#[cfg(feature = "compositing")]
+use crate::{BlurPyramid, ColorVisionFilter, OverlayLayer};
+#[cfg(feature = "js")]
+use crate::{RendererDataJs, RendererJs, RendererJsInner};
 
-use log::{error, info};
+#[cfg(feature = "recording")]
+use js_sys::Promise;
+use log::error;
+#[cfg(feature = "recording")]
+use log::info;
 
 use std::cell::RefCell;
 use std::ops::Deref;
 use std::rc::Rc;
+use uuid::Uuid;
 use wasm_bindgen::prelude::Closure;
-use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen::JsCast;
+#[cfg(any(feature = "js", feature = "recording"))]
+use wasm_bindgen::JsValue;
+#[cfg(any(feature = "recording", feature = "gif", feature = "apng"))]
+use web_sys::Blob;
+#[cfg(feature = "recording")]
+use web_sys::MediaStreamTrack;
 use web_sys::{
-    window, HtmlCanvasElement, WebGl2RenderingContext, WebGlProgram, WebGlShader,
-    WebGlTransformFeedback, WebGlVertexArrayObject,
+    window, HtmlCanvasElement, WebGl2RenderingContext, WebGlBuffer, WebGlFramebuffer, WebGlProgram,
+    WebGlShader, WebGlTexture, WebGlTransformFeedback, WebGlVertexArrayObject,
 };
 
 /// The `Renderer` struct takes ownership of the `RendererData`, enabling it to
@@ -67,7 +98,28 @@ pub struct Renderer<
             >,
         >,
     >,
+    #[cfg(feature = "recording")]
     recording_data: Option<Rc<RefCell<RecordingData>>>,
+    quality_monitor: Rc<RefCell<QualityMonitor>>,
+    quality_hint_listeners: Option<QualityHintListeners>,
+    /// Listeners keeping the output adjustment in sync with a [SettingsStore], if it's persisted
+    output_adjustment_settings: Option<SettingsSubscription>,
+    /// The configuration this renderer was last built from, if it was built with a [RendererDataBuilder]
+    build_config: Option<
+        RendererDataBuilder<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+    >,
 }
 
 impl<
@@ -136,17 +188,95 @@ impl<
         >,
     ) -> Self {
         Self {
+            #[cfg(feature = "recording")]
             recording_data: None,
             renderer_data,
             animation_data: Rc::new(RefCell::new(AnimationData::new())),
+            quality_monitor: Rc::default(),
+            quality_hint_listeners: None,
+            output_adjustment_settings: None,
+            build_config: None,
         }
     }
 
+    pub(crate) fn set_build_config(
+        &mut self,
+        build_config: RendererDataBuilder<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+    ) -> &mut Self {
+        self.build_config = Some(build_config);
+        self
+    }
+
+    /// Switches to a different pipeline configuration without rebuilding everything from scratch.
+    ///
+    /// `builder_mutator` receives a copy of the builder this renderer was last built from, and can add
+    /// or remove links and shader sources (to change a link, remove it and add the replacement).
+    /// WebGL objects whose links are unchanged are reused, so buffers and textures keep their contents;
+    /// objects that are no longer used are deleted. Animation, recording and tracing carry on as before.
+    ///
+    /// If the rebuild fails, the renderer is left as it was. Only renderers created with
+    /// [RendererDataBuilder::build_renderer] can be rebuilt.
+    pub fn rebuild_with(
+        &mut self,
+        builder_mutator: impl FnOnce(
+            &mut RendererDataBuilder<
+                VertexShaderId,
+                FragmentShaderId,
+                ProgramId,
+                UniformId,
+                BufferId,
+                AttributeId,
+                TextureId,
+                FramebufferId,
+                TransformFeedbackId,
+                VertexArrayObjectId,
+                UserCtx,
+            >,
+        ),
+    ) -> Result<&mut Self, RendererBuilderError> {
+        let previous_config = self
+            .build_config
+            .as_ref()
+            .ok_or(BuildRendererError::NoBuildConfig)?;
+        let mut build_config = previous_config.clone();
+        builder_mutator(&mut build_config);
+
+        let renderer_data = build_config
+            .clone()
+            .rebuild_renderer_data(previous_config, &self.renderer_data.borrow())?;
+        let previous = std::mem::replace(&mut *self.renderer_data.borrow_mut(), renderer_data);
+        previous.delete_objects_not_in(&self.renderer_data.borrow());
+        self.build_config = Some(build_config);
+
+        Ok(self)
+    }
+
     /// Must be called before starting to record.
     ///
     /// This prevents unexpected initialization of a MediaRecorder, when the
     /// user wasn't expecting to need one from the handle.
+    #[cfg(feature = "recording")]
     pub fn initialize_recorder(&mut self) {
+        self.initialize_recorder_with_audio(&[]);
+    }
+
+    /// Same as [Renderer::initialize_recorder], but mixes the given audio tracks into recordings
+    /// alongside the canvas video (e.g. the output of an audio-reactive visual's `AudioContext`,
+    /// see [crate::audio_node_track]).
+    #[cfg(feature = "recording")]
+    pub fn initialize_recorder_with_audio(&mut self, audio_tracks: &[MediaStreamTrack]) {
         if let Some(_) = &self.recording_data {
             error!("Error initializing recorder: a recorder has already been initialized. This is a no-op");
             return;
@@ -156,7 +286,7 @@ impl<
             let renderer_ref = self.renderer_data.borrow();
             renderer_ref.canvas().clone()
         };
-        let recording_data = RecordingData::new(&canvas);
+        let recording_data = RecordingData::new(&canvas, audio_tracks);
         let media_recorder = recording_data.media_recorder().clone();
         let recording_data = Rc::new(RefCell::new(recording_data));
 
@@ -218,10 +348,26 @@ impl<
                     return;
                 }
 
-                // run animation callback
-                animation_data
-                    .borrow_mut()
-                    .call_animation_callback(Rc::clone(&renderer_data));
+                // skip frames while paused or throttled to save power
+                let now = window().unwrap().performance().unwrap().now();
+                let power_saving = animation_data.borrow().power_saving();
+                let should_render = power_saving.borrow_mut().should_render(now);
+
+                if should_render {
+                    // run animation callback
+                    animation_data
+                        .borrow_mut()
+                        .call_animation_callback(Rc::clone(&renderer_data));
+
+                    // notify listeners once per completed loop
+                    let loop_time = renderer_data.borrow().loop_time();
+                    let loop_callback = animation_data
+                        .borrow_mut()
+                        .loop_callback_if_wrapped(loop_time);
+                    if let (Some(loop_callback), Some(loop_time)) = (loop_callback, loop_time) {
+                        loop_callback.call_with_js_arg(loop_time);
+                    }
+                }
 
                 // schedule another requestAnimationFrame callback
                 let animation_id = Self::request_animation_frame(f.borrow().as_ref().unwrap());
@@ -271,6 +417,428 @@ impl<
             .set_animation_callback(animation_callback.map(|cb| cb.into()));
     }
 
+    /// Called instead of the animation callback while reduced motion is active (see
+    /// [Renderer::set_reduced_motion]), e.g. to render a static or gentler version of the scene.
+    ///
+    /// If `None`, the usual animation callback keeps running, at the reduced time scale.
+    pub fn set_reduced_motion_animation_callback(
+        &mut self,
+        animation_callback: Option<
+            impl Into<
+                AnimationCallback<
+                    VertexShaderId,
+                    FragmentShaderId,
+                    ProgramId,
+                    UniformId,
+                    BufferId,
+                    AttributeId,
+                    TextureId,
+                    FramebufferId,
+                    TransformFeedbackId,
+                    VertexArrayObjectId,
+                    UserCtx,
+                >,
+            >,
+        >,
+    ) {
+        self.animation_data
+            .borrow_mut()
+            .set_reduced_motion_animation_callback(animation_callback.map(|cb| cb.into()));
+    }
+
+    /// Registers a callback to run before the render callback on every frame, after any hooks that
+    /// were added earlier. Useful for concerns like input snapshotting or video-frame uploads that
+    /// shouldn't have to be woven into the render callback itself.
+    ///
+    /// Adding a hook under an existing key replaces it in place.
+    pub fn add_before_render_hook(
+        &mut self,
+        key: impl Into<String>,
+        hook: impl Into<
+            RenderCallback<
+                VertexShaderId,
+                FragmentShaderId,
+                ProgramId,
+                UniformId,
+                BufferId,
+                AttributeId,
+                TextureId,
+                FramebufferId,
+                TransformFeedbackId,
+                VertexArrayObjectId,
+                UserCtx,
+            >,
+        >,
+    ) -> &mut Self {
+        self.deref().borrow_mut().add_before_render_hook(key, hook);
+        self
+    }
+
+    /// Registers a callback to run after the render callback on every frame (e.g. for stats collection).
+    ///
+    /// Adding a hook under an existing key replaces it in place.
+    pub fn add_after_render_hook(
+        &mut self,
+        key: impl Into<String>,
+        hook: impl Into<
+            RenderCallback<
+                VertexShaderId,
+                FragmentShaderId,
+                ProgramId,
+                UniformId,
+                BufferId,
+                AttributeId,
+                TextureId,
+                FramebufferId,
+                TransformFeedbackId,
+                VertexArrayObjectId,
+                UserCtx,
+            >,
+        >,
+    ) -> &mut Self {
+        self.deref().borrow_mut().add_after_render_hook(key, hook);
+        self
+    }
+
+    /// Streams metadata about every frame rendered from now on (see [FrameStream])
+    pub fn frames(&self) -> FrameStream {
+        self.frame_stream(false)
+    }
+
+    /// Like [Renderer::frames], but each frame also includes the canvas's pixels.
+    ///
+    /// Reading back every frame stalls the GPU pipeline, so this is best reserved for exporting or testing.
+    pub fn frames_with_readback(&self) -> FrameStream {
+        self.frame_stream(true)
+    }
+
+    fn frame_stream(&self, readback: bool) -> FrameStream {
+        let key = format!("wrend::frame_stream::{}", Uuid::new_v4());
+        let renderer_data = Rc::downgrade(&self.renderer_data);
+        let unsubscribe = {
+            let key = key.clone();
+            move || {
+                if let Some(renderer_data) = renderer_data.upgrade() {
+                    // if the stream is dropped mid-render, the hook is left in place and does nothing
+                    if let Ok(mut renderer_data) = renderer_data.try_borrow_mut() {
+                        renderer_data.remove_after_render_hook(&key);
+                    }
+                }
+            }
+        };
+
+        let (stream, sender) =
+            FrameStream::new(FrameStream::DEFAULT_CAPACITY, readback, unsubscribe);
+        self.deref().borrow_mut().add_after_render_hook(
+            key,
+            move |renderer_data: &RendererData<
+                VertexShaderId,
+                FragmentShaderId,
+                ProgramId,
+                UniformId,
+                BufferId,
+                AttributeId,
+                TextureId,
+                FramebufferId,
+                TransformFeedbackId,
+                VertexArrayObjectId,
+                UserCtx,
+            >| {
+                sender.send(renderer_data.gl(), renderer_data.frame_time());
+            },
+        );
+        stream
+    }
+
+    pub fn remove_before_render_hook(&mut self, key: &str) -> &mut Self {
+        self.deref().borrow_mut().remove_before_render_hook(key);
+        self
+    }
+
+    pub fn remove_after_render_hook(&mut self, key: &str) -> &mut Self {
+        self.deref().borrow_mut().remove_after_render_hook(key);
+        self
+    }
+
+    /// Makes the animation loop every `duration_ms` milliseconds, starting now.
+    ///
+    /// Callbacks can read the normalized time within the loop from [RendererData::loop_time]
+    /// (or [crate::UniformContext::loop_time] in uniform callbacks). To export exactly one loop,
+    /// capture with [FrameCaptureOptions::for_loop].
+    pub fn set_loop(&mut self, duration_ms: f64) -> &mut Self {
+        self.deref().borrow().start_loop(duration_ms);
+        self
+    }
+
+    /// Stops looping, so that [RendererData::loop_time] returns `None`
+    pub fn clear_loop(&mut self) -> &mut Self {
+        self.deref().borrow().set_animation_loop(None);
+        self
+    }
+
+    /// The current loop, as set by [Renderer::set_loop]
+    pub fn animation_loop(&self) -> Option<AnimationLoop> {
+        self.deref().borrow().animation_loop()
+    }
+
+    /// See [RendererData::set_deterministic_mode]
+    pub fn set_deterministic_mode(&self, deterministic_mode: Option<DeterministicMode>) -> &Self {
+        self.deref()
+            .borrow()
+            .set_deterministic_mode(deterministic_mode);
+        self
+    }
+
+    pub fn deterministic_mode(&self) -> Option<DeterministicMode> {
+        self.deref().borrow().deterministic_mode()
+    }
+
+    /// See [RendererData::rng]
+    pub fn rng(&self, stream: u64) -> Rng {
+        self.deref().borrow().rng(stream)
+    }
+
+    /// See [RendererData::noise]
+    pub fn noise(&self, stream: u64) -> Noise {
+        self.deref().borrow().noise(stream)
+    }
+
+    /// Called from the animation loop each time the [AnimationLoop] wraps around
+    pub fn set_loop_callback(
+        &mut self,
+        loop_callback: Option<impl Into<LoopCallback>>,
+    ) -> &mut Self {
+        self.animation_data
+            .borrow_mut()
+            .set_loop_callback(loop_callback.map(Into::into));
+        self
+    }
+
+    /// Pauses or throttles the animation while the page is hidden or the canvas is scrolled out of
+    /// view, as configured by `options`. Pass `None` to render every frame regardless.
+    ///
+    /// Visibility is tracked with the Page Visibility API and an `IntersectionObserver`.
+    pub fn set_power_saving(&mut self, options: Option<PowerSavingOptions>) -> &mut Self {
+        let power_saving = self.animation_data.borrow().power_saving();
+        update_power_saving(&power_saving, |power_saving| {
+            power_saving.set_options(options)
+        });
+
+        let listeners = options.and_then(|_| {
+            PowerSavingListeners::new(&self.canvas(), power_saving)
+                .map_err(|err| error!("Could not observe canvas visibility: {err:?}"))
+                .ok()
+        });
+        self.animation_data
+            .borrow_mut()
+            .set_power_saving_listeners(listeners);
+        self
+    }
+
+    /// The options set with [Renderer::set_power_saving]
+    pub fn power_saving(&self) -> Option<PowerSavingOptions> {
+        self.animation_data
+            .borrow()
+            .power_saving()
+            .borrow()
+            .options()
+    }
+
+    /// Whether the animation is currently paused or throttled to save power, and why
+    pub fn power_saving_state(&self) -> PowerSavingState {
+        self.animation_data.borrow().power_saving().borrow().state()
+    }
+
+    /// Called whenever the animation is paused, throttled or resumed by [Renderer::set_power_saving]
+    pub fn set_power_saving_callback(
+        &mut self,
+        callback: Option<impl Into<PowerSavingCallback>>,
+    ) -> &mut Self {
+        self.animation_data
+            .borrow()
+            .power_saving()
+            .borrow_mut()
+            .set_callback(callback.map(Into::into));
+        self
+    }
+
+    /// See [RendererData::set_output_adjustment]
+    ///
+    /// This stops following the settings from [Renderer::persist_output_adjustment], if any.
+    pub fn set_output_adjustment(
+        &mut self,
+        output_adjustment: Option<OutputAdjustment<ProgramId>>,
+    ) -> &mut Self {
+        self.output_adjustment_settings = None;
+        self.deref()
+            .borrow()
+            .set_output_adjustment(output_adjustment);
+        self
+    }
+
+    pub fn output_adjustment(&self) -> Option<OutputAdjustment<ProgramId>> {
+        self.deref().borrow().output_adjustment()
+    }
+
+    /// Applies `adjustment` with the brightness, contrast and gamma stored in `store`, and keeps it
+    /// in sync as those settings change (e.g. from a `TweakPanel` or a settings menu), so a
+    /// viewer's choice is remembered across page loads.
+    ///
+    /// The settings are registered with the adjustment's values as defaults. Call
+    /// [SettingsStore::restore] afterward to load the previously saved values.
+    ///
+    /// The renderer stops listening to `store` when it's dropped, when this is called again, or
+    /// when [Renderer::set_output_adjustment] is called.
+    pub fn persist_output_adjustment(
+        &mut self,
+        mut adjustment: OutputAdjustment<ProgramId>,
+        store: &SettingsStore,
+    ) -> &mut Self {
+        adjustment.register_settings(store);
+        adjustment.apply_settings(store);
+        self.set_output_adjustment(Some(adjustment.clone()));
+
+        let renderer_data = Rc::downgrade(&self.renderer_data);
+        let adjustment = RefCell::new(adjustment);
+        // the listeners may be called while the renderer is being dropped, so they only hold a
+        // weak reference to it
+        let on_change = Rc::new(move |name: &str, value: &SettingValue| {
+            let (Some(renderer_data), Some(value)) = (renderer_data.upgrade(), value.as_f32())
+            else {
+                return;
+            };
+            let mut adjustment = adjustment.borrow_mut();
+            match name {
+                OUTPUT_BRIGHTNESS_SETTING => adjustment.set_brightness(value),
+                OUTPUT_CONTRAST_SETTING => adjustment.set_contrast(value),
+                _ => adjustment.set_gamma(value),
+            };
+            renderer_data
+                .borrow()
+                .set_output_adjustment(Some(adjustment.clone()));
+        });
+        let mut subscription = SettingsSubscription::new(store.clone());
+        for name in [
+            OUTPUT_BRIGHTNESS_SETTING,
+            OUTPUT_CONTRAST_SETTING,
+            OUTPUT_GAMMA_SETTING,
+        ] {
+            let on_change = Rc::clone(&on_change);
+            subscription.add_listener(name, move |name: &str, value: &SettingValue| {
+                on_change(name, value)
+            });
+        }
+        self.output_adjustment_settings = Some(subscription);
+        self
+    }
+
+    /// Follows the user's `prefers-reduced-motion` setting, as configured by `options`: while
+    /// reduced motion is preferred, time runs at [ReducedMotionOptions::time_scale] and the
+    /// callback from [Renderer::set_reduced_motion_animation_callback] replaces the animation
+    /// callback. Pass `None` to animate normally regardless.
+    ///
+    /// Changes to the setting take effect immediately, without restarting the animation.
+    pub fn set_reduced_motion(&mut self, options: Option<ReducedMotionOptions>) -> &mut Self {
+        let reduced_motion = self.animation_data.borrow().reduced_motion();
+        {
+            let mut reduced_motion = reduced_motion.borrow_mut();
+            reduced_motion.set_options(options);
+            reduced_motion.set_prefers_reduced_motion(prefers_reduced_motion());
+            self.deref()
+                .borrow()
+                .set_time_scale(reduced_motion.time_scale());
+        }
+
+        let listener = options.and_then(|_| {
+            let renderer_data = Rc::clone(&self.renderer_data);
+            ReducedMotionListener::new(move |prefers_reduced_motion| {
+                let mut reduced_motion = reduced_motion.borrow_mut();
+                reduced_motion.set_prefers_reduced_motion(prefers_reduced_motion);
+                renderer_data
+                    .borrow()
+                    .set_time_scale(reduced_motion.time_scale());
+            })
+            .map_err(|err| error!("Could not watch the reduced motion preference: {err:?}"))
+            .ok()
+        });
+        self.animation_data
+            .borrow_mut()
+            .set_reduced_motion_listener(listener);
+        self
+    }
+
+    /// The options set with [Renderer::set_reduced_motion]
+    pub fn reduced_motion(&self) -> Option<ReducedMotionOptions> {
+        self.animation_data
+            .borrow()
+            .reduced_motion()
+            .borrow()
+            .options()
+    }
+
+    /// Whether reduced motion has been enabled with [Renderer::set_reduced_motion] and the user
+    /// currently prefers it
+    pub fn is_reduced_motion_active(&self) -> bool {
+        self.animation_data
+            .borrow()
+            .reduced_motion()
+            .borrow()
+            .is_active()
+    }
+
+    /// See [RendererData::set_time_scale]
+    ///
+    /// Reduced motion overrides this whenever the user's preference changes.
+    pub fn set_time_scale(&self, time_scale: f64) -> &Self {
+        self.deref().borrow().set_time_scale(time_scale);
+        self
+    }
+
+    pub fn time_scale(&self) -> f64 {
+        self.deref().borrow().time_scale()
+    }
+
+    /// Watches the battery and device pixel ratio and suggests rendering at reduced quality when
+    /// `options` calls for it (see [QualityHints]). Pass `None` to stop.
+    ///
+    /// The suggested quality isn't applied automatically: read it from [Renderer::quality_hints]
+    /// or [Renderer::set_quality_hint_callback] and resize render targets accordingly.
+    pub fn set_quality_hints(&mut self, options: Option<QualityHintOptions>) -> &mut Self {
+        update_quality_monitor(&self.quality_monitor, |quality_monitor| {
+            quality_monitor.set_options(options)
+        });
+
+        self.quality_hint_listeners = None;
+        self.quality_hint_listeners = options.and_then(|_| {
+            QualityHintListeners::new(Rc::clone(&self.quality_monitor))
+                .map_err(|err| error!("Could not watch the device's power state: {err:?}"))
+                .ok()
+        });
+        self
+    }
+
+    /// The options set with [Renderer::set_quality_hints]
+    pub fn quality_hint_options(&self) -> Option<QualityHintOptions> {
+        self.quality_monitor.borrow().options()
+    }
+
+    /// The latest suggested quality, or full quality if [Renderer::set_quality_hints] hasn't been called
+    pub fn quality_hints(&self) -> QualityHints {
+        self.quality_monitor.borrow().hints()
+    }
+
+    /// Called whenever the [QualityHints] change, e.g. to show a "reduced quality" indicator
+    pub fn set_quality_hint_callback(
+        &mut self,
+        callback: Option<impl Into<QualityHintCallback>>,
+    ) -> &mut Self {
+        self.quality_monitor
+            .borrow_mut()
+            .set_callback(callback.map(Into::into));
+        self
+    }
+
+    #[cfg(feature = "recording")]
     pub fn start_recording(&mut self) {
         const ERROR_START: &str = "Error trying to start video recording";
 
@@ -291,6 +859,7 @@ impl<
         }
     }
 
+    #[cfg(feature = "recording")]
     pub fn stop_recording(&self) {
         const ERROR_START: &str = "Error trying to stop video recording";
 
@@ -300,14 +869,18 @@ impl<
         }
 
         if let Some(recording_data) = &self.recording_data {
-            if let Err(err) = recording_data.borrow_mut().media_recorder().stop() {
+            let mut recording_data = recording_data.borrow_mut();
+            if let Err(err) = recording_data.media_recorder().stop() {
                 error!("{ERROR_START}: {err:?}");
+            } else {
+                recording_data.set_awaiting_final_data(true);
             }
         } else {
             error!("{ERROR_START}: recorder was not properly initialized");
         }
     }
 
+    #[cfg(feature = "recording")]
     pub fn clear_recorded_data(&self) {
         const ERROR_START: &str = "Error trying to clear video recording data";
 
@@ -318,6 +891,72 @@ impl<
         }
     }
 
+    /// Registers a callback that is called whenever the recorder starts or stops recording.
+    ///
+    /// Initializes the recorder if it has not been initialized yet.
+    #[cfg(feature = "recording")]
+    pub fn add_recording_state_callback(&mut self, callback: impl Into<RecordingStateCallback>) {
+        if !self.recorder_initialized() {
+            self.initialize_recorder();
+        }
+
+        if let Some(recording_data) = &self.recording_data {
+            recording_data
+                .borrow_mut()
+                .add_state_callback(callback.into());
+        }
+    }
+
+    /// Controls whether the video is downloaded automatically once recording stops (`true` by default).
+    ///
+    /// Disable this to handle the finished recording yourself, e.g. via [`Renderer::recording_blob_promise`].
+    #[cfg(feature = "recording")]
+    pub fn set_auto_download_recording(&mut self, auto_download: bool) {
+        if !self.recorder_initialized() {
+            self.initialize_recorder();
+        }
+
+        if let Some(recording_data) = &self.recording_data {
+            recording_data.borrow_mut().set_auto_download(auto_download);
+        }
+    }
+
+    /// Returns a Blob of everything that has been recorded so far
+    #[cfg(feature = "recording")]
+    pub fn recording_blob(&self) -> Option<Blob> {
+        self.recording_data
+            .as_ref()
+            .map(|recording_data| recording_data.borrow().recording_blob())
+    }
+
+    /// Returns a Promise that resolves with the recorded video as a Blob.
+    ///
+    /// If a recording is in progress (or has just been stopped), the Promise resolves once
+    /// the final recorded data is available. Otherwise it resolves immediately with whatever
+    /// has been recorded so far. Rejects if the recorder has not been initialized.
+    #[cfg(feature = "recording")]
+    pub fn recording_blob_promise(&self) -> Promise {
+        let recording_data = match &self.recording_data {
+            Some(recording_data) => Rc::clone(recording_data),
+            None => {
+                return Promise::reject(&JsValue::from_str(
+                    "Error getting recording Blob: recorder was not properly initialized",
+                ))
+            }
+        };
+
+        Promise::new(&mut |resolve, _reject| {
+            let mut recording_data = recording_data.borrow_mut();
+            if recording_data.is_recording() || recording_data.awaiting_final_data() {
+                recording_data.add_pending_blob_resolver(resolve);
+            } else if let Err(err) = resolve.call1(&JsValue::NULL, &recording_data.recording_blob())
+            {
+                error!("Error resolving recording Blob: {err:?}");
+            }
+        })
+    }
+
+    #[cfg(feature = "recording")]
     pub fn recorder_initialized(&self) -> bool {
         self.recording_data.is_some()
     }
@@ -326,6 +965,7 @@ impl<
         self.animation_data.borrow().is_animating()
     }
 
+    #[cfg(feature = "recording")]
     pub fn is_recording(&self) -> bool {
         self.recording_data
             .as_ref()
@@ -334,6 +974,7 @@ impl<
             })
     }
 
+    #[cfg(feature = "js")]
     pub(crate) fn renderer_data(
         &self,
     ) -> Rc<
@@ -395,12 +1036,15 @@ impl<
         // this would get dropped even if we didn't do it manually,
         // but dropping the listeners here before the rest of the data gets dropped
         // prevents them from accidentally firing when other clean up happens
-        if let Some(recording_data) = &self.recording_data {
-            recording_data.borrow_mut().remove_all_event_listeners();
-        }
+        #[cfg(feature = "recording")]
+        {
+            if let Some(recording_data) = &self.recording_data {
+                recording_data.borrow_mut().remove_all_event_listeners();
+            }
 
-        if self.is_recording() {
-            self.stop_recording();
+            if self.is_recording() {
+                self.stop_recording();
+            }
         }
 
         if self.is_animating() {
@@ -558,10 +1202,122 @@ impl<
             .map(Clone::clone)
     }
 
+    pub fn attribute_for_vao(
+        &self,
+        attribute_id: &AttributeId,
+        vao_id: Option<&VertexArrayObjectId>,
+    ) -> Option<Attribute<VertexArrayObjectId, BufferId, AttributeId>> {
+        self.deref()
+            .borrow()
+            .attribute_for_vao(attribute_id, vao_id)
+            .map(Clone::clone)
+    }
+
     pub fn texture(&self, texture_id: &TextureId) -> Option<Texture<TextureId>> {
         self.deref().borrow().texture(texture_id).map(Clone::clone)
     }
 
+    /// See [crate::RendererData::drawing_buffer_color_space]
+    pub fn drawing_buffer_color_space(&self) -> CanvasColorSpace {
+        self.deref().borrow().drawing_buffer_color_space()
+    }
+
+    /// Texture unit assigned to a texture when the renderer was built (see [crate::TextureUnits])
+    pub fn texture_unit(&self, texture_id: &TextureId) -> Option<u32> {
+        self.deref().borrow().texture_unit(texture_id)
+    }
+
+    /// See [crate::RendererData::render_blur_pyramid]
+    #[cfg(feature = "compositing")]
+    pub fn render_blur_pyramid(
+        &self,
+        pyramid: &BlurPyramid<ProgramId, TextureId>,
+        source_texture_id: &TextureId,
+    ) -> &Self {
+        self.deref()
+            .borrow()
+            .render_blur_pyramid(pyramid, source_texture_id);
+        self
+    }
+
+    /// See [crate::RendererData::frame_checksum]
+    pub fn frame_checksum(
+        &self,
+        framebuffer_id: Option<&FramebufferId>,
+    ) -> Result<FrameChecksum, CopyTextureError> {
+        self.deref().borrow().frame_checksum(framebuffer_id)
+    }
+
+    /// See [crate::RendererData::check_non_finite]
+    pub fn check_non_finite(
+        &self,
+        check: &NonFiniteCheck<ProgramId>,
+        texture_id: &TextureId,
+    ) -> Result<Option<NonFiniteReport>, CopyTextureError> {
+        self.deref().borrow().check_non_finite(check, texture_id)
+    }
+
+    /// See [crate::RendererData::frame_checksum_region]
+    pub fn frame_checksum_region(
+        &self,
+        framebuffer_id: Option<&FramebufferId>,
+        rect: Viewport,
+    ) -> Result<FrameChecksum, CopyTextureError> {
+        self.deref()
+            .borrow()
+            .frame_checksum_region(framebuffer_id, rect)
+    }
+
+    /// See [crate::RendererData::invalidate_framebuffer]
+    pub fn invalidate_framebuffer(
+        &self,
+        framebuffer_id: Option<&FramebufferId>,
+        attachments: &[u32],
+    ) -> bool {
+        self.deref()
+            .borrow()
+            .invalidate_framebuffer(framebuffer_id, attachments)
+    }
+
+    /// See [crate::RendererData::upload_bitmap_texture]
+    pub fn upload_bitmap_texture(&self, bitmap_texture: &BitmapTexture<TextureId>) -> bool {
+        self.deref().borrow().upload_bitmap_texture(bitmap_texture)
+    }
+
+    /// See [crate::RendererData::set_debug_view]
+    pub fn set_debug_view(&self, debug_view: DebugView) -> &Self {
+        self.deref().borrow().set_debug_view(debug_view);
+        self
+    }
+
+    /// See [crate::RendererData::debug_view]
+    pub fn debug_view(&self) -> DebugView {
+        self.deref().borrow().debug_view()
+    }
+
+    /// See [crate::RendererData::draw_color_vision_filter]
+    #[cfg(feature = "compositing")]
+    pub fn draw_color_vision_filter(
+        &self,
+        filter: &ColorVisionFilter<ProgramId>,
+        source_texture_id: &TextureId,
+    ) -> &Self {
+        self.deref()
+            .borrow()
+            .draw_color_vision_filter(filter, source_texture_id);
+        self
+    }
+
+    /// See [crate::RendererData::program_validation_warnings]
+    pub fn program_validation_warnings(&self) -> Vec<ProgramValidationWarning<ProgramId>> {
+        self.deref().borrow().program_validation_warnings().to_vec()
+    }
+
+    /// See [crate::RendererData::debug_info]
+    pub fn debug_info(&self) -> DebugInfo {
+        self.deref().borrow().debug_info()
+    }
+
     pub fn framebuffer(
         &self,
         framebuffer_id: &FramebufferId,
@@ -572,6 +1328,77 @@ impl<
             .map(Clone::clone)
     }
 
+    /// See [crate::RendererData::lend_texture]
+    pub fn lend_texture(&self, texture_id: &TextureId) -> Option<TextureGuard> {
+        self.deref().borrow().lend_texture(texture_id)
+    }
+
+    /// See [crate::RendererData::register_external_texture]
+    pub fn register_external_texture(
+        &self,
+        texture_id: TextureId,
+        webgl_texture: WebGlTexture,
+        descriptor: ExternalTextureDescriptor,
+        ownership: ExternalOwnership,
+    ) -> Result<&Self, ExternalResourceError> {
+        self.deref().borrow_mut().register_external_texture(
+            texture_id,
+            webgl_texture,
+            descriptor,
+            ownership,
+        )?;
+        Ok(self)
+    }
+
+    /// See [crate::RendererData::unregister_external_texture]
+    pub fn unregister_external_texture(&self, texture_id: &TextureId) -> bool {
+        self.deref()
+            .borrow_mut()
+            .unregister_external_texture(texture_id)
+    }
+
+    /// See [crate::RendererData::register_external_buffer]
+    pub fn register_external_buffer(
+        &self,
+        buffer_id: BufferId,
+        webgl_buffer: WebGlBuffer,
+        ownership: ExternalOwnership,
+    ) -> Result<&Self, ExternalResourceError> {
+        self.deref()
+            .borrow_mut()
+            .register_external_buffer(buffer_id, webgl_buffer, ownership)?;
+        Ok(self)
+    }
+
+    /// See [crate::RendererData::unregister_external_buffer]
+    pub fn unregister_external_buffer(&self, buffer_id: &BufferId) -> bool {
+        self.deref()
+            .borrow_mut()
+            .unregister_external_buffer(buffer_id)
+    }
+
+    /// See [crate::RendererData::register_external_framebuffer]
+    pub fn register_external_framebuffer(
+        &self,
+        framebuffer_id: FramebufferId,
+        webgl_framebuffer: WebGlFramebuffer,
+        ownership: ExternalOwnership,
+    ) -> Result<&Self, ExternalResourceError> {
+        self.deref().borrow_mut().register_external_framebuffer(
+            framebuffer_id,
+            webgl_framebuffer,
+            ownership,
+        )?;
+        Ok(self)
+    }
+
+    /// See [crate::RendererData::unregister_external_framebuffer]
+    pub fn unregister_external_framebuffer(&self, framebuffer_id: &FramebufferId) -> bool {
+        self.deref()
+            .borrow_mut()
+            .unregister_external_framebuffer(framebuffer_id)
+    }
+
     pub fn transform_feedback(
         &self,
         transform_feedback_id: &TransformFeedbackId,
@@ -586,10 +1413,57 @@ impl<
         self.deref().borrow().vao(vao_id).map(Clone::clone)
     }
 
+    pub fn buffer_pair(
+        &self,
+        pair_id: &BufferId,
+    ) -> Option<BufferPair<BufferId, VertexArrayObjectId>> {
+        self.deref().borrow().buffer_pair(pair_id).map(Clone::clone)
+    }
+
+    /// See [RendererData::export_state]
+    pub fn export_state(
+        &self,
+        state_ids: &StateIds<TextureId, BufferId>,
+    ) -> Result<StateSnapshot, StateSnapshotError>
+    where
+        TextureId: IdName,
+        BufferId: IdName,
+    {
+        self.deref().borrow().export_state(state_ids)
+    }
+
+    /// See [RendererData::import_state]
+    pub fn import_state(&self, snapshot: &StateSnapshot) -> Result<(), StateSnapshotError>
+    where
+        TextureId: IdName,
+        BufferId: IdName,
+    {
+        self.deref().borrow().import_state(snapshot)
+    }
+
+    /// See [RendererData::read_transform_feedback_buffer]
+    pub fn read_transform_feedback_buffer(&self, buffer_id: &BufferId, count: u32) -> Vec<f32> {
+        self.deref()
+            .borrow()
+            .read_transform_feedback_buffer(buffer_id, count)
+    }
+
+    /// See [RendererData::swap_buffer_pair]
+    pub fn swap_buffer_pair(&self, pair_id: &BufferId) -> &Self {
+        self.deref().borrow().swap_buffer_pair(pair_id);
+        self
+    }
+
+    /// Returns a clone of the user context. Use [Renderer::with_user_ctx] to read it without cloning.
     pub fn user_ctx(&self) -> Option<UserCtx> {
         self.deref().borrow().user_ctx().map(Clone::clone)
     }
 
+    /// Borrows the user context for the duration of `f`, without cloning it
+    pub fn with_user_ctx<R>(&self, f: impl FnOnce(Option<&UserCtx>) -> R) -> R {
+        f(self.deref().borrow().user_ctx())
+    }
+
     pub fn use_program(&self, program_id: &ProgramId) -> &Self {
         self.deref().borrow().use_program(program_id);
         self
@@ -599,6 +1473,38 @@ impl<
         self.deref().borrow().use_vao(vao_id);
         self
     }
+    pub fn draw(
+        &self,
+        draw_call: &DrawCall<ProgramId, UniformId, TextureId, FramebufferId, VertexArrayObjectId>,
+    ) -> &Self {
+        self.deref().borrow().draw(draw_call);
+        self
+    }
+
+    pub fn replay(
+        &self,
+        command_list: &CommandList<
+            ProgramId,
+            UniformId,
+            TextureId,
+            FramebufferId,
+            VertexArrayObjectId,
+        >,
+    ) -> &Self {
+        self.deref().borrow().replay(command_list);
+        self
+    }
+
+    pub fn use_framebuffer(&self, framebuffer_id: Option<&FramebufferId>) -> &Self {
+        self.deref().borrow().use_framebuffer(framebuffer_id);
+        self
+    }
+
+    pub fn bind_textures(&self, textures: &[(TextureId, u32)]) -> &Self {
+        self.deref().borrow().bind_textures(textures);
+        self
+    }
+
     pub fn update_uniform(&self, uniform_id: &UniformId) -> &Self {
         self.deref().borrow().update_uniform(uniform_id);
         self
@@ -609,11 +1515,196 @@ impl<
         self
     }
 
+    pub fn invalidate_uniform(&self, uniform_id: &UniformId) -> &Self {
+        self.deref().borrow().invalidate_uniform(uniform_id);
+        self
+    }
+
+    /// See [crate::RendererData::set_uniform_group]
+    pub fn set_uniform_group(&self, group: &impl UniformGroup) -> usize {
+        self.deref().borrow().set_uniform_group(group)
+    }
+
+    /// See [crate::RendererData::invalidate_uniform_groups]
+    pub fn invalidate_uniform_groups(&self) -> &Self {
+        self.deref().borrow().invalidate_uniform_groups();
+        self
+    }
+
+    /// A handle to the [RendererData::frame_tracer] (clones share the same recording)
+    pub fn frame_tracer(&self) -> FrameTracer {
+        self.deref().borrow().frame_tracer().clone()
+    }
+
+    /// Calls the render callback.
+    ///
+    /// If the render callback is a JavaScript function and this renderer's types are compatible
+    /// with JavaScript, the callback receives a `RendererData` handle as its argument, so that
+    /// pure-JavaScript pipelines do not need to retain a global reference to the renderer.
     pub fn render(&self) -> &Self {
-        self.deref().borrow().render();
+        let renderer_data = self.deref();
+        #[cfg(feature = "js")]
+        if let Some(renderer_data_js) = RendererDataJs::from_renderer_data(renderer_data) {
+            if renderer_data_js.borrow().render_callback().js().is_some() {
+                renderer_data_js.render();
+                return self;
+            }
+        }
+
+        renderer_data.borrow().render();
+        self
+    }
+
+    /// Updates uniforms and renders as if the current time were `time_ms`, bypassing `performance.now()`.
+    ///
+    /// Useful for scrubbing through a timeline or rendering specific instants for an external tool.
+    /// JavaScript render callbacks receive a `RendererData` handle, just like with [Renderer::render].
+    pub fn render_at(&self, time_ms: f64) -> &Self {
+        let previous_time_override = {
+            let renderer_data = self.deref().borrow();
+            let previous_time_override = renderer_data.time_override();
+            renderer_data.set_time_override(Some(time_ms));
+            renderer_data.update_uniforms();
+            previous_time_override
+        };
+        self.render();
+        self.deref()
+            .borrow()
+            .set_time_override(previous_time_override);
         self
     }
 
+    /// See [RendererData::capture_frames]
+    pub fn capture_frames(
+        &self,
+        options: &FrameCaptureOptions,
+    ) -> Result<Vec<CapturedFrame>, FrameExportError> {
+        self.deref().borrow().capture_frames(options)
+    }
+
+    /// Renders `capture_options.frame_count()` frames at a fixed timestep and encodes them as an animated GIF.
+    ///
+    /// The returned `Blob` can be downloaded or uploaded as-is.
+    #[cfg(feature = "gif")]
+    pub fn export_gif(
+        &self,
+        capture_options: &FrameCaptureOptions,
+        gif_options: &GifOptions,
+    ) -> Result<Blob, FrameExportError> {
+        self.deref()
+            .borrow()
+            .export_gif(capture_options, gif_options)
+    }
+
+    /// Renders `capture_options.frame_count()` frames at a fixed timestep and encodes them as a lossless animated PNG.
+    #[cfg(feature = "apng")]
+    pub fn export_apng(
+        &self,
+        capture_options: &FrameCaptureOptions,
+        apng_options: &ApngOptions,
+    ) -> Result<Blob, FrameExportError> {
+        self.deref()
+            .borrow()
+            .export_apng(capture_options, apng_options)
+    }
+
+    pub fn render_views(
+        &self,
+        layout: &ViewLayout,
+        callback: impl Fn(
+            &RendererData<
+                VertexShaderId,
+                FragmentShaderId,
+                ProgramId,
+                UniformId,
+                BufferId,
+                AttributeId,
+                TextureId,
+                FramebufferId,
+                TransformFeedbackId,
+                VertexArrayObjectId,
+                UserCtx,
+            >,
+            &ViewContext,
+        ),
+    ) -> &Self {
+        self.deref().borrow().render_views(layout, callback);
+        self
+    }
+
+    pub fn copy_texture(
+        &self,
+        src_texture_id: &TextureId,
+        dst_texture_id: &TextureId,
+        width: i32,
+        height: i32,
+    ) -> Result<(), CopyTextureError> {
+        self.deref()
+            .borrow()
+            .copy_texture(src_texture_id, dst_texture_id, width, height)
+    }
+
+    /// See [crate::RendererData::capture_canvas_to_texture]
+    pub fn capture_canvas_to_texture(
+        &self,
+        texture_id: &TextureId,
+    ) -> Result<(), CopyTextureError> {
+        self.deref().borrow().capture_canvas_to_texture(texture_id)
+    }
+
+    pub fn clear_texture(
+        &self,
+        texture_id: &TextureId,
+        value: impl Into<ClearValue>,
+    ) -> Result<(), CopyTextureError> {
+        self.deref().borrow().clear_texture(texture_id, value)
+    }
+
+    pub fn blit(
+        &self,
+        src_framebuffer_id: Option<&FramebufferId>,
+        dst_framebuffer_id: Option<&FramebufferId>,
+        src_rect: Viewport,
+        dst_rect: Viewport,
+        filter: BlitFilter,
+    ) -> Result<(), CopyTextureError> {
+        self.deref().borrow().blit(
+            src_framebuffer_id,
+            dst_framebuffer_id,
+            src_rect,
+            dst_rect,
+            filter,
+        )
+    }
+
+    #[cfg(feature = "compositing")]
+    pub fn draw_overlay(&self, overlay: &OverlayLayer<ProgramId>) -> &Self {
+        self.deref().borrow().draw_overlay(overlay);
+        self
+    }
+
+    /// See [RendererData::draw_fullscreen_triangle]
+    pub fn draw_fullscreen_triangle(&self, program_id: &ProgramId) -> &Self {
+        self.deref().borrow().draw_fullscreen_triangle(program_id);
+        self
+    }
+
+    #[cfg(feature = "shader-toy")]
+    pub fn draw_shader_toy(
+        &self,
+        program_id: &ProgramId,
+        channel_texture_ids: &[TextureId],
+    ) -> &Self {
+        self.deref()
+            .borrow()
+            .draw_shader_toy(program_id, channel_texture_ids);
+        self
+    }
+
+    pub fn find_feedback_hazard(&self) -> Option<FeedbackHazard<FramebufferId, TextureId>> {
+        self.deref().borrow().find_feedback_hazard()
+    }
+
     pub fn save_image(&self) {
         self.deref().borrow().save_image()
     }
@@ -756,6 +1847,7 @@ impl<
     }
 }
 
+#[cfg(feature = "js")]
 impl From<RendererJsInner> for JsValue {
     fn from(js_renderer_handle_inner: RendererJsInner) -> Self {
         let js_renderer_handle: RendererJs = js_renderer_handle_inner.into();
@@ -1,14 +1,23 @@
 use crate::{
-    AnimationCallbackJs, AttributeJs, BufferJs, Callback, FramebufferJs, RenderCallbackJs,
-    Renderer, RendererDataBuilderJs, RendererDataJs, TextureJs, UniformJs,
+    buffer_pair_ids_to_js, prefers_reduced_motion, utils, AnimationCallbackJs, AttributeJs,
+    BlitFilter, BufferJs, CanvasColorSpace, ClearValue, DebugView, DeterministicMode,
+    ExternalOwnership, ExternalTextureDescriptor, FramebufferJs, NonFiniteCheck, OutputAdjustment,
+    PowerSavingOptions, PowerSavingPolicy, QualityHintOptions, ReducedMotionOptions,
+    RenderCallbackJs, Renderer, RendererDataBuilderJs, RendererDataJs, SettingsStore, StateIdsJs,
+    StateSnapshotJs, StringArray, TextureJs, UniformJs, Viewport,
 };
-use js_sys::Object;
-use log::error;
+#[cfg(feature = "recording")]
+use js_sys::{Array, Promise};
+use js_sys::{Function, Object};
 use std::ops::{Deref, DerefMut};
+#[cfg(feature = "recording")]
+use wasm_bindgen::JsCast;
 use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+#[cfg(feature = "recording")]
+use web_sys::MediaStreamTrack;
 use web_sys::{
-    HtmlCanvasElement, WebGl2RenderingContext, WebGlProgram, WebGlShader, WebGlTransformFeedback,
-    WebGlVertexArrayObject,
+    HtmlCanvasElement, WebGl2RenderingContext, WebGlBuffer, WebGlFramebuffer, WebGlProgram,
+    WebGlShader, WebGlTexture, WebGlTransformFeedback, WebGlVertexArrayObject,
 };
 
 pub type RendererJsInner = Renderer<
@@ -30,11 +39,24 @@ pub struct RendererJs(RendererJsInner);
 
 #[wasm_bindgen(js_class = Renderer)]
 impl RendererJs {
+    #[cfg(feature = "recording")]
     #[wasm_bindgen(js_name = initializeRecorder)]
     pub fn initialize_recorder(&mut self) {
         self.deref_mut().initialize_recorder();
     }
 
+    /// Mixes the given `MediaStreamTrack`s into recordings alongside the canvas video
+    #[cfg(feature = "recording")]
+    #[wasm_bindgen(js_name = initializeRecorderWithAudio)]
+    pub fn initialize_recorder_with_audio(&mut self, audio_tracks: Array) {
+        let audio_tracks: Vec<MediaStreamTrack> = audio_tracks
+            .iter()
+            .filter_map(|track| track.dyn_into().ok())
+            .collect();
+        self.deref_mut()
+            .initialize_recorder_with_audio(&audio_tracks);
+    }
+
     #[wasm_bindgen(js_name = startAnimating)]
     pub fn start_animating(&self) {
         self.deref().start_animating();
@@ -50,21 +72,285 @@ impl RendererJs {
         self.deref_mut().set_animation_callback(animation_callback);
     }
 
+    /// Called instead of the animation callback while reduced motion is active
+    #[wasm_bindgen(js_name = setReducedMotionAnimationCallback)]
+    pub fn set_reduced_motion_animation_callback(
+        &mut self,
+        animation_callback: Option<AnimationCallbackJs>,
+    ) {
+        self.deref_mut()
+            .set_reduced_motion_animation_callback(animation_callback);
+    }
+
+    /// Registers a callback to run before the render callback on every frame.
+    /// Adding a hook under an existing key replaces it.
+    #[wasm_bindgen(js_name = addBeforeRenderHook)]
+    pub fn add_before_render_hook(&mut self, key: String, hook: RenderCallbackJs) {
+        self.deref_mut().add_before_render_hook(key, hook);
+    }
+
+    /// Registers a callback to run after the render callback on every frame.
+    /// Adding a hook under an existing key replaces it.
+    #[wasm_bindgen(js_name = addAfterRenderHook)]
+    pub fn add_after_render_hook(&mut self, key: String, hook: RenderCallbackJs) {
+        self.deref_mut().add_after_render_hook(key, hook);
+    }
+
+    #[wasm_bindgen(js_name = removeBeforeRenderHook)]
+    pub fn remove_before_render_hook(&mut self, key: String) {
+        self.deref_mut().remove_before_render_hook(&key);
+    }
+
+    #[wasm_bindgen(js_name = removeAfterRenderHook)]
+    pub fn remove_after_render_hook(&mut self, key: String) {
+        self.deref_mut().remove_after_render_hook(&key);
+    }
+
+    /// Makes the animation loop every `durationMs` milliseconds, starting now
+    #[wasm_bindgen(js_name = setLoop)]
+    pub fn set_loop(&mut self, duration_ms: f64) {
+        self.deref_mut().set_loop(duration_ms);
+    }
+
+    #[wasm_bindgen(js_name = clearLoop)]
+    pub fn clear_loop(&mut self) {
+        self.deref_mut().clear_loop();
+    }
+
+    /// Makes rendering reproducible: time starts at 0 and advances by `1000 / fps` milliseconds
+    /// per rendered frame, and `random()` is seeded with `seed`
+    #[wasm_bindgen(js_name = setDeterministicMode)]
+    pub fn set_deterministic_mode(&self, seed: u32, fps: f64) {
+        self.deref()
+            .set_deterministic_mode(Some(DeterministicMode::new(seed as u64, fps)));
+    }
+
+    #[wasm_bindgen(js_name = clearDeterministicMode)]
+    pub fn clear_deterministic_mode(&self) {
+        self.deref().set_deterministic_mode(None);
+    }
+
+    /// Number of frames rendered since deterministic mode was last set
+    #[wasm_bindgen(js_name = deterministicFrame)]
+    pub fn deterministic_frame(&self) -> f64 {
+        self.deref().borrow().deterministic_frame() as f64
+    }
+
+    /// `count` random values in `min..max` from the given stream (e.g. for an initial buffer),
+    /// which are the same every time in deterministic mode
+    #[wasm_bindgen(js_name = randomValues)]
+    pub fn random_values(&self, stream: u32, count: usize, min: f32, max: f32) -> Vec<f32> {
+        self.deref().rng(stream as u64).vec_f32(count, min, max)
+    }
+
+    /// Registers a callback that receives `{ t, loopCount }` each time the loop wraps around
+    #[wasm_bindgen(js_name = setLoopCallback)]
+    pub fn set_loop_callback(&mut self, callback: Option<Function>) {
+        self.deref_mut().set_loop_callback(callback);
+    }
+
+    /// Throttles the animation to the given frame rates while the page is hidden or the canvas is
+    /// offscreen: `0` pauses it and `Infinity` keeps it running at full rate
+    #[wasm_bindgen(js_name = setPowerSaving)]
+    pub fn set_power_saving(&mut self, hidden_fps: f64, offscreen_fps: f64) {
+        let policy = |fps: f64| {
+            if fps <= 0.0 {
+                PowerSavingPolicy::Pause
+            } else if fps.is_infinite() {
+                PowerSavingPolicy::KeepRunning
+            } else {
+                PowerSavingPolicy::Throttle(fps)
+            }
+        };
+        let mut options = PowerSavingOptions::new();
+        options
+            .set_hidden(policy(hidden_fps))
+            .set_offscreen(policy(offscreen_fps));
+        self.deref_mut().set_power_saving(Some(options));
+    }
+
+    #[wasm_bindgen(js_name = clearPowerSaving)]
+    pub fn clear_power_saving(&mut self) {
+        self.deref_mut().set_power_saving(None);
+    }
+
+    /// The current state, of the form `{ state, reason, fps }`
+    #[wasm_bindgen(js_name = powerSavingState)]
+    pub fn power_saving_state(&self) -> JsValue {
+        self.deref().power_saving_state().into()
+    }
+
+    /// Registers a callback that receives `{ state, reason, fps }` whenever the animation is
+    /// paused, throttled or resumed to save power
+    #[wasm_bindgen(js_name = setPowerSavingCallback)]
+    pub fn set_power_saving_callback(&mut self, callback: Option<Function>) {
+        self.deref_mut().set_power_saving_callback(callback);
+    }
+
+    /// Adjusts everything drawn to the canvas with a program added by
+    /// `RendererDataBuilder.addOutputAdjustment`
+    #[wasm_bindgen(js_name = setOutputAdjustment)]
+    pub fn set_output_adjustment(
+        &mut self,
+        program_id: String,
+        brightness: f32,
+        contrast: f32,
+        gamma: f32,
+    ) {
+        let mut adjustment = OutputAdjustment::new(program_id);
+        adjustment
+            .set_brightness(brightness)
+            .set_contrast(contrast)
+            .set_gamma(gamma);
+        self.deref_mut().set_output_adjustment(Some(adjustment));
+    }
+
+    #[wasm_bindgen(js_name = clearOutputAdjustment)]
+    pub fn clear_output_adjustment(&mut self) {
+        self.deref_mut().set_output_adjustment(None);
+    }
+
+    /// Saves the current output adjustment to `localStorage` under `storageKey`
+    #[wasm_bindgen(js_name = saveOutputAdjustment)]
+    pub fn save_output_adjustment(&self, storage_key: String) -> Result<(), String> {
+        let adjustment = self
+            .deref()
+            .output_adjustment()
+            .ok_or_else(|| String::from("No output adjustment has been set"))?;
+        let store = SettingsStore::new(storage_key);
+        OutputAdjustment::new(adjustment.program_id().clone()).register_settings(&store);
+        adjustment
+            .save_settings(&store)
+            .map_err(|err| err.to_string())
+    }
+
+    /// Applies the output adjustment saved under `storageKey` (if any) with a program added by
+    /// `RendererDataBuilder.addOutputAdjustment`
+    #[wasm_bindgen(js_name = restoreOutputAdjustment)]
+    pub fn restore_output_adjustment(
+        &mut self,
+        program_id: String,
+        storage_key: String,
+    ) -> Result<(), String> {
+        let store = SettingsStore::new(storage_key);
+        let mut adjustment = OutputAdjustment::new(program_id);
+        adjustment.register_settings(&store);
+        store.restore().map_err(|err| err.to_string())?;
+        adjustment.apply_settings(&store);
+        self.deref_mut().set_output_adjustment(Some(adjustment));
+        Ok(())
+    }
+
+    /// Runs time at `timeScale` (between `0` and `1`) and switches to the reduced motion animation
+    /// callback while the user prefers reduced motion
+    #[wasm_bindgen(js_name = setReducedMotion)]
+    pub fn set_reduced_motion(&mut self, time_scale: f64) {
+        let mut options = ReducedMotionOptions::new();
+        options.set_time_scale(time_scale);
+        self.deref_mut().set_reduced_motion(Some(options));
+    }
+
+    #[wasm_bindgen(js_name = clearReducedMotion)]
+    pub fn clear_reduced_motion(&mut self) {
+        self.deref_mut().set_reduced_motion(None);
+    }
+
+    /// Whether the user currently prefers reduced motion, whether or not it's been enabled
+    #[wasm_bindgen(js_name = prefersReducedMotion)]
+    pub fn prefers_reduced_motion(&self) -> bool {
+        prefers_reduced_motion()
+    }
+
+    #[wasm_bindgen(js_name = isReducedMotionActive)]
+    pub fn is_reduced_motion_active(&self) -> bool {
+        self.deref().is_reduced_motion_active()
+    }
+
+    #[wasm_bindgen(js_name = setTimeScale)]
+    pub fn set_time_scale(&self, time_scale: f64) {
+        self.deref().set_time_scale(time_scale);
+    }
+
+    #[wasm_bindgen(js_name = timeScale)]
+    pub fn time_scale(&self) -> f64 {
+        self.deref().time_scale()
+    }
+
+    /// Suggests a lower resolution scale while the battery is at or below `lowBatteryLevel` and
+    /// discharging, or while the device pixel ratio is above `maxDevicePixelRatio`
+    #[wasm_bindgen(js_name = setQualityHints)]
+    pub fn set_quality_hints(
+        &mut self,
+        low_battery_level: f64,
+        low_battery_resolution_scale: f64,
+        max_device_pixel_ratio: Option<f64>,
+    ) {
+        let mut options = QualityHintOptions::new();
+        options
+            .set_low_battery_level(low_battery_level)
+            .set_low_battery_resolution_scale(low_battery_resolution_scale)
+            .set_max_device_pixel_ratio(max_device_pixel_ratio);
+        self.deref_mut().set_quality_hints(Some(options));
+    }
+
+    #[wasm_bindgen(js_name = clearQualityHints)]
+    pub fn clear_quality_hints(&mut self) {
+        self.deref_mut().set_quality_hints(None);
+    }
+
+    /// The latest hints, of the form
+    /// `{ batteryLevel, charging, devicePixelRatio, lowBattery, resolutionScale, reduced }`
+    #[wasm_bindgen(js_name = qualityHints)]
+    pub fn quality_hints(&self) -> JsValue {
+        self.deref().quality_hints().into()
+    }
+
+    /// Registers a callback that receives the latest hints whenever they change
+    #[wasm_bindgen(js_name = setQualityHintCallback)]
+    pub fn set_quality_hint_callback(&mut self, callback: Option<Function>) {
+        self.deref_mut().set_quality_hint_callback(callback);
+    }
+
+    #[cfg(feature = "recording")]
     #[wasm_bindgen(js_name = startRecording)]
     pub fn start_recording(&mut self) {
         self.deref_mut().start_recording();
     }
 
+    #[cfg(feature = "recording")]
     #[wasm_bindgen(js_name = stopRecording)]
     pub fn stop_recording(&self) {
         self.deref().stop_recording();
     }
 
+    #[cfg(feature = "recording")]
     #[wasm_bindgen(js_name = clearRecordedData)]
     pub fn clear_recorded_data(&self) {
         self.deref().clear_recorded_data();
     }
 
+    /// Registers a callback that receives `true` when recording starts and `false` when it stops
+    #[cfg(feature = "recording")]
+    #[wasm_bindgen(js_name = onRecordingStateChange)]
+    pub fn on_recording_state_change(&mut self, callback: Function) {
+        self.deref_mut().add_recording_state_callback(callback);
+    }
+
+    /// Disable to stop the finished video from being downloaded automatically
+    #[cfg(feature = "recording")]
+    #[wasm_bindgen(js_name = setAutoDownloadRecording)]
+    pub fn set_auto_download_recording(&mut self, auto_download: bool) {
+        self.deref_mut().set_auto_download_recording(auto_download);
+    }
+
+    /// Resolves with the recorded video as a Blob once all recorded data is available
+    #[cfg(feature = "recording")]
+    #[wasm_bindgen(js_name = getRecordingBlob)]
+    pub fn get_recording_blob(&self) -> Promise {
+        self.deref().recording_blob_promise()
+    }
+
+    #[cfg(feature = "recording")]
     #[wasm_bindgen(js_name = recorderInitialized)]
     pub fn recorder_initialized(&self) -> bool {
         self.deref().recorder_initialized()
@@ -75,11 +361,14 @@ impl RendererJs {
         self.deref().is_animating()
     }
 
+    #[cfg(feature = "recording")]
     #[wasm_bindgen(js_name = isRecording)]
     pub fn is_recording(&self) -> bool {
         self.deref().is_recording()
     }
 
+    /// A handle to this renderer's `RendererData`, the same one render callbacks receive. Drawing
+    /// helpers such as `bindFramebuffer`, `drawArrays`, `drawArraysInstanced` and `drawElements` live there.
     #[wasm_bindgen(js_name = rendererData)]
     pub fn renderer_data(&self) -> RendererDataJs {
         self.deref().renderer_data().into()
@@ -141,6 +430,20 @@ impl RendererJs {
             .map(Into::into)
     }
 
+    /// Gets the attribute bound in a particular VAO (or in the default vertex array, if `vaoId` is omitted)
+    #[wasm_bindgen(js_name = attributeForVao)]
+    pub fn attribute_for_vao(
+        &self,
+        attribute_id: String,
+        vao_id: Option<String>,
+    ) -> Option<AttributeJs> {
+        self.deref()
+            .borrow()
+            .attribute_for_vao(&attribute_id, vao_id.as_ref())
+            .map(Clone::clone)
+            .map(Into::into)
+    }
+
     pub fn texture(&self, texture_id: String) -> Option<TextureJs> {
         self.deref()
             .borrow()
@@ -149,6 +452,51 @@ impl RendererJs {
             .map(Into::into)
     }
 
+    #[wasm_bindgen(js_name = drawingBufferColorSpace)]
+    pub fn drawing_buffer_color_space(&self) -> CanvasColorSpace {
+        self.deref().borrow().drawing_buffer_color_space()
+    }
+
+    #[wasm_bindgen(js_name = textureUnit)]
+    pub fn texture_unit(&self, texture_id: String) -> Option<u32> {
+        self.deref().borrow().texture_unit(&texture_id)
+    }
+
+    /// Descriptions of the problems found by `setValidatePrograms`
+    #[wasm_bindgen(js_name = programValidationWarnings)]
+    pub fn program_validation_warnings(&self) -> StringArray {
+        let warnings: Vec<String> = self
+            .deref()
+            .borrow()
+            .program_validation_warnings()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        utils::strings_to_js_array(&warnings)
+    }
+
+    /// Switches programs that use the debug view GLSL includes to `"normal"`, `"wireframe"`, or
+    /// `"overdraw"` rendering
+    #[wasm_bindgen(js_name = setDebugView)]
+    pub fn set_debug_view(&self, debug_view: &str) -> Result<(), JsValue> {
+        let debug_view = DebugView::from_name(debug_view)
+            .ok_or_else(|| JsValue::from_str(&format!("Unknown debug view {debug_view:?}")))?;
+        self.deref().set_debug_view(debug_view);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = debugView)]
+    pub fn debug_view(&self) -> String {
+        self.deref().debug_view().as_str().to_string()
+    }
+
+    /// Snapshot of all resources, how they depend on each other, frame stats, and the
+    /// capabilities of the WebGL implementation, as a plain object for devtools panels
+    #[wasm_bindgen(js_name = debugInfo)]
+    pub fn debug_info(&self) -> JsValue {
+        self.deref().borrow().debug_info().into()
+    }
+
     pub fn framebuffer(&self, framebuffer_id: String) -> Option<FramebufferJs> {
         self.deref()
             .borrow()
@@ -172,6 +520,74 @@ impl RendererJs {
         self.deref().borrow().vao(&vao_id).map(Clone::clone)
     }
 
+    /// Flips the read/write roles of a buffer pair (see `BufferPairLink`)
+    #[wasm_bindgen(js_name = swapBufferPair)]
+    pub fn swap_buffer_pair(&self, pair_id: String) {
+        self.deref().borrow().swap_buffer_pair(&pair_id);
+    }
+
+    /// Reads back the listed textures and buffers so they can be saved and restored later
+    #[wasm_bindgen(js_name = exportState)]
+    pub fn export_state(&self, state_ids: &StateIdsJs) -> Result<StateSnapshotJs, String> {
+        self.deref()
+            .borrow()
+            .export_state(state_ids)
+            .map(Into::into)
+            .map_err(|err| err.to_string())
+    }
+
+    #[wasm_bindgen(js_name = importState)]
+    pub fn import_state(&self, snapshot: &StateSnapshotJs) -> Result<(), String> {
+        self.deref()
+            .borrow()
+            .import_state(snapshot)
+            .map_err(|err| err.to_string())
+    }
+
+    /// Scans a float texture for NaN and infinite values with a program added by
+    /// `addNonFiniteCheck`, calling `callback` with (and returning) a
+    /// `{ textureId, frame, gridSize, nanCells, infinityCells }` report if any are found
+    #[wasm_bindgen(js_name = checkNonFinite)]
+    pub fn check_non_finite(
+        &self,
+        program_id: String,
+        texture_id: String,
+        callback: Function,
+    ) -> Result<JsValue, String> {
+        let check = NonFiniteCheck::new(program_id, callback);
+        self.deref()
+            .borrow()
+            .check_non_finite(&check, &texture_id)
+            .map(|report| report.map_or(JsValue::NULL, Into::into))
+            .map_err(|err| err.to_string())
+    }
+
+    /// Copies the first `count` floats of a buffer back to the CPU (stalls until the GPU catches up)
+    #[wasm_bindgen(js_name = readTransformFeedbackBuffer)]
+    pub fn read_transform_feedback_buffer(&self, buffer_id: String, count: u32) -> Vec<f32> {
+        self.deref()
+            .borrow()
+            .read_transform_feedback_buffer(&buffer_id, count)
+    }
+
+    /// The `[bufferId, VAOId]` of a buffer pair to read from this frame
+    #[wasm_bindgen(js_name = bufferPairRead)]
+    pub fn buffer_pair_read(&self, pair_id: String) -> Option<StringArray> {
+        self.deref()
+            .borrow()
+            .buffer_pair(&pair_id)
+            .map(|buffer_pair| buffer_pair_ids_to_js(buffer_pair.read()))
+    }
+
+    /// The `[bufferId, VAOId]` of a buffer pair to write to this frame
+    #[wasm_bindgen(js_name = bufferPairWrite)]
+    pub fn buffer_pair_write(&self, pair_id: String) -> Option<StringArray> {
+        self.deref()
+            .borrow()
+            .buffer_pair(&pair_id)
+            .map(|buffer_pair| buffer_pair_ids_to_js(buffer_pair.write()))
+    }
+
     #[wasm_bindgen(js_name = userCtx)]
     pub fn user_ctx(&self) -> Option<Object> {
         self.deref().borrow().user_ctx().map(Clone::clone)
@@ -187,6 +603,12 @@ impl RendererJs {
         self.deref().borrow().use_vao(&vao_id);
     }
 
+    /// Draws a viewport-covering triangle with an attribute-less VAO (positions should come from `gl_VertexID`)
+    #[wasm_bindgen(js_name = drawFullscreenTriangle)]
+    pub fn draw_fullscreen_triangle(&self, program_id: String) {
+        self.deref().borrow().draw_fullscreen_triangle(&program_id);
+    }
+
     #[wasm_bindgen(js_name = updateUniform)]
     pub fn update_uniform(&self, uniform_id: String) {
         self.deref().borrow().update_uniform(&uniform_id);
@@ -197,25 +619,206 @@ impl RendererJs {
         self.deref().borrow().update_uniforms();
     }
 
+    #[wasm_bindgen(js_name = invalidateUniform)]
+    pub fn invalidate_uniform(&self, uniform_id: String) {
+        self.deref().borrow().invalidate_uniform(&uniform_id);
+    }
+
     pub fn render(&self) {
         // does not deref() into the inner `RendererData` here, because it is more efficient
         // to keep this type as-is and pass in itself as a reference to the JavaScript function
         let renderer_data_js = self.renderer_data();
         let render_callback = renderer_data_js.render_callback();
-        match &*render_callback {
-            Callback::Rust(rust_callback) => {
-                let renderer_data = renderer_data_js.into_inner();
-                (rust_callback)(&renderer_data.borrow());
-            }
-            Callback::Js(js_callback) => {
-                let js_callback = js_callback.deref();
-                let js_value: JsValue = renderer_data_js.into();
-                let result = js_callback.call1(&JsValue::NULL, &js_value);
-                if let Err(error) = result {
-                    error!("Error occurred while calling JavaScript `render` callback: {error:?}");
-                }
-            }
-        }
+        renderer_data_js.render_frame(|| renderer_data_js.call_render_callback(&render_callback));
+    }
+
+    /// Updates uniforms and renders as if the current time were `timeMs`
+    #[wasm_bindgen(js_name = renderAt)]
+    pub fn render_at(&self, time_ms: f64) {
+        let previous_time_override = {
+            let renderer_data = self.deref().borrow();
+            let previous_time_override = renderer_data.time_override();
+            renderer_data.set_time_override(Some(time_ms));
+            renderer_data.update_uniforms();
+            previous_time_override
+        };
+        self.render();
+        self.deref()
+            .borrow()
+            .set_time_override(previous_time_override);
+    }
+
+    /// Starts or stops recording frame timings for [RendererJs::download_trace]
+    #[wasm_bindgen(js_name = setTracingEnabled)]
+    pub fn set_tracing_enabled(&self, enabled: bool) {
+        self.deref().borrow().frame_tracer().set_enabled(enabled);
+    }
+
+    /// Recorded frame timings as Chrome trace-event JSON
+    #[wasm_bindgen(js_name = traceJson)]
+    pub fn trace_json(&self) -> String {
+        self.deref().borrow().frame_tracer().to_chrome_trace_json()
+    }
+
+    /// Downloads recorded frame timings as a Chrome trace-event JSON file,
+    /// which can be opened in `chrome://tracing` or Perfetto
+    #[wasm_bindgen(js_name = downloadTrace)]
+    pub fn download_trace(&self, filename: String) -> Result<(), JsValue> {
+        self.deref().borrow().frame_tracer().download(&filename)
+    }
+
+    /// Binds the texture at each index of `texture_ids` to the texture unit at the same index of `texture_units`
+    #[wasm_bindgen(js_name = bindTextures)]
+    pub fn bind_textures(&self, texture_ids: StringArray, texture_units: Vec<u32>) {
+        let textures: Vec<(String, u32)> = utils::js_array_to_vec_strings(&texture_ids)
+            .into_iter()
+            .zip(texture_units)
+            .collect();
+        self.deref().borrow().bind_textures(&textures);
+    }
+
+    /// Makes a `TEXTURE_2D` created by other code sharing this context usable under `textureId`.
+    /// If `owned` is `true`, wrend deletes it once it is unregistered or replaced.
+    #[wasm_bindgen(js_name = registerExternalTexture)]
+    pub fn register_external_texture(
+        &self,
+        texture_id: String,
+        texture: WebGlTexture,
+        width: i32,
+        height: i32,
+        internal_format: u32,
+        owned: bool,
+    ) -> Result<(), String> {
+        self.deref()
+            .register_external_texture(
+                texture_id,
+                texture,
+                ExternalTextureDescriptor::new(width, height, internal_format),
+                external_ownership(owned),
+            )
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+
+    #[wasm_bindgen(js_name = unregisterExternalTexture)]
+    pub fn unregister_external_texture(&self, texture_id: String) -> bool {
+        self.deref().unregister_external_texture(&texture_id)
+    }
+
+    #[wasm_bindgen(js_name = registerExternalBuffer)]
+    pub fn register_external_buffer(
+        &self,
+        buffer_id: String,
+        buffer: WebGlBuffer,
+        owned: bool,
+    ) -> Result<(), String> {
+        self.deref()
+            .register_external_buffer(buffer_id, buffer, external_ownership(owned))
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+
+    #[wasm_bindgen(js_name = unregisterExternalBuffer)]
+    pub fn unregister_external_buffer(&self, buffer_id: String) -> bool {
+        self.deref().unregister_external_buffer(&buffer_id)
+    }
+
+    #[wasm_bindgen(js_name = registerExternalFramebuffer)]
+    pub fn register_external_framebuffer(
+        &self,
+        framebuffer_id: String,
+        framebuffer: WebGlFramebuffer,
+        owned: bool,
+    ) -> Result<(), String> {
+        self.deref()
+            .register_external_framebuffer(framebuffer_id, framebuffer, external_ownership(owned))
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+
+    #[wasm_bindgen(js_name = unregisterExternalFramebuffer)]
+    pub fn unregister_external_framebuffer(&self, framebuffer_id: String) -> bool {
+        self.deref()
+            .unregister_external_framebuffer(&framebuffer_id)
+    }
+
+    /// Copies the canvas's current contents into a texture (resizing it to match the canvas)
+    #[wasm_bindgen(js_name = captureCanvasToTexture)]
+    pub fn capture_canvas_to_texture(&self, texture_id: String) -> Result<(), String> {
+        self.deref()
+            .borrow()
+            .capture_canvas_to_texture(&texture_id)
+            .map_err(|err| err.to_string())
+    }
+
+    #[wasm_bindgen(js_name = copyTexture)]
+    pub fn copy_texture(
+        &self,
+        src_texture_id: String,
+        dst_texture_id: String,
+        width: i32,
+        height: i32,
+    ) -> Result<(), String> {
+        self.deref()
+            .borrow()
+            .copy_texture(&src_texture_id, &dst_texture_id, width, height)
+            .map_err(|err| err.to_string())
+    }
+
+    /// Clears a texture with 4 float components (for normalized and floating point formats)
+    #[wasm_bindgen(js_name = clearTexture)]
+    pub fn clear_texture(&self, texture_id: String, value: Vec<f32>) -> Result<(), String> {
+        let value = match value[..] {
+            [r, g, b, a, ..] => ClearValue::Float([r, g, b, a]),
+            _ => return Err(String::from("Clear values must have 4 components")),
+        };
+        self.deref()
+            .borrow()
+            .clear_texture(&texture_id, value)
+            .map_err(|err| err.to_string())
+    }
+
+    /// Rects are `[x, y, width, height]` in pixels. An `undefined` framebuffer id refers to the canvas.
+    pub fn blit(
+        &self,
+        src_framebuffer_id: Option<String>,
+        dst_framebuffer_id: Option<String>,
+        src_rect: Vec<i32>,
+        dst_rect: Vec<i32>,
+        filter: BlitFilter,
+    ) -> Result<(), String> {
+        let rect = |rect: &[i32]| match rect {
+            [x, y, width, height, ..] => Ok(Viewport::new(*x, *y, *width, *height)),
+            _ => Err(String::from("Blit rects must have 4 components")),
+        };
+        self.deref()
+            .borrow()
+            .blit(
+                src_framebuffer_id.as_ref(),
+                dst_framebuffer_id.as_ref(),
+                rect(&src_rect)?,
+                rect(&dst_rect)?,
+                filter,
+            )
+            .map_err(|err| err.to_string())
+    }
+
+    #[cfg(feature = "shader-toy")]
+    #[wasm_bindgen(js_name = drawShaderToy)]
+    pub fn draw_shader_toy(&self, program_id: String, channel_texture_ids: StringArray) {
+        let channel_texture_ids = utils::js_array_to_vec_strings(&channel_texture_ids);
+        self.deref()
+            .borrow()
+            .draw_shader_toy(&program_id, &channel_texture_ids);
+    }
+
+    /// Returns a description of the feedback hazard for the next draw call, if there is one
+    #[wasm_bindgen(js_name = findFeedbackHazard)]
+    pub fn find_feedback_hazard(&self) -> Option<String> {
+        self.deref()
+            .borrow()
+            .find_feedback_hazard()
+            .map(|hazard| hazard.to_string())
     }
 
     #[wasm_bindgen(js_name = saveImage)]
@@ -252,3 +855,11 @@ impl DerefMut for RendererJs {
         &mut self.0
     }
 }
+
+fn external_ownership(owned: bool) -> ExternalOwnership {
+    if owned {
+        ExternalOwnership::Owned
+    } else {
+        ExternalOwnership::Borrowed
+    }
+}
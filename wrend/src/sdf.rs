@@ -0,0 +1,11 @@
+mod sdf_font;
+mod sdf_glyph;
+mod sdf_shaders;
+mod sdf_text;
+mod sdf_text_layout;
+
+pub use sdf_font::*;
+pub use sdf_glyph::*;
+pub use sdf_shaders::*;
+pub use sdf_text::*;
+pub use sdf_text_layout::*;
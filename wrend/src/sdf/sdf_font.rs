@@ -0,0 +1,233 @@
+use crate::{SdfGlyph, SdfTextError, SdfTextLayout};
+use js_sys::{Array, Reflect, JSON};
+use std::collections::HashMap;
+use wasm_bindgen::JsValue;
+
+/// Metrics of a multi-channel signed distance field font, as produced by
+/// [msdf-atlas-gen](https://github.com/Chlumsky/msdf-atlas-gen) with `-json`.
+///
+/// Glyph metrics are kept in em units, so the same font can be laid out at any size with
+/// [SdfFont::layout] and still render crisply.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SdfFont {
+    distance_range: f32,
+    atlas_size: [f32; 2],
+    y_origin_bottom: bool,
+    line_height: f32,
+    ascender: f32,
+    descender: f32,
+    glyphs: HashMap<char, SdfGlyph>,
+    kerning: HashMap<(char, char), f32>,
+}
+
+impl SdfFont {
+    /// Creates a font without any glyphs. `distance_range` is in atlas pixels and `atlas_size` is
+    /// the atlas image's `[width, height]`.
+    pub fn new(
+        distance_range: f32,
+        atlas_size: [f32; 2],
+        y_origin_bottom: bool,
+        line_height: f32,
+        ascender: f32,
+        descender: f32,
+    ) -> Self {
+        Self {
+            distance_range,
+            atlas_size,
+            y_origin_bottom,
+            line_height,
+            ascender,
+            descender,
+            glyphs: HashMap::new(),
+            kerning: HashMap::new(),
+        }
+    }
+
+    /// Parses msdf-atlas-gen's JSON output
+    pub fn from_json(json: &str) -> Result<Self, SdfTextError> {
+        let root =
+            JSON::parse(json).map_err(|err| SdfTextError::InvalidJson(format!("{err:?}")))?;
+        let atlas = field(&root, "atlas")?;
+        let metrics = field(&root, "metrics")?;
+        let mut font = Self::new(
+            number(&atlas, "distanceRange")?,
+            [number(&atlas, "width")?, number(&atlas, "height")?],
+            Reflect::get(&atlas, &"yOrigin".into())
+                .ok()
+                .and_then(|value| value.as_string())
+                .is_none_or(|y_origin| y_origin == "bottom"),
+            number(&metrics, "lineHeight")?,
+            number(&metrics, "ascender")?,
+            number(&metrics, "descender")?,
+        );
+
+        for glyph in Array::from(&field(&root, "glyphs")?).iter() {
+            let Some(unicode) = char::from_u32(number(&glyph, "unicode")? as u32) else {
+                continue;
+            };
+            font.add_glyph(
+                unicode,
+                SdfGlyph::new(
+                    number(&glyph, "advance")?,
+                    bounds(&glyph, "planeBounds")?,
+                    bounds(&glyph, "atlasBounds")?,
+                ),
+            );
+        }
+
+        if let Ok(kerning) = field(&root, "kerning") {
+            for pair in Array::from(&kerning).iter() {
+                let first = char::from_u32(number(&pair, "unicode1")? as u32);
+                let second = char::from_u32(number(&pair, "unicode2")? as u32);
+                if let (Some(first), Some(second)) = (first, second) {
+                    font.add_kerning(first, second, number(&pair, "advance")?);
+                }
+            }
+        }
+
+        Ok(font)
+    }
+
+    pub fn add_glyph(&mut self, character: char, glyph: SdfGlyph) -> &mut Self {
+        self.glyphs.insert(character, glyph);
+        self
+    }
+
+    /// Adjusts the advance (in em units) between `first` and `second` when they appear together
+    pub fn add_kerning(&mut self, first: char, second: char, advance: f32) -> &mut Self {
+        self.kerning.insert((first, second), advance);
+        self
+    }
+
+    pub fn glyph(&self, character: char) -> Option<&SdfGlyph> {
+        self.glyphs.get(&character)
+    }
+
+    pub fn kerning(&self, first: char, second: char) -> f32 {
+        self.kerning.get(&(first, second)).copied().unwrap_or(0.0)
+    }
+
+    pub fn distance_range(&self) -> f32 {
+        self.distance_range
+    }
+
+    pub fn atlas_size(&self) -> [f32; 2] {
+        self.atlas_size
+    }
+
+    pub fn line_height(&self) -> f32 {
+        self.line_height
+    }
+
+    pub fn ascender(&self) -> f32 {
+        self.ascender
+    }
+
+    pub fn descender(&self) -> f32 {
+        self.descender
+    }
+
+    /// Lays `text` out in pixels, with `origin` as the top-left corner of the first line and y
+    /// pointing down. Characters missing from the font are skipped and `'\n'` starts a new line.
+    pub fn layout(&self, text: &str, font_size: f32, origin: [f32; 2]) -> SdfTextLayout {
+        let [atlas_width, atlas_height] = self.atlas_size;
+        let mut layout = SdfTextLayout::default();
+        let mut cursor = [origin[0], origin[1] + self.ascender * font_size];
+        let mut previous = None;
+
+        for character in text.chars() {
+            if character == '\n' {
+                cursor = [origin[0], cursor[1] + self.line_height * font_size];
+                previous = None;
+                continue;
+            }
+            let Some(glyph) = self.glyph(character) else {
+                continue;
+            };
+            if let Some(previous) = previous {
+                cursor[0] += self.kerning(previous, character) * font_size;
+            }
+            if let (Some([left, bottom, right, top]), Some([u0, v0, u1, v1])) =
+                (glyph.plane_bounds(), glyph.atlas_bounds())
+            {
+                let [v_bottom, v_top] = if self.y_origin_bottom {
+                    [1.0 - v0 / atlas_height, 1.0 - v1 / atlas_height]
+                } else {
+                    [v1 / atlas_height, v0 / atlas_height]
+                };
+                layout.push_quad(
+                    [
+                        cursor[0] + left * font_size,
+                        cursor[1] - top * font_size,
+                        cursor[0] + right * font_size,
+                        cursor[1] - bottom * font_size,
+                    ],
+                    [u0 / atlas_width, v_top, u1 / atlas_width, v_bottom],
+                );
+            }
+            cursor[0] += glyph.advance() * font_size;
+            layout.extend_to([
+                cursor[0] - origin[0],
+                cursor[1] - self.descender * font_size - origin[1],
+            ]);
+            previous = Some(character);
+        }
+
+        layout
+    }
+}
+
+fn field(object: &JsValue, name: &str) -> Result<JsValue, SdfTextError> {
+    Reflect::get(object, &name.into())
+        .ok()
+        .filter(|value| !value.is_undefined() && !value.is_null())
+        .ok_or_else(|| SdfTextError::MissingField(name.to_string()))
+}
+
+fn number(object: &JsValue, name: &str) -> Result<f32, SdfTextError> {
+    field(object, name)?
+        .as_f64()
+        .map(|value| value as f32)
+        .ok_or_else(|| SdfTextError::MissingField(name.to_string()))
+}
+
+fn bounds(object: &JsValue, name: &str) -> Result<Option<[f32; 4]>, SdfTextError> {
+    let Ok(bounds) = field(object, name) else {
+        return Ok(None);
+    };
+    Ok(Some([
+        number(&bounds, "left")?,
+        number(&bounds, "bottom")?,
+        number(&bounds, "right")?,
+        number(&bounds, "top")?,
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_applies_kerning_and_line_breaks() {
+        let mut font = SdfFont::new(4.0, [64.0, 64.0], false, 1.25, 1.0, -0.25);
+        let glyph = SdfGlyph::new(
+            0.5,
+            Some([0.0, 0.0, 0.5, 1.0]),
+            Some([0.0, 0.0, 32.0, 64.0]),
+        );
+        font.add_glyph('A', glyph)
+            .add_glyph(' ', SdfGlyph::new(0.25, None, None))
+            .add_kerning('A', 'A', -0.1);
+
+        let layout = font.layout("AA A\nA", 10.0, [0.0, 0.0]);
+
+        assert_eq!(layout.glyph_count(), 4);
+        let x_positions: Vec<f32> = layout
+            .vertices()
+            .chunks(SdfTextLayout::FLOATS_PER_GLYPH)
+            .map(|quad| quad[0])
+            .collect();
+        assert_eq!(x_positions, vec![0.0, 4.0, 11.5, 0.0]);
+        assert_eq!(layout.size(), [16.5, 25.0]);
+    }
+}
@@ -0,0 +1,39 @@
+/// Metrics of a single glyph in an [crate::SdfFont], in em units (plane bounds) and atlas pixels (atlas bounds).
+///
+/// Bounds are `[left, bottom, right, top]`, as in msdf-atlas-gen's JSON output.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct SdfGlyph {
+    advance: f32,
+    plane_bounds: Option<[f32; 4]>,
+    atlas_bounds: Option<[f32; 4]>,
+}
+
+impl SdfGlyph {
+    /// Glyphs without bounds (such as spaces) only advance the cursor
+    pub fn new(
+        advance: f32,
+        plane_bounds: Option<[f32; 4]>,
+        atlas_bounds: Option<[f32; 4]>,
+    ) -> Self {
+        Self {
+            advance,
+            plane_bounds,
+            atlas_bounds,
+        }
+    }
+
+    /// Horizontal distance to the next glyph
+    pub fn advance(&self) -> f32 {
+        self.advance
+    }
+
+    /// Where the glyph's quad sits relative to the cursor on the baseline
+    pub fn plane_bounds(&self) -> Option<[f32; 4]> {
+        self.plane_bounds
+    }
+
+    /// Where the glyph is in the atlas image
+    pub fn atlas_bounds(&self) -> Option<[f32; 4]> {
+        self.atlas_bounds
+    }
+}
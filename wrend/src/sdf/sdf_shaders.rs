@@ -0,0 +1,98 @@
+/// Positions glyph quads laid out by [crate::SdfFont::layout], converting pixels (y down) to clip space
+pub const SDF_TEXT_VERTEX_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+layout(location = 0) in vec2 a_position;
+layout(location = 1) in vec2 a_uv;
+
+uniform vec2 u_resolution;
+
+out vec2 v_uv;
+
+void main() {
+    vec2 clip = a_position / u_resolution * 2.0 - 1.0;
+    gl_Position = vec4(clip.x, -clip.y, 0.0, 1.0);
+    v_uv = a_uv;
+}
+"#;
+
+/// Renders multi-channel SDF glyphs. The distance range is converted to screen pixels with
+/// `fwidth`, so edges stay one pixel wide at any scale.
+pub const SDF_TEXT_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+in vec2 v_uv;
+
+uniform sampler2D u_atlas;
+uniform float u_distance_range;
+uniform vec4 u_color;
+
+out vec4 out_color;
+
+float median(float r, float g, float b) {
+    return max(min(r, g), min(max(r, g), b));
+}
+
+float screen_px_range() {
+    vec2 unit_range = vec2(u_distance_range) / vec2(textureSize(u_atlas, 0));
+    vec2 screen_tex_size = vec2(1.0) / fwidth(v_uv);
+    return max(0.5 * dot(unit_range, screen_tex_size), 1.0);
+}
+
+void main() {
+    vec3 msd = texture(u_atlas, v_uv).rgb;
+    float screen_px_distance = screen_px_range() * (median(msd.r, msd.g, msd.b) - 0.5);
+    float opacity = clamp(screen_px_distance + 0.5, 0.0, 1.0);
+    out_color = vec4(u_color.rgb, u_color.a * opacity);
+}
+"#;
+
+/// GLSL include for composing shapes from signed distance functions. Distances are negative inside
+/// a shape; `sdf_fill` and `sdf_stroke` turn a distance into antialiased coverage.
+pub const SDF_SHAPES_GLSL: &str = r#"
+float sd_circle(vec2 p, float radius) {
+    return length(p) - radius;
+}
+
+float sd_box(vec2 p, vec2 half_size) {
+    vec2 d = abs(p) - half_size;
+    return length(max(d, 0.0)) + min(max(d.x, d.y), 0.0);
+}
+
+float sd_rounded_box(vec2 p, vec2 half_size, float radius) {
+    return sd_box(p, half_size - vec2(radius)) - radius;
+}
+
+float sd_segment(vec2 p, vec2 a, vec2 b) {
+    vec2 pa = p - a;
+    vec2 ba = b - a;
+    float h = clamp(dot(pa, ba) / dot(ba, ba), 0.0, 1.0);
+    return length(pa - ba * h);
+}
+
+float op_union(float a, float b) {
+    return min(a, b);
+}
+
+float op_subtraction(float a, float b) {
+    return max(a, -b);
+}
+
+float op_intersection(float a, float b) {
+    return max(a, b);
+}
+
+float op_smooth_union(float a, float b, float k) {
+    float h = clamp(0.5 + 0.5 * (b - a) / k, 0.0, 1.0);
+    return mix(b, a, h) - k * h * (1.0 - h);
+}
+
+float sdf_fill(float d) {
+    float w = fwidth(d);
+    return 1.0 - smoothstep(-w, w, d);
+}
+
+float sdf_stroke(float d, float width) {
+    return sdf_fill(abs(d) - width * 0.5);
+}
+"#;
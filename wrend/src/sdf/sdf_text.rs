@@ -0,0 +1,209 @@
+use crate::{
+    create_program, AssetLoader, SdfFont, SdfTextError, SdfTextLayout, SDF_TEXT_FRAGMENT_SHADER,
+    SDF_TEXT_VERTEX_SHADER,
+};
+use js_sys::Float32Array;
+use web_sys::{
+    HtmlImageElement, WebGl2RenderingContext, WebGlBuffer, WebGlProgram, WebGlTexture,
+    WebGlVertexArrayObject,
+};
+
+/// Draws text from a multi-channel signed distance field font.
+///
+/// Text is laid out with [SdfText::set_text] and only re-uploaded when it changes. Because the
+/// atlas stores distances rather than coverage, the same atlas renders crisp text at any size.
+#[derive(Debug)]
+pub struct SdfText {
+    gl: WebGl2RenderingContext,
+    program: WebGlProgram,
+    vao: WebGlVertexArrayObject,
+    buffer: WebGlBuffer,
+    texture: WebGlTexture,
+    font: SdfFont,
+    layout: SdfTextLayout,
+    color: [f32; 4],
+}
+
+impl SdfText {
+    /// Uploads the atlas `image` and compiles the text program
+    pub fn new(
+        gl: &WebGl2RenderingContext,
+        font: SdfFont,
+        image: &HtmlImageElement,
+    ) -> Result<Self, SdfTextError> {
+        let program = create_program(
+            gl,
+            SDF_TEXT_VERTEX_SHADER,
+            SDF_TEXT_FRAGMENT_SHADER,
+            &[],
+            SdfTextError::CompileShader,
+            SdfTextError::LinkProgram,
+        )?;
+
+        let texture = gl.create_texture().ok_or(SdfTextError::CreateTexture)?;
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&texture));
+        gl.tex_image_2d_with_u32_and_u32_and_html_image_element(
+            WebGl2RenderingContext::TEXTURE_2D,
+            0,
+            WebGl2RenderingContext::RGBA as i32,
+            WebGl2RenderingContext::RGBA,
+            WebGl2RenderingContext::UNSIGNED_BYTE,
+            image,
+        )
+        .map_err(|err| SdfTextError::UploadAtlas(format!("{err:?}")))?;
+        for (parameter, value) in [
+            (
+                WebGl2RenderingContext::TEXTURE_MIN_FILTER,
+                WebGl2RenderingContext::LINEAR,
+            ),
+            (
+                WebGl2RenderingContext::TEXTURE_MAG_FILTER,
+                WebGl2RenderingContext::LINEAR,
+            ),
+            (
+                WebGl2RenderingContext::TEXTURE_WRAP_S,
+                WebGl2RenderingContext::CLAMP_TO_EDGE,
+            ),
+            (
+                WebGl2RenderingContext::TEXTURE_WRAP_T,
+                WebGl2RenderingContext::CLAMP_TO_EDGE,
+            ),
+        ] {
+            gl.tex_parameteri(WebGl2RenderingContext::TEXTURE_2D, parameter, value as i32);
+        }
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, None);
+
+        let buffer = gl.create_buffer().ok_or(SdfTextError::CreateBuffer)?;
+        let vao = gl
+            .create_vertex_array()
+            .ok_or(SdfTextError::CreateVertexArray)?;
+        gl.bind_vertex_array(Some(&vao));
+        gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, Some(&buffer));
+        let stride = (SdfTextLayout::FLOATS_PER_VERTEX * 4) as i32;
+        for (location, offset) in [(0, 0), (1, 8)] {
+            gl.enable_vertex_attrib_array(location);
+            gl.vertex_attrib_pointer_with_i32(
+                location,
+                2,
+                WebGl2RenderingContext::FLOAT,
+                false,
+                stride,
+                offset,
+            );
+        }
+        gl.bind_vertex_array(None);
+        gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, None);
+
+        Ok(Self {
+            gl: gl.clone(),
+            program,
+            vao,
+            buffer,
+            texture,
+            font,
+            layout: SdfTextLayout::default(),
+            color: [1.0, 1.0, 1.0, 1.0],
+        })
+    }
+
+    /// Loads msdf-atlas-gen's JSON metadata and atlas image, then creates the text pass
+    pub async fn load(
+        gl: &WebGl2RenderingContext,
+        asset_loader: &AssetLoader,
+        json_url: impl Into<String>,
+        image_url: impl Into<String>,
+    ) -> Result<Self, SdfTextError> {
+        let json = asset_loader.load_text(json_url).await?;
+        let font = SdfFont::from_json(&json)?;
+        let image = asset_loader.load_image(image_url).await?;
+        Self::new(gl, font, &image)
+    }
+
+    /// Lays out and uploads `text`, with `position` as its top-left corner in canvas pixels
+    pub fn set_text(&mut self, text: &str, font_size: f32, position: [f32; 2]) -> &mut Self {
+        self.layout = self.font.layout(text, font_size, position);
+        let gl = &self.gl;
+        let data = Float32Array::from(self.layout.vertices());
+        gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, Some(&self.buffer));
+        gl.buffer_data_with_array_buffer_view(
+            WebGl2RenderingContext::ARRAY_BUFFER,
+            &data,
+            WebGl2RenderingContext::DYNAMIC_DRAW,
+        );
+        gl.bind_buffer(WebGl2RenderingContext::ARRAY_BUFFER, None);
+        self
+    }
+
+    pub fn set_color(&mut self, color: [f32; 4]) -> &mut Self {
+        self.color = color;
+        self
+    }
+
+    /// Draws the current text over whatever is bound to the draw framebuffer, blending by alpha
+    pub fn draw(&self) -> &Self {
+        if self.layout.vertex_count() == 0 {
+            return self;
+        }
+        let gl = &self.gl;
+        let program = &self.program;
+        let location = |name| gl.get_uniform_location(program, name);
+        let [r, g, b, a] = self.color;
+
+        gl.use_program(Some(program));
+        gl.uniform2f(
+            location("u_resolution").as_ref(),
+            gl.drawing_buffer_width() as f32,
+            gl.drawing_buffer_height() as f32,
+        );
+        gl.uniform1f(
+            location("u_distance_range").as_ref(),
+            self.font.distance_range(),
+        );
+        gl.uniform4f(location("u_color").as_ref(), r, g, b, a);
+        gl.uniform1i(location("u_atlas").as_ref(), 0);
+        gl.active_texture(WebGl2RenderingContext::TEXTURE0);
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(&self.texture));
+
+        gl.enable(WebGl2RenderingContext::BLEND);
+        gl.blend_func(
+            WebGl2RenderingContext::SRC_ALPHA,
+            WebGl2RenderingContext::ONE_MINUS_SRC_ALPHA,
+        );
+        gl.bind_vertex_array(Some(&self.vao));
+        gl.draw_arrays(
+            WebGl2RenderingContext::TRIANGLES,
+            0,
+            self.layout.vertex_count() as i32,
+        );
+        gl.bind_vertex_array(None);
+        gl.disable(WebGl2RenderingContext::BLEND);
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, None);
+        self
+    }
+
+    pub fn font(&self) -> &SdfFont {
+        &self.font
+    }
+
+    /// The most recent layout passed to [SdfText::set_text]
+    pub fn layout(&self) -> &SdfTextLayout {
+        &self.layout
+    }
+
+    pub fn color(&self) -> [f32; 4] {
+        self.color
+    }
+
+    pub fn texture(&self) -> &WebGlTexture {
+        &self.texture
+    }
+
+    /// Deletes every WebGL object owned by this text
+    pub fn delete(&self) {
+        let gl = &self.gl;
+        gl.delete_program(Some(&self.program));
+        gl.delete_vertex_array(Some(&self.vao));
+        gl.delete_buffer(Some(&self.buffer));
+        gl.delete_texture(Some(&self.texture));
+    }
+}
@@ -0,0 +1,45 @@
+/// Glyph quads produced by [crate::SdfFont::layout], ready to upload to a vertex buffer.
+///
+/// Each glyph is two triangles of `x, y, u, v` vertices, in pixels and atlas texture coordinates.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub struct SdfTextLayout {
+    vertices: Vec<f32>,
+    size: [f32; 2],
+}
+
+impl SdfTextLayout {
+    pub const FLOATS_PER_VERTEX: usize = 4;
+    pub const VERTICES_PER_GLYPH: usize = 6;
+    pub const FLOATS_PER_GLYPH: usize = Self::FLOATS_PER_VERTEX * Self::VERTICES_PER_GLYPH;
+
+    pub fn vertices(&self) -> &[f32] {
+        &self.vertices
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len() / Self::FLOATS_PER_VERTEX
+    }
+
+    pub fn glyph_count(&self) -> usize {
+        self.vertices.len() / Self::FLOATS_PER_GLYPH
+    }
+
+    /// Furthest extent of the text from the layout's origin, in pixels
+    pub fn size(&self) -> [f32; 2] {
+        self.size
+    }
+
+    /// `rect` and `uv` are `[left, top, right, bottom]`
+    pub(crate) fn push_quad(&mut self, rect: [f32; 4], uv: [f32; 4]) {
+        let [left, top, right, bottom] = rect;
+        let [u0, v0, u1, v1] = uv;
+        self.vertices.extend_from_slice(&[
+            left, top, u0, v0, left, bottom, u0, v1, right, top, u1, v0, //
+            right, top, u1, v0, left, bottom, u0, v1, right, bottom, u1, v1,
+        ]);
+    }
+
+    pub(crate) fn extend_to(&mut self, point: [f32; 2]) {
+        self.size = [self.size[0].max(point[0]), self.size[1].max(point[1])];
+    }
+}
@@ -0,0 +1,13 @@
+mod setting_listener_id;
+mod setting_value;
+mod settings_serialization;
+mod settings_store;
+mod settings_subscription;
+mod url_state;
+
+pub use setting_listener_id::*;
+pub use setting_value::*;
+pub(crate) use settings_serialization::*;
+pub use settings_store::*;
+pub(crate) use settings_subscription::*;
+pub use url_state::*;
@@ -0,0 +1,5 @@
+/// Identifies a listener added with [crate::SettingsStore::add_listener] or
+/// [crate::SettingsStore::add_any_listener], so that it can be removed again with
+/// [crate::SettingsStore::remove_listener]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SettingListenerId(pub(crate) u64);
@@ -0,0 +1,83 @@
+/// A persisted parameter value, such as a uniform value or a filter selection
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum SettingValue {
+    Bool(bool),
+    Float(f32),
+    /// Vectors and colors
+    Floats(Vec<f32>),
+    Text(String),
+}
+
+impl SettingValue {
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            SettingValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_f32(&self) -> Option<f32> {
+        match self {
+            SettingValue::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_floats(&self) -> Option<&[f32]> {
+        match self {
+            SettingValue::Floats(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            SettingValue::Text(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Whether both values are the same variant (and, for `Floats`, the same length)
+    pub fn is_same_kind(&self, other: &SettingValue) -> bool {
+        match (self, other) {
+            (SettingValue::Floats(a), SettingValue::Floats(b)) => a.len() == b.len(),
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl From<bool> for SettingValue {
+    fn from(value: bool) -> Self {
+        SettingValue::Bool(value)
+    }
+}
+
+impl From<f32> for SettingValue {
+    fn from(value: f32) -> Self {
+        SettingValue::Float(value)
+    }
+}
+
+impl From<Vec<f32>> for SettingValue {
+    fn from(value: Vec<f32>) -> Self {
+        SettingValue::Floats(value)
+    }
+}
+
+impl<const N: usize> From<[f32; N]> for SettingValue {
+    fn from(value: [f32; N]) -> Self {
+        SettingValue::Floats(value.to_vec())
+    }
+}
+
+impl From<String> for SettingValue {
+    fn from(value: String) -> Self {
+        SettingValue::Text(value)
+    }
+}
+
+impl From<&str> for SettingValue {
+    fn from(value: &str) -> Self {
+        SettingValue::Text(value.to_string())
+    }
+}
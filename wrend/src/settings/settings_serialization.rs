@@ -0,0 +1,117 @@
+use crate::SettingValue;
+use std::collections::BTreeMap;
+
+/// Serializes settings into a line-based format: `name \t kind \t value`
+pub(crate) fn serialize_settings(settings: &BTreeMap<String, SettingValue>) -> String {
+    let mut serialized = String::new();
+    for (name, value) in settings {
+        let (kind, value) = match value {
+            SettingValue::Bool(value) => ("b", value.to_string()),
+            SettingValue::Float(value) => ("f", value.to_string()),
+            SettingValue::Floats(values) => (
+                "v",
+                values
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+            SettingValue::Text(value) => ("s", escape(value)),
+        };
+        serialized.push_str(&escape(name));
+        serialized.push('\t');
+        serialized.push_str(kind);
+        serialized.push('\t');
+        serialized.push_str(&value);
+        serialized.push('\n');
+    }
+    serialized
+}
+
+/// Parses the output of [serialize_settings]. Malformed lines are skipped.
+pub(crate) fn deserialize_settings(serialized: &str) -> BTreeMap<String, SettingValue> {
+    serialized
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, '\t');
+            let name = unescape(parts.next()?);
+            let kind = parts.next()?;
+            let value = parts.next()?;
+            let value = match kind {
+                "b" => SettingValue::Bool(value.parse().ok()?),
+                "f" => SettingValue::Float(value.parse().ok()?),
+                "v" if value.is_empty() => SettingValue::Floats(Vec::new()),
+                "v" => SettingValue::Floats(
+                    value
+                        .split(',')
+                        .map(str::parse)
+                        .collect::<Result<_, _>>()
+                        .ok()?,
+                ),
+                "s" => SettingValue::Text(unescape(value)),
+                _ => return None,
+            };
+            Some((name, value))
+        })
+        .collect()
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('t') => unescaped.push('\t'),
+                Some('n') => unescaped.push('\n'),
+                Some(other) => unescaped.push(other),
+                None => unescaped.push('\\'),
+            }
+        } else {
+            unescaped.push(c);
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{deserialize_settings, serialize_settings};
+    use crate::SettingValue;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn settings_round_trip() {
+        let mut settings = BTreeMap::new();
+        settings.insert(String::from("enabled"), SettingValue::Bool(true));
+        settings.insert(String::from("speed"), SettingValue::Float(0.25));
+        settings.insert(
+            String::from("tint"),
+            SettingValue::Floats(vec![1.0, 0.5, -2.0]),
+        );
+        settings.insert(String::from("empty"), SettingValue::Floats(Vec::new()));
+        settings.insert(
+            String::from("filter\tname"),
+            SettingValue::Text(String::from("a\\b\nc")),
+        );
+
+        assert_eq!(
+            deserialize_settings(&serialize_settings(&settings)),
+            settings
+        );
+    }
+
+    #[test]
+    fn skips_malformed_lines() {
+        let settings = deserialize_settings("speed\tf\tfast\nsize\tf\t2\nbroken\n");
+        assert_eq!(settings.len(), 1);
+        assert_eq!(settings.get("size"), Some(&SettingValue::Float(2.0)));
+    }
+}
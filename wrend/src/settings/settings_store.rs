@@ -0,0 +1,329 @@
+use crate::{
+    deserialize_settings, serialize_settings, SettingListenerId, SettingValue, SettingsError,
+    UniformShouldUpdateCallback,
+};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    fmt::Debug,
+    rc::Rc,
+};
+use web_sys::{Storage, WebGlUniformLocation};
+
+type SettingListener = Rc<dyn Fn(&str, &SettingValue)>;
+
+#[derive(Default)]
+struct SettingsStoreInner {
+    storage_key: String,
+    values: BTreeMap<String, SettingValue>,
+    defaults: BTreeMap<String, SettingValue>,
+    generations: HashMap<String, u64>,
+    listeners: Vec<(SettingListenerId, Option<String>, SettingListener)>,
+    next_listener_id: u64,
+}
+
+/// Stores a registered set of named parameters and persists them to `localStorage`
+/// under a single key, so that user tweaks survive page reloads.
+///
+/// Clones share the same underlying settings.
+#[derive(Clone)]
+pub struct SettingsStore {
+    inner: Rc<RefCell<SettingsStoreInner>>,
+}
+
+impl SettingsStore {
+    /// Creates an empty store that persists to `localStorage` under `storage_key`
+    pub fn new(storage_key: impl Into<String>) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(SettingsStoreInner {
+                storage_key: storage_key.into(),
+                ..Default::default()
+            })),
+        }
+    }
+
+    pub fn storage_key(&self) -> String {
+        self.inner.borrow().storage_key.clone()
+    }
+
+    /// Registers a setting with a default value. Only registered settings are restored or saved.
+    pub fn register(&self, name: impl Into<String>, default: impl Into<SettingValue>) -> &Self {
+        let name = name.into();
+        let default = default.into();
+        let mut inner = self.inner.borrow_mut();
+        inner.defaults.insert(name.clone(), default.clone());
+        inner.values.entry(name).or_insert(default);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<SettingValue> {
+        self.inner.borrow().values.get(name).cloned()
+    }
+
+    pub fn get_bool(&self, name: &str) -> Option<bool> {
+        self.inner.borrow().values.get(name)?.as_bool()
+    }
+
+    pub fn get_f32(&self, name: &str) -> Option<f32> {
+        self.inner.borrow().values.get(name)?.as_f32()
+    }
+
+    pub fn get_floats(&self, name: &str) -> Option<Vec<f32>> {
+        Some(self.inner.borrow().values.get(name)?.as_floats()?.to_vec())
+    }
+
+    pub fn get_text(&self, name: &str) -> Option<String> {
+        Some(self.inner.borrow().values.get(name)?.as_text()?.to_string())
+    }
+
+    /// The value a setting was registered with
+    pub fn default_value(&self, name: &str) -> Option<SettingValue> {
+        self.inner.borrow().defaults.get(name).cloned()
+    }
+
+    /// Names of all registered settings
+    pub fn names(&self) -> Vec<String> {
+        self.inner.borrow().defaults.keys().cloned().collect()
+    }
+
+    /// Updates a registered setting, notifies listeners, and persists the store.
+    ///
+    /// Returns an error if the setting was never registered, or if the value is a different kind
+    /// than the registered default: in either case nothing changes. Once a value is accepted it is
+    /// applied even if the store can't be persisted (e.g. when `localStorage` is full or disabled),
+    /// which is logged as a warning instead. Call [SettingsStore::save] to find out whether the
+    /// current values could be persisted.
+    pub fn set(&self, name: &str, value: impl Into<SettingValue>) -> Result<(), SettingsError> {
+        let value = value.into();
+        {
+            let mut inner = self.inner.borrow_mut();
+            let default = inner
+                .defaults
+                .get(name)
+                .ok_or_else(|| SettingsError::NotRegistered(name.to_string()))?;
+            if !default.is_same_kind(&value) {
+                return Err(SettingsError::WrongKind(name.to_string()));
+            }
+            if inner.values.get(name) == Some(&value) {
+                return Ok(());
+            }
+            inner.values.insert(name.to_string(), value.clone());
+            *inner.generations.entry(name.to_string()).or_default() += 1;
+        }
+
+        self.notify(name, &value);
+        if let Err(err) = self.save() {
+            log::warn!("Setting {name:?} was applied but could not be persisted: {err}");
+        }
+        Ok(())
+    }
+
+    /// Restores a setting to its registered default
+    pub fn reset(&self, name: &str) -> Result<(), SettingsError> {
+        let default = self
+            .inner
+            .borrow()
+            .defaults
+            .get(name)
+            .cloned()
+            .ok_or_else(|| SettingsError::NotRegistered(name.to_string()))?;
+        self.set(name, default)
+    }
+
+    /// Restores every setting to its registered default
+    pub fn reset_all(&self) -> Result<(), SettingsError> {
+        for name in self.names() {
+            self.reset(&name)?;
+        }
+        Ok(())
+    }
+
+    /// Calls `listener` whenever the named setting changes, until it's removed with
+    /// [SettingsStore::remove_listener]
+    pub fn add_listener(
+        &self,
+        name: impl Into<String>,
+        listener: impl Fn(&str, &SettingValue) + 'static,
+    ) -> SettingListenerId {
+        self.insert_listener(Some(name.into()), Rc::new(listener))
+    }
+
+    /// Calls `listener` whenever any setting changes, until it's removed with
+    /// [SettingsStore::remove_listener]
+    pub fn add_any_listener(
+        &self,
+        listener: impl Fn(&str, &SettingValue) + 'static,
+    ) -> SettingListenerId {
+        self.insert_listener(None, Rc::new(listener))
+    }
+
+    /// Stops calling a listener, returning whether it was still registered.
+    ///
+    /// Listeners often capture DOM elements or other handles, so anything shorter-lived than the
+    /// store should remove its listeners once it's done with them.
+    pub fn remove_listener(&self, listener_id: SettingListenerId) -> bool {
+        let mut inner = self.inner.borrow_mut();
+        let len = inner.listeners.len();
+        inner.listeners.retain(|(id, _, _)| *id != listener_id);
+        inner.listeners.len() != len
+    }
+
+    fn insert_listener(
+        &self,
+        name: Option<String>,
+        listener: SettingListener,
+    ) -> SettingListenerId {
+        let mut inner = self.inner.borrow_mut();
+        let listener_id = SettingListenerId(inner.next_listener_id);
+        inner.next_listener_id += 1;
+        inner.listeners.push((listener_id, name, listener));
+        listener_id
+    }
+
+    /// Creates a callback for [crate::UniformLink::set_should_update_callback] that only
+    /// allows the uniform to update when one of the named settings has changed since the
+    /// uniform was last updated (it always updates the first time).
+    pub fn uniform_should_update_callback(
+        &self,
+        setting_names: &[impl AsRef<str>],
+    ) -> UniformShouldUpdateCallback {
+        let store = self.clone();
+        let setting_names: Vec<String> = setting_names
+            .iter()
+            .map(|name| name.as_ref().to_string())
+            .collect();
+        // tracked per uniform location, since a uniform is updated once per program it is used in
+        let last_seen: RefCell<Vec<(WebGlUniformLocation, u64)>> = RefCell::new(Vec::new());
+
+        UniformShouldUpdateCallback::from(move |ctx: &crate::UniformContext| {
+            let generation = store.generation(&setting_names);
+            let mut last_seen = last_seen.borrow_mut();
+            match last_seen
+                .iter_mut()
+                .find(|(location, _)| location == ctx.uniform_location())
+            {
+                Some((_, seen)) if *seen == generation => false,
+                Some((_, seen)) => {
+                    *seen = generation;
+                    true
+                }
+                None => {
+                    last_seen.push((ctx.uniform_location().clone(), generation));
+                    true
+                }
+            }
+        })
+    }
+
+    /// Overwrites registered settings with any values previously saved to `localStorage`.
+    /// Saved values that are unregistered or a different kind than their default are ignored.
+    pub fn restore(&self) -> Result<(), SettingsError> {
+        let storage = Self::local_storage()?;
+        let storage_key = self.storage_key();
+        let serialized = storage
+            .get_item(&storage_key)
+            .map_err(|_| SettingsError::NoStorage)?;
+
+        if let Some(serialized) = serialized {
+            for (name, value) in deserialize_settings(&serialized) {
+                let is_valid = self
+                    .inner
+                    .borrow()
+                    .defaults
+                    .get(&name)
+                    .is_some_and(|default| default.is_same_kind(&value));
+                if is_valid {
+                    self.set(&name, value)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes all registered settings to `localStorage`
+    pub fn save(&self) -> Result<(), SettingsError> {
+        let storage = Self::local_storage()?;
+        let inner = self.inner.borrow();
+        storage
+            .set_item(&inner.storage_key, &serialize_settings(&inner.values))
+            .map_err(|e| SettingsError::Write(format!("{e:?}")))
+    }
+
+    /// Removes the persisted settings from `localStorage` (current values are unaffected)
+    pub fn clear_saved(&self) -> Result<(), SettingsError> {
+        Self::local_storage()?
+            .remove_item(&self.storage_key())
+            .map_err(|e| SettingsError::Write(format!("{e:?}")))
+    }
+
+    fn generation(&self, names: &[String]) -> u64 {
+        let inner = self.inner.borrow();
+        names
+            .iter()
+            .map(|name| inner.generations.get(name).copied().unwrap_or_default())
+            .sum()
+    }
+
+    fn notify(&self, name: &str, value: &SettingValue) {
+        // listeners are cloned out so that they may safely access the store
+        let listeners: Vec<SettingListener> = self
+            .inner
+            .borrow()
+            .listeners
+            .iter()
+            .filter(|(_, listener_name, _)| match listener_name {
+                Some(listener_name) => listener_name == name,
+                None => true,
+            })
+            .map(|(_, _, listener)| Rc::clone(listener))
+            .collect();
+
+        for listener in listeners {
+            listener(name, value);
+        }
+    }
+
+    fn local_storage() -> Result<Storage, SettingsError> {
+        web_sys::window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .ok_or(SettingsError::NoStorage)
+    }
+}
+
+impl Debug for SettingsStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let inner = self.inner.borrow();
+        f.debug_struct("SettingsStore")
+            .field("storage_key", &inner.storage_key)
+            .field("values", &inner.values)
+            .field("listeners", &inner.listeners.len())
+            .finish()
+    }
+}
+
+impl PartialEq for SettingsStore {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for SettingsStore {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removes_listeners_by_id() {
+        let store = SettingsStore::new("test");
+        let speed_listener = store.add_listener("speed", |_, _| {});
+        let any_listener = store.add_any_listener(|_, _| {});
+        assert_ne!(speed_listener, any_listener);
+
+        assert!(store.remove_listener(speed_listener));
+        assert!(!store.remove_listener(speed_listener));
+        assert_eq!(store.inner.borrow().listeners.len(), 1);
+        assert!(store.remove_listener(any_listener));
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{SettingListenerId, SettingValue, SettingsStore};
+
+/// Listeners added to a [SettingsStore] on behalf of something that doesn't live as long as the
+/// store (e.g. a mounted panel), which are removed from the store again when this is dropped
+#[derive(Debug)]
+pub(crate) struct SettingsSubscription {
+    store: SettingsStore,
+    listener_ids: Vec<SettingListenerId>,
+}
+
+impl SettingsSubscription {
+    pub(crate) fn new(store: SettingsStore) -> Self {
+        Self {
+            store,
+            listener_ids: Vec::new(),
+        }
+    }
+
+    /// See [SettingsStore::add_listener]
+    pub(crate) fn add_listener(
+        &mut self,
+        name: impl Into<String>,
+        listener: impl Fn(&str, &SettingValue) + 'static,
+    ) -> &mut Self {
+        let listener_id = self.store.add_listener(name, listener);
+        self.listener_ids.push(listener_id);
+        self
+    }
+
+    /// Removes every listener added so far
+    pub(crate) fn clear(&mut self) -> &mut Self {
+        for listener_id in self.listener_ids.drain(..) {
+            self.store.remove_listener(listener_id);
+        }
+        self
+    }
+}
+
+impl Drop for SettingsSubscription {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
@@ -0,0 +1,305 @@
+use crate::{SettingValue, SettingsError, SettingsStore};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::window;
+
+/// Which part of the page URL a [UrlState] reads and writes
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum UrlStateLocation {
+    /// `#name=value&...`
+    #[default]
+    Hash,
+    /// `?name=value&...`
+    Query,
+}
+
+#[derive(Default)]
+struct PendingUpdate {
+    timeout_id: Option<i32>,
+    callback: Option<Closure<dyn FnMut()>>,
+}
+
+/// Mirrors the settings of a [SettingsStore] (e.g. a seed, a filter selection, or uniform tweaks)
+/// into the page URL, so that a link reproduces the exact configuration it was copied from.
+///
+/// Only settings that differ from their registered defaults are written, which keeps links short.
+/// Text settings are percent-encoded. Since values are parsed according to each setting's default,
+/// settings that need more precision than an `f32` (such as a 64-bit seed) should be registered as text.
+#[derive(Clone)]
+pub struct UrlState {
+    store: SettingsStore,
+    location: UrlStateLocation,
+    pending: Rc<RefCell<PendingUpdate>>,
+}
+
+impl UrlState {
+    pub fn new(store: SettingsStore, location: UrlStateLocation) -> Self {
+        Self {
+            store,
+            location,
+            pending: Default::default(),
+        }
+    }
+
+    pub fn store(&self) -> &SettingsStore {
+        &self.store
+    }
+
+    pub fn location(&self) -> UrlStateLocation {
+        self.location
+    }
+
+    /// Applies any settings found in the current URL to the store.
+    /// Parameters that aren't registered settings, or can't be parsed as one, are ignored.
+    pub fn restore(&self) -> Result<(), SettingsError> {
+        let location = window().ok_or(SettingsError::NoStorage)?.location();
+        let params = match self.location {
+            UrlStateLocation::Hash => location.hash(),
+            UrlStateLocation::Query => location.search(),
+        }
+        .map_err(|err| SettingsError::Write(format!("{err:?}")))?;
+
+        for (name, value) in decode_url_params(&params) {
+            let parsed = self
+                .store
+                .default_value(&name)
+                .and_then(|default| parse_url_value(&default, &value));
+            if let Some(parsed) = parsed {
+                self.store.set(&name, parsed)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The URL parameters (without a leading `#` or `?`) for the store's current values
+    pub fn encode(&self) -> String {
+        let changed: Vec<(String, SettingValue)> = self
+            .store
+            .names()
+            .into_iter()
+            .filter_map(|name| {
+                let value = self.store.get(&name)?;
+                (self.store.default_value(&name).as_ref() != Some(&value)).then_some((name, value))
+            })
+            .collect();
+        encode_url_params(&changed)
+    }
+
+    /// Replaces the current URL with one for the store's current values, without adding a history entry
+    pub fn write(&self) -> Result<(), SettingsError> {
+        let window = window().ok_or(SettingsError::NoStorage)?;
+        let location = window.location();
+        let to_err = |err: JsValue| SettingsError::Write(format!("{err:?}"));
+        let params = self.encode();
+        let prefix = |sigil| {
+            if params.is_empty() {
+                String::new()
+            } else {
+                format!("{sigil}{params}")
+            }
+        };
+        let url = match self.location {
+            UrlStateLocation::Hash => format!(
+                "{}{}{}",
+                location.pathname().map_err(to_err)?,
+                location.search().map_err(to_err)?,
+                prefix('#')
+            ),
+            UrlStateLocation::Query => format!(
+                "{}{}{}",
+                location.pathname().map_err(to_err)?,
+                prefix('?'),
+                location.hash().map_err(to_err)?
+            ),
+        };
+
+        window
+            .history()
+            .map_err(to_err)?
+            .replace_state_with_url(&JsValue::NULL, "", Some(&url))
+            .map_err(to_err)
+    }
+
+    /// Rewrites the URL whenever a setting changes, at most once every `debounce_ms` milliseconds
+    /// of quiet, so that dragging a slider doesn't flood the browser's history API
+    pub fn sync(&self, debounce_ms: i32) -> &Self {
+        let url_state = self.clone();
+        self.store.add_any_listener(move |_, _| {
+            url_state.schedule_write(debounce_ms);
+        });
+        self
+    }
+
+    fn schedule_write(&self, debounce_ms: i32) {
+        let Some(window) = window() else {
+            return;
+        };
+        let mut pending = self.pending.borrow_mut();
+        if let Some(timeout_id) = pending.timeout_id.take() {
+            window.clear_timeout_with_handle(timeout_id);
+        }
+
+        let url_state = self.clone();
+        let callback = Closure::<dyn FnMut()>::new(move || {
+            url_state.pending.borrow_mut().timeout_id = None;
+            if let Err(err) = url_state.write() {
+                log::warn!("Could not write settings to the URL: {err}");
+            }
+        });
+        pending.timeout_id = window
+            .set_timeout_with_callback_and_timeout_and_arguments_0(
+                callback.as_ref().unchecked_ref(),
+                debounce_ms,
+            )
+            .ok();
+        // the previous callback can be dropped now that its timeout was cleared
+        pending.callback = Some(callback);
+    }
+}
+
+impl std::fmt::Debug for UrlState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UrlState")
+            .field("store", &self.store)
+            .field("location", &self.location)
+            .finish()
+    }
+}
+
+impl PartialEq for UrlState {
+    fn eq(&self, other: &Self) -> bool {
+        self.store == other.store && self.location == other.location
+    }
+}
+
+impl Eq for UrlState {}
+
+/// Encodes settings as `name=value&...`: booleans as `1`/`0`, vectors comma-separated
+pub(crate) fn encode_url_params(values: &[(String, SettingValue)]) -> String {
+    values
+        .iter()
+        .map(|(name, value)| {
+            let value = match value {
+                SettingValue::Bool(value) => String::from(if *value { "1" } else { "0" }),
+                SettingValue::Float(value) => value.to_string(),
+                SettingValue::Floats(values) => values
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(","),
+                SettingValue::Text(value) => percent_encode(value),
+            };
+            format!("{}={value}", percent_encode(name))
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Splits `#a=1&b=2` or `?a=1&b=2` into decoded names and (still encoded) values
+pub(crate) fn decode_url_params(params: &str) -> Vec<(String, String)> {
+    params
+        .trim_start_matches(['#', '?'])
+        .split('&')
+        .filter_map(|pair| {
+            let (name, value) = pair.split_once('=')?;
+            Some((percent_decode(name)?, value.to_string()))
+        })
+        .collect()
+}
+
+/// Parses an encoded value as the same kind of setting as `default`
+pub(crate) fn parse_url_value(default: &SettingValue, value: &str) -> Option<SettingValue> {
+    let parsed = match default {
+        SettingValue::Bool(_) => SettingValue::Bool(match value {
+            "1" | "true" => true,
+            "0" | "false" => false,
+            _ => return None,
+        }),
+        SettingValue::Float(_) => SettingValue::Float(value.parse().ok()?),
+        SettingValue::Floats(_) if value.is_empty() => SettingValue::Floats(Vec::new()),
+        SettingValue::Floats(_) => SettingValue::Floats(
+            value
+                .split(',')
+                .map(str::parse)
+                .collect::<Result<_, _>>()
+                .ok()?,
+        ),
+        SettingValue::Text(_) => SettingValue::Text(percent_decode(value)?),
+    };
+    default.is_same_kind(&parsed).then_some(parsed)
+}
+
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+fn percent_decode(value: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut iter = value.bytes();
+    while let Some(byte) = iter.next() {
+        match byte {
+            b'%' => {
+                let hex = [iter.next()?, iter.next()?];
+                bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+            }
+            b'+' => bytes.push(b' '),
+            byte => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_url_params, encode_url_params, parse_url_value};
+    use crate::SettingValue;
+
+    #[test]
+    fn round_trips_settings_through_url_params() {
+        let values = vec![
+            (
+                String::from("seed"),
+                SettingValue::Text(String::from("1234567890123")),
+            ),
+            (
+                String::from("filter name"),
+                SettingValue::Text(String::from("blur & glow")),
+            ),
+            (String::from("speed"), SettingValue::Float(0.25)),
+            (String::from("paused"), SettingValue::Bool(true)),
+            (
+                String::from("color"),
+                SettingValue::Floats(vec![1.0, 0.5, 0.0]),
+            ),
+        ];
+        let encoded = encode_url_params(&values);
+        assert_eq!(
+            encoded,
+            "seed=1234567890123&filter%20name=blur%20%26%20glow&speed=0.25&paused=1&color=1,0.5,0"
+        );
+
+        let decoded: Vec<(String, SettingValue)> = decode_url_params(&format!("#{encoded}"))
+            .into_iter()
+            .zip(&values)
+            .map(|((name, value), (_, default))| (name, parse_url_value(default, &value).unwrap()))
+            .collect();
+        assert_eq!(decoded, values);
+    }
+
+    #[test]
+    fn rejects_values_of_the_wrong_kind() {
+        let color = SettingValue::Floats(vec![0.0; 3]);
+        assert_eq!(parse_url_value(&color, "1,2"), None);
+        assert_eq!(parse_url_value(&SettingValue::Bool(false), "maybe"), None);
+        assert_eq!(parse_url_value(&SettingValue::Float(0.0), "fast"), None);
+    }
+}
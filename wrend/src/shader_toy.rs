@@ -0,0 +1,5 @@
+mod shader_toy_source;
+mod shader_toy_uniform;
+
+pub use shader_toy_source::*;
+pub use shader_toy_uniform::*;
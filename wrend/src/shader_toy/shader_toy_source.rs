@@ -0,0 +1,55 @@
+use crate::ShaderToyUniform;
+
+/// Vertex shader used by ShaderToy programs.
+///
+/// Generates a single fullscreen triangle from `gl_VertexID`, so no buffers or attributes
+/// are required: draw it with `gl.draw_arrays(TRIANGLES, 0, 3)`.
+pub const SHADER_TOY_VERTEX_SHADER: &str = r#"#version 300 es
+void main() {
+    vec2 position = vec2(float((gl_VertexID << 1) & 2), float(gl_VertexID & 2));
+    gl_Position = vec4(position * 2.0 - 1.0, 0.0, 1.0);
+}
+"#;
+
+/// Wraps a ShaderToy-style fragment shader (one that defines `mainImage`) so that it
+/// can be compiled as a standalone WebGL2 fragment shader.
+///
+/// All ShaderToy uniforms are declared, and a `main` function is appended that forwards
+/// `gl_FragCoord` to `mainImage`.
+pub fn wrap_shader_toy_source(shader_toy_src: &str) -> String {
+    let declarations = ShaderToyUniform::ALL
+        .iter()
+        .map(ShaderToyUniform::declaration)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "#version 300 es
+precision highp float;
+precision highp int;
+{declarations}
+out vec4 wrend_frag_color;
+{shader_toy_src}
+void main() {{
+    wrend_frag_color = vec4(0.0, 0.0, 0.0, 1.0);
+    mainImage(wrend_frag_color, gl_FragCoord.xy);
+}}
+"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::wrap_shader_toy_source;
+
+    #[test]
+    fn wraps_main_image() {
+        let src = "void mainImage(out vec4 c, in vec2 p) { c = vec4(iTime); }";
+        let wrapped = wrap_shader_toy_source(src);
+        assert!(wrapped.starts_with("#version 300 es\n"));
+        assert!(wrapped.contains("uniform float iTime;"));
+        assert!(wrapped.contains("uniform sampler2D iChannel3;"));
+        assert!(wrapped.contains(src));
+        assert!(wrapped.contains("mainImage(wrend_frag_color, gl_FragCoord.xy);"));
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{find_identifier, PointerState};
+
+/// Built-in uniforms that ShaderToy-style shaders expect to be available.
+///
+/// See [crate::RendererDataBuilder::add_shader_toy_program]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ShaderToyUniform {
+    /// `vec3 iResolution`: drawing buffer width, height, and pixel aspect ratio
+    Resolution,
+    /// `float iTime`: seconds since the program was built
+    Time,
+    /// `float iTimeDelta`: seconds since the previous update
+    TimeDelta,
+    /// `int iFrame`: number of updates since the program was built
+    Frame,
+    /// `vec4 iMouse`: pointer position in pixels (xy) and last click position (zw),
+    /// with zw negated while the pointer is released
+    Mouse,
+    /// `sampler2D iChannel0`: samples from texture unit 0
+    Channel0,
+    /// `sampler2D iChannel1`: samples from texture unit 1
+    Channel1,
+    /// `sampler2D iChannel2`: samples from texture unit 2
+    Channel2,
+    /// `sampler2D iChannel3`: samples from texture unit 3
+    Channel3,
+}
+
+impl ShaderToyUniform {
+    /// Every ShaderToy uniform, in declaration order
+    pub const ALL: [ShaderToyUniform; 9] = [
+        ShaderToyUniform::Resolution,
+        ShaderToyUniform::Time,
+        ShaderToyUniform::TimeDelta,
+        ShaderToyUniform::Frame,
+        ShaderToyUniform::Mouse,
+        ShaderToyUniform::Channel0,
+        ShaderToyUniform::Channel1,
+        ShaderToyUniform::Channel2,
+        ShaderToyUniform::Channel3,
+    ];
+
+    /// Name of the uniform as it appears in GLSL
+    pub fn name(&self) -> &'static str {
+        match self {
+            ShaderToyUniform::Resolution => "iResolution",
+            ShaderToyUniform::Time => "iTime",
+            ShaderToyUniform::TimeDelta => "iTimeDelta",
+            ShaderToyUniform::Frame => "iFrame",
+            ShaderToyUniform::Mouse => "iMouse",
+            ShaderToyUniform::Channel0 => "iChannel0",
+            ShaderToyUniform::Channel1 => "iChannel1",
+            ShaderToyUniform::Channel2 => "iChannel2",
+            ShaderToyUniform::Channel3 => "iChannel3",
+        }
+    }
+
+    /// GLSL declaration for this uniform
+    pub fn declaration(&self) -> String {
+        let glsl_type = match self {
+            ShaderToyUniform::Resolution => "vec3",
+            ShaderToyUniform::Time | ShaderToyUniform::TimeDelta => "float",
+            ShaderToyUniform::Frame => "int",
+            ShaderToyUniform::Mouse => "vec4",
+            _ => "sampler2D",
+        };
+        format!("uniform {glsl_type} {};", self.name())
+    }
+
+    /// Texture unit sampled by this uniform, if it is one of the `iChannel` samplers
+    pub fn texture_unit(&self) -> Option<i32> {
+        match self {
+            ShaderToyUniform::Channel0 => Some(0),
+            ShaderToyUniform::Channel1 => Some(1),
+            ShaderToyUniform::Channel2 => Some(2),
+            ShaderToyUniform::Channel3 => Some(3),
+            _ => None,
+        }
+    }
+
+    /// Whether the uniform is referenced anywhere in the given source.
+    ///
+    /// Unused uniforms are optimized away by the GLSL compiler, so only referenced
+    /// uniforms can be looked up after linking.
+    pub fn is_used_in(&self, src: &str) -> bool {
+        find_identifier(src, self.name()).is_some()
+    }
+
+    /// Computes the `iMouse` value for a pointer state, given the size of the drawing buffer
+    pub fn mouse_value(pointer_state: &PointerState, width: f32, height: f32) -> [f32; 4] {
+        let [x, y] = pointer_state
+            .position()
+            .map(|[x, y]| [x * width, y * height])
+            .unwrap_or_default();
+        let [click_x, click_y] = pointer_state
+            .down_position()
+            .map(|[x, y]| [x * width, y * height])
+            .unwrap_or_default();
+
+        if pointer_state.is_down() {
+            [x, y, click_x, click_y]
+        } else {
+            [x, y, -click_x, -click_y]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShaderToyUniform;
+
+    #[test]
+    fn detects_whole_identifiers_only() {
+        let src = "vec4 c = texture(iChannel1, uv); float t = iTimeDelta;";
+        assert!(ShaderToyUniform::Channel1.is_used_in(src));
+        assert!(ShaderToyUniform::TimeDelta.is_used_in(src));
+        assert!(!ShaderToyUniform::Time.is_used_in(src));
+        assert!(!ShaderToyUniform::Channel0.is_used_in(src));
+    }
+}
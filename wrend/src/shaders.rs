@@ -1,3 +1,20 @@
-mod shader_type;
+mod create_program;
+mod glsl_identifier;
+mod preprocess_shader_source;
+mod preprocessed_shader_source;
+mod shader_cache;
+#[cfg(feature = "js")]
+mod shader_cache_js;
+mod shader_header_options;
+mod shader_precision;
 
-pub use shader_type::*;
+pub(crate) use create_program::*;
+pub(crate) use glsl_identifier::*;
+
+pub use preprocess_shader_source::*;
+pub use preprocessed_shader_source::*;
+pub use shader_cache::*;
+#[cfg(feature = "js")]
+pub use shader_cache_js::*;
+pub use shader_header_options::*;
+pub use shader_precision::*;
@@ -0,0 +1,76 @@
+use web_sys::{WebGl2RenderingContext, WebGlProgram, WebGlShader};
+
+/// Compiles and links a program outside of the [crate::RendererDataBuilder], for presets that own
+/// their programs. `varyings` are captured with interleaved transform feedback, if any.
+///
+/// Compile and link failures are reported through `compile_error` and `link_error`, with the info log.
+pub(crate) fn create_program<E>(
+    gl: &WebGl2RenderingContext,
+    vertex_shader_src: &str,
+    fragment_shader_src: &str,
+    varyings: &[&str],
+    compile_error: impl Fn(String) -> E,
+    link_error: impl Fn(String) -> E,
+) -> Result<WebGlProgram, E> {
+    const NO_OBJECT: &str = "Call to WebGL2RenderingContext returned None";
+
+    let compile = |shader_type, source: &str| -> Result<WebGlShader, E> {
+        let shader = gl
+            .create_shader(shader_type)
+            .ok_or_else(|| compile_error(NO_OBJECT.to_string()))?;
+        gl.shader_source(&shader, source);
+        gl.compile_shader(&shader);
+        if gl
+            .get_shader_parameter(&shader, WebGl2RenderingContext::COMPILE_STATUS)
+            .as_bool()
+            .unwrap_or(false)
+        {
+            Ok(shader)
+        } else {
+            let info_log = gl.get_shader_info_log(&shader).unwrap_or_default();
+            gl.delete_shader(Some(&shader));
+            Err(compile_error(info_log))
+        }
+    };
+
+    let vertex_shader = compile(WebGl2RenderingContext::VERTEX_SHADER, vertex_shader_src)?;
+    let fragment_shader =
+        match compile(WebGl2RenderingContext::FRAGMENT_SHADER, fragment_shader_src) {
+            Ok(fragment_shader) => fragment_shader,
+            Err(err) => {
+                gl.delete_shader(Some(&vertex_shader));
+                return Err(err);
+            }
+        };
+    let program = gl
+        .create_program()
+        .ok_or_else(|| link_error(NO_OBJECT.to_string()))?;
+    gl.attach_shader(&program, &vertex_shader);
+    gl.attach_shader(&program, &fragment_shader);
+    if !varyings.is_empty() {
+        let varyings: js_sys::Array = varyings
+            .iter()
+            .map(|varying| wasm_bindgen::JsValue::from_str(varying))
+            .collect();
+        gl.transform_feedback_varyings(
+            &program,
+            &varyings,
+            WebGl2RenderingContext::INTERLEAVED_ATTRIBS,
+        );
+    }
+    gl.link_program(&program);
+    gl.delete_shader(Some(&vertex_shader));
+    gl.delete_shader(Some(&fragment_shader));
+
+    if gl
+        .get_program_parameter(&program, WebGl2RenderingContext::LINK_STATUS)
+        .as_bool()
+        .unwrap_or(false)
+    {
+        Ok(program)
+    } else {
+        let info_log = gl.get_program_info_log(&program).unwrap_or_default();
+        gl.delete_program(Some(&program));
+        Err(link_error(info_log))
+    }
+}
@@ -0,0 +1,12 @@
+/// Finds the byte index of the first occurrence of `name` as a whole GLSL identifier
+pub(crate) fn find_identifier(src: &str, name: &str) -> Option<usize> {
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+
+    src.match_indices(name)
+        .find(|(i, _)| {
+            let before = src[..*i].chars().next_back();
+            let after = src[i + name.len()..].chars().next();
+            !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+        })
+        .map(|(i, _)| i)
+}
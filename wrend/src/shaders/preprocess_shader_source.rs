@@ -0,0 +1,175 @@
+use crate::{
+    find_identifier, PreprocessedShaderSource, ShaderHeaderOptions, ShaderSourceError, ShaderType,
+};
+
+const SUPPORTED_VERSION: &str = "300 es";
+
+/// Identifiers that only exist in GLSL ES 1.00 (WebGL1) shaders
+const LEGACY_TOKENS: [&str; 6] = [
+    "gl_FragColor",
+    "gl_FragData",
+    "texture2D",
+    "textureCube",
+    "attribute",
+    "varying",
+];
+
+/// Adjusts a shader source according to the supplied [ShaderHeaderOptions] so that it can be
+/// compiled as a GLSL ES 3.00 shader.
+pub fn preprocess_shader_source(
+    src: &str,
+    shader_type: ShaderType,
+    options: &ShaderHeaderOptions,
+) -> Result<PreprocessedShaderSource, ShaderSourceError> {
+    let lines: Vec<&str> = src.lines().collect();
+    let version_line = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with("#version"));
+
+    if options.reject_legacy_glsl() {
+        match version_line {
+            Some(i) => {
+                let version = lines[i].trim_start()["#version".len()..].trim();
+                if version != SUPPORTED_VERSION {
+                    return Err(ShaderSourceError::UnsupportedVersion {
+                        version: version.to_string(),
+                    });
+                }
+            }
+            None => {
+                if let Some((line, token)) = find_legacy_token(&lines) {
+                    return Err(ShaderSourceError::LegacySyntax {
+                        token: token.to_string(),
+                        line,
+                    });
+                }
+            }
+        }
+    }
+
+    let mut header = Vec::new();
+
+    if version_line.is_none() && options.inject_version() {
+        header.push(format!("#version {SUPPORTED_VERSION}"));
+    }
+
+    if let (ShaderType::FragmentShader, Some(precision)) =
+        (shader_type, options.fragment_precision())
+    {
+        let declares_precision = lines.iter().any(|line| {
+            let words: Vec<&str> = line.split_whitespace().collect();
+            words.first() == Some(&"precision") && words.get(2) == Some(&"float;")
+        });
+        if !declares_precision {
+            header.push(format!("precision {} float;", precision.as_str()));
+        }
+    }
+
+    if header.is_empty() {
+        return Ok(PreprocessedShaderSource::unchanged(src));
+    }
+
+    // injected lines must come directly after the `#version` directive, if there is one
+    let insert_at = version_line.map_or(0, |i| i + 1);
+    let mut output: Vec<&str> = lines[..insert_at].to_vec();
+    output.extend(header.iter().map(String::as_str));
+    output.extend_from_slice(&lines[insert_at..]);
+
+    let line_map = (1..=insert_at)
+        .map(Some)
+        .chain(header.iter().map(|_| None))
+        .chain((insert_at + 1..=lines.len()).map(Some))
+        .collect();
+
+    Ok(PreprocessedShaderSource::new(
+        output.join("\n"),
+        src.to_string(),
+        line_map,
+    ))
+}
+
+/// Returns the 1-based line number and token of the first WebGL1-only identifier, ignoring line comments
+fn find_legacy_token(lines: &[&str]) -> Option<(usize, &'static str)> {
+    lines.iter().enumerate().find_map(|(i, line)| {
+        let code = line.split("//").next().unwrap_or_default();
+        LEGACY_TOKENS
+            .iter()
+            .find(|token| find_identifier(code, token).is_some())
+            .map(|token| (i + 1, *token))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::preprocess_shader_source;
+    use crate::{ShaderHeaderOptions, ShaderSourceError, ShaderType};
+
+    #[test]
+    fn injects_version_and_precision() {
+        let src = "out vec4 color;\nvoid main() { color = vec4(1.0); }";
+        let output =
+            preprocess_shader_source(src, ShaderType::FragmentShader, &Default::default()).unwrap();
+        assert_eq!(
+            output.source(),
+            "#version 300 es\nprecision highp float;\nout vec4 color;\nvoid main() { color = vec4(1.0); }"
+        );
+        assert_eq!(output.original_line(2), None);
+        assert_eq!(output.original_line(3), Some(1));
+    }
+
+    #[test]
+    fn inserts_precision_after_existing_version() {
+        let src = "#version 300 es\nout vec4 color;";
+        let output =
+            preprocess_shader_source(src, ShaderType::FragmentShader, &Default::default()).unwrap();
+        assert_eq!(
+            output.source(),
+            "#version 300 es\nprecision highp float;\nout vec4 color;"
+        );
+    }
+
+    #[test]
+    fn leaves_complete_sources_untouched() {
+        let src = "#version 300 es\nprecision mediump float;\nout vec4 color;";
+        let output =
+            preprocess_shader_source(src, ShaderType::FragmentShader, &Default::default()).unwrap();
+        assert_eq!(output.source(), src);
+
+        let src = "#version 300 es\nin vec2 a_position;";
+        let output =
+            preprocess_shader_source(src, ShaderType::VertexShader, &Default::default()).unwrap();
+        assert_eq!(output.source(), src);
+    }
+
+    #[test]
+    fn rejects_legacy_sources() {
+        let src = "#version 100\nvoid main() {}";
+        assert_eq!(
+            preprocess_shader_source(src, ShaderType::VertexShader, &Default::default()),
+            Err(ShaderSourceError::UnsupportedVersion {
+                version: "100".to_string()
+            })
+        );
+
+        let src = "// varying is fine in comments\nvoid main() { gl_FragColor = vec4(1.0); }";
+        assert_eq!(
+            preprocess_shader_source(src, ShaderType::FragmentShader, &Default::default()),
+            Err(ShaderSourceError::LegacySyntax {
+                token: "gl_FragColor".to_string(),
+                line: 2
+            })
+        );
+    }
+
+    #[test]
+    fn can_be_disabled() {
+        let src = "#version 100\nvoid main() { gl_FragColor = vec4(1.0); }";
+        let output = preprocess_shader_source(
+            src,
+            ShaderType::FragmentShader,
+            &ShaderHeaderOptions::disabled(),
+        )
+        .unwrap();
+        assert_eq!(output.source(), src);
+    }
+}
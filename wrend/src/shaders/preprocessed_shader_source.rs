@@ -0,0 +1,139 @@
+/// Output of [crate::preprocess_shader_source]: the source that is handed to WebGL, along with
+/// enough information to translate compiler diagnostics back to the source the user wrote.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct PreprocessedShaderSource {
+    source: String,
+    original_source: String,
+    line_map: Vec<Option<usize>>,
+}
+
+impl PreprocessedShaderSource {
+    /// Creates a new preprocessed source.
+    ///
+    /// `line_map` holds, for each line of `source`, the 1-based line number it came from in
+    /// `original_source`, or `None` if the line was injected.
+    pub fn new(source: String, original_source: String, line_map: Vec<Option<usize>>) -> Self {
+        Self {
+            source,
+            original_source,
+            line_map,
+        }
+    }
+
+    /// Creates a preprocessed source that is identical to the original
+    pub fn unchanged(source: &str) -> Self {
+        let line_map = (1..=source.lines().count()).map(Some).collect();
+        Self::new(source.to_string(), source.to_string(), line_map)
+    }
+
+    /// The source that should be compiled
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// The source before preprocessing
+    pub fn original_source(&self) -> &str {
+        &self.original_source
+    }
+
+    /// Translates a 1-based line number in the preprocessed source to the original source.
+    ///
+    /// Returns `None` for injected lines.
+    pub fn original_line(&self, line: usize) -> Option<usize> {
+        line.checked_sub(1)
+            .and_then(|i| self.line_map.get(i))
+            .copied()
+            .flatten()
+    }
+
+    /// Rewrites the line numbers in a shader info log (e.g. `ERROR: 0:12: ...`) so that they
+    /// refer to the original source.
+    pub fn map_info_log(&self, info_log: &str) -> String {
+        info_log
+            .lines()
+            .map(|log_line| match parse_log_line(log_line) {
+                Some((prefix, line, rest)) => match self.original_line(line) {
+                    Some(original_line) => format!("{prefix}{original_line}{rest}"),
+                    None => format!("{prefix}{line} (injected by wrend){rest}"),
+                },
+                None => log_line.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Builds an annotated excerpt of the original source around every line referenced in the info log
+    pub fn source_context(&self, info_log: &str) -> String {
+        const CONTEXT_LINES: usize = 2;
+        let original_lines: Vec<&str> = self.original_source.lines().collect();
+
+        let mut error_lines: Vec<usize> = info_log
+            .lines()
+            .filter_map(parse_log_line)
+            .filter_map(|(_, line, _)| self.original_line(line))
+            .collect();
+        error_lines.sort_unstable();
+        error_lines.dedup();
+
+        error_lines
+            .iter()
+            .map(|&error_line| {
+                let first = error_line.saturating_sub(CONTEXT_LINES).max(1);
+                let last = (error_line + CONTEXT_LINES).min(original_lines.len());
+                (first..=last)
+                    .map(|line| {
+                        let marker = if line == error_line { ">" } else { " " };
+                        format!("{marker} {line:>4} | {}", original_lines[line - 1])
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .collect::<Vec<_>>()
+            .join("\n...\n")
+    }
+}
+
+/// Splits a log line of the form `ERROR: 0:12: message` into (`ERROR: 0:`, 12, `: message`)
+fn parse_log_line(log_line: &str) -> Option<(&str, usize, &str)> {
+    let (severity, rest) = log_line.split_once(": ")?;
+    if severity != "ERROR" && severity != "WARNING" {
+        return None;
+    }
+    let (_source_string, rest) = rest.split_once(':')?;
+    let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+    let line = rest[..digits].parse().ok()?;
+    let prefix_len = log_line.len() - rest.len();
+
+    Some((&log_line[..prefix_len], line, &rest[digits..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PreprocessedShaderSource;
+
+    fn preprocessed() -> PreprocessedShaderSource {
+        PreprocessedShaderSource::new(
+            "#version 300 es\nprecision highp float;\nout vec4 c;\nvoid main() { c = x; }".into(),
+            "#version 300 es\nout vec4 c;\nvoid main() { c = x; }".into(),
+            vec![Some(1), None, Some(2), Some(3)],
+        )
+    }
+
+    #[test]
+    fn maps_info_log_lines() {
+        let log = "ERROR: 0:4: 'x' : undeclared identifier\nERROR: 0:2: 'oops' : syntax error";
+        assert_eq!(
+            preprocessed().map_info_log(log),
+            "ERROR: 0:3: 'x' : undeclared identifier\nERROR: 0:2 (injected by wrend): 'oops' : syntax error"
+        );
+    }
+
+    #[test]
+    fn annotates_source_context() {
+        let log = "ERROR: 0:4: 'x' : undeclared identifier";
+        assert_eq!(
+            preprocessed().source_context(log),
+            "     1 | #version 300 es\n     2 | out vec4 c;\n>    3 | void main() { c = x; }"
+        );
+    }
+}
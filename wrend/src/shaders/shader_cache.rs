@@ -0,0 +1,218 @@
+use crate::ShaderType;
+use std::{
+    cell::RefCell,
+    collections::{BTreeSet, HashMap},
+    fmt::Debug,
+    rc::Rc,
+};
+use web_sys::{window, Storage, WebGl2RenderingContext, WebGlProgram, WebGlShader};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Stable 64-bit FNV-1a hash, so that hashes stay valid across page reloads
+pub(crate) fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
+
+#[derive(Default)]
+struct ShaderCacheInner {
+    storage_key: Option<String>,
+    shaders: HashMap<u64, WebGlShader>,
+    programs: HashMap<u64, WebGlProgram>,
+    compiled: BTreeSet<u64>,
+}
+
+/// Remembers compiled shaders and linked programs by a hash of their sources, so that rebuilding
+/// (e.g. on hot reload, or when switching between demos that share shaders) doesn't recompile
+/// sources that are identical to ones compiled before.
+///
+/// The cache owns the shaders and programs it hands out: since the same objects may be in use by
+/// several renderers at once, rebuilding a renderer never deletes them, even when the new build no
+/// longer uses them. WebGL objects can only be reused within the same context, and only while they
+/// haven't been deleted (e.g. by losing the context): cached objects that no longer exist are simply
+/// compiled again. A cache created with
+/// [ShaderCache::with_session_storage] additionally remembers which sources compiled successfully
+/// across page reloads, and skips the compile status query for them (which would otherwise wait
+/// for the driver to finish compiling).
+///
+/// Set one with [crate::RendererDataBuilder::set_shader_cache]. Clones share the same cache.
+#[derive(Clone, Default)]
+pub struct ShaderCache {
+    inner: Rc<RefCell<ShaderCacheInner>>,
+}
+
+impl ShaderCache {
+    /// Creates a cache that only lasts as long as the page
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a cache that also persists which sources compiled successfully to `sessionStorage`
+    pub fn with_session_storage(storage_key: impl Into<String>) -> Self {
+        let storage_key = storage_key.into();
+        let compiled = session_storage()
+            .and_then(|storage| storage.get_item(&storage_key).ok().flatten())
+            .map(|saved| deserialize_hashes(&saved))
+            .unwrap_or_default();
+
+        Self {
+            inner: Rc::new(RefCell::new(ShaderCacheInner {
+                storage_key: Some(storage_key),
+                compiled,
+                ..Default::default()
+            })),
+        }
+    }
+
+    /// Hash of a (preprocessed) shader source, which identifies it in the cache
+    pub fn source_hash(shader_type: ShaderType, source: &str) -> u64 {
+        let shader_type: u32 = shader_type.into();
+        fnv1a(
+            fnv1a(FNV_OFFSET_BASIS, &shader_type.to_le_bytes()),
+            source.as_bytes(),
+        )
+    }
+
+    /// Forgets every cached object and persisted hash.
+    ///
+    /// Cached objects are not deleted, since renderers built with this cache may still be using them.
+    pub fn clear(&self) {
+        let storage_key = {
+            let mut inner = self.inner.borrow_mut();
+            inner.shaders.clear();
+            inner.programs.clear();
+            inner.compiled.clear();
+            inner.storage_key.clone()
+        };
+        if let (Some(storage), Some(storage_key)) = (session_storage(), storage_key) {
+            let _ = storage.remove_item(&storage_key);
+        }
+    }
+
+    pub(crate) fn shader(&self, gl: &WebGl2RenderingContext, hash: u64) -> Option<WebGlShader> {
+        self.inner
+            .borrow()
+            .shaders
+            .get(&hash)
+            .filter(|shader| gl.is_shader(Some(shader)))
+            .cloned()
+    }
+
+    pub(crate) fn program(&self, gl: &WebGl2RenderingContext, hash: u64) -> Option<WebGlProgram> {
+        self.inner
+            .borrow()
+            .programs
+            .get(&hash)
+            .filter(|program| gl.is_program(Some(program)))
+            .cloned()
+    }
+
+    /// Whether this shader was handed out by the cache (and so must not be deleted by a renderer)
+    pub(crate) fn owns_shader(&self, shader: &WebGlShader) -> bool {
+        self.inner
+            .borrow()
+            .shaders
+            .values()
+            .any(|cached| cached == shader)
+    }
+
+    /// Whether this program was handed out by the cache (and so must not be deleted by a renderer)
+    pub(crate) fn owns_program(&self, program: &WebGlProgram) -> bool {
+        self.inner
+            .borrow()
+            .programs
+            .values()
+            .any(|cached| cached == program)
+    }
+
+    /// Whether a source with this hash is known to compile
+    pub(crate) fn is_compiled(&self, hash: u64) -> bool {
+        self.inner.borrow().compiled.contains(&hash)
+    }
+
+    pub(crate) fn insert_shader(&self, hash: u64, shader: WebGlShader) {
+        let mut inner = self.inner.borrow_mut();
+        inner.shaders.insert(hash, shader);
+        if inner.compiled.insert(hash) {
+            if let (Some(storage), Some(storage_key)) = (session_storage(), &inner.storage_key) {
+                let _ = storage.set_item(storage_key, &serialize_hashes(&inner.compiled));
+            }
+        }
+    }
+
+    pub(crate) fn insert_program(&self, hash: u64, program: WebGlProgram) {
+        self.inner.borrow_mut().programs.insert(hash, program);
+    }
+}
+
+impl Debug for ShaderCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let inner = self.inner.borrow();
+        f.debug_struct("ShaderCache")
+            .field("storage_key", &inner.storage_key)
+            .field("shaders", &inner.shaders.len())
+            .field("programs", &inner.programs.len())
+            .field("compiled", &inner.compiled.len())
+            .finish()
+    }
+}
+
+impl PartialEq for ShaderCache {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for ShaderCache {}
+
+fn session_storage() -> Option<Storage> {
+    window()?.session_storage().ok().flatten()
+}
+
+fn serialize_hashes(hashes: &BTreeSet<u64>) -> String {
+    hashes
+        .iter()
+        .map(|hash| format!("{hash:016x}"))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn deserialize_hashes(serialized: &str) -> BTreeSet<u64> {
+    serialized
+        .split(',')
+        .filter_map(|hash| u64::from_str_radix(hash, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{deserialize_hashes, serialize_hashes, ShaderCache};
+    use crate::ShaderType;
+
+    #[test]
+    fn source_hash_is_stable_and_depends_on_type() {
+        let source = "void main() {}";
+        assert_eq!(
+            ShaderCache::source_hash(ShaderType::VertexShader, source),
+            ShaderCache::source_hash(ShaderType::VertexShader, source)
+        );
+        assert_ne!(
+            ShaderCache::source_hash(ShaderType::VertexShader, source),
+            ShaderCache::source_hash(ShaderType::FragmentShader, source)
+        );
+        assert_ne!(
+            ShaderCache::source_hash(ShaderType::VertexShader, source),
+            ShaderCache::source_hash(ShaderType::VertexShader, "void main() { }")
+        );
+    }
+
+    #[test]
+    fn persisted_hashes_round_trip() {
+        let hashes = [0, 42, u64::MAX].into_iter().collect();
+        assert_eq!(deserialize_hashes(&serialize_hashes(&hashes)), hashes);
+        assert!(deserialize_hashes("").is_empty());
+    }
+}
@@ -0,0 +1,50 @@
+use std::ops::{Deref, DerefMut};
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::ShaderCache;
+
+#[wasm_bindgen(inspectable, js_name = ShaderCache)]
+pub struct ShaderCacheJs(ShaderCache);
+
+#[wasm_bindgen(js_class = ShaderCache)]
+impl ShaderCacheJs {
+    /// With a `storageKey`, also remembers which sources compiled successfully in `sessionStorage`
+    #[wasm_bindgen(constructor)]
+    pub fn new(storage_key: Option<String>) -> Self {
+        Self(match storage_key {
+            Some(storage_key) => ShaderCache::with_session_storage(storage_key),
+            None => ShaderCache::new(),
+        })
+    }
+
+    pub fn clear(&self) {
+        self.deref().clear();
+    }
+}
+
+impl ShaderCacheJs {
+    pub fn into_inner(self) -> ShaderCache {
+        self.0
+    }
+}
+
+impl Deref for ShaderCacheJs {
+    type Target = ShaderCache;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ShaderCacheJs {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<ShaderCache> for ShaderCacheJs {
+    fn from(shader_cache: ShaderCache) -> Self {
+        Self(shader_cache)
+    }
+}
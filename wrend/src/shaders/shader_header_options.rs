@@ -0,0 +1,70 @@
+use crate::ShaderPrecision;
+
+/// Controls how shader sources are adjusted before they are compiled.
+///
+/// By default, `#version 300 es` is prepended to any source that does not declare a version,
+/// fragment shaders without a default float precision get `precision highp float;`, and
+/// WebGL1-style sources are rejected with a descriptive error rather than an opaque compile error.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ShaderHeaderOptions {
+    inject_version: bool,
+    fragment_precision: Option<ShaderPrecision>,
+    reject_legacy_glsl: bool,
+}
+
+impl ShaderHeaderOptions {
+    /// Options that leave shader sources completely untouched
+    pub fn disabled() -> Self {
+        Self {
+            inject_version: false,
+            fragment_precision: None,
+            reject_legacy_glsl: false,
+        }
+    }
+
+    /// Whether `#version 300 es` is prepended to sources that lack a `#version` directive
+    pub fn inject_version(&self) -> bool {
+        self.inject_version
+    }
+
+    /// See [ShaderHeaderOptions::inject_version]
+    pub fn set_inject_version(&mut self, inject_version: bool) -> &mut Self {
+        self.inject_version = inject_version;
+        self
+    }
+
+    /// Default float precision injected into fragment shaders that do not declare one
+    pub fn fragment_precision(&self) -> Option<ShaderPrecision> {
+        self.fragment_precision
+    }
+
+    /// See [ShaderHeaderOptions::fragment_precision]
+    pub fn set_fragment_precision(
+        &mut self,
+        fragment_precision: Option<ShaderPrecision>,
+    ) -> &mut Self {
+        self.fragment_precision = fragment_precision;
+        self
+    }
+
+    /// Whether `#version 100` sources and WebGL1-only syntax are rejected before compilation
+    pub fn reject_legacy_glsl(&self) -> bool {
+        self.reject_legacy_glsl
+    }
+
+    /// See [ShaderHeaderOptions::reject_legacy_glsl]
+    pub fn set_reject_legacy_glsl(&mut self, reject_legacy_glsl: bool) -> &mut Self {
+        self.reject_legacy_glsl = reject_legacy_glsl;
+        self
+    }
+}
+
+impl Default for ShaderHeaderOptions {
+    fn default() -> Self {
+        Self {
+            inject_version: true,
+            fragment_precision: Some(ShaderPrecision::High),
+            reject_legacy_glsl: true,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+/// GLSL precision qualifiers
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum ShaderPrecision {
+    /// `lowp`
+    Low,
+    /// `mediump`
+    Medium,
+    /// `highp`
+    #[default]
+    High,
+}
+
+impl ShaderPrecision {
+    /// The qualifier as it appears in GLSL
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ShaderPrecision::Low => "lowp",
+            ShaderPrecision::Medium => "mediump",
+            ShaderPrecision::High => "highp",
+        }
+    }
+}
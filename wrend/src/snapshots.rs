@@ -0,0 +1,29 @@
+mod buffer_snapshot;
+mod snapshot_key;
+mod snapshot_pixel_format;
+#[cfg(feature = "idb")]
+mod snapshot_store;
+#[cfg(all(feature = "idb", feature = "js"))]
+mod snapshot_store_js;
+mod state_ids;
+#[cfg(feature = "js")]
+mod state_ids_js;
+mod state_snapshot;
+#[cfg(feature = "js")]
+mod state_snapshot_js;
+mod texture_snapshot;
+
+pub use buffer_snapshot::*;
+pub use snapshot_key::*;
+pub use snapshot_pixel_format::*;
+#[cfg(feature = "idb")]
+pub use snapshot_store::*;
+#[cfg(all(feature = "idb", feature = "js"))]
+pub use snapshot_store_js::*;
+pub use state_ids::*;
+#[cfg(feature = "js")]
+pub use state_ids_js::*;
+pub use state_snapshot::*;
+#[cfg(feature = "js")]
+pub use state_snapshot_js::*;
+pub use texture_snapshot::*;
@@ -0,0 +1,53 @@
+use js_sys::Uint8Array;
+use web_sys::{WebGl2RenderingContext, WebGlBuffer};
+
+type Gl = WebGl2RenderingContext;
+
+/// The full contents of a buffer, saved by name
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BufferSnapshot {
+    name: String,
+    data: Vec<u8>,
+}
+
+impl BufferSnapshot {
+    pub fn new(name: impl Into<String>, data: Vec<u8>) -> Self {
+        Self {
+            name: name.into(),
+            data,
+        }
+    }
+
+    /// Copies the whole buffer back to the CPU (stalls until the GPU has caught up)
+    pub(crate) fn read(gl: &Gl, name: String, buffer: &WebGlBuffer) -> Self {
+        gl.bind_buffer(Gl::COPY_READ_BUFFER, Some(buffer));
+        let size = gl
+            .get_buffer_parameter(Gl::COPY_READ_BUFFER, Gl::BUFFER_SIZE)
+            .as_f64()
+            .unwrap_or_default() as u32;
+        let data = Uint8Array::new_with_length(size);
+        gl.get_buffer_sub_data_with_i32_and_array_buffer_view(Gl::COPY_READ_BUFFER, 0, &data);
+        gl.bind_buffer(Gl::COPY_READ_BUFFER, None);
+
+        Self::new(name, data.to_vec())
+    }
+
+    /// Replaces the buffer's contents (and size), keeping its usage hint
+    pub(crate) fn write(&self, gl: &Gl, buffer: &WebGlBuffer) {
+        gl.bind_buffer(Gl::COPY_WRITE_BUFFER, Some(buffer));
+        let usage = gl
+            .get_buffer_parameter(Gl::COPY_WRITE_BUFFER, Gl::BUFFER_USAGE)
+            .as_f64()
+            .map_or(Gl::DYNAMIC_COPY, |usage| usage as u32);
+        gl.buffer_data_with_u8_array(Gl::COPY_WRITE_BUFFER, &self.data, usage);
+        gl.bind_buffer(Gl::COPY_WRITE_BUFFER, None);
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
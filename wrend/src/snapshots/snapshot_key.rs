@@ -0,0 +1,50 @@
+/// Names a [crate::StateSnapshot] in a [crate::SnapshotStore].
+///
+/// Bump the version whenever the shape of the saved state changes (e.g. a texture is resized or a
+/// buffer gains a field): snapshots saved under other versions are then never loaded.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SnapshotKey {
+    name: String,
+    version: u32,
+}
+
+impl SnapshotKey {
+    pub fn new(name: impl Into<String>, version: u32) -> Self {
+        Self {
+            name: name.into(),
+            version,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// The key the snapshot is stored under, e.g. `"game-of-life@3"`
+    pub fn storage_key(&self) -> String {
+        format!("{}@{}", self.name, self.version)
+    }
+
+    pub fn from_storage_key(storage_key: &str) -> Option<Self> {
+        let (name, version) = storage_key.rsplit_once('@')?;
+        Some(Self::new(name, version.parse().ok()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SnapshotKey;
+
+    #[test]
+    fn round_trips_through_storage_key() {
+        let key = SnapshotKey::new("user@example/life", 3);
+        assert_eq!(key.storage_key(), "user@example/life@3");
+        assert_eq!(SnapshotKey::from_storage_key(&key.storage_key()), Some(key));
+        assert_eq!(SnapshotKey::from_storage_key("life"), None);
+        assert_eq!(SnapshotKey::from_storage_key("life@latest"), None);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::StateSnapshotError;
+use js_sys::{ArrayBuffer, Float32Array, Int32Array, Object, Uint32Array, Uint8Array};
+use web_sys::WebGl2RenderingContext;
+
+type Gl = WebGl2RenderingContext;
+
+/// How the pixels of a [crate::TextureSnapshot] are stored: always four channels, with the
+/// channel type determined by the texture's component type when it was read back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SnapshotPixelFormat {
+    /// Normalized formats (e.g. `RGBA8`), read as `RGBA` / `UNSIGNED_BYTE`
+    Rgba8,
+    /// Float formats (e.g. `RGBA32F`, `R16F`), read as `RGBA` / `FLOAT`
+    Rgba32F,
+    /// Signed integer formats, read as `RGBA_INTEGER` / `INT`
+    Rgba32I,
+    /// Unsigned integer formats, read as `RGBA_INTEGER` / `UNSIGNED_INT`
+    Rgba32UI,
+}
+
+impl SnapshotPixelFormat {
+    /// Picks the format to read a color attachment with from its `FRAMEBUFFER_ATTACHMENT_COMPONENT_TYPE`
+    pub fn from_component_type(component_type: u32) -> Result<Self, StateSnapshotError> {
+        match component_type {
+            Gl::UNSIGNED_NORMALIZED => Ok(Self::Rgba8),
+            Gl::FLOAT => Ok(Self::Rgba32F),
+            Gl::INT => Ok(Self::Rgba32I),
+            Gl::UNSIGNED_INT => Ok(Self::Rgba32UI),
+            other => Err(StateSnapshotError::UnsupportedFormat(other)),
+        }
+    }
+
+    pub fn bytes_per_pixel(&self) -> usize {
+        match self {
+            Self::Rgba8 => 4,
+            Self::Rgba32F | Self::Rgba32I | Self::Rgba32UI => 16,
+        }
+    }
+
+    /// Internal format of a texture that can hold these pixels unchanged
+    pub fn internal_format(&self) -> u32 {
+        match self {
+            Self::Rgba8 => Gl::RGBA8,
+            Self::Rgba32F => Gl::RGBA32F,
+            Self::Rgba32I => Gl::RGBA32I,
+            Self::Rgba32UI => Gl::RGBA32UI,
+        }
+    }
+
+    pub fn format(&self) -> u32 {
+        match self {
+            Self::Rgba8 | Self::Rgba32F => Gl::RGBA,
+            Self::Rgba32I | Self::Rgba32UI => Gl::RGBA_INTEGER,
+        }
+    }
+
+    pub fn data_type(&self) -> u32 {
+        match self {
+            Self::Rgba8 => Gl::UNSIGNED_BYTE,
+            Self::Rgba32F => Gl::FLOAT,
+            Self::Rgba32I => Gl::INT,
+            Self::Rgba32UI => Gl::UNSIGNED_INT,
+        }
+    }
+
+    /// WebGL requires the typed array passed to `readPixels`/`texImage2D` to match the data type
+    pub(crate) fn typed_view(&self, buffer: &ArrayBuffer) -> Object {
+        match self {
+            Self::Rgba8 => Uint8Array::new(buffer).into(),
+            Self::Rgba32F => Float32Array::new(buffer).into(),
+            Self::Rgba32I => Int32Array::new(buffer).into(),
+            Self::Rgba32UI => Uint32Array::new(buffer).into(),
+        }
+    }
+
+    pub(crate) fn to_byte(self) -> u8 {
+        match self {
+            Self::Rgba8 => 0,
+            Self::Rgba32F => 1,
+            Self::Rgba32I => 2,
+            Self::Rgba32UI => 3,
+        }
+    }
+
+    pub(crate) fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Self::Rgba8),
+            1 => Some(Self::Rgba32F),
+            2 => Some(Self::Rgba32I),
+            3 => Some(Self::Rgba32UI),
+            _ => None,
+        }
+    }
+}
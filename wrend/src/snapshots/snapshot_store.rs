@@ -0,0 +1,166 @@
+use crate::{SnapshotKey, SnapshotStoreError, StateSnapshot};
+use js_sys::{Array, Function, Promise, Uint8Array};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{window, IdbDatabase, IdbRequest, IdbTransaction, IdbTransactionMode};
+
+const OBJECT_STORE: &str = "snapshots";
+
+/// Persists [StateSnapshot]s in IndexedDB, which (unlike `localStorage`) can hold texture-sized
+/// data, so that simulation state survives page reloads.
+///
+/// Snapshots are stored under a [SnapshotKey], so that state saved by an older version of an app
+/// is ignored rather than restored into resources of a different shape.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SnapshotStore {
+    database_name: String,
+}
+
+impl SnapshotStore {
+    pub fn new(database_name: impl Into<String>) -> Self {
+        Self {
+            database_name: database_name.into(),
+        }
+    }
+
+    pub fn database_name(&self) -> &str {
+        &self.database_name
+    }
+
+    /// Saves a snapshot, replacing any previously saved under the same key
+    pub async fn save(
+        &self,
+        key: &SnapshotKey,
+        snapshot: &StateSnapshot,
+    ) -> Result<(), SnapshotStoreError> {
+        let bytes = Uint8Array::from(snapshot.to_bytes().as_slice());
+        let (transaction, store) = self.transaction(IdbTransactionMode::Readwrite).await?;
+        store
+            .put_with_key(&bytes, &JsValue::from_str(&key.storage_key()))
+            .map_err(SnapshotStoreError::from_js)?;
+        complete(&transaction).await
+    }
+
+    /// Loads the snapshot saved under `key`, if there is one
+    pub async fn load(
+        &self,
+        key: &SnapshotKey,
+    ) -> Result<Option<StateSnapshot>, SnapshotStoreError> {
+        let (_, store) = self.transaction(IdbTransactionMode::Readonly).await?;
+        let request = store
+            .get(&JsValue::from_str(&key.storage_key()))
+            .map_err(SnapshotStoreError::from_js)?;
+        let value = result(&request).await?;
+        if value.is_undefined() {
+            return Ok(None);
+        }
+
+        let bytes: Uint8Array = value.dyn_into().map_err(SnapshotStoreError::from_js)?;
+        Ok(Some(StateSnapshot::from_bytes(&bytes.to_vec())?))
+    }
+
+    pub async fn delete(&self, key: &SnapshotKey) -> Result<(), SnapshotStoreError> {
+        let (transaction, store) = self.transaction(IdbTransactionMode::Readwrite).await?;
+        store
+            .delete(&JsValue::from_str(&key.storage_key()))
+            .map_err(SnapshotStoreError::from_js)?;
+        complete(&transaction).await
+    }
+
+    /// Every key a snapshot has been saved under
+    pub async fn keys(&self) -> Result<Vec<SnapshotKey>, SnapshotStoreError> {
+        let (_, store) = self.transaction(IdbTransactionMode::Readonly).await?;
+        let request = store.get_all_keys().map_err(SnapshotStoreError::from_js)?;
+        let keys: Array = result(&request).await?.unchecked_into();
+
+        Ok(keys
+            .iter()
+            .filter_map(|key| key.as_string())
+            .filter_map(|key| SnapshotKey::from_storage_key(&key))
+            .collect())
+    }
+
+    /// Deletes snapshots saved under the same name as `key`, but a different version
+    pub async fn delete_other_versions(&self, key: &SnapshotKey) -> Result<(), SnapshotStoreError> {
+        for other in self.keys().await? {
+            if other.name() == key.name() && other.version() != key.version() {
+                self.delete(&other).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn open(&self) -> Result<IdbDatabase, SnapshotStoreError> {
+        let factory = window()
+            .and_then(|window| window.indexed_db().ok().flatten())
+            .ok_or(SnapshotStoreError::NoIndexedDb)?;
+        let request = factory
+            .open_with_u32(&self.database_name, 1)
+            .map_err(SnapshotStoreError::from_js)?;
+
+        // kept alive until the database has opened
+        let database_request = request.clone();
+        let on_upgrade_needed = Closure::<dyn FnMut()>::new(move || {
+            if let Ok(database) = database_request.result() {
+                let database: IdbDatabase = database.unchecked_into();
+                if !database.object_store_names().contains(OBJECT_STORE) {
+                    let _ = database.create_object_store(OBJECT_STORE);
+                }
+            }
+        });
+        request.set_onupgradeneeded(Some(on_upgrade_needed.as_ref().unchecked_ref()));
+        let database = result(&request).await;
+        request.set_onupgradeneeded(None);
+
+        Ok(database?.unchecked_into())
+    }
+
+    async fn transaction(
+        &self,
+        mode: IdbTransactionMode,
+    ) -> Result<(IdbTransaction, web_sys::IdbObjectStore), SnapshotStoreError> {
+        let database = self.open().await?;
+        let transaction = database
+            .transaction_with_str_and_mode(OBJECT_STORE, mode)
+            .map_err(SnapshotStoreError::from_js)?;
+        let store = transaction
+            .object_store(OBJECT_STORE)
+            .map_err(SnapshotStoreError::from_js)?;
+        // the connection closes once the transaction has finished
+        database.close();
+
+        Ok((transaction, store))
+    }
+}
+
+/// Waits for a request to succeed and returns its result
+async fn result(request: &IdbRequest) -> Result<JsValue, SnapshotStoreError> {
+    let promise = Promise::new(&mut |resolve: Function, reject: Function| {
+        request.set_onsuccess(Some(&resolve));
+        request.set_onerror(Some(&reject));
+    });
+    JsFuture::from(promise).await.map_err(|_| {
+        SnapshotStoreError::Request(
+            request
+                .error()
+                .ok()
+                .flatten()
+                .map(|error| error.message())
+                .unwrap_or_default(),
+        )
+    })?;
+    request.result().map_err(SnapshotStoreError::from_js)
+}
+
+/// Waits for a transaction to be committed
+async fn complete(transaction: &IdbTransaction) -> Result<(), SnapshotStoreError> {
+    let promise = Promise::new(&mut |resolve: Function, reject: Function| {
+        transaction.set_oncomplete(Some(&resolve));
+        transaction.set_onerror(Some(&reject));
+        transaction.set_onabort(Some(&reject));
+    });
+    JsFuture::from(promise)
+        .await
+        .map(|_| ())
+        .map_err(SnapshotStoreError::from_js)
+}
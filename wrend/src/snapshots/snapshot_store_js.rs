@@ -0,0 +1,66 @@
+use std::ops::Deref;
+
+use js_sys::Promise;
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+use wasm_bindgen_futures::future_to_promise;
+
+use crate::{SnapshotKey, SnapshotStore, StateSnapshotJs};
+
+#[wasm_bindgen(inspectable, js_name = SnapshotStore)]
+pub struct SnapshotStoreJs(SnapshotStore);
+
+#[wasm_bindgen(js_class = SnapshotStore)]
+impl SnapshotStoreJs {
+    #[wasm_bindgen(constructor)]
+    pub fn new(database_name: String) -> Self {
+        Self(SnapshotStore::new(database_name))
+    }
+
+    /// Resolves once the snapshot has been written
+    pub fn save(&self, name: String, version: u32, snapshot: &StateSnapshotJs) -> Promise {
+        let store = self.deref().clone();
+        let snapshot = snapshot.deref().clone();
+        future_to_promise(async move {
+            store
+                .save(&SnapshotKey::new(name, version), &snapshot)
+                .await
+                .map(|_| JsValue::UNDEFINED)
+                .map_err(|err| JsValue::from_str(&err.to_string()))
+        })
+    }
+
+    /// Resolves to a `StateSnapshot`, or `undefined` if none was saved under this name and version
+    pub fn load(&self, name: String, version: u32) -> Promise {
+        let store = self.deref().clone();
+        future_to_promise(async move {
+            store
+                .load(&SnapshotKey::new(name, version))
+                .await
+                .map(|snapshot| {
+                    snapshot.map_or(JsValue::UNDEFINED, |snapshot| {
+                        StateSnapshotJs::from(snapshot).into()
+                    })
+                })
+                .map_err(|err| JsValue::from_str(&err.to_string()))
+        })
+    }
+
+    pub fn delete(&self, name: String, version: u32) -> Promise {
+        let store = self.deref().clone();
+        future_to_promise(async move {
+            store
+                .delete(&SnapshotKey::new(name, version))
+                .await
+                .map(|_| JsValue::UNDEFINED)
+                .map_err(|err| JsValue::from_str(&err.to_string()))
+        })
+    }
+}
+
+impl Deref for SnapshotStoreJs {
+    type Target = SnapshotStore;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -0,0 +1,42 @@
+use crate::Id;
+
+/// Which textures and buffers to save with [crate::Renderer::export_state].
+///
+/// WebGL2 can't query the size of a texture, so each texture is listed with the size to read back.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StateIds<TextureId: Id, BufferId: Id> {
+    textures: Vec<(TextureId, u32, u32)>,
+    buffers: Vec<BufferId>,
+}
+
+impl<TextureId: Id, BufferId: Id> StateIds<TextureId, BufferId> {
+    pub fn new() -> Self {
+        Self {
+            textures: Vec::new(),
+            buffers: Vec::new(),
+        }
+    }
+
+    pub fn add_texture(
+        &mut self,
+        texture_id: impl Into<TextureId>,
+        width: u32,
+        height: u32,
+    ) -> &mut Self {
+        self.textures.push((texture_id.into(), width, height));
+        self
+    }
+
+    pub fn add_buffer(&mut self, buffer_id: impl Into<BufferId>) -> &mut Self {
+        self.buffers.push(buffer_id.into());
+        self
+    }
+
+    pub fn textures(&self) -> &[(TextureId, u32, u32)] {
+        &self.textures
+    }
+
+    pub fn buffers(&self) -> &[BufferId] {
+        &self.buffers
+    }
+}
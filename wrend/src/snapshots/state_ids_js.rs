@@ -0,0 +1,60 @@
+use std::ops::{Deref, DerefMut};
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::StateIds;
+
+pub type StateIdsJsInner = StateIds<String, String>;
+
+#[wasm_bindgen(inspectable, js_name = StateIds)]
+pub struct StateIdsJs(StateIdsJsInner);
+
+#[wasm_bindgen(js_class = StateIds)]
+impl StateIdsJs {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self(StateIdsJsInner::new())
+    }
+
+    #[wasm_bindgen(js_name = addTexture)]
+    pub fn add_texture(&mut self, texture_id: String, width: u32, height: u32) {
+        self.deref_mut().add_texture(texture_id, width, height);
+    }
+
+    #[wasm_bindgen(js_name = addBuffer)]
+    pub fn add_buffer(&mut self, buffer_id: String) {
+        self.deref_mut().add_buffer(buffer_id);
+    }
+}
+
+impl StateIdsJs {
+    pub fn into_inner(self) -> StateIdsJsInner {
+        self.0
+    }
+}
+
+impl Default for StateIdsJs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for StateIdsJs {
+    type Target = StateIdsJsInner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for StateIdsJs {
+    fn deref_mut(&mut self) -> &mut StateIdsJsInner {
+        &mut self.0
+    }
+}
+
+impl From<StateIdsJs> for StateIdsJsInner {
+    fn from(state_ids_js: StateIdsJs) -> Self {
+        state_ids_js.into_inner()
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{
+    bytes_to_blob, BufferSnapshot, FrameExportError, SnapshotPixelFormat, StateSnapshotError,
+    TextureSnapshot,
+};
+use web_sys::Blob;
+
+const MAGIC: &[u8; 4] = b"WRST";
+const VERSION: u32 = 1;
+
+/// Texture and buffer contents saved with [crate::Renderer::export_state], e.g. to resume a
+/// long-running simulation later with [crate::Renderer::import_state].
+///
+/// Resources are saved by name (see [crate::IdName]), so a snapshot can be restored into a
+/// renderer that was rebuilt, or created on a later visit to the page.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct StateSnapshot {
+    textures: Vec<TextureSnapshot>,
+    buffers: Vec<BufferSnapshot>,
+}
+
+impl StateSnapshot {
+    pub fn new(textures: Vec<TextureSnapshot>, buffers: Vec<BufferSnapshot>) -> Self {
+        Self { textures, buffers }
+    }
+
+    pub fn textures(&self) -> &[TextureSnapshot] {
+        &self.textures
+    }
+
+    pub fn buffers(&self) -> &[BufferSnapshot] {
+        &self.buffers
+    }
+
+    pub fn texture(&self, name: &str) -> Option<&TextureSnapshot> {
+        self.textures.iter().find(|texture| texture.name() == name)
+    }
+
+    pub fn buffer(&self, name: &str) -> Option<&BufferSnapshot> {
+        self.buffers.iter().find(|buffer| buffer.name() == name)
+    }
+
+    /// Serializes the snapshot into a compact, versioned binary format (all numbers little-endian)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+
+        bytes.extend_from_slice(&(self.textures.len() as u32).to_le_bytes());
+        for texture in &self.textures {
+            write_bytes(&mut bytes, texture.name().as_bytes());
+            bytes.extend_from_slice(&texture.width().to_le_bytes());
+            bytes.extend_from_slice(&texture.height().to_le_bytes());
+            bytes.push(texture.format().to_byte());
+            write_bytes(&mut bytes, texture.data());
+        }
+
+        bytes.extend_from_slice(&(self.buffers.len() as u32).to_le_bytes());
+        for buffer in &self.buffers {
+            write_bytes(&mut bytes, buffer.name().as_bytes());
+            write_bytes(&mut bytes, buffer.data());
+        }
+
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, StateSnapshotError> {
+        let mut reader = Reader(bytes);
+        if reader.take(MAGIC.len())? != MAGIC || reader.u32()? != VERSION {
+            return Err(StateSnapshotError::InvalidHeader);
+        }
+
+        let texture_count = reader.u32()?;
+        let mut textures = Vec::new();
+        for _ in 0..texture_count {
+            let name = reader.name()?;
+            let width = reader.u32()?;
+            let height = reader.u32()?;
+            let format = SnapshotPixelFormat::from_byte(reader.take(1)?[0])
+                .ok_or(StateSnapshotError::InvalidHeader)?;
+            let data = reader.bytes()?.to_vec();
+            textures.push(TextureSnapshot::new(name, width, height, format, data)?);
+        }
+
+        let buffer_count = reader.u32()?;
+        let mut buffers = Vec::new();
+        for _ in 0..buffer_count {
+            let name = reader.name()?;
+            buffers.push(BufferSnapshot::new(name, reader.bytes()?.to_vec()));
+        }
+
+        Ok(Self { textures, buffers })
+    }
+
+    /// Serializes the snapshot (see [StateSnapshot::to_bytes]) into a `Blob` for downloading or storage
+    pub fn to_blob(&self) -> Result<Blob, FrameExportError> {
+        bytes_to_blob(&self.to_bytes(), "application/octet-stream")
+    }
+}
+
+fn write_bytes(bytes: &mut Vec<u8>, data: &[u8]) {
+    bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(data);
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], StateSnapshotError> {
+        if self.0.len() < len {
+            return Err(StateSnapshotError::UnexpectedEnd);
+        }
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    fn u32(&mut self) -> Result<u32, StateSnapshotError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8], StateSnapshotError> {
+        let len = self.u32()? as usize;
+        self.take(len)
+    }
+
+    fn name(&mut self) -> Result<String, StateSnapshotError> {
+        let bytes = self.bytes()?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| StateSnapshotError::InvalidName)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StateSnapshot;
+    use crate::{BufferSnapshot, SnapshotPixelFormat, StateSnapshotError, TextureSnapshot};
+
+    fn snapshot() -> StateSnapshot {
+        StateSnapshot::new(
+            vec![TextureSnapshot::new(
+                "state",
+                2,
+                1,
+                SnapshotPixelFormat::Rgba32F,
+                (0..32).collect(),
+            )
+            .unwrap()],
+            vec![BufferSnapshot::new("particles", vec![1, 2, 3, 4, 5])],
+        )
+    }
+
+    #[test]
+    fn round_trips_through_bytes() {
+        let snapshot = snapshot();
+        assert_eq!(
+            StateSnapshot::from_bytes(&snapshot.to_bytes()),
+            Ok(snapshot)
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_or_foreign_data() {
+        let bytes = snapshot().to_bytes();
+        assert_eq!(
+            StateSnapshot::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(StateSnapshotError::UnexpectedEnd)
+        );
+        assert_eq!(
+            StateSnapshot::from_bytes(b"GIF89a.."),
+            Err(StateSnapshotError::InvalidHeader)
+        );
+    }
+
+    #[test]
+    fn texture_data_must_match_its_size() {
+        assert_eq!(
+            TextureSnapshot::new("state", 2, 2, SnapshotPixelFormat::Rgba8, vec![0; 4]),
+            Err(StateSnapshotError::TextureSizeMismatch {
+                expected: 16,
+                actual: 4
+            })
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use std::ops::{Deref, DerefMut};
+
+use wasm_bindgen::prelude::wasm_bindgen;
+use web_sys::Blob;
+
+use crate::StateSnapshot;
+
+#[wasm_bindgen(inspectable, js_name = StateSnapshot)]
+pub struct StateSnapshotJs(StateSnapshot);
+
+#[wasm_bindgen(js_class = StateSnapshot)]
+impl StateSnapshotJs {
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<StateSnapshotJs, String> {
+        StateSnapshot::from_bytes(&bytes)
+            .map(Self)
+            .map_err(|err| err.to_string())
+    }
+
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.deref().to_bytes()
+    }
+
+    #[wasm_bindgen(js_name = toBlob)]
+    pub fn to_blob(&self) -> Result<Blob, String> {
+        self.deref().to_blob().map_err(|err| err.to_string())
+    }
+}
+
+impl StateSnapshotJs {
+    pub fn into_inner(self) -> StateSnapshot {
+        self.0
+    }
+}
+
+impl Deref for StateSnapshotJs {
+    type Target = StateSnapshot;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for StateSnapshotJs {
+    fn deref_mut(&mut self) -> &mut StateSnapshot {
+        &mut self.0
+    }
+}
+
+impl From<StateSnapshot> for StateSnapshotJs {
+    fn from(state_snapshot: StateSnapshot) -> Self {
+        Self(state_snapshot)
+    }
+}
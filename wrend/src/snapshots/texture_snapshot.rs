@@ -0,0 +1,136 @@
+use crate::{
+    component_type, copy_texture_region, SavedBindings, ScratchFramebuffer, SnapshotPixelFormat,
+    StateSnapshotError,
+};
+use js_sys::ArrayBuffer;
+use web_sys::{WebGl2RenderingContext, WebGlTexture};
+
+type Gl = WebGl2RenderingContext;
+
+/// The bottom-left `width` x `height` pixels of a texture, saved by name along with their format
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TextureSnapshot {
+    name: String,
+    width: u32,
+    height: u32,
+    format: SnapshotPixelFormat,
+    data: Vec<u8>,
+}
+
+impl TextureSnapshot {
+    pub fn new(
+        name: impl Into<String>,
+        width: u32,
+        height: u32,
+        format: SnapshotPixelFormat,
+        data: Vec<u8>,
+    ) -> Result<Self, StateSnapshotError> {
+        let expected = width as usize * height as usize * format.bytes_per_pixel();
+        if data.len() != expected {
+            return Err(StateSnapshotError::TextureSizeMismatch {
+                expected,
+                actual: data.len(),
+            });
+        }
+
+        Ok(Self {
+            name: name.into(),
+            width,
+            height,
+            format,
+            data,
+        })
+    }
+
+    /// Reads pixels back through a temporary framebuffer, in a format chosen from the texture's component type
+    pub(crate) fn read(
+        gl: &Gl,
+        name: String,
+        texture: &WebGlTexture,
+        width: u32,
+        height: u32,
+    ) -> Result<Self, StateSnapshotError> {
+        let bindings = SavedBindings::save(gl);
+        let result = (|| {
+            let _framebuffer = ScratchFramebuffer::new(gl, Gl::READ_FRAMEBUFFER, texture)?;
+            let format = SnapshotPixelFormat::from_component_type(component_type(
+                gl,
+                Gl::READ_FRAMEBUFFER,
+                Gl::COLOR_ATTACHMENT0,
+            ))?;
+            let buffer = ArrayBuffer::new(
+                (width as usize * height as usize * format.bytes_per_pixel()) as u32,
+            );
+            gl.read_pixels_with_opt_array_buffer_view(
+                0,
+                0,
+                width as i32,
+                height as i32,
+                format.format(),
+                format.data_type(),
+                Some(&format.typed_view(&buffer)),
+            )
+            .map_err(|err| StateSnapshotError::ReadPixelsFailed(format!("{err:?}")))?;
+
+            Self::new(
+                name,
+                width,
+                height,
+                format,
+                js_sys::Uint8Array::new(&buffer).to_vec(),
+            )
+        })();
+        bindings.restore(gl);
+        result
+    }
+
+    /// Uploads the pixels into a temporary texture of the saved format and copies them into
+    /// `texture`, so that textures with fewer channels than the snapshot (e.g. `R32F`) can be restored too
+    pub(crate) fn write(&self, gl: &Gl, texture: &WebGlTexture) -> Result<(), StateSnapshotError> {
+        let bindings = SavedBindings::save(gl);
+        let staging = gl
+            .create_texture()
+            .ok_or(StateSnapshotError::CreateTexture)?;
+        let result = (|| {
+            let data = js_sys::Uint8Array::from(self.data.as_slice());
+            gl.bind_texture(Gl::TEXTURE_2D, Some(&staging));
+            gl.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_array_buffer_view(
+                Gl::TEXTURE_2D,
+                0,
+                self.format.internal_format() as i32,
+                self.width as i32,
+                self.height as i32,
+                0,
+                self.format.format(),
+                self.format.data_type(),
+                Some(&self.format.typed_view(&data.buffer())),
+            )
+            .map_err(|err| StateSnapshotError::UploadPixelsFailed(format!("{err:?}")))?;
+            copy_texture_region(gl, &staging, texture, self.width as i32, self.height as i32)?;
+            Ok(())
+        })();
+        gl.delete_texture(Some(&staging));
+        bindings.restore(gl);
+        result
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn format(&self) -> SnapshotPixelFormat {
+        self.format
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
@@ -0,0 +1,15 @@
+mod frame_tracer;
+mod frame_watchdog;
+mod long_frame_callback;
+mod long_frame_report;
+mod trace_category;
+mod trace_event;
+
+pub(crate) use trace_event::escape_json;
+
+pub use frame_tracer::*;
+pub use frame_watchdog::*;
+pub use long_frame_callback::*;
+pub use long_frame_report::*;
+pub use trace_category::*;
+pub use trace_event::*;
@@ -0,0 +1,198 @@
+use crate::{download_blob, TraceCategory, TraceEvent};
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+use wasm_bindgen::JsValue;
+use web_sys::{window, Blob};
+
+#[derive(Debug, Clone)]
+struct FrameTracerInner {
+    enabled: bool,
+    frame: u64,
+    max_events: usize,
+    events: VecDeque<TraceEvent>,
+}
+
+/// Records per-frame timing spans (frames, render passes, uniform updates, etc.) and exports them
+/// in the Chrome trace-event format, which can be opened in `chrome://tracing` or Perfetto.
+///
+/// Tracing is disabled by default and costs almost nothing until enabled.
+/// Clones share the same recording, so a tracer can be handed to a [crate::RenderGraph]
+/// as well as the [crate::RendererData] that owns it.
+#[derive(Debug, Clone)]
+pub struct FrameTracer(Rc<RefCell<FrameTracerInner>>);
+
+impl FrameTracer {
+    /// Oldest events are dropped once this many have been recorded
+    pub const DEFAULT_MAX_EVENTS: usize = 100_000;
+
+    pub fn new() -> Self {
+        Self(Rc::new(RefCell::new(FrameTracerInner {
+            enabled: false,
+            frame: 0,
+            max_events: Self::DEFAULT_MAX_EVENTS,
+            events: VecDeque::new(),
+        })))
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.0.borrow().enabled
+    }
+
+    pub fn set_enabled(&self, enabled: bool) -> &Self {
+        self.0.borrow_mut().enabled = enabled;
+        self
+    }
+
+    pub fn set_max_events(&self, max_events: usize) -> &Self {
+        let mut inner = self.0.borrow_mut();
+        inner.max_events = max_events;
+        while inner.events.len() > max_events {
+            inner.events.pop_front();
+        }
+        drop(inner);
+        self
+    }
+
+    /// Number of frames started since tracing was enabled
+    pub fn frame(&self) -> u64 {
+        self.0.borrow().frame
+    }
+
+    /// Starts a new frame: events recorded after this are tagged with the new frame number
+    pub fn begin_frame(&self) -> &Self {
+        let mut inner = self.0.borrow_mut();
+        if inner.enabled {
+            inner.frame += 1;
+        }
+        drop(inner);
+        self
+    }
+
+    /// Runs `f`, recording how long it took if tracing is enabled
+    pub fn span<R>(&self, name: &str, category: TraceCategory, f: impl FnOnce() -> R) -> R {
+        if !self.is_enabled() {
+            return f();
+        }
+
+        let start = Self::now();
+        let result = f();
+        self.record(name, category, start, Self::now() - start);
+
+        result
+    }
+
+    /// Records a span that was timed elsewhere. Ignored while tracing is disabled.
+    pub fn record(
+        &self,
+        name: impl Into<String>,
+        category: TraceCategory,
+        start_ms: f64,
+        duration_ms: f64,
+    ) -> &Self {
+        let mut inner = self.0.borrow_mut();
+        if !inner.enabled || inner.max_events == 0 {
+            return self;
+        }
+        if inner.events.len() >= inner.max_events {
+            inner.events.pop_front();
+        }
+        let frame = inner.frame;
+        inner.events.push_back(TraceEvent::new(
+            name,
+            category,
+            frame,
+            start_ms,
+            duration_ms,
+        ));
+        drop(inner);
+        self
+    }
+
+    pub fn events(&self) -> Vec<TraceEvent> {
+        self.0.borrow().events.iter().cloned().collect()
+    }
+
+    /// Number of recorded events (without copying them)
+    pub fn event_count(&self) -> usize {
+        self.0.borrow().events.len()
+    }
+
+    pub fn clear(&self) -> &Self {
+        let mut inner = self.0.borrow_mut();
+        inner.events.clear();
+        inner.frame = 0;
+        drop(inner);
+        self
+    }
+
+    /// Every recorded event as a Chrome trace-event JSON document
+    pub fn to_chrome_trace_json(&self) -> String {
+        let events: Vec<String> = self
+            .0
+            .borrow()
+            .events
+            .iter()
+            .map(TraceEvent::to_chrome_trace_json)
+            .collect();
+        format!(
+            r#"{{"traceEvents":[{}],"displayTimeUnit":"ms"}}"#,
+            events.join(",")
+        )
+    }
+
+    /// The trace JSON wrapped in a `Blob`, e.g. for uploading
+    pub fn to_blob(&self) -> Result<Blob, JsValue> {
+        let parts = js_sys::Array::of1(&JsValue::from_str(&self.to_chrome_trace_json()));
+        Blob::new_with_str_sequence(&parts)
+    }
+
+    /// Downloads the trace JSON as a file in the browser
+    pub fn download(&self, filename: &str) -> Result<(), JsValue> {
+        download_blob(&self.to_blob()?, filename)
+    }
+
+    fn now() -> f64 {
+        window().unwrap().performance().unwrap().now()
+    }
+}
+
+impl Default for FrameTracer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for FrameTracer {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for FrameTracer {}
+
+#[cfg(test)]
+mod tests {
+    use super::FrameTracer;
+    use crate::TraceCategory;
+
+    #[test]
+    fn exports_chrome_trace_json() {
+        let tracer = FrameTracer::new();
+        tracer.record("ignored", TraceCategory::Frame, 0.0, 1.0);
+        assert!(tracer.events().is_empty());
+
+        tracer.set_enabled(true).set_max_events(2).begin_frame();
+        tracer.record("dropped", TraceCategory::Frame, 0.0, 1.0);
+        tracer.record("render", TraceCategory::Frame, 1.0, 2.5);
+        tracer.record("blur \"h\"", TraceCategory::Pass, 1.5, 0.25);
+
+        assert_eq!(
+            tracer.to_chrome_trace_json(),
+            concat!(
+                r#"{"traceEvents":["#,
+                r#"{"name":"render","cat":"frame","ph":"X","ts":1000.000,"dur":2500.000,"pid":1,"tid":1,"args":{"frame":1}},"#,
+                r#"{"name":"blur \"h\"","cat":"pass","ph":"X","ts":1500.000,"dur":250.000,"pid":1,"tid":1,"args":{"frame":1}}"#,
+                r#"],"displayTimeUnit":"ms"}"#
+            )
+        );
+    }
+}
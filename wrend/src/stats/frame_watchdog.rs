@@ -0,0 +1,41 @@
+use crate::LongFrameCallback;
+
+/// Calls a [LongFrameCallback] with a [crate::LongFrameReport] whenever a frame takes longer than
+/// `threshold_ms`, to help diagnose intermittent hitches that are hard to reproduce locally.
+///
+/// See [crate::RendererDataBuilder::set_frame_watchdog]
+#[derive(Debug, Clone)]
+pub struct FrameWatchdog {
+    threshold_ms: f64,
+    callback: LongFrameCallback,
+}
+
+impl FrameWatchdog {
+    pub fn new(threshold_ms: f64, callback: impl Into<LongFrameCallback>) -> Self {
+        Self {
+            threshold_ms: threshold_ms.max(0.0),
+            callback: callback.into(),
+        }
+    }
+
+    pub fn threshold_ms(&self) -> f64 {
+        self.threshold_ms
+    }
+
+    pub fn callback(&self) -> &LongFrameCallback {
+        &self.callback
+    }
+
+    pub fn is_exceeded(&self, duration_ms: f64) -> bool {
+        duration_ms > self.threshold_ms
+    }
+}
+
+impl PartialEq for FrameWatchdog {
+    fn eq(&self, other: &Self) -> bool {
+        self.threshold_ms.to_bits() == other.threshold_ms.to_bits()
+            && self.callback == other.callback
+    }
+}
+
+impl Eq for FrameWatchdog {}
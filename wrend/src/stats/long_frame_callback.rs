@@ -0,0 +1,44 @@
+use crate::{Callback, LongFrameReport};
+use js_sys::Function;
+use std::fmt::Debug;
+use std::{ops::Deref, rc::Rc};
+
+/// Called by a [crate::FrameWatchdog] with a snapshot of pipeline state whenever a frame runs long.
+///
+/// JavaScript callbacks receive the report as a plain object.
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd)]
+pub struct LongFrameCallback(Callback<dyn Fn(LongFrameReport), Function>);
+
+impl Deref for LongFrameCallback {
+    type Target = Callback<dyn Fn(LongFrameReport), Function>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Debug for LongFrameCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("LongFrameCallback").field(&self.0).finish()
+    }
+}
+
+impl<F: Fn(LongFrameReport) + 'static> From<F> for LongFrameCallback {
+    fn from(callback: F) -> Self {
+        Self(Callback::new_rs(
+            Rc::new(callback) as Rc<dyn Fn(LongFrameReport)>
+        ))
+    }
+}
+
+impl<F: Fn(LongFrameReport) + 'static> From<Rc<F>> for LongFrameCallback {
+    fn from(callback: Rc<F>) -> Self {
+        Self(Callback::new_rs(callback as Rc<dyn Fn(LongFrameReport)>))
+    }
+}
+
+impl From<Function> for LongFrameCallback {
+    fn from(callback: Function) -> Self {
+        Self(Callback::new_js(callback))
+    }
+}
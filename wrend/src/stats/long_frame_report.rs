@@ -0,0 +1,150 @@
+use crate::{escape_json, TraceEvent};
+use wasm_bindgen::JsValue;
+
+/// A snapshot of pipeline state taken right after a frame exceeded a [crate::FrameWatchdog]'s threshold.
+///
+/// Ids are formatted with `Debug`, so that reports can be logged or sent to a server as-is.
+/// Pass and uniform timings are only available while the [crate::FrameTracer] is enabled.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub struct LongFrameReport {
+    pub(crate) frame: u64,
+    pub(crate) duration_ms: f64,
+    pub(crate) threshold_ms: f64,
+    pub(crate) program_id: Option<String>,
+    pub(crate) framebuffer_id: Option<String>,
+    pub(crate) vao_id: Option<String>,
+    pub(crate) active_texture_unit: u32,
+    pub(crate) bound_textures: Vec<(u32, String)>,
+    pub(crate) gl_errors: Vec<u32>,
+    pub(crate) events: Vec<TraceEvent>,
+}
+
+impl LongFrameReport {
+    /// Frame number according to the [crate::FrameTracer] (`0` if tracing is disabled)
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    pub fn duration_ms(&self) -> f64 {
+        self.duration_ms
+    }
+
+    pub fn threshold_ms(&self) -> f64 {
+        self.threshold_ms
+    }
+
+    /// The program in use when the frame ended
+    pub fn program_id(&self) -> Option<&str> {
+        self.program_id.as_deref()
+    }
+
+    /// The framebuffer bound when the frame ended (`None` for the canvas)
+    pub fn framebuffer_id(&self) -> Option<&str> {
+        self.framebuffer_id.as_deref()
+    }
+
+    /// The vertex array object bound when the frame ended
+    pub fn vao_id(&self) -> Option<&str> {
+        self.vao_id.as_deref()
+    }
+
+    /// Index of the active texture unit (e.g. `0` for `TEXTURE0`)
+    pub fn active_texture_unit(&self) -> u32 {
+        self.active_texture_unit
+    }
+
+    /// Texture unit and id of every known texture bound to a `TEXTURE_2D` target
+    pub fn bound_textures(&self) -> &[(u32, String)] {
+        &self.bound_textures
+    }
+
+    /// Errors returned by `getError` after the frame (which clears them)
+    pub fn gl_errors(&self) -> &[u32] {
+        &self.gl_errors
+    }
+
+    /// Spans recorded during the frame
+    pub fn events(&self) -> &[TraceEvent] {
+        &self.events
+    }
+
+    /// The slowest render pass recorded during the frame
+    pub fn slowest_pass(&self) -> Option<&TraceEvent> {
+        self.events
+            .iter()
+            .filter(|event| event.category() == crate::TraceCategory::Pass)
+            .max_by(|a, b| a.duration_ms().total_cmp(&b.duration_ms()))
+    }
+
+    pub fn to_json(&self) -> String {
+        let optional = |value: &Option<String>| match value {
+            Some(value) => format!("\"{}\"", escape_json(value)),
+            None => String::from("null"),
+        };
+        let bound_textures: Vec<String> = self
+            .bound_textures
+            .iter()
+            .map(|(unit, texture_id)| {
+                format!(
+                    r#"{{"unit":{unit},"textureId":"{}"}}"#,
+                    escape_json(texture_id)
+                )
+            })
+            .collect();
+        let gl_errors: Vec<String> = self.gl_errors.iter().map(u32::to_string).collect();
+        let events: Vec<String> = self
+            .events
+            .iter()
+            .map(TraceEvent::to_chrome_trace_json)
+            .collect();
+
+        format!(
+            r#"{{"frame":{},"durationMs":{},"thresholdMs":{},"programId":{},"framebufferId":{},"vaoId":{},"activeTextureUnit":{},"boundTextures":[{}],"glErrors":[{}],"events":[{}]}}"#,
+            self.frame,
+            self.duration_ms,
+            self.threshold_ms,
+            optional(&self.program_id),
+            optional(&self.framebuffer_id),
+            optional(&self.vao_id),
+            self.active_texture_unit,
+            bound_textures.join(","),
+            gl_errors.join(","),
+            events.join(","),
+        )
+    }
+}
+
+impl From<LongFrameReport> for JsValue {
+    fn from(report: LongFrameReport) -> Self {
+        js_sys::JSON::parse(&report.to_json()).unwrap_or(JsValue::NULL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LongFrameReport;
+    use crate::{TraceCategory, TraceEvent};
+
+    #[test]
+    fn serializes_report_to_json() {
+        let report = LongFrameReport {
+            frame: 3,
+            duration_ms: 40.5,
+            threshold_ms: 32.0,
+            program_id: Some(String::from("Blur")),
+            vao_id: Some(String::from("Quad")),
+            bound_textures: vec![(1, String::from("\"noise\""))],
+            gl_errors: vec![1282],
+            events: vec![
+                TraceEvent::new("blur", TraceCategory::Pass, 3, 0.0, 30.0),
+                TraceEvent::new("composite", TraceCategory::Pass, 3, 30.0, 5.0),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(report.slowest_pass().unwrap().name(), "blur");
+        assert!(report.to_json().starts_with(
+            r#"{"frame":3,"durationMs":40.5,"thresholdMs":32,"programId":"Blur","framebufferId":null,"vaoId":"Quad","activeTextureUnit":0,"boundTextures":[{"unit":1,"textureId":"\"noise\""}],"glErrors":[1282],"events":[{"name":"blur""#
+        ));
+    }
+}
@@ -0,0 +1,38 @@
+use std::fmt::Display;
+
+/// What kind of work a [crate::TraceEvent] measured. Shown as the event's category in trace viewers.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum TraceCategory {
+    /// A whole call to the render callback
+    #[default]
+    Frame,
+    /// A single [crate::RenderPass] of a [crate::RenderGraph]
+    Pass,
+    /// Updating uniforms
+    Uniforms,
+    /// Running transform feedback
+    TransformFeedback,
+    /// Reading data back from the GPU (e.g. `readPixels`)
+    Readback,
+    /// Anything else recorded by the app
+    User,
+}
+
+impl TraceCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TraceCategory::Frame => "frame",
+            TraceCategory::Pass => "pass",
+            TraceCategory::Uniforms => "uniforms",
+            TraceCategory::TransformFeedback => "transform_feedback",
+            TraceCategory::Readback => "readback",
+            TraceCategory::User => "user",
+        }
+    }
+}
+
+impl Display for TraceCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,81 @@
+use crate::TraceCategory;
+
+/// A single timed span recorded by a [crate::FrameTracer]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub struct TraceEvent {
+    name: String,
+    category: TraceCategory,
+    frame: u64,
+    start_ms: f64,
+    duration_ms: f64,
+}
+
+impl TraceEvent {
+    pub fn new(
+        name: impl Into<String>,
+        category: TraceCategory,
+        frame: u64,
+        start_ms: f64,
+        duration_ms: f64,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            category,
+            frame,
+            start_ms,
+            duration_ms,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn category(&self) -> TraceCategory {
+        self.category
+    }
+
+    /// Which frame (counted from when tracing was enabled) the span belongs to
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Start time from `performance.now()`
+    pub fn start_ms(&self) -> f64 {
+        self.start_ms
+    }
+
+    pub fn duration_ms(&self) -> f64 {
+        self.duration_ms
+    }
+
+    /// Formats the span as a Chrome trace-event "complete" (`"ph": "X"`) event.
+    /// Timestamps are converted to microseconds, as the format expects.
+    pub fn to_chrome_trace_json(&self) -> String {
+        format!(
+            r#"{{"name":"{}","cat":"{}","ph":"X","ts":{:.3},"dur":{:.3},"pid":1,"tid":1,"args":{{"frame":{}}}}}"#,
+            escape_json(&self.name),
+            self.category,
+            self.start_ms * 1000.0,
+            self.duration_ms * 1000.0,
+            self.frame,
+        )
+    }
+}
+
+/// Escapes a string for embedding between double quotes in JSON
+pub(crate) fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
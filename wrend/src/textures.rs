@@ -1,17 +1,44 @@
+mod bitmap_texture;
+mod blit_filter;
+mod clear_value;
+mod external_texture_descriptor;
+mod sampler_texture_units;
+mod saved_texture_units;
+mod scratch_framebuffer;
 mod texture;
+mod texture_clear;
+mod texture_copy;
 mod texture_create_callback;
 mod texture_create_callback_js;
 mod texture_create_context;
 mod texture_create_context_js;
+mod texture_guard;
+#[cfg(feature = "js")]
 mod texture_js;
 mod texture_link;
+#[cfg(feature = "js")]
 mod texture_link_js;
+mod texture_units;
 
+pub(crate) use sampler_texture_units::*;
+pub(crate) use saved_texture_units::*;
+pub(crate) use scratch_framebuffer::*;
+pub(crate) use texture_clear::*;
+pub(crate) use texture_copy::*;
+
+pub use bitmap_texture::*;
+pub use blit_filter::*;
+pub use clear_value::*;
+pub use external_texture_descriptor::*;
 pub use texture::*;
 pub use texture_create_callback::*;
 pub use texture_create_callback_js::*;
 pub use texture_create_context::*;
 pub use texture_create_context_js::*;
+pub use texture_guard::*;
+#[cfg(feature = "js")]
 pub use texture_js::*;
 pub use texture_link::*;
+#[cfg(feature = "js")]
 pub use texture_link_js::*;
+pub use texture_units::*;
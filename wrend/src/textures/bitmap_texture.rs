@@ -0,0 +1,80 @@
+use crate::{AssetError, AssetLoader, Id};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+use web_sys::ImageBitmap;
+
+/// A texture whose contents are replaced by [ImageBitmap]s that arrive asynchronously, e.g. from
+/// [AssetLoader::load_image_bitmap].
+///
+/// Setting a bitmap only marks the texture as dirty: the upload happens the next time
+/// [crate::RendererData::upload_bitmap_texture] is called (typically at the start of a frame), so
+/// bitmaps can be set from any async task. Clones share the same pending bitmap.
+#[derive(Debug, Clone)]
+pub struct BitmapTexture<TextureId: Id> {
+    texture_id: TextureId,
+    pending: Rc<RefCell<Option<ImageBitmap>>>,
+    size: Rc<Cell<Option<(u32, u32)>>>,
+    upload_count: Rc<Cell<u32>>,
+}
+
+impl<TextureId: Id> BitmapTexture<TextureId> {
+    /// `texture_id` should be a texture created with a [crate::TextureLink], which this will overwrite
+    pub fn new(texture_id: TextureId) -> Self {
+        Self {
+            texture_id,
+            pending: Default::default(),
+            size: Default::default(),
+            upload_count: Default::default(),
+        }
+    }
+
+    pub fn texture_id(&self) -> &TextureId {
+        &self.texture_id
+    }
+
+    /// Queues `bitmap` for upload, closing any bitmap that was queued but never uploaded
+    pub fn set_bitmap(&self, bitmap: ImageBitmap) -> &Self {
+        if let Some(replaced) = self.pending.replace(Some(bitmap)) {
+            replaced.close();
+        }
+        self
+    }
+
+    /// Loads `url` with [AssetLoader::load_image_bitmap] and queues it for upload
+    pub async fn load(
+        &self,
+        asset_loader: &AssetLoader,
+        url: impl Into<String>,
+        flip_y: bool,
+    ) -> Result<(), AssetError> {
+        let bitmap = asset_loader.load_image_bitmap(url, flip_y).await?;
+        self.set_bitmap(bitmap);
+        Ok(())
+    }
+
+    /// Whether a bitmap is waiting to be uploaded
+    pub fn is_dirty(&self) -> bool {
+        self.pending.borrow().is_some()
+    }
+
+    /// Size of the most recently uploaded bitmap, if any has been uploaded
+    pub fn size(&self) -> Option<(u32, u32)> {
+        self.size.get()
+    }
+
+    /// Number of bitmaps uploaded so far, which can be compared between frames to detect changes
+    pub fn upload_count(&self) -> u32 {
+        self.upload_count.get()
+    }
+
+    /// Takes the pending bitmap, recording it as uploaded
+    pub(crate) fn take_bitmap(&self) -> Option<ImageBitmap> {
+        let bitmap = self.pending.take()?;
+        self.size.set(Some((bitmap.width(), bitmap.height())));
+        self.upload_count
+            .set(self.upload_count.get().wrapping_add(1));
+        Some(bitmap)
+    }
+}
@@ -0,0 +1,22 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+use web_sys::WebGl2RenderingContext;
+
+/// Filter used when a blit stretches or shrinks its source
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum BlitFilter {
+    #[default]
+    Nearest,
+    /// Only valid for non-integer color formats
+    Linear,
+}
+
+impl BlitFilter {
+    /// The WebGL enum value for this filter
+    pub fn gl_enum(&self) -> u32 {
+        match self {
+            BlitFilter::Nearest => WebGl2RenderingContext::NEAREST,
+            BlitFilter::Linear => WebGl2RenderingContext::LINEAR,
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use web_sys::WebGl2RenderingContext;
+
+/// Value used by [crate::RendererData::clear_texture]. The variant must match the texture's
+/// format: `Float` for normalized and floating point formats, `Int` for signed integer formats
+/// (e.g. `RGBA32I`), and `Uint` for unsigned integer formats (e.g. `RGBA8UI`).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum ClearValue {
+    Float([f32; 4]),
+    Int([i32; 4]),
+    Uint([u32; 4]),
+}
+
+impl ClearValue {
+    /// Clears to transparent black
+    pub const ZERO: ClearValue = ClearValue::Float([0.0; 4]);
+
+    /// Whether this value can clear a color buffer with the given `FRAMEBUFFER_ATTACHMENT_COMPONENT_TYPE`
+    pub fn is_compatible_with(&self, component_type: u32) -> bool {
+        match self {
+            ClearValue::Int(_) => component_type == WebGl2RenderingContext::INT,
+            ClearValue::Uint(_) => component_type == WebGl2RenderingContext::UNSIGNED_INT,
+            ClearValue::Float(_) => !matches!(
+                component_type,
+                WebGl2RenderingContext::INT | WebGl2RenderingContext::UNSIGNED_INT
+            ),
+        }
+    }
+
+    pub(crate) fn clear_color_attachment(&self, gl: &WebGl2RenderingContext) {
+        let buffer = WebGl2RenderingContext::COLOR;
+        match self {
+            ClearValue::Float(value) => gl.clear_bufferfv_with_f32_array(buffer, 0, value),
+            ClearValue::Int(value) => gl.clear_bufferiv_with_i32_array(buffer, 0, value),
+            ClearValue::Uint(value) => gl.clear_bufferuiv_with_u32_array(buffer, 0, value),
+        }
+    }
+}
+
+impl Default for ClearValue {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<[f32; 4]> for ClearValue {
+    fn from(value: [f32; 4]) -> Self {
+        ClearValue::Float(value)
+    }
+}
+
+impl From<[i32; 4]> for ClearValue {
+    fn from(value: [i32; 4]) -> Self {
+        ClearValue::Int(value)
+    }
+}
+
+impl From<[u32; 4]> for ClearValue {
+    fn from(value: [u32; 4]) -> Self {
+        ClearValue::Uint(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClearValue;
+    use web_sys::WebGl2RenderingContext as Gl;
+
+    #[test]
+    fn matches_component_types() {
+        assert!(ClearValue::ZERO.is_compatible_with(Gl::UNSIGNED_NORMALIZED));
+        assert!(ClearValue::ZERO.is_compatible_with(Gl::FLOAT));
+        assert!(!ClearValue::ZERO.is_compatible_with(Gl::INT));
+        assert!(ClearValue::Int([0; 4]).is_compatible_with(Gl::INT));
+        assert!(!ClearValue::Int([0; 4]).is_compatible_with(Gl::UNSIGNED_INT));
+        assert!(ClearValue::Uint([0; 4]).is_compatible_with(Gl::UNSIGNED_INT));
+    }
+}
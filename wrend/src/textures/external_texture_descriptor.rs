@@ -0,0 +1,45 @@
+use web_sys::WebGl2RenderingContext;
+
+/// What wrend needs to know about a texture that was created outside of it
+/// (see [crate::RendererData::register_external_texture])
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ExternalTextureDescriptor {
+    width: i32,
+    height: i32,
+    internal_format: u32,
+    target: u32,
+}
+
+impl ExternalTextureDescriptor {
+    /// A `TEXTURE_2D` with the given size and sized internal format, such as `RGBA8`
+    pub fn new(width: i32, height: i32, internal_format: u32) -> Self {
+        Self {
+            width,
+            height,
+            internal_format,
+            target: WebGl2RenderingContext::TEXTURE_2D,
+        }
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn internal_format(&self) -> u32 {
+        self.internal_format
+    }
+
+    /// The target the texture is bound to, such as `TEXTURE_2D` or `TEXTURE_2D_ARRAY`
+    pub fn target(&self) -> u32 {
+        self.target
+    }
+
+    pub fn set_target(&mut self, target: u32) -> &mut Self {
+        self.target = target;
+        self
+    }
+}
@@ -0,0 +1,66 @@
+use crate::TextureUnits;
+use log::warn;
+use std::collections::HashMap;
+use web_sys::{WebGl2RenderingContext, WebGlTexture};
+
+/// Texture units as seen by [crate::UniformContext]s, which aren't generic over the texture id
+/// type: textures are looked up by their `Debug` representation.
+///
+/// While the renderer is being built, asking for the unit of a texture that doesn't have one yet
+/// gives it the lowest free unit and binds it there, so that only pinned textures and textures
+/// that a sampler actually reads from take up one of the `MAX_COMBINED_TEXTURE_IMAGE_UNITS`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SamplerTextureUnits {
+    units: TextureUnits<String>,
+    /// Textures that can still be given a unit on request (emptied once the renderer is built)
+    unassigned: HashMap<String, WebGlTexture>,
+    max_units: u32,
+}
+
+impl SamplerTextureUnits {
+    pub(crate) fn new(
+        units: TextureUnits<String>,
+        unassigned: HashMap<String, WebGlTexture>,
+        max_units: u32,
+    ) -> Self {
+        Self {
+            units,
+            unassigned,
+            max_units,
+        }
+    }
+
+    /// The texture's unit, assigning it one (and binding the texture there) if it can still be given one
+    pub(crate) fn unit(&mut self, gl: &WebGl2RenderingContext, texture_name: &str) -> Option<u32> {
+        if let Some(unit) = self.units.unit(&texture_name.to_string()) {
+            return Some(unit);
+        }
+        let texture = self.unassigned.get(texture_name)?;
+        let Some(unit) = self.units.assign(texture_name.to_string(), self.max_units) else {
+            warn!(
+                "Texture {texture_name} could not be given a texture unit: all {} available texture units are taken",
+                self.max_units
+            );
+            return None;
+        };
+
+        let active_texture = gl.get_parameter(WebGl2RenderingContext::ACTIVE_TEXTURE);
+        gl.active_texture(WebGl2RenderingContext::TEXTURE0 + unit);
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, Some(texture));
+        if let Some(active_texture) = active_texture.ok().and_then(|unit| unit.as_f64()) {
+            gl.active_texture(active_texture as u32);
+        }
+        self.unassigned.remove(texture_name);
+
+        Some(unit)
+    }
+
+    pub(crate) fn units(&self) -> &TextureUnits<String> {
+        &self.units
+    }
+
+    /// Stops handing out new units: called once the renderer has been built
+    pub(crate) fn finish(&mut self) {
+        self.unassigned.clear();
+    }
+}
@@ -0,0 +1,61 @@
+use wasm_bindgen::JsCast;
+use web_sys::{WebGl2RenderingContext as Gl, WebGlTexture};
+
+/// The active texture unit and the `TEXTURE_2D` bindings of the texture units that an internal
+/// pass is about to borrow, so that textures bound to their units by the [crate::TextureUnits]
+/// allocator can be put back once the pass is done, and sampler uniforms that were pointed at a
+/// texture's unit once keep reading from that texture.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SavedTextureUnits {
+    active_texture: u32,
+    textures: Vec<(u32, Option<WebGlTexture>)>,
+}
+
+impl SavedTextureUnits {
+    /// Saves the bindings of every unit in `units` (and of the active unit) for which `is_assigned`
+    /// returns true
+    pub(crate) fn save(
+        gl: &Gl,
+        units: impl IntoIterator<Item = u32>,
+        is_assigned: impl Fn(u32) -> bool,
+    ) -> Self {
+        let active_texture = gl
+            .get_parameter(Gl::ACTIVE_TEXTURE)
+            .ok()
+            .and_then(|unit| unit.as_f64())
+            .map_or(Gl::TEXTURE0, |unit| unit as u32);
+        let mut units: Vec<u32> = units
+            .into_iter()
+            .chain([active_texture - Gl::TEXTURE0])
+            .filter(|unit| is_assigned(*unit))
+            .collect();
+        units.sort_unstable();
+        units.dedup();
+
+        let textures = units
+            .into_iter()
+            .map(|unit| {
+                gl.active_texture(Gl::TEXTURE0 + unit);
+                let texture = gl
+                    .get_parameter(Gl::TEXTURE_BINDING_2D)
+                    .ok()
+                    .and_then(|texture| texture.dyn_into::<WebGlTexture>().ok());
+                (unit, texture)
+            })
+            .collect();
+        gl.active_texture(active_texture);
+
+        Self {
+            active_texture,
+            textures,
+        }
+    }
+
+    pub(crate) fn restore(&self, gl: &Gl) {
+        for (unit, texture) in &self.textures {
+            gl.active_texture(Gl::TEXTURE0 + unit);
+            gl.bind_texture(Gl::TEXTURE_2D, texture.as_ref());
+        }
+        gl.active_texture(self.active_texture);
+    }
+}
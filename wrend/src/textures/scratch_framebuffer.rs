@@ -0,0 +1,92 @@
+use crate::CopyTextureError;
+use web_sys::{WebGl2RenderingContext, WebGlFramebuffer, WebGlTexture};
+
+type Gl = WebGl2RenderingContext;
+
+/// Color attachment of the default framebuffer (the canvas)
+pub(crate) const DEFAULT_COLOR_ATTACHMENT: u32 = 0x0405;
+
+/// Reads the `FRAMEBUFFER_ATTACHMENT_COMPONENT_TYPE` of a color attachment
+pub(crate) fn component_type(gl: &Gl, target: u32, attachment: u32) -> u32 {
+    gl.get_framebuffer_attachment_parameter(
+        target,
+        attachment,
+        Gl::FRAMEBUFFER_ATTACHMENT_COMPONENT_TYPE,
+    )
+    .ok()
+    .and_then(|value| value.as_f64())
+    .map_or(Gl::UNSIGNED_NORMALIZED, |value| value as u32)
+}
+
+pub(crate) fn check_complete(gl: &Gl, target: u32) -> Result<(), CopyTextureError> {
+    let status = gl.check_framebuffer_status(target);
+    if status == Gl::FRAMEBUFFER_COMPLETE {
+        Ok(())
+    } else {
+        Err(CopyTextureError::IncompleteFramebuffer { status })
+    }
+}
+
+/// Temporary framebuffer with a texture attached, deleted when dropped
+pub(crate) struct ScratchFramebuffer<'a> {
+    gl: &'a Gl,
+    framebuffer: WebGlFramebuffer,
+}
+
+impl<'a> ScratchFramebuffer<'a> {
+    pub(crate) fn new(
+        gl: &'a Gl,
+        target: u32,
+        texture: &WebGlTexture,
+    ) -> Result<Self, CopyTextureError> {
+        let framebuffer = gl
+            .create_framebuffer()
+            .ok_or(CopyTextureError::CreateFramebuffer)?;
+        let scratch = Self { gl, framebuffer };
+        gl.bind_framebuffer(target, Some(&scratch.framebuffer));
+        gl.framebuffer_texture_2d(
+            target,
+            Gl::COLOR_ATTACHMENT0,
+            Gl::TEXTURE_2D,
+            Some(texture),
+            0,
+        );
+        check_complete(gl, target)?;
+        Ok(scratch)
+    }
+}
+
+impl Drop for ScratchFramebuffer<'_> {
+    fn drop(&mut self) {
+        self.gl.delete_framebuffer(Some(&self.framebuffer));
+    }
+}
+
+/// Framebuffer and texture bindings that copies disturb, so they can be restored afterward
+pub(crate) struct SavedBindings {
+    read_framebuffer: Option<WebGlFramebuffer>,
+    draw_framebuffer: Option<WebGlFramebuffer>,
+    texture: Option<WebGlTexture>,
+}
+
+impl SavedBindings {
+    pub(crate) fn save(gl: &Gl) -> Self {
+        use wasm_bindgen::JsCast;
+        let get = |pname| {
+            gl.get_parameter(pname)
+                .ok()
+                .filter(|value| !value.is_null())
+        };
+        Self {
+            read_framebuffer: get(Gl::READ_FRAMEBUFFER_BINDING).map(JsCast::unchecked_into),
+            draw_framebuffer: get(Gl::DRAW_FRAMEBUFFER_BINDING).map(JsCast::unchecked_into),
+            texture: get(Gl::TEXTURE_BINDING_2D).map(JsCast::unchecked_into),
+        }
+    }
+
+    pub(crate) fn restore(&self, gl: &Gl) {
+        gl.bind_framebuffer(Gl::READ_FRAMEBUFFER, self.read_framebuffer.as_ref());
+        gl.bind_framebuffer(Gl::DRAW_FRAMEBUFFER, self.draw_framebuffer.as_ref());
+        gl.bind_texture(Gl::TEXTURE_2D, self.texture.as_ref());
+    }
+}
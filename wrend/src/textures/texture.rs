@@ -1,6 +1,9 @@
-use crate::{Id, TextureJs, TextureJsInner};
+use crate::Id;
+#[cfg(feature = "js")]
+use crate::{TextureJs, TextureJsInner};
 use std::fmt::Debug;
 use std::hash::Hash;
+#[cfg(feature = "js")]
 use wasm_bindgen::JsValue;
 use web_sys::WebGlTexture;
 
@@ -50,6 +53,7 @@ impl<TextureId: Id> PartialEq for Texture<TextureId> {
 
 impl<TextureId: Id> Eq for Texture<TextureId> {}
 
+#[cfg(feature = "js")]
 impl From<TextureJsInner> for JsValue {
     fn from(texture: TextureJsInner) -> Self {
         let js_texture: TextureJs = texture.into();
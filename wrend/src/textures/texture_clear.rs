@@ -0,0 +1,33 @@
+use crate::{component_type, ClearValue, CopyTextureError, SavedBindings, ScratchFramebuffer};
+use web_sys::{WebGl2RenderingContext, WebGlTexture};
+
+/// Clears every texel of a texture's base level by attaching it to a temporary framebuffer
+pub(crate) fn clear_texture_level(
+    gl: &WebGl2RenderingContext,
+    texture: &WebGlTexture,
+    value: ClearValue,
+) -> Result<(), CopyTextureError> {
+    let target = WebGl2RenderingContext::DRAW_FRAMEBUFFER;
+    let bindings = SavedBindings::save(gl);
+    let scissor_enabled = gl.is_enabled(WebGl2RenderingContext::SCISSOR_TEST);
+
+    let result = ScratchFramebuffer::new(gl, target, texture).and_then(|framebuffer| {
+        let component_type = component_type(gl, target, WebGl2RenderingContext::COLOR_ATTACHMENT0);
+        if !value.is_compatible_with(component_type) {
+            return Err(CopyTextureError::ClearValueMismatch { component_type });
+        }
+
+        // the scissor rect would otherwise limit the clear to a region of the texture
+        gl.disable(WebGl2RenderingContext::SCISSOR_TEST);
+        value.clear_color_attachment(gl);
+
+        drop(framebuffer);
+        Ok(())
+    });
+
+    if scissor_enabled {
+        gl.enable(WebGl2RenderingContext::SCISSOR_TEST);
+    }
+    bindings.restore(gl);
+    result
+}
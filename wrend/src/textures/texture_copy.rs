@@ -0,0 +1,129 @@
+use crate::{
+    check_complete, component_type, BlitFilter, CopyTextureError, SavedBindings,
+    ScratchFramebuffer, Viewport, DEFAULT_COLOR_ATTACHMENT,
+};
+use web_sys::{WebGl2RenderingContext, WebGlFramebuffer, WebGlTexture};
+
+type Gl = WebGl2RenderingContext;
+
+/// Checks that pixels can be copied between color buffers with the given
+/// `FRAMEBUFFER_ATTACHMENT_COMPONENT_TYPE`s: integer formats can only be copied to integer formats
+/// of the same signedness, and never with linear filtering.
+pub(crate) fn check_copy_compatible(
+    src_component_type: u32,
+    dst_component_type: u32,
+    filter: BlitFilter,
+) -> Result<(), CopyTextureError> {
+    let is_integer = |component_type| matches!(component_type, Gl::INT | Gl::UNSIGNED_INT);
+
+    if is_integer(src_component_type) || is_integer(dst_component_type) {
+        if src_component_type != dst_component_type {
+            return Err(CopyTextureError::IncompatibleFormats {
+                src_component_type,
+                dst_component_type,
+            });
+        }
+        if filter == BlitFilter::Linear {
+            return Err(CopyTextureError::IntegerLinearFilter);
+        }
+    }
+
+    Ok(())
+}
+
+/// Copies the `width` x `height` bottom-left region of one texture into another
+pub(crate) fn copy_texture_region(
+    gl: &Gl,
+    src: &WebGlTexture,
+    dst: &WebGlTexture,
+    width: i32,
+    height: i32,
+) -> Result<(), CopyTextureError> {
+    let bindings = SavedBindings::save(gl);
+    let result = (|| {
+        let src_framebuffer = ScratchFramebuffer::new(gl, Gl::READ_FRAMEBUFFER, src)?;
+        let dst_framebuffer = ScratchFramebuffer::new(gl, Gl::DRAW_FRAMEBUFFER, dst)?;
+        check_copy_compatible(
+            component_type(gl, Gl::READ_FRAMEBUFFER, Gl::COLOR_ATTACHMENT0),
+            component_type(gl, Gl::DRAW_FRAMEBUFFER, Gl::COLOR_ATTACHMENT0),
+            BlitFilter::Nearest,
+        )?;
+
+        gl.bind_texture(Gl::TEXTURE_2D, Some(dst));
+        gl.copy_tex_sub_image_2d(Gl::TEXTURE_2D, 0, 0, 0, 0, 0, width, height);
+
+        drop((src_framebuffer, dst_framebuffer));
+        Ok(())
+    })();
+    bindings.restore(gl);
+    result
+}
+
+/// Copies a region of one framebuffer into a region of another (`None` is the canvas)
+pub(crate) fn blit_framebuffer(
+    gl: &Gl,
+    src: Option<&WebGlFramebuffer>,
+    dst: Option<&WebGlFramebuffer>,
+    src_rect: Viewport,
+    dst_rect: Viewport,
+    filter: BlitFilter,
+) -> Result<(), CopyTextureError> {
+    let bindings = SavedBindings::save(gl);
+    let result = (|| {
+        gl.bind_framebuffer(Gl::READ_FRAMEBUFFER, src);
+        gl.bind_framebuffer(Gl::DRAW_FRAMEBUFFER, dst);
+        check_complete(gl, Gl::READ_FRAMEBUFFER)?;
+        check_complete(gl, Gl::DRAW_FRAMEBUFFER)?;
+
+        let attachment = |framebuffer: Option<&WebGlFramebuffer>| match framebuffer {
+            Some(_) => Gl::COLOR_ATTACHMENT0,
+            None => DEFAULT_COLOR_ATTACHMENT,
+        };
+        check_copy_compatible(
+            component_type(gl, Gl::READ_FRAMEBUFFER, attachment(src)),
+            component_type(gl, Gl::DRAW_FRAMEBUFFER, attachment(dst)),
+            filter,
+        )?;
+
+        gl.blit_framebuffer(
+            src_rect.x(),
+            src_rect.y(),
+            src_rect.x() + src_rect.width(),
+            src_rect.y() + src_rect.height(),
+            dst_rect.x(),
+            dst_rect.y(),
+            dst_rect.x() + dst_rect.width(),
+            dst_rect.y() + dst_rect.height(),
+            Gl::COLOR_BUFFER_BIT,
+            filter.gl_enum(),
+        );
+        Ok(())
+    })();
+    bindings.restore(gl);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_copy_compatible, Gl};
+    use crate::{BlitFilter, CopyTextureError};
+
+    #[test]
+    fn checks_component_type_compatibility() {
+        assert!(
+            check_copy_compatible(Gl::FLOAT, Gl::UNSIGNED_NORMALIZED, BlitFilter::Linear).is_ok()
+        );
+        assert!(check_copy_compatible(Gl::INT, Gl::INT, BlitFilter::Nearest).is_ok());
+        assert_eq!(
+            check_copy_compatible(Gl::INT, Gl::INT, BlitFilter::Linear),
+            Err(CopyTextureError::IntegerLinearFilter)
+        );
+        assert_eq!(
+            check_copy_compatible(Gl::UNSIGNED_INT, Gl::FLOAT, BlitFilter::Nearest),
+            Err(CopyTextureError::IncompatibleFormats {
+                src_component_type: Gl::UNSIGNED_INT,
+                dst_component_type: Gl::FLOAT,
+            })
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use crate::GlStateSnapshot;
+use std::ops::Deref;
+use web_sys::{WebGl2RenderingContext, WebGlTexture};
+
+/// Sampling parameters that external code commonly changes on a texture it's given
+const TEXTURE_PARAMETERS: [u32; 8] = [
+    WebGl2RenderingContext::TEXTURE_MIN_FILTER,
+    WebGl2RenderingContext::TEXTURE_MAG_FILTER,
+    WebGl2RenderingContext::TEXTURE_WRAP_S,
+    WebGl2RenderingContext::TEXTURE_WRAP_T,
+    WebGl2RenderingContext::TEXTURE_WRAP_R,
+    WebGl2RenderingContext::TEXTURE_BASE_LEVEL,
+    WebGl2RenderingContext::TEXTURE_MAX_LEVEL,
+    WebGl2RenderingContext::TEXTURE_COMPARE_MODE,
+];
+
+/// The query for the texture bound to `target` on the active texture unit
+pub(crate) fn texture_binding_parameter(target: u32) -> u32 {
+    match target {
+        WebGl2RenderingContext::TEXTURE_2D_ARRAY => {
+            WebGl2RenderingContext::TEXTURE_BINDING_2D_ARRAY
+        }
+        WebGl2RenderingContext::TEXTURE_3D => WebGl2RenderingContext::TEXTURE_BINDING_3D,
+        WebGl2RenderingContext::TEXTURE_CUBE_MAP => {
+            WebGl2RenderingContext::TEXTURE_BINDING_CUBE_MAP
+        }
+        _ => WebGl2RenderingContext::TEXTURE_BINDING_2D,
+    }
+}
+
+/// A wrend texture lent to code outside of wrend, e.g. a third-party WebGL widget sharing the
+/// context (see [crate::RendererData::lend_texture]). Derefs to the [WebGlTexture].
+///
+/// When the guard is dropped, the texture's sampling parameters and the WebGL state captured in
+/// a [GlStateSnapshot] are restored, so whatever the external code bound or enabled doesn't leak
+/// into wrend's later draws. The texture must not be deleted or reallocated while it is lent.
+#[derive(Debug)]
+pub struct TextureGuard {
+    gl: WebGl2RenderingContext,
+    texture: WebGlTexture,
+    target: u32,
+    parameters: Vec<(u32, i32)>,
+    state: GlStateSnapshot,
+}
+
+impl TextureGuard {
+    /// Captures the current state. `texture_units` are the units whose bindings should be restored,
+    /// in addition to the active one
+    pub(crate) fn new(
+        gl: &WebGl2RenderingContext,
+        texture: WebGlTexture,
+        target: u32,
+        texture_units: &[u32],
+    ) -> Self {
+        let state = GlStateSnapshot::capture(gl, texture_units);
+
+        let previous = gl
+            .get_parameter(texture_binding_parameter(target))
+            .ok()
+            .filter(|value| !value.is_null())
+            .map(wasm_bindgen::JsCast::unchecked_into::<WebGlTexture>);
+        gl.bind_texture(target, Some(&texture));
+        let parameters = TEXTURE_PARAMETERS
+            .into_iter()
+            .filter_map(|pname| {
+                let value = gl.get_tex_parameter(target, pname).as_f64()?;
+                Some((pname, value as i32))
+            })
+            .collect();
+        gl.bind_texture(target, previous.as_ref());
+
+        Self {
+            gl: gl.clone(),
+            texture,
+            target,
+            parameters,
+            state,
+        }
+    }
+
+    pub fn texture(&self) -> &WebGlTexture {
+        &self.texture
+    }
+
+    /// The target the texture is bound to, such as `TEXTURE_2D`
+    pub fn target(&self) -> u32 {
+        self.target
+    }
+}
+
+impl Deref for TextureGuard {
+    type Target = WebGlTexture;
+
+    fn deref(&self) -> &Self::Target {
+        &self.texture
+    }
+}
+
+impl Drop for TextureGuard {
+    fn drop(&mut self) {
+        let gl = &self.gl;
+        let previous = gl
+            .get_parameter(texture_binding_parameter(self.target))
+            .ok()
+            .filter(|value| !value.is_null())
+            .map(wasm_bindgen::JsCast::unchecked_into::<WebGlTexture>);
+        gl.bind_texture(self.target, Some(&self.texture));
+        for &(pname, value) in &self.parameters {
+            gl.tex_parameteri(self.target, pname, value);
+        }
+        gl.bind_texture(self.target, previous.as_ref());
+
+        self.state.restore(gl);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::texture_binding_parameter;
+    use web_sys::WebGl2RenderingContext as Gl;
+
+    #[test]
+    fn maps_targets_to_binding_queries() {
+        assert_eq!(
+            texture_binding_parameter(Gl::TEXTURE_2D),
+            Gl::TEXTURE_BINDING_2D
+        );
+        assert_eq!(
+            texture_binding_parameter(Gl::TEXTURE_2D_ARRAY),
+            Gl::TEXTURE_BINDING_2D_ARRAY
+        );
+        assert_eq!(
+            texture_binding_parameter(Gl::TEXTURE_3D),
+            Gl::TEXTURE_BINDING_3D
+        );
+        assert_eq!(
+            texture_binding_parameter(Gl::TEXTURE_CUBE_MAP),
+            Gl::TEXTURE_BINDING_CUBE_MAP
+        );
+    }
+}
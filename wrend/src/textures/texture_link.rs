@@ -35,6 +35,14 @@ impl<TextureId: Id> TextureLink<TextureId> {
         self.create_texture_callback
             .call_with_into_js_arg_and_return(&texture_create_context)
     }
+
+    /// The same link with its id replaced (used when scoping links to a namespace)
+    pub(crate) fn map_ids(self, texture_id: impl Fn(&TextureId) -> TextureId) -> Self {
+        Self {
+            texture_id: texture_id(&self.texture_id),
+            ..self
+        }
+    }
 }
 
 impl<TextureId: Id> Debug for TextureLink<TextureId> {
@@ -0,0 +1,133 @@
+use crate::Id;
+use std::collections::HashMap;
+
+/// Gives the textures of a [crate::RendererData] that are sampled their own texture unit.
+///
+/// Units requested with [crate::RendererDataBuilder::set_texture_unit] are kept as-is and bound
+/// before any uniform is initialized. Every other texture is given the lowest unit that isn't
+/// taken yet the first time a uniform initialize callback asks for it with
+/// [crate::UniformContext::texture_unit], and is bound to it then, so that sampler uniforms can be
+/// pointed at a texture's unit once. Textures that no sampler asks for are left without a unit.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TextureUnits<TextureId: Id> {
+    units: HashMap<TextureId, u32>,
+}
+
+impl<TextureId: Id> TextureUnits<TextureId> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assigns `unit` to the texture, replacing any texture that previously had it
+    ///
+    /// Units must be below `MAX_COMBINED_TEXTURE_IMAGE_UNITS`, which is checked when the renderer is built
+    pub fn pin(&mut self, texture_id: TextureId, unit: u32) -> &mut Self {
+        self.units.retain(|_, existing_unit| *existing_unit != unit);
+        self.units.insert(texture_id, unit);
+        self
+    }
+
+    /// Returns the texture's unit, assigning it the lowest free unit below `max_units` if it
+    /// doesn't have one yet
+    ///
+    /// Returns `None` if every unit below `max_units` is taken.
+    pub fn assign(&mut self, texture_id: TextureId, max_units: u32) -> Option<u32> {
+        if let Some(unit) = self.units.get(&texture_id) {
+            return Some(*unit);
+        }
+        let unit = (0..max_units).find(|unit| self.texture_id(*unit).is_none())?;
+        self.units.insert(texture_id, unit);
+        Some(unit)
+    }
+
+    /// The first texture (in order of units) whose unit isn't below `max_units`, if any
+    pub fn out_of_range(&self, max_units: u32) -> Option<(&TextureId, u32)> {
+        self.iter()
+            .filter(|(_, unit)| *unit >= max_units)
+            .min_by_key(|(_, unit)| *unit)
+    }
+
+    pub fn unit(&self, texture_id: &TextureId) -> Option<u32> {
+        self.units.get(texture_id).copied()
+    }
+
+    /// The texture that has been assigned `unit`, if any
+    pub fn texture_id(&self, unit: u32) -> Option<&TextureId> {
+        self.units
+            .iter()
+            .find(|(_, texture_unit)| **texture_unit == unit)
+            .map(|(texture_id, _)| texture_id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&TextureId, u32)> {
+        self.units
+            .iter()
+            .map(|(texture_id, unit)| (texture_id, *unit))
+    }
+
+    pub fn len(&self) -> usize {
+        self.units.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.units.is_empty()
+    }
+
+    /// Keeps only the textures for which `f` returns true
+    pub(crate) fn retain(&mut self, mut f: impl FnMut(&TextureId) -> bool) {
+        self.units.retain(|texture_id, _| f(texture_id));
+    }
+
+    /// Units keyed by each texture's `Debug` representation, for contexts that aren't generic
+    /// over the texture id type (such as [crate::UniformContext])
+    pub(crate) fn by_debug_name(&self) -> TextureUnits<String> {
+        TextureUnits {
+            units: self
+                .units
+                .iter()
+                .map(|(texture_id, unit)| (format!("{texture_id:?}"), *unit))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextureUnits;
+
+    #[test]
+    fn assigns_lowest_free_unit_around_pinned_units() {
+        let mut texture_units = TextureUnits::new();
+        texture_units.pin("noise", 0).pin("feedback", 2);
+
+        assert_eq!(texture_units.assign("a", 16), Some(1));
+        assert_eq!(texture_units.assign("b", 16), Some(3));
+        assert_eq!(texture_units.assign("a", 16), Some(1));
+        assert_eq!(texture_units.texture_id(2), Some(&"feedback"));
+
+        texture_units.pin("b", 2);
+        assert_eq!(texture_units.unit(&"feedback"), None);
+        assert_eq!(texture_units.unit(&"b"), Some(2));
+        assert_eq!(texture_units.assign("c", 16), Some(3));
+    }
+
+    #[test]
+    fn stops_assigning_once_units_run_out() {
+        let mut texture_units = TextureUnits::new();
+        texture_units.pin("noise", 1);
+
+        assert_eq!(texture_units.assign("a", 2), Some(0));
+        assert_eq!(texture_units.assign("b", 2), None);
+        assert_eq!(texture_units.unit(&"b"), None);
+        assert_eq!(texture_units.assign("a", 2), Some(0));
+    }
+
+    #[test]
+    fn finds_pinned_units_out_of_range() {
+        let mut texture_units = TextureUnits::new();
+        texture_units.pin("a", 3).pin("b", 40).pin("c", 32);
+
+        assert_eq!(texture_units.out_of_range(32), Some((&"c", 32)));
+        assert_eq!(texture_units.out_of_range(41), None);
+    }
+}
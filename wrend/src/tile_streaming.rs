@@ -0,0 +1,10 @@
+mod tile_cache;
+mod tile_coord;
+mod tile_pyramid;
+mod tile_streamer;
+
+pub(crate) use tile_cache::*;
+
+pub use tile_coord::*;
+pub use tile_pyramid::*;
+pub use tile_streamer::*;
@@ -0,0 +1,98 @@
+use crate::TileCoord;
+use std::collections::HashMap;
+
+/// Assigns tiles to layers of a fixed-size texture array, evicting the least recently used tile
+/// when every layer is taken.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TileCache {
+    capacity: u32,
+    clock: u64,
+    /// layer and the clock value at which the tile was last used
+    slots: HashMap<TileCoord, (u32, u64)>,
+    free_layers: Vec<u32>,
+}
+
+impl TileCache {
+    pub fn new(capacity: u32) -> Self {
+        Self {
+            capacity,
+            clock: 0,
+            slots: HashMap::new(),
+            free_layers: (0..capacity).rev().collect(),
+        }
+    }
+
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    pub fn layer(&self, tile: &TileCoord) -> Option<u32> {
+        self.slots.get(tile).map(|(layer, _)| *layer)
+    }
+
+    pub fn contains(&self, tile: &TileCoord) -> bool {
+        self.slots.contains_key(tile)
+    }
+
+    pub fn tiles(&self) -> impl Iterator<Item = &TileCoord> {
+        self.slots.keys()
+    }
+
+    /// Marks a tile as used, so that it is evicted after any tile that hasn't been touched since
+    pub fn touch(&mut self, tile: &TileCoord) {
+        self.clock += 1;
+        if let Some((_, last_used)) = self.slots.get_mut(tile) {
+            *last_used = self.clock;
+        }
+    }
+
+    /// Returns the layer the tile should be uploaded to and, if a tile had to be evicted
+    /// to make room, which one.
+    pub fn insert(&mut self, tile: TileCoord) -> Option<(u32, Option<TileCoord>)> {
+        self.clock += 1;
+        if let Some((layer, last_used)) = self.slots.get_mut(&tile) {
+            *last_used = self.clock;
+            return Some((*layer, None));
+        }
+
+        let (layer, evicted) = match self.free_layers.pop() {
+            Some(layer) => (layer, None),
+            None => {
+                let (&evicted, &(layer, _)) = self
+                    .slots
+                    .iter()
+                    .min_by_key(|(_, (_, last_used))| *last_used)?;
+                self.slots.remove(&evicted);
+                (layer, Some(evicted))
+            }
+        };
+
+        self.slots.insert(tile, (layer, self.clock));
+        Some((layer, evicted))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TileCache;
+    use crate::TileCoord;
+
+    #[test]
+    fn evicts_least_recently_used_tile() {
+        let a = TileCoord::new(0, 0, 0);
+        let b = TileCoord::new(0, 1, 0);
+        let c = TileCoord::new(0, 2, 0);
+
+        let mut cache = TileCache::new(2);
+        assert_eq!(cache.insert(a), Some((0, None)));
+        assert_eq!(cache.insert(b), Some((1, None)));
+
+        cache.touch(&a);
+        assert_eq!(cache.insert(c), Some((1, Some(b))));
+        assert!(cache.contains(&a));
+        assert!(!cache.contains(&b));
+        assert_eq!(cache.layer(&c), Some(1));
+
+        assert_eq!(TileCache::new(0).insert(a), None);
+    }
+}
@@ -0,0 +1,33 @@
+/// Identifies a single tile in a [crate::TilePyramid].
+///
+/// Level `0` is the full-resolution image; every level above it is half the size of the one below.
+/// `x` and `y` count tiles from the top-left corner of the image at that level.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct TileCoord {
+    level: u32,
+    x: u32,
+    y: u32,
+}
+
+impl TileCoord {
+    pub fn new(level: u32, x: u32, y: u32) -> Self {
+        Self { level, x, y }
+    }
+
+    pub fn level(&self) -> u32 {
+        self.level
+    }
+
+    pub fn x(&self) -> u32 {
+        self.x
+    }
+
+    pub fn y(&self) -> u32 {
+        self.y
+    }
+
+    /// The tile one level up (half resolution) that covers this tile, if any
+    pub fn parent(&self) -> Self {
+        Self::new(self.level + 1, self.x / 2, self.y / 2)
+    }
+}
@@ -0,0 +1,132 @@
+use crate::TileCoord;
+
+/// Describes the layout of a tiled image pyramid (e.g. a Deep Zoom or IIIF image),
+/// where each level is half the resolution of the level below it.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TilePyramid {
+    width: u32,
+    height: u32,
+    tile_size: u32,
+}
+
+impl TilePyramid {
+    /// `width` and `height` are the dimensions of the full-resolution image in pixels
+    pub fn new(width: u32, height: u32, tile_size: u32) -> Self {
+        Self {
+            width: width.max(1),
+            height: height.max(1),
+            tile_size: tile_size.max(1),
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn tile_size(&self) -> u32 {
+        self.tile_size
+    }
+
+    /// Number of levels, down to (and including) the first level that fits in a single tile
+    pub fn levels(&self) -> u32 {
+        let mut levels = 1;
+        let (mut width, mut height) = (self.width, self.height);
+        while width > self.tile_size || height > self.tile_size {
+            width = width.div_ceil(2);
+            height = height.div_ceil(2);
+            levels += 1;
+        }
+        levels
+    }
+
+    /// Size of the image in pixels at `level`
+    pub fn level_size(&self, level: u32) -> (u32, u32) {
+        let scale = 1u64 << level.min(31);
+        (
+            (self.width as u64).div_ceil(scale) as u32,
+            (self.height as u64).div_ceil(scale) as u32,
+        )
+    }
+
+    /// Number of tiles across and down at `level`
+    pub fn tile_count(&self, level: u32) -> (u32, u32) {
+        let (width, height) = self.level_size(level);
+        (
+            width.div_ceil(self.tile_size),
+            height.div_ceil(self.tile_size),
+        )
+    }
+
+    /// Picks the coarsest level that still has at least one image pixel per screen pixel.
+    ///
+    /// `image_pixels_per_screen_pixel` is how many full-resolution pixels are covered by each pixel
+    /// on screen (greater than 1 when zoomed out).
+    pub fn level_for_scale(&self, image_pixels_per_screen_pixel: f64) -> u32 {
+        if !image_pixels_per_screen_pixel.is_finite() || image_pixels_per_screen_pixel <= 1.0 {
+            return 0;
+        }
+        (image_pixels_per_screen_pixel.log2().floor() as u32).min(self.levels() - 1)
+    }
+
+    /// Every tile at `level` that overlaps `rect`, given as `[x, y, width, height]`
+    /// in full-resolution pixels with a top-left origin.
+    pub fn visible_tiles(&self, rect: [f64; 4], level: u32) -> Vec<TileCoord> {
+        let [x, y, width, height] = rect;
+        let (tiles_x, tiles_y) = self.tile_count(level);
+        let tile_extent = self.tile_size as f64 * (1u64 << level.min(31)) as f64;
+
+        let to_range = |start: f64, length: f64, count: u32| {
+            let first = (start / tile_extent).floor().max(0.0) as u32;
+            let last = ((start + length) / tile_extent).ceil().max(0.0) as u32;
+            first.min(count)..last.min(count)
+        };
+
+        let columns = to_range(x, width, tiles_x);
+        to_range(y, height, tiles_y)
+            .flat_map(|tile_y| {
+                columns
+                    .clone()
+                    .map(move |tile_x| TileCoord::new(level, tile_x, tile_y))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TilePyramid;
+    use crate::TileCoord;
+
+    #[test]
+    fn computes_levels_and_visible_tiles() {
+        let pyramid = TilePyramid::new(1000, 600, 256);
+        assert_eq!(pyramid.levels(), 3);
+        assert_eq!(pyramid.tile_count(0), (4, 3));
+        assert_eq!(pyramid.tile_count(1), (2, 2));
+        assert_eq!(pyramid.tile_count(2), (1, 1));
+
+        assert_eq!(pyramid.level_for_scale(0.5), 0);
+        assert_eq!(pyramid.level_for_scale(2.5), 1);
+        assert_eq!(pyramid.level_for_scale(100.0), 2);
+
+        assert_eq!(
+            pyramid.visible_tiles([200.0, 0.0, 100.0, 300.0], 0),
+            vec![
+                TileCoord::new(0, 0, 0),
+                TileCoord::new(0, 1, 0),
+                TileCoord::new(0, 0, 1),
+                TileCoord::new(0, 1, 1),
+            ]
+        );
+        assert_eq!(
+            pyramid
+                .visible_tiles([-500.0, -500.0, 5000.0, 5000.0], 1)
+                .len(),
+            4
+        );
+    }
+}
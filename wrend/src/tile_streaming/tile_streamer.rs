@@ -0,0 +1,344 @@
+use crate::{CreateTextureError, TileCache, TileCoord, TilePyramid};
+use js_sys::Int32Array;
+use log::warn;
+use std::{cell::RefCell, collections::HashSet, fmt::Debug, iter, rc::Rc};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{HtmlImageElement, WebGl2RenderingContext, WebGlTexture};
+
+/// Tiles that have finished loading (`None` if they failed), waiting to be uploaded
+type LoadedTiles = Rc<RefCell<Vec<(TileCoord, Option<HtmlImageElement>)>>>;
+
+/// GLSL helper for sampling a [TileStreamer]'s textures.
+///
+/// Expects the atlas bound to `u_tile_atlas`, the lookup texture bound to `u_tile_lookup`,
+/// and the pyramid's full-resolution size, tile size and level count in the remaining uniforms.
+/// `sample_tiles` falls back to coarser levels while finer tiles are still loading.
+pub const TILE_STREAMING_GLSL: &str = r#"
+uniform highp sampler2DArray u_tile_atlas;
+uniform highp isampler2DArray u_tile_lookup;
+uniform vec2 u_tile_image_size;
+uniform float u_tile_size;
+uniform int u_tile_levels;
+
+// `uv` spans the full image (0.0 to 1.0, top-left origin)
+vec4 sample_tiles(vec2 uv, int level) {
+    vec2 pixel = uv * u_tile_image_size;
+    for (int l = level; l < u_tile_levels; l++) {
+        vec2 tile_position = pixel / (u_tile_size * exp2(float(l)));
+        int layer = texelFetch(u_tile_lookup, ivec3(floor(tile_position), l), 0).r;
+        if (layer >= 0) {
+            return texture(u_tile_atlas, vec3(fract(tile_position), float(layer)));
+        }
+    }
+    return vec4(0.0);
+}
+"#;
+
+/// Streams tiles of a very large image (e.g. for a gigapixel viewer) into a texture array on demand.
+///
+/// Register the region of the image being viewed with [TileStreamer::set_view] and call
+/// [TileStreamer::update] once per frame: tiles covering the view are requested, uploaded into a
+/// layer of the atlas as they arrive, and the least recently used tiles are evicted once the atlas is full.
+/// Tile availability is written to a lookup texture (one layer per pyramid level, one texel per tile,
+/// holding the atlas layer or `-1`), which shaders can read with [TILE_STREAMING_GLSL].
+///
+/// Tiles are expected to be `tile_size` square (smaller at the right and bottom edges) with no overlap.
+pub struct TileStreamer {
+    gl: WebGl2RenderingContext,
+    pyramid: TilePyramid,
+    cache: TileCache,
+    atlas: WebGlTexture,
+    lookup: WebGlTexture,
+    tile_url: Rc<dyn Fn(TileCoord) -> String>,
+    view: Option<([f64; 4], f64)>,
+    max_in_flight: usize,
+    requested: HashSet<TileCoord>,
+    failed: HashSet<TileCoord>,
+    loaded: LoadedTiles,
+}
+
+impl TileStreamer {
+    /// Default number of tile requests allowed to be in flight at once
+    pub const DEFAULT_MAX_IN_FLIGHT: usize = 6;
+
+    /// Creates the atlas (with room for `capacity` tiles) and the lookup texture.
+    ///
+    /// `tile_url` maps each tile to the url it should be loaded from. `capacity` should be large enough
+    /// to hold every tile visible at once, plus the single tile of the coarsest level.
+    pub fn new(
+        gl: WebGl2RenderingContext,
+        pyramid: TilePyramid,
+        capacity: u32,
+        tile_url: impl Fn(TileCoord) -> String + 'static,
+    ) -> Result<Self, CreateTextureError> {
+        let atlas = gl
+            .create_texture()
+            .ok_or(CreateTextureError::TextureCreationFailed)?;
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D_ARRAY, Some(&atlas));
+        gl.tex_storage_3d(
+            WebGl2RenderingContext::TEXTURE_2D_ARRAY,
+            1,
+            WebGl2RenderingContext::RGBA8,
+            pyramid.tile_size() as i32,
+            pyramid.tile_size() as i32,
+            capacity.max(1) as i32,
+        );
+        Self::set_sampling(&gl, WebGl2RenderingContext::LINEAR);
+
+        let (tiles_x, tiles_y) = pyramid.tile_count(0);
+        let levels = pyramid.levels();
+        let lookup = gl
+            .create_texture()
+            .ok_or(CreateTextureError::TextureCreationFailed)?;
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D_ARRAY, Some(&lookup));
+        gl.tex_storage_3d(
+            WebGl2RenderingContext::TEXTURE_2D_ARRAY,
+            1,
+            WebGl2RenderingContext::R32I,
+            tiles_x as i32,
+            tiles_y as i32,
+            levels as i32,
+        );
+        Self::set_sampling(&gl, WebGl2RenderingContext::NEAREST);
+        let unavailable = vec![-1; (tiles_x * tiles_y * levels) as usize];
+        Self::write_lookup(
+            &gl,
+            [0, 0, 0],
+            [tiles_x, tiles_y, levels],
+            &Int32Array::from(&unavailable[..]),
+        );
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D_ARRAY, None);
+
+        Ok(Self {
+            gl,
+            pyramid,
+            cache: TileCache::new(capacity),
+            atlas,
+            lookup,
+            tile_url: Rc::new(tile_url),
+            view: None,
+            max_in_flight: Self::DEFAULT_MAX_IN_FLIGHT,
+            requested: HashSet::new(),
+            failed: HashSet::new(),
+            loaded: Rc::new(RefCell::new(Vec::new())),
+        })
+    }
+
+    pub fn pyramid(&self) -> &TilePyramid {
+        &self.pyramid
+    }
+
+    /// The `TEXTURE_2D_ARRAY` that loaded tiles are uploaded into
+    pub fn atlas_texture(&self) -> &WebGlTexture {
+        &self.atlas
+    }
+
+    /// The `TEXTURE_2D_ARRAY` (`R32I`) mapping each tile to its atlas layer, or `-1` if unavailable
+    pub fn lookup_texture(&self) -> &WebGlTexture {
+        &self.lookup
+    }
+
+    /// Registers the region of the image currently being viewed.
+    ///
+    /// `rect` is `[x, y, width, height]` in full-resolution pixels (top-left origin), and
+    /// `image_pixels_per_screen_pixel` is used to pick which level of the pyramid to load.
+    pub fn set_view(&mut self, rect: [f64; 4], image_pixels_per_screen_pixel: f64) -> &mut Self {
+        self.view = Some((rect, image_pixels_per_screen_pixel));
+        self
+    }
+
+    /// The level tiles are currently being requested at, if a view has been registered
+    pub fn view_level(&self) -> Option<u32> {
+        self.view
+            .map(|(_, scale)| self.pyramid.level_for_scale(scale))
+    }
+
+    pub fn set_max_in_flight(&mut self, max_in_flight: usize) -> &mut Self {
+        self.max_in_flight = max_in_flight.max(1);
+        self
+    }
+
+    /// Maximum number of tiles the atlas can hold at once
+    pub fn capacity(&self) -> u32 {
+        self.cache.capacity()
+    }
+
+    pub fn is_available(&self, tile: &TileCoord) -> bool {
+        self.cache.contains(tile)
+    }
+
+    /// Which layer of the atlas a tile was uploaded to, if it is currently available
+    pub fn atlas_layer(&self, tile: &TileCoord) -> Option<u32> {
+        self.cache.layer(tile)
+    }
+
+    /// Every tile currently uploaded to the atlas, in no particular order
+    pub fn available_tiles(&self) -> impl Iterator<Item = &TileCoord> {
+        self.cache.tiles()
+    }
+
+    /// Number of tile requests that have been started but not yet finished
+    pub fn pending_count(&self) -> usize {
+        self.requested.len()
+    }
+
+    /// Uploads any tiles that have finished loading and requests any missing tiles covering the view.
+    ///
+    /// Call once per frame, before rendering.
+    pub fn update(&mut self) {
+        self.upload_loaded_tiles();
+
+        let Some((rect, scale)) = self.view else {
+            return;
+        };
+        let level = self.pyramid.level_for_scale(scale);
+        // the coarsest tile is always kept around, so there is something to fall back to
+        let coarsest = TileCoord::new(self.pyramid.levels() - 1, 0, 0);
+
+        for tile in iter::once(coarsest).chain(self.pyramid.visible_tiles(rect, level)) {
+            if self.cache.contains(&tile) {
+                self.cache.touch(&tile);
+            } else if !self.requested.contains(&tile)
+                && !self.failed.contains(&tile)
+                && self.requested.len() < self.max_in_flight
+            {
+                self.request_tile(tile);
+            }
+        }
+    }
+
+    fn request_tile(&mut self, tile: TileCoord) {
+        let Ok(image) = HtmlImageElement::new() else {
+            return;
+        };
+        // allows the tile to be uploaded to WebGL when served from another origin
+        image.set_cross_origin(Some("anonymous"));
+        image.set_src(&(self.tile_url)(tile));
+        self.requested.insert(tile);
+
+        let loaded = Rc::clone(&self.loaded);
+        wasm_bindgen_futures::spawn_local(async move {
+            let decoded = JsFuture::from(image.decode()).await.is_ok();
+            loaded.borrow_mut().push((tile, decoded.then_some(image)));
+        });
+    }
+
+    fn upload_loaded_tiles(&mut self) {
+        let loaded: Vec<_> = self.loaded.borrow_mut().drain(..).collect();
+        if loaded.is_empty() {
+            return;
+        }
+
+        for (tile, image) in loaded {
+            self.requested.remove(&tile);
+            let Some(image) = image else {
+                warn!("Failed to load tile {tile:?}");
+                self.failed.insert(tile);
+                continue;
+            };
+            let Some((layer, evicted)) = self.cache.insert(tile) else {
+                continue;
+            };
+
+            self.gl
+                .bind_texture(WebGl2RenderingContext::TEXTURE_2D_ARRAY, Some(&self.atlas));
+            let upload = self.gl.tex_sub_image_3d_with_html_image_element(
+                WebGl2RenderingContext::TEXTURE_2D_ARRAY,
+                0,
+                0,
+                0,
+                layer as i32,
+                image.natural_width().min(self.pyramid.tile_size()) as i32,
+                image.natural_height().min(self.pyramid.tile_size()) as i32,
+                1,
+                WebGl2RenderingContext::RGBA,
+                WebGl2RenderingContext::UNSIGNED_BYTE,
+                &image,
+            );
+            if let Err(err) = upload {
+                warn!("Failed to upload tile {tile:?}: {err:?}");
+            }
+
+            self.gl
+                .bind_texture(WebGl2RenderingContext::TEXTURE_2D_ARRAY, Some(&self.lookup));
+            if let Some(evicted) = evicted {
+                self.set_lookup(&evicted, -1);
+            }
+            self.set_lookup(&tile, layer as i32);
+        }
+
+        self.gl
+            .bind_texture(WebGl2RenderingContext::TEXTURE_2D_ARRAY, None);
+    }
+
+    /// Expects the lookup texture to be bound
+    fn set_lookup(&self, tile: &TileCoord, layer: i32) {
+        Self::write_lookup(
+            &self.gl,
+            [tile.x(), tile.y(), tile.level()],
+            [1, 1, 1],
+            &Int32Array::from(&[layer][..]),
+        );
+    }
+
+    fn write_lookup(
+        gl: &WebGl2RenderingContext,
+        [x, y, level]: [u32; 3],
+        [width, height, depth]: [u32; 3],
+        data: &Int32Array,
+    ) {
+        let result = gl.tex_sub_image_3d_with_opt_array_buffer_view(
+            WebGl2RenderingContext::TEXTURE_2D_ARRAY,
+            0,
+            x as i32,
+            y as i32,
+            level as i32,
+            width as i32,
+            height as i32,
+            depth as i32,
+            WebGl2RenderingContext::RED_INTEGER,
+            WebGl2RenderingContext::INT,
+            Some(data),
+        );
+        if let Err(err) = result {
+            warn!("Failed to update tile lookup texture: {err:?}");
+        }
+    }
+
+    fn set_sampling(gl: &WebGl2RenderingContext, filter: u32) {
+        let target = WebGl2RenderingContext::TEXTURE_2D_ARRAY;
+        gl.tex_parameteri(
+            target,
+            WebGl2RenderingContext::TEXTURE_MIN_FILTER,
+            filter as i32,
+        );
+        gl.tex_parameteri(
+            target,
+            WebGl2RenderingContext::TEXTURE_MAG_FILTER,
+            filter as i32,
+        );
+        gl.tex_parameteri(
+            target,
+            WebGl2RenderingContext::TEXTURE_WRAP_S,
+            WebGl2RenderingContext::CLAMP_TO_EDGE as i32,
+        );
+        gl.tex_parameteri(
+            target,
+            WebGl2RenderingContext::TEXTURE_WRAP_T,
+            WebGl2RenderingContext::CLAMP_TO_EDGE as i32,
+        );
+    }
+}
+
+impl Debug for TileStreamer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TileStreamer")
+            .field("pyramid", &self.pyramid)
+            .field("cache", &self.cache)
+            .field("view", &self.view)
+            .field("max_in_flight", &self.max_in_flight)
+            .field("requested", &self.requested)
+            .field("failed", &self.failed)
+            .finish()
+    }
+}
@@ -1,5 +1,7 @@
 mod transform_feedback_link;
+#[cfg(feature = "js")]
 mod transform_feedback_link_js;
 
 pub use transform_feedback_link::*;
+#[cfg(feature = "js")]
 pub use transform_feedback_link_js::*;
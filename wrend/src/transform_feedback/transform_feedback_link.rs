@@ -15,4 +15,14 @@ impl<TransformFeedbackId: Id> TransformFeedbackLink<TransformFeedbackId> {
     pub fn transform_feedback_id(&self) -> &TransformFeedbackId {
         &self.transform_feedback_id
     }
+
+    /// The same link with its id replaced (used when scoping links to a namespace)
+    pub(crate) fn map_ids(
+        self,
+        transform_feedback_id: impl Fn(&TransformFeedbackId) -> TransformFeedbackId,
+    ) -> Self {
+        Self {
+            transform_feedback_id: transform_feedback_id(&self.transform_feedback_id),
+        }
+    }
 }
@@ -0,0 +1,7 @@
+mod tweak_color;
+mod tweak_panel;
+mod tweak_range;
+
+pub(crate) use tweak_color::*;
+pub use tweak_panel::*;
+pub use tweak_range::*;
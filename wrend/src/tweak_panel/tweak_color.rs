@@ -0,0 +1,37 @@
+/// Converts 0.0..=1.0 rgb components into a `#rrggbb` string for `<input type="color">`
+pub(crate) fn rgb_to_hex(rgb: &[f32]) -> String {
+    let channel = |i: usize| {
+        let value = rgb.get(i).copied().unwrap_or_default();
+        (value.clamp(0.0, 1.0) * 255.0).round() as u8
+    };
+    format!("#{:02x}{:02x}{:02x}", channel(0), channel(1), channel(2))
+}
+
+/// Parses a `#rrggbb` string into 0.0..=1.0 rgb components
+pub(crate) fn hex_to_rgb(hex: &str) -> Option<[f32; 3]> {
+    let hex = hex.strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let channel = |i: usize| {
+        u8::from_str_radix(hex.get(i * 2..i * 2 + 2)?, 16)
+            .ok()
+            .map(|value| value as f32 / 255.0)
+    };
+    Some([channel(0)?, channel(1)?, channel(2)?])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hex_to_rgb, rgb_to_hex};
+
+    #[test]
+    fn converts_hex_colors() {
+        assert_eq!(rgb_to_hex(&[1.0, 0.0, 0.5, 1.0]), "#ff0080");
+        assert_eq!(rgb_to_hex(&[2.0, -1.0]), "#ff0000");
+        assert_eq!(hex_to_rgb("#ff0000"), Some([1.0, 0.0, 0.0]));
+        assert_eq!(hex_to_rgb("ff0000"), None);
+        assert_eq!(hex_to_rgb("#ff00"), None);
+        assert_eq!(hex_to_rgb("#gg0000"), None);
+    }
+}
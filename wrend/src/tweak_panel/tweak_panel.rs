@@ -0,0 +1,258 @@
+use crate::{
+    hex_to_rgb, rgb_to_hex, Listener, SettingValue, SettingsStore, SettingsSubscription,
+    TweakPanelError, TweakRange,
+};
+use std::collections::{HashMap, HashSet};
+use wasm_bindgen::JsCast;
+use web_sys::{Document, Element, Event, HtmlInputElement, Node};
+
+const PANEL_STYLE: &str = "position: fixed; top: 8px; right: 8px; z-index: 1000; \
+    padding: 8px; background: rgba(0, 0, 0, 0.75); color: #fff; \
+    font: 12px sans-serif; border-radius: 4px; max-height: 90vh; overflow-y: auto;";
+const ROW_STYLE: &str = "display: flex; align-items: center; gap: 8px; margin: 4px 0;";
+
+/// A lightweight, dat.gui-style control panel generated from the settings in a [SettingsStore].
+///
+/// Floats become sliders (or number inputs, if no [TweakRange] is given), vectors become a number
+/// input per component (or a color picker, if marked with [TweakPanel::set_color]), bools become
+/// checkboxes, and text becomes a text input.
+/// Edits are written back through [SettingsStore::set], so uniforms using
+/// [SettingsStore::uniform_should_update_callback] update automatically.
+#[derive(Debug)]
+pub struct TweakPanel {
+    store: SettingsStore,
+    title: Option<String>,
+    ranges: HashMap<String, TweakRange>,
+    colors: HashSet<String>,
+    root: Option<Element>,
+    listeners: Vec<Listener<Node, Event>>,
+    store_listeners: SettingsSubscription,
+}
+
+impl TweakPanel {
+    pub fn new(store: SettingsStore) -> Self {
+        Self {
+            title: None,
+            ranges: HashMap::new(),
+            colors: HashSet::new(),
+            root: None,
+            listeners: Vec::new(),
+            store_listeners: SettingsSubscription::new(store.clone()),
+            store,
+        }
+    }
+
+    pub fn store(&self) -> &SettingsStore {
+        &self.store
+    }
+
+    pub fn set_title(&mut self, title: impl Into<String>) -> &mut Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Renders the named float setting as a slider with the given bounds
+    pub fn set_range(&mut self, name: impl Into<String>, range: TweakRange) -> &mut Self {
+        self.ranges.insert(name.into(), range);
+        self
+    }
+
+    /// Renders the named 3 or 4 component setting as a color picker, keeping any alpha component
+    /// as it is
+    pub fn set_color(&mut self, name: impl Into<String>) -> &mut Self {
+        self.colors.insert(name.into());
+        self
+    }
+
+    /// The panel's root element, if it is currently mounted
+    pub fn root(&self) -> Option<&Element> {
+        self.root.as_ref()
+    }
+
+    /// Builds the panel and appends it to `parent`, replacing any previously mounted panel
+    pub fn mount(&mut self, parent: &Element) -> Result<(), TweakPanelError> {
+        self.unmount();
+
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .ok_or(TweakPanelError::NoDocument)?;
+
+        let root = create_element(&document, "div")?;
+        root.set_attribute("style", PANEL_STYLE)
+            .map_err(|_| TweakPanelError::CreateElement("div"))?;
+
+        if let Some(title) = &self.title {
+            let heading = create_element(&document, "strong")?;
+            heading.set_text_content(Some(title));
+            append(&root, &heading)?;
+        }
+
+        for name in self.store.names() {
+            let value = match self.store.get(&name) {
+                Some(value) => value,
+                None => continue,
+            };
+            let row = self.create_row(&document, &name, &value)?;
+            append(&root, &row)?;
+        }
+
+        append(parent, &root)?;
+        self.root = Some(root);
+
+        Ok(())
+    }
+
+    /// Removes the panel from the DOM and detaches its event listeners, including the ones that
+    /// keep its inputs in sync with the store
+    pub fn unmount(&mut self) {
+        self.listeners.clear();
+        self.store_listeners.clear();
+        if let Some(root) = self.root.take() {
+            root.remove();
+        }
+    }
+
+    fn create_row(
+        &mut self,
+        document: &Document,
+        name: &str,
+        value: &SettingValue,
+    ) -> Result<Element, TweakPanelError> {
+        let row = create_element(document, "label")?;
+        row.set_attribute("style", ROW_STYLE)
+            .map_err(|_| TweakPanelError::CreateElement("label"))?;
+        let label = create_element(document, "span")?;
+        label.set_text_content(Some(name));
+        append(&row, &label)?;
+
+        let is_color = self.colors.contains(name)
+            && matches!(value, SettingValue::Floats(values) if matches!(values.len(), 3 | 4));
+        let (input_type, event_name) = match value {
+            SettingValue::Bool(_) => ("checkbox", "change"),
+            SettingValue::Float(_) if self.ranges.contains_key(name) => ("range", "input"),
+            SettingValue::Float(_) => ("number", "input"),
+            SettingValue::Floats(_) if is_color => ("color", "input"),
+            SettingValue::Floats(_) => ("number", "input"),
+            SettingValue::Text(_) => ("text", "change"),
+        };
+        let input_count = match value {
+            SettingValue::Floats(values) if !is_color => values.len(),
+            _ => 1,
+        };
+        let mut inputs = Vec::with_capacity(input_count);
+        for _ in 0..input_count {
+            let input: HtmlInputElement = create_element(document, "input")?
+                .dyn_into()
+                .map_err(|_| TweakPanelError::CreateElement("input"))?;
+            input.set_type(input_type);
+            if let Some(range) = self.ranges.get(name) {
+                input.set_min(&range.min().to_string());
+                input.set_max(&range.max().to_string());
+                input.set_step(&range.step().to_string());
+            } else if input_type == "number" {
+                input.set_step("any");
+            }
+            inputs.push(input);
+        }
+        write_inputs(&inputs, value, is_color);
+
+        // keep the controls in sync when the setting is changed from elsewhere (e.g. reset)
+        {
+            let inputs = inputs.clone();
+            self.store_listeners.add_listener(name, move |_, value| {
+                write_inputs(&inputs, value, is_color);
+            });
+        }
+
+        for input in &inputs {
+            let listener = {
+                let store = self.store.clone();
+                let name = name.to_string();
+                let inputs = inputs.clone();
+                Listener::new(
+                    input.clone().unchecked_into::<Node>(),
+                    event_name,
+                    move |_| {
+                        let current = match store.get(&name) {
+                            Some(current) => current,
+                            None => return,
+                        };
+                        if let Some(value) = read_inputs(&inputs, &current, is_color) {
+                            if let Err(e) = store.set(&name, value) {
+                                log::warn!("Could not update setting {name:?}: {e}");
+                            }
+                        }
+                    },
+                )
+            };
+            self.listeners.push(listener);
+            append(&row, input)?;
+        }
+
+        Ok(row)
+    }
+}
+
+impl Drop for TweakPanel {
+    fn drop(&mut self) {
+        self.unmount();
+    }
+}
+
+fn create_element(document: &Document, tag: &'static str) -> Result<Element, TweakPanelError> {
+    document
+        .create_element(tag)
+        .map_err(|_| TweakPanelError::CreateElement(tag))
+}
+
+fn append(parent: &Element, child: &Element) -> Result<(), TweakPanelError> {
+    parent
+        .append_child(child)
+        .map(|_| ())
+        .map_err(|_| TweakPanelError::AppendElement)
+}
+
+/// Writes `value` into the row's inputs: one per component for vectors that aren't colors
+fn write_inputs(inputs: &[HtmlInputElement], value: &SettingValue, is_color: bool) {
+    match value {
+        SettingValue::Bool(value) => inputs.iter().for_each(|input| input.set_checked(*value)),
+        SettingValue::Float(value) => inputs
+            .iter()
+            .for_each(|input| input.set_value(&value.to_string())),
+        SettingValue::Floats(values) if is_color => inputs
+            .iter()
+            .for_each(|input| input.set_value(&rgb_to_hex(values))),
+        SettingValue::Floats(values) => {
+            for (input, value) in inputs.iter().zip(values) {
+                input.set_value(&value.to_string());
+            }
+        }
+        SettingValue::Text(value) => inputs.iter().for_each(|input| input.set_value(value)),
+    }
+}
+
+/// Reads the row's inputs back into a setting of the same kind as `current`
+fn read_inputs(
+    inputs: &[HtmlInputElement],
+    current: &SettingValue,
+    is_color: bool,
+) -> Option<SettingValue> {
+    let input = inputs.first()?;
+    match current {
+        SettingValue::Bool(_) => Some(SettingValue::Bool(input.checked())),
+        SettingValue::Float(_) => input.value().trim().parse().ok().map(SettingValue::Float),
+        SettingValue::Floats(current) if is_color => {
+            let mut values = hex_to_rgb(&input.value())?.to_vec();
+            // color pickers have no alpha, so the existing alpha is preserved
+            values.extend(current.get(3));
+            Some(SettingValue::Floats(values))
+        }
+        SettingValue::Floats(_) => inputs
+            .iter()
+            .map(|input| input.value().trim().parse())
+            .collect::<Result<Vec<f32>, _>>()
+            .ok()
+            .map(SettingValue::Floats),
+        SettingValue::Text(_) => Some(SettingValue::Text(input.value())),
+    }
+}
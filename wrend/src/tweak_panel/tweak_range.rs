@@ -0,0 +1,31 @@
+/// Slider bounds for a float setting in a [crate::TweakPanel]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct TweakRange {
+    min: f32,
+    max: f32,
+    step: f32,
+}
+
+impl TweakRange {
+    pub fn new(min: f32, max: f32, step: f32) -> Self {
+        Self { min, max, step }
+    }
+
+    pub fn min(&self) -> f32 {
+        self.min
+    }
+
+    pub fn max(&self) -> f32 {
+        self.max
+    }
+
+    pub fn step(&self) -> f32 {
+        self.step
+    }
+}
+
+impl Default for TweakRange {
+    fn default() -> Self {
+        Self::new(0.0, 1.0, 0.01)
+    }
+}
@@ -3,19 +3,33 @@ mod uniform_context;
 mod uniform_context_js;
 mod uniform_create_update_callback;
 mod uniform_create_update_callback_js;
+mod uniform_default;
+mod uniform_group;
+mod uniform_group_cache;
+#[cfg(feature = "js")]
 mod uniform_js;
 mod uniform_link;
+#[cfg(feature = "js")]
 mod uniform_link_js;
 mod uniform_should_update_callback;
 mod uniform_should_update_callback_js;
+mod uniform_update_frequency;
+mod uniform_value;
 
 pub use uniform::*;
 pub use uniform_context::*;
 pub use uniform_context_js::*;
 pub use uniform_create_update_callback::*;
 pub use uniform_create_update_callback_js::*;
+pub(crate) use uniform_default::*;
+pub use uniform_group::*;
+pub(crate) use uniform_group_cache::*;
+#[cfg(feature = "js")]
 pub use uniform_js::*;
 pub use uniform_link::*;
+#[cfg(feature = "js")]
 pub use uniform_link_js::*;
 pub use uniform_should_update_callback::*;
 pub use uniform_should_update_callback_js::*;
+pub use uniform_update_frequency::*;
+pub use uniform_value::*;
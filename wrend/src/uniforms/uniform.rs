@@ -1,10 +1,15 @@
 use crate::Callback;
 use crate::Id;
+use crate::LoopTime;
 use crate::UniformContext;
 use crate::UniformCreateUpdateCallback;
+#[cfg(feature = "js")]
 use crate::UniformJs;
+#[cfg(feature = "js")]
 use crate::UniformJsInner;
 use crate::UniformShouldUpdateCallback;
+use crate::UniformUpdateFrequency;
+use std::cell::Cell;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::hash::Hash;
@@ -20,33 +25,56 @@ use web_sys::{WebGl2RenderingContext, WebGlProgram, WebGlUniformLocation};
 pub struct Uniform<ProgramId: Id, UniformId: Id> {
     program_ids: Vec<ProgramId>,
     uniform_id: UniformId,
+    /// only used for lookups by id: updates iterate over `contexts` instead
     uniform_locations: HashMap<ProgramId, WebGlUniformLocation>,
+    /// one entry per program, in the same order as `program_ids`, with the program resolved at build time
+    /// so that updates don't need any hash lookups. Contexts are reused for every update,
+    /// rather than cloning the context and location each time.
+    contexts: Vec<(WebGlProgram, UniformContext)>,
     uniform_create_callback: UniformCreateUpdateCallback,
     update_callback: Option<UniformCreateUpdateCallback>,
     should_update_callback: Option<UniformShouldUpdateCallback>,
     use_init_callback_for_update: bool,
+    update_frequency: UniformUpdateFrequency,
+    last_updated: Cell<Option<f64>>,
+    invalidated: Cell<bool>,
 }
 
 impl<ProgramId: Id, UniformId: Id> Uniform<ProgramId, UniformId> {
     // @todo move into builder pattern
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         program_ids: Vec<ProgramId>,
         uniform_id: UniformId,
         // a single "conceptual" uniform can be shared across multiple programs and updated in tandem
-        uniform_locations: HashMap<ProgramId, WebGlUniformLocation>,
+        contexts: Vec<(ProgramId, WebGlProgram, UniformContext)>,
         initialize_callback: UniformCreateUpdateCallback,
         update_callback: Option<UniformCreateUpdateCallback>,
         should_update_callback: Option<UniformShouldUpdateCallback>,
         use_init_callback_for_update: bool,
+        update_frequency: UniformUpdateFrequency,
     ) -> Self {
+        let uniform_locations = contexts
+            .iter()
+            .map(|(program_id, _, ctx)| (program_id.clone(), ctx.uniform_location().clone()))
+            .collect();
+        let contexts = contexts
+            .into_iter()
+            .map(|(_, program, ctx)| (program, ctx))
+            .collect();
+
         Self {
             program_ids,
             uniform_id,
             uniform_locations,
+            contexts,
             uniform_create_callback: initialize_callback,
             update_callback,
             should_update_callback,
             use_init_callback_for_update,
+            update_frequency,
+            last_updated: Cell::new(None),
+            invalidated: Cell::new(false),
         }
     }
 
@@ -89,31 +117,59 @@ impl<ProgramId: Id, UniformId: Id> Uniform<ProgramId, UniformId> {
         self.use_init_callback_for_update
     }
 
+    /// See [UniformUpdateFrequency]
+    pub fn update_frequency(&self) -> UniformUpdateFrequency {
+        self.update_frequency
+    }
+
+    /// Forces this uniform to be updated on the next call to [crate::RendererData::update_uniforms],
+    /// regardless of its [UniformUpdateFrequency]
+    pub fn invalidate(&self) {
+        self.invalidated.set(true);
+    }
+
+    /// Whether [crate::RendererData::update_uniforms] should update this uniform at time `now`
+    pub fn is_due(&self, now: f64) -> bool {
+        self.update_frequency
+            .is_due(now, self.last_updated.get(), self.invalidated.get())
+    }
+
     /// Updates the value of this uniform in WebGl for every Program where this uniform is used,
     /// using the update callback that was passed in at creation time.
     ///
-    /// @todo: calling this function for anything more than the current program is useless without a UBO
+    /// `programs` is no longer used: each uniform's programs are resolved when the renderer is built.
+    #[deprecated(
+        note = "use `Uniform::update_with_loop_time`, which doesn't need the programs map"
+    )]
     pub fn update(
         &self,
         gl: &WebGl2RenderingContext,
         now: f64,
-        programs: &HashMap<ProgramId, WebGlProgram>,
+        _programs: &HashMap<ProgramId, WebGlProgram>,
     ) {
-        let uniform_locations = self.uniform_locations();
-
-        for (program_id, uniform_location) in uniform_locations.iter() {
-            let program = programs
-                .get(program_id)
-                .expect("Program id should correspond to a saved WebGlProgram");
+        self.update_with_loop_time(gl, now, None);
+    }
 
+    /// Updates the value of this uniform in WebGl for every Program where this uniform is used,
+    /// using the update callback that was passed in at creation time.
+    ///
+    /// `loop_time` is passed on to the callbacks through [UniformContext::loop_time] (`None` if not looping).
+    ///
+    /// @todo: calling this function for anything more than the current program is useless without a UBO
+    pub fn update_with_loop_time(
+        &self,
+        gl: &WebGl2RenderingContext,
+        now: f64,
+        loop_time: Option<LoopTime>,
+    ) {
+        for (program, ctx) in self.contexts.iter() {
             gl.use_program(Some(program));
+            ctx.set_now(now);
+            ctx.set_loop_time(loop_time);
 
-            let ctx = UniformContext::new(gl.clone(), now, uniform_location.clone());
-            let should_update_callback = self.should_update_callback();
-
-            let should_call = if let Some(should_update_callback) = should_update_callback {
-                match &*should_update_callback {
-                    Callback::Rust(rust_callback) => (rust_callback)(&ctx),
+            let should_call = if let Some(should_update_callback) = &self.should_update_callback {
+                match &**should_update_callback {
+                    Callback::Rust(rust_callback) => (rust_callback)(ctx),
                     Callback::Js(js_callback) => {
                         JsValue::as_bool(&js_callback.call0(&JsValue::NULL).expect(
                             "Should be able to call `should_update_callback` JavaScript callback",
@@ -129,14 +185,17 @@ impl<ProgramId: Id, UniformId: Id> Uniform<ProgramId, UniformId> {
 
             if should_call {
                 if self.use_init_callback_for_update {
-                    self.uniform_create_callback.call_with_into_js_arg(&ctx);
+                    self.uniform_create_callback.call_with_into_js_arg(ctx);
                 } else if let Some(update_callback) = &self.update_callback {
-                    update_callback.call_with_into_js_arg(&ctx)
+                    update_callback.call_with_into_js_arg(ctx)
                 }
             }
 
             gl.use_program(None);
         }
+
+        self.last_updated.set(Some(now));
+        self.invalidated.set(false);
     }
 }
 
@@ -162,6 +221,7 @@ impl<ProgramId: Id, UniformId: Id> PartialEq for Uniform<ProgramId, UniformId> {
 
 impl<ProgramId: Id, UniformId: Id> Eq for Uniform<ProgramId, UniformId> {}
 
+#[cfg(feature = "js")]
 impl From<UniformJsInner> for JsValue {
     fn from(uniform: UniformJsInner) -> Self {
         let js_uniform: UniformJs = uniform.into();
@@ -169,6 +229,7 @@ impl From<UniformJsInner> for JsValue {
     }
 }
 
+#[cfg(feature = "js")]
 impl From<UniformJs> for UniformJsInner {
     fn from(js_uniform: UniformJs) -> Self {
         js_uniform.into_inner()
@@ -1,11 +1,23 @@
+use crate::{LoopTime, SamplerTextureUnits};
+use std::{
+    cell::{Cell, RefCell},
+    fmt::Debug,
+    rc::Rc,
+};
 use web_sys::{WebGl2RenderingContext, WebGlUniformLocation};
 
 #[derive(Debug, Clone)]
 /// This is the context object that is passed to each uniform's update callback
+///
+/// Each [crate::Uniform] keeps one context per program and reuses it for every update,
+/// so that updating a uniform from Rust doesn't clone any JavaScript handles
+/// (see the `update_uniforms` comparison in `tests/overhead.rs` for the per-frame cost).
 pub struct UniformContext {
     gl: WebGl2RenderingContext,
-    now: f64,
+    now: Cell<f64>,
+    loop_time: Cell<Option<LoopTime>>,
     uniform_location: WebGlUniformLocation,
+    texture_units: Rc<RefCell<SamplerTextureUnits>>,
 }
 
 impl UniformContext {
@@ -17,8 +29,10 @@ impl UniformContext {
     ) -> Self {
         Self {
             gl,
-            now,
+            now: Cell::new(now),
+            loop_time: Cell::new(None),
             uniform_location,
+            texture_units: Default::default(),
         }
     }
 
@@ -27,10 +41,39 @@ impl UniformContext {
     }
 
     pub fn now(&self) -> f64 {
-        self.now
+        self.now.get()
+    }
+
+    pub(crate) fn set_now(&self, now: f64) {
+        self.now.set(now);
+    }
+
+    /// Position within the renderer's [crate::AnimationLoop], if one has been set.
+    /// Useful for driving a normalized `u_t` uniform that loops seamlessly.
+    pub fn loop_time(&self) -> Option<LoopTime> {
+        self.loop_time.get()
+    }
+
+    pub(crate) fn set_loop_time(&self, loop_time: Option<LoopTime>) {
+        self.loop_time.set(loop_time);
     }
 
     pub fn uniform_location(&self) -> &WebGlUniformLocation {
         &self.uniform_location
     }
+
+    /// Texture unit that the renderer's [crate::TextureUnits] allocator assigned to a texture.
+    ///
+    /// While the renderer is being built, a texture that doesn't have a unit yet is given the
+    /// lowest free one and bound to it, so sampler uniforms can be set to this once, from an
+    /// initialize callback. Returns `None` once every texture unit is taken.
+    pub fn texture_unit<TextureId: Debug>(&self, texture_id: &TextureId) -> Option<u32> {
+        self.texture_units
+            .borrow_mut()
+            .unit(&self.gl, &format!("{texture_id:?}"))
+    }
+
+    pub(crate) fn set_texture_units(&mut self, texture_units: Rc<RefCell<SamplerTextureUnits>>) {
+        self.texture_units = texture_units;
+    }
 }
@@ -18,6 +18,25 @@ impl UniformContextJs {
         self.deref().now()
     }
 
+    /// Normalized time within the renderer's loop, in `[0, 1)` (`undefined` if no loop has been set)
+    #[wasm_bindgen(js_name = loopT)]
+    pub fn loop_t(&self) -> Option<f64> {
+        self.deref().loop_time().map(|loop_time| loop_time.t())
+    }
+
+    #[wasm_bindgen(js_name = loopCount)]
+    pub fn loop_count(&self) -> Option<u32> {
+        self.deref()
+            .loop_time()
+            .map(|loop_time| loop_time.loop_count())
+    }
+
+    /// Texture unit assigned to a texture, which is already bound to it during initialization
+    #[wasm_bindgen(js_name = textureUnit)]
+    pub fn texture_unit(&self, texture_id: String) -> Option<u32> {
+        self.deref().texture_unit(&texture_id)
+    }
+
     #[wasm_bindgen(js_name = uniformLocation)]
     pub fn uniform_location(&self) -> WebGlUniformLocation {
         self.deref().uniform_location().to_owned()
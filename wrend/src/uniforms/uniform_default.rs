@@ -0,0 +1,178 @@
+use std::fmt::Display;
+use web_sys::{WebGl2RenderingContext, WebGlUniformLocation};
+
+/// The value given to an active uniform that has no [crate::UniformLink] at build time,
+/// so that it never relies on whatever value the driver happens to initialize it with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) enum UniformDefault {
+    /// All components set to `0.0`
+    Float { components: usize },
+    /// All components set to `0` (also used for `bool` uniforms)
+    Int { components: usize },
+    /// All components set to `0`
+    Uint { components: usize },
+    /// The identity matrix (with ones along the main diagonal for non-square matrices)
+    Matrix { columns: usize, rows: usize },
+}
+
+impl UniformDefault {
+    /// Returns `None` for samplers and any other types that should not be touched
+    pub fn from_gl_type(gl_type: u32) -> Option<Self> {
+        use UniformDefault::*;
+        use WebGl2RenderingContext as Gl;
+
+        let default = match gl_type {
+            Gl::FLOAT => Float { components: 1 },
+            Gl::FLOAT_VEC2 => Float { components: 2 },
+            Gl::FLOAT_VEC3 => Float { components: 3 },
+            Gl::FLOAT_VEC4 => Float { components: 4 },
+            Gl::INT | Gl::BOOL => Int { components: 1 },
+            Gl::INT_VEC2 | Gl::BOOL_VEC2 => Int { components: 2 },
+            Gl::INT_VEC3 | Gl::BOOL_VEC3 => Int { components: 3 },
+            Gl::INT_VEC4 | Gl::BOOL_VEC4 => Int { components: 4 },
+            Gl::UNSIGNED_INT => Uint { components: 1 },
+            Gl::UNSIGNED_INT_VEC2 => Uint { components: 2 },
+            Gl::UNSIGNED_INT_VEC3 => Uint { components: 3 },
+            Gl::UNSIGNED_INT_VEC4 => Uint { components: 4 },
+            Gl::FLOAT_MAT2 => Matrix {
+                columns: 2,
+                rows: 2,
+            },
+            Gl::FLOAT_MAT3 => Matrix {
+                columns: 3,
+                rows: 3,
+            },
+            Gl::FLOAT_MAT4 => Matrix {
+                columns: 4,
+                rows: 4,
+            },
+            Gl::FLOAT_MAT2X3 => Matrix {
+                columns: 2,
+                rows: 3,
+            },
+            Gl::FLOAT_MAT2X4 => Matrix {
+                columns: 2,
+                rows: 4,
+            },
+            Gl::FLOAT_MAT3X2 => Matrix {
+                columns: 3,
+                rows: 2,
+            },
+            Gl::FLOAT_MAT3X4 => Matrix {
+                columns: 3,
+                rows: 4,
+            },
+            Gl::FLOAT_MAT4X2 => Matrix {
+                columns: 4,
+                rows: 2,
+            },
+            Gl::FLOAT_MAT4X3 => Matrix {
+                columns: 4,
+                rows: 3,
+            },
+            _ => return None,
+        };
+
+        Some(default)
+    }
+
+    /// Column-major values for a single (non-array) uniform of this type
+    pub fn float_values(&self) -> Vec<f32> {
+        match *self {
+            UniformDefault::Matrix { columns, rows } => (0..columns)
+                .flat_map(|column| (0..rows).map(move |row| if row == column { 1.0 } else { 0.0 }))
+                .collect(),
+            UniformDefault::Float { components } => vec![0.0; components],
+            UniformDefault::Int { components } | UniformDefault::Uint { components } => {
+                vec![0.0; components]
+            }
+        }
+    }
+
+    /// Sets the default for every element of a (possibly array) uniform of `size` elements.
+    ///
+    /// The uniform's program must currently be in use.
+    pub fn apply(&self, gl: &WebGl2RenderingContext, location: &WebGlUniformLocation, size: i32) {
+        let size = size.max(1) as usize;
+        let location = Some(location);
+
+        match *self {
+            UniformDefault::Float { components } => {
+                let values = vec![0.0; components * size];
+                match components {
+                    1 => gl.uniform1fv_with_f32_array(location, &values),
+                    2 => gl.uniform2fv_with_f32_array(location, &values),
+                    3 => gl.uniform3fv_with_f32_array(location, &values),
+                    _ => gl.uniform4fv_with_f32_array(location, &values),
+                }
+            }
+            UniformDefault::Int { components } => {
+                let values = vec![0; components * size];
+                match components {
+                    1 => gl.uniform1iv_with_i32_array(location, &values),
+                    2 => gl.uniform2iv_with_i32_array(location, &values),
+                    3 => gl.uniform3iv_with_i32_array(location, &values),
+                    _ => gl.uniform4iv_with_i32_array(location, &values),
+                }
+            }
+            UniformDefault::Uint { components } => {
+                let values = vec![0; components * size];
+                match components {
+                    1 => gl.uniform1uiv_with_u32_array(location, &values),
+                    2 => gl.uniform2uiv_with_u32_array(location, &values),
+                    3 => gl.uniform3uiv_with_u32_array(location, &values),
+                    _ => gl.uniform4uiv_with_u32_array(location, &values),
+                }
+            }
+            UniformDefault::Matrix { columns, rows } => {
+                let values = self.float_values().repeat(size);
+                match (columns, rows) {
+                    (2, 2) => gl.uniform_matrix2fv_with_f32_array(location, false, &values),
+                    (3, 3) => gl.uniform_matrix3fv_with_f32_array(location, false, &values),
+                    (2, 3) => gl.uniform_matrix2x3fv_with_f32_array(location, false, &values),
+                    (2, 4) => gl.uniform_matrix2x4fv_with_f32_array(location, false, &values),
+                    (3, 2) => gl.uniform_matrix3x2fv_with_f32_array(location, false, &values),
+                    (3, 4) => gl.uniform_matrix3x4fv_with_f32_array(location, false, &values),
+                    (4, 2) => gl.uniform_matrix4x2fv_with_f32_array(location, false, &values),
+                    (4, 3) => gl.uniform_matrix4x3fv_with_f32_array(location, false, &values),
+                    _ => gl.uniform_matrix4fv_with_f32_array(location, false, &values),
+                }
+            }
+        }
+    }
+}
+
+impl Display for UniformDefault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UniformDefault::Matrix { .. } => write!(f, "identity"),
+            _ => write!(f, "zero"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UniformDefault;
+    use web_sys::WebGl2RenderingContext;
+
+    #[test]
+    fn defaults_matrices_to_identity() {
+        let mat3 = UniformDefault::from_gl_type(WebGl2RenderingContext::FLOAT_MAT3).unwrap();
+        assert_eq!(
+            mat3.float_values(),
+            vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]
+        );
+
+        let mat2x3 = UniformDefault::from_gl_type(WebGl2RenderingContext::FLOAT_MAT2X3).unwrap();
+        assert_eq!(mat2x3.float_values(), vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+
+        let vec3 = UniformDefault::from_gl_type(WebGl2RenderingContext::FLOAT_VEC3).unwrap();
+        assert_eq!(vec3.float_values(), vec![0.0; 3]);
+
+        assert_eq!(
+            UniformDefault::from_gl_type(WebGl2RenderingContext::SAMPLER_2D),
+            None
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use crate::UniformValue;
+
+/// A set of named uniform values that can be uploaded all at once with
+/// [crate::RendererData::set_uniform_group], instead of registering an update callback per uniform.
+///
+/// With the `derive` feature enabled, this can be derived for structs whose fields convert into
+/// [UniformValue]. Each field maps to a uniform of the same name, which can be changed with
+/// `#[uniform(name = "...")]` on the field or `#[uniform(prefix = "...")]` on the struct.
+/// Fields marked `#[uniform(skip)]` are left out:
+///
+/// ```ignore
+/// #[derive(UniformGroup)]
+/// #[uniform(prefix = "u_")]
+/// struct Params {
+///     // uploaded to `u_speed`
+///     speed: f32,
+///     // uploaded to `u_tint`
+///     tint: [f32; 3],
+///     #[uniform(name = "u_iterations")]
+///     max_iterations: i32,
+///     #[uniform(skip)]
+///     label: String,
+/// }
+///
+/// renderer.set_uniform_group(&params);
+/// ```
+pub trait UniformGroup {
+    /// The uniform name and current value of every uniform in the group
+    fn uniforms(&self) -> Vec<(&'static str, UniformValue)>;
+}
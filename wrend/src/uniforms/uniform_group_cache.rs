@@ -0,0 +1,70 @@
+use crate::{Id, UniformValue};
+use std::{cell::RefCell, collections::HashMap};
+use web_sys::{WebGl2RenderingContext, WebGlProgram, WebGlUniformLocation};
+
+/// Uniform locations and last uploaded values for [crate::RendererData::set_uniform_group],
+/// so that each location is only looked up once and unchanged values aren't uploaded again.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct UniformGroupCache<ProgramId: Id> {
+    locations: RefCell<HashMap<(ProgramId, &'static str), Option<WebGlUniformLocation>>>,
+    values: RefCell<HashMap<(ProgramId, &'static str), UniformValue>>,
+}
+
+impl<ProgramId: Id> UniformGroupCache<ProgramId> {
+    /// `None` if the program doesn't declare (or the compiler optimized out) a uniform named `name`
+    pub fn location(
+        &self,
+        gl: &WebGl2RenderingContext,
+        program_id: &ProgramId,
+        program: &WebGlProgram,
+        name: &'static str,
+    ) -> Option<WebGlUniformLocation> {
+        self.locations
+            .borrow_mut()
+            .entry((program_id.clone(), name))
+            .or_insert_with(|| gl.get_uniform_location(program, name))
+            .clone()
+    }
+
+    /// Records `value` as the latest value of the uniform, returning whether it differs from the
+    /// previously recorded one
+    pub fn record(&self, program_id: &ProgramId, name: &'static str, value: UniformValue) -> bool {
+        let previous = self
+            .values
+            .borrow_mut()
+            .insert((program_id.clone(), name), value);
+        previous != Some(value)
+    }
+
+    /// Forgets all recorded values, so that everything is uploaded again on the next call
+    pub fn clear_values(&self) {
+        self.values.borrow_mut().clear();
+    }
+}
+
+// the cache doesn't affect what gets rendered, so it's ignored when comparing renderer data
+impl<ProgramId: Id> PartialEq for UniformGroupCache<ProgramId> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<ProgramId: Id> Eq for UniformGroupCache<ProgramId> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_only_changed_values() {
+        let cache = UniformGroupCache::<u32>::default();
+
+        assert!(cache.record(&0, "u_speed", UniformValue::Float(1.0)));
+        assert!(!cache.record(&0, "u_speed", UniformValue::Float(1.0)));
+        assert!(cache.record(&1, "u_speed", UniformValue::Float(1.0)));
+        assert!(cache.record(&0, "u_speed", UniformValue::Float(2.0)));
+
+        cache.clear_values();
+        assert!(cache.record(&0, "u_speed", UniformValue::Float(2.0)));
+    }
+}
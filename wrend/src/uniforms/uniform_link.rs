@@ -1,7 +1,11 @@
 use crate::Bridge;
 use crate::Id;
+use crate::IdName;
+use crate::UniformContext;
 use crate::UniformCreateUpdateCallback;
 use crate::UniformShouldUpdateCallback;
+use crate::UniformUpdateFrequency;
+use log::warn;
 use std::fmt::Debug;
 use std::hash::Hash;
 
@@ -11,10 +15,12 @@ use std::hash::Hash;
 pub struct UniformLink<ProgramId: Id, UniformId: Id> {
     program_ids: Vec<ProgramId>,
     uniform_id: UniformId,
+    name: Option<String>,
     initialize_callback: UniformCreateUpdateCallback,
     update_callback: Option<UniformCreateUpdateCallback>,
     should_update_callback: Option<UniformShouldUpdateCallback>,
     use_init_callback_for_update: bool,
+    update_frequency: UniformUpdateFrequency,
 }
 
 impl<ProgramId: Id, UniformId: Id> UniformLink<ProgramId, UniformId> {
@@ -29,13 +35,98 @@ impl<ProgramId: Id, UniformId: Id> UniformLink<ProgramId, UniformId> {
         Self {
             program_ids,
             uniform_id,
+            name: None,
             initialize_callback: initialize_callback.into(),
             use_init_callback_for_update: false,
+            update_frequency: UniformUpdateFrequency::default(),
             should_update_callback: None,
             update_callback: None,
         }
     }
 
+    /// Creates a link for a `float` uniform that never changes.
+    ///
+    /// Constant uniforms are [UniformUpdateFrequency::OnDemand], so they are only set again
+    /// after being invalidated.
+    pub fn constant_f32(
+        program_ids: impl Into<Bridge<ProgramId>>,
+        uniform_id: UniformId,
+        value: f32,
+    ) -> Self {
+        Self::constant(program_ids, uniform_id, move |ctx: &UniformContext| {
+            ctx.gl().uniform1f(Some(ctx.uniform_location()), value);
+        })
+    }
+
+    /// Creates a link for an `int` uniform that never changes (see [UniformLink::constant_f32])
+    pub fn constant_i32(
+        program_ids: impl Into<Bridge<ProgramId>>,
+        uniform_id: UniformId,
+        value: i32,
+    ) -> Self {
+        Self::constant(program_ids, uniform_id, move |ctx: &UniformContext| {
+            ctx.gl().uniform1i(Some(ctx.uniform_location()), value);
+        })
+    }
+
+    /// Creates a link for a `bool` uniform that never changes (see [UniformLink::constant_f32])
+    pub fn constant_bool(
+        program_ids: impl Into<Bridge<ProgramId>>,
+        uniform_id: UniformId,
+        value: bool,
+    ) -> Self {
+        Self::constant_i32(program_ids, uniform_id, value as i32)
+    }
+
+    /// Creates a link for a sampler uniform that reads from the unit assigned to `texture_id`
+    /// (see [crate::TextureUnits])
+    pub fn constant_texture_unit<TextureId: Id>(
+        program_ids: impl Into<Bridge<ProgramId>>,
+        uniform_id: UniformId,
+        texture_id: TextureId,
+    ) -> Self {
+        Self::constant(
+            program_ids,
+            uniform_id,
+            move |ctx: &UniformContext| match ctx.texture_unit(&texture_id) {
+                Some(unit) => ctx
+                    .gl()
+                    .uniform1i(Some(ctx.uniform_location()), unit as i32),
+                None => warn!("No texture unit was assigned to texture {texture_id:?}"),
+            },
+        )
+    }
+
+    /// Creates a link for a `mat4` uniform that is recomputed with `matrix` every time
+    /// the uniform is updated
+    pub fn matrix4_from(
+        program_ids: impl Into<Bridge<ProgramId>>,
+        uniform_id: UniformId,
+        matrix: impl Fn(&UniformContext) -> [f32; 16] + 'static,
+    ) -> Self {
+        let mut uniform_link = Self::new(program_ids, uniform_id, move |ctx: &UniformContext| {
+            ctx.gl().uniform_matrix4fv_with_f32_array(
+                Some(ctx.uniform_location()),
+                false,
+                &matrix(ctx),
+            );
+        });
+        uniform_link.set_use_init_callback_for_update(true);
+        uniform_link
+    }
+
+    fn constant(
+        program_ids: impl Into<Bridge<ProgramId>>,
+        uniform_id: UniformId,
+        callback: impl Fn(&UniformContext) + 'static,
+    ) -> Self {
+        let mut uniform_link = Self::new(program_ids, uniform_id, callback);
+        uniform_link
+            .set_use_init_callback_for_update(true)
+            .set_update_frequency(UniformUpdateFrequency::OnDemand);
+        uniform_link
+    }
+
     /// Gets all program ids that this link is associated with
     pub fn program_ids(&self) -> &Vec<ProgramId> {
         &self.program_ids
@@ -46,6 +137,16 @@ impl<ProgramId: Id, UniformId: Id> UniformLink<ProgramId, UniformId> {
         &self.uniform_id
     }
 
+    /// Overrides the name the uniform is looked up by in shaders, which is otherwise the
+    /// [IdName] of its id.
+    ///
+    /// Useful when several links set uniforms with the same name in different programs, since
+    /// each link needs its own id.
+    pub fn set_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.name = Some(name.into());
+        self
+    }
+
     /// See [crate::Uniform::initialize_callback]
     pub fn initialize_callback(&self) -> UniformCreateUpdateCallback {
         self.initialize_callback.clone()
@@ -101,6 +202,37 @@ impl<ProgramId: Id, UniformId: Id> UniformLink<ProgramId, UniformId> {
         self.use_init_callback_for_update = use_init_callback_for_update;
         self
     }
+
+    /// See [UniformUpdateFrequency]
+    pub fn update_frequency(&self) -> UniformUpdateFrequency {
+        self.update_frequency
+    }
+
+    /// See [UniformUpdateFrequency]
+    pub fn set_update_frequency(&mut self, update_frequency: UniformUpdateFrequency) -> &mut Self {
+        self.update_frequency = update_frequency;
+        self
+    }
+
+    /// The same link with its ids replaced (used when scoping links to a namespace)
+    pub(crate) fn map_ids(
+        self,
+        program_id: impl Fn(&ProgramId) -> ProgramId,
+        uniform_id: impl Fn(&UniformId) -> UniformId,
+    ) -> Self {
+        Self {
+            program_ids: self.program_ids.iter().map(program_id).collect(),
+            uniform_id: uniform_id(&self.uniform_id),
+            ..self
+        }
+    }
+}
+
+impl<ProgramId: Id, UniformId: Id + IdName> UniformLink<ProgramId, UniformId> {
+    /// The name the uniform is looked up by in shaders (see [UniformLink::set_name])
+    pub fn name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| self.uniform_id.name())
+    }
 }
 
 impl<ProgramId: Id, UniformId: Id> Debug for UniformLink<ProgramId, UniformId> {
@@ -108,6 +240,7 @@ impl<ProgramId: Id, UniformId: Id> Debug for UniformLink<ProgramId, UniformId> {
         f.debug_struct("UniformLink")
             .field("program_ids", &self.program_ids)
             .field("uniform_id", &self.uniform_id)
+            .field("name", &self.name)
             .field("initialize_callback", &self.initialize_callback)
             .field("update_callback", &self.update_callback)
             .field("should_update_callback", &self.should_update_callback)
@@ -115,6 +248,7 @@ impl<ProgramId: Id, UniformId: Id> Debug for UniformLink<ProgramId, UniformId> {
                 "use_init_callback_for_update",
                 &self.use_init_callback_for_update,
             )
+            .field("update_frequency", &self.update_frequency)
             .finish()
     }
 }
@@ -133,3 +267,23 @@ impl<ProgramId: Id, UniformId: Id> PartialEq for UniformLink<ProgramId, UniformI
 }
 
 impl<ProgramId: Id, UniformId: Id> Eq for UniformLink<ProgramId, UniformId> {}
+
+#[cfg(test)]
+mod tests {
+    use super::UniformLink;
+    use crate::UniformContext;
+
+    #[test]
+    fn looks_up_uniforms_by_name_when_one_is_set() {
+        let mut uniform_link = UniformLink::new(
+            "program".to_string(),
+            "program.iTime".to_string(),
+            |_: &UniformContext| {},
+        );
+        assert_eq!(uniform_link.name(), "program.iTime");
+
+        uniform_link.set_name("iTime");
+        assert_eq!(uniform_link.name(), "iTime");
+        assert_eq!(uniform_link.uniform_id(), "program.iTime");
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
     utils, StringArray, UniformCreateUpdateCallbackJs, UniformLink, UniformShouldUpdateCallbackJs,
+    UniformUpdateFrequency,
 };
 use std::ops::{Deref, DerefMut};
 use wasm_bindgen::prelude::wasm_bindgen;
@@ -25,6 +26,50 @@ impl UniformLinkJs {
         ))
     }
 
+    #[wasm_bindgen(js_name = constantF32)]
+    pub fn constant_f32(program_ids: StringArray, uniform_id: String, value: f32) -> Self {
+        let program_ids = utils::js_array_to_vec_strings(&program_ids);
+        Self(UniformLinkJsInner::constant_f32(
+            program_ids,
+            uniform_id,
+            value,
+        ))
+    }
+
+    #[wasm_bindgen(js_name = constantI32)]
+    pub fn constant_i32(program_ids: StringArray, uniform_id: String, value: i32) -> Self {
+        let program_ids = utils::js_array_to_vec_strings(&program_ids);
+        Self(UniformLinkJsInner::constant_i32(
+            program_ids,
+            uniform_id,
+            value,
+        ))
+    }
+
+    #[wasm_bindgen(js_name = constantBool)]
+    pub fn constant_bool(program_ids: StringArray, uniform_id: String, value: bool) -> Self {
+        let program_ids = utils::js_array_to_vec_strings(&program_ids);
+        Self(UniformLinkJsInner::constant_bool(
+            program_ids,
+            uniform_id,
+            value,
+        ))
+    }
+
+    #[wasm_bindgen(js_name = constantTextureUnit)]
+    pub fn constant_texture_unit(
+        program_ids: StringArray,
+        uniform_id: String,
+        texture_id: String,
+    ) -> Self {
+        let program_ids = utils::js_array_to_vec_strings(&program_ids);
+        Self(UniformLinkJsInner::constant_texture_unit(
+            program_ids,
+            uniform_id,
+            texture_id,
+        ))
+    }
+
     #[wasm_bindgen(js_name = programIds)]
     pub fn program_ids(&self) -> StringArray {
         utils::strings_to_js_array(self.deref().program_ids())
@@ -82,6 +127,16 @@ impl UniformLinkJs {
         self.deref_mut()
             .set_use_init_callback_for_update(use_init_callback_for_update);
     }
+
+    #[wasm_bindgen(js_name = updateFrequency)]
+    pub fn update_frequency(&self) -> UniformUpdateFrequency {
+        self.deref().update_frequency()
+    }
+
+    #[wasm_bindgen(js_name = setUpdateFrequency)]
+    pub fn set_update_frequency(&mut self, update_frequency: UniformUpdateFrequency) {
+        self.deref_mut().set_update_frequency(update_frequency);
+    }
 }
 
 impl From<UniformLinkJs> for UniformLinkJsInner {
@@ -0,0 +1,59 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Controls how often [crate::RendererData::update_uniforms] updates a uniform.
+///
+/// [crate::RendererData::update_uniform] always updates the uniform it is given, regardless of its frequency.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum UniformUpdateFrequency {
+    /// Updated on every call to `update_uniforms`
+    #[default]
+    PerFrame,
+    /// Updated at most once per second
+    PerSecond,
+    /// Only updated after being invalidated with [crate::RendererData::invalidate_uniform]
+    OnDemand,
+}
+
+impl UniformUpdateFrequency {
+    /// Minimum time (in ms) between updates of a [UniformUpdateFrequency::PerSecond] uniform
+    pub const PER_SECOND_INTERVAL: f64 = 1000.0;
+
+    /// Whether a uniform in this group should be updated at time `now` (in ms).
+    ///
+    /// Invalidated uniforms and uniforms that have never been updated are always due.
+    pub fn is_due(&self, now: f64, last_updated: Option<f64>, invalidated: bool) -> bool {
+        if invalidated {
+            return true;
+        }
+
+        match (self, last_updated) {
+            (UniformUpdateFrequency::PerFrame, _) => true,
+            (_, None) => true,
+            (UniformUpdateFrequency::PerSecond, Some(last_updated)) => {
+                now - last_updated >= Self::PER_SECOND_INTERVAL
+            }
+            (UniformUpdateFrequency::OnDemand, Some(_)) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UniformUpdateFrequency;
+
+    #[test]
+    fn schedules_updates_by_group() {
+        let per_frame = UniformUpdateFrequency::PerFrame;
+        assert!(per_frame.is_due(16.0, Some(0.0), false));
+
+        let per_second = UniformUpdateFrequency::PerSecond;
+        assert!(per_second.is_due(0.0, None, false));
+        assert!(!per_second.is_due(999.0, Some(0.0), false));
+        assert!(per_second.is_due(1000.0, Some(0.0), false));
+
+        let on_demand = UniformUpdateFrequency::OnDemand;
+        assert!(!on_demand.is_due(5000.0, Some(0.0), false));
+        assert!(on_demand.is_due(5000.0, Some(0.0), true));
+    }
+}
@@ -0,0 +1,136 @@
+use web_sys::{WebGl2RenderingContext, WebGlUniformLocation};
+
+/// A plain value that can be uploaded to a uniform without a user-supplied update callback.
+///
+/// Fields of a [crate::UniformGroup] are converted into this type, so any type that implements
+/// `Into<UniformValue>` can be used as a field (including `UniformValue` itself, e.g. for `mat2`s,
+/// which would otherwise be indistinguishable from `vec4`s).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UniformValue {
+    /// `float`
+    Float(f32),
+    /// `vec2`
+    Vec2([f32; 2]),
+    /// `vec3`
+    Vec3([f32; 3]),
+    /// `vec4`
+    Vec4([f32; 4]),
+    /// `int` (or a sampler's texture unit)
+    Int(i32),
+    /// `ivec2`
+    IVec2([i32; 2]),
+    /// `ivec3`
+    IVec3([i32; 3]),
+    /// `ivec4`
+    IVec4([i32; 4]),
+    /// `uint`
+    UInt(u32),
+    /// `bool`
+    Bool(bool),
+    /// `mat2`, in column-major order
+    Mat2([f32; 4]),
+    /// `mat3`, in column-major order
+    Mat3([f32; 9]),
+    /// `mat4`, in column-major order
+    Mat4([f32; 16]),
+}
+
+impl UniformValue {
+    /// Uploads this value to the uniform at `location` in the currently used program
+    pub fn upload(&self, gl: &WebGl2RenderingContext, location: &WebGlUniformLocation) {
+        let location = Some(location);
+        match self {
+            UniformValue::Float(value) => gl.uniform1f(location, *value),
+            UniformValue::Vec2(value) => gl.uniform2fv_with_f32_array(location, value),
+            UniformValue::Vec3(value) => gl.uniform3fv_with_f32_array(location, value),
+            UniformValue::Vec4(value) => gl.uniform4fv_with_f32_array(location, value),
+            UniformValue::Int(value) => gl.uniform1i(location, *value),
+            UniformValue::IVec2(value) => gl.uniform2iv_with_i32_array(location, value),
+            UniformValue::IVec3(value) => gl.uniform3iv_with_i32_array(location, value),
+            UniformValue::IVec4(value) => gl.uniform4iv_with_i32_array(location, value),
+            UniformValue::UInt(value) => gl.uniform1ui(location, *value),
+            UniformValue::Bool(value) => gl.uniform1i(location, *value as i32),
+            UniformValue::Mat2(value) => {
+                gl.uniform_matrix2fv_with_f32_array(location, false, value)
+            }
+            UniformValue::Mat3(value) => {
+                gl.uniform_matrix3fv_with_f32_array(location, false, value)
+            }
+            UniformValue::Mat4(value) => {
+                gl.uniform_matrix4fv_with_f32_array(location, false, value)
+            }
+        }
+    }
+}
+
+impl From<f32> for UniformValue {
+    fn from(value: f32) -> Self {
+        UniformValue::Float(value)
+    }
+}
+
+impl From<[f32; 2]> for UniformValue {
+    fn from(value: [f32; 2]) -> Self {
+        UniformValue::Vec2(value)
+    }
+}
+
+impl From<[f32; 3]> for UniformValue {
+    fn from(value: [f32; 3]) -> Self {
+        UniformValue::Vec3(value)
+    }
+}
+
+impl From<[f32; 4]> for UniformValue {
+    fn from(value: [f32; 4]) -> Self {
+        UniformValue::Vec4(value)
+    }
+}
+
+impl From<i32> for UniformValue {
+    fn from(value: i32) -> Self {
+        UniformValue::Int(value)
+    }
+}
+
+impl From<[i32; 2]> for UniformValue {
+    fn from(value: [i32; 2]) -> Self {
+        UniformValue::IVec2(value)
+    }
+}
+
+impl From<[i32; 3]> for UniformValue {
+    fn from(value: [i32; 3]) -> Self {
+        UniformValue::IVec3(value)
+    }
+}
+
+impl From<[i32; 4]> for UniformValue {
+    fn from(value: [i32; 4]) -> Self {
+        UniformValue::IVec4(value)
+    }
+}
+
+impl From<u32> for UniformValue {
+    fn from(value: u32) -> Self {
+        UniformValue::UInt(value)
+    }
+}
+
+impl From<bool> for UniformValue {
+    fn from(value: bool) -> Self {
+        UniformValue::Bool(value)
+    }
+}
+
+impl From<[f32; 9]> for UniformValue {
+    fn from(value: [f32; 9]) -> Self {
+        UniformValue::Mat3(value)
+    }
+}
+
+impl From<[f32; 16]> for UniformValue {
+    fn from(value: [f32; 16]) -> Self {
+        UniformValue::Mat4(value)
+    }
+}
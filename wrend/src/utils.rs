@@ -1,12 +1,19 @@
 mod bridge;
+mod download;
+mod gl_state_snapshot;
 mod init;
 mod into_js_wrapper;
+#[cfg(feature = "js")]
 mod js_conversion;
 mod listener;
 
+#[cfg(feature = "js")]
 pub(crate) use js_conversion::*;
 
+pub(crate) use download::*;
+
 pub use bridge::*;
+pub use gl_state_snapshot::*;
 pub use init::*;
 pub use into_js_wrapper::*;
 pub use listener::*;
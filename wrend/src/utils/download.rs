@@ -0,0 +1,23 @@
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{window, Blob, HtmlAnchorElement, Url};
+
+/// Downloads a Blob as a file in the browser, using a temporary hidden link
+pub(crate) fn download_blob(blob: &Blob, filename: &str) -> Result<(), JsValue> {
+    let document = window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| JsValue::from_str("No document available"))?;
+    let body = document
+        .body()
+        .ok_or_else(|| JsValue::from_str("No document body available"))?;
+    let url = Url::create_object_url_with_blob(blob)?;
+    let a: HtmlAnchorElement = document.create_element("a")?.dyn_into()?;
+
+    a.style().set_css_text("display: none;");
+    a.set_href(&url);
+    a.set_download(filename);
+
+    body.append_child(&a)?;
+    a.click();
+    body.remove_child(&a)?;
+    Url::revoke_object_url(&url)
+}
@@ -0,0 +1,200 @@
+use js_sys::{Array, Float32Array, Int32Array};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{
+    WebGl2RenderingContext as Gl, WebGlBuffer, WebGlFramebuffer, WebGlProgram, WebGlTexture,
+    WebGlVertexArrayObject,
+};
+
+/// Capabilities that wrend's drawing code toggles and expects to find as it left them
+const CAPABILITIES: [u32; 8] = [
+    Gl::BLEND,
+    Gl::CULL_FACE,
+    Gl::DEPTH_TEST,
+    Gl::POLYGON_OFFSET_FILL,
+    Gl::RASTERIZER_DISCARD,
+    Gl::SAMPLE_ALPHA_TO_COVERAGE,
+    Gl::SCISSOR_TEST,
+    Gl::STENCIL_TEST,
+];
+
+/// The parts of the WebGL state that code outside of wrend is likely to change: bindings,
+/// capabilities, blending, masks, the viewport and pixel storage parameters.
+///
+/// Capture a snapshot before handing the context to other code and restore it afterward, so that
+/// wrend's later draws aren't affected (see [crate::TextureGuard]). Capturing queries the context,
+/// so avoid doing it in hot loops.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlStateSnapshot {
+    program: Option<WebGlProgram>,
+    vao: Option<WebGlVertexArrayObject>,
+    array_buffer: Option<WebGlBuffer>,
+    draw_framebuffer: Option<WebGlFramebuffer>,
+    read_framebuffer: Option<WebGlFramebuffer>,
+    active_texture: u32,
+    textures: Vec<(u32, Option<WebGlTexture>)>,
+    viewport: [i32; 4],
+    scissor_box: [i32; 4],
+    capabilities: Vec<(u32, bool)>,
+    blend_func: [u32; 4],
+    blend_equation: [u32; 2],
+    color_mask: [bool; 4],
+    depth_mask: bool,
+    clear_color: [f32; 4],
+    pixel_store: Vec<(u32, i32)>,
+}
+
+impl GlStateSnapshot {
+    /// Captures the current state, including the `TEXTURE_2D` binding of the active texture unit
+    /// and of each unit in `texture_units`
+    pub fn capture(gl: &Gl, texture_units: &[u32]) -> Self {
+        let active_texture = number(gl, Gl::ACTIVE_TEXTURE);
+        let mut units = texture_units
+            .iter()
+            .map(|unit| Gl::TEXTURE0 + unit)
+            .collect::<Vec<_>>();
+        units.push(active_texture);
+        units.sort_unstable();
+        units.dedup();
+        let textures = units
+            .into_iter()
+            .map(|unit| {
+                gl.active_texture(unit);
+                (unit, object(gl, Gl::TEXTURE_BINDING_2D))
+            })
+            .collect();
+        gl.active_texture(active_texture);
+
+        let blend_func = [
+            Gl::BLEND_SRC_RGB,
+            Gl::BLEND_DST_RGB,
+            Gl::BLEND_SRC_ALPHA,
+            Gl::BLEND_DST_ALPHA,
+        ]
+        .map(|pname| number(gl, pname));
+        let color_mask = gl
+            .get_parameter(Gl::COLOR_WRITEMASK)
+            .ok()
+            .and_then(|value| value.dyn_into::<Array>().ok())
+            .map_or([true; 4], |mask| {
+                [0, 1, 2, 3].map(|i| mask.get(i).as_bool().unwrap_or(true))
+            });
+
+        Self {
+            program: object(gl, Gl::CURRENT_PROGRAM),
+            vao: object(gl, Gl::VERTEX_ARRAY_BINDING),
+            array_buffer: object(gl, Gl::ARRAY_BUFFER_BINDING),
+            draw_framebuffer: object(gl, Gl::DRAW_FRAMEBUFFER_BINDING),
+            read_framebuffer: object(gl, Gl::READ_FRAMEBUFFER_BINDING),
+            active_texture,
+            textures,
+            viewport: int4(gl, Gl::VIEWPORT),
+            scissor_box: int4(gl, Gl::SCISSOR_BOX),
+            capabilities: CAPABILITIES
+                .iter()
+                .map(|&capability| (capability, gl.is_enabled(capability)))
+                .collect(),
+            blend_func,
+            blend_equation: [Gl::BLEND_EQUATION_RGB, Gl::BLEND_EQUATION_ALPHA]
+                .map(|pname| number(gl, pname)),
+            color_mask,
+            depth_mask: gl
+                .get_parameter(Gl::DEPTH_WRITEMASK)
+                .ok()
+                .and_then(|value| value.as_bool())
+                .unwrap_or(true),
+            clear_color: float4(gl, Gl::COLOR_CLEAR_VALUE),
+            pixel_store: [
+                Gl::UNPACK_FLIP_Y_WEBGL,
+                Gl::UNPACK_PREMULTIPLY_ALPHA_WEBGL,
+                Gl::UNPACK_ALIGNMENT,
+                Gl::PACK_ALIGNMENT,
+            ]
+            .into_iter()
+            .map(|pname| {
+                let value = gl.get_parameter(pname).unwrap_or(JsValue::UNDEFINED);
+                let value = match value.as_bool() {
+                    Some(value) => value as i32,
+                    None => value.as_f64().unwrap_or_default() as i32,
+                };
+                (pname, value)
+            })
+            .collect(),
+        }
+    }
+
+    /// Puts the context back into the captured state
+    pub fn restore(&self, gl: &Gl) {
+        gl.use_program(self.program.as_ref());
+        gl.bind_vertex_array(self.vao.as_ref());
+        gl.bind_buffer(Gl::ARRAY_BUFFER, self.array_buffer.as_ref());
+        gl.bind_framebuffer(Gl::DRAW_FRAMEBUFFER, self.draw_framebuffer.as_ref());
+        gl.bind_framebuffer(Gl::READ_FRAMEBUFFER, self.read_framebuffer.as_ref());
+        for (unit, texture) in &self.textures {
+            gl.active_texture(*unit);
+            gl.bind_texture(Gl::TEXTURE_2D, texture.as_ref());
+        }
+        gl.active_texture(self.active_texture);
+
+        let [x, y, width, height] = self.viewport;
+        gl.viewport(x, y, width, height);
+        let [x, y, width, height] = self.scissor_box;
+        gl.scissor(x, y, width, height);
+        for &(capability, enabled) in &self.capabilities {
+            if enabled {
+                gl.enable(capability);
+            } else {
+                gl.disable(capability);
+            }
+        }
+        let [src_rgb, dst_rgb, src_alpha, dst_alpha] = self.blend_func;
+        gl.blend_func_separate(src_rgb, dst_rgb, src_alpha, dst_alpha);
+        let [rgb, alpha] = self.blend_equation;
+        gl.blend_equation_separate(rgb, alpha);
+        let [r, g, b, a] = self.color_mask;
+        gl.color_mask(r, g, b, a);
+        gl.depth_mask(self.depth_mask);
+        let [r, g, b, a] = self.clear_color;
+        gl.clear_color(r, g, b, a);
+        for &(pname, value) in &self.pixel_store {
+            gl.pixel_storei(pname, value);
+        }
+    }
+}
+
+fn object<T: JsCast>(gl: &Gl, pname: u32) -> Option<T> {
+    gl.get_parameter(pname)
+        .ok()
+        .filter(|value| !value.is_null() && !value.is_undefined())
+        .map(JsCast::unchecked_into)
+}
+
+fn number(gl: &Gl, pname: u32) -> u32 {
+    gl.get_parameter(pname)
+        .ok()
+        .and_then(|value| value.as_f64())
+        .unwrap_or_default() as u32
+}
+
+fn int4(gl: &Gl, pname: u32) -> [i32; 4] {
+    let mut values = [0; 4];
+    if let Some(array) = gl
+        .get_parameter(pname)
+        .ok()
+        .and_then(|value| value.dyn_into::<Int32Array>().ok())
+    {
+        array.slice(0, 4).copy_to(&mut values);
+    }
+    values
+}
+
+fn float4(gl: &Gl, pname: u32) -> [f32; 4] {
+    let mut values = [0.0; 4];
+    if let Some(array) = gl
+        .get_parameter(pname)
+        .ok()
+        .and_then(|value| value.dyn_into::<Float32Array>().ok())
+    {
+        array.slice(0, 4).copy_to(&mut values);
+    }
+    values
+}
@@ -0,0 +1,9 @@
+mod view;
+mod view_context;
+mod view_layout;
+mod viewport;
+
+pub use view::*;
+pub use view_context::*;
+pub use view_layout::*;
+pub use viewport::*;
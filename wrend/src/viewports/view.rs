@@ -0,0 +1,25 @@
+/// A named region of the canvas within a [crate::ViewLayout]
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct View {
+    name: String,
+    rect: [f32; 4],
+}
+
+impl View {
+    /// Creates a view from a normalized `[x, y, width, height]` rect (0.0 to 1.0, bottom-left origin)
+    pub fn new(name: impl Into<String>, rect: [f32; 4]) -> Self {
+        Self {
+            name: name.into(),
+            rect,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Normalized `[x, y, width, height]` rect
+    pub fn rect(&self) -> [f32; 4] {
+        self.rect
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{View, Viewport};
+
+/// Passed to the callback of [crate::RendererData::render_views] for each view
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViewContext<'a> {
+    index: usize,
+    view: &'a View,
+    viewport: Viewport,
+}
+
+impl<'a> ViewContext<'a> {
+    pub fn new(index: usize, view: &'a View, viewport: Viewport) -> Self {
+        Self {
+            index,
+            view,
+            viewport,
+        }
+    }
+
+    /// Position of the view in its layout, which can be used to select per-view camera uniforms
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn view(&self) -> &View {
+        self.view
+    }
+
+    pub fn name(&self) -> &str {
+        self.view.name()
+    }
+
+    /// Pixel rect the view is rendering into, which is already bound as the viewport and scissor rect
+    pub fn viewport(&self) -> Viewport {
+        self.viewport
+    }
+
+    pub fn aspect_ratio(&self) -> f32 {
+        self.viewport.aspect_ratio()
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{View, Viewport};
+
+/// An ordered set of views that divide up a single canvas, e.g. for split-screen comparisons
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub struct ViewLayout {
+    views: Vec<View>,
+}
+
+impl ViewLayout {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Splits the canvas into equally sized columns, from left to right
+    pub fn columns(names: &[impl AsRef<str>]) -> Self {
+        Self::grid(names, names.len().max(1))
+    }
+
+    /// Splits the canvas into equally sized rows, from top to bottom
+    pub fn rows(names: &[impl AsRef<str>]) -> Self {
+        Self::grid(names, 1)
+    }
+
+    /// Arranges views into a grid with the given number of columns, filling rows from top-left to bottom-right
+    pub fn grid(names: &[impl AsRef<str>], columns: usize) -> Self {
+        let columns = columns.max(1);
+        let rows = names.len().div_ceil(columns).max(1);
+        let width = 1.0 / columns as f32;
+        let height = 1.0 / rows as f32;
+
+        let mut layout = Self::new();
+        for (i, name) in names.iter().enumerate() {
+            let column = i % columns;
+            let row = i / columns;
+            layout.add_view(View::new(
+                name.as_ref(),
+                [
+                    column as f32 * width,
+                    1.0 - (row + 1) as f32 * height,
+                    width,
+                    height,
+                ],
+            ));
+        }
+        layout
+    }
+
+    /// Adds a view. Views are rendered in the order they are added.
+    pub fn add_view(&mut self, view: View) -> &mut Self {
+        self.views.push(view);
+        self
+    }
+
+    pub fn views(&self) -> &[View] {
+        &self.views
+    }
+
+    pub fn view(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|view| view.name() == name)
+    }
+
+    /// Pixel viewports for every view, given the size of the drawing buffer
+    pub fn viewports(&self, buffer_width: i32, buffer_height: i32) -> Vec<Viewport> {
+        self.views
+            .iter()
+            .map(|view| Viewport::from_normalized(view.rect(), buffer_width, buffer_height))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ViewLayout;
+    use crate::Viewport;
+
+    #[test]
+    fn splits_canvas_into_viewports() {
+        let layout = ViewLayout::columns(&["original", "filtered"]);
+        assert_eq!(
+            layout.viewports(101, 50),
+            vec![Viewport::new(0, 0, 51, 50), Viewport::new(51, 0, 50, 50)]
+        );
+
+        let layout = ViewLayout::grid(&["a", "b", "c"], 2);
+        assert_eq!(
+            layout.viewports(100, 100),
+            vec![
+                Viewport::new(0, 50, 50, 50),
+                Viewport::new(50, 50, 50, 50),
+                Viewport::new(0, 0, 50, 50),
+            ]
+        );
+        assert_eq!(layout.view("c").unwrap().rect(), [0.0, 0.0, 0.5, 0.5]);
+    }
+}
@@ -0,0 +1,66 @@
+use web_sys::WebGl2RenderingContext;
+
+/// A rectangle of the drawing buffer, in pixels, with the origin in the bottom-left corner
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Viewport {
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+}
+
+impl Viewport {
+    pub fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Converts a normalized `[x, y, width, height]` rect (0.0 to 1.0, bottom-left origin)
+    /// into pixels. Edges are rounded so that adjacent rects share edges without gaps.
+    pub fn from_normalized(rect: [f32; 4], buffer_width: i32, buffer_height: i32) -> Self {
+        let [x, y, width, height] = rect;
+        let left = (x * buffer_width as f32).round() as i32;
+        let bottom = (y * buffer_height as f32).round() as i32;
+        let right = ((x + width) * buffer_width as f32).round() as i32;
+        let top = ((y + height) * buffer_height as f32).round() as i32;
+        Self::new(left, bottom, right - left, top - bottom)
+    }
+
+    /// Covers the whole drawing buffer
+    pub fn full(gl: &WebGl2RenderingContext) -> Self {
+        Self::new(0, 0, gl.drawing_buffer_width(), gl.drawing_buffer_height())
+    }
+
+    pub fn x(&self) -> i32 {
+        self.x
+    }
+
+    pub fn y(&self) -> i32 {
+        self.y
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// Width divided by height, e.g. for a per-view projection matrix
+    pub fn aspect_ratio(&self) -> f32 {
+        self.width as f32 / self.height.max(1) as f32
+    }
+
+    /// Sets both the viewport and the scissor rect, so that draws and clears are confined to this rect.
+    ///
+    /// Note: `SCISSOR_TEST` must be enabled for the scissor rect to take effect.
+    pub fn apply(&self, gl: &WebGl2RenderingContext) {
+        gl.viewport(self.x, self.y, self.width, self.height);
+        gl.scissor(self.x, self.y, self.width, self.height);
+    }
+}
@@ -0,0 +1,11 @@
+mod colormap;
+mod heatmap;
+mod vector_field;
+mod vector_field_mode;
+mod visualization_shaders;
+
+pub use colormap::*;
+pub use heatmap::*;
+pub use vector_field::*;
+pub use vector_field_mode::*;
+pub use visualization_shaders::*;
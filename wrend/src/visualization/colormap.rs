@@ -0,0 +1,91 @@
+use crate::Id;
+
+/// Polynomial fits of matplotlib's colormaps by Matt Zucker (<https://www.shadertoy.com/view/WlfXRN>),
+/// as coefficients `c0..c6` of `c0 + t * (c1 + t * (c2 + ...))`
+const VIRIDIS: [[f32; 3]; 7] = [
+    [0.277_727_33, 0.005_407_345, 0.334_099_8],
+    [0.105_093_04, 1.404_613_5, 1.384_590_2],
+    [-0.330_861_83, 0.214_847_56, 0.095_095_16],
+    [-4.634_230_6, -5.799_101, -19.332_441],
+    [6.228_27, 14.179_933, 56.690_55],
+    [4.776_385, -13.745_145, -65.353_03],
+    [-5.435_456, 4.645_852_6, 26.312_435],
+];
+
+const INFERNO: [[f32; 3]; 7] = [
+    [0.000_218_940_4, 0.001_651_004_6, -0.019_480_898],
+    [0.106_513_42, 0.563_956_45, 3.932_712_4],
+    [11.602_493, -3.972_854, -15.942_394],
+    [-41.703_995, 17.436_4, 44.354_145],
+    [77.162_94, -33.402_36, -81.807_31],
+    [-71.319_43, 32.626_064, 73.209_52],
+    [25.131_126, -12.242_669, -23.070_326],
+];
+
+/// How a [crate::Heatmap] turns normalized values into colors
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum Colormap<TextureId: Id> {
+    /// Perceptually uniform blue-green-yellow
+    #[default]
+    Viridis,
+    /// Perceptually uniform black-red-yellow
+    Inferno,
+    /// Samples a horizontal lookup texture, such as one made with [crate::Gradient::texture_link]
+    Texture(TextureId),
+}
+
+impl<TextureId: Id> Colormap<TextureId> {
+    /// Value of the heatmap shader's `u_colormap` uniform
+    pub fn shader_index(&self) -> i32 {
+        match self {
+            Colormap::Viridis => 0,
+            Colormap::Inferno => 1,
+            Colormap::Texture(_) => 2,
+        }
+    }
+
+    pub fn texture_id(&self) -> Option<&TextureId> {
+        match self {
+            Colormap::Texture(texture_id) => Some(texture_id),
+            _ => None,
+        }
+    }
+
+    /// Evaluates a built-in colormap at `t` (clamped to 0.0..=1.0), the same way the heatmap shader does.
+    /// Texture colormaps return `None`.
+    pub fn sample(&self, t: f32) -> Option<[f32; 3]> {
+        let coefficients = match self {
+            Colormap::Viridis => &VIRIDIS,
+            Colormap::Inferno => &INFERNO,
+            Colormap::Texture(_) => return None,
+        };
+        let t = t.clamp(0.0, 1.0);
+        let mut rgb = [0.0; 3];
+        for (channel, value) in rgb.iter_mut().enumerate() {
+            *value = coefficients
+                .iter()
+                .rev()
+                .fold(0.0, |sum, c| sum * t + c[channel])
+                .clamp(0.0, 1.0);
+        }
+        Some(rgb)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Colormap;
+
+    #[test]
+    fn samples_builtin_colormaps() {
+        let viridis = Colormap::<&str>::Viridis;
+        let [r, g, b] = viridis.sample(0.0).unwrap();
+        assert!((r - 0.267).abs() < 0.02 && g < 0.02 && (b - 0.329).abs() < 0.02);
+        let [r, g, b] = viridis.sample(2.0).unwrap();
+        assert!(r > 0.95 && g > 0.85 && b < 0.2);
+
+        let [r, g, _] = Colormap::<&str>::Inferno.sample(1.0).unwrap();
+        assert!(r > 0.95 && g > 0.95);
+        assert_eq!(Colormap::Texture("lut").sample(0.5), None);
+    }
+}
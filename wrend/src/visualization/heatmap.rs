@@ -0,0 +1,150 @@
+use crate::{Colormap, Id, IdName, RendererData};
+
+/// Renders a scalar field (such as an `R32F` simulation texture) through a [Colormap], with
+/// optional contour lines.
+///
+/// The program is added with [crate::RendererDataBuilder::add_heatmap] and drawn with
+/// [Heatmap::draw]. Values are normalized to [Heatmap::range] before being
+/// colored, so the same heatmap can show data in any units.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Heatmap<ProgramId: Id, TextureId: Id> {
+    program_id: ProgramId,
+    colormap: Colormap<TextureId>,
+    range: [f32; 2],
+    contour_count: u32,
+    contour_width: f32,
+    contour_color: [f32; 4],
+}
+
+impl<ProgramId: Id, TextureId: Id> Heatmap<ProgramId, TextureId> {
+    /// Creates a viridis heatmap of values from 0.0 to 1.0, without contours
+    pub fn new(program_id: ProgramId) -> Self {
+        Self {
+            program_id,
+            colormap: Colormap::default(),
+            range: [0.0, 1.0],
+            contour_count: 0,
+            contour_width: 1.0,
+            contour_color: [0.0, 0.0, 0.0, 0.6],
+        }
+    }
+
+    pub fn set_colormap(&mut self, colormap: Colormap<TextureId>) -> &mut Self {
+        self.colormap = colormap;
+        self
+    }
+
+    /// Values mapped to the start and end of the colormap. Values outside of it are clamped.
+    pub fn set_range(&mut self, min: f32, max: f32) -> &mut Self {
+        self.range = [min, max];
+        self
+    }
+
+    /// Number of evenly spaced contour lines across the range, where 0 disables them
+    pub fn set_contour_count(&mut self, contour_count: u32) -> &mut Self {
+        self.contour_count = contour_count;
+        self
+    }
+
+    /// Width of contour lines, in pixels
+    pub fn set_contour_width(&mut self, contour_width: f32) -> &mut Self {
+        self.contour_width = contour_width.max(0.0);
+        self
+    }
+
+    pub fn set_contour_color(&mut self, contour_color: [f32; 4]) -> &mut Self {
+        self.contour_color = contour_color;
+        self
+    }
+
+    pub fn program_id(&self) -> &ProgramId {
+        &self.program_id
+    }
+
+    pub fn colormap(&self) -> &Colormap<TextureId> {
+        &self.colormap
+    }
+
+    pub fn range(&self) -> [f32; 2] {
+        self.range
+    }
+
+    pub fn contour_count(&self) -> u32 {
+        self.contour_count
+    }
+
+    pub fn contour_width(&self) -> f32 {
+        self.contour_width
+    }
+
+    pub fn contour_color(&self) -> [f32; 4] {
+        self.contour_color
+    }
+
+    /// Where `value` falls on the colormap, from 0.0 to 1.0
+    pub fn normalize(&self, value: f32) -> f32 {
+        let [min, max] = self.range;
+        if max == min {
+            return 0.0;
+        }
+        ((value - min) / (max - min)).clamp(0.0, 1.0)
+    }
+
+    /// Draws a [Heatmap] of `scalar_texture_id`'s red channel to the canvas
+    pub fn draw<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        UniformId: Id + IdName,
+        BufferId: Id,
+        AttributeId: Id + IdName,
+        FramebufferId: Id,
+        TransformFeedbackId: Id,
+        VertexArrayObjectId: Id,
+        UserCtx: Clone,
+    >(
+        &self,
+        renderer_data: &RendererData<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+        scalar_texture_id: &TextureId,
+    ) -> &Self {
+        let colormap_texture = self
+            .colormap()
+            .texture_id()
+            .and_then(|texture_id| renderer_data.webgl_texture(texture_id));
+        let [min, max] = self.range();
+        let [r, g, b, a] = self.contour_color();
+        renderer_data.draw_fullscreen_pass(
+            self.program_id(),
+            &[
+                ("u_scalar", renderer_data.webgl_texture(scalar_texture_id)),
+                ("u_colormap_texture", colormap_texture),
+            ],
+            |gl, program| {
+                let location = |name| gl.get_uniform_location(program, name);
+                gl.uniform1i(
+                    location("u_colormap").as_ref(),
+                    self.colormap().shader_index(),
+                );
+                gl.uniform2f(location("u_range").as_ref(), min, max);
+                gl.uniform1f(
+                    location("u_contour_count").as_ref(),
+                    self.contour_count() as f32,
+                );
+                gl.uniform1f(location("u_contour_width").as_ref(), self.contour_width());
+                gl.uniform4f(location("u_contour_color").as_ref(), r, g, b, a);
+            },
+        );
+        self
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{Id, IdName, RendererData, VectorFieldMode};
+use log::warn;
+use web_sys::WebGl2RenderingContext;
+
+/// Visualizes a 2D vector field (such as an `RG32F` velocity texture) with arrows or line
+/// integral convolution.
+///
+/// The program for the field's [VectorFieldMode] is added with
+/// [crate::RendererDataBuilder::add_vector_field], and the field is drawn over the canvas with
+/// [VectorField::draw]. Vectors are read from the red and green channels,
+/// with y pointing up (the same orientation as texture coordinates).
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct VectorField<ProgramId: Id> {
+    program_id: ProgramId,
+    mode: VectorFieldMode,
+    spacing: f32,
+    scale: f32,
+    color: [f32; 4],
+    lic_length: u32,
+}
+
+impl<ProgramId: Id> VectorField<ProgramId> {
+    /// Most samples taken in each direction along a streamline, see [VectorField::set_lic_length]
+    pub const MAX_LIC_LENGTH: u32 = 64;
+
+    pub fn new(program_id: ProgramId, mode: VectorFieldMode) -> Self {
+        Self {
+            program_id,
+            mode,
+            spacing: 24.0,
+            scale: 1.0,
+            color: [1.0, 1.0, 1.0, 1.0],
+            lic_length: 20,
+        }
+    }
+
+    /// Distance between arrows, in pixels: lower values draw a denser grid
+    pub fn set_spacing(&mut self, spacing: f32) -> &mut Self {
+        self.spacing = spacing.max(1.0);
+        self
+    }
+
+    /// Arrow length in pixels per unit of the field's magnitude
+    pub fn set_scale(&mut self, scale: f32) -> &mut Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Color of arrows, or the tint of the convolved noise
+    pub fn set_color(&mut self, color: [f32; 4]) -> &mut Self {
+        self.color = color;
+        self
+    }
+
+    /// Number of one-pixel steps taken in each direction along a streamline when convolving.
+    /// Longer streamlines show the flow more clearly, but are slower.
+    pub fn set_lic_length(&mut self, lic_length: u32) -> &mut Self {
+        self.lic_length = lic_length.clamp(1, Self::MAX_LIC_LENGTH);
+        self
+    }
+
+    pub fn program_id(&self) -> &ProgramId {
+        &self.program_id
+    }
+
+    pub fn mode(&self) -> VectorFieldMode {
+        self.mode
+    }
+
+    pub fn spacing(&self) -> f32 {
+        self.spacing
+    }
+
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    pub fn color(&self) -> [f32; 4] {
+        self.color
+    }
+
+    pub fn lic_length(&self) -> u32 {
+        self.lic_length
+    }
+
+    /// Columns and rows of arrows drawn over a canvas of this size
+    pub fn grid_size(&self, width: u32, height: u32) -> (u32, u32) {
+        let cells = |size: u32| (size as f32 / self.spacing).ceil() as u32;
+        (cells(width), cells(height))
+    }
+
+    /// Draws a [VectorField] of `field_texture_id` over the canvas. Arrows are alpha blended
+    /// over what is already there, while line integral convolution covers the whole canvas.
+    pub fn draw<
+        VertexShaderId: Id,
+        FragmentShaderId: Id,
+        UniformId: Id + IdName,
+        BufferId: Id,
+        AttributeId: Id + IdName,
+        TextureId: Id,
+        FramebufferId: Id,
+        TransformFeedbackId: Id,
+        VertexArrayObjectId: Id,
+        UserCtx: Clone,
+    >(
+        &self,
+        renderer_data: &RendererData<
+            VertexShaderId,
+            FragmentShaderId,
+            ProgramId,
+            UniformId,
+            BufferId,
+            AttributeId,
+            TextureId,
+            FramebufferId,
+            TransformFeedbackId,
+            VertexArrayObjectId,
+            UserCtx,
+        >,
+        field_texture_id: &TextureId,
+    ) -> &Self {
+        let field_texture = renderer_data.webgl_texture(field_texture_id);
+        let gl = renderer_data.gl();
+        let (width, height) = (gl.drawing_buffer_width(), gl.drawing_buffer_height());
+        let [r, g, b, a] = self.color();
+
+        if self.mode() == VectorFieldMode::LineIntegralConvolution {
+            renderer_data.draw_fullscreen_pass(
+                self.program_id(),
+                &[("u_field", field_texture)],
+                |gl, program| {
+                    let location = |name| gl.get_uniform_location(program, name);
+                    gl.uniform2f(
+                        location("u_resolution").as_ref(),
+                        width as f32,
+                        height as f32,
+                    );
+                    gl.uniform1i(location("u_length").as_ref(), self.lic_length() as i32);
+                    gl.uniform4f(location("u_color").as_ref(), r, g, b, a);
+                },
+            );
+            return self;
+        }
+
+        let Some(program) = renderer_data.program(self.program_id()) else {
+            warn!("Could not find VectorField program {:?}", self.program_id());
+            return self;
+        };
+        let (columns, rows) = self.grid_size(width as u32, height as u32);
+        let location = |name| gl.get_uniform_location(program, name);
+
+        gl.use_program(Some(program));
+        gl.bind_vertex_array(Some(renderer_data.empty_vao()));
+        let saved_units = renderer_data.save_texture_units([0]);
+        gl.active_texture(WebGl2RenderingContext::TEXTURE0);
+        gl.bind_texture(WebGl2RenderingContext::TEXTURE_2D, field_texture);
+        gl.uniform1i(location("u_field").as_ref(), 0);
+        gl.uniform2f(
+            location("u_resolution").as_ref(),
+            width as f32,
+            height as f32,
+        );
+        gl.uniform1f(location("u_spacing").as_ref(), self.spacing());
+        gl.uniform1f(location("u_scale").as_ref(), self.scale());
+        gl.uniform1i(location("u_columns").as_ref(), columns as i32);
+        gl.uniform4f(location("u_color").as_ref(), r, g, b, a);
+
+        gl.bind_framebuffer(WebGl2RenderingContext::FRAMEBUFFER, None);
+        gl.viewport(0, 0, width, height);
+        gl.enable(WebGl2RenderingContext::BLEND);
+        gl.blend_func(
+            WebGl2RenderingContext::SRC_ALPHA,
+            WebGl2RenderingContext::ONE_MINUS_SRC_ALPHA,
+        );
+        gl.draw_arrays_instanced(
+            WebGl2RenderingContext::TRIANGLES,
+            0,
+            9,
+            (columns * rows) as i32,
+        );
+        gl.disable(WebGl2RenderingContext::BLEND);
+        gl.bind_vertex_array(None);
+        saved_units.restore(gl);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VectorField;
+    use crate::VectorFieldMode;
+
+    #[test]
+    fn grid_covers_canvas() {
+        let mut field = VectorField::new("arrows", VectorFieldMode::Arrows);
+        field.set_spacing(32.0);
+        assert_eq!(field.grid_size(640, 100), (20, 4));
+        field.set_spacing(0.0).set_lic_length(1000);
+        assert_eq!(field.grid_size(3, 2), (3, 2));
+        assert_eq!(field.lic_length(), VectorField::<&str>::MAX_LIC_LENGTH);
+    }
+}
@@ -0,0 +1,9 @@
+/// How a [crate::VectorField] is drawn
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum VectorFieldMode {
+    /// An instanced arrow at the center of each grid cell, pointing along the field
+    #[default]
+    Arrows,
+    /// Line integral convolution: noise smeared along the field's streamlines
+    LineIntegralConvolution,
+}
@@ -0,0 +1,159 @@
+/// Colors the red channel of `u_scalar` through a colormap, after normalizing it to `u_range`.
+///
+/// `u_colormap` selects viridis (0), inferno (1), or the `u_colormap_texture` lookup texture (2).
+/// When `u_contour_count` is above 0, lines of `u_contour_color` are drawn `u_contour_width`
+/// pixels wide at that many evenly spaced levels.
+pub const HEATMAP_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform highp sampler2D u_scalar;
+uniform sampler2D u_colormap_texture;
+uniform int u_colormap;
+uniform vec2 u_range;
+uniform float u_contour_count;
+uniform float u_contour_width;
+uniform vec4 u_contour_color;
+
+in vec2 v_uv;
+out vec4 out_color;
+
+vec3 polynomial(float t, vec3 c0, vec3 c1, vec3 c2, vec3 c3, vec3 c4, vec3 c5, vec3 c6) {
+    return c0 + t * (c1 + t * (c2 + t * (c3 + t * (c4 + t * (c5 + t * c6)))));
+}
+
+vec3 viridis(float t) {
+    return polynomial(t,
+        vec3(0.2777273272234177, 0.005407344544966578, 0.3340998053353061),
+        vec3(0.1050930431085774, 1.404613529898575, 1.384590162594685),
+        vec3(-0.3308618287255563, 0.214847559468213, 0.09509516302823659),
+        vec3(-4.634230498983486, -5.799100973351585, -19.33244095627987),
+        vec3(6.228269936347081, 14.17993336680509, 56.69055260068105),
+        vec3(4.776384997670288, -13.74514537774601, -65.35303263337234),
+        vec3(-5.435455855934631, 4.645852612178535, 26.3124352495832));
+}
+
+vec3 inferno(float t) {
+    return polynomial(t,
+        vec3(0.0002189403691192265, 0.001651004631001012, -0.01948089843709184),
+        vec3(0.1065134194856116, 0.5639564367884091, 3.932712388889277),
+        vec3(11.60249308247187, -3.972853965665698, -15.9423941062914),
+        vec3(-41.70399613139459, 17.43639888205313, 44.35414519872813),
+        vec3(77.162935699427, -33.40235894210092, -81.80730925738993),
+        vec3(-71.31942824499214, 32.62606426397723, 73.20951985803202),
+        vec3(25.13112622477341, -12.24266895238567, -23.07032500287172));
+}
+
+void main() {
+    float value = texture(u_scalar, v_uv).r;
+    float t = clamp((value - u_range.x) / (u_range.y - u_range.x), 0.0, 1.0);
+
+    vec3 color;
+    if (u_colormap == 0) {
+        color = viridis(t);
+    } else if (u_colormap == 1) {
+        color = inferno(t);
+    } else {
+        color = texture(u_colormap_texture, vec2(t, 0.5)).rgb;
+    }
+    color = clamp(color, 0.0, 1.0);
+
+    if (u_contour_count > 0.0) {
+        float level = t * u_contour_count;
+        float distance_to_line = abs(fract(level - 0.5) - 0.5) / max(fwidth(level), 1e-6);
+        float line = 1.0 - clamp(distance_to_line - 0.5 * u_contour_width + 0.5, 0.0, 1.0);
+        color = mix(color, u_contour_color.rgb, line * u_contour_color.a);
+    }
+
+    out_color = vec4(color, 1.0);
+}
+"#;
+
+/// Attribute-less, instanced vertex shader for [crate::VectorFieldMode::Arrows]: each instance is
+/// one arrow (9 vertices) at the center of a `u_spacing`-pixel grid cell, pointing along `u_field`
+pub const VECTOR_FIELD_ARROWS_VERTEX_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform highp sampler2D u_field;
+uniform vec2 u_resolution;
+uniform float u_spacing;
+uniform float u_scale;
+uniform int u_columns;
+
+// (along, across) the arrow, relative to its length: a shaft and a triangular head
+const vec2 ARROW[9] = vec2[9](
+    vec2(0.0, -0.05), vec2(0.7, -0.05), vec2(0.7, 0.05),
+    vec2(0.0, -0.05), vec2(0.7, 0.05), vec2(0.0, 0.05),
+    vec2(0.7, -0.2), vec2(1.0, 0.0), vec2(0.7, 0.2)
+);
+
+void main() {
+    vec2 cell = vec2(gl_InstanceID % u_columns, gl_InstanceID / u_columns);
+    vec2 center = (cell + 0.5) * u_spacing;
+    vec2 vector = texture(u_field, center / u_resolution).xy;
+    float magnitude = length(vector);
+    vec2 along = magnitude > 0.0 ? vector / magnitude : vec2(1.0, 0.0);
+    vec2 across = vec2(-along.y, along.x);
+
+    vec2 local = ARROW[gl_VertexID] * magnitude * u_scale;
+    vec2 position = center + along * local.x + across * local.y;
+    gl_Position = vec4(position / u_resolution * 2.0 - 1.0, 0.0, 1.0);
+}
+"#;
+
+/// Fills each arrow of [VECTOR_FIELD_ARROWS_VERTEX_SHADER] with `u_color`
+pub const VECTOR_FIELD_ARROWS_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform vec4 u_color;
+
+out vec4 out_color;
+
+void main() {
+    out_color = u_color;
+}
+"#;
+
+/// Line integral convolution of `u_field`: averages per-pixel white noise along the streamline
+/// through each pixel, `u_length` one-pixel steps in each direction
+pub const VECTOR_FIELD_LIC_FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+
+uniform highp sampler2D u_field;
+uniform vec2 u_resolution;
+uniform int u_length;
+uniform vec4 u_color;
+
+in vec2 v_uv;
+out vec4 out_color;
+
+const int MAX_LENGTH = 64;
+
+float noise(vec2 pixel) {
+    return fract(sin(dot(floor(pixel), vec2(12.9898, 78.233))) * 43758.5453);
+}
+
+float follow(vec2 start, float direction) {
+    float sum = 0.0;
+    vec2 position = start;
+    for (int i = 0; i < MAX_LENGTH; i++) {
+        if (i >= u_length) {
+            break;
+        }
+        vec2 vector = texture(u_field, position / u_resolution).xy;
+        if (dot(vector, vector) == 0.0) {
+            break;
+        }
+        position += normalize(vector) * direction;
+        sum += noise(position);
+    }
+    return sum;
+}
+
+void main() {
+    vec2 pixel = v_uv * u_resolution;
+    float sum = noise(pixel) + follow(pixel, 1.0) + follow(pixel, -1.0);
+    float value = sum / float(2 * u_length + 1);
+    float contrast = clamp((value - 0.5) * 3.0 + 0.5, 0.0, 1.0);
+    out_color = vec4(u_color.rgb * contrast, u_color.a);
+}
+"#;
@@ -0,0 +1,248 @@
+//! Benchmarks comparing wrend's per-frame overhead against hand-written `web-sys` calls.
+//!
+//! Run in a browser with `wasm-pack test --headless --chrome -- --test overhead`.
+//! Results are logged to the console and are available from [`BenchRunner::results`].
+
+#![cfg(target_arch = "wasm32")]
+
+use std::{cell::Cell, rc::Rc, sync::Once};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{HtmlCanvasElement, WebGl2RenderingContext};
+use wrend::{BenchRunner, ProgramLink, RendererData, UniformContext, UniformLink};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const VERTEX_SHADER: &str = r#"#version 300 es
+void main() {
+    vec2 position = vec2(gl_VertexID % 2, gl_VertexID / 2) * 2.0 - 1.0;
+    gl_Position = vec4(position, 0.0, 1.0);
+}
+"#;
+
+const FRAGMENT_SHADER: &str = r#"#version 300 es
+precision highp float;
+uniform float u_now;
+out vec4 out_color;
+void main() {
+    out_color = vec4(fract(u_now), 0.0, 0.0, 1.0);
+}
+"#;
+
+const PROGRAM_ID: &str = "program";
+const UNIFORM_ID: &str = "u_now";
+/// Enough uniforms for per-uniform costs (e.g. allocating a context per update) to dominate,
+/// while staying under WebGL2's minimum `MAX_FRAGMENT_UNIFORM_VECTORS`
+const MANY_UNIFORMS: usize = 128;
+
+type BenchRendererData = RendererData<
+    String,
+    String,
+    String,
+    String,
+    String,
+    String,
+    String,
+    String,
+    String,
+    String,
+    Rc<Cell<u32>>,
+>;
+
+/// Sends [BenchRunner]'s results to the browser console
+fn init_logging() {
+    static INIT_LOGGING: Once = Once::new();
+    INIT_LOGGING.call_once(|| wasm_logger::init(wasm_logger::Config::default()));
+}
+
+fn create_canvas() -> HtmlCanvasElement {
+    web_sys::window()
+        .unwrap()
+        .document()
+        .unwrap()
+        .create_element("canvas")
+        .unwrap()
+        .dyn_into()
+        .unwrap()
+}
+
+fn build_renderer_data(render_count: Rc<Cell<u32>>) -> BenchRendererData {
+    let canvas = create_canvas();
+
+    let program_link = ProgramLink::new(
+        PROGRAM_ID.to_string(),
+        "vertex".to_string(),
+        "fragment".to_string(),
+    );
+
+    let uniform_link = UniformLink::new(
+        PROGRAM_ID.to_string(),
+        UNIFORM_ID.to_string(),
+        |ctx: &UniformContext| {
+            ctx.gl()
+                .uniform1f(Some(ctx.uniform_location()), ctx.now() as f32);
+        },
+    );
+
+    let mut builder = RendererData::builder();
+    builder
+        .set_canvas(canvas)
+        .set_user_ctx(render_count)
+        .set_render_callback(|renderer_data: &BenchRendererData| {
+            let render_count = renderer_data.user_ctx().unwrap();
+            render_count.set(render_count.get() + 1);
+        })
+        .add_vertex_shader_src("vertex".to_string(), VERTEX_SHADER.to_string())
+        .add_fragment_shader_src("fragment".to_string(), FRAGMENT_SHADER.to_string())
+        .add_program_link(program_link)
+        .add_uniform_link(uniform_link)
+        .add_vao_link(PROGRAM_ID.to_string());
+
+    builder
+        .build_renderer_data()
+        .expect("RendererData should build for benchmarks")
+}
+
+/// A program reading [MANY_UNIFORMS] separate float uniforms, named `u_0`, `u_1`, etc.
+fn many_uniforms_fragment_shader() -> String {
+    let declarations: String = (0..MANY_UNIFORMS)
+        .map(|i| format!("uniform float u_{i};\n"))
+        .collect();
+    let sum = (0..MANY_UNIFORMS)
+        .map(|i| format!("u_{i}"))
+        .collect::<Vec<_>>()
+        .join(" + ");
+    format!(
+        "#version 300 es\nprecision highp float;\n{declarations}out vec4 out_color;\n\
+        void main() {{\n    out_color = vec4(fract({sum}), 0.0, 0.0, 1.0);\n}}\n"
+    )
+}
+
+fn build_many_uniforms_renderer_data() -> BenchRendererData {
+    let mut builder = RendererData::builder();
+    builder
+        .set_canvas(create_canvas())
+        .set_render_callback(|_: &BenchRendererData| {})
+        .add_vertex_shader_src("vertex".to_string(), VERTEX_SHADER.to_string())
+        .add_fragment_shader_src("fragment".to_string(), many_uniforms_fragment_shader())
+        .add_program_link(ProgramLink::new(
+            PROGRAM_ID.to_string(),
+            "vertex".to_string(),
+            "fragment".to_string(),
+        ));
+
+    for i in 0..MANY_UNIFORMS {
+        builder.add_uniform_link(UniformLink::new(
+            PROGRAM_ID.to_string(),
+            format!("u_{i}"),
+            |ctx: &UniformContext| {
+                ctx.gl()
+                    .uniform1f(Some(ctx.uniform_location()), ctx.now() as f32);
+            },
+        ));
+    }
+
+    builder
+        .build_renderer_data()
+        .expect("RendererData should build for benchmarks")
+}
+
+#[wasm_bindgen_test]
+fn wrend_overhead() {
+    init_logging();
+    let render_count = Rc::new(Cell::new(0));
+    let renderer_data = build_renderer_data(Rc::clone(&render_count));
+    let gl: WebGl2RenderingContext = renderer_data.gl().clone();
+    let program = renderer_data
+        .program(&PROGRAM_ID.to_string())
+        .unwrap()
+        .clone();
+    let vao = renderer_data.vao(&PROGRAM_ID.to_string()).unwrap().clone();
+    let location = gl.get_uniform_location(&program, UNIFORM_ID).unwrap();
+    let performance = web_sys::window().unwrap().performance().unwrap();
+
+    let mut runner = BenchRunner::new();
+
+    runner.compare(
+        "update_uniforms",
+        || {
+            renderer_data.update_uniforms();
+        },
+        || {
+            gl.use_program(Some(&program));
+            gl.uniform1f(Some(&location), performance.now() as f32);
+            gl.use_program(None);
+        },
+    );
+
+    runner.compare(
+        "use_program + use_vao",
+        || {
+            renderer_data.use_program(&PROGRAM_ID.to_string());
+            renderer_data.use_vao(&PROGRAM_ID.to_string());
+        },
+        || {
+            gl.use_program(Some(&program));
+            gl.bind_vertex_array(Some(&vao));
+        },
+    );
+
+    let direct_count = Cell::new(0);
+    runner.compare(
+        "render callback dispatch",
+        || {
+            renderer_data.render();
+        },
+        || direct_count.set(direct_count.get() + 1),
+    );
+
+    assert_eq!(runner.results().len(), 3);
+    assert!(render_count.get() > 0);
+    for comparison in runner.results() {
+        assert!(comparison.wrend().median_ms().is_finite());
+        assert!(comparison.raw().median_ms().is_finite());
+    }
+}
+
+/// Per-uniform overhead when updating many uniforms each frame (e.g. from reusing each uniform's
+/// `UniformContext` rather than building one per update). See CONTRIBUTING.md for comparing results
+/// before and after a change.
+#[wasm_bindgen_test]
+fn many_uniforms_overhead() {
+    init_logging();
+    let renderer_data = build_many_uniforms_renderer_data();
+    let gl: WebGl2RenderingContext = renderer_data.gl().clone();
+    let program = renderer_data
+        .program(&PROGRAM_ID.to_string())
+        .unwrap()
+        .clone();
+    let locations: Vec<_> = (0..MANY_UNIFORMS)
+        .map(|i| {
+            gl.get_uniform_location(&program, &format!("u_{i}"))
+                .unwrap()
+        })
+        .collect();
+    let performance = web_sys::window().unwrap().performance().unwrap();
+
+    let mut runner = BenchRunner::new();
+    runner.set_batch_size(10);
+
+    runner.compare(
+        "update_uniforms (many uniforms)",
+        || {
+            renderer_data.update_uniforms();
+        },
+        || {
+            let now = performance.now() as f32;
+            for location in &locations {
+                gl.use_program(Some(&program));
+                gl.uniform1f(Some(location), now);
+            }
+            gl.use_program(None);
+        },
+    );
+
+    let comparison = &runner.results()[0];
+    assert!(comparison.wrend().median_ms().is_finite());
+    assert!(comparison.raw().median_ms().is_finite());
+}
@@ -0,0 +1,106 @@
+// Minimal DOM + WebGL2 stand-in so wrend's overhead benchmarks can run in Node.js.
+// Every WebGL call is a no-op JS function, so timings measure wrend's Rust/wasm-side
+// overhead and the wasm<->JS boundary, not driver or GPU work.
+const GL = {
+  COMPILE_STATUS: 0x8b81,
+  LINK_STATUS: 0x8b82,
+  ACTIVE_UNIFORMS: 0x8b86,
+  ACTIVE_ATTRIBUTES: 0x8b89,
+  MAX_COMBINED_TEXTURE_IMAGE_UNITS: 0x8b4d,
+  ACTIVE_TEXTURE: 0x84e0,
+  TEXTURE0: 0x84c0,
+  FLOAT: 0x1406,
+};
+
+const classNames = [
+  'Window', 'Document', 'Element', 'Node', 'EventTarget', 'HTMLElement', 'HTMLCanvasElement',
+  'WebGL2RenderingContext', 'WebGLProgram', 'WebGLShader', 'WebGLTexture', 'WebGLBuffer',
+  'WebGLFramebuffer', 'WebGLUniformLocation', 'WebGLVertexArrayObject', 'WebGLTransformFeedback',
+  'WebGLActiveInfo', 'WebGLContextAttributes', 'Performance', 'CSSStyleDeclaration',
+];
+for (const name of classNames) {
+  globalThis[name] = class {
+    static [Symbol.hasInstance](value) {
+      return value !== null && typeof value === 'object';
+    }
+  };
+}
+
+let activeUniformCount = 0;
+const uniformLocations = new Map();
+
+const glTarget = { drawingBufferWidth: 300, drawingBufferHeight: 150 };
+const gl = new Proxy(glTarget, {
+  get(target, prop) {
+    if (prop in target) return target[prop];
+    if (typeof prop !== 'string') return undefined;
+    if (prop.startsWith('create')) return () => ({});
+    switch (prop) {
+      case 'getShaderParameter':
+        return (_, pname) => pname === GL.COMPILE_STATUS;
+      case 'getProgramParameter':
+        return (_, pname) => {
+          if (pname === GL.LINK_STATUS) return true;
+          if (pname === GL.ACTIVE_UNIFORMS) return activeUniformCount;
+          return 0;
+        };
+      case 'shaderSource':
+        return (_, source) => {
+          const matches = source.match(/uniform\s+\w+\s+\w+\s+\w+;|uniform\s+\w+\s+\w+;/g) || [];
+          activeUniformCount = Math.max(activeUniformCount, matches.length);
+        };
+      case 'getUniformLocation':
+        return (_, name) => {
+          if (!uniformLocations.has(name)) uniformLocations.set(name, { name });
+          return uniformLocations.get(name);
+        };
+      case 'getActiveUniform':
+        return () => null;
+      case 'getParameter':
+        return (pname) => {
+          if (pname === GL.MAX_COMBINED_TEXTURE_IMAGE_UNITS) return 32;
+          if (pname === GL.ACTIVE_TEXTURE) return GL.TEXTURE0;
+          return null;
+        };
+      case 'getShaderInfoLog':
+      case 'getProgramInfoLog':
+        return () => '';
+      case 'getContextAttributes':
+        return () => ({ alpha: true });
+      case 'getError':
+        return () => 0;
+      case 'isEnabled':
+        return () => false;
+      case 'getExtension':
+      case 'getSupportedExtensions':
+        return () => null;
+      default:
+        return () => undefined;
+    }
+  },
+});
+
+const element = () => ({
+  width: 300,
+  height: 150,
+  style: {},
+  getContext: () => gl,
+  addEventListener() {},
+  removeEventListener() {},
+  setAttribute() {},
+  appendChild() {},
+  getBoundingClientRect: () => ({ left: 0, top: 0, width: 300, height: 150 }),
+});
+
+globalThis.document = {
+  createElement: element,
+  body: element(),
+  addEventListener() {},
+  removeEventListener() {},
+};
+globalThis.window = globalThis;
+globalThis.devicePixelRatio = 1;
+globalThis.addEventListener = () => {};
+globalThis.removeEventListener = () => {};
+globalThis.requestAnimationFrame = () => 0;
+globalThis.cancelAnimationFrame = () => {};
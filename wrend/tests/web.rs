@@ -11,3 +11,108 @@ wasm_bindgen_test_configure!(run_in_browser);
 fn pass() {
     // todo
 }
+
+#[cfg(feature = "js")]
+mod js_render_path {
+    use js_sys::Function;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::*;
+    use web_sys::{HtmlCanvasElement, WebGl2RenderingContext};
+    use wrend::{RendererDataJs, RendererJs};
+
+    /// Builds a renderer whose render callback is a JavaScript function that clears the canvas
+    /// to a dark gray
+    fn build_renderer() -> RendererJs {
+        let canvas: HtmlCanvasElement = web_sys::window()
+            .unwrap()
+            .document()
+            .unwrap()
+            .create_element("canvas")
+            .unwrap()
+            .dyn_into()
+            .unwrap();
+        canvas.set_width(4);
+        canvas.set_height(4);
+
+        let render_callback = Function::new_with_args(
+            "rendererData",
+            "const gl = rendererData.gl();
+            gl.clearColor(0.25, 0.25, 0.25, 1.0);
+            gl.clear(gl.COLOR_BUFFER_BIT);",
+        );
+
+        let mut builder = RendererDataJs::builder();
+        builder.set_canvas(canvas);
+        builder.set_render_callback(render_callback.unchecked_into());
+        builder.add_output_adjustment(
+            "output_adjustment".to_string(),
+            "output_adjustment_vertex".to_string(),
+            "output_adjustment_fragment".to_string(),
+        );
+        builder
+            .build_renderer()
+            .expect("Renderer should build for the JavaScript render path")
+    }
+
+    fn read_red(gl: &WebGl2RenderingContext) -> u8 {
+        let mut pixel = [0u8; 4];
+        gl.read_pixels_with_opt_u8_array(
+            0,
+            0,
+            1,
+            1,
+            WebGl2RenderingContext::RGBA,
+            WebGl2RenderingContext::UNSIGNED_BYTE,
+            Some(&mut pixel),
+        )
+        .unwrap();
+        pixel[0]
+    }
+
+    #[wasm_bindgen_test]
+    fn applies_output_adjustment_after_js_render_callback() {
+        let mut renderer = build_renderer();
+        let gl = renderer.gl();
+
+        renderer.render();
+        let unadjusted = read_red(&gl);
+
+        renderer.set_output_adjustment("output_adjustment".to_string(), 0.5, 1.0, 1.0);
+        renderer.render();
+        let adjusted = read_red(&gl);
+
+        // 0.25 + 0.5 brightness
+        assert!(
+            unadjusted.abs_diff(64) <= 2,
+            "unadjusted red was {unadjusted}"
+        );
+        assert!(adjusted.abs_diff(191) <= 2, "adjusted red was {adjusted}");
+    }
+
+    #[wasm_bindgen_test]
+    fn overdraw_view_restores_app_blend_state() {
+        let renderer = build_renderer();
+        let gl = renderer.gl();
+        renderer.set_debug_view("overdraw").unwrap();
+        let blend_parameter = |pname| gl.get_parameter(pname).unwrap().as_f64().unwrap() as u32;
+
+        renderer.render();
+        assert!(!gl.is_enabled(WebGl2RenderingContext::BLEND));
+
+        gl.enable(WebGl2RenderingContext::BLEND);
+        gl.blend_func(
+            WebGl2RenderingContext::SRC_ALPHA,
+            WebGl2RenderingContext::ONE_MINUS_SRC_ALPHA,
+        );
+        renderer.render();
+        assert!(gl.is_enabled(WebGl2RenderingContext::BLEND));
+        assert_eq!(
+            blend_parameter(WebGl2RenderingContext::BLEND_SRC_RGB),
+            WebGl2RenderingContext::SRC_ALPHA
+        );
+        assert_eq!(
+            blend_parameter(WebGl2RenderingContext::BLEND_DST_RGB),
+            WebGl2RenderingContext::ONE_MINUS_SRC_ALPHA
+        );
+    }
+}